@@ -0,0 +1,109 @@
+// Determines which nightly feature gates enable run-time feature detection.
+//
+// Each gate only exists on nightlies from a particular date, so each has its own cfg.
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    for cfg in CFGS {
+        println!("cargo:rustc-check-cfg=cfg({})", cfg);
+    }
+
+    if std::env::var_os("CARGO_FEATURE_NIGHTLY").is_some() {
+        for cfg in nightly_cfgs() {
+            println!("cargo:rustc-cfg={}", cfg);
+        }
+    }
+}
+
+const CFGS: &[&str] = &[
+    "arch_types_stdsimd",
+    "arch_types_stdarch",
+    "arch_types_arm_detection",
+    "arch_types_mips_detection",
+    "arch_types_powerpc_detection",
+    "arch_types_power9_detection",
+    "arch_types_riscv_detection",
+    "arch_types_amx",
+];
+
+fn nightly_cfgs() -> Vec<&'static str> {
+    let mut cfgs = Vec::new();
+    if stdsimd() {
+        cfgs.push("arch_types_stdsimd");
+    }
+    if stdarch() {
+        cfgs.push("arch_types_stdarch");
+    }
+    if stdsimd() || stdarch() {
+        // Detection on these architectures predates the split of `stdsimd`, so it's available
+        // behind whichever gate the nightly uses
+        cfgs.push("arch_types_arm_detection");
+        cfgs.push("arch_types_mips_detection");
+        cfgs.push("arch_types_powerpc_detection");
+    }
+    if power9() {
+        cfgs.push("arch_types_power9_detection");
+    }
+    if riscv() {
+        cfgs.push("arch_types_riscv_detection");
+    }
+    if amx() {
+        cfgs.push("arch_types_amx");
+    }
+    cfgs
+}
+
+// Older nightlies gate all feature detection behind `stdsimd`
+#[rustversion::all(nightly, before(2024-02-24))]
+fn stdsimd() -> bool {
+    true
+}
+
+#[rustversion::not(all(nightly, before(2024-02-24)))]
+fn stdsimd() -> bool {
+    false
+}
+
+// Newer nightlies split `stdsimd` into per-architecture gates, such as
+// `stdarch_arm_feature_detection` and `stdarch_neon_dotprod`
+#[rustversion::all(nightly, since(2024-02-24))]
+fn stdarch() -> bool {
+    true
+}
+
+#[rustversion::not(all(nightly, since(2024-02-24)))]
+fn stdarch() -> bool {
+    false
+}
+
+// Detection of the `power8-*` and `power9-*` features was added after the split
+#[rustversion::all(nightly, since(2024-11-01))]
+fn power9() -> bool {
+    true
+}
+
+#[rustversion::not(all(nightly, since(2024-11-01)))]
+fn power9() -> bool {
+    false
+}
+
+// `is_riscv_feature_detected` was added behind `stdsimd`
+#[rustversion::all(nightly, since(2022-09-01))]
+fn riscv() -> bool {
+    true
+}
+
+#[rustversion::not(all(nightly, since(2022-09-01)))]
+fn riscv() -> bool {
+    false
+}
+
+// AMX detection and the `x86_amx_intrinsics` gate were added later
+#[rustversion::all(nightly, since(2024-09-01))]
+fn amx() -> bool {
+    true
+}
+
+#[rustversion::not(all(nightly, since(2024-09-01)))]
+fn amx() -> bool {
+    false
+}
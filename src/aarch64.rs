@@ -0,0 +1,431 @@
+//! Additional functionality for the AArch64 architecture.
+//!
+//! # Architecture revisions
+//! Each token type proves support for the features made mandatory by an architecture revision
+//! (and all previous revisions).  Armv9.0 is based on Armv8.5 and adds SVE2, and each later Armv9.x
+//! revision adds the features of Armv8.(x + 5).  Armv9.0 therefore doesn't include Armv8.6, so
+//! [`Aarch64Level`] is only partially ordered.
+//!
+//! [`Aarch64Level`]: enum.Aarch64Level.html
+
+use crate::{FeatureSet, Features};
+
+pub mod intrinsics;
+
+// `new_features_type` can't be used within this crate, since macro-expanded exported macros can't
+// be referred to by path.  Instead, the final step of `new_features_type` is used directly.
+macro_rules! level {
+    { $(#[doc = $doc:literal])* $name:ident => $($feature:ident)* } => {
+        new_features_type_internal! { [$($doc)*] pub $name => [] => [$($feature)*] }
+    };
+}
+
+level! {
+    /// Armv8.0-A, the baseline for AArch64 Linux targets.
+    Armv8_0 => fp neon
+}
+
+level! {
+    /// Armv8.1-A.
+    Armv8_1 => fp neon crc lse rdm
+}
+
+level! {
+    /// Armv8.2-A.
+    ///
+    /// Armv8.2-A doesn't add any mandatory features that can be detected.
+    Armv8_2 => fp neon crc lse rdm
+}
+
+level! {
+    /// Armv8.3-A.
+    Armv8_3 => fp neon crc lse rdm rcpc fcma jsconv paca pacg
+}
+
+level! {
+    /// Armv8.4-A.
+    Armv8_4 => fp neon crc lse rdm rcpc fcma jsconv paca pacg dotprod dit flagm lse2
+}
+
+level! {
+    /// Armv8.5-A.
+    Armv8_5 =>
+        fp neon crc lse rdm rcpc fcma jsconv paca pacg dotprod dit flagm lse2 frintts sb
+        ssbs bti
+}
+
+level! {
+    /// Armv8.6-A.
+    Armv8_6 =>
+        fp neon crc lse rdm rcpc fcma jsconv paca pacg dotprod dit flagm lse2 frintts sb
+        ssbs bti bf16 i8mm
+}
+
+level! {
+    /// Armv8.7-A.
+    Armv8_7 =>
+        fp neon crc lse rdm rcpc fcma jsconv paca pacg dotprod dit flagm lse2 frintts sb
+        ssbs bti bf16 i8mm wfxt
+}
+
+level! {
+    /// Armv8.8-A.
+    Armv8_8 =>
+        fp neon crc lse rdm rcpc fcma jsconv paca pacg dotprod dit flagm lse2 frintts sb
+        ssbs bti bf16 i8mm wfxt hbc mops
+}
+
+level! {
+    /// Armv8.9-A.
+    Armv8_9 =>
+        fp neon crc lse rdm rcpc fcma jsconv paca pacg dotprod dit flagm lse2 frintts sb
+        ssbs bti bf16 i8mm wfxt hbc mops cssc
+}
+
+level! {
+    /// Armv9.0-A.
+    Armv9_0 =>
+        fp neon crc lse rdm rcpc fcma jsconv paca pacg dotprod dit flagm lse2 frintts sb
+        ssbs bti sve sve2
+}
+
+level! {
+    /// Armv9.1-A.
+    Armv9_1 =>
+        fp neon crc lse rdm rcpc fcma jsconv paca pacg dotprod dit flagm lse2 frintts sb
+        ssbs bti sve sve2 bf16 i8mm
+}
+
+level! {
+    /// Armv9.2-A.
+    Armv9_2 =>
+        fp neon crc lse rdm rcpc fcma jsconv paca pacg dotprod dit flagm lse2 frintts sb
+        ssbs bti sve sve2 bf16 i8mm wfxt
+}
+
+level! {
+    /// Armv9.3-A.
+    Armv9_3 =>
+        fp neon crc lse rdm rcpc fcma jsconv paca pacg dotprod dit flagm lse2 frintts sb
+        ssbs bti sve sve2 bf16 i8mm wfxt hbc mops
+}
+
+level! {
+    /// Armv9.4-A.
+    Armv9_4 =>
+        fp neon crc lse rdm rcpc fcma jsconv paca pacg dotprod dit flagm lse2 frintts sb
+        ssbs bti sve sve2 bf16 i8mm wfxt hbc mops cssc
+}
+
+/// An AArch64 architecture revision.
+///
+/// Revisions are partially ordered, with each revision greater than the revisions whose mandatory
+/// features it includes.  Armv9.x is greater than Armv8.(x + 5) and earlier, but no Armv8 revision
+/// is greater than an Armv9 revision, so Armv8.6 and Armv9.0 aren't comparable.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Aarch64Level {
+    /// Armv8.0-A, proven by [`Armv8_0`].
+    ///
+    /// [`Armv8_0`]: struct.Armv8_0.html
+    Armv8_0,
+
+    /// Armv8.1-A, proven by [`Armv8_1`].
+    ///
+    /// [`Armv8_1`]: struct.Armv8_1.html
+    Armv8_1,
+
+    /// Armv8.2-A, proven by [`Armv8_2`].
+    ///
+    /// [`Armv8_2`]: struct.Armv8_2.html
+    Armv8_2,
+
+    /// Armv8.3-A, proven by [`Armv8_3`].
+    ///
+    /// [`Armv8_3`]: struct.Armv8_3.html
+    Armv8_3,
+
+    /// Armv8.4-A, proven by [`Armv8_4`].
+    ///
+    /// [`Armv8_4`]: struct.Armv8_4.html
+    Armv8_4,
+
+    /// Armv8.5-A, proven by [`Armv8_5`].
+    ///
+    /// [`Armv8_5`]: struct.Armv8_5.html
+    Armv8_5,
+
+    /// Armv8.6-A, proven by [`Armv8_6`].
+    ///
+    /// [`Armv8_6`]: struct.Armv8_6.html
+    Armv8_6,
+
+    /// Armv8.7-A, proven by [`Armv8_7`].
+    ///
+    /// [`Armv8_7`]: struct.Armv8_7.html
+    Armv8_7,
+
+    /// Armv8.8-A, proven by [`Armv8_8`].
+    ///
+    /// [`Armv8_8`]: struct.Armv8_8.html
+    Armv8_8,
+
+    /// Armv8.9-A, proven by [`Armv8_9`].
+    ///
+    /// [`Armv8_9`]: struct.Armv8_9.html
+    Armv8_9,
+
+    /// Armv9.0-A, proven by [`Armv9_0`].
+    ///
+    /// [`Armv9_0`]: struct.Armv9_0.html
+    Armv9_0,
+
+    /// Armv9.1-A, proven by [`Armv9_1`].
+    ///
+    /// [`Armv9_1`]: struct.Armv9_1.html
+    Armv9_1,
+
+    /// Armv9.2-A, proven by [`Armv9_2`].
+    ///
+    /// [`Armv9_2`]: struct.Armv9_2.html
+    Armv9_2,
+
+    /// Armv9.3-A, proven by [`Armv9_3`].
+    ///
+    /// [`Armv9_3`]: struct.Armv9_3.html
+    Armv9_3,
+
+    /// Armv9.4-A, proven by [`Armv9_4`].
+    ///
+    /// [`Armv9_4`]: struct.Armv9_4.html
+    Armv9_4,
+}
+
+impl Aarch64Level {
+    /// Every architecture revision, ordered by version.
+    pub const ALL: &'static [Aarch64Level] = &[
+        Aarch64Level::Armv8_0,
+        Aarch64Level::Armv8_1,
+        Aarch64Level::Armv8_2,
+        Aarch64Level::Armv8_3,
+        Aarch64Level::Armv8_4,
+        Aarch64Level::Armv8_5,
+        Aarch64Level::Armv8_6,
+        Aarch64Level::Armv8_7,
+        Aarch64Level::Armv8_8,
+        Aarch64Level::Armv8_9,
+        Aarch64Level::Armv9_0,
+        Aarch64Level::Armv9_1,
+        Aarch64Level::Armv9_2,
+        Aarch64Level::Armv9_3,
+        Aarch64Level::Armv9_4,
+    ];
+
+    /// Detect the highest architecture revision supported by the CPU.
+    ///
+    /// Returns `None` if the CPU doesn't support Armv8.0, which is only possible if feature
+    /// detection isn't available.
+    pub fn detect() -> Option<Self> {
+        // Supporting an Armv9 revision and Armv8.(x + 5) implies supporting Armv9.x, so the last
+        // supported revision in `ALL` is greater than every other supported revision
+        Self::ALL
+            .iter()
+            .rev()
+            .cloned()
+            .find(|level| level.is_detected())
+    }
+
+    /// Detect whether the CPU supports this architecture revision.
+    pub fn is_detected(self) -> bool {
+        match self {
+            Aarch64Level::Armv8_0 => Armv8_0::new().is_some(),
+            Aarch64Level::Armv8_1 => Armv8_1::new().is_some(),
+            Aarch64Level::Armv8_2 => Armv8_2::new().is_some(),
+            Aarch64Level::Armv8_3 => Armv8_3::new().is_some(),
+            Aarch64Level::Armv8_4 => Armv8_4::new().is_some(),
+            Aarch64Level::Armv8_5 => Armv8_5::new().is_some(),
+            Aarch64Level::Armv8_6 => Armv8_6::new().is_some(),
+            Aarch64Level::Armv8_7 => Armv8_7::new().is_some(),
+            Aarch64Level::Armv8_8 => Armv8_8::new().is_some(),
+            Aarch64Level::Armv8_9 => Armv8_9::new().is_some(),
+            Aarch64Level::Armv9_0 => Armv9_0::new().is_some(),
+            Aarch64Level::Armv9_1 => Armv9_1::new().is_some(),
+            Aarch64Level::Armv9_2 => Armv9_2::new().is_some(),
+            Aarch64Level::Armv9_3 => Armv9_3::new().is_some(),
+            Aarch64Level::Armv9_4 => Armv9_4::new().is_some(),
+        }
+    }
+
+    /// The features required by this architecture revision.
+    pub fn features(self) -> FeatureSet {
+        match self {
+            Aarch64Level::Armv8_0 => FeatureSet::of::<Armv8_0>(),
+            Aarch64Level::Armv8_1 => FeatureSet::of::<Armv8_1>(),
+            Aarch64Level::Armv8_2 => FeatureSet::of::<Armv8_2>(),
+            Aarch64Level::Armv8_3 => FeatureSet::of::<Armv8_3>(),
+            Aarch64Level::Armv8_4 => FeatureSet::of::<Armv8_4>(),
+            Aarch64Level::Armv8_5 => FeatureSet::of::<Armv8_5>(),
+            Aarch64Level::Armv8_6 => FeatureSet::of::<Armv8_6>(),
+            Aarch64Level::Armv8_7 => FeatureSet::of::<Armv8_7>(),
+            Aarch64Level::Armv8_8 => FeatureSet::of::<Armv8_8>(),
+            Aarch64Level::Armv8_9 => FeatureSet::of::<Armv8_9>(),
+            Aarch64Level::Armv9_0 => FeatureSet::of::<Armv9_0>(),
+            Aarch64Level::Armv9_1 => FeatureSet::of::<Armv9_1>(),
+            Aarch64Level::Armv9_2 => FeatureSet::of::<Armv9_2>(),
+            Aarch64Level::Armv9_3 => FeatureSet::of::<Armv9_3>(),
+            Aarch64Level::Armv9_4 => FeatureSet::of::<Armv9_4>(),
+        }
+    }
+
+    // The Armv8 revision whose features are included, and whether SVE2 is included
+    fn armv8_minor(self) -> (u8, bool) {
+        match self {
+            Aarch64Level::Armv8_0 => (0, false),
+            Aarch64Level::Armv8_1 => (1, false),
+            Aarch64Level::Armv8_2 => (2, false),
+            Aarch64Level::Armv8_3 => (3, false),
+            Aarch64Level::Armv8_4 => (4, false),
+            Aarch64Level::Armv8_5 => (5, false),
+            Aarch64Level::Armv8_6 => (6, false),
+            Aarch64Level::Armv8_7 => (7, false),
+            Aarch64Level::Armv8_8 => (8, false),
+            Aarch64Level::Armv8_9 => (9, false),
+            Aarch64Level::Armv9_0 => (5, true),
+            Aarch64Level::Armv9_1 => (6, true),
+            Aarch64Level::Armv9_2 => (7, true),
+            Aarch64Level::Armv9_3 => (8, true),
+            Aarch64Level::Armv9_4 => (9, true),
+        }
+    }
+}
+
+impl PartialOrd for Aarch64Level {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        use core::cmp::Ordering;
+        let (minor, armv9) = self.armv8_minor();
+        let (other_minor, other_armv9) = other.armv8_minor();
+        match (minor.cmp(&other_minor), armv9.cmp(&other_armv9)) {
+            (ordering, Ordering::Equal) => Some(ordering),
+            (Ordering::Greater, Ordering::Less) | (Ordering::Less, Ordering::Greater) => None,
+            (_, ordering) => Some(ordering),
+        }
+    }
+}
+
+/// Query the SVE vector length.
+///
+/// This trait is implemented for any type proving support for SVE.  Requires Rust 1.59.
+#[rustversion::since(1.59)]
+pub trait SveVectorLength: Features<sve = crate::logic::True> {
+    /// Returns the current SVE vector length of this thread, in bytes.
+    #[inline]
+    fn sve_vector_length(&self) -> usize {
+        let bytes: usize;
+        // `rdvl x0, #1`, encoded directly since the assembler may not enable SVE
+        unsafe {
+            core::arch::asm!(".inst 0x04bf5020", out("x0") bytes, options(nomem, nostack));
+        }
+        bytes
+    }
+}
+
+#[rustversion::since(1.59)]
+impl<T> SveVectorLength for T where T: Features<sve = crate::logic::True> {}
+
+/// An error returned by the kernel, containing the `errno` value.
+#[cfg(target_os = "linux")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Errno(pub i32);
+
+#[cfg(target_os = "linux")]
+impl core::fmt::Display for Errno {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "kernel returned error {}", self.0)
+    }
+}
+
+#[cfg(all(target_os = "linux", feature = "std"))]
+impl std::error::Error for Errno {}
+
+/// The SVE vector length configuration of a thread.
+#[cfg(target_os = "linux")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SveConfig {
+    /// The vector length, in bytes.
+    pub vector_length: usize,
+
+    /// Whether the vector length is inherited by child processes and threads.
+    pub inherit: bool,
+}
+
+#[cfg(target_os = "linux")]
+const PR_SVE_SET_VL: usize = 50;
+#[cfg(target_os = "linux")]
+const PR_SVE_GET_VL: usize = 51;
+#[cfg(target_os = "linux")]
+const PR_SVE_VL_LEN_MASK: usize = 0xffff;
+#[cfg(target_os = "linux")]
+const PR_SVE_VL_INHERIT: usize = 1 << 17;
+
+#[cfg(target_os = "linux")]
+impl SveConfig {
+    fn from_prctl(value: usize) -> Self {
+        Self {
+            vector_length: value & PR_SVE_VL_LEN_MASK,
+            inherit: value & PR_SVE_VL_INHERIT != 0,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[rustversion::since(1.59)]
+fn prctl(option: usize, arg: usize) -> Result<usize, Errno> {
+    const SYS_PRCTL: usize = 167;
+    let ret: isize;
+    unsafe {
+        core::arch::asm!(
+            "svc #0",
+            in("x8") SYS_PRCTL,
+            inlateout("x0") option => ret,
+            in("x1") arg,
+            in("x2") 0usize,
+            in("x3") 0usize,
+            in("x4") 0usize,
+            options(nostack),
+        );
+    }
+    if ret < 0 {
+        Err(Errno(-ret as i32))
+    } else {
+        Ok(ret as usize)
+    }
+}
+
+/// Returns the SVE vector length configuration of the current thread, using
+/// `prctl(PR_SVE_GET_VL)`.
+///
+/// Requires Rust 1.59.
+#[cfg(target_os = "linux")]
+#[rustversion::since(1.59)]
+pub fn get_sve_config(_: impl Features<sve = crate::logic::True>) -> Result<SveConfig, Errno> {
+    prctl(PR_SVE_GET_VL, 0).map(SveConfig::from_prctl)
+}
+
+/// Sets the SVE vector length configuration of the current thread, using
+/// `prctl(PR_SVE_SET_VL)`.
+///
+/// The kernel selects the largest supported vector length not exceeding the requested length,
+/// and the resulting configuration is returned.
+///
+/// Requires Rust 1.59.
+#[cfg(target_os = "linux")]
+#[rustversion::since(1.59)]
+pub fn set_sve_config(
+    _: impl Features<sve = crate::logic::True>,
+    config: SveConfig,
+) -> Result<SveConfig, Errno> {
+    let mut arg = config.vector_length & PR_SVE_VL_LEN_MASK;
+    if config.inherit {
+        arg |= PR_SVE_VL_INHERIT;
+    }
+    prctl(PR_SVE_SET_VL, arg).map(SveConfig::from_prctl)
+}
@@ -0,0 +1,4113 @@
+//! Safe wrappers for `core::arch` intrinsics.
+//!
+//! Each intrinsic requiring CPU features is exposed as a function with the same name, taking a
+//! [`Features`] token proving exactly the features the intrinsic requires as its first argument.
+//! Intrinsics that access memory through pointers remain `unsafe`, and have the same safety
+//! requirements as the underlying intrinsic.
+//!
+//! Intrinsics that don't require any CPU features, or are deprecated, are not included.
+//!
+//! Wrappers require Rust 1.63, or the version the intrinsic was stabilized in if later.  The
+//! `dotprod` and `sm4` intrinsics are unstable and require the `nightly` cargo feature.
+//!
+//! ```
+//! # #[cfg(target_arch = "aarch64")]
+//! # {
+//! use arch_types::{aarch64::intrinsics, new_features_type, Features};
+//!
+//! new_features_type! { Neon => "neon" }
+//!
+//! if let Some(neon) = Neon::new() {
+//!     let a = intrinsics::vdupq_n_f32(neon, 1.);
+//!     let b = intrinsics::vaddq_f32(neon, a, a);
+//!     let mut out = [0f32; 4];
+//!     unsafe { intrinsics::vst1q_f32(neon, out.as_mut_ptr(), b) };
+//!     assert_eq!(out, [2.; 4]);
+//! }
+//! # }
+//! ```
+//!
+//! [`Features`]: ../../trait.Features.html
+#![allow(clippy::too_many_arguments)]
+// Enabling target features on the wrappers would make them unsafe to call, so intrinsics marked
+// `#[inline(always)]` are only inlined once the wrapper is inlined into a caller with the features
+#![allow(unknown_lints, inline_always_mismatching_target_features)]
+
+use core::arch::aarch64 as arch;
+
+// Create a wrapper for each intrinsic, which calls the intrinsic after requiring a token
+macro_rules! intrinsics {
+    {
+        $(
+            $(#[$attr:meta])*
+            [$($feature:ident),*] $safety:tt fn $name:ident
+            $(<$(const $const:ident: $const_ty:ty),*>)?
+            ($($arg:ident: $arg_ty:ty),*) $(-> $ret:ty)?;
+        )*
+    } => {
+        $(
+            intrinsics! {
+                @wrapper $safety [$(#[$attr])*] [$($feature),*] $name
+                [$($(const $const: $const_ty),*)?] [$($($const),*)?]
+                ($($arg: $arg_ty),*) [$($ret)?]
+            }
+        )*
+    };
+
+    {
+        @wrapper safe [$(#[$attr:meta])*] [$($feature:ident),*] $name:ident
+        [$($generic:tt)*] [$($const:ident),*] ($($arg:ident: $arg_ty:ty),*) [$($ret:ty)?]
+    } => {
+        $(#[$attr])*
+        #[doc = concat!("Calls [`", stringify!($name), "`](https://doc.rust-lang.org/core/arch/aarch64/fn.", stringify!($name), ".html).")]
+        #[inline(always)]
+        pub fn $name<$($generic)*>(
+            _: impl crate::Features<$($feature = crate::logic::True),*>,
+            $($arg: $arg_ty),*
+        ) $(-> $ret)? {
+            unsafe { arch::$name::<$($const),*>($($arg),*) }
+        }
+    };
+
+    {
+        @wrapper unsafe [$(#[$attr:meta])*] [$($feature:ident),*] $name:ident
+        [$($generic:tt)*] [$($const:ident),*] ($($arg:ident: $arg_ty:ty),*) [$($ret:ty)?]
+    } => {
+        $(#[$attr])*
+        #[doc = concat!("Calls [`", stringify!($name), "`](https://doc.rust-lang.org/core/arch/aarch64/fn.", stringify!($name), ".html).")]
+        ///
+        /// # Safety
+        /// The safety requirements of the intrinsic must be upheld.
+        #[inline(always)]
+        pub unsafe fn $name<$($generic)*>(
+            _: impl crate::Features<$($feature = crate::logic::True),*>,
+            $($arg: $arg_ty),*
+        ) $(-> $ret)? {
+            arch::$name::<$($const),*>($($arg),*)
+        }
+    };
+}
+
+#[rustversion::since(1.63)]
+mod since_1_63 {
+    use super::arch;
+    use super::arch::*;
+
+    intrinsics! {
+        [neon] safe fn vaba_s16(a: int16x4_t, b: int16x4_t, c: int16x4_t) -> int16x4_t;
+        [neon] safe fn vaba_s32(a: int32x2_t, b: int32x2_t, c: int32x2_t) -> int32x2_t;
+        [neon] safe fn vaba_s8(a: int8x8_t, b: int8x8_t, c: int8x8_t) -> int8x8_t;
+        [neon] safe fn vaba_u16(a: uint16x4_t, b: uint16x4_t, c: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vaba_u32(a: uint32x2_t, b: uint32x2_t, c: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vaba_u8(a: uint8x8_t, b: uint8x8_t, c: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vabal_high_s16(a: int32x4_t, b: int16x8_t, c: int16x8_t) -> int32x4_t;
+        [neon] safe fn vabal_high_s32(a: int64x2_t, b: int32x4_t, c: int32x4_t) -> int64x2_t;
+        [neon] safe fn vabal_high_s8(a: int16x8_t, b: int8x16_t, c: int8x16_t) -> int16x8_t;
+        [neon] safe fn vabal_high_u16(a: uint32x4_t, b: uint16x8_t, c: uint16x8_t) -> uint32x4_t;
+        [neon] safe fn vabal_high_u32(a: uint64x2_t, b: uint32x4_t, c: uint32x4_t) -> uint64x2_t;
+        [neon] safe fn vabal_high_u8(a: uint16x8_t, b: uint8x16_t, c: uint8x16_t) -> uint16x8_t;
+        [neon] safe fn vabal_s16(a: int32x4_t, b: int16x4_t, c: int16x4_t) -> int32x4_t;
+        [neon] safe fn vabal_s32(a: int64x2_t, b: int32x2_t, c: int32x2_t) -> int64x2_t;
+        [neon] safe fn vabal_s8(a: int16x8_t, b: int8x8_t, c: int8x8_t) -> int16x8_t;
+        [neon] safe fn vabal_u16(a: uint32x4_t, b: uint16x4_t, c: uint16x4_t) -> uint32x4_t;
+        [neon] safe fn vabal_u32(a: uint64x2_t, b: uint32x2_t, c: uint32x2_t) -> uint64x2_t;
+        [neon] safe fn vabal_u8(a: uint16x8_t, b: uint8x8_t, c: uint8x8_t) -> uint16x8_t;
+        [neon] safe fn vabaq_s16(a: int16x8_t, b: int16x8_t, c: int16x8_t) -> int16x8_t;
+        [neon] safe fn vabaq_s32(a: int32x4_t, b: int32x4_t, c: int32x4_t) -> int32x4_t;
+        [neon] safe fn vabaq_s8(a: int8x16_t, b: int8x16_t, c: int8x16_t) -> int8x16_t;
+        [neon] safe fn vabaq_u16(a: uint16x8_t, b: uint16x8_t, c: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vabaq_u32(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vabaq_u8(a: uint8x16_t, b: uint8x16_t, c: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vabd_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vabd_f64(a: float64x1_t, b: float64x1_t) -> float64x1_t;
+        [neon] safe fn vabd_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vabd_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vabd_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vabd_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vabd_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vabd_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vabdd_f64(a: f64, b: f64) -> f64;
+        [neon] safe fn vabdl_high_s16(a: int16x8_t, b: int16x8_t) -> int32x4_t;
+        [neon] safe fn vabdl_high_s32(a: int32x4_t, b: int32x4_t) -> int64x2_t;
+        [neon] safe fn vabdl_high_s8(a: int8x16_t, b: int8x16_t) -> int16x8_t;
+        [neon] safe fn vabdl_high_u16(a: uint16x8_t, b: uint16x8_t) -> uint32x4_t;
+        [neon] safe fn vabdl_high_u32(a: uint32x4_t, b: uint32x4_t) -> uint64x2_t;
+        [neon] safe fn vabdl_high_u8(a: uint8x16_t, b: uint8x16_t) -> uint16x8_t;
+        [neon] safe fn vabdl_s16(a: int16x4_t, b: int16x4_t) -> int32x4_t;
+        [neon] safe fn vabdl_s32(a: int32x2_t, b: int32x2_t) -> int64x2_t;
+        [neon] safe fn vabdl_s8(a: int8x8_t, b: int8x8_t) -> int16x8_t;
+        [neon] safe fn vabdl_u16(a: uint16x4_t, b: uint16x4_t) -> uint32x4_t;
+        [neon] safe fn vabdl_u32(a: uint32x2_t, b: uint32x2_t) -> uint64x2_t;
+        [neon] safe fn vabdl_u8(a: uint8x8_t, b: uint8x8_t) -> uint16x8_t;
+        [neon] safe fn vabdq_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vabdq_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vabdq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vabdq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vabdq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vabdq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vabdq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vabdq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vabds_f32(a: f32, b: f32) -> f32;
+        [neon] safe fn vabs_f32(a: float32x2_t) -> float32x2_t;
+        [neon] safe fn vabs_f64(a: float64x1_t) -> float64x1_t;
+        [neon] safe fn vabs_s16(a: int16x4_t) -> int16x4_t;
+        [neon] safe fn vabs_s32(a: int32x2_t) -> int32x2_t;
+        [neon] safe fn vabs_s64(a: int64x1_t) -> int64x1_t;
+        [neon] safe fn vabs_s8(a: int8x8_t) -> int8x8_t;
+        [neon] safe fn vabsd_s64(a: i64) -> i64;
+        [neon] safe fn vabsq_f32(a: float32x4_t) -> float32x4_t;
+        [neon] safe fn vabsq_f64(a: float64x2_t) -> float64x2_t;
+        [neon] safe fn vabsq_s16(a: int16x8_t) -> int16x8_t;
+        [neon] safe fn vabsq_s32(a: int32x4_t) -> int32x4_t;
+        [neon] safe fn vabsq_s64(a: int64x2_t) -> int64x2_t;
+        [neon] safe fn vabsq_s8(a: int8x16_t) -> int8x16_t;
+        [neon] safe fn vadd_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vadd_f64(a: float64x1_t, b: float64x1_t) -> float64x1_t;
+        [neon] safe fn vadd_p16(a: poly16x4_t, b: poly16x4_t) -> poly16x4_t;
+        [neon] safe fn vadd_p64(a: poly64x1_t, b: poly64x1_t) -> poly64x1_t;
+        [neon] safe fn vadd_p8(a: poly8x8_t, b: poly8x8_t) -> poly8x8_t;
+        [neon] safe fn vadd_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vadd_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vadd_s64(a: int64x1_t, b: int64x1_t) -> int64x1_t;
+        [neon] safe fn vadd_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vadd_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vadd_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vadd_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vadd_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vaddd_s64(a: i64, b: i64) -> i64;
+        [neon] safe fn vaddd_u64(a: u64, b: u64) -> u64;
+        [neon] safe fn vaddhn_high_s16(r: int8x8_t, a: int16x8_t, b: int16x8_t) -> int8x16_t;
+        [neon] safe fn vaddhn_high_s32(r: int16x4_t, a: int32x4_t, b: int32x4_t) -> int16x8_t;
+        [neon] safe fn vaddhn_high_s64(r: int32x2_t, a: int64x2_t, b: int64x2_t) -> int32x4_t;
+        [neon] safe fn vaddhn_high_u16(r: uint8x8_t, a: uint16x8_t, b: uint16x8_t) -> uint8x16_t;
+        [neon] safe fn vaddhn_high_u32(r: uint16x4_t, a: uint32x4_t, b: uint32x4_t) -> uint16x8_t;
+        [neon] safe fn vaddhn_high_u64(r: uint32x2_t, a: uint64x2_t, b: uint64x2_t) -> uint32x4_t;
+        [neon] safe fn vaddhn_s16(a: int16x8_t, b: int16x8_t) -> int8x8_t;
+        [neon] safe fn vaddhn_s32(a: int32x4_t, b: int32x4_t) -> int16x4_t;
+        [neon] safe fn vaddhn_s64(a: int64x2_t, b: int64x2_t) -> int32x2_t;
+        [neon] safe fn vaddhn_u16(a: uint16x8_t, b: uint16x8_t) -> uint8x8_t;
+        [neon] safe fn vaddhn_u32(a: uint32x4_t, b: uint32x4_t) -> uint16x4_t;
+        [neon] safe fn vaddhn_u64(a: uint64x2_t, b: uint64x2_t) -> uint32x2_t;
+        [neon] safe fn vaddl_high_s16(a: int16x8_t, b: int16x8_t) -> int32x4_t;
+        [neon] safe fn vaddl_high_s32(a: int32x4_t, b: int32x4_t) -> int64x2_t;
+        [neon] safe fn vaddl_high_s8(a: int8x16_t, b: int8x16_t) -> int16x8_t;
+        [neon] safe fn vaddl_high_u16(a: uint16x8_t, b: uint16x8_t) -> uint32x4_t;
+        [neon] safe fn vaddl_high_u32(a: uint32x4_t, b: uint32x4_t) -> uint64x2_t;
+        [neon] safe fn vaddl_high_u8(a: uint8x16_t, b: uint8x16_t) -> uint16x8_t;
+        [neon] safe fn vaddl_s16(a: int16x4_t, b: int16x4_t) -> int32x4_t;
+        [neon] safe fn vaddl_s32(a: int32x2_t, b: int32x2_t) -> int64x2_t;
+        [neon] safe fn vaddl_s8(a: int8x8_t, b: int8x8_t) -> int16x8_t;
+        [neon] safe fn vaddl_u16(a: uint16x4_t, b: uint16x4_t) -> uint32x4_t;
+        [neon] safe fn vaddl_u32(a: uint32x2_t, b: uint32x2_t) -> uint64x2_t;
+        [neon] safe fn vaddl_u8(a: uint8x8_t, b: uint8x8_t) -> uint16x8_t;
+        [neon] safe fn vaddlv_s16(a: int16x4_t) -> i32;
+        [neon] safe fn vaddlv_s32(a: int32x2_t) -> i64;
+        [neon] safe fn vaddlv_s8(a: int8x8_t) -> i16;
+        [neon] safe fn vaddlv_u16(a: uint16x4_t) -> u32;
+        [neon] safe fn vaddlv_u32(a: uint32x2_t) -> u64;
+        [neon] safe fn vaddlv_u8(a: uint8x8_t) -> u16;
+        [neon] safe fn vaddlvq_s16(a: int16x8_t) -> i32;
+        [neon] safe fn vaddlvq_s32(a: int32x4_t) -> i64;
+        [neon] safe fn vaddlvq_s8(a: int8x16_t) -> i16;
+        [neon] safe fn vaddlvq_u16(a: uint16x8_t) -> u32;
+        [neon] safe fn vaddlvq_u32(a: uint32x4_t) -> u64;
+        [neon] safe fn vaddlvq_u8(a: uint8x16_t) -> u16;
+        [neon] safe fn vaddq_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vaddq_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vaddq_p128(a: u128, b: u128) -> u128;
+        [neon] safe fn vaddq_p16(a: poly16x8_t, b: poly16x8_t) -> poly16x8_t;
+        [neon] safe fn vaddq_p64(a: poly64x2_t, b: poly64x2_t) -> poly64x2_t;
+        [neon] safe fn vaddq_p8(a: poly8x16_t, b: poly8x16_t) -> poly8x16_t;
+        [neon] safe fn vaddq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vaddq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vaddq_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vaddq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vaddq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vaddq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vaddq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vaddq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vaddv_f32(a: float32x2_t) -> f32;
+        [neon] safe fn vaddv_s16(a: int16x4_t) -> i16;
+        [neon] safe fn vaddv_s32(a: int32x2_t) -> i32;
+        [neon] safe fn vaddv_s8(a: int8x8_t) -> i8;
+        [neon] safe fn vaddv_u16(a: uint16x4_t) -> u16;
+        [neon] safe fn vaddv_u32(a: uint32x2_t) -> u32;
+        [neon] safe fn vaddv_u8(a: uint8x8_t) -> u8;
+        [neon] safe fn vaddvq_f32(a: float32x4_t) -> f32;
+        [neon] safe fn vaddvq_f64(a: float64x2_t) -> f64;
+        [neon] safe fn vaddvq_s16(a: int16x8_t) -> i16;
+        [neon] safe fn vaddvq_s32(a: int32x4_t) -> i32;
+        [neon] safe fn vaddvq_s64(a: int64x2_t) -> i64;
+        [neon] safe fn vaddvq_s8(a: int8x16_t) -> i8;
+        [neon] safe fn vaddvq_u16(a: uint16x8_t) -> u16;
+        [neon] safe fn vaddvq_u32(a: uint32x4_t) -> u32;
+        [neon] safe fn vaddvq_u64(a: uint64x2_t) -> u64;
+        [neon] safe fn vaddvq_u8(a: uint8x16_t) -> u8;
+        [neon] safe fn vaddw_high_s16(a: int32x4_t, b: int16x8_t) -> int32x4_t;
+        [neon] safe fn vaddw_high_s32(a: int64x2_t, b: int32x4_t) -> int64x2_t;
+        [neon] safe fn vaddw_high_s8(a: int16x8_t, b: int8x16_t) -> int16x8_t;
+        [neon] safe fn vaddw_high_u16(a: uint32x4_t, b: uint16x8_t) -> uint32x4_t;
+        [neon] safe fn vaddw_high_u32(a: uint64x2_t, b: uint32x4_t) -> uint64x2_t;
+        [neon] safe fn vaddw_high_u8(a: uint16x8_t, b: uint8x16_t) -> uint16x8_t;
+        [neon] safe fn vaddw_s16(a: int32x4_t, b: int16x4_t) -> int32x4_t;
+        [neon] safe fn vaddw_s32(a: int64x2_t, b: int32x2_t) -> int64x2_t;
+        [neon] safe fn vaddw_s8(a: int16x8_t, b: int8x8_t) -> int16x8_t;
+        [neon] safe fn vaddw_u16(a: uint32x4_t, b: uint16x4_t) -> uint32x4_t;
+        [neon] safe fn vaddw_u32(a: uint64x2_t, b: uint32x2_t) -> uint64x2_t;
+        [neon] safe fn vaddw_u8(a: uint16x8_t, b: uint8x8_t) -> uint16x8_t;
+        [neon] safe fn vand_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vand_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vand_s64(a: int64x1_t, b: int64x1_t) -> int64x1_t;
+        [neon] safe fn vand_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vand_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vand_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vand_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vand_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vandq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vandq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vandq_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vandq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vandq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vandq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vandq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vandq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vbic_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vbic_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vbic_s64(a: int64x1_t, b: int64x1_t) -> int64x1_t;
+        [neon] safe fn vbic_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vbic_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vbic_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vbic_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vbic_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vbicq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vbicq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vbicq_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vbicq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vbicq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vbicq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vbicq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vbicq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vbsl_f32(a: uint32x2_t, b: float32x2_t, c: float32x2_t) -> float32x2_t;
+        [neon] safe fn vbsl_f64(a: uint64x1_t, b: float64x1_t, c: float64x1_t) -> float64x1_t;
+        [neon] safe fn vbsl_p16(a: uint16x4_t, b: poly16x4_t, c: poly16x4_t) -> poly16x4_t;
+        [neon] safe fn vbsl_p64(a: poly64x1_t, b: poly64x1_t, c: poly64x1_t) -> poly64x1_t;
+        [neon] safe fn vbsl_p8(a: uint8x8_t, b: poly8x8_t, c: poly8x8_t) -> poly8x8_t;
+        [neon] safe fn vbsl_s16(a: uint16x4_t, b: int16x4_t, c: int16x4_t) -> int16x4_t;
+        [neon] safe fn vbsl_s32(a: uint32x2_t, b: int32x2_t, c: int32x2_t) -> int32x2_t;
+        [neon] safe fn vbsl_s64(a: uint64x1_t, b: int64x1_t, c: int64x1_t) -> int64x1_t;
+        [neon] safe fn vbsl_s8(a: uint8x8_t, b: int8x8_t, c: int8x8_t) -> int8x8_t;
+        [neon] safe fn vbsl_u16(a: uint16x4_t, b: uint16x4_t, c: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vbsl_u32(a: uint32x2_t, b: uint32x2_t, c: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vbsl_u64(a: uint64x1_t, b: uint64x1_t, c: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vbsl_u8(a: uint8x8_t, b: uint8x8_t, c: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vbslq_f32(a: uint32x4_t, b: float32x4_t, c: float32x4_t) -> float32x4_t;
+        [neon] safe fn vbslq_f64(a: uint64x2_t, b: float64x2_t, c: float64x2_t) -> float64x2_t;
+        [neon] safe fn vbslq_p16(a: uint16x8_t, b: poly16x8_t, c: poly16x8_t) -> poly16x8_t;
+        [neon] safe fn vbslq_p64(a: poly64x2_t, b: poly64x2_t, c: poly64x2_t) -> poly64x2_t;
+        [neon] safe fn vbslq_p8(a: uint8x16_t, b: poly8x16_t, c: poly8x16_t) -> poly8x16_t;
+        [neon] safe fn vbslq_s16(a: uint16x8_t, b: int16x8_t, c: int16x8_t) -> int16x8_t;
+        [neon] safe fn vbslq_s32(a: uint32x4_t, b: int32x4_t, c: int32x4_t) -> int32x4_t;
+        [neon] safe fn vbslq_s64(a: uint64x2_t, b: int64x2_t, c: int64x2_t) -> int64x2_t;
+        [neon] safe fn vbslq_s8(a: uint8x16_t, b: int8x16_t, c: int8x16_t) -> int8x16_t;
+        [neon] safe fn vbslq_u16(a: uint16x8_t, b: uint16x8_t, c: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vbslq_u32(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vbslq_u64(a: uint64x2_t, b: uint64x2_t, c: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vbslq_u8(a: uint8x16_t, b: uint8x16_t, c: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vcage_f32(a: float32x2_t, b: float32x2_t) -> uint32x2_t;
+        [neon] safe fn vcage_f64(a: float64x1_t, b: float64x1_t) -> uint64x1_t;
+        [neon] safe fn vcaged_f64(a: f64, b: f64) -> u64;
+        [neon] safe fn vcageq_f32(a: float32x4_t, b: float32x4_t) -> uint32x4_t;
+        [neon] safe fn vcageq_f64(a: float64x2_t, b: float64x2_t) -> uint64x2_t;
+        [neon] safe fn vcages_f32(a: f32, b: f32) -> u32;
+        [neon] safe fn vcagt_f32(a: float32x2_t, b: float32x2_t) -> uint32x2_t;
+        [neon] safe fn vcagt_f64(a: float64x1_t, b: float64x1_t) -> uint64x1_t;
+        [neon] safe fn vcagtd_f64(a: f64, b: f64) -> u64;
+        [neon] safe fn vcagtq_f32(a: float32x4_t, b: float32x4_t) -> uint32x4_t;
+        [neon] safe fn vcagtq_f64(a: float64x2_t, b: float64x2_t) -> uint64x2_t;
+        [neon] safe fn vcagts_f32(a: f32, b: f32) -> u32;
+        [neon] safe fn vcale_f32(a: float32x2_t, b: float32x2_t) -> uint32x2_t;
+        [neon] safe fn vcale_f64(a: float64x1_t, b: float64x1_t) -> uint64x1_t;
+        [neon] safe fn vcaled_f64(a: f64, b: f64) -> u64;
+        [neon] safe fn vcaleq_f32(a: float32x4_t, b: float32x4_t) -> uint32x4_t;
+        [neon] safe fn vcaleq_f64(a: float64x2_t, b: float64x2_t) -> uint64x2_t;
+        [neon] safe fn vcales_f32(a: f32, b: f32) -> u32;
+        [neon] safe fn vcalt_f32(a: float32x2_t, b: float32x2_t) -> uint32x2_t;
+        [neon] safe fn vcalt_f64(a: float64x1_t, b: float64x1_t) -> uint64x1_t;
+        [neon] safe fn vcaltd_f64(a: f64, b: f64) -> u64;
+        [neon] safe fn vcaltq_f32(a: float32x4_t, b: float32x4_t) -> uint32x4_t;
+        [neon] safe fn vcaltq_f64(a: float64x2_t, b: float64x2_t) -> uint64x2_t;
+        [neon] safe fn vcalts_f32(a: f32, b: f32) -> u32;
+        [neon] safe fn vceq_f32(a: float32x2_t, b: float32x2_t) -> uint32x2_t;
+        [neon] safe fn vceq_f64(a: float64x1_t, b: float64x1_t) -> uint64x1_t;
+        [neon] safe fn vceq_p64(a: poly64x1_t, b: poly64x1_t) -> uint64x1_t;
+        [neon] safe fn vceq_p8(a: poly8x8_t, b: poly8x8_t) -> uint8x8_t;
+        [neon] safe fn vceq_s16(a: int16x4_t, b: int16x4_t) -> uint16x4_t;
+        [neon] safe fn vceq_s32(a: int32x2_t, b: int32x2_t) -> uint32x2_t;
+        [neon] safe fn vceq_s64(a: int64x1_t, b: int64x1_t) -> uint64x1_t;
+        [neon] safe fn vceq_s8(a: int8x8_t, b: int8x8_t) -> uint8x8_t;
+        [neon] safe fn vceq_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vceq_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vceq_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vceq_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vceqd_f64(a: f64, b: f64) -> u64;
+        [neon] safe fn vceqd_s64(a: i64, b: i64) -> u64;
+        [neon] safe fn vceqd_u64(a: u64, b: u64) -> u64;
+        [neon] safe fn vceqq_f32(a: float32x4_t, b: float32x4_t) -> uint32x4_t;
+        [neon] safe fn vceqq_f64(a: float64x2_t, b: float64x2_t) -> uint64x2_t;
+        [neon] safe fn vceqq_p64(a: poly64x2_t, b: poly64x2_t) -> uint64x2_t;
+        [neon] safe fn vceqq_p8(a: poly8x16_t, b: poly8x16_t) -> uint8x16_t;
+        [neon] safe fn vceqq_s16(a: int16x8_t, b: int16x8_t) -> uint16x8_t;
+        [neon] safe fn vceqq_s32(a: int32x4_t, b: int32x4_t) -> uint32x4_t;
+        [neon] safe fn vceqq_s64(a: int64x2_t, b: int64x2_t) -> uint64x2_t;
+        [neon] safe fn vceqq_s8(a: int8x16_t, b: int8x16_t) -> uint8x16_t;
+        [neon] safe fn vceqq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vceqq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vceqq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vceqq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vceqs_f32(a: f32, b: f32) -> u32;
+        [neon] safe fn vceqz_f32(a: float32x2_t) -> uint32x2_t;
+        [neon] safe fn vceqz_f64(a: float64x1_t) -> uint64x1_t;
+        [neon] safe fn vceqz_p64(a: poly64x1_t) -> uint64x1_t;
+        [neon] safe fn vceqz_p8(a: poly8x8_t) -> uint8x8_t;
+        [neon] safe fn vceqz_s16(a: int16x4_t) -> uint16x4_t;
+        [neon] safe fn vceqz_s32(a: int32x2_t) -> uint32x2_t;
+        [neon] safe fn vceqz_s64(a: int64x1_t) -> uint64x1_t;
+        [neon] safe fn vceqz_s8(a: int8x8_t) -> uint8x8_t;
+        [neon] safe fn vceqz_u16(a: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vceqz_u32(a: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vceqz_u64(a: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vceqz_u8(a: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vceqzd_f64(a: f64) -> u64;
+        [neon] safe fn vceqzd_s64(a: i64) -> u64;
+        [neon] safe fn vceqzd_u64(a: u64) -> u64;
+        [neon] safe fn vceqzq_f32(a: float32x4_t) -> uint32x4_t;
+        [neon] safe fn vceqzq_f64(a: float64x2_t) -> uint64x2_t;
+        [neon] safe fn vceqzq_p64(a: poly64x2_t) -> uint64x2_t;
+        [neon] safe fn vceqzq_p8(a: poly8x16_t) -> uint8x16_t;
+        [neon] safe fn vceqzq_s16(a: int16x8_t) -> uint16x8_t;
+        [neon] safe fn vceqzq_s32(a: int32x4_t) -> uint32x4_t;
+        [neon] safe fn vceqzq_s64(a: int64x2_t) -> uint64x2_t;
+        [neon] safe fn vceqzq_s8(a: int8x16_t) -> uint8x16_t;
+        [neon] safe fn vceqzq_u16(a: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vceqzq_u32(a: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vceqzq_u64(a: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vceqzq_u8(a: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vceqzs_f32(a: f32) -> u32;
+        [neon] safe fn vcge_f32(a: float32x2_t, b: float32x2_t) -> uint32x2_t;
+        [neon] safe fn vcge_f64(a: float64x1_t, b: float64x1_t) -> uint64x1_t;
+        [neon] safe fn vcge_s16(a: int16x4_t, b: int16x4_t) -> uint16x4_t;
+        [neon] safe fn vcge_s32(a: int32x2_t, b: int32x2_t) -> uint32x2_t;
+        [neon] safe fn vcge_s64(a: int64x1_t, b: int64x1_t) -> uint64x1_t;
+        [neon] safe fn vcge_s8(a: int8x8_t, b: int8x8_t) -> uint8x8_t;
+        [neon] safe fn vcge_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vcge_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vcge_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vcge_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vcged_f64(a: f64, b: f64) -> u64;
+        [neon] safe fn vcged_s64(a: i64, b: i64) -> u64;
+        [neon] safe fn vcged_u64(a: u64, b: u64) -> u64;
+        [neon] safe fn vcgeq_f32(a: float32x4_t, b: float32x4_t) -> uint32x4_t;
+        [neon] safe fn vcgeq_f64(a: float64x2_t, b: float64x2_t) -> uint64x2_t;
+        [neon] safe fn vcgeq_s16(a: int16x8_t, b: int16x8_t) -> uint16x8_t;
+        [neon] safe fn vcgeq_s32(a: int32x4_t, b: int32x4_t) -> uint32x4_t;
+        [neon] safe fn vcgeq_s64(a: int64x2_t, b: int64x2_t) -> uint64x2_t;
+        [neon] safe fn vcgeq_s8(a: int8x16_t, b: int8x16_t) -> uint8x16_t;
+        [neon] safe fn vcgeq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vcgeq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vcgeq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vcgeq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vcges_f32(a: f32, b: f32) -> u32;
+        [neon] safe fn vcgez_f32(a: float32x2_t) -> uint32x2_t;
+        [neon] safe fn vcgez_f64(a: float64x1_t) -> uint64x1_t;
+        [neon] safe fn vcgez_s16(a: int16x4_t) -> uint16x4_t;
+        [neon] safe fn vcgez_s32(a: int32x2_t) -> uint32x2_t;
+        [neon] safe fn vcgez_s64(a: int64x1_t) -> uint64x1_t;
+        [neon] safe fn vcgez_s8(a: int8x8_t) -> uint8x8_t;
+        [neon] safe fn vcgezd_f64(a: f64) -> u64;
+        [neon] safe fn vcgezd_s64(a: i64) -> u64;
+        [neon] safe fn vcgezq_f32(a: float32x4_t) -> uint32x4_t;
+        [neon] safe fn vcgezq_f64(a: float64x2_t) -> uint64x2_t;
+        [neon] safe fn vcgezq_s16(a: int16x8_t) -> uint16x8_t;
+        [neon] safe fn vcgezq_s32(a: int32x4_t) -> uint32x4_t;
+        [neon] safe fn vcgezq_s64(a: int64x2_t) -> uint64x2_t;
+        [neon] safe fn vcgezq_s8(a: int8x16_t) -> uint8x16_t;
+        [neon] safe fn vcgezs_f32(a: f32) -> u32;
+        [neon] safe fn vcgt_f32(a: float32x2_t, b: float32x2_t) -> uint32x2_t;
+        [neon] safe fn vcgt_f64(a: float64x1_t, b: float64x1_t) -> uint64x1_t;
+        [neon] safe fn vcgt_s16(a: int16x4_t, b: int16x4_t) -> uint16x4_t;
+        [neon] safe fn vcgt_s32(a: int32x2_t, b: int32x2_t) -> uint32x2_t;
+        [neon] safe fn vcgt_s64(a: int64x1_t, b: int64x1_t) -> uint64x1_t;
+        [neon] safe fn vcgt_s8(a: int8x8_t, b: int8x8_t) -> uint8x8_t;
+        [neon] safe fn vcgt_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vcgt_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vcgt_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vcgt_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vcgtd_f64(a: f64, b: f64) -> u64;
+        [neon] safe fn vcgtd_s64(a: i64, b: i64) -> u64;
+        [neon] safe fn vcgtd_u64(a: u64, b: u64) -> u64;
+        [neon] safe fn vcgtq_f32(a: float32x4_t, b: float32x4_t) -> uint32x4_t;
+        [neon] safe fn vcgtq_f64(a: float64x2_t, b: float64x2_t) -> uint64x2_t;
+        [neon] safe fn vcgtq_s16(a: int16x8_t, b: int16x8_t) -> uint16x8_t;
+        [neon] safe fn vcgtq_s32(a: int32x4_t, b: int32x4_t) -> uint32x4_t;
+        [neon] safe fn vcgtq_s64(a: int64x2_t, b: int64x2_t) -> uint64x2_t;
+        [neon] safe fn vcgtq_s8(a: int8x16_t, b: int8x16_t) -> uint8x16_t;
+        [neon] safe fn vcgtq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vcgtq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vcgtq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vcgtq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vcgts_f32(a: f32, b: f32) -> u32;
+        [neon] safe fn vcgtz_f32(a: float32x2_t) -> uint32x2_t;
+        [neon] safe fn vcgtz_f64(a: float64x1_t) -> uint64x1_t;
+        [neon] safe fn vcgtz_s16(a: int16x4_t) -> uint16x4_t;
+        [neon] safe fn vcgtz_s32(a: int32x2_t) -> uint32x2_t;
+        [neon] safe fn vcgtz_s64(a: int64x1_t) -> uint64x1_t;
+        [neon] safe fn vcgtz_s8(a: int8x8_t) -> uint8x8_t;
+        [neon] safe fn vcgtzd_f64(a: f64) -> u64;
+        [neon] safe fn vcgtzd_s64(a: i64) -> u64;
+        [neon] safe fn vcgtzq_f32(a: float32x4_t) -> uint32x4_t;
+        [neon] safe fn vcgtzq_f64(a: float64x2_t) -> uint64x2_t;
+        [neon] safe fn vcgtzq_s16(a: int16x8_t) -> uint16x8_t;
+        [neon] safe fn vcgtzq_s32(a: int32x4_t) -> uint32x4_t;
+        [neon] safe fn vcgtzq_s64(a: int64x2_t) -> uint64x2_t;
+        [neon] safe fn vcgtzq_s8(a: int8x16_t) -> uint8x16_t;
+        [neon] safe fn vcgtzs_f32(a: f32) -> u32;
+        [neon] safe fn vcle_f32(a: float32x2_t, b: float32x2_t) -> uint32x2_t;
+        [neon] safe fn vcle_f64(a: float64x1_t, b: float64x1_t) -> uint64x1_t;
+        [neon] safe fn vcle_s16(a: int16x4_t, b: int16x4_t) -> uint16x4_t;
+        [neon] safe fn vcle_s32(a: int32x2_t, b: int32x2_t) -> uint32x2_t;
+        [neon] safe fn vcle_s64(a: int64x1_t, b: int64x1_t) -> uint64x1_t;
+        [neon] safe fn vcle_s8(a: int8x8_t, b: int8x8_t) -> uint8x8_t;
+        [neon] safe fn vcle_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vcle_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vcle_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vcle_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vcled_f64(a: f64, b: f64) -> u64;
+        [neon] safe fn vcled_s64(a: i64, b: i64) -> u64;
+        [neon] safe fn vcled_u64(a: u64, b: u64) -> u64;
+        [neon] safe fn vcleq_f32(a: float32x4_t, b: float32x4_t) -> uint32x4_t;
+        [neon] safe fn vcleq_f64(a: float64x2_t, b: float64x2_t) -> uint64x2_t;
+        [neon] safe fn vcleq_s16(a: int16x8_t, b: int16x8_t) -> uint16x8_t;
+        [neon] safe fn vcleq_s32(a: int32x4_t, b: int32x4_t) -> uint32x4_t;
+        [neon] safe fn vcleq_s64(a: int64x2_t, b: int64x2_t) -> uint64x2_t;
+        [neon] safe fn vcleq_s8(a: int8x16_t, b: int8x16_t) -> uint8x16_t;
+        [neon] safe fn vcleq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vcleq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vcleq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vcleq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vcles_f32(a: f32, b: f32) -> u32;
+        [neon] safe fn vclez_f32(a: float32x2_t) -> uint32x2_t;
+        [neon] safe fn vclez_f64(a: float64x1_t) -> uint64x1_t;
+        [neon] safe fn vclez_s16(a: int16x4_t) -> uint16x4_t;
+        [neon] safe fn vclez_s32(a: int32x2_t) -> uint32x2_t;
+        [neon] safe fn vclez_s64(a: int64x1_t) -> uint64x1_t;
+        [neon] safe fn vclez_s8(a: int8x8_t) -> uint8x8_t;
+        [neon] safe fn vclezd_f64(a: f64) -> u64;
+        [neon] safe fn vclezd_s64(a: i64) -> u64;
+        [neon] safe fn vclezq_f32(a: float32x4_t) -> uint32x4_t;
+        [neon] safe fn vclezq_f64(a: float64x2_t) -> uint64x2_t;
+        [neon] safe fn vclezq_s16(a: int16x8_t) -> uint16x8_t;
+        [neon] safe fn vclezq_s32(a: int32x4_t) -> uint32x4_t;
+        [neon] safe fn vclezq_s64(a: int64x2_t) -> uint64x2_t;
+        [neon] safe fn vclezq_s8(a: int8x16_t) -> uint8x16_t;
+        [neon] safe fn vclezs_f32(a: f32) -> u32;
+        [neon] safe fn vcls_s16(a: int16x4_t) -> int16x4_t;
+        [neon] safe fn vcls_s32(a: int32x2_t) -> int32x2_t;
+        [neon] safe fn vcls_s8(a: int8x8_t) -> int8x8_t;
+        [neon] safe fn vcls_u16(a: uint16x4_t) -> int16x4_t;
+        [neon] safe fn vcls_u32(a: uint32x2_t) -> int32x2_t;
+        [neon] safe fn vcls_u8(a: uint8x8_t) -> int8x8_t;
+        [neon] safe fn vclsq_s16(a: int16x8_t) -> int16x8_t;
+        [neon] safe fn vclsq_s32(a: int32x4_t) -> int32x4_t;
+        [neon] safe fn vclsq_s8(a: int8x16_t) -> int8x16_t;
+        [neon] safe fn vclsq_u16(a: uint16x8_t) -> int16x8_t;
+        [neon] safe fn vclsq_u32(a: uint32x4_t) -> int32x4_t;
+        [neon] safe fn vclsq_u8(a: uint8x16_t) -> int8x16_t;
+        [neon] safe fn vclt_f32(a: float32x2_t, b: float32x2_t) -> uint32x2_t;
+        [neon] safe fn vclt_f64(a: float64x1_t, b: float64x1_t) -> uint64x1_t;
+        [neon] safe fn vclt_s16(a: int16x4_t, b: int16x4_t) -> uint16x4_t;
+        [neon] safe fn vclt_s32(a: int32x2_t, b: int32x2_t) -> uint32x2_t;
+        [neon] safe fn vclt_s64(a: int64x1_t, b: int64x1_t) -> uint64x1_t;
+        [neon] safe fn vclt_s8(a: int8x8_t, b: int8x8_t) -> uint8x8_t;
+        [neon] safe fn vclt_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vclt_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vclt_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vclt_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vcltd_f64(a: f64, b: f64) -> u64;
+        [neon] safe fn vcltd_s64(a: i64, b: i64) -> u64;
+        [neon] safe fn vcltd_u64(a: u64, b: u64) -> u64;
+        [neon] safe fn vcltq_f32(a: float32x4_t, b: float32x4_t) -> uint32x4_t;
+        [neon] safe fn vcltq_f64(a: float64x2_t, b: float64x2_t) -> uint64x2_t;
+        [neon] safe fn vcltq_s16(a: int16x8_t, b: int16x8_t) -> uint16x8_t;
+        [neon] safe fn vcltq_s32(a: int32x4_t, b: int32x4_t) -> uint32x4_t;
+        [neon] safe fn vcltq_s64(a: int64x2_t, b: int64x2_t) -> uint64x2_t;
+        [neon] safe fn vcltq_s8(a: int8x16_t, b: int8x16_t) -> uint8x16_t;
+        [neon] safe fn vcltq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vcltq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vcltq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vcltq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vclts_f32(a: f32, b: f32) -> u32;
+        [neon] safe fn vcltz_f32(a: float32x2_t) -> uint32x2_t;
+        [neon] safe fn vcltz_f64(a: float64x1_t) -> uint64x1_t;
+        [neon] safe fn vcltz_s16(a: int16x4_t) -> uint16x4_t;
+        [neon] safe fn vcltz_s32(a: int32x2_t) -> uint32x2_t;
+        [neon] safe fn vcltz_s64(a: int64x1_t) -> uint64x1_t;
+        [neon] safe fn vcltz_s8(a: int8x8_t) -> uint8x8_t;
+        [neon] safe fn vcltzd_f64(a: f64) -> u64;
+        [neon] safe fn vcltzd_s64(a: i64) -> u64;
+        [neon] safe fn vcltzq_f32(a: float32x4_t) -> uint32x4_t;
+        [neon] safe fn vcltzq_f64(a: float64x2_t) -> uint64x2_t;
+        [neon] safe fn vcltzq_s16(a: int16x8_t) -> uint16x8_t;
+        [neon] safe fn vcltzq_s32(a: int32x4_t) -> uint32x4_t;
+        [neon] safe fn vcltzq_s64(a: int64x2_t) -> uint64x2_t;
+        [neon] safe fn vcltzq_s8(a: int8x16_t) -> uint8x16_t;
+        [neon] safe fn vcltzs_f32(a: f32) -> u32;
+        [neon] safe fn vclz_s16(a: int16x4_t) -> int16x4_t;
+        [neon] safe fn vclz_s32(a: int32x2_t) -> int32x2_t;
+        [neon] safe fn vclz_s8(a: int8x8_t) -> int8x8_t;
+        [neon] safe fn vclz_u16(a: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vclz_u32(a: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vclz_u8(a: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vclzq_s16(a: int16x8_t) -> int16x8_t;
+        [neon] safe fn vclzq_s32(a: int32x4_t) -> int32x4_t;
+        [neon] safe fn vclzq_s8(a: int8x16_t) -> int8x16_t;
+        [neon] safe fn vclzq_u16(a: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vclzq_u32(a: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vclzq_u8(a: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vcnt_p8(a: poly8x8_t) -> poly8x8_t;
+        [neon] safe fn vcnt_s8(a: int8x8_t) -> int8x8_t;
+        [neon] safe fn vcnt_u8(a: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vcntq_p8(a: poly8x16_t) -> poly8x16_t;
+        [neon] safe fn vcntq_s8(a: int8x16_t) -> int8x16_t;
+        [neon] safe fn vcntq_u8(a: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vcombine_f32(a: float32x2_t, b: float32x2_t) -> float32x4_t;
+        [neon] safe fn vcombine_f64(low: float64x1_t, high: float64x1_t) -> float64x2_t;
+        [neon] safe fn vcombine_p16(a: poly16x4_t, b: poly16x4_t) -> poly16x8_t;
+        [neon] safe fn vcombine_p64(a: poly64x1_t, b: poly64x1_t) -> poly64x2_t;
+        [neon] safe fn vcombine_p8(a: poly8x8_t, b: poly8x8_t) -> poly8x16_t;
+        [neon] safe fn vcombine_s16(a: int16x4_t, b: int16x4_t) -> int16x8_t;
+        [neon] safe fn vcombine_s32(a: int32x2_t, b: int32x2_t) -> int32x4_t;
+        [neon] safe fn vcombine_s64(a: int64x1_t, b: int64x1_t) -> int64x2_t;
+        [neon] safe fn vcombine_s8(a: int8x8_t, b: int8x8_t) -> int8x16_t;
+        [neon] safe fn vcombine_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x8_t;
+        [neon] safe fn vcombine_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x4_t;
+        [neon] safe fn vcombine_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x2_t;
+        [neon] safe fn vcombine_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x16_t;
+        [neon] safe fn vcopy_lane_f32<const LANE1: i32, const LANE2: i32>(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vcopy_lane_f64<const N1: i32, const N2: i32>(_a: float64x1_t, b: float64x1_t) -> float64x1_t;
+        [neon] safe fn vcopy_lane_p16<const LANE1: i32, const LANE2: i32>(a: poly16x4_t, b: poly16x4_t) -> poly16x4_t;
+        [neon] safe fn vcopy_lane_p64<const N1: i32, const N2: i32>(_a: poly64x1_t, b: poly64x1_t) -> poly64x1_t;
+        [neon] safe fn vcopy_lane_p8<const LANE1: i32, const LANE2: i32>(a: poly8x8_t, b: poly8x8_t) -> poly8x8_t;
+        [neon] safe fn vcopy_lane_s16<const LANE1: i32, const LANE2: i32>(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vcopy_lane_s32<const LANE1: i32, const LANE2: i32>(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vcopy_lane_s64<const N1: i32, const N2: i32>(_a: int64x1_t, b: int64x1_t) -> int64x1_t;
+        [neon] safe fn vcopy_lane_s8<const LANE1: i32, const LANE2: i32>(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vcopy_lane_u16<const LANE1: i32, const LANE2: i32>(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vcopy_lane_u32<const LANE1: i32, const LANE2: i32>(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vcopy_lane_u64<const N1: i32, const N2: i32>(_a: uint64x1_t, b: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vcopy_lane_u8<const LANE1: i32, const LANE2: i32>(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vcopy_laneq_f32<const LANE1: i32, const LANE2: i32>(a: float32x2_t, b: float32x4_t) -> float32x2_t;
+        [neon] safe fn vcopy_laneq_f64<const LANE1: i32, const LANE2: i32>(_a: float64x1_t, b: float64x2_t) -> float64x1_t;
+        [neon] safe fn vcopy_laneq_p16<const LANE1: i32, const LANE2: i32>(a: poly16x4_t, b: poly16x8_t) -> poly16x4_t;
+        [neon] safe fn vcopy_laneq_p64<const LANE1: i32, const LANE2: i32>(_a: poly64x1_t, b: poly64x2_t) -> poly64x1_t;
+        [neon] safe fn vcopy_laneq_p8<const LANE1: i32, const LANE2: i32>(a: poly8x8_t, b: poly8x16_t) -> poly8x8_t;
+        [neon] safe fn vcopy_laneq_s16<const LANE1: i32, const LANE2: i32>(a: int16x4_t, b: int16x8_t) -> int16x4_t;
+        [neon] safe fn vcopy_laneq_s32<const LANE1: i32, const LANE2: i32>(a: int32x2_t, b: int32x4_t) -> int32x2_t;
+        [neon] safe fn vcopy_laneq_s64<const LANE1: i32, const LANE2: i32>(_a: int64x1_t, b: int64x2_t) -> int64x1_t;
+        [neon] safe fn vcopy_laneq_s8<const LANE1: i32, const LANE2: i32>(a: int8x8_t, b: int8x16_t) -> int8x8_t;
+        [neon] safe fn vcopy_laneq_u16<const LANE1: i32, const LANE2: i32>(a: uint16x4_t, b: uint16x8_t) -> uint16x4_t;
+        [neon] safe fn vcopy_laneq_u32<const LANE1: i32, const LANE2: i32>(a: uint32x2_t, b: uint32x4_t) -> uint32x2_t;
+        [neon] safe fn vcopy_laneq_u64<const LANE1: i32, const LANE2: i32>(_a: uint64x1_t, b: uint64x2_t) -> uint64x1_t;
+        [neon] safe fn vcopy_laneq_u8<const LANE1: i32, const LANE2: i32>(a: uint8x8_t, b: uint8x16_t) -> uint8x8_t;
+        [neon] safe fn vcopyq_lane_f32<const LANE1: i32, const LANE2: i32>(a: float32x4_t, b: float32x2_t) -> float32x4_t;
+        [neon] safe fn vcopyq_lane_f64<const LANE1: i32, const LANE2: i32>(a: float64x2_t, b: float64x1_t) -> float64x2_t;
+        [neon] safe fn vcopyq_lane_p16<const LANE1: i32, const LANE2: i32>(a: poly16x8_t, b: poly16x4_t) -> poly16x8_t;
+        [neon] safe fn vcopyq_lane_p64<const LANE1: i32, const LANE2: i32>(a: poly64x2_t, b: poly64x1_t) -> poly64x2_t;
+        [neon] safe fn vcopyq_lane_p8<const LANE1: i32, const LANE2: i32>(a: poly8x16_t, b: poly8x8_t) -> poly8x16_t;
+        [neon] safe fn vcopyq_lane_s16<const LANE1: i32, const LANE2: i32>(a: int16x8_t, b: int16x4_t) -> int16x8_t;
+        [neon] safe fn vcopyq_lane_s32<const LANE1: i32, const LANE2: i32>(a: int32x4_t, b: int32x2_t) -> int32x4_t;
+        [neon] safe fn vcopyq_lane_s64<const LANE1: i32, const LANE2: i32>(a: int64x2_t, b: int64x1_t) -> int64x2_t;
+        [neon] safe fn vcopyq_lane_s8<const LANE1: i32, const LANE2: i32>(a: int8x16_t, b: int8x8_t) -> int8x16_t;
+        [neon] safe fn vcopyq_lane_u16<const LANE1: i32, const LANE2: i32>(a: uint16x8_t, b: uint16x4_t) -> uint16x8_t;
+        [neon] safe fn vcopyq_lane_u32<const LANE1: i32, const LANE2: i32>(a: uint32x4_t, b: uint32x2_t) -> uint32x4_t;
+        [neon] safe fn vcopyq_lane_u64<const LANE1: i32, const LANE2: i32>(a: uint64x2_t, b: uint64x1_t) -> uint64x2_t;
+        [neon] safe fn vcopyq_lane_u8<const LANE1: i32, const LANE2: i32>(a: uint8x16_t, b: uint8x8_t) -> uint8x16_t;
+        [neon] safe fn vcopyq_laneq_f32<const LANE1: i32, const LANE2: i32>(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vcopyq_laneq_f64<const LANE1: i32, const LANE2: i32>(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vcopyq_laneq_p16<const LANE1: i32, const LANE2: i32>(a: poly16x8_t, b: poly16x8_t) -> poly16x8_t;
+        [neon] safe fn vcopyq_laneq_p64<const LANE1: i32, const LANE2: i32>(a: poly64x2_t, b: poly64x2_t) -> poly64x2_t;
+        [neon] safe fn vcopyq_laneq_p8<const LANE1: i32, const LANE2: i32>(a: poly8x16_t, b: poly8x16_t) -> poly8x16_t;
+        [neon] safe fn vcopyq_laneq_s16<const LANE1: i32, const LANE2: i32>(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vcopyq_laneq_s32<const LANE1: i32, const LANE2: i32>(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vcopyq_laneq_s64<const LANE1: i32, const LANE2: i32>(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vcopyq_laneq_s8<const LANE1: i32, const LANE2: i32>(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vcopyq_laneq_u16<const LANE1: i32, const LANE2: i32>(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vcopyq_laneq_u32<const LANE1: i32, const LANE2: i32>(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vcopyq_laneq_u64<const LANE1: i32, const LANE2: i32>(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vcopyq_laneq_u8<const LANE1: i32, const LANE2: i32>(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vcreate_f32(a: u64) -> float32x2_t;
+        [neon] safe fn vcreate_f64(a: u64) -> float64x1_t;
+        [neon] safe fn vcreate_p16(a: u64) -> poly16x4_t;
+        [neon, aes] safe fn vcreate_p64(a: u64) -> poly64x1_t;
+        [neon] safe fn vcreate_p8(a: u64) -> poly8x8_t;
+        [neon] safe fn vcreate_s16(a: u64) -> int16x4_t;
+        [neon] safe fn vcreate_s32(a: u64) -> int32x2_t;
+        [neon] safe fn vcreate_s64(a: u64) -> int64x1_t;
+        [neon] safe fn vcreate_s8(a: u64) -> int8x8_t;
+        [neon] safe fn vcreate_u16(a: u64) -> uint16x4_t;
+        [neon] safe fn vcreate_u32(a: u64) -> uint32x2_t;
+        [neon] safe fn vcreate_u64(a: u64) -> uint64x1_t;
+        [neon] safe fn vcreate_u8(a: u64) -> uint8x8_t;
+        [neon] safe fn vcvt_f32_f64(a: float64x2_t) -> float32x2_t;
+        [neon] safe fn vcvt_f32_s32(a: int32x2_t) -> float32x2_t;
+        [neon] safe fn vcvt_f32_u32(a: uint32x2_t) -> float32x2_t;
+        [neon] safe fn vcvt_f64_f32(a: float32x2_t) -> float64x2_t;
+        [neon] safe fn vcvt_f64_s64(a: int64x1_t) -> float64x1_t;
+        [neon] safe fn vcvt_f64_u64(a: uint64x1_t) -> float64x1_t;
+        [neon] safe fn vcvt_high_f32_f64(a: float32x2_t, b: float64x2_t) -> float32x4_t;
+        [neon] safe fn vcvt_high_f64_f32(a: float32x4_t) -> float64x2_t;
+        [neon] safe fn vcvt_n_f32_s32<const N: i32>(a: int32x2_t) -> float32x2_t;
+        [neon] safe fn vcvt_n_f32_u32<const N: i32>(a: uint32x2_t) -> float32x2_t;
+        [neon] safe fn vcvt_n_f64_s64<const N: i32>(a: int64x1_t) -> float64x1_t;
+        [neon] safe fn vcvt_n_f64_u64<const N: i32>(a: uint64x1_t) -> float64x1_t;
+        [neon] safe fn vcvt_n_s32_f32<const N: i32>(a: float32x2_t) -> int32x2_t;
+        [neon] safe fn vcvt_n_s64_f64<const N: i32>(a: float64x1_t) -> int64x1_t;
+        [neon] safe fn vcvt_n_u32_f32<const N: i32>(a: float32x2_t) -> uint32x2_t;
+        [neon] safe fn vcvt_n_u64_f64<const N: i32>(a: float64x1_t) -> uint64x1_t;
+        [neon] safe fn vcvt_s32_f32(a: float32x2_t) -> int32x2_t;
+        [neon] safe fn vcvt_s64_f64(a: float64x1_t) -> int64x1_t;
+        [neon] safe fn vcvt_u32_f32(a: float32x2_t) -> uint32x2_t;
+        [neon] safe fn vcvt_u64_f64(a: float64x1_t) -> uint64x1_t;
+        [neon] safe fn vcvta_s32_f32(a: float32x2_t) -> int32x2_t;
+        [neon] safe fn vcvta_s64_f64(a: float64x1_t) -> int64x1_t;
+        [neon] safe fn vcvta_u32_f32(a: float32x2_t) -> uint32x2_t;
+        [neon] safe fn vcvta_u64_f64(a: float64x1_t) -> uint64x1_t;
+        [neon] safe fn vcvtad_s64_f64(a: f64) -> i64;
+        [neon] safe fn vcvtad_u64_f64(a: f64) -> u64;
+        [neon] safe fn vcvtaq_s32_f32(a: float32x4_t) -> int32x4_t;
+        [neon] safe fn vcvtaq_s64_f64(a: float64x2_t) -> int64x2_t;
+        [neon] safe fn vcvtaq_u32_f32(a: float32x4_t) -> uint32x4_t;
+        [neon] safe fn vcvtaq_u64_f64(a: float64x2_t) -> uint64x2_t;
+        [neon] safe fn vcvtas_s32_f32(a: f32) -> i32;
+        [neon] safe fn vcvtas_u32_f32(a: f32) -> u32;
+        [neon] safe fn vcvtd_f64_s64(a: i64) -> f64;
+        [neon] safe fn vcvtd_f64_u64(a: u64) -> f64;
+        [neon] safe fn vcvtd_n_f64_s64<const N: i32>(a: i64) -> f64;
+        [neon] safe fn vcvtd_n_f64_u64<const N: i32>(a: u64) -> f64;
+        [neon] safe fn vcvtd_n_s64_f64<const N: i32>(a: f64) -> i64;
+        [neon] safe fn vcvtd_n_u64_f64<const N: i32>(a: f64) -> u64;
+        [neon] safe fn vcvtd_s64_f64(a: f64) -> i64;
+        [neon] safe fn vcvtd_u64_f64(a: f64) -> u64;
+        [neon] safe fn vcvtm_s32_f32(a: float32x2_t) -> int32x2_t;
+        [neon] safe fn vcvtm_s64_f64(a: float64x1_t) -> int64x1_t;
+        [neon] safe fn vcvtm_u32_f32(a: float32x2_t) -> uint32x2_t;
+        [neon] safe fn vcvtm_u64_f64(a: float64x1_t) -> uint64x1_t;
+        [neon] safe fn vcvtmd_s64_f64(a: f64) -> i64;
+        [neon] safe fn vcvtmd_u64_f64(a: f64) -> u64;
+        [neon] safe fn vcvtmq_s32_f32(a: float32x4_t) -> int32x4_t;
+        [neon] safe fn vcvtmq_s64_f64(a: float64x2_t) -> int64x2_t;
+        [neon] safe fn vcvtmq_u32_f32(a: float32x4_t) -> uint32x4_t;
+        [neon] safe fn vcvtmq_u64_f64(a: float64x2_t) -> uint64x2_t;
+        [neon] safe fn vcvtms_s32_f32(a: f32) -> i32;
+        [neon] safe fn vcvtms_u32_f32(a: f32) -> u32;
+        [neon] safe fn vcvtn_s32_f32(a: float32x2_t) -> int32x2_t;
+        [neon] safe fn vcvtn_s64_f64(a: float64x1_t) -> int64x1_t;
+        [neon] safe fn vcvtn_u32_f32(a: float32x2_t) -> uint32x2_t;
+        [neon] safe fn vcvtn_u64_f64(a: float64x1_t) -> uint64x1_t;
+        [neon] safe fn vcvtnd_s64_f64(a: f64) -> i64;
+        [neon] safe fn vcvtnd_u64_f64(a: f64) -> u64;
+        [neon] safe fn vcvtnq_s32_f32(a: float32x4_t) -> int32x4_t;
+        [neon] safe fn vcvtnq_s64_f64(a: float64x2_t) -> int64x2_t;
+        [neon] safe fn vcvtnq_u32_f32(a: float32x4_t) -> uint32x4_t;
+        [neon] safe fn vcvtnq_u64_f64(a: float64x2_t) -> uint64x2_t;
+        [neon] safe fn vcvtns_s32_f32(a: f32) -> i32;
+        [neon] safe fn vcvtns_u32_f32(a: f32) -> u32;
+        [neon] safe fn vcvtp_s32_f32(a: float32x2_t) -> int32x2_t;
+        [neon] safe fn vcvtp_s64_f64(a: float64x1_t) -> int64x1_t;
+        [neon] safe fn vcvtp_u32_f32(a: float32x2_t) -> uint32x2_t;
+        [neon] safe fn vcvtp_u64_f64(a: float64x1_t) -> uint64x1_t;
+        [neon] safe fn vcvtpd_s64_f64(a: f64) -> i64;
+        [neon] safe fn vcvtpd_u64_f64(a: f64) -> u64;
+        [neon] safe fn vcvtpq_s32_f32(a: float32x4_t) -> int32x4_t;
+        [neon] safe fn vcvtpq_s64_f64(a: float64x2_t) -> int64x2_t;
+        [neon] safe fn vcvtpq_u32_f32(a: float32x4_t) -> uint32x4_t;
+        [neon] safe fn vcvtpq_u64_f64(a: float64x2_t) -> uint64x2_t;
+        [neon] safe fn vcvtps_s32_f32(a: f32) -> i32;
+        [neon] safe fn vcvtps_u32_f32(a: f32) -> u32;
+        [neon] safe fn vcvtq_f32_s32(a: int32x4_t) -> float32x4_t;
+        [neon] safe fn vcvtq_f32_u32(a: uint32x4_t) -> float32x4_t;
+        [neon] safe fn vcvtq_f64_s64(a: int64x2_t) -> float64x2_t;
+        [neon] safe fn vcvtq_f64_u64(a: uint64x2_t) -> float64x2_t;
+        [neon] safe fn vcvtq_n_f32_s32<const N: i32>(a: int32x4_t) -> float32x4_t;
+        [neon] safe fn vcvtq_n_f32_u32<const N: i32>(a: uint32x4_t) -> float32x4_t;
+        [neon] safe fn vcvtq_n_f64_s64<const N: i32>(a: int64x2_t) -> float64x2_t;
+        [neon] safe fn vcvtq_n_f64_u64<const N: i32>(a: uint64x2_t) -> float64x2_t;
+        [neon] safe fn vcvtq_n_s32_f32<const N: i32>(a: float32x4_t) -> int32x4_t;
+        [neon] safe fn vcvtq_n_s64_f64<const N: i32>(a: float64x2_t) -> int64x2_t;
+        [neon] safe fn vcvtq_n_u32_f32<const N: i32>(a: float32x4_t) -> uint32x4_t;
+        [neon] safe fn vcvtq_n_u64_f64<const N: i32>(a: float64x2_t) -> uint64x2_t;
+        [neon] safe fn vcvtq_s32_f32(a: float32x4_t) -> int32x4_t;
+        [neon] safe fn vcvtq_s64_f64(a: float64x2_t) -> int64x2_t;
+        [neon] safe fn vcvtq_u32_f32(a: float32x4_t) -> uint32x4_t;
+        [neon] safe fn vcvtq_u64_f64(a: float64x2_t) -> uint64x2_t;
+        [neon] safe fn vcvts_f32_s32(a: i32) -> f32;
+        [neon] safe fn vcvts_f32_u32(a: u32) -> f32;
+        [neon] safe fn vcvts_n_f32_s32<const N: i32>(a: i32) -> f32;
+        [neon] safe fn vcvts_n_f32_u32<const N: i32>(a: u32) -> f32;
+        [neon] safe fn vcvts_n_s32_f32<const N: i32>(a: f32) -> i32;
+        [neon] safe fn vcvts_n_u32_f32<const N: i32>(a: f32) -> u32;
+        [neon] safe fn vcvts_s32_f32(a: f32) -> i32;
+        [neon] safe fn vcvts_u32_f32(a: f32) -> u32;
+        [neon] safe fn vcvtx_f32_f64(a: float64x2_t) -> float32x2_t;
+        [neon] safe fn vcvtx_high_f32_f64(a: float32x2_t, b: float64x2_t) -> float32x4_t;
+        [neon] safe fn vcvtxd_f32_f64(a: f64) -> f32;
+        [neon] safe fn vdiv_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vdiv_f64(a: float64x1_t, b: float64x1_t) -> float64x1_t;
+        [neon] safe fn vdivq_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vdivq_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vdup_lane_f32<const N: i32>(a: float32x2_t) -> float32x2_t;
+        [neon] safe fn vdup_lane_f64<const N: i32>(a: float64x1_t) -> float64x1_t;
+        [neon] safe fn vdup_lane_p16<const N: i32>(a: poly16x4_t) -> poly16x4_t;
+        [neon] safe fn vdup_lane_p64<const N: i32>(a: poly64x1_t) -> poly64x1_t;
+        [neon] safe fn vdup_lane_p8<const N: i32>(a: poly8x8_t) -> poly8x8_t;
+        [neon] safe fn vdup_lane_s16<const N: i32>(a: int16x4_t) -> int16x4_t;
+        [neon] safe fn vdup_lane_s32<const N: i32>(a: int32x2_t) -> int32x2_t;
+        [neon] safe fn vdup_lane_s64<const N: i32>(a: int64x1_t) -> int64x1_t;
+        [neon] safe fn vdup_lane_s8<const N: i32>(a: int8x8_t) -> int8x8_t;
+        [neon] safe fn vdup_lane_u16<const N: i32>(a: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vdup_lane_u32<const N: i32>(a: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vdup_lane_u64<const N: i32>(a: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vdup_lane_u8<const N: i32>(a: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vdup_laneq_f32<const N: i32>(a: float32x4_t) -> float32x2_t;
+        [neon] safe fn vdup_laneq_f64<const N: i32>(a: float64x2_t) -> float64x1_t;
+        [neon] safe fn vdup_laneq_p16<const N: i32>(a: poly16x8_t) -> poly16x4_t;
+        [neon] safe fn vdup_laneq_p64<const N: i32>(a: poly64x2_t) -> poly64x1_t;
+        [neon] safe fn vdup_laneq_p8<const N: i32>(a: poly8x16_t) -> poly8x8_t;
+        [neon] safe fn vdup_laneq_s16<const N: i32>(a: int16x8_t) -> int16x4_t;
+        [neon] safe fn vdup_laneq_s32<const N: i32>(a: int32x4_t) -> int32x2_t;
+        [neon] safe fn vdup_laneq_s64<const N: i32>(a: int64x2_t) -> int64x1_t;
+        [neon] safe fn vdup_laneq_s8<const N: i32>(a: int8x16_t) -> int8x8_t;
+        [neon] safe fn vdup_laneq_u16<const N: i32>(a: uint16x8_t) -> uint16x4_t;
+        [neon] safe fn vdup_laneq_u32<const N: i32>(a: uint32x4_t) -> uint32x2_t;
+        [neon] safe fn vdup_laneq_u64<const N: i32>(a: uint64x2_t) -> uint64x1_t;
+        [neon] safe fn vdup_laneq_u8<const N: i32>(a: uint8x16_t) -> uint8x8_t;
+        [neon] safe fn vdup_n_f32(value: f32) -> float32x2_t;
+        [neon] safe fn vdup_n_f64(value: f64) -> float64x1_t;
+        [neon] safe fn vdup_n_p16(value: u16) -> poly16x4_t;
+        [neon] safe fn vdup_n_p64(value: u64) -> poly64x1_t;
+        [neon] safe fn vdup_n_p8(value: u8) -> poly8x8_t;
+        [neon] safe fn vdup_n_s16(value: i16) -> int16x4_t;
+        [neon] safe fn vdup_n_s32(value: i32) -> int32x2_t;
+        [neon] safe fn vdup_n_s64(value: i64) -> int64x1_t;
+        [neon] safe fn vdup_n_s8(value: i8) -> int8x8_t;
+        [neon] safe fn vdup_n_u16(value: u16) -> uint16x4_t;
+        [neon] safe fn vdup_n_u32(value: u32) -> uint32x2_t;
+        [neon] safe fn vdup_n_u64(value: u64) -> uint64x1_t;
+        [neon] safe fn vdup_n_u8(value: u8) -> uint8x8_t;
+        [neon] safe fn vdupb_lane_p8<const N: i32>(a: poly8x8_t) -> u8;
+        [neon] safe fn vdupb_lane_s8<const N: i32>(a: int8x8_t) -> i8;
+        [neon] safe fn vdupb_lane_u8<const N: i32>(a: uint8x8_t) -> u8;
+        [neon] safe fn vdupb_laneq_p8<const N: i32>(a: poly8x16_t) -> u8;
+        [neon] safe fn vdupb_laneq_s8<const N: i32>(a: int8x16_t) -> i8;
+        [neon] safe fn vdupb_laneq_u8<const N: i32>(a: uint8x16_t) -> u8;
+        [neon] safe fn vdupd_lane_f64<const N: i32>(a: float64x1_t) -> f64;
+        [neon] safe fn vdupd_lane_s64<const N: i32>(a: int64x1_t) -> i64;
+        [neon] safe fn vdupd_lane_u64<const N: i32>(a: uint64x1_t) -> u64;
+        [neon] safe fn vdupd_laneq_f64<const N: i32>(a: float64x2_t) -> f64;
+        [neon] safe fn vdupd_laneq_s64<const N: i32>(a: int64x2_t) -> i64;
+        [neon] safe fn vdupd_laneq_u64<const N: i32>(a: uint64x2_t) -> u64;
+        [neon] safe fn vduph_lane_p16<const N: i32>(a: poly16x4_t) -> u16;
+        [neon] safe fn vduph_lane_s16<const N: i32>(a: int16x4_t) -> i16;
+        [neon] safe fn vduph_lane_u16<const N: i32>(a: uint16x4_t) -> u16;
+        [neon] safe fn vduph_laneq_p16<const N: i32>(a: poly16x8_t) -> u16;
+        [neon] safe fn vduph_laneq_s16<const N: i32>(a: int16x8_t) -> i16;
+        [neon] safe fn vduph_laneq_u16<const N: i32>(a: uint16x8_t) -> u16;
+        [neon] safe fn vdupq_lane_f32<const N: i32>(a: float32x2_t) -> float32x4_t;
+        [neon] safe fn vdupq_lane_f64<const N: i32>(a: float64x1_t) -> float64x2_t;
+        [neon] safe fn vdupq_lane_p16<const N: i32>(a: poly16x4_t) -> poly16x8_t;
+        [neon] safe fn vdupq_lane_p64<const N: i32>(a: poly64x1_t) -> poly64x2_t;
+        [neon] safe fn vdupq_lane_p8<const N: i32>(a: poly8x8_t) -> poly8x16_t;
+        [neon] safe fn vdupq_lane_s16<const N: i32>(a: int16x4_t) -> int16x8_t;
+        [neon] safe fn vdupq_lane_s32<const N: i32>(a: int32x2_t) -> int32x4_t;
+        [neon] safe fn vdupq_lane_s64<const N: i32>(a: int64x1_t) -> int64x2_t;
+        [neon] safe fn vdupq_lane_s8<const N: i32>(a: int8x8_t) -> int8x16_t;
+        [neon] safe fn vdupq_lane_u16<const N: i32>(a: uint16x4_t) -> uint16x8_t;
+        [neon] safe fn vdupq_lane_u32<const N: i32>(a: uint32x2_t) -> uint32x4_t;
+        [neon] safe fn vdupq_lane_u64<const N: i32>(a: uint64x1_t) -> uint64x2_t;
+        [neon] safe fn vdupq_lane_u8<const N: i32>(a: uint8x8_t) -> uint8x16_t;
+        [neon] safe fn vdupq_laneq_f32<const N: i32>(a: float32x4_t) -> float32x4_t;
+        [neon] safe fn vdupq_laneq_f64<const N: i32>(a: float64x2_t) -> float64x2_t;
+        [neon] safe fn vdupq_laneq_p16<const N: i32>(a: poly16x8_t) -> poly16x8_t;
+        [neon] safe fn vdupq_laneq_p64<const N: i32>(a: poly64x2_t) -> poly64x2_t;
+        [neon] safe fn vdupq_laneq_p8<const N: i32>(a: poly8x16_t) -> poly8x16_t;
+        [neon] safe fn vdupq_laneq_s16<const N: i32>(a: int16x8_t) -> int16x8_t;
+        [neon] safe fn vdupq_laneq_s32<const N: i32>(a: int32x4_t) -> int32x4_t;
+        [neon] safe fn vdupq_laneq_s64<const N: i32>(a: int64x2_t) -> int64x2_t;
+        [neon] safe fn vdupq_laneq_s8<const N: i32>(a: int8x16_t) -> int8x16_t;
+        [neon] safe fn vdupq_laneq_u16<const N: i32>(a: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vdupq_laneq_u32<const N: i32>(a: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vdupq_laneq_u64<const N: i32>(a: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vdupq_laneq_u8<const N: i32>(a: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vdupq_n_f32(value: f32) -> float32x4_t;
+        [neon] safe fn vdupq_n_f64(value: f64) -> float64x2_t;
+        [neon] safe fn vdupq_n_p16(value: u16) -> poly16x8_t;
+        [neon] safe fn vdupq_n_p64(value: u64) -> poly64x2_t;
+        [neon] safe fn vdupq_n_p8(value: u8) -> poly8x16_t;
+        [neon] safe fn vdupq_n_s16(value: i16) -> int16x8_t;
+        [neon] safe fn vdupq_n_s32(value: i32) -> int32x4_t;
+        [neon] safe fn vdupq_n_s64(value: i64) -> int64x2_t;
+        [neon] safe fn vdupq_n_s8(value: i8) -> int8x16_t;
+        [neon] safe fn vdupq_n_u16(value: u16) -> uint16x8_t;
+        [neon] safe fn vdupq_n_u32(value: u32) -> uint32x4_t;
+        [neon] safe fn vdupq_n_u64(value: u64) -> uint64x2_t;
+        [neon] safe fn vdupq_n_u8(value: u8) -> uint8x16_t;
+        [neon] safe fn vdups_lane_f32<const N: i32>(a: float32x2_t) -> f32;
+        [neon] safe fn vdups_lane_s32<const N: i32>(a: int32x2_t) -> i32;
+        [neon] safe fn vdups_lane_u32<const N: i32>(a: uint32x2_t) -> u32;
+        [neon] safe fn vdups_laneq_f32<const N: i32>(a: float32x4_t) -> f32;
+        [neon] safe fn vdups_laneq_s32<const N: i32>(a: int32x4_t) -> i32;
+        [neon] safe fn vdups_laneq_u32<const N: i32>(a: uint32x4_t) -> u32;
+        [neon] safe fn veor_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn veor_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn veor_s64(a: int64x1_t, b: int64x1_t) -> int64x1_t;
+        [neon] safe fn veor_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn veor_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn veor_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn veor_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn veor_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn veorq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn veorq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn veorq_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn veorq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn veorq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn veorq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn veorq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn veorq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vext_f32<const N: i32>(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vext_f64<const N: i32>(a: float64x1_t, _b: float64x1_t) -> float64x1_t;
+        [neon] safe fn vext_p16<const N: i32>(a: poly16x4_t, b: poly16x4_t) -> poly16x4_t;
+        [neon] safe fn vext_p64<const N: i32>(a: poly64x1_t, _b: poly64x1_t) -> poly64x1_t;
+        [neon] safe fn vext_p8<const N: i32>(a: poly8x8_t, b: poly8x8_t) -> poly8x8_t;
+        [neon] safe fn vext_s16<const N: i32>(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vext_s32<const N: i32>(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] unsafe fn vext_s64<const N: i32>(a: int64x1_t, _b: int64x1_t) -> int64x1_t;
+        [neon] safe fn vext_s8<const N: i32>(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vext_u16<const N: i32>(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vext_u32<const N: i32>(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] unsafe fn vext_u64<const N: i32>(a: uint64x1_t, _b: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vext_u8<const N: i32>(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vextq_f32<const N: i32>(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vextq_f64<const N: i32>(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vextq_p16<const N: i32>(a: poly16x8_t, b: poly16x8_t) -> poly16x8_t;
+        [neon] safe fn vextq_p64<const N: i32>(a: poly64x2_t, b: poly64x2_t) -> poly64x2_t;
+        [neon] safe fn vextq_p8<const N: i32>(a: poly8x16_t, b: poly8x16_t) -> poly8x16_t;
+        [neon] safe fn vextq_s16<const N: i32>(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vextq_s32<const N: i32>(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vextq_s64<const N: i32>(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vextq_s8<const N: i32>(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vextq_u16<const N: i32>(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vextq_u32<const N: i32>(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vextq_u64<const N: i32>(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vextq_u8<const N: i32>(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vfma_f32(a: float32x2_t, b: float32x2_t, c: float32x2_t) -> float32x2_t;
+        [neon] safe fn vfma_f64(a: float64x1_t, b: float64x1_t, c: float64x1_t) -> float64x1_t;
+        [neon] safe fn vfma_lane_f32<const LANE: i32>(a: float32x2_t, b: float32x2_t, c: float32x2_t) -> float32x2_t;
+        [neon] safe fn vfma_lane_f64<const LANE: i32>(a: float64x1_t, b: float64x1_t, c: float64x1_t) -> float64x1_t;
+        [neon] safe fn vfma_laneq_f32<const LANE: i32>(a: float32x2_t, b: float32x2_t, c: float32x4_t) -> float32x2_t;
+        [neon] safe fn vfma_laneq_f64<const LANE: i32>(a: float64x1_t, b: float64x1_t, c: float64x2_t) -> float64x1_t;
+        [neon] safe fn vfma_n_f32(a: float32x2_t, b: float32x2_t, c: f32) -> float32x2_t;
+        [neon] safe fn vfma_n_f64(a: float64x1_t, b: float64x1_t, c: f64) -> float64x1_t;
+        [neon] safe fn vfmad_lane_f64<const LANE: i32>(a: f64, b: f64, c: float64x1_t) -> f64;
+        [neon] safe fn vfmad_laneq_f64<const LANE: i32>(a: f64, b: f64, c: float64x2_t) -> f64;
+        [neon] safe fn vfmaq_f32(a: float32x4_t, b: float32x4_t, c: float32x4_t) -> float32x4_t;
+        [neon] safe fn vfmaq_f64(a: float64x2_t, b: float64x2_t, c: float64x2_t) -> float64x2_t;
+        [neon] safe fn vfmaq_lane_f32<const LANE: i32>(a: float32x4_t, b: float32x4_t, c: float32x2_t) -> float32x4_t;
+        [neon] safe fn vfmaq_lane_f64<const LANE: i32>(a: float64x2_t, b: float64x2_t, c: float64x1_t) -> float64x2_t;
+        [neon] safe fn vfmaq_laneq_f32<const LANE: i32>(a: float32x4_t, b: float32x4_t, c: float32x4_t) -> float32x4_t;
+        [neon] safe fn vfmaq_laneq_f64<const LANE: i32>(a: float64x2_t, b: float64x2_t, c: float64x2_t) -> float64x2_t;
+        [neon] safe fn vfmaq_n_f32(a: float32x4_t, b: float32x4_t, c: f32) -> float32x4_t;
+        [neon] safe fn vfmaq_n_f64(a: float64x2_t, b: float64x2_t, c: f64) -> float64x2_t;
+        [neon] safe fn vfmas_lane_f32<const LANE: i32>(a: f32, b: f32, c: float32x2_t) -> f32;
+        [neon] safe fn vfmas_laneq_f32<const LANE: i32>(a: f32, b: f32, c: float32x4_t) -> f32;
+        [neon] safe fn vfms_f32(a: float32x2_t, b: float32x2_t, c: float32x2_t) -> float32x2_t;
+        [neon] safe fn vfms_f64(a: float64x1_t, b: float64x1_t, c: float64x1_t) -> float64x1_t;
+        [neon] safe fn vfms_lane_f32<const LANE: i32>(a: float32x2_t, b: float32x2_t, c: float32x2_t) -> float32x2_t;
+        [neon] safe fn vfms_lane_f64<const LANE: i32>(a: float64x1_t, b: float64x1_t, c: float64x1_t) -> float64x1_t;
+        [neon] safe fn vfms_laneq_f32<const LANE: i32>(a: float32x2_t, b: float32x2_t, c: float32x4_t) -> float32x2_t;
+        [neon] safe fn vfms_laneq_f64<const LANE: i32>(a: float64x1_t, b: float64x1_t, c: float64x2_t) -> float64x1_t;
+        [neon] safe fn vfms_n_f32(a: float32x2_t, b: float32x2_t, c: f32) -> float32x2_t;
+        [neon] safe fn vfms_n_f64(a: float64x1_t, b: float64x1_t, c: f64) -> float64x1_t;
+        [neon] safe fn vfmsd_lane_f64<const LANE: i32>(a: f64, b: f64, c: float64x1_t) -> f64;
+        [neon] safe fn vfmsd_laneq_f64<const LANE: i32>(a: f64, b: f64, c: float64x2_t) -> f64;
+        [neon] safe fn vfmsq_f32(a: float32x4_t, b: float32x4_t, c: float32x4_t) -> float32x4_t;
+        [neon] safe fn vfmsq_f64(a: float64x2_t, b: float64x2_t, c: float64x2_t) -> float64x2_t;
+        [neon] safe fn vfmsq_lane_f32<const LANE: i32>(a: float32x4_t, b: float32x4_t, c: float32x2_t) -> float32x4_t;
+        [neon] safe fn vfmsq_lane_f64<const LANE: i32>(a: float64x2_t, b: float64x2_t, c: float64x1_t) -> float64x2_t;
+        [neon] safe fn vfmsq_laneq_f32<const LANE: i32>(a: float32x4_t, b: float32x4_t, c: float32x4_t) -> float32x4_t;
+        [neon] safe fn vfmsq_laneq_f64<const LANE: i32>(a: float64x2_t, b: float64x2_t, c: float64x2_t) -> float64x2_t;
+        [neon] safe fn vfmsq_n_f32(a: float32x4_t, b: float32x4_t, c: f32) -> float32x4_t;
+        [neon] safe fn vfmsq_n_f64(a: float64x2_t, b: float64x2_t, c: f64) -> float64x2_t;
+        [neon] safe fn vfmss_lane_f32<const LANE: i32>(a: f32, b: f32, c: float32x2_t) -> f32;
+        [neon] safe fn vfmss_laneq_f32<const LANE: i32>(a: f32, b: f32, c: float32x4_t) -> f32;
+        [neon] safe fn vget_high_f32(a: float32x4_t) -> float32x2_t;
+        [neon] safe fn vget_high_f64(a: float64x2_t) -> float64x1_t;
+        [neon] safe fn vget_high_p16(a: poly16x8_t) -> poly16x4_t;
+        [neon] safe fn vget_high_p64(a: poly64x2_t) -> poly64x1_t;
+        [neon] safe fn vget_high_p8(a: poly8x16_t) -> poly8x8_t;
+        [neon] safe fn vget_high_s16(a: int16x8_t) -> int16x4_t;
+        [neon] safe fn vget_high_s32(a: int32x4_t) -> int32x2_t;
+        [neon] safe fn vget_high_s64(a: int64x2_t) -> int64x1_t;
+        [neon] safe fn vget_high_s8(a: int8x16_t) -> int8x8_t;
+        [neon] safe fn vget_high_u16(a: uint16x8_t) -> uint16x4_t;
+        [neon] safe fn vget_high_u32(a: uint32x4_t) -> uint32x2_t;
+        [neon] safe fn vget_high_u64(a: uint64x2_t) -> uint64x1_t;
+        [neon] safe fn vget_high_u8(a: uint8x16_t) -> uint8x8_t;
+        [neon] safe fn vget_lane_f32<const IMM5: i32>(v: float32x2_t) -> f32;
+        [neon] safe fn vget_lane_f64<const IMM5: i32>(v: float64x1_t) -> f64;
+        [neon] safe fn vget_lane_p16<const IMM5: i32>(v: poly16x4_t) -> u16;
+        [neon] safe fn vget_lane_p64<const IMM5: i32>(v: poly64x1_t) -> u64;
+        [neon] safe fn vget_lane_p8<const IMM5: i32>(v: poly8x8_t) -> u8;
+        [neon] safe fn vget_lane_s16<const IMM5: i32>(v: int16x4_t) -> i16;
+        [neon] safe fn vget_lane_s32<const IMM5: i32>(v: int32x2_t) -> i32;
+        [neon] safe fn vget_lane_s64<const IMM5: i32>(v: int64x1_t) -> i64;
+        [neon] safe fn vget_lane_s8<const IMM5: i32>(v: int8x8_t) -> i8;
+        [neon] safe fn vget_lane_u16<const IMM5: i32>(v: uint16x4_t) -> u16;
+        [neon] safe fn vget_lane_u32<const IMM5: i32>(v: uint32x2_t) -> u32;
+        [neon] safe fn vget_lane_u64<const IMM5: i32>(v: uint64x1_t) -> u64;
+        [neon] safe fn vget_lane_u8<const IMM5: i32>(v: uint8x8_t) -> u8;
+        [neon] safe fn vget_low_f32(a: float32x4_t) -> float32x2_t;
+        [neon] safe fn vget_low_f64(a: float64x2_t) -> float64x1_t;
+        [neon] safe fn vget_low_p16(a: poly16x8_t) -> poly16x4_t;
+        [neon] safe fn vget_low_p64(a: poly64x2_t) -> poly64x1_t;
+        [neon] safe fn vget_low_p8(a: poly8x16_t) -> poly8x8_t;
+        [neon] safe fn vget_low_s16(a: int16x8_t) -> int16x4_t;
+        [neon] safe fn vget_low_s32(a: int32x4_t) -> int32x2_t;
+        [neon] safe fn vget_low_s64(a: int64x2_t) -> int64x1_t;
+        [neon] safe fn vget_low_s8(a: int8x16_t) -> int8x8_t;
+        [neon] safe fn vget_low_u16(a: uint16x8_t) -> uint16x4_t;
+        [neon] safe fn vget_low_u32(a: uint32x4_t) -> uint32x2_t;
+        [neon] safe fn vget_low_u64(a: uint64x2_t) -> uint64x1_t;
+        [neon] safe fn vget_low_u8(a: uint8x16_t) -> uint8x8_t;
+        [neon] safe fn vgetq_lane_f32<const IMM5: i32>(v: float32x4_t) -> f32;
+        [neon] safe fn vgetq_lane_f64<const IMM5: i32>(v: float64x2_t) -> f64;
+        [neon] safe fn vgetq_lane_p16<const IMM5: i32>(v: poly16x8_t) -> u16;
+        [neon] safe fn vgetq_lane_p64<const IMM5: i32>(v: poly64x2_t) -> u64;
+        [neon] safe fn vgetq_lane_p8<const IMM5: i32>(v: poly8x16_t) -> u8;
+        [neon] safe fn vgetq_lane_s16<const IMM5: i32>(v: int16x8_t) -> i16;
+        [neon] safe fn vgetq_lane_s32<const IMM5: i32>(v: int32x4_t) -> i32;
+        [neon] safe fn vgetq_lane_s64<const IMM5: i32>(v: int64x2_t) -> i64;
+        [neon] safe fn vgetq_lane_s8<const IMM5: i32>(v: int8x16_t) -> i8;
+        [neon] safe fn vgetq_lane_u16<const IMM5: i32>(v: uint16x8_t) -> u16;
+        [neon] safe fn vgetq_lane_u32<const IMM5: i32>(v: uint32x4_t) -> u32;
+        [neon] safe fn vgetq_lane_u64<const IMM5: i32>(v: uint64x2_t) -> u64;
+        [neon] safe fn vgetq_lane_u8<const IMM5: i32>(v: uint8x16_t) -> u8;
+        [neon] safe fn vhadd_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vhadd_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vhadd_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vhadd_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vhadd_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vhadd_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vhaddq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vhaddq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vhaddq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vhaddq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vhaddq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vhaddq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vhsub_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vhsub_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vhsub_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vhsub_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vhsub_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vhsub_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vhsubq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vhsubq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vhsubq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vhsubq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vhsubq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vhsubq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] unsafe fn vld1_dup_f32(ptr: *const f32) -> float32x2_t;
+        [neon] unsafe fn vld1_dup_f64(ptr: *const f64) -> float64x1_t;
+        [neon] unsafe fn vld1_dup_p16(ptr: *const u16) -> poly16x4_t;
+        [neon, aes] unsafe fn vld1_dup_p64(ptr: *const u64) -> poly64x1_t;
+        [neon] unsafe fn vld1_dup_p8(ptr: *const u8) -> poly8x8_t;
+        [neon] unsafe fn vld1_dup_s16(ptr: *const i16) -> int16x4_t;
+        [neon] unsafe fn vld1_dup_s32(ptr: *const i32) -> int32x2_t;
+        [neon] unsafe fn vld1_dup_s64(ptr: *const i64) -> int64x1_t;
+        [neon] unsafe fn vld1_dup_s8(ptr: *const i8) -> int8x8_t;
+        [neon] unsafe fn vld1_dup_u16(ptr: *const u16) -> uint16x4_t;
+        [neon] unsafe fn vld1_dup_u32(ptr: *const u32) -> uint32x2_t;
+        [neon] unsafe fn vld1_dup_u64(ptr: *const u64) -> uint64x1_t;
+        [neon] unsafe fn vld1_dup_u8(ptr: *const u8) -> uint8x8_t;
+        [neon] unsafe fn vld1_f32(ptr: *const f32) -> float32x2_t;
+        [neon] unsafe fn vld1_f32_x2(a: *const f32) -> float32x2x2_t;
+        [neon] unsafe fn vld1_f32_x3(a: *const f32) -> float32x2x3_t;
+        [neon] unsafe fn vld1_f32_x4(a: *const f32) -> float32x2x4_t;
+        [neon] unsafe fn vld1_f64(ptr: *const f64) -> float64x1_t;
+        [neon] unsafe fn vld1_f64_x2(ptr: *const f64) -> float64x1x2_t;
+        [neon] unsafe fn vld1_f64_x3(ptr: *const f64) -> float64x1x3_t;
+        [neon] unsafe fn vld1_f64_x4(ptr: *const f64) -> float64x1x4_t;
+        [neon] unsafe fn vld1_lane_f32<const LANE: i32>(ptr: *const f32, src: float32x2_t) -> float32x2_t;
+        [neon] unsafe fn vld1_lane_f64<const LANE: i32>(ptr: *const f64, src: float64x1_t) -> float64x1_t;
+        [neon] unsafe fn vld1_lane_p16<const LANE: i32>(ptr: *const u16, src: poly16x4_t) -> poly16x4_t;
+        [neon, aes] unsafe fn vld1_lane_p64<const LANE: i32>(ptr: *const u64, src: poly64x1_t) -> poly64x1_t;
+        [neon] unsafe fn vld1_lane_p8<const LANE: i32>(ptr: *const u8, src: poly8x8_t) -> poly8x8_t;
+        [neon] unsafe fn vld1_lane_s16<const LANE: i32>(ptr: *const i16, src: int16x4_t) -> int16x4_t;
+        [neon] unsafe fn vld1_lane_s32<const LANE: i32>(ptr: *const i32, src: int32x2_t) -> int32x2_t;
+        [neon] unsafe fn vld1_lane_s64<const LANE: i32>(ptr: *const i64, src: int64x1_t) -> int64x1_t;
+        [neon] unsafe fn vld1_lane_s8<const LANE: i32>(ptr: *const i8, src: int8x8_t) -> int8x8_t;
+        [neon] unsafe fn vld1_lane_u16<const LANE: i32>(ptr: *const u16, src: uint16x4_t) -> uint16x4_t;
+        [neon] unsafe fn vld1_lane_u32<const LANE: i32>(ptr: *const u32, src: uint32x2_t) -> uint32x2_t;
+        [neon] unsafe fn vld1_lane_u64<const LANE: i32>(ptr: *const u64, src: uint64x1_t) -> uint64x1_t;
+        [neon] unsafe fn vld1_lane_u8<const LANE: i32>(ptr: *const u8, src: uint8x8_t) -> uint8x8_t;
+        [neon] unsafe fn vld1_p16(ptr: *const u16) -> poly16x4_t;
+        [neon] unsafe fn vld1_p16_x2(a: *const u16) -> poly16x4x2_t;
+        [neon] unsafe fn vld1_p16_x3(a: *const u16) -> poly16x4x3_t;
+        [neon] unsafe fn vld1_p16_x4(a: *const u16) -> poly16x4x4_t;
+        [neon, aes] unsafe fn vld1_p64(ptr: *const u64) -> poly64x1_t;
+        [neon, aes] unsafe fn vld1_p64_x2(a: *const u64) -> poly64x1x2_t;
+        [neon, aes] unsafe fn vld1_p64_x3(a: *const u64) -> poly64x1x3_t;
+        [neon, aes] unsafe fn vld1_p64_x4(a: *const u64) -> poly64x1x4_t;
+        [neon] unsafe fn vld1_p8(ptr: *const u8) -> poly8x8_t;
+        [neon] unsafe fn vld1_p8_x2(a: *const u8) -> poly8x8x2_t;
+        [neon] unsafe fn vld1_p8_x3(a: *const u8) -> poly8x8x3_t;
+        [neon] unsafe fn vld1_p8_x4(a: *const u8) -> poly8x8x4_t;
+        [neon] unsafe fn vld1_s16(ptr: *const i16) -> int16x4_t;
+        [neon] unsafe fn vld1_s16_x2(a: *const i16) -> int16x4x2_t;
+        [neon] unsafe fn vld1_s16_x3(a: *const i16) -> int16x4x3_t;
+        [neon] unsafe fn vld1_s16_x4(a: *const i16) -> int16x4x4_t;
+        [neon] unsafe fn vld1_s32(ptr: *const i32) -> int32x2_t;
+        [neon] unsafe fn vld1_s32_x2(a: *const i32) -> int32x2x2_t;
+        [neon] unsafe fn vld1_s32_x3(a: *const i32) -> int32x2x3_t;
+        [neon] unsafe fn vld1_s32_x4(a: *const i32) -> int32x2x4_t;
+        [neon] unsafe fn vld1_s64(ptr: *const i64) -> int64x1_t;
+        [neon] unsafe fn vld1_s64_x2(a: *const i64) -> int64x1x2_t;
+        [neon] unsafe fn vld1_s64_x3(a: *const i64) -> int64x1x3_t;
+        [neon] unsafe fn vld1_s64_x4(a: *const i64) -> int64x1x4_t;
+        [neon] unsafe fn vld1_s8(ptr: *const i8) -> int8x8_t;
+        [neon] unsafe fn vld1_s8_x2(a: *const i8) -> int8x8x2_t;
+        [neon] unsafe fn vld1_s8_x3(a: *const i8) -> int8x8x3_t;
+        [neon] unsafe fn vld1_s8_x4(a: *const i8) -> int8x8x4_t;
+        [neon] unsafe fn vld1_u16(ptr: *const u16) -> uint16x4_t;
+        [neon] unsafe fn vld1_u16_x2(a: *const u16) -> uint16x4x2_t;
+        [neon] unsafe fn vld1_u16_x3(a: *const u16) -> uint16x4x3_t;
+        [neon] unsafe fn vld1_u16_x4(a: *const u16) -> uint16x4x4_t;
+        [neon] unsafe fn vld1_u32(ptr: *const u32) -> uint32x2_t;
+        [neon] unsafe fn vld1_u32_x2(a: *const u32) -> uint32x2x2_t;
+        [neon] unsafe fn vld1_u32_x3(a: *const u32) -> uint32x2x3_t;
+        [neon] unsafe fn vld1_u32_x4(a: *const u32) -> uint32x2x4_t;
+        [neon] unsafe fn vld1_u64(ptr: *const u64) -> uint64x1_t;
+        [neon] unsafe fn vld1_u64_x2(a: *const u64) -> uint64x1x2_t;
+        [neon] unsafe fn vld1_u64_x3(a: *const u64) -> uint64x1x3_t;
+        [neon] unsafe fn vld1_u64_x4(a: *const u64) -> uint64x1x4_t;
+        [neon] unsafe fn vld1_u8(ptr: *const u8) -> uint8x8_t;
+        [neon] unsafe fn vld1_u8_x2(a: *const u8) -> uint8x8x2_t;
+        [neon] unsafe fn vld1_u8_x3(a: *const u8) -> uint8x8x3_t;
+        [neon] unsafe fn vld1_u8_x4(a: *const u8) -> uint8x8x4_t;
+        [neon] unsafe fn vld1q_dup_f32(ptr: *const f32) -> float32x4_t;
+        [neon] unsafe fn vld1q_dup_f64(ptr: *const f64) -> float64x2_t;
+        [neon] unsafe fn vld1q_dup_p16(ptr: *const u16) -> poly16x8_t;
+        [neon, aes] unsafe fn vld1q_dup_p64(ptr: *const u64) -> poly64x2_t;
+        [neon] unsafe fn vld1q_dup_p8(ptr: *const u8) -> poly8x16_t;
+        [neon] unsafe fn vld1q_dup_s16(ptr: *const i16) -> int16x8_t;
+        [neon] unsafe fn vld1q_dup_s32(ptr: *const i32) -> int32x4_t;
+        [neon] unsafe fn vld1q_dup_s64(ptr: *const i64) -> int64x2_t;
+        [neon] unsafe fn vld1q_dup_s8(ptr: *const i8) -> int8x16_t;
+        [neon] unsafe fn vld1q_dup_u16(ptr: *const u16) -> uint16x8_t;
+        [neon] unsafe fn vld1q_dup_u32(ptr: *const u32) -> uint32x4_t;
+        [neon] unsafe fn vld1q_dup_u64(ptr: *const u64) -> uint64x2_t;
+        [neon] unsafe fn vld1q_dup_u8(ptr: *const u8) -> uint8x16_t;
+        [neon] unsafe fn vld1q_f32(ptr: *const f32) -> float32x4_t;
+        [neon] unsafe fn vld1q_f32_x2(a: *const f32) -> float32x4x2_t;
+        [neon] unsafe fn vld1q_f32_x3(a: *const f32) -> float32x4x3_t;
+        [neon] unsafe fn vld1q_f32_x4(a: *const f32) -> float32x4x4_t;
+        [neon] unsafe fn vld1q_f64(ptr: *const f64) -> float64x2_t;
+        [neon] unsafe fn vld1q_f64_x2(ptr: *const f64) -> float64x2x2_t;
+        [neon] unsafe fn vld1q_f64_x3(ptr: *const f64) -> float64x2x3_t;
+        [neon] unsafe fn vld1q_f64_x4(ptr: *const f64) -> float64x2x4_t;
+        [neon] unsafe fn vld1q_lane_f32<const LANE: i32>(ptr: *const f32, src: float32x4_t) -> float32x4_t;
+        [neon] unsafe fn vld1q_lane_f64<const LANE: i32>(ptr: *const f64, src: float64x2_t) -> float64x2_t;
+        [neon] unsafe fn vld1q_lane_p16<const LANE: i32>(ptr: *const u16, src: poly16x8_t) -> poly16x8_t;
+        [neon, aes] unsafe fn vld1q_lane_p64<const LANE: i32>(ptr: *const u64, src: poly64x2_t) -> poly64x2_t;
+        [neon] unsafe fn vld1q_lane_p8<const LANE: i32>(ptr: *const u8, src: poly8x16_t) -> poly8x16_t;
+        [neon] unsafe fn vld1q_lane_s16<const LANE: i32>(ptr: *const i16, src: int16x8_t) -> int16x8_t;
+        [neon] unsafe fn vld1q_lane_s32<const LANE: i32>(ptr: *const i32, src: int32x4_t) -> int32x4_t;
+        [neon] unsafe fn vld1q_lane_s64<const LANE: i32>(ptr: *const i64, src: int64x2_t) -> int64x2_t;
+        [neon] unsafe fn vld1q_lane_s8<const LANE: i32>(ptr: *const i8, src: int8x16_t) -> int8x16_t;
+        [neon] unsafe fn vld1q_lane_u16<const LANE: i32>(ptr: *const u16, src: uint16x8_t) -> uint16x8_t;
+        [neon] unsafe fn vld1q_lane_u32<const LANE: i32>(ptr: *const u32, src: uint32x4_t) -> uint32x4_t;
+        [neon] unsafe fn vld1q_lane_u64<const LANE: i32>(ptr: *const u64, src: uint64x2_t) -> uint64x2_t;
+        [neon] unsafe fn vld1q_lane_u8<const LANE: i32>(ptr: *const u8, src: uint8x16_t) -> uint8x16_t;
+        [neon] unsafe fn vld1q_p16(ptr: *const u16) -> poly16x8_t;
+        [neon] unsafe fn vld1q_p16_x2(a: *const u16) -> poly16x8x2_t;
+        [neon] unsafe fn vld1q_p16_x3(a: *const u16) -> poly16x8x3_t;
+        [neon] unsafe fn vld1q_p16_x4(a: *const u16) -> poly16x8x4_t;
+        [neon, aes] unsafe fn vld1q_p64(ptr: *const u64) -> poly64x2_t;
+        [neon, aes] unsafe fn vld1q_p64_x2(a: *const u64) -> poly64x2x2_t;
+        [neon, aes] unsafe fn vld1q_p64_x3(a: *const u64) -> poly64x2x3_t;
+        [neon, aes] unsafe fn vld1q_p64_x4(a: *const u64) -> poly64x2x4_t;
+        [neon] unsafe fn vld1q_p8(ptr: *const u8) -> poly8x16_t;
+        [neon] unsafe fn vld1q_p8_x2(a: *const u8) -> poly8x16x2_t;
+        [neon] unsafe fn vld1q_p8_x3(a: *const u8) -> poly8x16x3_t;
+        [neon] unsafe fn vld1q_p8_x4(a: *const u8) -> poly8x16x4_t;
+        [neon] unsafe fn vld1q_s16(ptr: *const i16) -> int16x8_t;
+        [neon] unsafe fn vld1q_s16_x2(a: *const i16) -> int16x8x2_t;
+        [neon] unsafe fn vld1q_s16_x3(a: *const i16) -> int16x8x3_t;
+        [neon] unsafe fn vld1q_s16_x4(a: *const i16) -> int16x8x4_t;
+        [neon] unsafe fn vld1q_s32(ptr: *const i32) -> int32x4_t;
+        [neon] unsafe fn vld1q_s32_x2(a: *const i32) -> int32x4x2_t;
+        [neon] unsafe fn vld1q_s32_x3(a: *const i32) -> int32x4x3_t;
+        [neon] unsafe fn vld1q_s32_x4(a: *const i32) -> int32x4x4_t;
+        [neon] unsafe fn vld1q_s64(ptr: *const i64) -> int64x2_t;
+        [neon] unsafe fn vld1q_s64_x2(a: *const i64) -> int64x2x2_t;
+        [neon] unsafe fn vld1q_s64_x3(a: *const i64) -> int64x2x3_t;
+        [neon] unsafe fn vld1q_s64_x4(a: *const i64) -> int64x2x4_t;
+        [neon] unsafe fn vld1q_s8(ptr: *const i8) -> int8x16_t;
+        [neon] unsafe fn vld1q_s8_x2(a: *const i8) -> int8x16x2_t;
+        [neon] unsafe fn vld1q_s8_x3(a: *const i8) -> int8x16x3_t;
+        [neon] unsafe fn vld1q_s8_x4(a: *const i8) -> int8x16x4_t;
+        [neon] unsafe fn vld1q_u16(ptr: *const u16) -> uint16x8_t;
+        [neon] unsafe fn vld1q_u16_x2(a: *const u16) -> uint16x8x2_t;
+        [neon] unsafe fn vld1q_u16_x3(a: *const u16) -> uint16x8x3_t;
+        [neon] unsafe fn vld1q_u16_x4(a: *const u16) -> uint16x8x4_t;
+        [neon] unsafe fn vld1q_u32(ptr: *const u32) -> uint32x4_t;
+        [neon] unsafe fn vld1q_u32_x2(a: *const u32) -> uint32x4x2_t;
+        [neon] unsafe fn vld1q_u32_x3(a: *const u32) -> uint32x4x3_t;
+        [neon] unsafe fn vld1q_u32_x4(a: *const u32) -> uint32x4x4_t;
+        [neon] unsafe fn vld1q_u64(ptr: *const u64) -> uint64x2_t;
+        [neon] unsafe fn vld1q_u64_x2(a: *const u64) -> uint64x2x2_t;
+        [neon] unsafe fn vld1q_u64_x3(a: *const u64) -> uint64x2x3_t;
+        [neon] unsafe fn vld1q_u64_x4(a: *const u64) -> uint64x2x4_t;
+        [neon] unsafe fn vld1q_u8(ptr: *const u8) -> uint8x16_t;
+        [neon] unsafe fn vld1q_u8_x2(a: *const u8) -> uint8x16x2_t;
+        [neon] unsafe fn vld1q_u8_x3(a: *const u8) -> uint8x16x3_t;
+        [neon] unsafe fn vld1q_u8_x4(a: *const u8) -> uint8x16x4_t;
+        [neon] unsafe fn vld2_dup_f32(a: *const f32) -> float32x2x2_t;
+        [neon] unsafe fn vld2_dup_f64(a: *const f64) -> float64x1x2_t;
+        [neon] unsafe fn vld2_dup_p16(a: *const u16) -> poly16x4x2_t;
+        [neon, aes] unsafe fn vld2_dup_p64(a: *const u64) -> poly64x1x2_t;
+        [neon] unsafe fn vld2_dup_p8(a: *const u8) -> poly8x8x2_t;
+        [neon] unsafe fn vld2_dup_s16(a: *const i16) -> int16x4x2_t;
+        [neon] unsafe fn vld2_dup_s32(a: *const i32) -> int32x2x2_t;
+        [neon] unsafe fn vld2_dup_s64(a: *const i64) -> int64x1x2_t;
+        [neon] unsafe fn vld2_dup_s8(a: *const i8) -> int8x8x2_t;
+        [neon] unsafe fn vld2_dup_u16(a: *const u16) -> uint16x4x2_t;
+        [neon] unsafe fn vld2_dup_u32(a: *const u32) -> uint32x2x2_t;
+        [neon] unsafe fn vld2_dup_u64(a: *const u64) -> uint64x1x2_t;
+        [neon] unsafe fn vld2_dup_u8(a: *const u8) -> uint8x8x2_t;
+        [neon] unsafe fn vld2_f32(a: *const f32) -> float32x2x2_t;
+        [neon] unsafe fn vld2_f64(a: *const f64) -> float64x1x2_t;
+        [neon] unsafe fn vld2_lane_f32<const LANE: i32>(a: *const f32, b: float32x2x2_t) -> float32x2x2_t;
+        [neon] unsafe fn vld2_lane_f64<const LANE: i32>(a: *const f64, b: float64x1x2_t) -> float64x1x2_t;
+        [neon] unsafe fn vld2_lane_p16<const LANE: i32>(a: *const u16, b: poly16x4x2_t) -> poly16x4x2_t;
+        [neon, aes] unsafe fn vld2_lane_p64<const LANE: i32>(a: *const u64, b: poly64x1x2_t) -> poly64x1x2_t;
+        [neon] unsafe fn vld2_lane_p8<const LANE: i32>(a: *const u8, b: poly8x8x2_t) -> poly8x8x2_t;
+        [neon] unsafe fn vld2_lane_s16<const LANE: i32>(a: *const i16, b: int16x4x2_t) -> int16x4x2_t;
+        [neon] unsafe fn vld2_lane_s32<const LANE: i32>(a: *const i32, b: int32x2x2_t) -> int32x2x2_t;
+        [neon] unsafe fn vld2_lane_s64<const LANE: i32>(a: *const i64, b: int64x1x2_t) -> int64x1x2_t;
+        [neon] unsafe fn vld2_lane_s8<const LANE: i32>(a: *const i8, b: int8x8x2_t) -> int8x8x2_t;
+        [neon] unsafe fn vld2_lane_u16<const LANE: i32>(a: *const u16, b: uint16x4x2_t) -> uint16x4x2_t;
+        [neon] unsafe fn vld2_lane_u32<const LANE: i32>(a: *const u32, b: uint32x2x2_t) -> uint32x2x2_t;
+        [neon] unsafe fn vld2_lane_u64<const LANE: i32>(a: *const u64, b: uint64x1x2_t) -> uint64x1x2_t;
+        [neon] unsafe fn vld2_lane_u8<const LANE: i32>(a: *const u8, b: uint8x8x2_t) -> uint8x8x2_t;
+        [neon] unsafe fn vld2_p16(a: *const u16) -> poly16x4x2_t;
+        [neon, aes] unsafe fn vld2_p64(a: *const u64) -> poly64x1x2_t;
+        [neon] unsafe fn vld2_p8(a: *const u8) -> poly8x8x2_t;
+        [neon] unsafe fn vld2_s16(a: *const i16) -> int16x4x2_t;
+        [neon] unsafe fn vld2_s32(a: *const i32) -> int32x2x2_t;
+        [neon] unsafe fn vld2_s64(a: *const i64) -> int64x1x2_t;
+        [neon] unsafe fn vld2_s8(a: *const i8) -> int8x8x2_t;
+        [neon] unsafe fn vld2_u16(a: *const u16) -> uint16x4x2_t;
+        [neon] unsafe fn vld2_u32(a: *const u32) -> uint32x2x2_t;
+        [neon] unsafe fn vld2_u64(a: *const u64) -> uint64x1x2_t;
+        [neon] unsafe fn vld2_u8(a: *const u8) -> uint8x8x2_t;
+        [neon] unsafe fn vld2q_dup_f32(a: *const f32) -> float32x4x2_t;
+        [neon] unsafe fn vld2q_dup_f64(a: *const f64) -> float64x2x2_t;
+        [neon] unsafe fn vld2q_dup_p16(a: *const u16) -> poly16x8x2_t;
+        [neon, aes] unsafe fn vld2q_dup_p64(a: *const u64) -> poly64x2x2_t;
+        [neon] unsafe fn vld2q_dup_p8(a: *const u8) -> poly8x16x2_t;
+        [neon] unsafe fn vld2q_dup_s16(a: *const i16) -> int16x8x2_t;
+        [neon] unsafe fn vld2q_dup_s32(a: *const i32) -> int32x4x2_t;
+        [neon] unsafe fn vld2q_dup_s64(a: *const i64) -> int64x2x2_t;
+        [neon] unsafe fn vld2q_dup_s8(a: *const i8) -> int8x16x2_t;
+        [neon] unsafe fn vld2q_dup_u16(a: *const u16) -> uint16x8x2_t;
+        [neon] unsafe fn vld2q_dup_u32(a: *const u32) -> uint32x4x2_t;
+        [neon] unsafe fn vld2q_dup_u64(a: *const u64) -> uint64x2x2_t;
+        [neon] unsafe fn vld2q_dup_u8(a: *const u8) -> uint8x16x2_t;
+        [neon] unsafe fn vld2q_f32(a: *const f32) -> float32x4x2_t;
+        [neon] unsafe fn vld2q_f64(a: *const f64) -> float64x2x2_t;
+        [neon] unsafe fn vld2q_lane_f32<const LANE: i32>(a: *const f32, b: float32x4x2_t) -> float32x4x2_t;
+        [neon] unsafe fn vld2q_lane_f64<const LANE: i32>(a: *const f64, b: float64x2x2_t) -> float64x2x2_t;
+        [neon] unsafe fn vld2q_lane_p16<const LANE: i32>(a: *const u16, b: poly16x8x2_t) -> poly16x8x2_t;
+        [neon, aes] unsafe fn vld2q_lane_p64<const LANE: i32>(a: *const u64, b: poly64x2x2_t) -> poly64x2x2_t;
+        [neon] unsafe fn vld2q_lane_p8<const LANE: i32>(a: *const u8, b: poly8x16x2_t) -> poly8x16x2_t;
+        [neon] unsafe fn vld2q_lane_s16<const LANE: i32>(a: *const i16, b: int16x8x2_t) -> int16x8x2_t;
+        [neon] unsafe fn vld2q_lane_s32<const LANE: i32>(a: *const i32, b: int32x4x2_t) -> int32x4x2_t;
+        [neon] unsafe fn vld2q_lane_s64<const LANE: i32>(a: *const i64, b: int64x2x2_t) -> int64x2x2_t;
+        [neon] unsafe fn vld2q_lane_s8<const LANE: i32>(a: *const i8, b: int8x16x2_t) -> int8x16x2_t;
+        [neon] unsafe fn vld2q_lane_u16<const LANE: i32>(a: *const u16, b: uint16x8x2_t) -> uint16x8x2_t;
+        [neon] unsafe fn vld2q_lane_u32<const LANE: i32>(a: *const u32, b: uint32x4x2_t) -> uint32x4x2_t;
+        [neon] unsafe fn vld2q_lane_u64<const LANE: i32>(a: *const u64, b: uint64x2x2_t) -> uint64x2x2_t;
+        [neon] unsafe fn vld2q_lane_u8<const LANE: i32>(a: *const u8, b: uint8x16x2_t) -> uint8x16x2_t;
+        [neon] unsafe fn vld2q_p16(a: *const u16) -> poly16x8x2_t;
+        [neon, aes] unsafe fn vld2q_p64(a: *const u64) -> poly64x2x2_t;
+        [neon] unsafe fn vld2q_p8(a: *const u8) -> poly8x16x2_t;
+        [neon] unsafe fn vld2q_s16(a: *const i16) -> int16x8x2_t;
+        [neon] unsafe fn vld2q_s32(a: *const i32) -> int32x4x2_t;
+        [neon] unsafe fn vld2q_s64(a: *const i64) -> int64x2x2_t;
+        [neon] unsafe fn vld2q_s8(a: *const i8) -> int8x16x2_t;
+        [neon] unsafe fn vld2q_u16(a: *const u16) -> uint16x8x2_t;
+        [neon] unsafe fn vld2q_u32(a: *const u32) -> uint32x4x2_t;
+        [neon] unsafe fn vld2q_u64(a: *const u64) -> uint64x2x2_t;
+        [neon] unsafe fn vld2q_u8(a: *const u8) -> uint8x16x2_t;
+        [neon] unsafe fn vld3_dup_f32(a: *const f32) -> float32x2x3_t;
+        [neon] unsafe fn vld3_dup_f64(a: *const f64) -> float64x1x3_t;
+        [neon] unsafe fn vld3_dup_p16(a: *const u16) -> poly16x4x3_t;
+        [neon, aes] unsafe fn vld3_dup_p64(a: *const u64) -> poly64x1x3_t;
+        [neon] unsafe fn vld3_dup_p8(a: *const u8) -> poly8x8x3_t;
+        [neon] unsafe fn vld3_dup_s16(a: *const i16) -> int16x4x3_t;
+        [neon] unsafe fn vld3_dup_s32(a: *const i32) -> int32x2x3_t;
+        [neon] unsafe fn vld3_dup_s64(a: *const i64) -> int64x1x3_t;
+        [neon] unsafe fn vld3_dup_s8(a: *const i8) -> int8x8x3_t;
+        [neon] unsafe fn vld3_dup_u16(a: *const u16) -> uint16x4x3_t;
+        [neon] unsafe fn vld3_dup_u32(a: *const u32) -> uint32x2x3_t;
+        [neon] unsafe fn vld3_dup_u64(a: *const u64) -> uint64x1x3_t;
+        [neon] unsafe fn vld3_dup_u8(a: *const u8) -> uint8x8x3_t;
+        [neon] unsafe fn vld3_f32(a: *const f32) -> float32x2x3_t;
+        [neon] unsafe fn vld3_f64(a: *const f64) -> float64x1x3_t;
+        [neon] unsafe fn vld3_lane_f32<const LANE: i32>(a: *const f32, b: float32x2x3_t) -> float32x2x3_t;
+        [neon] unsafe fn vld3_lane_f64<const LANE: i32>(a: *const f64, b: float64x1x3_t) -> float64x1x3_t;
+        [neon] unsafe fn vld3_lane_p16<const LANE: i32>(a: *const u16, b: poly16x4x3_t) -> poly16x4x3_t;
+        [neon, aes] unsafe fn vld3_lane_p64<const LANE: i32>(a: *const u64, b: poly64x1x3_t) -> poly64x1x3_t;
+        [neon] unsafe fn vld3_lane_p8<const LANE: i32>(a: *const u8, b: poly8x8x3_t) -> poly8x8x3_t;
+        [neon] unsafe fn vld3_lane_s16<const LANE: i32>(a: *const i16, b: int16x4x3_t) -> int16x4x3_t;
+        [neon] unsafe fn vld3_lane_s32<const LANE: i32>(a: *const i32, b: int32x2x3_t) -> int32x2x3_t;
+        [neon] unsafe fn vld3_lane_s64<const LANE: i32>(a: *const i64, b: int64x1x3_t) -> int64x1x3_t;
+        [neon] unsafe fn vld3_lane_s8<const LANE: i32>(a: *const i8, b: int8x8x3_t) -> int8x8x3_t;
+        [neon] unsafe fn vld3_lane_u16<const LANE: i32>(a: *const u16, b: uint16x4x3_t) -> uint16x4x3_t;
+        [neon] unsafe fn vld3_lane_u32<const LANE: i32>(a: *const u32, b: uint32x2x3_t) -> uint32x2x3_t;
+        [neon] unsafe fn vld3_lane_u64<const LANE: i32>(a: *const u64, b: uint64x1x3_t) -> uint64x1x3_t;
+        [neon] unsafe fn vld3_lane_u8<const LANE: i32>(a: *const u8, b: uint8x8x3_t) -> uint8x8x3_t;
+        [neon] unsafe fn vld3_p16(a: *const u16) -> poly16x4x3_t;
+        [neon, aes] unsafe fn vld3_p64(a: *const u64) -> poly64x1x3_t;
+        [neon] unsafe fn vld3_p8(a: *const u8) -> poly8x8x3_t;
+        [neon] unsafe fn vld3_s16(a: *const i16) -> int16x4x3_t;
+        [neon] unsafe fn vld3_s32(a: *const i32) -> int32x2x3_t;
+        [neon] unsafe fn vld3_s64(a: *const i64) -> int64x1x3_t;
+        [neon] unsafe fn vld3_s8(a: *const i8) -> int8x8x3_t;
+        [neon] unsafe fn vld3_u16(a: *const u16) -> uint16x4x3_t;
+        [neon] unsafe fn vld3_u32(a: *const u32) -> uint32x2x3_t;
+        [neon] unsafe fn vld3_u64(a: *const u64) -> uint64x1x3_t;
+        [neon] unsafe fn vld3_u8(a: *const u8) -> uint8x8x3_t;
+        [neon] unsafe fn vld3q_dup_f32(a: *const f32) -> float32x4x3_t;
+        [neon] unsafe fn vld3q_dup_f64(a: *const f64) -> float64x2x3_t;
+        [neon] unsafe fn vld3q_dup_p16(a: *const u16) -> poly16x8x3_t;
+        [neon, aes] unsafe fn vld3q_dup_p64(a: *const u64) -> poly64x2x3_t;
+        [neon] unsafe fn vld3q_dup_p8(a: *const u8) -> poly8x16x3_t;
+        [neon] unsafe fn vld3q_dup_s16(a: *const i16) -> int16x8x3_t;
+        [neon] unsafe fn vld3q_dup_s32(a: *const i32) -> int32x4x3_t;
+        [neon] unsafe fn vld3q_dup_s64(a: *const i64) -> int64x2x3_t;
+        [neon] unsafe fn vld3q_dup_s8(a: *const i8) -> int8x16x3_t;
+        [neon] unsafe fn vld3q_dup_u16(a: *const u16) -> uint16x8x3_t;
+        [neon] unsafe fn vld3q_dup_u32(a: *const u32) -> uint32x4x3_t;
+        [neon] unsafe fn vld3q_dup_u64(a: *const u64) -> uint64x2x3_t;
+        [neon] unsafe fn vld3q_dup_u8(a: *const u8) -> uint8x16x3_t;
+        [neon] unsafe fn vld3q_f32(a: *const f32) -> float32x4x3_t;
+        [neon] unsafe fn vld3q_f64(a: *const f64) -> float64x2x3_t;
+        [neon] unsafe fn vld3q_lane_f32<const LANE: i32>(a: *const f32, b: float32x4x3_t) -> float32x4x3_t;
+        [neon] unsafe fn vld3q_lane_f64<const LANE: i32>(a: *const f64, b: float64x2x3_t) -> float64x2x3_t;
+        [neon] unsafe fn vld3q_lane_p16<const LANE: i32>(a: *const u16, b: poly16x8x3_t) -> poly16x8x3_t;
+        [neon, aes] unsafe fn vld3q_lane_p64<const LANE: i32>(a: *const u64, b: poly64x2x3_t) -> poly64x2x3_t;
+        [neon] unsafe fn vld3q_lane_p8<const LANE: i32>(a: *const u8, b: poly8x16x3_t) -> poly8x16x3_t;
+        [neon] unsafe fn vld3q_lane_s16<const LANE: i32>(a: *const i16, b: int16x8x3_t) -> int16x8x3_t;
+        [neon] unsafe fn vld3q_lane_s32<const LANE: i32>(a: *const i32, b: int32x4x3_t) -> int32x4x3_t;
+        [neon] unsafe fn vld3q_lane_s64<const LANE: i32>(a: *const i64, b: int64x2x3_t) -> int64x2x3_t;
+        [neon] unsafe fn vld3q_lane_s8<const LANE: i32>(a: *const i8, b: int8x16x3_t) -> int8x16x3_t;
+        [neon] unsafe fn vld3q_lane_u16<const LANE: i32>(a: *const u16, b: uint16x8x3_t) -> uint16x8x3_t;
+        [neon] unsafe fn vld3q_lane_u32<const LANE: i32>(a: *const u32, b: uint32x4x3_t) -> uint32x4x3_t;
+        [neon] unsafe fn vld3q_lane_u64<const LANE: i32>(a: *const u64, b: uint64x2x3_t) -> uint64x2x3_t;
+        [neon] unsafe fn vld3q_lane_u8<const LANE: i32>(a: *const u8, b: uint8x16x3_t) -> uint8x16x3_t;
+        [neon] unsafe fn vld3q_p16(a: *const u16) -> poly16x8x3_t;
+        [neon, aes] unsafe fn vld3q_p64(a: *const u64) -> poly64x2x3_t;
+        [neon] unsafe fn vld3q_p8(a: *const u8) -> poly8x16x3_t;
+        [neon] unsafe fn vld3q_s16(a: *const i16) -> int16x8x3_t;
+        [neon] unsafe fn vld3q_s32(a: *const i32) -> int32x4x3_t;
+        [neon] unsafe fn vld3q_s64(a: *const i64) -> int64x2x3_t;
+        [neon] unsafe fn vld3q_s8(a: *const i8) -> int8x16x3_t;
+        [neon] unsafe fn vld3q_u16(a: *const u16) -> uint16x8x3_t;
+        [neon] unsafe fn vld3q_u32(a: *const u32) -> uint32x4x3_t;
+        [neon] unsafe fn vld3q_u64(a: *const u64) -> uint64x2x3_t;
+        [neon] unsafe fn vld3q_u8(a: *const u8) -> uint8x16x3_t;
+        [neon] unsafe fn vld4_dup_f32(a: *const f32) -> float32x2x4_t;
+        [neon] unsafe fn vld4_dup_f64(a: *const f64) -> float64x1x4_t;
+        [neon] unsafe fn vld4_dup_p16(a: *const u16) -> poly16x4x4_t;
+        [neon, aes] unsafe fn vld4_dup_p64(a: *const u64) -> poly64x1x4_t;
+        [neon] unsafe fn vld4_dup_p8(a: *const u8) -> poly8x8x4_t;
+        [neon] unsafe fn vld4_dup_s16(a: *const i16) -> int16x4x4_t;
+        [neon] unsafe fn vld4_dup_s32(a: *const i32) -> int32x2x4_t;
+        [neon] unsafe fn vld4_dup_s64(a: *const i64) -> int64x1x4_t;
+        [neon] unsafe fn vld4_dup_s8(a: *const i8) -> int8x8x4_t;
+        [neon] unsafe fn vld4_dup_u16(a: *const u16) -> uint16x4x4_t;
+        [neon] unsafe fn vld4_dup_u32(a: *const u32) -> uint32x2x4_t;
+        [neon] unsafe fn vld4_dup_u64(a: *const u64) -> uint64x1x4_t;
+        [neon] unsafe fn vld4_dup_u8(a: *const u8) -> uint8x8x4_t;
+        [neon] unsafe fn vld4_f32(a: *const f32) -> float32x2x4_t;
+        [neon] unsafe fn vld4_f64(a: *const f64) -> float64x1x4_t;
+        [neon] unsafe fn vld4_lane_f32<const LANE: i32>(a: *const f32, b: float32x2x4_t) -> float32x2x4_t;
+        [neon] unsafe fn vld4_lane_f64<const LANE: i32>(a: *const f64, b: float64x1x4_t) -> float64x1x4_t;
+        [neon] unsafe fn vld4_lane_p16<const LANE: i32>(a: *const u16, b: poly16x4x4_t) -> poly16x4x4_t;
+        [neon, aes] unsafe fn vld4_lane_p64<const LANE: i32>(a: *const u64, b: poly64x1x4_t) -> poly64x1x4_t;
+        [neon] unsafe fn vld4_lane_p8<const LANE: i32>(a: *const u8, b: poly8x8x4_t) -> poly8x8x4_t;
+        [neon] unsafe fn vld4_lane_s16<const LANE: i32>(a: *const i16, b: int16x4x4_t) -> int16x4x4_t;
+        [neon] unsafe fn vld4_lane_s32<const LANE: i32>(a: *const i32, b: int32x2x4_t) -> int32x2x4_t;
+        [neon] unsafe fn vld4_lane_s64<const LANE: i32>(a: *const i64, b: int64x1x4_t) -> int64x1x4_t;
+        [neon] unsafe fn vld4_lane_s8<const LANE: i32>(a: *const i8, b: int8x8x4_t) -> int8x8x4_t;
+        [neon] unsafe fn vld4_lane_u16<const LANE: i32>(a: *const u16, b: uint16x4x4_t) -> uint16x4x4_t;
+        [neon] unsafe fn vld4_lane_u32<const LANE: i32>(a: *const u32, b: uint32x2x4_t) -> uint32x2x4_t;
+        [neon] unsafe fn vld4_lane_u64<const LANE: i32>(a: *const u64, b: uint64x1x4_t) -> uint64x1x4_t;
+        [neon] unsafe fn vld4_lane_u8<const LANE: i32>(a: *const u8, b: uint8x8x4_t) -> uint8x8x4_t;
+        [neon] unsafe fn vld4_p16(a: *const u16) -> poly16x4x4_t;
+        [neon, aes] unsafe fn vld4_p64(a: *const u64) -> poly64x1x4_t;
+        [neon] unsafe fn vld4_p8(a: *const u8) -> poly8x8x4_t;
+        [neon] unsafe fn vld4_s16(a: *const i16) -> int16x4x4_t;
+        [neon] unsafe fn vld4_s32(a: *const i32) -> int32x2x4_t;
+        [neon] unsafe fn vld4_s64(a: *const i64) -> int64x1x4_t;
+        [neon] unsafe fn vld4_s8(a: *const i8) -> int8x8x4_t;
+        [neon] unsafe fn vld4_u16(a: *const u16) -> uint16x4x4_t;
+        [neon] unsafe fn vld4_u32(a: *const u32) -> uint32x2x4_t;
+        [neon] unsafe fn vld4_u64(a: *const u64) -> uint64x1x4_t;
+        [neon] unsafe fn vld4_u8(a: *const u8) -> uint8x8x4_t;
+        [neon] unsafe fn vld4q_dup_f32(a: *const f32) -> float32x4x4_t;
+        [neon] unsafe fn vld4q_dup_f64(a: *const f64) -> float64x2x4_t;
+        [neon] unsafe fn vld4q_dup_p16(a: *const u16) -> poly16x8x4_t;
+        [neon, aes] unsafe fn vld4q_dup_p64(a: *const u64) -> poly64x2x4_t;
+        [neon] unsafe fn vld4q_dup_p8(a: *const u8) -> poly8x16x4_t;
+        [neon] unsafe fn vld4q_dup_s16(a: *const i16) -> int16x8x4_t;
+        [neon] unsafe fn vld4q_dup_s32(a: *const i32) -> int32x4x4_t;
+        [neon] unsafe fn vld4q_dup_s64(a: *const i64) -> int64x2x4_t;
+        [neon] unsafe fn vld4q_dup_s8(a: *const i8) -> int8x16x4_t;
+        [neon] unsafe fn vld4q_dup_u16(a: *const u16) -> uint16x8x4_t;
+        [neon] unsafe fn vld4q_dup_u32(a: *const u32) -> uint32x4x4_t;
+        [neon] unsafe fn vld4q_dup_u64(a: *const u64) -> uint64x2x4_t;
+        [neon] unsafe fn vld4q_dup_u8(a: *const u8) -> uint8x16x4_t;
+        [neon] unsafe fn vld4q_f32(a: *const f32) -> float32x4x4_t;
+        [neon] unsafe fn vld4q_f64(a: *const f64) -> float64x2x4_t;
+        [neon] unsafe fn vld4q_lane_f32<const LANE: i32>(a: *const f32, b: float32x4x4_t) -> float32x4x4_t;
+        [neon] unsafe fn vld4q_lane_f64<const LANE: i32>(a: *const f64, b: float64x2x4_t) -> float64x2x4_t;
+        [neon] unsafe fn vld4q_lane_p16<const LANE: i32>(a: *const u16, b: poly16x8x4_t) -> poly16x8x4_t;
+        [neon, aes] unsafe fn vld4q_lane_p64<const LANE: i32>(a: *const u64, b: poly64x2x4_t) -> poly64x2x4_t;
+        [neon] unsafe fn vld4q_lane_p8<const LANE: i32>(a: *const u8, b: poly8x16x4_t) -> poly8x16x4_t;
+        [neon] unsafe fn vld4q_lane_s16<const LANE: i32>(a: *const i16, b: int16x8x4_t) -> int16x8x4_t;
+        [neon] unsafe fn vld4q_lane_s32<const LANE: i32>(a: *const i32, b: int32x4x4_t) -> int32x4x4_t;
+        [neon] unsafe fn vld4q_lane_s64<const LANE: i32>(a: *const i64, b: int64x2x4_t) -> int64x2x4_t;
+        [neon] unsafe fn vld4q_lane_s8<const LANE: i32>(a: *const i8, b: int8x16x4_t) -> int8x16x4_t;
+        [neon] unsafe fn vld4q_lane_u16<const LANE: i32>(a: *const u16, b: uint16x8x4_t) -> uint16x8x4_t;
+        [neon] unsafe fn vld4q_lane_u32<const LANE: i32>(a: *const u32, b: uint32x4x4_t) -> uint32x4x4_t;
+        [neon] unsafe fn vld4q_lane_u64<const LANE: i32>(a: *const u64, b: uint64x2x4_t) -> uint64x2x4_t;
+        [neon] unsafe fn vld4q_lane_u8<const LANE: i32>(a: *const u8, b: uint8x16x4_t) -> uint8x16x4_t;
+        [neon] unsafe fn vld4q_p16(a: *const u16) -> poly16x8x4_t;
+        [neon, aes] unsafe fn vld4q_p64(a: *const u64) -> poly64x2x4_t;
+        [neon] unsafe fn vld4q_p8(a: *const u8) -> poly8x16x4_t;
+        [neon] unsafe fn vld4q_s16(a: *const i16) -> int16x8x4_t;
+        [neon] unsafe fn vld4q_s32(a: *const i32) -> int32x4x4_t;
+        [neon] unsafe fn vld4q_s64(a: *const i64) -> int64x2x4_t;
+        [neon] unsafe fn vld4q_s8(a: *const i8) -> int8x16x4_t;
+        [neon] unsafe fn vld4q_u16(a: *const u16) -> uint16x8x4_t;
+        [neon] unsafe fn vld4q_u32(a: *const u32) -> uint32x4x4_t;
+        [neon] unsafe fn vld4q_u64(a: *const u64) -> uint64x2x4_t;
+        [neon] unsafe fn vld4q_u8(a: *const u8) -> uint8x16x4_t;
+        [neon] unsafe fn vldrq_p128(a: *const u128) -> u128;
+        [neon] safe fn vmax_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vmax_f64(a: float64x1_t, b: float64x1_t) -> float64x1_t;
+        [neon] safe fn vmax_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vmax_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vmax_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vmax_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vmax_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vmax_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vmaxnm_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vmaxnm_f64(a: float64x1_t, b: float64x1_t) -> float64x1_t;
+        [neon] safe fn vmaxnmq_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vmaxnmq_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vmaxnmv_f32(a: float32x2_t) -> f32;
+        [neon] safe fn vmaxnmvq_f32(a: float32x4_t) -> f32;
+        [neon] safe fn vmaxnmvq_f64(a: float64x2_t) -> f64;
+        [neon] safe fn vmaxq_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vmaxq_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vmaxq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vmaxq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vmaxq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vmaxq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vmaxq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vmaxq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vmaxv_f32(a: float32x2_t) -> f32;
+        [neon] safe fn vmaxv_s16(a: int16x4_t) -> i16;
+        [neon] safe fn vmaxv_s32(a: int32x2_t) -> i32;
+        [neon] safe fn vmaxv_s8(a: int8x8_t) -> i8;
+        [neon] safe fn vmaxv_u16(a: uint16x4_t) -> u16;
+        [neon] safe fn vmaxv_u32(a: uint32x2_t) -> u32;
+        [neon] safe fn vmaxv_u8(a: uint8x8_t) -> u8;
+        [neon] safe fn vmaxvq_f32(a: float32x4_t) -> f32;
+        [neon] safe fn vmaxvq_f64(a: float64x2_t) -> f64;
+        [neon] safe fn vmaxvq_s16(a: int16x8_t) -> i16;
+        [neon] safe fn vmaxvq_s32(a: int32x4_t) -> i32;
+        [neon] safe fn vmaxvq_s8(a: int8x16_t) -> i8;
+        [neon] safe fn vmaxvq_u16(a: uint16x8_t) -> u16;
+        [neon] safe fn vmaxvq_u32(a: uint32x4_t) -> u32;
+        [neon] safe fn vmaxvq_u8(a: uint8x16_t) -> u8;
+        [neon] safe fn vmin_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vmin_f64(a: float64x1_t, b: float64x1_t) -> float64x1_t;
+        [neon] safe fn vmin_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vmin_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vmin_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vmin_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vmin_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vmin_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vminnm_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vminnm_f64(a: float64x1_t, b: float64x1_t) -> float64x1_t;
+        [neon] safe fn vminnmq_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vminnmq_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vminnmv_f32(a: float32x2_t) -> f32;
+        [neon] safe fn vminnmvq_f32(a: float32x4_t) -> f32;
+        [neon] safe fn vminnmvq_f64(a: float64x2_t) -> f64;
+        [neon] safe fn vminq_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vminq_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vminq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vminq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vminq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vminq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vminq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vminq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vminv_f32(a: float32x2_t) -> f32;
+        [neon] safe fn vminv_s16(a: int16x4_t) -> i16;
+        [neon] safe fn vminv_s32(a: int32x2_t) -> i32;
+        [neon] safe fn vminv_s8(a: int8x8_t) -> i8;
+        [neon] safe fn vminv_u16(a: uint16x4_t) -> u16;
+        [neon] safe fn vminv_u32(a: uint32x2_t) -> u32;
+        [neon] safe fn vminv_u8(a: uint8x8_t) -> u8;
+        [neon] safe fn vminvq_f32(a: float32x4_t) -> f32;
+        [neon] safe fn vminvq_f64(a: float64x2_t) -> f64;
+        [neon] safe fn vminvq_s16(a: int16x8_t) -> i16;
+        [neon] safe fn vminvq_s32(a: int32x4_t) -> i32;
+        [neon] safe fn vminvq_s8(a: int8x16_t) -> i8;
+        [neon] safe fn vminvq_u16(a: uint16x8_t) -> u16;
+        [neon] safe fn vminvq_u32(a: uint32x4_t) -> u32;
+        [neon] safe fn vminvq_u8(a: uint8x16_t) -> u8;
+        [neon] safe fn vmla_f32(a: float32x2_t, b: float32x2_t, c: float32x2_t) -> float32x2_t;
+        [neon] safe fn vmla_f64(a: float64x1_t, b: float64x1_t, c: float64x1_t) -> float64x1_t;
+        [neon] safe fn vmla_lane_f32<const LANE: i32>(a: float32x2_t, b: float32x2_t, c: float32x2_t) -> float32x2_t;
+        [neon] safe fn vmla_lane_s16<const LANE: i32>(a: int16x4_t, b: int16x4_t, c: int16x4_t) -> int16x4_t;
+        [neon] safe fn vmla_lane_s32<const LANE: i32>(a: int32x2_t, b: int32x2_t, c: int32x2_t) -> int32x2_t;
+        [neon] safe fn vmla_lane_u16<const LANE: i32>(a: uint16x4_t, b: uint16x4_t, c: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vmla_lane_u32<const LANE: i32>(a: uint32x2_t, b: uint32x2_t, c: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vmla_laneq_f32<const LANE: i32>(a: float32x2_t, b: float32x2_t, c: float32x4_t) -> float32x2_t;
+        [neon] safe fn vmla_laneq_s16<const LANE: i32>(a: int16x4_t, b: int16x4_t, c: int16x8_t) -> int16x4_t;
+        [neon] safe fn vmla_laneq_s32<const LANE: i32>(a: int32x2_t, b: int32x2_t, c: int32x4_t) -> int32x2_t;
+        [neon] safe fn vmla_laneq_u16<const LANE: i32>(a: uint16x4_t, b: uint16x4_t, c: uint16x8_t) -> uint16x4_t;
+        [neon] safe fn vmla_laneq_u32<const LANE: i32>(a: uint32x2_t, b: uint32x2_t, c: uint32x4_t) -> uint32x2_t;
+        [neon] safe fn vmla_n_f32(a: float32x2_t, b: float32x2_t, c: f32) -> float32x2_t;
+        [neon] safe fn vmla_n_s16(a: int16x4_t, b: int16x4_t, c: i16) -> int16x4_t;
+        [neon] safe fn vmla_n_s32(a: int32x2_t, b: int32x2_t, c: i32) -> int32x2_t;
+        [neon] safe fn vmla_n_u16(a: uint16x4_t, b: uint16x4_t, c: u16) -> uint16x4_t;
+        [neon] safe fn vmla_n_u32(a: uint32x2_t, b: uint32x2_t, c: u32) -> uint32x2_t;
+        [neon] safe fn vmla_s16(a: int16x4_t, b: int16x4_t, c: int16x4_t) -> int16x4_t;
+        [neon] safe fn vmla_s32(a: int32x2_t, b: int32x2_t, c: int32x2_t) -> int32x2_t;
+        [neon] safe fn vmla_s8(a: int8x8_t, b: int8x8_t, c: int8x8_t) -> int8x8_t;
+        [neon] safe fn vmla_u16(a: uint16x4_t, b: uint16x4_t, c: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vmla_u32(a: uint32x2_t, b: uint32x2_t, c: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vmla_u8(a: uint8x8_t, b: uint8x8_t, c: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vmlal_high_lane_s16<const LANE: i32>(a: int32x4_t, b: int16x8_t, c: int16x4_t) -> int32x4_t;
+        [neon] safe fn vmlal_high_lane_s32<const LANE: i32>(a: int64x2_t, b: int32x4_t, c: int32x2_t) -> int64x2_t;
+        [neon] safe fn vmlal_high_lane_u16<const LANE: i32>(a: uint32x4_t, b: uint16x8_t, c: uint16x4_t) -> uint32x4_t;
+        [neon] safe fn vmlal_high_lane_u32<const LANE: i32>(a: uint64x2_t, b: uint32x4_t, c: uint32x2_t) -> uint64x2_t;
+        [neon] safe fn vmlal_high_laneq_s16<const LANE: i32>(a: int32x4_t, b: int16x8_t, c: int16x8_t) -> int32x4_t;
+        [neon] safe fn vmlal_high_laneq_s32<const LANE: i32>(a: int64x2_t, b: int32x4_t, c: int32x4_t) -> int64x2_t;
+        [neon] safe fn vmlal_high_laneq_u16<const LANE: i32>(a: uint32x4_t, b: uint16x8_t, c: uint16x8_t) -> uint32x4_t;
+        [neon] safe fn vmlal_high_laneq_u32<const LANE: i32>(a: uint64x2_t, b: uint32x4_t, c: uint32x4_t) -> uint64x2_t;
+        [neon] safe fn vmlal_high_n_s16(a: int32x4_t, b: int16x8_t, c: i16) -> int32x4_t;
+        [neon] safe fn vmlal_high_n_s32(a: int64x2_t, b: int32x4_t, c: i32) -> int64x2_t;
+        [neon] safe fn vmlal_high_n_u16(a: uint32x4_t, b: uint16x8_t, c: u16) -> uint32x4_t;
+        [neon] safe fn vmlal_high_n_u32(a: uint64x2_t, b: uint32x4_t, c: u32) -> uint64x2_t;
+        [neon] safe fn vmlal_high_s16(a: int32x4_t, b: int16x8_t, c: int16x8_t) -> int32x4_t;
+        [neon] safe fn vmlal_high_s32(a: int64x2_t, b: int32x4_t, c: int32x4_t) -> int64x2_t;
+        [neon] safe fn vmlal_high_s8(a: int16x8_t, b: int8x16_t, c: int8x16_t) -> int16x8_t;
+        [neon] safe fn vmlal_high_u16(a: uint32x4_t, b: uint16x8_t, c: uint16x8_t) -> uint32x4_t;
+        [neon] safe fn vmlal_high_u32(a: uint64x2_t, b: uint32x4_t, c: uint32x4_t) -> uint64x2_t;
+        [neon] safe fn vmlal_high_u8(a: uint16x8_t, b: uint8x16_t, c: uint8x16_t) -> uint16x8_t;
+        [neon] safe fn vmlal_lane_s16<const LANE: i32>(a: int32x4_t, b: int16x4_t, c: int16x4_t) -> int32x4_t;
+        [neon] safe fn vmlal_lane_s32<const LANE: i32>(a: int64x2_t, b: int32x2_t, c: int32x2_t) -> int64x2_t;
+        [neon] safe fn vmlal_lane_u16<const LANE: i32>(a: uint32x4_t, b: uint16x4_t, c: uint16x4_t) -> uint32x4_t;
+        [neon] safe fn vmlal_lane_u32<const LANE: i32>(a: uint64x2_t, b: uint32x2_t, c: uint32x2_t) -> uint64x2_t;
+        [neon] safe fn vmlal_laneq_s16<const LANE: i32>(a: int32x4_t, b: int16x4_t, c: int16x8_t) -> int32x4_t;
+        [neon] safe fn vmlal_laneq_s32<const LANE: i32>(a: int64x2_t, b: int32x2_t, c: int32x4_t) -> int64x2_t;
+        [neon] safe fn vmlal_laneq_u16<const LANE: i32>(a: uint32x4_t, b: uint16x4_t, c: uint16x8_t) -> uint32x4_t;
+        [neon] safe fn vmlal_laneq_u32<const LANE: i32>(a: uint64x2_t, b: uint32x2_t, c: uint32x4_t) -> uint64x2_t;
+        [neon] safe fn vmlal_n_s16(a: int32x4_t, b: int16x4_t, c: i16) -> int32x4_t;
+        [neon] safe fn vmlal_n_s32(a: int64x2_t, b: int32x2_t, c: i32) -> int64x2_t;
+        [neon] safe fn vmlal_n_u16(a: uint32x4_t, b: uint16x4_t, c: u16) -> uint32x4_t;
+        [neon] safe fn vmlal_n_u32(a: uint64x2_t, b: uint32x2_t, c: u32) -> uint64x2_t;
+        [neon] safe fn vmlal_s16(a: int32x4_t, b: int16x4_t, c: int16x4_t) -> int32x4_t;
+        [neon] safe fn vmlal_s32(a: int64x2_t, b: int32x2_t, c: int32x2_t) -> int64x2_t;
+        [neon] safe fn vmlal_s8(a: int16x8_t, b: int8x8_t, c: int8x8_t) -> int16x8_t;
+        [neon] safe fn vmlal_u16(a: uint32x4_t, b: uint16x4_t, c: uint16x4_t) -> uint32x4_t;
+        [neon] safe fn vmlal_u32(a: uint64x2_t, b: uint32x2_t, c: uint32x2_t) -> uint64x2_t;
+        [neon] safe fn vmlal_u8(a: uint16x8_t, b: uint8x8_t, c: uint8x8_t) -> uint16x8_t;
+        [neon] safe fn vmlaq_f32(a: float32x4_t, b: float32x4_t, c: float32x4_t) -> float32x4_t;
+        [neon] safe fn vmlaq_f64(a: float64x2_t, b: float64x2_t, c: float64x2_t) -> float64x2_t;
+        [neon] safe fn vmlaq_lane_f32<const LANE: i32>(a: float32x4_t, b: float32x4_t, c: float32x2_t) -> float32x4_t;
+        [neon] safe fn vmlaq_lane_s16<const LANE: i32>(a: int16x8_t, b: int16x8_t, c: int16x4_t) -> int16x8_t;
+        [neon] safe fn vmlaq_lane_s32<const LANE: i32>(a: int32x4_t, b: int32x4_t, c: int32x2_t) -> int32x4_t;
+        [neon] safe fn vmlaq_lane_u16<const LANE: i32>(a: uint16x8_t, b: uint16x8_t, c: uint16x4_t) -> uint16x8_t;
+        [neon] safe fn vmlaq_lane_u32<const LANE: i32>(a: uint32x4_t, b: uint32x4_t, c: uint32x2_t) -> uint32x4_t;
+        [neon] safe fn vmlaq_laneq_f32<const LANE: i32>(a: float32x4_t, b: float32x4_t, c: float32x4_t) -> float32x4_t;
+        [neon] safe fn vmlaq_laneq_s16<const LANE: i32>(a: int16x8_t, b: int16x8_t, c: int16x8_t) -> int16x8_t;
+        [neon] safe fn vmlaq_laneq_s32<const LANE: i32>(a: int32x4_t, b: int32x4_t, c: int32x4_t) -> int32x4_t;
+        [neon] safe fn vmlaq_laneq_u16<const LANE: i32>(a: uint16x8_t, b: uint16x8_t, c: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vmlaq_laneq_u32<const LANE: i32>(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vmlaq_n_f32(a: float32x4_t, b: float32x4_t, c: f32) -> float32x4_t;
+        [neon] safe fn vmlaq_n_s16(a: int16x8_t, b: int16x8_t, c: i16) -> int16x8_t;
+        [neon] safe fn vmlaq_n_s32(a: int32x4_t, b: int32x4_t, c: i32) -> int32x4_t;
+        [neon] safe fn vmlaq_n_u16(a: uint16x8_t, b: uint16x8_t, c: u16) -> uint16x8_t;
+        [neon] safe fn vmlaq_n_u32(a: uint32x4_t, b: uint32x4_t, c: u32) -> uint32x4_t;
+        [neon] safe fn vmlaq_s16(a: int16x8_t, b: int16x8_t, c: int16x8_t) -> int16x8_t;
+        [neon] safe fn vmlaq_s32(a: int32x4_t, b: int32x4_t, c: int32x4_t) -> int32x4_t;
+        [neon] safe fn vmlaq_s8(a: int8x16_t, b: int8x16_t, c: int8x16_t) -> int8x16_t;
+        [neon] safe fn vmlaq_u16(a: uint16x8_t, b: uint16x8_t, c: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vmlaq_u32(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vmlaq_u8(a: uint8x16_t, b: uint8x16_t, c: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vmls_f32(a: float32x2_t, b: float32x2_t, c: float32x2_t) -> float32x2_t;
+        [neon] safe fn vmls_f64(a: float64x1_t, b: float64x1_t, c: float64x1_t) -> float64x1_t;
+        [neon] safe fn vmls_lane_f32<const LANE: i32>(a: float32x2_t, b: float32x2_t, c: float32x2_t) -> float32x2_t;
+        [neon] safe fn vmls_lane_s16<const LANE: i32>(a: int16x4_t, b: int16x4_t, c: int16x4_t) -> int16x4_t;
+        [neon] safe fn vmls_lane_s32<const LANE: i32>(a: int32x2_t, b: int32x2_t, c: int32x2_t) -> int32x2_t;
+        [neon] safe fn vmls_lane_u16<const LANE: i32>(a: uint16x4_t, b: uint16x4_t, c: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vmls_lane_u32<const LANE: i32>(a: uint32x2_t, b: uint32x2_t, c: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vmls_laneq_f32<const LANE: i32>(a: float32x2_t, b: float32x2_t, c: float32x4_t) -> float32x2_t;
+        [neon] safe fn vmls_laneq_s16<const LANE: i32>(a: int16x4_t, b: int16x4_t, c: int16x8_t) -> int16x4_t;
+        [neon] safe fn vmls_laneq_s32<const LANE: i32>(a: int32x2_t, b: int32x2_t, c: int32x4_t) -> int32x2_t;
+        [neon] safe fn vmls_laneq_u16<const LANE: i32>(a: uint16x4_t, b: uint16x4_t, c: uint16x8_t) -> uint16x4_t;
+        [neon] safe fn vmls_laneq_u32<const LANE: i32>(a: uint32x2_t, b: uint32x2_t, c: uint32x4_t) -> uint32x2_t;
+        [neon] safe fn vmls_n_f32(a: float32x2_t, b: float32x2_t, c: f32) -> float32x2_t;
+        [neon] safe fn vmls_n_s16(a: int16x4_t, b: int16x4_t, c: i16) -> int16x4_t;
+        [neon] safe fn vmls_n_s32(a: int32x2_t, b: int32x2_t, c: i32) -> int32x2_t;
+        [neon] safe fn vmls_n_u16(a: uint16x4_t, b: uint16x4_t, c: u16) -> uint16x4_t;
+        [neon] safe fn vmls_n_u32(a: uint32x2_t, b: uint32x2_t, c: u32) -> uint32x2_t;
+        [neon] safe fn vmls_s16(a: int16x4_t, b: int16x4_t, c: int16x4_t) -> int16x4_t;
+        [neon] safe fn vmls_s32(a: int32x2_t, b: int32x2_t, c: int32x2_t) -> int32x2_t;
+        [neon] safe fn vmls_s8(a: int8x8_t, b: int8x8_t, c: int8x8_t) -> int8x8_t;
+        [neon] safe fn vmls_u16(a: uint16x4_t, b: uint16x4_t, c: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vmls_u32(a: uint32x2_t, b: uint32x2_t, c: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vmls_u8(a: uint8x8_t, b: uint8x8_t, c: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vmlsl_high_lane_s16<const LANE: i32>(a: int32x4_t, b: int16x8_t, c: int16x4_t) -> int32x4_t;
+        [neon] safe fn vmlsl_high_lane_s32<const LANE: i32>(a: int64x2_t, b: int32x4_t, c: int32x2_t) -> int64x2_t;
+        [neon] safe fn vmlsl_high_lane_u16<const LANE: i32>(a: uint32x4_t, b: uint16x8_t, c: uint16x4_t) -> uint32x4_t;
+        [neon] safe fn vmlsl_high_lane_u32<const LANE: i32>(a: uint64x2_t, b: uint32x4_t, c: uint32x2_t) -> uint64x2_t;
+        [neon] safe fn vmlsl_high_laneq_s16<const LANE: i32>(a: int32x4_t, b: int16x8_t, c: int16x8_t) -> int32x4_t;
+        [neon] safe fn vmlsl_high_laneq_s32<const LANE: i32>(a: int64x2_t, b: int32x4_t, c: int32x4_t) -> int64x2_t;
+        [neon] safe fn vmlsl_high_laneq_u16<const LANE: i32>(a: uint32x4_t, b: uint16x8_t, c: uint16x8_t) -> uint32x4_t;
+        [neon] safe fn vmlsl_high_laneq_u32<const LANE: i32>(a: uint64x2_t, b: uint32x4_t, c: uint32x4_t) -> uint64x2_t;
+        [neon] safe fn vmlsl_high_n_s16(a: int32x4_t, b: int16x8_t, c: i16) -> int32x4_t;
+        [neon] safe fn vmlsl_high_n_s32(a: int64x2_t, b: int32x4_t, c: i32) -> int64x2_t;
+        [neon] safe fn vmlsl_high_n_u16(a: uint32x4_t, b: uint16x8_t, c: u16) -> uint32x4_t;
+        [neon] safe fn vmlsl_high_n_u32(a: uint64x2_t, b: uint32x4_t, c: u32) -> uint64x2_t;
+        [neon] safe fn vmlsl_high_s16(a: int32x4_t, b: int16x8_t, c: int16x8_t) -> int32x4_t;
+        [neon] safe fn vmlsl_high_s32(a: int64x2_t, b: int32x4_t, c: int32x4_t) -> int64x2_t;
+        [neon] safe fn vmlsl_high_s8(a: int16x8_t, b: int8x16_t, c: int8x16_t) -> int16x8_t;
+        [neon] safe fn vmlsl_high_u16(a: uint32x4_t, b: uint16x8_t, c: uint16x8_t) -> uint32x4_t;
+        [neon] safe fn vmlsl_high_u32(a: uint64x2_t, b: uint32x4_t, c: uint32x4_t) -> uint64x2_t;
+        [neon] safe fn vmlsl_high_u8(a: uint16x8_t, b: uint8x16_t, c: uint8x16_t) -> uint16x8_t;
+        [neon] safe fn vmlsl_lane_s16<const LANE: i32>(a: int32x4_t, b: int16x4_t, c: int16x4_t) -> int32x4_t;
+        [neon] safe fn vmlsl_lane_s32<const LANE: i32>(a: int64x2_t, b: int32x2_t, c: int32x2_t) -> int64x2_t;
+        [neon] safe fn vmlsl_lane_u16<const LANE: i32>(a: uint32x4_t, b: uint16x4_t, c: uint16x4_t) -> uint32x4_t;
+        [neon] safe fn vmlsl_lane_u32<const LANE: i32>(a: uint64x2_t, b: uint32x2_t, c: uint32x2_t) -> uint64x2_t;
+        [neon] safe fn vmlsl_laneq_s16<const LANE: i32>(a: int32x4_t, b: int16x4_t, c: int16x8_t) -> int32x4_t;
+        [neon] safe fn vmlsl_laneq_s32<const LANE: i32>(a: int64x2_t, b: int32x2_t, c: int32x4_t) -> int64x2_t;
+        [neon] safe fn vmlsl_laneq_u16<const LANE: i32>(a: uint32x4_t, b: uint16x4_t, c: uint16x8_t) -> uint32x4_t;
+        [neon] safe fn vmlsl_laneq_u32<const LANE: i32>(a: uint64x2_t, b: uint32x2_t, c: uint32x4_t) -> uint64x2_t;
+        [neon] safe fn vmlsl_n_s16(a: int32x4_t, b: int16x4_t, c: i16) -> int32x4_t;
+        [neon] safe fn vmlsl_n_s32(a: int64x2_t, b: int32x2_t, c: i32) -> int64x2_t;
+        [neon] safe fn vmlsl_n_u16(a: uint32x4_t, b: uint16x4_t, c: u16) -> uint32x4_t;
+        [neon] safe fn vmlsl_n_u32(a: uint64x2_t, b: uint32x2_t, c: u32) -> uint64x2_t;
+        [neon] safe fn vmlsl_s16(a: int32x4_t, b: int16x4_t, c: int16x4_t) -> int32x4_t;
+        [neon] safe fn vmlsl_s32(a: int64x2_t, b: int32x2_t, c: int32x2_t) -> int64x2_t;
+        [neon] safe fn vmlsl_s8(a: int16x8_t, b: int8x8_t, c: int8x8_t) -> int16x8_t;
+        [neon] safe fn vmlsl_u16(a: uint32x4_t, b: uint16x4_t, c: uint16x4_t) -> uint32x4_t;
+        [neon] safe fn vmlsl_u32(a: uint64x2_t, b: uint32x2_t, c: uint32x2_t) -> uint64x2_t;
+        [neon] safe fn vmlsl_u8(a: uint16x8_t, b: uint8x8_t, c: uint8x8_t) -> uint16x8_t;
+        [neon] safe fn vmlsq_f32(a: float32x4_t, b: float32x4_t, c: float32x4_t) -> float32x4_t;
+        [neon] safe fn vmlsq_f64(a: float64x2_t, b: float64x2_t, c: float64x2_t) -> float64x2_t;
+        [neon] safe fn vmlsq_lane_f32<const LANE: i32>(a: float32x4_t, b: float32x4_t, c: float32x2_t) -> float32x4_t;
+        [neon] safe fn vmlsq_lane_s16<const LANE: i32>(a: int16x8_t, b: int16x8_t, c: int16x4_t) -> int16x8_t;
+        [neon] safe fn vmlsq_lane_s32<const LANE: i32>(a: int32x4_t, b: int32x4_t, c: int32x2_t) -> int32x4_t;
+        [neon] safe fn vmlsq_lane_u16<const LANE: i32>(a: uint16x8_t, b: uint16x8_t, c: uint16x4_t) -> uint16x8_t;
+        [neon] safe fn vmlsq_lane_u32<const LANE: i32>(a: uint32x4_t, b: uint32x4_t, c: uint32x2_t) -> uint32x4_t;
+        [neon] safe fn vmlsq_laneq_f32<const LANE: i32>(a: float32x4_t, b: float32x4_t, c: float32x4_t) -> float32x4_t;
+        [neon] safe fn vmlsq_laneq_s16<const LANE: i32>(a: int16x8_t, b: int16x8_t, c: int16x8_t) -> int16x8_t;
+        [neon] safe fn vmlsq_laneq_s32<const LANE: i32>(a: int32x4_t, b: int32x4_t, c: int32x4_t) -> int32x4_t;
+        [neon] safe fn vmlsq_laneq_u16<const LANE: i32>(a: uint16x8_t, b: uint16x8_t, c: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vmlsq_laneq_u32<const LANE: i32>(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vmlsq_n_f32(a: float32x4_t, b: float32x4_t, c: f32) -> float32x4_t;
+        [neon] safe fn vmlsq_n_s16(a: int16x8_t, b: int16x8_t, c: i16) -> int16x8_t;
+        [neon] safe fn vmlsq_n_s32(a: int32x4_t, b: int32x4_t, c: i32) -> int32x4_t;
+        [neon] safe fn vmlsq_n_u16(a: uint16x8_t, b: uint16x8_t, c: u16) -> uint16x8_t;
+        [neon] safe fn vmlsq_n_u32(a: uint32x4_t, b: uint32x4_t, c: u32) -> uint32x4_t;
+        [neon] safe fn vmlsq_s16(a: int16x8_t, b: int16x8_t, c: int16x8_t) -> int16x8_t;
+        [neon] safe fn vmlsq_s32(a: int32x4_t, b: int32x4_t, c: int32x4_t) -> int32x4_t;
+        [neon] safe fn vmlsq_s8(a: int8x16_t, b: int8x16_t, c: int8x16_t) -> int8x16_t;
+        [neon] safe fn vmlsq_u16(a: uint16x8_t, b: uint16x8_t, c: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vmlsq_u32(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vmlsq_u8(a: uint8x16_t, b: uint8x16_t, c: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vmov_n_f32(value: f32) -> float32x2_t;
+        [neon] safe fn vmov_n_f64(value: f64) -> float64x1_t;
+        [neon] safe fn vmov_n_p16(value: u16) -> poly16x4_t;
+        [neon] safe fn vmov_n_p64(value: u64) -> poly64x1_t;
+        [neon] safe fn vmov_n_p8(value: u8) -> poly8x8_t;
+        [neon] safe fn vmov_n_s16(value: i16) -> int16x4_t;
+        [neon] safe fn vmov_n_s32(value: i32) -> int32x2_t;
+        [neon] safe fn vmov_n_s64(value: i64) -> int64x1_t;
+        [neon] safe fn vmov_n_s8(value: i8) -> int8x8_t;
+        [neon] safe fn vmov_n_u16(value: u16) -> uint16x4_t;
+        [neon] safe fn vmov_n_u32(value: u32) -> uint32x2_t;
+        [neon] safe fn vmov_n_u64(value: u64) -> uint64x1_t;
+        [neon] safe fn vmov_n_u8(value: u8) -> uint8x8_t;
+        [neon] safe fn vmovl_high_s16(a: int16x8_t) -> int32x4_t;
+        [neon] safe fn vmovl_high_s32(a: int32x4_t) -> int64x2_t;
+        [neon] safe fn vmovl_high_s8(a: int8x16_t) -> int16x8_t;
+        [neon] safe fn vmovl_high_u16(a: uint16x8_t) -> uint32x4_t;
+        [neon] safe fn vmovl_high_u32(a: uint32x4_t) -> uint64x2_t;
+        [neon] safe fn vmovl_high_u8(a: uint8x16_t) -> uint16x8_t;
+        [neon] safe fn vmovl_s16(a: int16x4_t) -> int32x4_t;
+        [neon] safe fn vmovl_s32(a: int32x2_t) -> int64x2_t;
+        [neon] safe fn vmovl_s8(a: int8x8_t) -> int16x8_t;
+        [neon] safe fn vmovl_u16(a: uint16x4_t) -> uint32x4_t;
+        [neon] safe fn vmovl_u32(a: uint32x2_t) -> uint64x2_t;
+        [neon] safe fn vmovl_u8(a: uint8x8_t) -> uint16x8_t;
+        [neon] safe fn vmovn_high_s16(a: int8x8_t, b: int16x8_t) -> int8x16_t;
+        [neon] safe fn vmovn_high_s32(a: int16x4_t, b: int32x4_t) -> int16x8_t;
+        [neon] safe fn vmovn_high_s64(a: int32x2_t, b: int64x2_t) -> int32x4_t;
+        [neon] safe fn vmovn_high_u16(a: uint8x8_t, b: uint16x8_t) -> uint8x16_t;
+        [neon] safe fn vmovn_high_u32(a: uint16x4_t, b: uint32x4_t) -> uint16x8_t;
+        [neon] safe fn vmovn_high_u64(a: uint32x2_t, b: uint64x2_t) -> uint32x4_t;
+        [neon] safe fn vmovn_s16(a: int16x8_t) -> int8x8_t;
+        [neon] safe fn vmovn_s32(a: int32x4_t) -> int16x4_t;
+        [neon] safe fn vmovn_s64(a: int64x2_t) -> int32x2_t;
+        [neon] safe fn vmovn_u16(a: uint16x8_t) -> uint8x8_t;
+        [neon] safe fn vmovn_u32(a: uint32x4_t) -> uint16x4_t;
+        [neon] safe fn vmovn_u64(a: uint64x2_t) -> uint32x2_t;
+        [neon] safe fn vmovq_n_f32(value: f32) -> float32x4_t;
+        [neon] safe fn vmovq_n_f64(value: f64) -> float64x2_t;
+        [neon] safe fn vmovq_n_p16(value: u16) -> poly16x8_t;
+        [neon] safe fn vmovq_n_p64(value: u64) -> poly64x2_t;
+        [neon] safe fn vmovq_n_p8(value: u8) -> poly8x16_t;
+        [neon] safe fn vmovq_n_s16(value: i16) -> int16x8_t;
+        [neon] safe fn vmovq_n_s32(value: i32) -> int32x4_t;
+        [neon] safe fn vmovq_n_s64(value: i64) -> int64x2_t;
+        [neon] safe fn vmovq_n_s8(value: i8) -> int8x16_t;
+        [neon] safe fn vmovq_n_u16(value: u16) -> uint16x8_t;
+        [neon] safe fn vmovq_n_u32(value: u32) -> uint32x4_t;
+        [neon] safe fn vmovq_n_u64(value: u64) -> uint64x2_t;
+        [neon] safe fn vmovq_n_u8(value: u8) -> uint8x16_t;
+        [neon] safe fn vmul_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vmul_f64(a: float64x1_t, b: float64x1_t) -> float64x1_t;
+        [neon] safe fn vmul_lane_f32<const LANE: i32>(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vmul_lane_f64<const LANE: i32>(a: float64x1_t, b: float64x1_t) -> float64x1_t;
+        [neon] safe fn vmul_lane_s16<const LANE: i32>(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vmul_lane_s32<const LANE: i32>(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vmul_lane_u16<const LANE: i32>(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vmul_lane_u32<const LANE: i32>(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vmul_laneq_f32<const LANE: i32>(a: float32x2_t, b: float32x4_t) -> float32x2_t;
+        [neon] safe fn vmul_laneq_f64<const LANE: i32>(a: float64x1_t, b: float64x2_t) -> float64x1_t;
+        [neon] safe fn vmul_laneq_s16<const LANE: i32>(a: int16x4_t, b: int16x8_t) -> int16x4_t;
+        [neon] safe fn vmul_laneq_s32<const LANE: i32>(a: int32x2_t, b: int32x4_t) -> int32x2_t;
+        [neon] safe fn vmul_laneq_u16<const LANE: i32>(a: uint16x4_t, b: uint16x8_t) -> uint16x4_t;
+        [neon] safe fn vmul_laneq_u32<const LANE: i32>(a: uint32x2_t, b: uint32x4_t) -> uint32x2_t;
+        [neon] safe fn vmul_n_f32(a: float32x2_t, b: f32) -> float32x2_t;
+        [neon] safe fn vmul_n_f64(a: float64x1_t, b: f64) -> float64x1_t;
+        [neon] safe fn vmul_n_s16(a: int16x4_t, b: i16) -> int16x4_t;
+        [neon] safe fn vmul_n_s32(a: int32x2_t, b: i32) -> int32x2_t;
+        [neon] safe fn vmul_n_u16(a: uint16x4_t, b: u16) -> uint16x4_t;
+        [neon] safe fn vmul_n_u32(a: uint32x2_t, b: u32) -> uint32x2_t;
+        [neon] safe fn vmul_p8(a: poly8x8_t, b: poly8x8_t) -> poly8x8_t;
+        [neon] safe fn vmul_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vmul_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vmul_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vmul_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vmul_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vmul_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vmuld_lane_f64<const LANE: i32>(a: f64, b: float64x1_t) -> f64;
+        [neon] safe fn vmuld_laneq_f64<const LANE: i32>(a: f64, b: float64x2_t) -> f64;
+        [neon] safe fn vmull_high_lane_s16<const LANE: i32>(a: int16x8_t, b: int16x4_t) -> int32x4_t;
+        [neon] safe fn vmull_high_lane_s32<const LANE: i32>(a: int32x4_t, b: int32x2_t) -> int64x2_t;
+        [neon] safe fn vmull_high_lane_u16<const LANE: i32>(a: uint16x8_t, b: uint16x4_t) -> uint32x4_t;
+        [neon] safe fn vmull_high_lane_u32<const LANE: i32>(a: uint32x4_t, b: uint32x2_t) -> uint64x2_t;
+        [neon] safe fn vmull_high_laneq_s16<const LANE: i32>(a: int16x8_t, b: int16x8_t) -> int32x4_t;
+        [neon] safe fn vmull_high_laneq_s32<const LANE: i32>(a: int32x4_t, b: int32x4_t) -> int64x2_t;
+        [neon] safe fn vmull_high_laneq_u16<const LANE: i32>(a: uint16x8_t, b: uint16x8_t) -> uint32x4_t;
+        [neon] safe fn vmull_high_laneq_u32<const LANE: i32>(a: uint32x4_t, b: uint32x4_t) -> uint64x2_t;
+        [neon] safe fn vmull_high_n_s16(a: int16x8_t, b: i16) -> int32x4_t;
+        [neon] safe fn vmull_high_n_s32(a: int32x4_t, b: i32) -> int64x2_t;
+        [neon] safe fn vmull_high_n_u16(a: uint16x8_t, b: u16) -> uint32x4_t;
+        [neon] safe fn vmull_high_n_u32(a: uint32x4_t, b: u32) -> uint64x2_t;
+        [neon, aes] safe fn vmull_high_p64(a: poly64x2_t, b: poly64x2_t) -> u128;
+        [neon] safe fn vmull_high_p8(a: poly8x16_t, b: poly8x16_t) -> poly16x8_t;
+        [neon] safe fn vmull_high_s16(a: int16x8_t, b: int16x8_t) -> int32x4_t;
+        [neon] safe fn vmull_high_s32(a: int32x4_t, b: int32x4_t) -> int64x2_t;
+        [neon] safe fn vmull_high_s8(a: int8x16_t, b: int8x16_t) -> int16x8_t;
+        [neon] safe fn vmull_high_u16(a: uint16x8_t, b: uint16x8_t) -> uint32x4_t;
+        [neon] safe fn vmull_high_u32(a: uint32x4_t, b: uint32x4_t) -> uint64x2_t;
+        [neon] safe fn vmull_high_u8(a: uint8x16_t, b: uint8x16_t) -> uint16x8_t;
+        [neon] safe fn vmull_lane_s16<const LANE: i32>(a: int16x4_t, b: int16x4_t) -> int32x4_t;
+        [neon] safe fn vmull_lane_s32<const LANE: i32>(a: int32x2_t, b: int32x2_t) -> int64x2_t;
+        [neon] safe fn vmull_lane_u16<const LANE: i32>(a: uint16x4_t, b: uint16x4_t) -> uint32x4_t;
+        [neon] safe fn vmull_lane_u32<const LANE: i32>(a: uint32x2_t, b: uint32x2_t) -> uint64x2_t;
+        [neon] safe fn vmull_laneq_s16<const LANE: i32>(a: int16x4_t, b: int16x8_t) -> int32x4_t;
+        [neon] safe fn vmull_laneq_s32<const LANE: i32>(a: int32x2_t, b: int32x4_t) -> int64x2_t;
+        [neon] safe fn vmull_laneq_u16<const LANE: i32>(a: uint16x4_t, b: uint16x8_t) -> uint32x4_t;
+        [neon] safe fn vmull_laneq_u32<const LANE: i32>(a: uint32x2_t, b: uint32x4_t) -> uint64x2_t;
+        [neon] safe fn vmull_n_s16(a: int16x4_t, b: i16) -> int32x4_t;
+        [neon] safe fn vmull_n_s32(a: int32x2_t, b: i32) -> int64x2_t;
+        [neon] safe fn vmull_n_u16(a: uint16x4_t, b: u16) -> uint32x4_t;
+        [neon] safe fn vmull_n_u32(a: uint32x2_t, b: u32) -> uint64x2_t;
+        [neon, aes] safe fn vmull_p64(a: u64, b: u64) -> u128;
+        [neon] safe fn vmull_p8(a: poly8x8_t, b: poly8x8_t) -> poly16x8_t;
+        [neon] safe fn vmull_s16(a: int16x4_t, b: int16x4_t) -> int32x4_t;
+        [neon] safe fn vmull_s32(a: int32x2_t, b: int32x2_t) -> int64x2_t;
+        [neon] safe fn vmull_s8(a: int8x8_t, b: int8x8_t) -> int16x8_t;
+        [neon] safe fn vmull_u16(a: uint16x4_t, b: uint16x4_t) -> uint32x4_t;
+        [neon] safe fn vmull_u32(a: uint32x2_t, b: uint32x2_t) -> uint64x2_t;
+        [neon] safe fn vmull_u8(a: uint8x8_t, b: uint8x8_t) -> uint16x8_t;
+        [neon] safe fn vmulq_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vmulq_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vmulq_lane_f32<const LANE: i32>(a: float32x4_t, b: float32x2_t) -> float32x4_t;
+        [neon] safe fn vmulq_lane_f64<const LANE: i32>(a: float64x2_t, b: float64x1_t) -> float64x2_t;
+        [neon] safe fn vmulq_lane_s16<const LANE: i32>(a: int16x8_t, b: int16x4_t) -> int16x8_t;
+        [neon] safe fn vmulq_lane_s32<const LANE: i32>(a: int32x4_t, b: int32x2_t) -> int32x4_t;
+        [neon] safe fn vmulq_lane_u16<const LANE: i32>(a: uint16x8_t, b: uint16x4_t) -> uint16x8_t;
+        [neon] safe fn vmulq_lane_u32<const LANE: i32>(a: uint32x4_t, b: uint32x2_t) -> uint32x4_t;
+        [neon] safe fn vmulq_laneq_f32<const LANE: i32>(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vmulq_laneq_f64<const LANE: i32>(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vmulq_laneq_s16<const LANE: i32>(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vmulq_laneq_s32<const LANE: i32>(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vmulq_laneq_u16<const LANE: i32>(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vmulq_laneq_u32<const LANE: i32>(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vmulq_n_f32(a: float32x4_t, b: f32) -> float32x4_t;
+        [neon] safe fn vmulq_n_f64(a: float64x2_t, b: f64) -> float64x2_t;
+        [neon] safe fn vmulq_n_s16(a: int16x8_t, b: i16) -> int16x8_t;
+        [neon] safe fn vmulq_n_s32(a: int32x4_t, b: i32) -> int32x4_t;
+        [neon] safe fn vmulq_n_u16(a: uint16x8_t, b: u16) -> uint16x8_t;
+        [neon] safe fn vmulq_n_u32(a: uint32x4_t, b: u32) -> uint32x4_t;
+        [neon] safe fn vmulq_p8(a: poly8x16_t, b: poly8x16_t) -> poly8x16_t;
+        [neon] safe fn vmulq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vmulq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vmulq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vmulq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vmulq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vmulq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vmuls_lane_f32<const LANE: i32>(a: f32, b: float32x2_t) -> f32;
+        [neon] safe fn vmuls_laneq_f32<const LANE: i32>(a: f32, b: float32x4_t) -> f32;
+        [neon] safe fn vmulx_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vmulx_f64(a: float64x1_t, b: float64x1_t) -> float64x1_t;
+        [neon] safe fn vmulx_lane_f32<const LANE: i32>(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vmulx_lane_f64<const LANE: i32>(a: float64x1_t, b: float64x1_t) -> float64x1_t;
+        [neon] safe fn vmulx_laneq_f32<const LANE: i32>(a: float32x2_t, b: float32x4_t) -> float32x2_t;
+        [neon] safe fn vmulx_laneq_f64<const LANE: i32>(a: float64x1_t, b: float64x2_t) -> float64x1_t;
+        [neon] safe fn vmulxd_f64(a: f64, b: f64) -> f64;
+        [neon] safe fn vmulxd_lane_f64<const LANE: i32>(a: f64, b: float64x1_t) -> f64;
+        [neon] safe fn vmulxd_laneq_f64<const LANE: i32>(a: f64, b: float64x2_t) -> f64;
+        [neon] safe fn vmulxq_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vmulxq_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vmulxq_lane_f32<const LANE: i32>(a: float32x4_t, b: float32x2_t) -> float32x4_t;
+        [neon] safe fn vmulxq_lane_f64<const LANE: i32>(a: float64x2_t, b: float64x1_t) -> float64x2_t;
+        [neon] safe fn vmulxq_laneq_f32<const LANE: i32>(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vmulxq_laneq_f64<const LANE: i32>(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vmulxs_f32(a: f32, b: f32) -> f32;
+        [neon] safe fn vmulxs_lane_f32<const LANE: i32>(a: f32, b: float32x2_t) -> f32;
+        [neon] safe fn vmulxs_laneq_f32<const LANE: i32>(a: f32, b: float32x4_t) -> f32;
+        [neon] safe fn vmvn_p8(a: poly8x8_t) -> poly8x8_t;
+        [neon] safe fn vmvn_s16(a: int16x4_t) -> int16x4_t;
+        [neon] safe fn vmvn_s32(a: int32x2_t) -> int32x2_t;
+        [neon] safe fn vmvn_s8(a: int8x8_t) -> int8x8_t;
+        [neon] safe fn vmvn_u16(a: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vmvn_u32(a: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vmvn_u8(a: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vmvnq_p8(a: poly8x16_t) -> poly8x16_t;
+        [neon] safe fn vmvnq_s16(a: int16x8_t) -> int16x8_t;
+        [neon] safe fn vmvnq_s32(a: int32x4_t) -> int32x4_t;
+        [neon] safe fn vmvnq_s8(a: int8x16_t) -> int8x16_t;
+        [neon] safe fn vmvnq_u16(a: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vmvnq_u32(a: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vmvnq_u8(a: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vneg_f32(a: float32x2_t) -> float32x2_t;
+        [neon] safe fn vneg_f64(a: float64x1_t) -> float64x1_t;
+        [neon] safe fn vneg_s16(a: int16x4_t) -> int16x4_t;
+        [neon] safe fn vneg_s32(a: int32x2_t) -> int32x2_t;
+        [neon] safe fn vneg_s64(a: int64x1_t) -> int64x1_t;
+        [neon] safe fn vneg_s8(a: int8x8_t) -> int8x8_t;
+        [neon] safe fn vnegd_s64(a: i64) -> i64;
+        [neon] safe fn vnegq_f32(a: float32x4_t) -> float32x4_t;
+        [neon] safe fn vnegq_f64(a: float64x2_t) -> float64x2_t;
+        [neon] safe fn vnegq_s16(a: int16x8_t) -> int16x8_t;
+        [neon] safe fn vnegq_s32(a: int32x4_t) -> int32x4_t;
+        [neon] safe fn vnegq_s64(a: int64x2_t) -> int64x2_t;
+        [neon] safe fn vnegq_s8(a: int8x16_t) -> int8x16_t;
+        [neon] safe fn vorn_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vorn_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vorn_s64(a: int64x1_t, b: int64x1_t) -> int64x1_t;
+        [neon] safe fn vorn_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vorn_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vorn_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vorn_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vorn_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vornq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vornq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vornq_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vornq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vornq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vornq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vornq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vornq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vorr_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vorr_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vorr_s64(a: int64x1_t, b: int64x1_t) -> int64x1_t;
+        [neon] safe fn vorr_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vorr_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vorr_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vorr_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vorr_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vorrq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vorrq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vorrq_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vorrq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vorrq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vorrq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vorrq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vorrq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vpadal_s16(a: int32x2_t, b: int16x4_t) -> int32x2_t;
+        [neon] safe fn vpadal_s32(a: int64x1_t, b: int32x2_t) -> int64x1_t;
+        [neon] safe fn vpadal_s8(a: int16x4_t, b: int8x8_t) -> int16x4_t;
+        [neon] safe fn vpadal_u16(a: uint32x2_t, b: uint16x4_t) -> uint32x2_t;
+        [neon] safe fn vpadal_u32(a: uint64x1_t, b: uint32x2_t) -> uint64x1_t;
+        [neon] safe fn vpadal_u8(a: uint16x4_t, b: uint8x8_t) -> uint16x4_t;
+        [neon] safe fn vpadalq_s16(a: int32x4_t, b: int16x8_t) -> int32x4_t;
+        [neon] safe fn vpadalq_s32(a: int64x2_t, b: int32x4_t) -> int64x2_t;
+        [neon] safe fn vpadalq_s8(a: int16x8_t, b: int8x16_t) -> int16x8_t;
+        [neon] safe fn vpadalq_u16(a: uint32x4_t, b: uint16x8_t) -> uint32x4_t;
+        [neon] safe fn vpadalq_u32(a: uint64x2_t, b: uint32x4_t) -> uint64x2_t;
+        [neon] safe fn vpadalq_u8(a: uint16x8_t, b: uint8x16_t) -> uint16x8_t;
+        [neon] safe fn vpadd_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vpadd_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vpadd_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vpadd_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vpadd_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vpadd_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vpadd_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vpaddd_f64(a: float64x2_t) -> f64;
+        [neon] safe fn vpaddd_s64(a: int64x2_t) -> i64;
+        [neon] safe fn vpaddd_u64(a: uint64x2_t) -> u64;
+        [neon] safe fn vpaddl_s16(a: int16x4_t) -> int32x2_t;
+        [neon] safe fn vpaddl_s32(a: int32x2_t) -> int64x1_t;
+        [neon] safe fn vpaddl_s8(a: int8x8_t) -> int16x4_t;
+        [neon] safe fn vpaddl_u16(a: uint16x4_t) -> uint32x2_t;
+        [neon] safe fn vpaddl_u32(a: uint32x2_t) -> uint64x1_t;
+        [neon] safe fn vpaddl_u8(a: uint8x8_t) -> uint16x4_t;
+        [neon] safe fn vpaddlq_s16(a: int16x8_t) -> int32x4_t;
+        [neon] safe fn vpaddlq_s32(a: int32x4_t) -> int64x2_t;
+        [neon] safe fn vpaddlq_s8(a: int8x16_t) -> int16x8_t;
+        [neon] safe fn vpaddlq_u16(a: uint16x8_t) -> uint32x4_t;
+        [neon] safe fn vpaddlq_u32(a: uint32x4_t) -> uint64x2_t;
+        [neon] safe fn vpaddlq_u8(a: uint8x16_t) -> uint16x8_t;
+        [neon] safe fn vpaddq_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vpaddq_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vpaddq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vpaddq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vpaddq_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vpaddq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vpaddq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vpaddq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vpaddq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vpaddq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vpadds_f32(a: float32x2_t) -> f32;
+        [neon] safe fn vpmax_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vpmax_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vpmax_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vpmax_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vpmax_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vpmax_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vpmax_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vpmaxnm_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vpmaxnmq_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vpmaxnmq_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vpmaxnmqd_f64(a: float64x2_t) -> f64;
+        [neon] safe fn vpmaxnms_f32(a: float32x2_t) -> f32;
+        [neon] safe fn vpmaxq_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vpmaxq_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vpmaxq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vpmaxq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vpmaxq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vpmaxq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vpmaxq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vpmaxq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vpmaxqd_f64(a: float64x2_t) -> f64;
+        [neon] safe fn vpmaxs_f32(a: float32x2_t) -> f32;
+        [neon] safe fn vpmin_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vpmin_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vpmin_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vpmin_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vpmin_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vpmin_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vpmin_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vpminnm_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vpminnmq_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vpminnmq_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vpminnmqd_f64(a: float64x2_t) -> f64;
+        [neon] safe fn vpminnms_f32(a: float32x2_t) -> f32;
+        [neon] safe fn vpminq_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vpminq_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vpminq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vpminq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vpminq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vpminq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vpminq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vpminq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vpminqd_f64(a: float64x2_t) -> f64;
+        [neon] safe fn vpmins_f32(a: float32x2_t) -> f32;
+        [neon] safe fn vqabs_s16(a: int16x4_t) -> int16x4_t;
+        [neon] safe fn vqabs_s32(a: int32x2_t) -> int32x2_t;
+        [neon] safe fn vqabs_s64(a: int64x1_t) -> int64x1_t;
+        [neon] safe fn vqabs_s8(a: int8x8_t) -> int8x8_t;
+        [neon] safe fn vqabsb_s8(a: i8) -> i8;
+        [neon] safe fn vqabsd_s64(a: i64) -> i64;
+        [neon] safe fn vqabsh_s16(a: i16) -> i16;
+        [neon] safe fn vqabsq_s16(a: int16x8_t) -> int16x8_t;
+        [neon] safe fn vqabsq_s32(a: int32x4_t) -> int32x4_t;
+        [neon] safe fn vqabsq_s64(a: int64x2_t) -> int64x2_t;
+        [neon] safe fn vqabsq_s8(a: int8x16_t) -> int8x16_t;
+        [neon] safe fn vqabss_s32(a: i32) -> i32;
+        [neon] safe fn vqadd_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vqadd_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vqadd_s64(a: int64x1_t, b: int64x1_t) -> int64x1_t;
+        [neon] safe fn vqadd_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vqadd_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vqadd_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vqadd_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vqadd_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vqaddb_s8(a: i8, b: i8) -> i8;
+        [neon] safe fn vqaddb_u8(a: u8, b: u8) -> u8;
+        [neon] safe fn vqaddd_s64(a: i64, b: i64) -> i64;
+        [neon] safe fn vqaddd_u64(a: u64, b: u64) -> u64;
+        [neon] safe fn vqaddh_s16(a: i16, b: i16) -> i16;
+        [neon] safe fn vqaddh_u16(a: u16, b: u16) -> u16;
+        [neon] safe fn vqaddq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vqaddq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vqaddq_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vqaddq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vqaddq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vqaddq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vqaddq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vqaddq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vqadds_s32(a: i32, b: i32) -> i32;
+        [neon] safe fn vqadds_u32(a: u32, b: u32) -> u32;
+        [neon] safe fn vqdmlal_high_lane_s16<const N: i32>(a: int32x4_t, b: int16x8_t, c: int16x4_t) -> int32x4_t;
+        [neon] safe fn vqdmlal_high_lane_s32<const N: i32>(a: int64x2_t, b: int32x4_t, c: int32x2_t) -> int64x2_t;
+        [neon] safe fn vqdmlal_high_laneq_s16<const N: i32>(a: int32x4_t, b: int16x8_t, c: int16x8_t) -> int32x4_t;
+        [neon] safe fn vqdmlal_high_laneq_s32<const N: i32>(a: int64x2_t, b: int32x4_t, c: int32x4_t) -> int64x2_t;
+        [neon] safe fn vqdmlal_high_n_s16(a: int32x4_t, b: int16x8_t, c: i16) -> int32x4_t;
+        [neon] safe fn vqdmlal_high_n_s32(a: int64x2_t, b: int32x4_t, c: i32) -> int64x2_t;
+        [neon] safe fn vqdmlal_high_s16(a: int32x4_t, b: int16x8_t, c: int16x8_t) -> int32x4_t;
+        [neon] safe fn vqdmlal_high_s32(a: int64x2_t, b: int32x4_t, c: int32x4_t) -> int64x2_t;
+        [neon] safe fn vqdmlal_lane_s16<const N: i32>(a: int32x4_t, b: int16x4_t, c: int16x4_t) -> int32x4_t;
+        [neon] safe fn vqdmlal_lane_s32<const N: i32>(a: int64x2_t, b: int32x2_t, c: int32x2_t) -> int64x2_t;
+        [neon] safe fn vqdmlal_laneq_s16<const N: i32>(a: int32x4_t, b: int16x4_t, c: int16x8_t) -> int32x4_t;
+        [neon] safe fn vqdmlal_laneq_s32<const N: i32>(a: int64x2_t, b: int32x2_t, c: int32x4_t) -> int64x2_t;
+        [neon] safe fn vqdmlal_n_s16(a: int32x4_t, b: int16x4_t, c: i16) -> int32x4_t;
+        [neon] safe fn vqdmlal_n_s32(a: int64x2_t, b: int32x2_t, c: i32) -> int64x2_t;
+        [neon] safe fn vqdmlal_s16(a: int32x4_t, b: int16x4_t, c: int16x4_t) -> int32x4_t;
+        [neon] safe fn vqdmlal_s32(a: int64x2_t, b: int32x2_t, c: int32x2_t) -> int64x2_t;
+        [neon] safe fn vqdmlalh_lane_s16<const LANE: i32>(a: i32, b: i16, c: int16x4_t) -> i32;
+        [neon] safe fn vqdmlalh_laneq_s16<const LANE: i32>(a: i32, b: i16, c: int16x8_t) -> i32;
+        [neon] safe fn vqdmlalh_s16(a: i32, b: i16, c: i16) -> i32;
+        [neon] safe fn vqdmlals_lane_s32<const LANE: i32>(a: i64, b: i32, c: int32x2_t) -> i64;
+        [neon] safe fn vqdmlals_laneq_s32<const LANE: i32>(a: i64, b: i32, c: int32x4_t) -> i64;
+        [neon] safe fn vqdmlals_s32(a: i64, b: i32, c: i32) -> i64;
+        [neon] safe fn vqdmlsl_high_lane_s16<const N: i32>(a: int32x4_t, b: int16x8_t, c: int16x4_t) -> int32x4_t;
+        [neon] safe fn vqdmlsl_high_lane_s32<const N: i32>(a: int64x2_t, b: int32x4_t, c: int32x2_t) -> int64x2_t;
+        [neon] safe fn vqdmlsl_high_laneq_s16<const N: i32>(a: int32x4_t, b: int16x8_t, c: int16x8_t) -> int32x4_t;
+        [neon] safe fn vqdmlsl_high_laneq_s32<const N: i32>(a: int64x2_t, b: int32x4_t, c: int32x4_t) -> int64x2_t;
+        [neon] safe fn vqdmlsl_high_n_s16(a: int32x4_t, b: int16x8_t, c: i16) -> int32x4_t;
+        [neon] safe fn vqdmlsl_high_n_s32(a: int64x2_t, b: int32x4_t, c: i32) -> int64x2_t;
+        [neon] safe fn vqdmlsl_high_s16(a: int32x4_t, b: int16x8_t, c: int16x8_t) -> int32x4_t;
+        [neon] safe fn vqdmlsl_high_s32(a: int64x2_t, b: int32x4_t, c: int32x4_t) -> int64x2_t;
+        [neon] safe fn vqdmlsl_lane_s16<const N: i32>(a: int32x4_t, b: int16x4_t, c: int16x4_t) -> int32x4_t;
+        [neon] safe fn vqdmlsl_lane_s32<const N: i32>(a: int64x2_t, b: int32x2_t, c: int32x2_t) -> int64x2_t;
+        [neon] safe fn vqdmlsl_laneq_s16<const N: i32>(a: int32x4_t, b: int16x4_t, c: int16x8_t) -> int32x4_t;
+        [neon] safe fn vqdmlsl_laneq_s32<const N: i32>(a: int64x2_t, b: int32x2_t, c: int32x4_t) -> int64x2_t;
+        [neon] safe fn vqdmlsl_n_s16(a: int32x4_t, b: int16x4_t, c: i16) -> int32x4_t;
+        [neon] safe fn vqdmlsl_n_s32(a: int64x2_t, b: int32x2_t, c: i32) -> int64x2_t;
+        [neon] safe fn vqdmlsl_s16(a: int32x4_t, b: int16x4_t, c: int16x4_t) -> int32x4_t;
+        [neon] safe fn vqdmlsl_s32(a: int64x2_t, b: int32x2_t, c: int32x2_t) -> int64x2_t;
+        [neon] safe fn vqdmlslh_lane_s16<const LANE: i32>(a: i32, b: i16, c: int16x4_t) -> i32;
+        [neon] safe fn vqdmlslh_laneq_s16<const LANE: i32>(a: i32, b: i16, c: int16x8_t) -> i32;
+        [neon] safe fn vqdmlslh_s16(a: i32, b: i16, c: i16) -> i32;
+        [neon] safe fn vqdmlsls_lane_s32<const LANE: i32>(a: i64, b: i32, c: int32x2_t) -> i64;
+        [neon] safe fn vqdmlsls_laneq_s32<const LANE: i32>(a: i64, b: i32, c: int32x4_t) -> i64;
+        [neon] safe fn vqdmlsls_s32(a: i64, b: i32, c: i32) -> i64;
+        [neon] safe fn vqdmulh_lane_s16<const LANE: i32>(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vqdmulh_lane_s32<const LANE: i32>(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vqdmulh_laneq_s16<const LANE: i32>(a: int16x4_t, b: int16x8_t) -> int16x4_t;
+        [neon] safe fn vqdmulh_laneq_s32<const LANE: i32>(a: int32x2_t, b: int32x4_t) -> int32x2_t;
+        [neon] safe fn vqdmulh_n_s16(a: int16x4_t, b: i16) -> int16x4_t;
+        [neon] safe fn vqdmulh_n_s32(a: int32x2_t, b: i32) -> int32x2_t;
+        [neon] safe fn vqdmulh_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vqdmulh_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vqdmulhh_lane_s16<const N: i32>(a: i16, b: int16x4_t) -> i16;
+        [neon] safe fn vqdmulhh_laneq_s16<const N: i32>(a: i16, b: int16x8_t) -> i16;
+        [neon] safe fn vqdmulhh_s16(a: i16, b: i16) -> i16;
+        [neon] safe fn vqdmulhq_lane_s16<const LANE: i32>(a: int16x8_t, b: int16x4_t) -> int16x8_t;
+        [neon] safe fn vqdmulhq_lane_s32<const LANE: i32>(a: int32x4_t, b: int32x2_t) -> int32x4_t;
+        [neon] safe fn vqdmulhq_laneq_s16<const LANE: i32>(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vqdmulhq_laneq_s32<const LANE: i32>(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vqdmulhq_n_s16(a: int16x8_t, b: i16) -> int16x8_t;
+        [neon] safe fn vqdmulhq_n_s32(a: int32x4_t, b: i32) -> int32x4_t;
+        [neon] safe fn vqdmulhq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vqdmulhq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vqdmulhs_lane_s32<const N: i32>(a: i32, b: int32x2_t) -> i32;
+        [neon] safe fn vqdmulhs_laneq_s32<const N: i32>(a: i32, b: int32x4_t) -> i32;
+        [neon] safe fn vqdmulhs_s32(a: i32, b: i32) -> i32;
+        [neon] safe fn vqdmull_high_lane_s16<const N: i32>(a: int16x8_t, b: int16x4_t) -> int32x4_t;
+        [neon] safe fn vqdmull_high_lane_s32<const N: i32>(a: int32x4_t, b: int32x2_t) -> int64x2_t;
+        [neon] safe fn vqdmull_high_laneq_s16<const N: i32>(a: int16x8_t, b: int16x8_t) -> int32x4_t;
+        [neon] safe fn vqdmull_high_laneq_s32<const N: i32>(a: int32x4_t, b: int32x4_t) -> int64x2_t;
+        [neon] safe fn vqdmull_high_n_s16(a: int16x8_t, b: i16) -> int32x4_t;
+        [neon] safe fn vqdmull_high_n_s32(a: int32x4_t, b: i32) -> int64x2_t;
+        [neon] safe fn vqdmull_high_s16(a: int16x8_t, b: int16x8_t) -> int32x4_t;
+        [neon] safe fn vqdmull_high_s32(a: int32x4_t, b: int32x4_t) -> int64x2_t;
+        [neon] safe fn vqdmull_lane_s16<const N: i32>(a: int16x4_t, b: int16x4_t) -> int32x4_t;
+        [neon] safe fn vqdmull_lane_s32<const N: i32>(a: int32x2_t, b: int32x2_t) -> int64x2_t;
+        [neon] safe fn vqdmull_laneq_s16<const N: i32>(a: int16x4_t, b: int16x8_t) -> int32x4_t;
+        [neon] safe fn vqdmull_laneq_s32<const N: i32>(a: int32x2_t, b: int32x4_t) -> int64x2_t;
+        [neon] safe fn vqdmull_n_s16(a: int16x4_t, b: i16) -> int32x4_t;
+        [neon] safe fn vqdmull_n_s32(a: int32x2_t, b: i32) -> int64x2_t;
+        [neon] safe fn vqdmull_s16(a: int16x4_t, b: int16x4_t) -> int32x4_t;
+        [neon] safe fn vqdmull_s32(a: int32x2_t, b: int32x2_t) -> int64x2_t;
+        [neon] safe fn vqdmullh_lane_s16<const N: i32>(a: i16, b: int16x4_t) -> i32;
+        [neon] safe fn vqdmullh_laneq_s16<const N: i32>(a: i16, b: int16x8_t) -> i32;
+        [neon] safe fn vqdmullh_s16(a: i16, b: i16) -> i32;
+        [neon] safe fn vqdmulls_lane_s32<const N: i32>(a: i32, b: int32x2_t) -> i64;
+        [neon] safe fn vqdmulls_laneq_s32<const N: i32>(a: i32, b: int32x4_t) -> i64;
+        [neon] safe fn vqdmulls_s32(a: i32, b: i32) -> i64;
+        [neon] safe fn vqmovn_high_s16(a: int8x8_t, b: int16x8_t) -> int8x16_t;
+        [neon] safe fn vqmovn_high_s32(a: int16x4_t, b: int32x4_t) -> int16x8_t;
+        [neon] safe fn vqmovn_high_s64(a: int32x2_t, b: int64x2_t) -> int32x4_t;
+        [neon] safe fn vqmovn_high_u16(a: uint8x8_t, b: uint16x8_t) -> uint8x16_t;
+        [neon] safe fn vqmovn_high_u32(a: uint16x4_t, b: uint32x4_t) -> uint16x8_t;
+        [neon] safe fn vqmovn_high_u64(a: uint32x2_t, b: uint64x2_t) -> uint32x4_t;
+        [neon] safe fn vqmovn_s16(a: int16x8_t) -> int8x8_t;
+        [neon] safe fn vqmovn_s32(a: int32x4_t) -> int16x4_t;
+        [neon] safe fn vqmovn_s64(a: int64x2_t) -> int32x2_t;
+        [neon] safe fn vqmovn_u16(a: uint16x8_t) -> uint8x8_t;
+        [neon] safe fn vqmovn_u32(a: uint32x4_t) -> uint16x4_t;
+        [neon] safe fn vqmovn_u64(a: uint64x2_t) -> uint32x2_t;
+        [neon] safe fn vqmovnd_s64(a: i64) -> i32;
+        [neon] safe fn vqmovnd_u64(a: u64) -> u32;
+        [neon] safe fn vqmovnh_s16(a: i16) -> i8;
+        [neon] safe fn vqmovnh_u16(a: u16) -> u8;
+        [neon] safe fn vqmovns_s32(a: i32) -> i16;
+        [neon] safe fn vqmovns_u32(a: u32) -> u16;
+        [neon] safe fn vqmovun_high_s16(a: uint8x8_t, b: int16x8_t) -> uint8x16_t;
+        [neon] safe fn vqmovun_high_s32(a: uint16x4_t, b: int32x4_t) -> uint16x8_t;
+        [neon] safe fn vqmovun_high_s64(a: uint32x2_t, b: int64x2_t) -> uint32x4_t;
+        [neon] safe fn vqmovun_s16(a: int16x8_t) -> uint8x8_t;
+        [neon] safe fn vqmovun_s32(a: int32x4_t) -> uint16x4_t;
+        [neon] safe fn vqmovun_s64(a: int64x2_t) -> uint32x2_t;
+        [neon] safe fn vqmovund_s64(a: i64) -> u32;
+        [neon] safe fn vqmovunh_s16(a: i16) -> u8;
+        [neon] safe fn vqmovuns_s32(a: i32) -> u16;
+        [neon] safe fn vqneg_s16(a: int16x4_t) -> int16x4_t;
+        [neon] safe fn vqneg_s32(a: int32x2_t) -> int32x2_t;
+        [neon] safe fn vqneg_s64(a: int64x1_t) -> int64x1_t;
+        [neon] safe fn vqneg_s8(a: int8x8_t) -> int8x8_t;
+        [neon] safe fn vqnegb_s8(a: i8) -> i8;
+        [neon] safe fn vqnegd_s64(a: i64) -> i64;
+        [neon] safe fn vqnegh_s16(a: i16) -> i16;
+        [neon] safe fn vqnegq_s16(a: int16x8_t) -> int16x8_t;
+        [neon] safe fn vqnegq_s32(a: int32x4_t) -> int32x4_t;
+        [neon] safe fn vqnegq_s64(a: int64x2_t) -> int64x2_t;
+        [neon] safe fn vqnegq_s8(a: int8x16_t) -> int8x16_t;
+        [neon] safe fn vqnegs_s32(a: i32) -> i32;
+        [rdm] safe fn vqrdmlah_lane_s16<const LANE: i32>(a: int16x4_t, b: int16x4_t, c: int16x4_t) -> int16x4_t;
+        [rdm] safe fn vqrdmlah_lane_s32<const LANE: i32>(a: int32x2_t, b: int32x2_t, c: int32x2_t) -> int32x2_t;
+        [rdm] safe fn vqrdmlah_laneq_s16<const LANE: i32>(a: int16x4_t, b: int16x4_t, c: int16x8_t) -> int16x4_t;
+        [rdm] safe fn vqrdmlah_laneq_s32<const LANE: i32>(a: int32x2_t, b: int32x2_t, c: int32x4_t) -> int32x2_t;
+        [rdm] safe fn vqrdmlah_s16(a: int16x4_t, b: int16x4_t, c: int16x4_t) -> int16x4_t;
+        [rdm] safe fn vqrdmlah_s32(a: int32x2_t, b: int32x2_t, c: int32x2_t) -> int32x2_t;
+        [rdm] safe fn vqrdmlahh_lane_s16<const LANE: i32>(a: i16, b: i16, c: int16x4_t) -> i16;
+        [rdm] safe fn vqrdmlahh_laneq_s16<const LANE: i32>(a: i16, b: i16, c: int16x8_t) -> i16;
+        [rdm] safe fn vqrdmlahh_s16(a: i16, b: i16, c: i16) -> i16;
+        [rdm] safe fn vqrdmlahq_lane_s16<const LANE: i32>(a: int16x8_t, b: int16x8_t, c: int16x4_t) -> int16x8_t;
+        [rdm] safe fn vqrdmlahq_lane_s32<const LANE: i32>(a: int32x4_t, b: int32x4_t, c: int32x2_t) -> int32x4_t;
+        [rdm] safe fn vqrdmlahq_laneq_s16<const LANE: i32>(a: int16x8_t, b: int16x8_t, c: int16x8_t) -> int16x8_t;
+        [rdm] safe fn vqrdmlahq_laneq_s32<const LANE: i32>(a: int32x4_t, b: int32x4_t, c: int32x4_t) -> int32x4_t;
+        [rdm] safe fn vqrdmlahq_s16(a: int16x8_t, b: int16x8_t, c: int16x8_t) -> int16x8_t;
+        [rdm] safe fn vqrdmlahq_s32(a: int32x4_t, b: int32x4_t, c: int32x4_t) -> int32x4_t;
+        [rdm] safe fn vqrdmlahs_lane_s32<const LANE: i32>(a: i32, b: i32, c: int32x2_t) -> i32;
+        [rdm] safe fn vqrdmlahs_laneq_s32<const LANE: i32>(a: i32, b: i32, c: int32x4_t) -> i32;
+        [rdm] safe fn vqrdmlahs_s32(a: i32, b: i32, c: i32) -> i32;
+        [rdm] safe fn vqrdmlsh_lane_s16<const LANE: i32>(a: int16x4_t, b: int16x4_t, c: int16x4_t) -> int16x4_t;
+        [rdm] safe fn vqrdmlsh_lane_s32<const LANE: i32>(a: int32x2_t, b: int32x2_t, c: int32x2_t) -> int32x2_t;
+        [rdm] safe fn vqrdmlsh_laneq_s16<const LANE: i32>(a: int16x4_t, b: int16x4_t, c: int16x8_t) -> int16x4_t;
+        [rdm] safe fn vqrdmlsh_laneq_s32<const LANE: i32>(a: int32x2_t, b: int32x2_t, c: int32x4_t) -> int32x2_t;
+        [rdm] safe fn vqrdmlsh_s16(a: int16x4_t, b: int16x4_t, c: int16x4_t) -> int16x4_t;
+        [rdm] safe fn vqrdmlsh_s32(a: int32x2_t, b: int32x2_t, c: int32x2_t) -> int32x2_t;
+        [rdm] safe fn vqrdmlshh_lane_s16<const LANE: i32>(a: i16, b: i16, c: int16x4_t) -> i16;
+        [rdm] safe fn vqrdmlshh_laneq_s16<const LANE: i32>(a: i16, b: i16, c: int16x8_t) -> i16;
+        [rdm] safe fn vqrdmlshh_s16(a: i16, b: i16, c: i16) -> i16;
+        [rdm] safe fn vqrdmlshq_lane_s16<const LANE: i32>(a: int16x8_t, b: int16x8_t, c: int16x4_t) -> int16x8_t;
+        [rdm] safe fn vqrdmlshq_lane_s32<const LANE: i32>(a: int32x4_t, b: int32x4_t, c: int32x2_t) -> int32x4_t;
+        [rdm] safe fn vqrdmlshq_laneq_s16<const LANE: i32>(a: int16x8_t, b: int16x8_t, c: int16x8_t) -> int16x8_t;
+        [rdm] safe fn vqrdmlshq_laneq_s32<const LANE: i32>(a: int32x4_t, b: int32x4_t, c: int32x4_t) -> int32x4_t;
+        [rdm] safe fn vqrdmlshq_s16(a: int16x8_t, b: int16x8_t, c: int16x8_t) -> int16x8_t;
+        [rdm] safe fn vqrdmlshq_s32(a: int32x4_t, b: int32x4_t, c: int32x4_t) -> int32x4_t;
+        [rdm] safe fn vqrdmlshs_lane_s32<const LANE: i32>(a: i32, b: i32, c: int32x2_t) -> i32;
+        [rdm] safe fn vqrdmlshs_laneq_s32<const LANE: i32>(a: i32, b: i32, c: int32x4_t) -> i32;
+        [rdm] safe fn vqrdmlshs_s32(a: i32, b: i32, c: i32) -> i32;
+        [neon] safe fn vqrdmulh_lane_s16<const LANE: i32>(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vqrdmulh_lane_s32<const LANE: i32>(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vqrdmulh_laneq_s16<const LANE: i32>(a: int16x4_t, b: int16x8_t) -> int16x4_t;
+        [neon] safe fn vqrdmulh_laneq_s32<const LANE: i32>(a: int32x2_t, b: int32x4_t) -> int32x2_t;
+        [neon] safe fn vqrdmulh_n_s16(a: int16x4_t, b: i16) -> int16x4_t;
+        [neon] safe fn vqrdmulh_n_s32(a: int32x2_t, b: i32) -> int32x2_t;
+        [neon] safe fn vqrdmulh_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vqrdmulh_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vqrdmulhh_lane_s16<const LANE: i32>(a: i16, b: int16x4_t) -> i16;
+        [neon] safe fn vqrdmulhh_laneq_s16<const LANE: i32>(a: i16, b: int16x8_t) -> i16;
+        [neon] safe fn vqrdmulhh_s16(a: i16, b: i16) -> i16;
+        [neon] safe fn vqrdmulhq_lane_s16<const LANE: i32>(a: int16x8_t, b: int16x4_t) -> int16x8_t;
+        [neon] safe fn vqrdmulhq_lane_s32<const LANE: i32>(a: int32x4_t, b: int32x2_t) -> int32x4_t;
+        [neon] safe fn vqrdmulhq_laneq_s16<const LANE: i32>(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vqrdmulhq_laneq_s32<const LANE: i32>(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vqrdmulhq_n_s16(a: int16x8_t, b: i16) -> int16x8_t;
+        [neon] safe fn vqrdmulhq_n_s32(a: int32x4_t, b: i32) -> int32x4_t;
+        [neon] safe fn vqrdmulhq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vqrdmulhq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vqrdmulhs_lane_s32<const LANE: i32>(a: i32, b: int32x2_t) -> i32;
+        [neon] safe fn vqrdmulhs_laneq_s32<const LANE: i32>(a: i32, b: int32x4_t) -> i32;
+        [neon] safe fn vqrdmulhs_s32(a: i32, b: i32) -> i32;
+        [neon] safe fn vqrshl_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vqrshl_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vqrshl_s64(a: int64x1_t, b: int64x1_t) -> int64x1_t;
+        [neon] safe fn vqrshl_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vqrshl_u16(a: uint16x4_t, b: int16x4_t) -> uint16x4_t;
+        [neon] safe fn vqrshl_u32(a: uint32x2_t, b: int32x2_t) -> uint32x2_t;
+        [neon] safe fn vqrshl_u64(a: uint64x1_t, b: int64x1_t) -> uint64x1_t;
+        [neon] safe fn vqrshl_u8(a: uint8x8_t, b: int8x8_t) -> uint8x8_t;
+        [neon] safe fn vqrshlb_s8(a: i8, b: i8) -> i8;
+        [neon] safe fn vqrshlb_u8(a: u8, b: i8) -> u8;
+        [neon] safe fn vqrshld_s64(a: i64, b: i64) -> i64;
+        [neon] safe fn vqrshld_u64(a: u64, b: i64) -> u64;
+        [neon] safe fn vqrshlh_s16(a: i16, b: i16) -> i16;
+        [neon] safe fn vqrshlh_u16(a: u16, b: i16) -> u16;
+        [neon] safe fn vqrshlq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vqrshlq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vqrshlq_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vqrshlq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vqrshlq_u16(a: uint16x8_t, b: int16x8_t) -> uint16x8_t;
+        [neon] safe fn vqrshlq_u32(a: uint32x4_t, b: int32x4_t) -> uint32x4_t;
+        [neon] safe fn vqrshlq_u64(a: uint64x2_t, b: int64x2_t) -> uint64x2_t;
+        [neon] safe fn vqrshlq_u8(a: uint8x16_t, b: int8x16_t) -> uint8x16_t;
+        [neon] safe fn vqrshls_s32(a: i32, b: i32) -> i32;
+        [neon] safe fn vqrshls_u32(a: u32, b: i32) -> u32;
+        [neon] safe fn vqrshrn_high_n_s16<const N: i32>(a: int8x8_t, b: int16x8_t) -> int8x16_t;
+        [neon] safe fn vqrshrn_high_n_s32<const N: i32>(a: int16x4_t, b: int32x4_t) -> int16x8_t;
+        [neon] safe fn vqrshrn_high_n_s64<const N: i32>(a: int32x2_t, b: int64x2_t) -> int32x4_t;
+        [neon] safe fn vqrshrn_high_n_u16<const N: i32>(a: uint8x8_t, b: uint16x8_t) -> uint8x16_t;
+        [neon] safe fn vqrshrn_high_n_u32<const N: i32>(a: uint16x4_t, b: uint32x4_t) -> uint16x8_t;
+        [neon] safe fn vqrshrn_high_n_u64<const N: i32>(a: uint32x2_t, b: uint64x2_t) -> uint32x4_t;
+        [neon] safe fn vqrshrn_n_s16<const N: i32>(a: int16x8_t) -> int8x8_t;
+        [neon] safe fn vqrshrn_n_s32<const N: i32>(a: int32x4_t) -> int16x4_t;
+        [neon] safe fn vqrshrn_n_s64<const N: i32>(a: int64x2_t) -> int32x2_t;
+        [neon] safe fn vqrshrn_n_u16<const N: i32>(a: uint16x8_t) -> uint8x8_t;
+        [neon] safe fn vqrshrn_n_u32<const N: i32>(a: uint32x4_t) -> uint16x4_t;
+        [neon] safe fn vqrshrn_n_u64<const N: i32>(a: uint64x2_t) -> uint32x2_t;
+        [neon] safe fn vqrshrnd_n_s64<const N: i32>(a: i64) -> i32;
+        [neon] safe fn vqrshrnd_n_u64<const N: i32>(a: u64) -> u32;
+        [neon] safe fn vqrshrnh_n_s16<const N: i32>(a: i16) -> i8;
+        [neon] safe fn vqrshrnh_n_u16<const N: i32>(a: u16) -> u8;
+        [neon] safe fn vqrshrns_n_s32<const N: i32>(a: i32) -> i16;
+        [neon] safe fn vqrshrns_n_u32<const N: i32>(a: u32) -> u16;
+        [neon] safe fn vqrshrun_high_n_s16<const N: i32>(a: uint8x8_t, b: int16x8_t) -> uint8x16_t;
+        [neon] safe fn vqrshrun_high_n_s32<const N: i32>(a: uint16x4_t, b: int32x4_t) -> uint16x8_t;
+        [neon] safe fn vqrshrun_high_n_s64<const N: i32>(a: uint32x2_t, b: int64x2_t) -> uint32x4_t;
+        [neon] safe fn vqrshrun_n_s16<const N: i32>(a: int16x8_t) -> uint8x8_t;
+        [neon] safe fn vqrshrun_n_s32<const N: i32>(a: int32x4_t) -> uint16x4_t;
+        [neon] safe fn vqrshrun_n_s64<const N: i32>(a: int64x2_t) -> uint32x2_t;
+        [neon] safe fn vqrshrund_n_s64<const N: i32>(a: i64) -> u32;
+        [neon] safe fn vqrshrunh_n_s16<const N: i32>(a: i16) -> u8;
+        [neon] safe fn vqrshruns_n_s32<const N: i32>(a: i32) -> u16;
+        [neon] safe fn vqshl_n_s16<const N: i32>(a: int16x4_t) -> int16x4_t;
+        [neon] safe fn vqshl_n_s32<const N: i32>(a: int32x2_t) -> int32x2_t;
+        [neon] safe fn vqshl_n_s64<const N: i32>(a: int64x1_t) -> int64x1_t;
+        [neon] safe fn vqshl_n_s8<const N: i32>(a: int8x8_t) -> int8x8_t;
+        [neon] safe fn vqshl_n_u16<const N: i32>(a: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vqshl_n_u32<const N: i32>(a: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vqshl_n_u64<const N: i32>(a: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vqshl_n_u8<const N: i32>(a: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vqshl_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vqshl_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vqshl_s64(a: int64x1_t, b: int64x1_t) -> int64x1_t;
+        [neon] safe fn vqshl_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vqshl_u16(a: uint16x4_t, b: int16x4_t) -> uint16x4_t;
+        [neon] safe fn vqshl_u32(a: uint32x2_t, b: int32x2_t) -> uint32x2_t;
+        [neon] safe fn vqshl_u64(a: uint64x1_t, b: int64x1_t) -> uint64x1_t;
+        [neon] safe fn vqshl_u8(a: uint8x8_t, b: int8x8_t) -> uint8x8_t;
+        [neon] safe fn vqshlb_n_s8<const N: i32>(a: i8) -> i8;
+        [neon] safe fn vqshlb_n_u8<const N: i32>(a: u8) -> u8;
+        [neon] safe fn vqshlb_s8(a: i8, b: i8) -> i8;
+        [neon] safe fn vqshlb_u8(a: u8, b: i8) -> u8;
+        [neon] safe fn vqshld_n_s64<const N: i32>(a: i64) -> i64;
+        [neon] safe fn vqshld_n_u64<const N: i32>(a: u64) -> u64;
+        [neon] safe fn vqshld_s64(a: i64, b: i64) -> i64;
+        [neon] safe fn vqshld_u64(a: u64, b: i64) -> u64;
+        [neon] safe fn vqshlh_n_s16<const N: i32>(a: i16) -> i16;
+        [neon] safe fn vqshlh_n_u16<const N: i32>(a: u16) -> u16;
+        [neon] safe fn vqshlh_s16(a: i16, b: i16) -> i16;
+        [neon] safe fn vqshlh_u16(a: u16, b: i16) -> u16;
+        [neon] safe fn vqshlq_n_s16<const N: i32>(a: int16x8_t) -> int16x8_t;
+        [neon] safe fn vqshlq_n_s32<const N: i32>(a: int32x4_t) -> int32x4_t;
+        [neon] safe fn vqshlq_n_s64<const N: i32>(a: int64x2_t) -> int64x2_t;
+        [neon] safe fn vqshlq_n_s8<const N: i32>(a: int8x16_t) -> int8x16_t;
+        [neon] safe fn vqshlq_n_u16<const N: i32>(a: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vqshlq_n_u32<const N: i32>(a: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vqshlq_n_u64<const N: i32>(a: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vqshlq_n_u8<const N: i32>(a: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vqshlq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vqshlq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vqshlq_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vqshlq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vqshlq_u16(a: uint16x8_t, b: int16x8_t) -> uint16x8_t;
+        [neon] safe fn vqshlq_u32(a: uint32x4_t, b: int32x4_t) -> uint32x4_t;
+        [neon] safe fn vqshlq_u64(a: uint64x2_t, b: int64x2_t) -> uint64x2_t;
+        [neon] safe fn vqshlq_u8(a: uint8x16_t, b: int8x16_t) -> uint8x16_t;
+        [neon] safe fn vqshls_n_s32<const N: i32>(a: i32) -> i32;
+        [neon] safe fn vqshls_n_u32<const N: i32>(a: u32) -> u32;
+        [neon] safe fn vqshls_s32(a: i32, b: i32) -> i32;
+        [neon] safe fn vqshls_u32(a: u32, b: i32) -> u32;
+        [neon] safe fn vqshlu_n_s16<const N: i32>(a: int16x4_t) -> uint16x4_t;
+        [neon] safe fn vqshlu_n_s32<const N: i32>(a: int32x2_t) -> uint32x2_t;
+        [neon] safe fn vqshlu_n_s64<const N: i32>(a: int64x1_t) -> uint64x1_t;
+        [neon] safe fn vqshlu_n_s8<const N: i32>(a: int8x8_t) -> uint8x8_t;
+        [neon] safe fn vqshlub_n_s8<const N: i32>(a: i8) -> u8;
+        [neon] safe fn vqshlud_n_s64<const N: i32>(a: i64) -> u64;
+        [neon] safe fn vqshluh_n_s16<const N: i32>(a: i16) -> u16;
+        [neon] safe fn vqshluq_n_s16<const N: i32>(a: int16x8_t) -> uint16x8_t;
+        [neon] safe fn vqshluq_n_s32<const N: i32>(a: int32x4_t) -> uint32x4_t;
+        [neon] safe fn vqshluq_n_s64<const N: i32>(a: int64x2_t) -> uint64x2_t;
+        [neon] safe fn vqshluq_n_s8<const N: i32>(a: int8x16_t) -> uint8x16_t;
+        [neon] safe fn vqshlus_n_s32<const N: i32>(a: i32) -> u32;
+        [neon] safe fn vqshrn_high_n_s16<const N: i32>(a: int8x8_t, b: int16x8_t) -> int8x16_t;
+        [neon] safe fn vqshrn_high_n_s32<const N: i32>(a: int16x4_t, b: int32x4_t) -> int16x8_t;
+        [neon] safe fn vqshrn_high_n_s64<const N: i32>(a: int32x2_t, b: int64x2_t) -> int32x4_t;
+        [neon] safe fn vqshrn_high_n_u16<const N: i32>(a: uint8x8_t, b: uint16x8_t) -> uint8x16_t;
+        [neon] safe fn vqshrn_high_n_u32<const N: i32>(a: uint16x4_t, b: uint32x4_t) -> uint16x8_t;
+        [neon] safe fn vqshrn_high_n_u64<const N: i32>(a: uint32x2_t, b: uint64x2_t) -> uint32x4_t;
+        [neon] safe fn vqshrn_n_s16<const N: i32>(a: int16x8_t) -> int8x8_t;
+        [neon] safe fn vqshrn_n_s32<const N: i32>(a: int32x4_t) -> int16x4_t;
+        [neon] safe fn vqshrn_n_s64<const N: i32>(a: int64x2_t) -> int32x2_t;
+        [neon] safe fn vqshrn_n_u16<const N: i32>(a: uint16x8_t) -> uint8x8_t;
+        [neon] safe fn vqshrn_n_u32<const N: i32>(a: uint32x4_t) -> uint16x4_t;
+        [neon] safe fn vqshrn_n_u64<const N: i32>(a: uint64x2_t) -> uint32x2_t;
+        [neon] safe fn vqshrnd_n_s64<const N: i32>(a: i64) -> i32;
+        [neon] safe fn vqshrnd_n_u64<const N: i32>(a: u64) -> u32;
+        [neon] safe fn vqshrnh_n_s16<const N: i32>(a: i16) -> i8;
+        [neon] safe fn vqshrnh_n_u16<const N: i32>(a: u16) -> u8;
+        [neon] safe fn vqshrns_n_s32<const N: i32>(a: i32) -> i16;
+        [neon] safe fn vqshrns_n_u32<const N: i32>(a: u32) -> u16;
+        [neon] safe fn vqshrun_high_n_s16<const N: i32>(a: uint8x8_t, b: int16x8_t) -> uint8x16_t;
+        [neon] safe fn vqshrun_high_n_s32<const N: i32>(a: uint16x4_t, b: int32x4_t) -> uint16x8_t;
+        [neon] safe fn vqshrun_high_n_s64<const N: i32>(a: uint32x2_t, b: int64x2_t) -> uint32x4_t;
+        [neon] safe fn vqshrun_n_s16<const N: i32>(a: int16x8_t) -> uint8x8_t;
+        [neon] safe fn vqshrun_n_s32<const N: i32>(a: int32x4_t) -> uint16x4_t;
+        [neon] safe fn vqshrun_n_s64<const N: i32>(a: int64x2_t) -> uint32x2_t;
+        [neon] safe fn vqshrund_n_s64<const N: i32>(a: i64) -> u32;
+        [neon] safe fn vqshrunh_n_s16<const N: i32>(a: i16) -> u8;
+        [neon] safe fn vqshruns_n_s32<const N: i32>(a: i32) -> u16;
+        [neon] safe fn vqsub_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vqsub_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vqsub_s64(a: int64x1_t, b: int64x1_t) -> int64x1_t;
+        [neon] safe fn vqsub_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vqsub_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vqsub_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vqsub_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vqsub_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vqsubb_s8(a: i8, b: i8) -> i8;
+        [neon] safe fn vqsubb_u8(a: u8, b: u8) -> u8;
+        [neon] safe fn vqsubd_s64(a: i64, b: i64) -> i64;
+        [neon] safe fn vqsubd_u64(a: u64, b: u64) -> u64;
+        [neon] safe fn vqsubh_s16(a: i16, b: i16) -> i16;
+        [neon] safe fn vqsubh_u16(a: u16, b: u16) -> u16;
+        [neon] safe fn vqsubq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vqsubq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vqsubq_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vqsubq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vqsubq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vqsubq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vqsubq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vqsubq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vqsubs_s32(a: i32, b: i32) -> i32;
+        [neon] safe fn vqsubs_u32(a: u32, b: u32) -> u32;
+        [neon] safe fn vqtbl1_p8(a: poly8x16_t, b: uint8x8_t) -> poly8x8_t;
+        [neon] safe fn vqtbl1_s8(a: int8x16_t, b: uint8x8_t) -> int8x8_t;
+        [neon] safe fn vqtbl1_u8(a: uint8x16_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vqtbl1q_p8(a: poly8x16_t, b: uint8x16_t) -> poly8x16_t;
+        [neon] safe fn vqtbl1q_s8(a: int8x16_t, b: uint8x16_t) -> int8x16_t;
+        [neon] safe fn vqtbl1q_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vqtbl2_p8(a: poly8x16x2_t, b: uint8x8_t) -> poly8x8_t;
+        [neon] safe fn vqtbl2_s8(a: int8x16x2_t, b: uint8x8_t) -> int8x8_t;
+        [neon] safe fn vqtbl2_u8(a: uint8x16x2_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vqtbl2q_p8(a: poly8x16x2_t, b: uint8x16_t) -> poly8x16_t;
+        [neon] safe fn vqtbl2q_s8(a: int8x16x2_t, b: uint8x16_t) -> int8x16_t;
+        [neon] safe fn vqtbl2q_u8(a: uint8x16x2_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vqtbl3_p8(a: poly8x16x3_t, b: uint8x8_t) -> poly8x8_t;
+        [neon] safe fn vqtbl3_s8(a: int8x16x3_t, b: uint8x8_t) -> int8x8_t;
+        [neon] safe fn vqtbl3_u8(a: uint8x16x3_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vqtbl3q_p8(a: poly8x16x3_t, b: uint8x16_t) -> poly8x16_t;
+        [neon] safe fn vqtbl3q_s8(a: int8x16x3_t, b: uint8x16_t) -> int8x16_t;
+        [neon] safe fn vqtbl3q_u8(a: uint8x16x3_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vqtbl4_p8(a: poly8x16x4_t, b: uint8x8_t) -> poly8x8_t;
+        [neon] safe fn vqtbl4_s8(a: int8x16x4_t, b: uint8x8_t) -> int8x8_t;
+        [neon] safe fn vqtbl4_u8(a: uint8x16x4_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vqtbl4q_p8(a: poly8x16x4_t, b: uint8x16_t) -> poly8x16_t;
+        [neon] safe fn vqtbl4q_s8(a: int8x16x4_t, b: uint8x16_t) -> int8x16_t;
+        [neon] safe fn vqtbl4q_u8(a: uint8x16x4_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vqtbx1_p8(a: poly8x8_t, b: poly8x16_t, c: uint8x8_t) -> poly8x8_t;
+        [neon] safe fn vqtbx1_s8(a: int8x8_t, b: int8x16_t, c: uint8x8_t) -> int8x8_t;
+        [neon] safe fn vqtbx1_u8(a: uint8x8_t, b: uint8x16_t, c: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vqtbx1q_p8(a: poly8x16_t, b: poly8x16_t, c: uint8x16_t) -> poly8x16_t;
+        [neon] safe fn vqtbx1q_s8(a: int8x16_t, b: int8x16_t, c: uint8x16_t) -> int8x16_t;
+        [neon] safe fn vqtbx1q_u8(a: uint8x16_t, b: uint8x16_t, c: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vqtbx2_p8(a: poly8x8_t, b: poly8x16x2_t, c: uint8x8_t) -> poly8x8_t;
+        [neon] safe fn vqtbx2_s8(a: int8x8_t, b: int8x16x2_t, c: uint8x8_t) -> int8x8_t;
+        [neon] safe fn vqtbx2_u8(a: uint8x8_t, b: uint8x16x2_t, c: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vqtbx2q_p8(a: poly8x16_t, b: poly8x16x2_t, c: uint8x16_t) -> poly8x16_t;
+        [neon] safe fn vqtbx2q_s8(a: int8x16_t, b: int8x16x2_t, c: uint8x16_t) -> int8x16_t;
+        [neon] safe fn vqtbx2q_u8(a: uint8x16_t, b: uint8x16x2_t, c: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vqtbx3_p8(a: poly8x8_t, b: poly8x16x3_t, c: uint8x8_t) -> poly8x8_t;
+        [neon] safe fn vqtbx3_s8(a: int8x8_t, b: int8x16x3_t, c: uint8x8_t) -> int8x8_t;
+        [neon] safe fn vqtbx3_u8(a: uint8x8_t, b: uint8x16x3_t, c: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vqtbx3q_p8(a: poly8x16_t, b: poly8x16x3_t, c: uint8x16_t) -> poly8x16_t;
+        [neon] safe fn vqtbx3q_s8(a: int8x16_t, b: int8x16x3_t, c: uint8x16_t) -> int8x16_t;
+        [neon] safe fn vqtbx3q_u8(a: uint8x16_t, b: uint8x16x3_t, c: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vqtbx4_p8(a: poly8x8_t, b: poly8x16x4_t, c: uint8x8_t) -> poly8x8_t;
+        [neon] safe fn vqtbx4_s8(a: int8x8_t, b: int8x16x4_t, c: uint8x8_t) -> int8x8_t;
+        [neon] safe fn vqtbx4_u8(a: uint8x8_t, b: uint8x16x4_t, c: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vqtbx4q_p8(a: poly8x16_t, b: poly8x16x4_t, c: uint8x16_t) -> poly8x16_t;
+        [neon] safe fn vqtbx4q_s8(a: int8x16_t, b: int8x16x4_t, c: uint8x16_t) -> int8x16_t;
+        [neon] safe fn vqtbx4q_u8(a: uint8x16_t, b: uint8x16x4_t, c: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vraddhn_high_s16(a: int8x8_t, b: int16x8_t, c: int16x8_t) -> int8x16_t;
+        [neon] safe fn vraddhn_high_s32(a: int16x4_t, b: int32x4_t, c: int32x4_t) -> int16x8_t;
+        [neon] safe fn vraddhn_high_s64(a: int32x2_t, b: int64x2_t, c: int64x2_t) -> int32x4_t;
+        [neon] safe fn vraddhn_high_u16(a: uint8x8_t, b: uint16x8_t, c: uint16x8_t) -> uint8x16_t;
+        [neon] safe fn vraddhn_high_u32(a: uint16x4_t, b: uint32x4_t, c: uint32x4_t) -> uint16x8_t;
+        [neon] safe fn vraddhn_high_u64(a: uint32x2_t, b: uint64x2_t, c: uint64x2_t) -> uint32x4_t;
+        [neon] safe fn vraddhn_s16(a: int16x8_t, b: int16x8_t) -> int8x8_t;
+        [neon] safe fn vraddhn_s32(a: int32x4_t, b: int32x4_t) -> int16x4_t;
+        [neon] safe fn vraddhn_s64(a: int64x2_t, b: int64x2_t) -> int32x2_t;
+        [neon] safe fn vraddhn_u16(a: uint16x8_t, b: uint16x8_t) -> uint8x8_t;
+        [neon] safe fn vraddhn_u32(a: uint32x4_t, b: uint32x4_t) -> uint16x4_t;
+        [neon] safe fn vraddhn_u64(a: uint64x2_t, b: uint64x2_t) -> uint32x2_t;
+        [neon] safe fn vrbit_p8(a: poly8x8_t) -> poly8x8_t;
+        [neon] safe fn vrbit_s8(a: int8x8_t) -> int8x8_t;
+        [neon] safe fn vrbit_u8(a: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vrbitq_p8(a: poly8x16_t) -> poly8x16_t;
+        [neon] safe fn vrbitq_s8(a: int8x16_t) -> int8x16_t;
+        [neon] safe fn vrbitq_u8(a: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vrecpe_f32(a: float32x2_t) -> float32x2_t;
+        [neon] safe fn vrecpe_f64(a: float64x1_t) -> float64x1_t;
+        [neon] safe fn vrecpe_u32(a: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vrecped_f64(a: f64) -> f64;
+        [neon] safe fn vrecpeq_f32(a: float32x4_t) -> float32x4_t;
+        [neon] safe fn vrecpeq_f64(a: float64x2_t) -> float64x2_t;
+        [neon] safe fn vrecpeq_u32(a: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vrecpes_f32(a: f32) -> f32;
+        [neon] safe fn vrecps_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vrecps_f64(a: float64x1_t, b: float64x1_t) -> float64x1_t;
+        [neon] safe fn vrecpsd_f64(a: f64, b: f64) -> f64;
+        [neon] safe fn vrecpsq_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vrecpsq_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vrecpss_f32(a: f32, b: f32) -> f32;
+        [neon] safe fn vrecpxd_f64(a: f64) -> f64;
+        [neon] safe fn vrecpxs_f32(a: f32) -> f32;
+        [neon] safe fn vreinterpret_f32_f64(a: float64x1_t) -> float32x2_t;
+        [neon] safe fn vreinterpret_f32_p16(a: poly16x4_t) -> float32x2_t;
+        [neon] safe fn vreinterpret_f32_p64(a: poly64x1_t) -> float32x2_t;
+        [neon] safe fn vreinterpret_f32_p8(a: poly8x8_t) -> float32x2_t;
+        [neon] safe fn vreinterpret_f32_s16(a: int16x4_t) -> float32x2_t;
+        [neon] safe fn vreinterpret_f32_s32(a: int32x2_t) -> float32x2_t;
+        [neon] safe fn vreinterpret_f32_s64(a: int64x1_t) -> float32x2_t;
+        [neon] safe fn vreinterpret_f32_s8(a: int8x8_t) -> float32x2_t;
+        [neon] safe fn vreinterpret_f32_u16(a: uint16x4_t) -> float32x2_t;
+        [neon] safe fn vreinterpret_f32_u32(a: uint32x2_t) -> float32x2_t;
+        [neon] safe fn vreinterpret_f32_u64(a: uint64x1_t) -> float32x2_t;
+        [neon] safe fn vreinterpret_f32_u8(a: uint8x8_t) -> float32x2_t;
+        [neon] safe fn vreinterpret_f64_f32(a: float32x2_t) -> float64x1_t;
+        [neon] safe fn vreinterpret_f64_p16(a: poly16x4_t) -> float64x1_t;
+        [neon] safe fn vreinterpret_f64_p64(a: poly64x1_t) -> float64x1_t;
+        [neon] safe fn vreinterpret_f64_p8(a: poly8x8_t) -> float64x1_t;
+        [neon] safe fn vreinterpret_f64_s16(a: int16x4_t) -> float64x1_t;
+        [neon] safe fn vreinterpret_f64_s32(a: int32x2_t) -> float64x1_t;
+        [neon] safe fn vreinterpret_f64_s64(a: int64x1_t) -> float64x1_t;
+        [neon] safe fn vreinterpret_f64_s8(a: int8x8_t) -> float64x1_t;
+        [neon] safe fn vreinterpret_f64_u16(a: uint16x4_t) -> float64x1_t;
+        [neon] safe fn vreinterpret_f64_u32(a: uint32x2_t) -> float64x1_t;
+        [neon] safe fn vreinterpret_f64_u64(a: uint64x1_t) -> float64x1_t;
+        [neon] safe fn vreinterpret_f64_u8(a: uint8x8_t) -> float64x1_t;
+        [neon] safe fn vreinterpret_p16_f32(a: float32x2_t) -> poly16x4_t;
+        [neon] safe fn vreinterpret_p16_f64(a: float64x1_t) -> poly16x4_t;
+        [neon, aes] safe fn vreinterpret_p16_p64(a: poly64x1_t) -> poly16x4_t;
+        [neon] safe fn vreinterpret_p16_p8(a: poly8x8_t) -> poly16x4_t;
+        [neon] safe fn vreinterpret_p16_s16(a: int16x4_t) -> poly16x4_t;
+        [neon] safe fn vreinterpret_p16_s32(a: int32x2_t) -> poly16x4_t;
+        [neon] safe fn vreinterpret_p16_s64(a: int64x1_t) -> poly16x4_t;
+        [neon] safe fn vreinterpret_p16_s8(a: int8x8_t) -> poly16x4_t;
+        [neon] safe fn vreinterpret_p16_u16(a: uint16x4_t) -> poly16x4_t;
+        [neon] safe fn vreinterpret_p16_u32(a: uint32x2_t) -> poly16x4_t;
+        [neon] safe fn vreinterpret_p16_u64(a: uint64x1_t) -> poly16x4_t;
+        [neon] safe fn vreinterpret_p16_u8(a: uint8x8_t) -> poly16x4_t;
+        [neon] safe fn vreinterpret_p64_f32(a: float32x2_t) -> poly64x1_t;
+        [neon] safe fn vreinterpret_p64_f64(a: float64x1_t) -> poly64x1_t;
+        [neon, aes] safe fn vreinterpret_p64_p16(a: poly16x4_t) -> poly64x1_t;
+        [neon, aes] safe fn vreinterpret_p64_p8(a: poly8x8_t) -> poly64x1_t;
+        [neon, aes] safe fn vreinterpret_p64_s16(a: int16x4_t) -> poly64x1_t;
+        [neon, aes] safe fn vreinterpret_p64_s32(a: int32x2_t) -> poly64x1_t;
+        [neon] safe fn vreinterpret_p64_s64(a: int64x1_t) -> poly64x1_t;
+        [neon, aes] safe fn vreinterpret_p64_s8(a: int8x8_t) -> poly64x1_t;
+        [neon, aes] safe fn vreinterpret_p64_u16(a: uint16x4_t) -> poly64x1_t;
+        [neon, aes] safe fn vreinterpret_p64_u32(a: uint32x2_t) -> poly64x1_t;
+        [neon] safe fn vreinterpret_p64_u64(a: uint64x1_t) -> poly64x1_t;
+        [neon, aes] safe fn vreinterpret_p64_u8(a: uint8x8_t) -> poly64x1_t;
+        [neon] safe fn vreinterpret_p8_f32(a: float32x2_t) -> poly8x8_t;
+        [neon] safe fn vreinterpret_p8_f64(a: float64x1_t) -> poly8x8_t;
+        [neon] safe fn vreinterpret_p8_p16(a: poly16x4_t) -> poly8x8_t;
+        [neon, aes] safe fn vreinterpret_p8_p64(a: poly64x1_t) -> poly8x8_t;
+        [neon] safe fn vreinterpret_p8_s16(a: int16x4_t) -> poly8x8_t;
+        [neon] safe fn vreinterpret_p8_s32(a: int32x2_t) -> poly8x8_t;
+        [neon] safe fn vreinterpret_p8_s64(a: int64x1_t) -> poly8x8_t;
+        [neon] safe fn vreinterpret_p8_s8(a: int8x8_t) -> poly8x8_t;
+        [neon] safe fn vreinterpret_p8_u16(a: uint16x4_t) -> poly8x8_t;
+        [neon] safe fn vreinterpret_p8_u32(a: uint32x2_t) -> poly8x8_t;
+        [neon] safe fn vreinterpret_p8_u64(a: uint64x1_t) -> poly8x8_t;
+        [neon] safe fn vreinterpret_p8_u8(a: uint8x8_t) -> poly8x8_t;
+        [neon] safe fn vreinterpret_s16_f32(a: float32x2_t) -> int16x4_t;
+        [neon] safe fn vreinterpret_s16_f64(a: float64x1_t) -> int16x4_t;
+        [neon] safe fn vreinterpret_s16_p16(a: poly16x4_t) -> int16x4_t;
+        [neon, aes] safe fn vreinterpret_s16_p64(a: poly64x1_t) -> int16x4_t;
+        [neon] safe fn vreinterpret_s16_p8(a: poly8x8_t) -> int16x4_t;
+        [neon] safe fn vreinterpret_s16_s32(a: int32x2_t) -> int16x4_t;
+        [neon] safe fn vreinterpret_s16_s64(a: int64x1_t) -> int16x4_t;
+        [neon] safe fn vreinterpret_s16_s8(a: int8x8_t) -> int16x4_t;
+        [neon] safe fn vreinterpret_s16_u16(a: uint16x4_t) -> int16x4_t;
+        [neon] safe fn vreinterpret_s16_u32(a: uint32x2_t) -> int16x4_t;
+        [neon] safe fn vreinterpret_s16_u64(a: uint64x1_t) -> int16x4_t;
+        [neon] safe fn vreinterpret_s16_u8(a: uint8x8_t) -> int16x4_t;
+        [neon] safe fn vreinterpret_s32_f32(a: float32x2_t) -> int32x2_t;
+        [neon] safe fn vreinterpret_s32_f64(a: float64x1_t) -> int32x2_t;
+        [neon] safe fn vreinterpret_s32_p16(a: poly16x4_t) -> int32x2_t;
+        [neon, aes] safe fn vreinterpret_s32_p64(a: poly64x1_t) -> int32x2_t;
+        [neon] safe fn vreinterpret_s32_p8(a: poly8x8_t) -> int32x2_t;
+        [neon] safe fn vreinterpret_s32_s16(a: int16x4_t) -> int32x2_t;
+        [neon] safe fn vreinterpret_s32_s64(a: int64x1_t) -> int32x2_t;
+        [neon] safe fn vreinterpret_s32_s8(a: int8x8_t) -> int32x2_t;
+        [neon] safe fn vreinterpret_s32_u16(a: uint16x4_t) -> int32x2_t;
+        [neon] safe fn vreinterpret_s32_u32(a: uint32x2_t) -> int32x2_t;
+        [neon] safe fn vreinterpret_s32_u64(a: uint64x1_t) -> int32x2_t;
+        [neon] safe fn vreinterpret_s32_u8(a: uint8x8_t) -> int32x2_t;
+        [neon] safe fn vreinterpret_s64_f32(a: float32x2_t) -> int64x1_t;
+        [neon] safe fn vreinterpret_s64_f64(a: float64x1_t) -> int64x1_t;
+        [neon] safe fn vreinterpret_s64_p16(a: poly16x4_t) -> int64x1_t;
+        [neon] safe fn vreinterpret_s64_p64(a: poly64x1_t) -> int64x1_t;
+        [neon] safe fn vreinterpret_s64_p8(a: poly8x8_t) -> int64x1_t;
+        [neon] safe fn vreinterpret_s64_s16(a: int16x4_t) -> int64x1_t;
+        [neon] safe fn vreinterpret_s64_s32(a: int32x2_t) -> int64x1_t;
+        [neon] safe fn vreinterpret_s64_s8(a: int8x8_t) -> int64x1_t;
+        [neon] safe fn vreinterpret_s64_u16(a: uint16x4_t) -> int64x1_t;
+        [neon] safe fn vreinterpret_s64_u32(a: uint32x2_t) -> int64x1_t;
+        [neon] safe fn vreinterpret_s64_u64(a: uint64x1_t) -> int64x1_t;
+        [neon] safe fn vreinterpret_s64_u8(a: uint8x8_t) -> int64x1_t;
+        [neon] safe fn vreinterpret_s8_f32(a: float32x2_t) -> int8x8_t;
+        [neon] safe fn vreinterpret_s8_f64(a: float64x1_t) -> int8x8_t;
+        [neon] safe fn vreinterpret_s8_p16(a: poly16x4_t) -> int8x8_t;
+        [neon, aes] safe fn vreinterpret_s8_p64(a: poly64x1_t) -> int8x8_t;
+        [neon] safe fn vreinterpret_s8_p8(a: poly8x8_t) -> int8x8_t;
+        [neon] safe fn vreinterpret_s8_s16(a: int16x4_t) -> int8x8_t;
+        [neon] safe fn vreinterpret_s8_s32(a: int32x2_t) -> int8x8_t;
+        [neon] safe fn vreinterpret_s8_s64(a: int64x1_t) -> int8x8_t;
+        [neon] safe fn vreinterpret_s8_u16(a: uint16x4_t) -> int8x8_t;
+        [neon] safe fn vreinterpret_s8_u32(a: uint32x2_t) -> int8x8_t;
+        [neon] safe fn vreinterpret_s8_u64(a: uint64x1_t) -> int8x8_t;
+        [neon] safe fn vreinterpret_s8_u8(a: uint8x8_t) -> int8x8_t;
+        [neon] safe fn vreinterpret_u16_f32(a: float32x2_t) -> uint16x4_t;
+        [neon] safe fn vreinterpret_u16_f64(a: float64x1_t) -> uint16x4_t;
+        [neon] safe fn vreinterpret_u16_p16(a: poly16x4_t) -> uint16x4_t;
+        [neon, aes] safe fn vreinterpret_u16_p64(a: poly64x1_t) -> uint16x4_t;
+        [neon] safe fn vreinterpret_u16_p8(a: poly8x8_t) -> uint16x4_t;
+        [neon] safe fn vreinterpret_u16_s16(a: int16x4_t) -> uint16x4_t;
+        [neon] safe fn vreinterpret_u16_s32(a: int32x2_t) -> uint16x4_t;
+        [neon] safe fn vreinterpret_u16_s64(a: int64x1_t) -> uint16x4_t;
+        [neon] safe fn vreinterpret_u16_s8(a: int8x8_t) -> uint16x4_t;
+        [neon] safe fn vreinterpret_u16_u32(a: uint32x2_t) -> uint16x4_t;
+        [neon] safe fn vreinterpret_u16_u64(a: uint64x1_t) -> uint16x4_t;
+        [neon] safe fn vreinterpret_u16_u8(a: uint8x8_t) -> uint16x4_t;
+        [neon] safe fn vreinterpret_u32_f32(a: float32x2_t) -> uint32x2_t;
+        [neon] safe fn vreinterpret_u32_f64(a: float64x1_t) -> uint32x2_t;
+        [neon] safe fn vreinterpret_u32_p16(a: poly16x4_t) -> uint32x2_t;
+        [neon, aes] safe fn vreinterpret_u32_p64(a: poly64x1_t) -> uint32x2_t;
+        [neon] safe fn vreinterpret_u32_p8(a: poly8x8_t) -> uint32x2_t;
+        [neon] safe fn vreinterpret_u32_s16(a: int16x4_t) -> uint32x2_t;
+        [neon] safe fn vreinterpret_u32_s32(a: int32x2_t) -> uint32x2_t;
+        [neon] safe fn vreinterpret_u32_s64(a: int64x1_t) -> uint32x2_t;
+        [neon] safe fn vreinterpret_u32_s8(a: int8x8_t) -> uint32x2_t;
+        [neon] safe fn vreinterpret_u32_u16(a: uint16x4_t) -> uint32x2_t;
+        [neon] safe fn vreinterpret_u32_u64(a: uint64x1_t) -> uint32x2_t;
+        [neon] safe fn vreinterpret_u32_u8(a: uint8x8_t) -> uint32x2_t;
+        [neon] safe fn vreinterpret_u64_f32(a: float32x2_t) -> uint64x1_t;
+        [neon] safe fn vreinterpret_u64_f64(a: float64x1_t) -> uint64x1_t;
+        [neon] safe fn vreinterpret_u64_p16(a: poly16x4_t) -> uint64x1_t;
+        [neon] safe fn vreinterpret_u64_p64(a: poly64x1_t) -> uint64x1_t;
+        [neon] safe fn vreinterpret_u64_p8(a: poly8x8_t) -> uint64x1_t;
+        [neon] safe fn vreinterpret_u64_s16(a: int16x4_t) -> uint64x1_t;
+        [neon] safe fn vreinterpret_u64_s32(a: int32x2_t) -> uint64x1_t;
+        [neon] safe fn vreinterpret_u64_s64(a: int64x1_t) -> uint64x1_t;
+        [neon] safe fn vreinterpret_u64_s8(a: int8x8_t) -> uint64x1_t;
+        [neon] safe fn vreinterpret_u64_u16(a: uint16x4_t) -> uint64x1_t;
+        [neon] safe fn vreinterpret_u64_u32(a: uint32x2_t) -> uint64x1_t;
+        [neon] safe fn vreinterpret_u64_u8(a: uint8x8_t) -> uint64x1_t;
+        [neon] safe fn vreinterpret_u8_f32(a: float32x2_t) -> uint8x8_t;
+        [neon] safe fn vreinterpret_u8_f64(a: float64x1_t) -> uint8x8_t;
+        [neon] safe fn vreinterpret_u8_p16(a: poly16x4_t) -> uint8x8_t;
+        [neon, aes] safe fn vreinterpret_u8_p64(a: poly64x1_t) -> uint8x8_t;
+        [neon] safe fn vreinterpret_u8_p8(a: poly8x8_t) -> uint8x8_t;
+        [neon] safe fn vreinterpret_u8_s16(a: int16x4_t) -> uint8x8_t;
+        [neon] safe fn vreinterpret_u8_s32(a: int32x2_t) -> uint8x8_t;
+        [neon] safe fn vreinterpret_u8_s64(a: int64x1_t) -> uint8x8_t;
+        [neon] safe fn vreinterpret_u8_s8(a: int8x8_t) -> uint8x8_t;
+        [neon] safe fn vreinterpret_u8_u16(a: uint16x4_t) -> uint8x8_t;
+        [neon] safe fn vreinterpret_u8_u32(a: uint32x2_t) -> uint8x8_t;
+        [neon] safe fn vreinterpret_u8_u64(a: uint64x1_t) -> uint8x8_t;
+        [neon] safe fn vreinterpretq_f32_f64(a: float64x2_t) -> float32x4_t;
+        [neon] safe fn vreinterpretq_f32_p128(a: u128) -> float32x4_t;
+        [neon] safe fn vreinterpretq_f32_p16(a: poly16x8_t) -> float32x4_t;
+        [neon] safe fn vreinterpretq_f32_p64(a: poly64x2_t) -> float32x4_t;
+        [neon] safe fn vreinterpretq_f32_p8(a: poly8x16_t) -> float32x4_t;
+        [neon] safe fn vreinterpretq_f32_s16(a: int16x8_t) -> float32x4_t;
+        [neon] safe fn vreinterpretq_f32_s32(a: int32x4_t) -> float32x4_t;
+        [neon] safe fn vreinterpretq_f32_s64(a: int64x2_t) -> float32x4_t;
+        [neon] safe fn vreinterpretq_f32_s8(a: int8x16_t) -> float32x4_t;
+        [neon] safe fn vreinterpretq_f32_u16(a: uint16x8_t) -> float32x4_t;
+        [neon] safe fn vreinterpretq_f32_u32(a: uint32x4_t) -> float32x4_t;
+        [neon] safe fn vreinterpretq_f32_u64(a: uint64x2_t) -> float32x4_t;
+        [neon] safe fn vreinterpretq_f32_u8(a: uint8x16_t) -> float32x4_t;
+        [neon] safe fn vreinterpretq_f64_f32(a: float32x4_t) -> float64x2_t;
+        [neon] safe fn vreinterpretq_f64_p128(a: u128) -> float64x2_t;
+        [neon] safe fn vreinterpretq_f64_p16(a: poly16x8_t) -> float64x2_t;
+        [neon] safe fn vreinterpretq_f64_p64(a: poly64x2_t) -> float64x2_t;
+        [neon] safe fn vreinterpretq_f64_p8(a: poly8x16_t) -> float64x2_t;
+        [neon] safe fn vreinterpretq_f64_s16(a: int16x8_t) -> float64x2_t;
+        [neon] safe fn vreinterpretq_f64_s32(a: int32x4_t) -> float64x2_t;
+        [neon] safe fn vreinterpretq_f64_s64(a: int64x2_t) -> float64x2_t;
+        [neon] safe fn vreinterpretq_f64_s8(a: int8x16_t) -> float64x2_t;
+        [neon] safe fn vreinterpretq_f64_u16(a: uint16x8_t) -> float64x2_t;
+        [neon] safe fn vreinterpretq_f64_u32(a: uint32x4_t) -> float64x2_t;
+        [neon] safe fn vreinterpretq_f64_u64(a: uint64x2_t) -> float64x2_t;
+        [neon] safe fn vreinterpretq_f64_u8(a: uint8x16_t) -> float64x2_t;
+        [neon] safe fn vreinterpretq_p128_f32(a: float32x4_t) -> u128;
+        [neon] safe fn vreinterpretq_p128_f64(a: float64x2_t) -> u128;
+        [neon, aes] safe fn vreinterpretq_p128_p16(a: poly16x8_t) -> u128;
+        [neon, aes] safe fn vreinterpretq_p128_p64(a: poly64x2_t) -> u128;
+        [neon, aes] safe fn vreinterpretq_p128_p8(a: poly8x16_t) -> u128;
+        [neon, aes] safe fn vreinterpretq_p128_s16(a: int16x8_t) -> u128;
+        [neon, aes] safe fn vreinterpretq_p128_s32(a: int32x4_t) -> u128;
+        [neon, aes] safe fn vreinterpretq_p128_s64(a: int64x2_t) -> u128;
+        [neon, aes] safe fn vreinterpretq_p128_s8(a: int8x16_t) -> u128;
+        [neon, aes] safe fn vreinterpretq_p128_u16(a: uint16x8_t) -> u128;
+        [neon, aes] safe fn vreinterpretq_p128_u32(a: uint32x4_t) -> u128;
+        [neon, aes] safe fn vreinterpretq_p128_u64(a: uint64x2_t) -> u128;
+        [neon, aes] safe fn vreinterpretq_p128_u8(a: uint8x16_t) -> u128;
+        [neon] safe fn vreinterpretq_p16_f32(a: float32x4_t) -> poly16x8_t;
+        [neon] safe fn vreinterpretq_p16_f64(a: float64x2_t) -> poly16x8_t;
+        [neon, aes] safe fn vreinterpretq_p16_p128(a: u128) -> poly16x8_t;
+        [neon, aes] safe fn vreinterpretq_p16_p64(a: poly64x2_t) -> poly16x8_t;
+        [neon] safe fn vreinterpretq_p16_p8(a: poly8x16_t) -> poly16x8_t;
+        [neon] safe fn vreinterpretq_p16_s16(a: int16x8_t) -> poly16x8_t;
+        [neon] safe fn vreinterpretq_p16_s32(a: int32x4_t) -> poly16x8_t;
+        [neon] safe fn vreinterpretq_p16_s64(a: int64x2_t) -> poly16x8_t;
+        [neon] safe fn vreinterpretq_p16_s8(a: int8x16_t) -> poly16x8_t;
+        [neon] safe fn vreinterpretq_p16_u16(a: uint16x8_t) -> poly16x8_t;
+        [neon] safe fn vreinterpretq_p16_u32(a: uint32x4_t) -> poly16x8_t;
+        [neon] safe fn vreinterpretq_p16_u64(a: uint64x2_t) -> poly16x8_t;
+        [neon] safe fn vreinterpretq_p16_u8(a: uint8x16_t) -> poly16x8_t;
+        [neon] safe fn vreinterpretq_p64_f32(a: float32x4_t) -> poly64x2_t;
+        [neon] safe fn vreinterpretq_p64_f64(a: float64x2_t) -> poly64x2_t;
+        [neon, aes] safe fn vreinterpretq_p64_p128(a: u128) -> poly64x2_t;
+        [neon, aes] safe fn vreinterpretq_p64_p16(a: poly16x8_t) -> poly64x2_t;
+        [neon, aes] safe fn vreinterpretq_p64_p8(a: poly8x16_t) -> poly64x2_t;
+        [neon, aes] safe fn vreinterpretq_p64_s16(a: int16x8_t) -> poly64x2_t;
+        [neon, aes] safe fn vreinterpretq_p64_s32(a: int32x4_t) -> poly64x2_t;
+        [neon] safe fn vreinterpretq_p64_s64(a: int64x2_t) -> poly64x2_t;
+        [neon, aes] safe fn vreinterpretq_p64_s8(a: int8x16_t) -> poly64x2_t;
+        [neon, aes] safe fn vreinterpretq_p64_u16(a: uint16x8_t) -> poly64x2_t;
+        [neon, aes] safe fn vreinterpretq_p64_u32(a: uint32x4_t) -> poly64x2_t;
+        [neon] safe fn vreinterpretq_p64_u64(a: uint64x2_t) -> poly64x2_t;
+        [neon, aes] safe fn vreinterpretq_p64_u8(a: uint8x16_t) -> poly64x2_t;
+        [neon] safe fn vreinterpretq_p8_f32(a: float32x4_t) -> poly8x16_t;
+        [neon] safe fn vreinterpretq_p8_f64(a: float64x2_t) -> poly8x16_t;
+        [neon, aes] safe fn vreinterpretq_p8_p128(a: u128) -> poly8x16_t;
+        [neon] safe fn vreinterpretq_p8_p16(a: poly16x8_t) -> poly8x16_t;
+        [neon, aes] safe fn vreinterpretq_p8_p64(a: poly64x2_t) -> poly8x16_t;
+        [neon] safe fn vreinterpretq_p8_s16(a: int16x8_t) -> poly8x16_t;
+        [neon] safe fn vreinterpretq_p8_s32(a: int32x4_t) -> poly8x16_t;
+        [neon] safe fn vreinterpretq_p8_s64(a: int64x2_t) -> poly8x16_t;
+        [neon] safe fn vreinterpretq_p8_s8(a: int8x16_t) -> poly8x16_t;
+        [neon] safe fn vreinterpretq_p8_u16(a: uint16x8_t) -> poly8x16_t;
+        [neon] safe fn vreinterpretq_p8_u32(a: uint32x4_t) -> poly8x16_t;
+        [neon] safe fn vreinterpretq_p8_u64(a: uint64x2_t) -> poly8x16_t;
+        [neon] safe fn vreinterpretq_p8_u8(a: uint8x16_t) -> poly8x16_t;
+        [neon] safe fn vreinterpretq_s16_f32(a: float32x4_t) -> int16x8_t;
+        [neon] safe fn vreinterpretq_s16_f64(a: float64x2_t) -> int16x8_t;
+        [neon, aes] safe fn vreinterpretq_s16_p128(a: u128) -> int16x8_t;
+        [neon] safe fn vreinterpretq_s16_p16(a: poly16x8_t) -> int16x8_t;
+        [neon, aes] safe fn vreinterpretq_s16_p64(a: poly64x2_t) -> int16x8_t;
+        [neon] safe fn vreinterpretq_s16_p8(a: poly8x16_t) -> int16x8_t;
+        [neon] safe fn vreinterpretq_s16_s32(a: int32x4_t) -> int16x8_t;
+        [neon] safe fn vreinterpretq_s16_s64(a: int64x2_t) -> int16x8_t;
+        [neon] safe fn vreinterpretq_s16_s8(a: int8x16_t) -> int16x8_t;
+        [neon] safe fn vreinterpretq_s16_u16(a: uint16x8_t) -> int16x8_t;
+        [neon] safe fn vreinterpretq_s16_u32(a: uint32x4_t) -> int16x8_t;
+        [neon] safe fn vreinterpretq_s16_u64(a: uint64x2_t) -> int16x8_t;
+        [neon] safe fn vreinterpretq_s16_u8(a: uint8x16_t) -> int16x8_t;
+        [neon] safe fn vreinterpretq_s32_f32(a: float32x4_t) -> int32x4_t;
+        [neon] safe fn vreinterpretq_s32_f64(a: float64x2_t) -> int32x4_t;
+        [neon, aes] safe fn vreinterpretq_s32_p128(a: u128) -> int32x4_t;
+        [neon] safe fn vreinterpretq_s32_p16(a: poly16x8_t) -> int32x4_t;
+        [neon, aes] safe fn vreinterpretq_s32_p64(a: poly64x2_t) -> int32x4_t;
+        [neon] safe fn vreinterpretq_s32_p8(a: poly8x16_t) -> int32x4_t;
+        [neon] safe fn vreinterpretq_s32_s16(a: int16x8_t) -> int32x4_t;
+        [neon] safe fn vreinterpretq_s32_s64(a: int64x2_t) -> int32x4_t;
+        [neon] safe fn vreinterpretq_s32_s8(a: int8x16_t) -> int32x4_t;
+        [neon] safe fn vreinterpretq_s32_u16(a: uint16x8_t) -> int32x4_t;
+        [neon] safe fn vreinterpretq_s32_u32(a: uint32x4_t) -> int32x4_t;
+        [neon] safe fn vreinterpretq_s32_u64(a: uint64x2_t) -> int32x4_t;
+        [neon] safe fn vreinterpretq_s32_u8(a: uint8x16_t) -> int32x4_t;
+        [neon] safe fn vreinterpretq_s64_f32(a: float32x4_t) -> int64x2_t;
+        [neon] safe fn vreinterpretq_s64_f64(a: float64x2_t) -> int64x2_t;
+        [neon, aes] safe fn vreinterpretq_s64_p128(a: u128) -> int64x2_t;
+        [neon] safe fn vreinterpretq_s64_p16(a: poly16x8_t) -> int64x2_t;
+        [neon] safe fn vreinterpretq_s64_p64(a: poly64x2_t) -> int64x2_t;
+        [neon] safe fn vreinterpretq_s64_p8(a: poly8x16_t) -> int64x2_t;
+        [neon] safe fn vreinterpretq_s64_s16(a: int16x8_t) -> int64x2_t;
+        [neon] safe fn vreinterpretq_s64_s32(a: int32x4_t) -> int64x2_t;
+        [neon] safe fn vreinterpretq_s64_s8(a: int8x16_t) -> int64x2_t;
+        [neon] safe fn vreinterpretq_s64_u16(a: uint16x8_t) -> int64x2_t;
+        [neon] safe fn vreinterpretq_s64_u32(a: uint32x4_t) -> int64x2_t;
+        [neon] safe fn vreinterpretq_s64_u64(a: uint64x2_t) -> int64x2_t;
+        [neon] safe fn vreinterpretq_s64_u8(a: uint8x16_t) -> int64x2_t;
+        [neon] safe fn vreinterpretq_s8_f32(a: float32x4_t) -> int8x16_t;
+        [neon] safe fn vreinterpretq_s8_f64(a: float64x2_t) -> int8x16_t;
+        [neon, aes] safe fn vreinterpretq_s8_p128(a: u128) -> int8x16_t;
+        [neon] safe fn vreinterpretq_s8_p16(a: poly16x8_t) -> int8x16_t;
+        [neon, aes] safe fn vreinterpretq_s8_p64(a: poly64x2_t) -> int8x16_t;
+        [neon] safe fn vreinterpretq_s8_p8(a: poly8x16_t) -> int8x16_t;
+        [neon] safe fn vreinterpretq_s8_s16(a: int16x8_t) -> int8x16_t;
+        [neon] safe fn vreinterpretq_s8_s32(a: int32x4_t) -> int8x16_t;
+        [neon] safe fn vreinterpretq_s8_s64(a: int64x2_t) -> int8x16_t;
+        [neon] safe fn vreinterpretq_s8_u16(a: uint16x8_t) -> int8x16_t;
+        [neon] safe fn vreinterpretq_s8_u32(a: uint32x4_t) -> int8x16_t;
+        [neon] safe fn vreinterpretq_s8_u64(a: uint64x2_t) -> int8x16_t;
+        [neon] safe fn vreinterpretq_s8_u8(a: uint8x16_t) -> int8x16_t;
+        [neon] safe fn vreinterpretq_u16_f32(a: float32x4_t) -> uint16x8_t;
+        [neon] safe fn vreinterpretq_u16_f64(a: float64x2_t) -> uint16x8_t;
+        [neon, aes] safe fn vreinterpretq_u16_p128(a: u128) -> uint16x8_t;
+        [neon] safe fn vreinterpretq_u16_p16(a: poly16x8_t) -> uint16x8_t;
+        [neon, aes] safe fn vreinterpretq_u16_p64(a: poly64x2_t) -> uint16x8_t;
+        [neon] safe fn vreinterpretq_u16_p8(a: poly8x16_t) -> uint16x8_t;
+        [neon] safe fn vreinterpretq_u16_s16(a: int16x8_t) -> uint16x8_t;
+        [neon] safe fn vreinterpretq_u16_s32(a: int32x4_t) -> uint16x8_t;
+        [neon] safe fn vreinterpretq_u16_s64(a: int64x2_t) -> uint16x8_t;
+        [neon] safe fn vreinterpretq_u16_s8(a: int8x16_t) -> uint16x8_t;
+        [neon] safe fn vreinterpretq_u16_u32(a: uint32x4_t) -> uint16x8_t;
+        [neon] safe fn vreinterpretq_u16_u64(a: uint64x2_t) -> uint16x8_t;
+        [neon] safe fn vreinterpretq_u16_u8(a: uint8x16_t) -> uint16x8_t;
+        [neon] safe fn vreinterpretq_u32_f32(a: float32x4_t) -> uint32x4_t;
+        [neon] safe fn vreinterpretq_u32_f64(a: float64x2_t) -> uint32x4_t;
+        [neon, aes] safe fn vreinterpretq_u32_p128(a: u128) -> uint32x4_t;
+        [neon] safe fn vreinterpretq_u32_p16(a: poly16x8_t) -> uint32x4_t;
+        [neon, aes] safe fn vreinterpretq_u32_p64(a: poly64x2_t) -> uint32x4_t;
+        [neon] safe fn vreinterpretq_u32_p8(a: poly8x16_t) -> uint32x4_t;
+        [neon] safe fn vreinterpretq_u32_s16(a: int16x8_t) -> uint32x4_t;
+        [neon] safe fn vreinterpretq_u32_s32(a: int32x4_t) -> uint32x4_t;
+        [neon] safe fn vreinterpretq_u32_s64(a: int64x2_t) -> uint32x4_t;
+        [neon] safe fn vreinterpretq_u32_s8(a: int8x16_t) -> uint32x4_t;
+        [neon] safe fn vreinterpretq_u32_u16(a: uint16x8_t) -> uint32x4_t;
+        [neon] safe fn vreinterpretq_u32_u64(a: uint64x2_t) -> uint32x4_t;
+        [neon] safe fn vreinterpretq_u32_u8(a: uint8x16_t) -> uint32x4_t;
+        [neon] safe fn vreinterpretq_u64_f32(a: float32x4_t) -> uint64x2_t;
+        [neon] safe fn vreinterpretq_u64_f64(a: float64x2_t) -> uint64x2_t;
+        [neon, aes] safe fn vreinterpretq_u64_p128(a: u128) -> uint64x2_t;
+        [neon] safe fn vreinterpretq_u64_p16(a: poly16x8_t) -> uint64x2_t;
+        [neon] safe fn vreinterpretq_u64_p64(a: poly64x2_t) -> uint64x2_t;
+        [neon] safe fn vreinterpretq_u64_p8(a: poly8x16_t) -> uint64x2_t;
+        [neon] safe fn vreinterpretq_u64_s16(a: int16x8_t) -> uint64x2_t;
+        [neon] safe fn vreinterpretq_u64_s32(a: int32x4_t) -> uint64x2_t;
+        [neon] safe fn vreinterpretq_u64_s64(a: int64x2_t) -> uint64x2_t;
+        [neon] safe fn vreinterpretq_u64_s8(a: int8x16_t) -> uint64x2_t;
+        [neon] safe fn vreinterpretq_u64_u16(a: uint16x8_t) -> uint64x2_t;
+        [neon] safe fn vreinterpretq_u64_u32(a: uint32x4_t) -> uint64x2_t;
+        [neon] safe fn vreinterpretq_u64_u8(a: uint8x16_t) -> uint64x2_t;
+        [neon] safe fn vreinterpretq_u8_f32(a: float32x4_t) -> uint8x16_t;
+        [neon] safe fn vreinterpretq_u8_f64(a: float64x2_t) -> uint8x16_t;
+        [neon, aes] safe fn vreinterpretq_u8_p128(a: u128) -> uint8x16_t;
+        [neon] safe fn vreinterpretq_u8_p16(a: poly16x8_t) -> uint8x16_t;
+        [neon, aes] safe fn vreinterpretq_u8_p64(a: poly64x2_t) -> uint8x16_t;
+        [neon] safe fn vreinterpretq_u8_p8(a: poly8x16_t) -> uint8x16_t;
+        [neon] safe fn vreinterpretq_u8_s16(a: int16x8_t) -> uint8x16_t;
+        [neon] safe fn vreinterpretq_u8_s32(a: int32x4_t) -> uint8x16_t;
+        [neon] safe fn vreinterpretq_u8_s64(a: int64x2_t) -> uint8x16_t;
+        [neon] safe fn vreinterpretq_u8_s8(a: int8x16_t) -> uint8x16_t;
+        [neon] safe fn vreinterpretq_u8_u16(a: uint16x8_t) -> uint8x16_t;
+        [neon] safe fn vreinterpretq_u8_u32(a: uint32x4_t) -> uint8x16_t;
+        [neon] safe fn vreinterpretq_u8_u64(a: uint64x2_t) -> uint8x16_t;
+        [neon] safe fn vrev16_p8(a: poly8x8_t) -> poly8x8_t;
+        [neon] safe fn vrev16_s8(a: int8x8_t) -> int8x8_t;
+        [neon] safe fn vrev16_u8(a: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vrev16q_p8(a: poly8x16_t) -> poly8x16_t;
+        [neon] safe fn vrev16q_s8(a: int8x16_t) -> int8x16_t;
+        [neon] safe fn vrev16q_u8(a: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vrev32_p16(a: poly16x4_t) -> poly16x4_t;
+        [neon] safe fn vrev32_p8(a: poly8x8_t) -> poly8x8_t;
+        [neon] safe fn vrev32_s16(a: int16x4_t) -> int16x4_t;
+        [neon] safe fn vrev32_s8(a: int8x8_t) -> int8x8_t;
+        [neon] safe fn vrev32_u16(a: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vrev32_u8(a: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vrev32q_p16(a: poly16x8_t) -> poly16x8_t;
+        [neon] safe fn vrev32q_p8(a: poly8x16_t) -> poly8x16_t;
+        [neon] safe fn vrev32q_s16(a: int16x8_t) -> int16x8_t;
+        [neon] safe fn vrev32q_s8(a: int8x16_t) -> int8x16_t;
+        [neon] safe fn vrev32q_u16(a: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vrev32q_u8(a: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vrev64_f32(a: float32x2_t) -> float32x2_t;
+        [neon] safe fn vrev64_p16(a: poly16x4_t) -> poly16x4_t;
+        [neon] safe fn vrev64_p8(a: poly8x8_t) -> poly8x8_t;
+        [neon] safe fn vrev64_s16(a: int16x4_t) -> int16x4_t;
+        [neon] safe fn vrev64_s32(a: int32x2_t) -> int32x2_t;
+        [neon] safe fn vrev64_s8(a: int8x8_t) -> int8x8_t;
+        [neon] safe fn vrev64_u16(a: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vrev64_u32(a: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vrev64_u8(a: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vrev64q_f32(a: float32x4_t) -> float32x4_t;
+        [neon] safe fn vrev64q_p16(a: poly16x8_t) -> poly16x8_t;
+        [neon] safe fn vrev64q_p8(a: poly8x16_t) -> poly8x16_t;
+        [neon] safe fn vrev64q_s16(a: int16x8_t) -> int16x8_t;
+        [neon] safe fn vrev64q_s32(a: int32x4_t) -> int32x4_t;
+        [neon] safe fn vrev64q_s8(a: int8x16_t) -> int8x16_t;
+        [neon] safe fn vrev64q_u16(a: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vrev64q_u32(a: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vrev64q_u8(a: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vrhadd_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vrhadd_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vrhadd_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vrhadd_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vrhadd_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vrhadd_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vrhaddq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vrhaddq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vrhaddq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vrhaddq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vrhaddq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vrhaddq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vrnd_f32(a: float32x2_t) -> float32x2_t;
+        [neon] safe fn vrnd_f64(a: float64x1_t) -> float64x1_t;
+        [neon] safe fn vrnda_f32(a: float32x2_t) -> float32x2_t;
+        [neon] safe fn vrnda_f64(a: float64x1_t) -> float64x1_t;
+        [neon] safe fn vrndaq_f32(a: float32x4_t) -> float32x4_t;
+        [neon] safe fn vrndaq_f64(a: float64x2_t) -> float64x2_t;
+        [neon] safe fn vrndi_f32(a: float32x2_t) -> float32x2_t;
+        [neon] safe fn vrndi_f64(a: float64x1_t) -> float64x1_t;
+        [neon] safe fn vrndiq_f32(a: float32x4_t) -> float32x4_t;
+        [neon] safe fn vrndiq_f64(a: float64x2_t) -> float64x2_t;
+        [neon] safe fn vrndm_f32(a: float32x2_t) -> float32x2_t;
+        [neon] safe fn vrndm_f64(a: float64x1_t) -> float64x1_t;
+        [neon] safe fn vrndmq_f32(a: float32x4_t) -> float32x4_t;
+        [neon] safe fn vrndmq_f64(a: float64x2_t) -> float64x2_t;
+        [neon] safe fn vrndn_f32(a: float32x2_t) -> float32x2_t;
+        [neon] safe fn vrndn_f64(a: float64x1_t) -> float64x1_t;
+        [neon] safe fn vrndnq_f32(a: float32x4_t) -> float32x4_t;
+        [neon] safe fn vrndnq_f64(a: float64x2_t) -> float64x2_t;
+        [neon] safe fn vrndns_f32(a: f32) -> f32;
+        [neon] safe fn vrndp_f32(a: float32x2_t) -> float32x2_t;
+        [neon] safe fn vrndp_f64(a: float64x1_t) -> float64x1_t;
+        [neon] safe fn vrndpq_f32(a: float32x4_t) -> float32x4_t;
+        [neon] safe fn vrndpq_f64(a: float64x2_t) -> float64x2_t;
+        [neon] safe fn vrndq_f32(a: float32x4_t) -> float32x4_t;
+        [neon] safe fn vrndq_f64(a: float64x2_t) -> float64x2_t;
+        [neon] safe fn vrndx_f32(a: float32x2_t) -> float32x2_t;
+        [neon] safe fn vrndx_f64(a: float64x1_t) -> float64x1_t;
+        [neon] safe fn vrndxq_f32(a: float32x4_t) -> float32x4_t;
+        [neon] safe fn vrndxq_f64(a: float64x2_t) -> float64x2_t;
+        [neon] safe fn vrshl_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vrshl_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vrshl_s64(a: int64x1_t, b: int64x1_t) -> int64x1_t;
+        [neon] safe fn vrshl_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vrshl_u16(a: uint16x4_t, b: int16x4_t) -> uint16x4_t;
+        [neon] safe fn vrshl_u32(a: uint32x2_t, b: int32x2_t) -> uint32x2_t;
+        [neon] safe fn vrshl_u64(a: uint64x1_t, b: int64x1_t) -> uint64x1_t;
+        [neon] safe fn vrshl_u8(a: uint8x8_t, b: int8x8_t) -> uint8x8_t;
+        [neon] safe fn vrshld_s64(a: i64, b: i64) -> i64;
+        [neon] safe fn vrshld_u64(a: u64, b: i64) -> u64;
+        [neon] safe fn vrshlq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vrshlq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vrshlq_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vrshlq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vrshlq_u16(a: uint16x8_t, b: int16x8_t) -> uint16x8_t;
+        [neon] safe fn vrshlq_u32(a: uint32x4_t, b: int32x4_t) -> uint32x4_t;
+        [neon] safe fn vrshlq_u64(a: uint64x2_t, b: int64x2_t) -> uint64x2_t;
+        [neon] safe fn vrshlq_u8(a: uint8x16_t, b: int8x16_t) -> uint8x16_t;
+        [neon] safe fn vrshr_n_s16<const N: i32>(a: int16x4_t) -> int16x4_t;
+        [neon] safe fn vrshr_n_s32<const N: i32>(a: int32x2_t) -> int32x2_t;
+        [neon] safe fn vrshr_n_s64<const N: i32>(a: int64x1_t) -> int64x1_t;
+        [neon] safe fn vrshr_n_s8<const N: i32>(a: int8x8_t) -> int8x8_t;
+        [neon] safe fn vrshr_n_u16<const N: i32>(a: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vrshr_n_u32<const N: i32>(a: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vrshr_n_u64<const N: i32>(a: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vrshr_n_u8<const N: i32>(a: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vrshrd_n_s64<const N: i32>(a: i64) -> i64;
+        [neon] safe fn vrshrd_n_u64<const N: i32>(a: u64) -> u64;
+        [neon] safe fn vrshrn_high_n_s16<const N: i32>(a: int8x8_t, b: int16x8_t) -> int8x16_t;
+        [neon] safe fn vrshrn_high_n_s32<const N: i32>(a: int16x4_t, b: int32x4_t) -> int16x8_t;
+        [neon] safe fn vrshrn_high_n_s64<const N: i32>(a: int32x2_t, b: int64x2_t) -> int32x4_t;
+        [neon] safe fn vrshrn_high_n_u16<const N: i32>(a: uint8x8_t, b: uint16x8_t) -> uint8x16_t;
+        [neon] safe fn vrshrn_high_n_u32<const N: i32>(a: uint16x4_t, b: uint32x4_t) -> uint16x8_t;
+        [neon] safe fn vrshrn_high_n_u64<const N: i32>(a: uint32x2_t, b: uint64x2_t) -> uint32x4_t;
+        [neon] safe fn vrshrn_n_s16<const N: i32>(a: int16x8_t) -> int8x8_t;
+        [neon] safe fn vrshrn_n_s32<const N: i32>(a: int32x4_t) -> int16x4_t;
+        [neon] safe fn vrshrn_n_s64<const N: i32>(a: int64x2_t) -> int32x2_t;
+        [neon] safe fn vrshrn_n_u16<const N: i32>(a: uint16x8_t) -> uint8x8_t;
+        [neon] safe fn vrshrn_n_u32<const N: i32>(a: uint32x4_t) -> uint16x4_t;
+        [neon] safe fn vrshrn_n_u64<const N: i32>(a: uint64x2_t) -> uint32x2_t;
+        [neon] safe fn vrshrq_n_s16<const N: i32>(a: int16x8_t) -> int16x8_t;
+        [neon] safe fn vrshrq_n_s32<const N: i32>(a: int32x4_t) -> int32x4_t;
+        [neon] safe fn vrshrq_n_s64<const N: i32>(a: int64x2_t) -> int64x2_t;
+        [neon] safe fn vrshrq_n_s8<const N: i32>(a: int8x16_t) -> int8x16_t;
+        [neon] safe fn vrshrq_n_u16<const N: i32>(a: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vrshrq_n_u32<const N: i32>(a: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vrshrq_n_u64<const N: i32>(a: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vrshrq_n_u8<const N: i32>(a: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vrsqrte_f32(a: float32x2_t) -> float32x2_t;
+        [neon] safe fn vrsqrte_f64(a: float64x1_t) -> float64x1_t;
+        [neon] safe fn vrsqrte_u32(a: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vrsqrted_f64(a: f64) -> f64;
+        [neon] safe fn vrsqrteq_f32(a: float32x4_t) -> float32x4_t;
+        [neon] safe fn vrsqrteq_f64(a: float64x2_t) -> float64x2_t;
+        [neon] safe fn vrsqrteq_u32(a: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vrsqrtes_f32(a: f32) -> f32;
+        [neon] safe fn vrsqrts_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vrsqrts_f64(a: float64x1_t, b: float64x1_t) -> float64x1_t;
+        [neon] safe fn vrsqrtsd_f64(a: f64, b: f64) -> f64;
+        [neon] safe fn vrsqrtsq_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vrsqrtsq_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vrsqrtss_f32(a: f32, b: f32) -> f32;
+        [neon] safe fn vrsra_n_s16<const N: i32>(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vrsra_n_s32<const N: i32>(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vrsra_n_s64<const N: i32>(a: int64x1_t, b: int64x1_t) -> int64x1_t;
+        [neon] safe fn vrsra_n_s8<const N: i32>(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vrsra_n_u16<const N: i32>(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vrsra_n_u32<const N: i32>(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vrsra_n_u64<const N: i32>(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vrsra_n_u8<const N: i32>(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vrsrad_n_s64<const N: i32>(a: i64, b: i64) -> i64;
+        [neon] safe fn vrsrad_n_u64<const N: i32>(a: u64, b: u64) -> u64;
+        [neon] safe fn vrsraq_n_s16<const N: i32>(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vrsraq_n_s32<const N: i32>(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vrsraq_n_s64<const N: i32>(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vrsraq_n_s8<const N: i32>(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vrsraq_n_u16<const N: i32>(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vrsraq_n_u32<const N: i32>(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vrsraq_n_u64<const N: i32>(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vrsraq_n_u8<const N: i32>(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vrsubhn_high_s16(a: int8x8_t, b: int16x8_t, c: int16x8_t) -> int8x16_t;
+        [neon] safe fn vrsubhn_high_s32(a: int16x4_t, b: int32x4_t, c: int32x4_t) -> int16x8_t;
+        [neon] safe fn vrsubhn_high_s64(a: int32x2_t, b: int64x2_t, c: int64x2_t) -> int32x4_t;
+        [neon] safe fn vrsubhn_high_u16(a: uint8x8_t, b: uint16x8_t, c: uint16x8_t) -> uint8x16_t;
+        [neon] safe fn vrsubhn_high_u32(a: uint16x4_t, b: uint32x4_t, c: uint32x4_t) -> uint16x8_t;
+        [neon] safe fn vrsubhn_high_u64(a: uint32x2_t, b: uint64x2_t, c: uint64x2_t) -> uint32x4_t;
+        [neon] safe fn vrsubhn_s16(a: int16x8_t, b: int16x8_t) -> int8x8_t;
+        [neon] safe fn vrsubhn_s32(a: int32x4_t, b: int32x4_t) -> int16x4_t;
+        [neon] safe fn vrsubhn_s64(a: int64x2_t, b: int64x2_t) -> int32x2_t;
+        [neon] safe fn vrsubhn_u16(a: uint16x8_t, b: uint16x8_t) -> uint8x8_t;
+        [neon] safe fn vrsubhn_u32(a: uint32x4_t, b: uint32x4_t) -> uint16x4_t;
+        [neon] safe fn vrsubhn_u64(a: uint64x2_t, b: uint64x2_t) -> uint32x2_t;
+        [neon] safe fn vset_lane_f32<const LANE: i32>(a: f32, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vset_lane_f64<const LANE: i32>(a: f64, b: float64x1_t) -> float64x1_t;
+        [neon] safe fn vset_lane_p16<const LANE: i32>(a: u16, b: poly16x4_t) -> poly16x4_t;
+        [neon, aes] safe fn vset_lane_p64<const LANE: i32>(a: u64, b: poly64x1_t) -> poly64x1_t;
+        [neon] safe fn vset_lane_p8<const LANE: i32>(a: u8, b: poly8x8_t) -> poly8x8_t;
+        [neon] safe fn vset_lane_s16<const LANE: i32>(a: i16, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vset_lane_s32<const LANE: i32>(a: i32, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vset_lane_s64<const LANE: i32>(a: i64, b: int64x1_t) -> int64x1_t;
+        [neon] safe fn vset_lane_s8<const LANE: i32>(a: i8, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vset_lane_u16<const LANE: i32>(a: u16, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vset_lane_u32<const LANE: i32>(a: u32, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vset_lane_u64<const LANE: i32>(a: u64, b: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vset_lane_u8<const LANE: i32>(a: u8, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vsetq_lane_f32<const LANE: i32>(a: f32, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vsetq_lane_f64<const LANE: i32>(a: f64, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vsetq_lane_p16<const LANE: i32>(a: u16, b: poly16x8_t) -> poly16x8_t;
+        [neon, aes] safe fn vsetq_lane_p64<const LANE: i32>(a: u64, b: poly64x2_t) -> poly64x2_t;
+        [neon] safe fn vsetq_lane_p8<const LANE: i32>(a: u8, b: poly8x16_t) -> poly8x16_t;
+        [neon] safe fn vsetq_lane_s16<const LANE: i32>(a: i16, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vsetq_lane_s32<const LANE: i32>(a: i32, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vsetq_lane_s64<const LANE: i32>(a: i64, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vsetq_lane_s8<const LANE: i32>(a: i8, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vsetq_lane_u16<const LANE: i32>(a: u16, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vsetq_lane_u32<const LANE: i32>(a: u32, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vsetq_lane_u64<const LANE: i32>(a: u64, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vsetq_lane_u8<const LANE: i32>(a: u8, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vshl_n_s16<const N: i32>(a: int16x4_t) -> int16x4_t;
+        [neon] safe fn vshl_n_s32<const N: i32>(a: int32x2_t) -> int32x2_t;
+        [neon] safe fn vshl_n_s64<const N: i32>(a: int64x1_t) -> int64x1_t;
+        [neon] safe fn vshl_n_s8<const N: i32>(a: int8x8_t) -> int8x8_t;
+        [neon] safe fn vshl_n_u16<const N: i32>(a: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vshl_n_u32<const N: i32>(a: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vshl_n_u64<const N: i32>(a: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vshl_n_u8<const N: i32>(a: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vshl_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vshl_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vshl_s64(a: int64x1_t, b: int64x1_t) -> int64x1_t;
+        [neon] safe fn vshl_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vshl_u16(a: uint16x4_t, b: int16x4_t) -> uint16x4_t;
+        [neon] safe fn vshl_u32(a: uint32x2_t, b: int32x2_t) -> uint32x2_t;
+        [neon] safe fn vshl_u64(a: uint64x1_t, b: int64x1_t) -> uint64x1_t;
+        [neon] safe fn vshl_u8(a: uint8x8_t, b: int8x8_t) -> uint8x8_t;
+        [neon] safe fn vshld_n_s64<const N: i32>(a: i64) -> i64;
+        [neon] safe fn vshld_n_u64<const N: i32>(a: u64) -> u64;
+        [neon] safe fn vshld_s64(a: i64, b: i64) -> i64;
+        [neon] safe fn vshld_u64(a: u64, b: i64) -> u64;
+        [neon] safe fn vshll_high_n_s16<const N: i32>(a: int16x8_t) -> int32x4_t;
+        [neon] safe fn vshll_high_n_s32<const N: i32>(a: int32x4_t) -> int64x2_t;
+        [neon] safe fn vshll_high_n_s8<const N: i32>(a: int8x16_t) -> int16x8_t;
+        [neon] safe fn vshll_high_n_u16<const N: i32>(a: uint16x8_t) -> uint32x4_t;
+        [neon] safe fn vshll_high_n_u32<const N: i32>(a: uint32x4_t) -> uint64x2_t;
+        [neon] safe fn vshll_high_n_u8<const N: i32>(a: uint8x16_t) -> uint16x8_t;
+        [neon] safe fn vshll_n_s16<const N: i32>(a: int16x4_t) -> int32x4_t;
+        [neon] safe fn vshll_n_s32<const N: i32>(a: int32x2_t) -> int64x2_t;
+        [neon] safe fn vshll_n_s8<const N: i32>(a: int8x8_t) -> int16x8_t;
+        [neon] safe fn vshll_n_u16<const N: i32>(a: uint16x4_t) -> uint32x4_t;
+        [neon] safe fn vshll_n_u32<const N: i32>(a: uint32x2_t) -> uint64x2_t;
+        [neon] safe fn vshll_n_u8<const N: i32>(a: uint8x8_t) -> uint16x8_t;
+        [neon] safe fn vshlq_n_s16<const N: i32>(a: int16x8_t) -> int16x8_t;
+        [neon] safe fn vshlq_n_s32<const N: i32>(a: int32x4_t) -> int32x4_t;
+        [neon] safe fn vshlq_n_s64<const N: i32>(a: int64x2_t) -> int64x2_t;
+        [neon] safe fn vshlq_n_s8<const N: i32>(a: int8x16_t) -> int8x16_t;
+        [neon] safe fn vshlq_n_u16<const N: i32>(a: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vshlq_n_u32<const N: i32>(a: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vshlq_n_u64<const N: i32>(a: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vshlq_n_u8<const N: i32>(a: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vshlq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vshlq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vshlq_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vshlq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vshlq_u16(a: uint16x8_t, b: int16x8_t) -> uint16x8_t;
+        [neon] safe fn vshlq_u32(a: uint32x4_t, b: int32x4_t) -> uint32x4_t;
+        [neon] safe fn vshlq_u64(a: uint64x2_t, b: int64x2_t) -> uint64x2_t;
+        [neon] safe fn vshlq_u8(a: uint8x16_t, b: int8x16_t) -> uint8x16_t;
+        [neon] safe fn vshr_n_s16<const N: i32>(a: int16x4_t) -> int16x4_t;
+        [neon] safe fn vshr_n_s32<const N: i32>(a: int32x2_t) -> int32x2_t;
+        [neon] safe fn vshr_n_s64<const N: i32>(a: int64x1_t) -> int64x1_t;
+        [neon] safe fn vshr_n_s8<const N: i32>(a: int8x8_t) -> int8x8_t;
+        [neon] safe fn vshr_n_u16<const N: i32>(a: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vshr_n_u32<const N: i32>(a: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vshr_n_u64<const N: i32>(a: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vshr_n_u8<const N: i32>(a: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vshrd_n_s64<const N: i32>(a: i64) -> i64;
+        [neon] safe fn vshrd_n_u64<const N: i32>(a: u64) -> u64;
+        [neon] safe fn vshrn_high_n_s16<const N: i32>(a: int8x8_t, b: int16x8_t) -> int8x16_t;
+        [neon] safe fn vshrn_high_n_s32<const N: i32>(a: int16x4_t, b: int32x4_t) -> int16x8_t;
+        [neon] safe fn vshrn_high_n_s64<const N: i32>(a: int32x2_t, b: int64x2_t) -> int32x4_t;
+        [neon] safe fn vshrn_high_n_u16<const N: i32>(a: uint8x8_t, b: uint16x8_t) -> uint8x16_t;
+        [neon] safe fn vshrn_high_n_u32<const N: i32>(a: uint16x4_t, b: uint32x4_t) -> uint16x8_t;
+        [neon] safe fn vshrn_high_n_u64<const N: i32>(a: uint32x2_t, b: uint64x2_t) -> uint32x4_t;
+        [neon] safe fn vshrn_n_s16<const N: i32>(a: int16x8_t) -> int8x8_t;
+        [neon] safe fn vshrn_n_s32<const N: i32>(a: int32x4_t) -> int16x4_t;
+        [neon] safe fn vshrn_n_s64<const N: i32>(a: int64x2_t) -> int32x2_t;
+        [neon] safe fn vshrn_n_u16<const N: i32>(a: uint16x8_t) -> uint8x8_t;
+        [neon] safe fn vshrn_n_u32<const N: i32>(a: uint32x4_t) -> uint16x4_t;
+        [neon] safe fn vshrn_n_u64<const N: i32>(a: uint64x2_t) -> uint32x2_t;
+        [neon] safe fn vshrq_n_s16<const N: i32>(a: int16x8_t) -> int16x8_t;
+        [neon] safe fn vshrq_n_s32<const N: i32>(a: int32x4_t) -> int32x4_t;
+        [neon] safe fn vshrq_n_s64<const N: i32>(a: int64x2_t) -> int64x2_t;
+        [neon] safe fn vshrq_n_s8<const N: i32>(a: int8x16_t) -> int8x16_t;
+        [neon] safe fn vshrq_n_u16<const N: i32>(a: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vshrq_n_u32<const N: i32>(a: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vshrq_n_u64<const N: i32>(a: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vshrq_n_u8<const N: i32>(a: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vsli_n_p16<const N: i32>(a: poly16x4_t, b: poly16x4_t) -> poly16x4_t;
+        [neon, aes] safe fn vsli_n_p64<const N: i32>(a: poly64x1_t, b: poly64x1_t) -> poly64x1_t;
+        [neon] safe fn vsli_n_p8<const N: i32>(a: poly8x8_t, b: poly8x8_t) -> poly8x8_t;
+        [neon] safe fn vsli_n_s16<const N: i32>(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vsli_n_s32<const N: i32>(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vsli_n_s64<const N: i32>(a: int64x1_t, b: int64x1_t) -> int64x1_t;
+        [neon] safe fn vsli_n_s8<const N: i32>(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vsli_n_u16<const N: i32>(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vsli_n_u32<const N: i32>(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vsli_n_u64<const N: i32>(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vsli_n_u8<const N: i32>(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vslid_n_s64<const N: i32>(a: i64, b: i64) -> i64;
+        [neon] safe fn vslid_n_u64<const N: i32>(a: u64, b: u64) -> u64;
+        [neon] safe fn vsliq_n_p16<const N: i32>(a: poly16x8_t, b: poly16x8_t) -> poly16x8_t;
+        [neon, aes] safe fn vsliq_n_p64<const N: i32>(a: poly64x2_t, b: poly64x2_t) -> poly64x2_t;
+        [neon] safe fn vsliq_n_p8<const N: i32>(a: poly8x16_t, b: poly8x16_t) -> poly8x16_t;
+        [neon] safe fn vsliq_n_s16<const N: i32>(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vsliq_n_s32<const N: i32>(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vsliq_n_s64<const N: i32>(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vsliq_n_s8<const N: i32>(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vsliq_n_u16<const N: i32>(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vsliq_n_u32<const N: i32>(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vsliq_n_u64<const N: i32>(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vsliq_n_u8<const N: i32>(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vsqadd_u16(a: uint16x4_t, b: int16x4_t) -> uint16x4_t;
+        [neon] safe fn vsqadd_u32(a: uint32x2_t, b: int32x2_t) -> uint32x2_t;
+        [neon] safe fn vsqadd_u64(a: uint64x1_t, b: int64x1_t) -> uint64x1_t;
+        [neon] safe fn vsqadd_u8(a: uint8x8_t, b: int8x8_t) -> uint8x8_t;
+        [neon] safe fn vsqaddb_u8(a: u8, b: i8) -> u8;
+        [neon] safe fn vsqaddd_u64(a: u64, b: i64) -> u64;
+        [neon] safe fn vsqaddh_u16(a: u16, b: i16) -> u16;
+        [neon] safe fn vsqaddq_u16(a: uint16x8_t, b: int16x8_t) -> uint16x8_t;
+        [neon] safe fn vsqaddq_u32(a: uint32x4_t, b: int32x4_t) -> uint32x4_t;
+        [neon] safe fn vsqaddq_u64(a: uint64x2_t, b: int64x2_t) -> uint64x2_t;
+        [neon] safe fn vsqaddq_u8(a: uint8x16_t, b: int8x16_t) -> uint8x16_t;
+        [neon] safe fn vsqadds_u32(a: u32, b: i32) -> u32;
+        [neon] safe fn vsqrt_f32(a: float32x2_t) -> float32x2_t;
+        [neon] safe fn vsqrt_f64(a: float64x1_t) -> float64x1_t;
+        [neon] safe fn vsqrtq_f32(a: float32x4_t) -> float32x4_t;
+        [neon] safe fn vsqrtq_f64(a: float64x2_t) -> float64x2_t;
+        [neon] safe fn vsra_n_s16<const N: i32>(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vsra_n_s32<const N: i32>(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vsra_n_s64<const N: i32>(a: int64x1_t, b: int64x1_t) -> int64x1_t;
+        [neon] safe fn vsra_n_s8<const N: i32>(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vsra_n_u16<const N: i32>(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vsra_n_u32<const N: i32>(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vsra_n_u64<const N: i32>(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vsra_n_u8<const N: i32>(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vsrad_n_s64<const N: i32>(a: i64, b: i64) -> i64;
+        [neon] safe fn vsrad_n_u64<const N: i32>(a: u64, b: u64) -> u64;
+        [neon] safe fn vsraq_n_s16<const N: i32>(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vsraq_n_s32<const N: i32>(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vsraq_n_s64<const N: i32>(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vsraq_n_s8<const N: i32>(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vsraq_n_u16<const N: i32>(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vsraq_n_u32<const N: i32>(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vsraq_n_u64<const N: i32>(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vsraq_n_u8<const N: i32>(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vsri_n_p16<const N: i32>(a: poly16x4_t, b: poly16x4_t) -> poly16x4_t;
+        [neon, aes] safe fn vsri_n_p64<const N: i32>(a: poly64x1_t, b: poly64x1_t) -> poly64x1_t;
+        [neon] safe fn vsri_n_p8<const N: i32>(a: poly8x8_t, b: poly8x8_t) -> poly8x8_t;
+        [neon] safe fn vsri_n_s16<const N: i32>(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vsri_n_s32<const N: i32>(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vsri_n_s64<const N: i32>(a: int64x1_t, b: int64x1_t) -> int64x1_t;
+        [neon] safe fn vsri_n_s8<const N: i32>(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vsri_n_u16<const N: i32>(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vsri_n_u32<const N: i32>(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vsri_n_u64<const N: i32>(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vsri_n_u8<const N: i32>(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vsrid_n_s64<const N: i32>(a: i64, b: i64) -> i64;
+        [neon] safe fn vsrid_n_u64<const N: i32>(a: u64, b: u64) -> u64;
+        [neon] safe fn vsriq_n_p16<const N: i32>(a: poly16x8_t, b: poly16x8_t) -> poly16x8_t;
+        [neon, aes] safe fn vsriq_n_p64<const N: i32>(a: poly64x2_t, b: poly64x2_t) -> poly64x2_t;
+        [neon] safe fn vsriq_n_p8<const N: i32>(a: poly8x16_t, b: poly8x16_t) -> poly8x16_t;
+        [neon] safe fn vsriq_n_s16<const N: i32>(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vsriq_n_s32<const N: i32>(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vsriq_n_s64<const N: i32>(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vsriq_n_s8<const N: i32>(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vsriq_n_u16<const N: i32>(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vsriq_n_u32<const N: i32>(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vsriq_n_u64<const N: i32>(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vsriq_n_u8<const N: i32>(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] unsafe fn vst1_f32(ptr: *mut f32, a: float32x2_t);
+        [neon] unsafe fn vst1_f32_x2(a: *mut f32, b: float32x2x2_t);
+        [neon] unsafe fn vst1_f32_x3(a: *mut f32, b: float32x2x3_t);
+        [neon] unsafe fn vst1_f32_x4(a: *mut f32, b: float32x2x4_t);
+        [neon] unsafe fn vst1_f64(ptr: *mut f64, a: float64x1_t);
+        [neon] unsafe fn vst1_f64_x2(a: *mut f64, b: float64x1x2_t);
+        [neon] unsafe fn vst1_f64_x3(a: *mut f64, b: float64x1x3_t);
+        [neon] unsafe fn vst1_f64_x4(a: *mut f64, b: float64x1x4_t);
+        [neon] unsafe fn vst1_lane_f32<const LANE: i32>(a: *mut f32, b: float32x2_t);
+        [neon] unsafe fn vst1_lane_f64<const LANE: i32>(a: *mut f64, b: float64x1_t);
+        [neon] unsafe fn vst1_lane_p16<const LANE: i32>(a: *mut u16, b: poly16x4_t);
+        [neon, aes] unsafe fn vst1_lane_p64<const LANE: i32>(a: *mut u64, b: poly64x1_t);
+        [neon] unsafe fn vst1_lane_p8<const LANE: i32>(a: *mut u8, b: poly8x8_t);
+        [neon] unsafe fn vst1_lane_s16<const LANE: i32>(a: *mut i16, b: int16x4_t);
+        [neon] unsafe fn vst1_lane_s32<const LANE: i32>(a: *mut i32, b: int32x2_t);
+        [neon] unsafe fn vst1_lane_s64<const LANE: i32>(a: *mut i64, b: int64x1_t);
+        [neon] unsafe fn vst1_lane_s8<const LANE: i32>(a: *mut i8, b: int8x8_t);
+        [neon] unsafe fn vst1_lane_u16<const LANE: i32>(a: *mut u16, b: uint16x4_t);
+        [neon] unsafe fn vst1_lane_u32<const LANE: i32>(a: *mut u32, b: uint32x2_t);
+        [neon] unsafe fn vst1_lane_u64<const LANE: i32>(a: *mut u64, b: uint64x1_t);
+        [neon] unsafe fn vst1_lane_u8<const LANE: i32>(a: *mut u8, b: uint8x8_t);
+        [neon] unsafe fn vst1_p16(ptr: *mut u16, a: poly16x4_t);
+        [neon] unsafe fn vst1_p16_x2(a: *mut u16, b: poly16x4x2_t);
+        [neon] unsafe fn vst1_p16_x3(a: *mut u16, b: poly16x4x3_t);
+        [neon] unsafe fn vst1_p16_x4(a: *mut u16, b: poly16x4x4_t);
+        [neon, aes] unsafe fn vst1_p64(ptr: *mut u64, a: poly64x1_t);
+        [neon, aes] unsafe fn vst1_p64_x2(a: *mut u64, b: poly64x1x2_t);
+        [neon, aes] unsafe fn vst1_p64_x3(a: *mut u64, b: poly64x1x3_t);
+        [neon, aes] unsafe fn vst1_p64_x4(a: *mut u64, b: poly64x1x4_t);
+        [neon] unsafe fn vst1_p8(ptr: *mut u8, a: poly8x8_t);
+        [neon] unsafe fn vst1_p8_x2(a: *mut u8, b: poly8x8x2_t);
+        [neon] unsafe fn vst1_p8_x3(a: *mut u8, b: poly8x8x3_t);
+        [neon] unsafe fn vst1_p8_x4(a: *mut u8, b: poly8x8x4_t);
+        [neon] unsafe fn vst1_s16(ptr: *mut i16, a: int16x4_t);
+        [neon] unsafe fn vst1_s16_x2(a: *mut i16, b: int16x4x2_t);
+        [neon] unsafe fn vst1_s16_x3(a: *mut i16, b: int16x4x3_t);
+        [neon] unsafe fn vst1_s16_x4(a: *mut i16, b: int16x4x4_t);
+        [neon] unsafe fn vst1_s32(ptr: *mut i32, a: int32x2_t);
+        [neon] unsafe fn vst1_s32_x2(a: *mut i32, b: int32x2x2_t);
+        [neon] unsafe fn vst1_s32_x3(a: *mut i32, b: int32x2x3_t);
+        [neon] unsafe fn vst1_s32_x4(a: *mut i32, b: int32x2x4_t);
+        [neon] unsafe fn vst1_s64(ptr: *mut i64, a: int64x1_t);
+        [neon] unsafe fn vst1_s64_x2(a: *mut i64, b: int64x1x2_t);
+        [neon] unsafe fn vst1_s64_x3(a: *mut i64, b: int64x1x3_t);
+        [neon] unsafe fn vst1_s64_x4(a: *mut i64, b: int64x1x4_t);
+        [neon] unsafe fn vst1_s8(ptr: *mut i8, a: int8x8_t);
+        [neon] unsafe fn vst1_s8_x2(a: *mut i8, b: int8x8x2_t);
+        [neon] unsafe fn vst1_s8_x3(a: *mut i8, b: int8x8x3_t);
+        [neon] unsafe fn vst1_s8_x4(a: *mut i8, b: int8x8x4_t);
+        [neon] unsafe fn vst1_u16(ptr: *mut u16, a: uint16x4_t);
+        [neon] unsafe fn vst1_u16_x2(a: *mut u16, b: uint16x4x2_t);
+        [neon] unsafe fn vst1_u16_x3(a: *mut u16, b: uint16x4x3_t);
+        [neon] unsafe fn vst1_u16_x4(a: *mut u16, b: uint16x4x4_t);
+        [neon] unsafe fn vst1_u32(ptr: *mut u32, a: uint32x2_t);
+        [neon] unsafe fn vst1_u32_x2(a: *mut u32, b: uint32x2x2_t);
+        [neon] unsafe fn vst1_u32_x3(a: *mut u32, b: uint32x2x3_t);
+        [neon] unsafe fn vst1_u32_x4(a: *mut u32, b: uint32x2x4_t);
+        [neon] unsafe fn vst1_u64(ptr: *mut u64, a: uint64x1_t);
+        [neon] unsafe fn vst1_u64_x2(a: *mut u64, b: uint64x1x2_t);
+        [neon] unsafe fn vst1_u64_x3(a: *mut u64, b: uint64x1x3_t);
+        [neon] unsafe fn vst1_u64_x4(a: *mut u64, b: uint64x1x4_t);
+        [neon] unsafe fn vst1_u8(ptr: *mut u8, a: uint8x8_t);
+        [neon] unsafe fn vst1_u8_x2(a: *mut u8, b: uint8x8x2_t);
+        [neon] unsafe fn vst1_u8_x3(a: *mut u8, b: uint8x8x3_t);
+        [neon] unsafe fn vst1_u8_x4(a: *mut u8, b: uint8x8x4_t);
+        [neon] unsafe fn vst1q_f32(ptr: *mut f32, a: float32x4_t);
+        [neon] unsafe fn vst1q_f32_x2(a: *mut f32, b: float32x4x2_t);
+        [neon] unsafe fn vst1q_f32_x3(a: *mut f32, b: float32x4x3_t);
+        [neon] unsafe fn vst1q_f32_x4(a: *mut f32, b: float32x4x4_t);
+        [neon] unsafe fn vst1q_f64(ptr: *mut f64, a: float64x2_t);
+        [neon] unsafe fn vst1q_f64_x2(a: *mut f64, b: float64x2x2_t);
+        [neon] unsafe fn vst1q_f64_x3(a: *mut f64, b: float64x2x3_t);
+        [neon] unsafe fn vst1q_f64_x4(a: *mut f64, b: float64x2x4_t);
+        [neon] unsafe fn vst1q_lane_f32<const LANE: i32>(a: *mut f32, b: float32x4_t);
+        [neon] unsafe fn vst1q_lane_f64<const LANE: i32>(a: *mut f64, b: float64x2_t);
+        [neon] unsafe fn vst1q_lane_p16<const LANE: i32>(a: *mut u16, b: poly16x8_t);
+        [neon, aes] unsafe fn vst1q_lane_p64<const LANE: i32>(a: *mut u64, b: poly64x2_t);
+        [neon] unsafe fn vst1q_lane_p8<const LANE: i32>(a: *mut u8, b: poly8x16_t);
+        [neon] unsafe fn vst1q_lane_s16<const LANE: i32>(a: *mut i16, b: int16x8_t);
+        [neon] unsafe fn vst1q_lane_s32<const LANE: i32>(a: *mut i32, b: int32x4_t);
+        [neon] unsafe fn vst1q_lane_s64<const LANE: i32>(a: *mut i64, b: int64x2_t);
+        [neon] unsafe fn vst1q_lane_s8<const LANE: i32>(a: *mut i8, b: int8x16_t);
+        [neon] unsafe fn vst1q_lane_u16<const LANE: i32>(a: *mut u16, b: uint16x8_t);
+        [neon] unsafe fn vst1q_lane_u32<const LANE: i32>(a: *mut u32, b: uint32x4_t);
+        [neon] unsafe fn vst1q_lane_u64<const LANE: i32>(a: *mut u64, b: uint64x2_t);
+        [neon] unsafe fn vst1q_lane_u8<const LANE: i32>(a: *mut u8, b: uint8x16_t);
+        [neon] unsafe fn vst1q_p16(ptr: *mut u16, a: poly16x8_t);
+        [neon] unsafe fn vst1q_p16_x2(a: *mut u16, b: poly16x8x2_t);
+        [neon] unsafe fn vst1q_p16_x3(a: *mut u16, b: poly16x8x3_t);
+        [neon] unsafe fn vst1q_p16_x4(a: *mut u16, b: poly16x8x4_t);
+        [neon, aes] unsafe fn vst1q_p64(ptr: *mut u64, a: poly64x2_t);
+        [neon, aes] unsafe fn vst1q_p64_x2(a: *mut u64, b: poly64x2x2_t);
+        [neon, aes] unsafe fn vst1q_p64_x3(a: *mut u64, b: poly64x2x3_t);
+        [neon, aes] unsafe fn vst1q_p64_x4(a: *mut u64, b: poly64x2x4_t);
+        [neon] unsafe fn vst1q_p8(ptr: *mut u8, a: poly8x16_t);
+        [neon] unsafe fn vst1q_p8_x2(a: *mut u8, b: poly8x16x2_t);
+        [neon] unsafe fn vst1q_p8_x3(a: *mut u8, b: poly8x16x3_t);
+        [neon] unsafe fn vst1q_p8_x4(a: *mut u8, b: poly8x16x4_t);
+        [neon] unsafe fn vst1q_s16(ptr: *mut i16, a: int16x8_t);
+        [neon] unsafe fn vst1q_s16_x2(a: *mut i16, b: int16x8x2_t);
+        [neon] unsafe fn vst1q_s16_x3(a: *mut i16, b: int16x8x3_t);
+        [neon] unsafe fn vst1q_s16_x4(a: *mut i16, b: int16x8x4_t);
+        [neon] unsafe fn vst1q_s32(ptr: *mut i32, a: int32x4_t);
+        [neon] unsafe fn vst1q_s32_x2(a: *mut i32, b: int32x4x2_t);
+        [neon] unsafe fn vst1q_s32_x3(a: *mut i32, b: int32x4x3_t);
+        [neon] unsafe fn vst1q_s32_x4(a: *mut i32, b: int32x4x4_t);
+        [neon] unsafe fn vst1q_s64(ptr: *mut i64, a: int64x2_t);
+        [neon] unsafe fn vst1q_s64_x2(a: *mut i64, b: int64x2x2_t);
+        [neon] unsafe fn vst1q_s64_x3(a: *mut i64, b: int64x2x3_t);
+        [neon] unsafe fn vst1q_s64_x4(a: *mut i64, b: int64x2x4_t);
+        [neon] unsafe fn vst1q_s8(ptr: *mut i8, a: int8x16_t);
+        [neon] unsafe fn vst1q_s8_x2(a: *mut i8, b: int8x16x2_t);
+        [neon] unsafe fn vst1q_s8_x3(a: *mut i8, b: int8x16x3_t);
+        [neon] unsafe fn vst1q_s8_x4(a: *mut i8, b: int8x16x4_t);
+        [neon] unsafe fn vst1q_u16(ptr: *mut u16, a: uint16x8_t);
+        [neon] unsafe fn vst1q_u16_x2(a: *mut u16, b: uint16x8x2_t);
+        [neon] unsafe fn vst1q_u16_x3(a: *mut u16, b: uint16x8x3_t);
+        [neon] unsafe fn vst1q_u16_x4(a: *mut u16, b: uint16x8x4_t);
+        [neon] unsafe fn vst1q_u32(ptr: *mut u32, a: uint32x4_t);
+        [neon] unsafe fn vst1q_u32_x2(a: *mut u32, b: uint32x4x2_t);
+        [neon] unsafe fn vst1q_u32_x3(a: *mut u32, b: uint32x4x3_t);
+        [neon] unsafe fn vst1q_u32_x4(a: *mut u32, b: uint32x4x4_t);
+        [neon] unsafe fn vst1q_u64(ptr: *mut u64, a: uint64x2_t);
+        [neon] unsafe fn vst1q_u64_x2(a: *mut u64, b: uint64x2x2_t);
+        [neon] unsafe fn vst1q_u64_x3(a: *mut u64, b: uint64x2x3_t);
+        [neon] unsafe fn vst1q_u64_x4(a: *mut u64, b: uint64x2x4_t);
+        [neon] unsafe fn vst1q_u8(ptr: *mut u8, a: uint8x16_t);
+        [neon] unsafe fn vst1q_u8_x2(a: *mut u8, b: uint8x16x2_t);
+        [neon] unsafe fn vst1q_u8_x3(a: *mut u8, b: uint8x16x3_t);
+        [neon] unsafe fn vst1q_u8_x4(a: *mut u8, b: uint8x16x4_t);
+        [neon] unsafe fn vst2_f32(a: *mut f32, b: float32x2x2_t);
+        [neon] unsafe fn vst2_f64(a: *mut f64, b: float64x1x2_t);
+        [neon] unsafe fn vst2_lane_f32<const LANE: i32>(a: *mut f32, b: float32x2x2_t);
+        [neon] unsafe fn vst2_lane_f64<const LANE: i32>(a: *mut f64, b: float64x1x2_t);
+        [neon] unsafe fn vst2_lane_p16<const LANE: i32>(a: *mut u16, b: poly16x4x2_t);
+        [neon, aes] unsafe fn vst2_lane_p64<const LANE: i32>(a: *mut u64, b: poly64x1x2_t);
+        [neon] unsafe fn vst2_lane_p8<const LANE: i32>(a: *mut u8, b: poly8x8x2_t);
+        [neon] unsafe fn vst2_lane_s16<const LANE: i32>(a: *mut i16, b: int16x4x2_t);
+        [neon] unsafe fn vst2_lane_s32<const LANE: i32>(a: *mut i32, b: int32x2x2_t);
+        [neon] unsafe fn vst2_lane_s64<const LANE: i32>(a: *mut i64, b: int64x1x2_t);
+        [neon] unsafe fn vst2_lane_s8<const LANE: i32>(a: *mut i8, b: int8x8x2_t);
+        [neon] unsafe fn vst2_lane_u16<const LANE: i32>(a: *mut u16, b: uint16x4x2_t);
+        [neon] unsafe fn vst2_lane_u32<const LANE: i32>(a: *mut u32, b: uint32x2x2_t);
+        [neon] unsafe fn vst2_lane_u64<const LANE: i32>(a: *mut u64, b: uint64x1x2_t);
+        [neon] unsafe fn vst2_lane_u8<const LANE: i32>(a: *mut u8, b: uint8x8x2_t);
+        [neon] unsafe fn vst2_p16(a: *mut u16, b: poly16x4x2_t);
+        [neon, aes] unsafe fn vst2_p64(a: *mut u64, b: poly64x1x2_t);
+        [neon] unsafe fn vst2_p8(a: *mut u8, b: poly8x8x2_t);
+        [neon] unsafe fn vst2_s16(a: *mut i16, b: int16x4x2_t);
+        [neon] unsafe fn vst2_s32(a: *mut i32, b: int32x2x2_t);
+        [neon] unsafe fn vst2_s64(a: *mut i64, b: int64x1x2_t);
+        [neon] unsafe fn vst2_s8(a: *mut i8, b: int8x8x2_t);
+        [neon] unsafe fn vst2_u16(a: *mut u16, b: uint16x4x2_t);
+        [neon] unsafe fn vst2_u32(a: *mut u32, b: uint32x2x2_t);
+        [neon] unsafe fn vst2_u64(a: *mut u64, b: uint64x1x2_t);
+        [neon] unsafe fn vst2_u8(a: *mut u8, b: uint8x8x2_t);
+        [neon] unsafe fn vst2q_f32(a: *mut f32, b: float32x4x2_t);
+        [neon] unsafe fn vst2q_f64(a: *mut f64, b: float64x2x2_t);
+        [neon] unsafe fn vst2q_lane_f32<const LANE: i32>(a: *mut f32, b: float32x4x2_t);
+        [neon] unsafe fn vst2q_lane_f64<const LANE: i32>(a: *mut f64, b: float64x2x2_t);
+        [neon] unsafe fn vst2q_lane_p16<const LANE: i32>(a: *mut u16, b: poly16x8x2_t);
+        [neon, aes] unsafe fn vst2q_lane_p64<const LANE: i32>(a: *mut u64, b: poly64x2x2_t);
+        [neon] unsafe fn vst2q_lane_p8<const LANE: i32>(a: *mut u8, b: poly8x16x2_t);
+        [neon] unsafe fn vst2q_lane_s16<const LANE: i32>(a: *mut i16, b: int16x8x2_t);
+        [neon] unsafe fn vst2q_lane_s32<const LANE: i32>(a: *mut i32, b: int32x4x2_t);
+        [neon] unsafe fn vst2q_lane_s64<const LANE: i32>(a: *mut i64, b: int64x2x2_t);
+        [neon] unsafe fn vst2q_lane_s8<const LANE: i32>(a: *mut i8, b: int8x16x2_t);
+        [neon] unsafe fn vst2q_lane_u16<const LANE: i32>(a: *mut u16, b: uint16x8x2_t);
+        [neon] unsafe fn vst2q_lane_u32<const LANE: i32>(a: *mut u32, b: uint32x4x2_t);
+        [neon] unsafe fn vst2q_lane_u64<const LANE: i32>(a: *mut u64, b: uint64x2x2_t);
+        [neon] unsafe fn vst2q_lane_u8<const LANE: i32>(a: *mut u8, b: uint8x16x2_t);
+        [neon] unsafe fn vst2q_p16(a: *mut u16, b: poly16x8x2_t);
+        [neon, aes] unsafe fn vst2q_p64(a: *mut u64, b: poly64x2x2_t);
+        [neon] unsafe fn vst2q_p8(a: *mut u8, b: poly8x16x2_t);
+        [neon] unsafe fn vst2q_s16(a: *mut i16, b: int16x8x2_t);
+        [neon] unsafe fn vst2q_s32(a: *mut i32, b: int32x4x2_t);
+        [neon] unsafe fn vst2q_s64(a: *mut i64, b: int64x2x2_t);
+        [neon] unsafe fn vst2q_s8(a: *mut i8, b: int8x16x2_t);
+        [neon] unsafe fn vst2q_u16(a: *mut u16, b: uint16x8x2_t);
+        [neon] unsafe fn vst2q_u32(a: *mut u32, b: uint32x4x2_t);
+        [neon] unsafe fn vst2q_u64(a: *mut u64, b: uint64x2x2_t);
+        [neon] unsafe fn vst2q_u8(a: *mut u8, b: uint8x16x2_t);
+        [neon] unsafe fn vst3_f32(a: *mut f32, b: float32x2x3_t);
+        [neon] unsafe fn vst3_f64(a: *mut f64, b: float64x1x3_t);
+        [neon] unsafe fn vst3_lane_f32<const LANE: i32>(a: *mut f32, b: float32x2x3_t);
+        [neon] unsafe fn vst3_lane_f64<const LANE: i32>(a: *mut f64, b: float64x1x3_t);
+        [neon] unsafe fn vst3_lane_p16<const LANE: i32>(a: *mut u16, b: poly16x4x3_t);
+        [neon, aes] unsafe fn vst3_lane_p64<const LANE: i32>(a: *mut u64, b: poly64x1x3_t);
+        [neon] unsafe fn vst3_lane_p8<const LANE: i32>(a: *mut u8, b: poly8x8x3_t);
+        [neon] unsafe fn vst3_lane_s16<const LANE: i32>(a: *mut i16, b: int16x4x3_t);
+        [neon] unsafe fn vst3_lane_s32<const LANE: i32>(a: *mut i32, b: int32x2x3_t);
+        [neon] unsafe fn vst3_lane_s64<const LANE: i32>(a: *mut i64, b: int64x1x3_t);
+        [neon] unsafe fn vst3_lane_s8<const LANE: i32>(a: *mut i8, b: int8x8x3_t);
+        [neon] unsafe fn vst3_lane_u16<const LANE: i32>(a: *mut u16, b: uint16x4x3_t);
+        [neon] unsafe fn vst3_lane_u32<const LANE: i32>(a: *mut u32, b: uint32x2x3_t);
+        [neon] unsafe fn vst3_lane_u64<const LANE: i32>(a: *mut u64, b: uint64x1x3_t);
+        [neon] unsafe fn vst3_lane_u8<const LANE: i32>(a: *mut u8, b: uint8x8x3_t);
+        [neon] unsafe fn vst3_p16(a: *mut u16, b: poly16x4x3_t);
+        [neon, aes] unsafe fn vst3_p64(a: *mut u64, b: poly64x1x3_t);
+        [neon] unsafe fn vst3_p8(a: *mut u8, b: poly8x8x3_t);
+        [neon] unsafe fn vst3_s16(a: *mut i16, b: int16x4x3_t);
+        [neon] unsafe fn vst3_s32(a: *mut i32, b: int32x2x3_t);
+        [neon] unsafe fn vst3_s64(a: *mut i64, b: int64x1x3_t);
+        [neon] unsafe fn vst3_s8(a: *mut i8, b: int8x8x3_t);
+        [neon] unsafe fn vst3_u16(a: *mut u16, b: uint16x4x3_t);
+        [neon] unsafe fn vst3_u32(a: *mut u32, b: uint32x2x3_t);
+        [neon] unsafe fn vst3_u64(a: *mut u64, b: uint64x1x3_t);
+        [neon] unsafe fn vst3_u8(a: *mut u8, b: uint8x8x3_t);
+        [neon] unsafe fn vst3q_f32(a: *mut f32, b: float32x4x3_t);
+        [neon] unsafe fn vst3q_f64(a: *mut f64, b: float64x2x3_t);
+        [neon] unsafe fn vst3q_lane_f32<const LANE: i32>(a: *mut f32, b: float32x4x3_t);
+        [neon] unsafe fn vst3q_lane_f64<const LANE: i32>(a: *mut f64, b: float64x2x3_t);
+        [neon] unsafe fn vst3q_lane_p16<const LANE: i32>(a: *mut u16, b: poly16x8x3_t);
+        [neon, aes] unsafe fn vst3q_lane_p64<const LANE: i32>(a: *mut u64, b: poly64x2x3_t);
+        [neon] unsafe fn vst3q_lane_p8<const LANE: i32>(a: *mut u8, b: poly8x16x3_t);
+        [neon] unsafe fn vst3q_lane_s16<const LANE: i32>(a: *mut i16, b: int16x8x3_t);
+        [neon] unsafe fn vst3q_lane_s32<const LANE: i32>(a: *mut i32, b: int32x4x3_t);
+        [neon] unsafe fn vst3q_lane_s64<const LANE: i32>(a: *mut i64, b: int64x2x3_t);
+        [neon] unsafe fn vst3q_lane_s8<const LANE: i32>(a: *mut i8, b: int8x16x3_t);
+        [neon] unsafe fn vst3q_lane_u16<const LANE: i32>(a: *mut u16, b: uint16x8x3_t);
+        [neon] unsafe fn vst3q_lane_u32<const LANE: i32>(a: *mut u32, b: uint32x4x3_t);
+        [neon] unsafe fn vst3q_lane_u64<const LANE: i32>(a: *mut u64, b: uint64x2x3_t);
+        [neon] unsafe fn vst3q_lane_u8<const LANE: i32>(a: *mut u8, b: uint8x16x3_t);
+        [neon] unsafe fn vst3q_p16(a: *mut u16, b: poly16x8x3_t);
+        [neon, aes] unsafe fn vst3q_p64(a: *mut u64, b: poly64x2x3_t);
+        [neon] unsafe fn vst3q_p8(a: *mut u8, b: poly8x16x3_t);
+        [neon] unsafe fn vst3q_s16(a: *mut i16, b: int16x8x3_t);
+        [neon] unsafe fn vst3q_s32(a: *mut i32, b: int32x4x3_t);
+        [neon] unsafe fn vst3q_s64(a: *mut i64, b: int64x2x3_t);
+        [neon] unsafe fn vst3q_s8(a: *mut i8, b: int8x16x3_t);
+        [neon] unsafe fn vst3q_u16(a: *mut u16, b: uint16x8x3_t);
+        [neon] unsafe fn vst3q_u32(a: *mut u32, b: uint32x4x3_t);
+        [neon] unsafe fn vst3q_u64(a: *mut u64, b: uint64x2x3_t);
+        [neon] unsafe fn vst3q_u8(a: *mut u8, b: uint8x16x3_t);
+        [neon] unsafe fn vst4_f32(a: *mut f32, b: float32x2x4_t);
+        [neon] unsafe fn vst4_f64(a: *mut f64, b: float64x1x4_t);
+        [neon] unsafe fn vst4_lane_f32<const LANE: i32>(a: *mut f32, b: float32x2x4_t);
+        [neon] unsafe fn vst4_lane_f64<const LANE: i32>(a: *mut f64, b: float64x1x4_t);
+        [neon] unsafe fn vst4_lane_p16<const LANE: i32>(a: *mut u16, b: poly16x4x4_t);
+        [neon, aes] unsafe fn vst4_lane_p64<const LANE: i32>(a: *mut u64, b: poly64x1x4_t);
+        [neon] unsafe fn vst4_lane_p8<const LANE: i32>(a: *mut u8, b: poly8x8x4_t);
+        [neon] unsafe fn vst4_lane_s16<const LANE: i32>(a: *mut i16, b: int16x4x4_t);
+        [neon] unsafe fn vst4_lane_s32<const LANE: i32>(a: *mut i32, b: int32x2x4_t);
+        [neon] unsafe fn vst4_lane_s64<const LANE: i32>(a: *mut i64, b: int64x1x4_t);
+        [neon] unsafe fn vst4_lane_s8<const LANE: i32>(a: *mut i8, b: int8x8x4_t);
+        [neon] unsafe fn vst4_lane_u16<const LANE: i32>(a: *mut u16, b: uint16x4x4_t);
+        [neon] unsafe fn vst4_lane_u32<const LANE: i32>(a: *mut u32, b: uint32x2x4_t);
+        [neon] unsafe fn vst4_lane_u64<const LANE: i32>(a: *mut u64, b: uint64x1x4_t);
+        [neon] unsafe fn vst4_lane_u8<const LANE: i32>(a: *mut u8, b: uint8x8x4_t);
+        [neon] unsafe fn vst4_p16(a: *mut u16, b: poly16x4x4_t);
+        [neon, aes] unsafe fn vst4_p64(a: *mut u64, b: poly64x1x4_t);
+        [neon] unsafe fn vst4_p8(a: *mut u8, b: poly8x8x4_t);
+        [neon] unsafe fn vst4_s16(a: *mut i16, b: int16x4x4_t);
+        [neon] unsafe fn vst4_s32(a: *mut i32, b: int32x2x4_t);
+        [neon] unsafe fn vst4_s64(a: *mut i64, b: int64x1x4_t);
+        [neon] unsafe fn vst4_s8(a: *mut i8, b: int8x8x4_t);
+        [neon] unsafe fn vst4_u16(a: *mut u16, b: uint16x4x4_t);
+        [neon] unsafe fn vst4_u32(a: *mut u32, b: uint32x2x4_t);
+        [neon] unsafe fn vst4_u64(a: *mut u64, b: uint64x1x4_t);
+        [neon] unsafe fn vst4_u8(a: *mut u8, b: uint8x8x4_t);
+        [neon] unsafe fn vst4q_f32(a: *mut f32, b: float32x4x4_t);
+        [neon] unsafe fn vst4q_f64(a: *mut f64, b: float64x2x4_t);
+        [neon] unsafe fn vst4q_lane_f32<const LANE: i32>(a: *mut f32, b: float32x4x4_t);
+        [neon] unsafe fn vst4q_lane_f64<const LANE: i32>(a: *mut f64, b: float64x2x4_t);
+        [neon] unsafe fn vst4q_lane_p16<const LANE: i32>(a: *mut u16, b: poly16x8x4_t);
+        [neon, aes] unsafe fn vst4q_lane_p64<const LANE: i32>(a: *mut u64, b: poly64x2x4_t);
+        [neon] unsafe fn vst4q_lane_p8<const LANE: i32>(a: *mut u8, b: poly8x16x4_t);
+        [neon] unsafe fn vst4q_lane_s16<const LANE: i32>(a: *mut i16, b: int16x8x4_t);
+        [neon] unsafe fn vst4q_lane_s32<const LANE: i32>(a: *mut i32, b: int32x4x4_t);
+        [neon] unsafe fn vst4q_lane_s64<const LANE: i32>(a: *mut i64, b: int64x2x4_t);
+        [neon] unsafe fn vst4q_lane_s8<const LANE: i32>(a: *mut i8, b: int8x16x4_t);
+        [neon] unsafe fn vst4q_lane_u16<const LANE: i32>(a: *mut u16, b: uint16x8x4_t);
+        [neon] unsafe fn vst4q_lane_u32<const LANE: i32>(a: *mut u32, b: uint32x4x4_t);
+        [neon] unsafe fn vst4q_lane_u64<const LANE: i32>(a: *mut u64, b: uint64x2x4_t);
+        [neon] unsafe fn vst4q_lane_u8<const LANE: i32>(a: *mut u8, b: uint8x16x4_t);
+        [neon] unsafe fn vst4q_p16(a: *mut u16, b: poly16x8x4_t);
+        [neon, aes] unsafe fn vst4q_p64(a: *mut u64, b: poly64x2x4_t);
+        [neon] unsafe fn vst4q_p8(a: *mut u8, b: poly8x16x4_t);
+        [neon] unsafe fn vst4q_s16(a: *mut i16, b: int16x8x4_t);
+        [neon] unsafe fn vst4q_s32(a: *mut i32, b: int32x4x4_t);
+        [neon] unsafe fn vst4q_s64(a: *mut i64, b: int64x2x4_t);
+        [neon] unsafe fn vst4q_s8(a: *mut i8, b: int8x16x4_t);
+        [neon] unsafe fn vst4q_u16(a: *mut u16, b: uint16x8x4_t);
+        [neon] unsafe fn vst4q_u32(a: *mut u32, b: uint32x4x4_t);
+        [neon] unsafe fn vst4q_u64(a: *mut u64, b: uint64x2x4_t);
+        [neon] unsafe fn vst4q_u8(a: *mut u8, b: uint8x16x4_t);
+        [neon] unsafe fn vstrq_p128(a: *mut u128, b: u128);
+        [neon] safe fn vsub_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vsub_f64(a: float64x1_t, b: float64x1_t) -> float64x1_t;
+        [neon] safe fn vsub_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vsub_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vsub_s64(a: int64x1_t, b: int64x1_t) -> int64x1_t;
+        [neon] safe fn vsub_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vsub_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vsub_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vsub_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vsub_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vsubd_s64(a: i64, b: i64) -> i64;
+        [neon] safe fn vsubd_u64(a: u64, b: u64) -> u64;
+        [neon] safe fn vsubhn_high_s16(a: int8x8_t, b: int16x8_t, c: int16x8_t) -> int8x16_t;
+        [neon] safe fn vsubhn_high_s32(a: int16x4_t, b: int32x4_t, c: int32x4_t) -> int16x8_t;
+        [neon] safe fn vsubhn_high_s64(a: int32x2_t, b: int64x2_t, c: int64x2_t) -> int32x4_t;
+        [neon] safe fn vsubhn_high_u16(a: uint8x8_t, b: uint16x8_t, c: uint16x8_t) -> uint8x16_t;
+        [neon] safe fn vsubhn_high_u32(a: uint16x4_t, b: uint32x4_t, c: uint32x4_t) -> uint16x8_t;
+        [neon] safe fn vsubhn_high_u64(a: uint32x2_t, b: uint64x2_t, c: uint64x2_t) -> uint32x4_t;
+        [neon] safe fn vsubhn_s16(a: int16x8_t, b: int16x8_t) -> int8x8_t;
+        [neon] safe fn vsubhn_s32(a: int32x4_t, b: int32x4_t) -> int16x4_t;
+        [neon] safe fn vsubhn_s64(a: int64x2_t, b: int64x2_t) -> int32x2_t;
+        [neon] safe fn vsubhn_u16(a: uint16x8_t, b: uint16x8_t) -> uint8x8_t;
+        [neon] safe fn vsubhn_u32(a: uint32x4_t, b: uint32x4_t) -> uint16x4_t;
+        [neon] safe fn vsubhn_u64(a: uint64x2_t, b: uint64x2_t) -> uint32x2_t;
+        [neon] safe fn vsubl_high_s16(a: int16x8_t, b: int16x8_t) -> int32x4_t;
+        [neon] safe fn vsubl_high_s32(a: int32x4_t, b: int32x4_t) -> int64x2_t;
+        [neon] safe fn vsubl_high_s8(a: int8x16_t, b: int8x16_t) -> int16x8_t;
+        [neon] safe fn vsubl_high_u16(a: uint16x8_t, b: uint16x8_t) -> uint32x4_t;
+        [neon] safe fn vsubl_high_u32(a: uint32x4_t, b: uint32x4_t) -> uint64x2_t;
+        [neon] safe fn vsubl_high_u8(a: uint8x16_t, b: uint8x16_t) -> uint16x8_t;
+        [neon] safe fn vsubl_s16(a: int16x4_t, b: int16x4_t) -> int32x4_t;
+        [neon] safe fn vsubl_s32(a: int32x2_t, b: int32x2_t) -> int64x2_t;
+        [neon] safe fn vsubl_s8(a: int8x8_t, b: int8x8_t) -> int16x8_t;
+        [neon] safe fn vsubl_u16(a: uint16x4_t, b: uint16x4_t) -> uint32x4_t;
+        [neon] safe fn vsubl_u32(a: uint32x2_t, b: uint32x2_t) -> uint64x2_t;
+        [neon] safe fn vsubl_u8(a: uint8x8_t, b: uint8x8_t) -> uint16x8_t;
+        [neon] safe fn vsubq_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vsubq_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vsubq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vsubq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vsubq_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vsubq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vsubq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vsubq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vsubq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vsubq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vsubw_high_s16(a: int32x4_t, b: int16x8_t) -> int32x4_t;
+        [neon] safe fn vsubw_high_s32(a: int64x2_t, b: int32x4_t) -> int64x2_t;
+        [neon] safe fn vsubw_high_s8(a: int16x8_t, b: int8x16_t) -> int16x8_t;
+        [neon] safe fn vsubw_high_u16(a: uint32x4_t, b: uint16x8_t) -> uint32x4_t;
+        [neon] safe fn vsubw_high_u32(a: uint64x2_t, b: uint32x4_t) -> uint64x2_t;
+        [neon] safe fn vsubw_high_u8(a: uint16x8_t, b: uint8x16_t) -> uint16x8_t;
+        [neon] safe fn vsubw_s16(a: int32x4_t, b: int16x4_t) -> int32x4_t;
+        [neon] safe fn vsubw_s32(a: int64x2_t, b: int32x2_t) -> int64x2_t;
+        [neon] safe fn vsubw_s8(a: int16x8_t, b: int8x8_t) -> int16x8_t;
+        [neon] safe fn vsubw_u16(a: uint32x4_t, b: uint16x4_t) -> uint32x4_t;
+        [neon] safe fn vsubw_u32(a: uint64x2_t, b: uint32x2_t) -> uint64x2_t;
+        [neon] safe fn vsubw_u8(a: uint16x8_t, b: uint8x8_t) -> uint16x8_t;
+        [neon] safe fn vtbl1_p8(a: poly8x8_t, b: uint8x8_t) -> poly8x8_t;
+        [neon] safe fn vtbl1_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vtbl1_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vtbl2_p8(a: poly8x8x2_t, b: uint8x8_t) -> poly8x8_t;
+        [neon] safe fn vtbl2_s8(a: int8x8x2_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vtbl2_u8(a: uint8x8x2_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vtbl3_p8(a: poly8x8x3_t, b: uint8x8_t) -> poly8x8_t;
+        [neon] safe fn vtbl3_s8(a: int8x8x3_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vtbl3_u8(a: uint8x8x3_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vtbl4_p8(a: poly8x8x4_t, b: uint8x8_t) -> poly8x8_t;
+        [neon] safe fn vtbl4_s8(a: int8x8x4_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vtbl4_u8(a: uint8x8x4_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vtbx1_p8(a: poly8x8_t, b: poly8x8_t, c: uint8x8_t) -> poly8x8_t;
+        [neon] safe fn vtbx1_s8(a: int8x8_t, b: int8x8_t, c: int8x8_t) -> int8x8_t;
+        [neon] safe fn vtbx1_u8(a: uint8x8_t, b: uint8x8_t, c: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vtbx2_p8(a: poly8x8_t, b: poly8x8x2_t, c: uint8x8_t) -> poly8x8_t;
+        [neon] safe fn vtbx2_s8(a: int8x8_t, b: int8x8x2_t, c: int8x8_t) -> int8x8_t;
+        [neon] safe fn vtbx2_u8(a: uint8x8_t, b: uint8x8x2_t, c: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vtbx3_p8(a: poly8x8_t, b: poly8x8x3_t, c: uint8x8_t) -> poly8x8_t;
+        [neon] safe fn vtbx3_s8(a: int8x8_t, b: int8x8x3_t, c: int8x8_t) -> int8x8_t;
+        [neon] safe fn vtbx3_u8(a: uint8x8_t, b: uint8x8x3_t, c: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vtbx4_p8(a: poly8x8_t, b: poly8x8x4_t, c: uint8x8_t) -> poly8x8_t;
+        [neon] safe fn vtbx4_s8(a: int8x8_t, b: int8x8x4_t, c: int8x8_t) -> int8x8_t;
+        [neon] safe fn vtbx4_u8(a: uint8x8_t, b: uint8x8x4_t, c: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vtrn1_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vtrn1_p16(a: poly16x4_t, b: poly16x4_t) -> poly16x4_t;
+        [neon] safe fn vtrn1_p8(a: poly8x8_t, b: poly8x8_t) -> poly8x8_t;
+        [neon] safe fn vtrn1_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vtrn1_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vtrn1_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vtrn1_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vtrn1_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vtrn1_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vtrn1q_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vtrn1q_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vtrn1q_p16(a: poly16x8_t, b: poly16x8_t) -> poly16x8_t;
+        [neon] safe fn vtrn1q_p64(a: poly64x2_t, b: poly64x2_t) -> poly64x2_t;
+        [neon] safe fn vtrn1q_p8(a: poly8x16_t, b: poly8x16_t) -> poly8x16_t;
+        [neon] safe fn vtrn1q_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vtrn1q_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vtrn1q_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vtrn1q_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vtrn1q_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vtrn1q_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vtrn1q_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vtrn1q_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vtrn2_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vtrn2_p16(a: poly16x4_t, b: poly16x4_t) -> poly16x4_t;
+        [neon] safe fn vtrn2_p8(a: poly8x8_t, b: poly8x8_t) -> poly8x8_t;
+        [neon] safe fn vtrn2_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vtrn2_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vtrn2_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vtrn2_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vtrn2_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vtrn2_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vtrn2q_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vtrn2q_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vtrn2q_p16(a: poly16x8_t, b: poly16x8_t) -> poly16x8_t;
+        [neon] safe fn vtrn2q_p64(a: poly64x2_t, b: poly64x2_t) -> poly64x2_t;
+        [neon] safe fn vtrn2q_p8(a: poly8x16_t, b: poly8x16_t) -> poly8x16_t;
+        [neon] safe fn vtrn2q_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vtrn2q_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vtrn2q_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vtrn2q_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vtrn2q_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vtrn2q_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vtrn2q_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vtrn2q_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vtrn_f32(a: float32x2_t, b: float32x2_t) -> float32x2x2_t;
+        [neon] safe fn vtrn_p16(a: poly16x4_t, b: poly16x4_t) -> poly16x4x2_t;
+        [neon] safe fn vtrn_p8(a: poly8x8_t, b: poly8x8_t) -> poly8x8x2_t;
+        [neon] safe fn vtrn_s16(a: int16x4_t, b: int16x4_t) -> int16x4x2_t;
+        [neon] safe fn vtrn_s32(a: int32x2_t, b: int32x2_t) -> int32x2x2_t;
+        [neon] safe fn vtrn_s8(a: int8x8_t, b: int8x8_t) -> int8x8x2_t;
+        [neon] safe fn vtrn_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4x2_t;
+        [neon] safe fn vtrn_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2x2_t;
+        [neon] safe fn vtrn_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8x2_t;
+        [neon] safe fn vtrnq_f32(a: float32x4_t, b: float32x4_t) -> float32x4x2_t;
+        [neon] safe fn vtrnq_p16(a: poly16x8_t, b: poly16x8_t) -> poly16x8x2_t;
+        [neon] safe fn vtrnq_p8(a: poly8x16_t, b: poly8x16_t) -> poly8x16x2_t;
+        [neon] safe fn vtrnq_s16(a: int16x8_t, b: int16x8_t) -> int16x8x2_t;
+        [neon] safe fn vtrnq_s32(a: int32x4_t, b: int32x4_t) -> int32x4x2_t;
+        [neon] safe fn vtrnq_s8(a: int8x16_t, b: int8x16_t) -> int8x16x2_t;
+        [neon] safe fn vtrnq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8x2_t;
+        [neon] safe fn vtrnq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4x2_t;
+        [neon] safe fn vtrnq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16x2_t;
+        [neon] safe fn vtst_p16(a: poly16x4_t, b: poly16x4_t) -> uint16x4_t;
+        [neon] safe fn vtst_p64(a: poly64x1_t, b: poly64x1_t) -> uint64x1_t;
+        [neon] safe fn vtst_p8(a: poly8x8_t, b: poly8x8_t) -> uint8x8_t;
+        [neon] safe fn vtst_s16(a: int16x4_t, b: int16x4_t) -> uint16x4_t;
+        [neon] safe fn vtst_s32(a: int32x2_t, b: int32x2_t) -> uint32x2_t;
+        [neon] safe fn vtst_s64(a: int64x1_t, b: int64x1_t) -> uint64x1_t;
+        [neon] safe fn vtst_s8(a: int8x8_t, b: int8x8_t) -> uint8x8_t;
+        [neon] safe fn vtst_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vtst_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vtst_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t;
+        [neon] safe fn vtst_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vtstd_s64(a: i64, b: i64) -> u64;
+        [neon] safe fn vtstd_u64(a: u64, b: u64) -> u64;
+        [neon] safe fn vtstq_p16(a: poly16x8_t, b: poly16x8_t) -> uint16x8_t;
+        [neon] safe fn vtstq_p64(a: poly64x2_t, b: poly64x2_t) -> uint64x2_t;
+        [neon] safe fn vtstq_p8(a: poly8x16_t, b: poly8x16_t) -> uint8x16_t;
+        [neon] safe fn vtstq_s16(a: int16x8_t, b: int16x8_t) -> uint16x8_t;
+        [neon] safe fn vtstq_s32(a: int32x4_t, b: int32x4_t) -> uint32x4_t;
+        [neon] safe fn vtstq_s64(a: int64x2_t, b: int64x2_t) -> uint64x2_t;
+        [neon] safe fn vtstq_s8(a: int8x16_t, b: int8x16_t) -> uint8x16_t;
+        [neon] safe fn vtstq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vtstq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vtstq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vtstq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vuqadd_s16(a: int16x4_t, b: uint16x4_t) -> int16x4_t;
+        [neon] safe fn vuqadd_s32(a: int32x2_t, b: uint32x2_t) -> int32x2_t;
+        [neon] safe fn vuqadd_s64(a: int64x1_t, b: uint64x1_t) -> int64x1_t;
+        [neon] safe fn vuqadd_s8(a: int8x8_t, b: uint8x8_t) -> int8x8_t;
+        [neon] safe fn vuqaddb_s8(a: i8, b: u8) -> i8;
+        [neon] safe fn vuqaddd_s64(a: i64, b: u64) -> i64;
+        [neon] safe fn vuqaddh_s16(a: i16, b: u16) -> i16;
+        [neon] safe fn vuqaddq_s16(a: int16x8_t, b: uint16x8_t) -> int16x8_t;
+        [neon] safe fn vuqaddq_s32(a: int32x4_t, b: uint32x4_t) -> int32x4_t;
+        [neon] safe fn vuqaddq_s64(a: int64x2_t, b: uint64x2_t) -> int64x2_t;
+        [neon] safe fn vuqaddq_s8(a: int8x16_t, b: uint8x16_t) -> int8x16_t;
+        [neon] safe fn vuqadds_s32(a: i32, b: u32) -> i32;
+        [neon] safe fn vuzp1_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vuzp1_p16(a: poly16x4_t, b: poly16x4_t) -> poly16x4_t;
+        [neon] safe fn vuzp1_p8(a: poly8x8_t, b: poly8x8_t) -> poly8x8_t;
+        [neon] safe fn vuzp1_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vuzp1_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vuzp1_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vuzp1_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vuzp1_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vuzp1_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vuzp1q_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vuzp1q_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vuzp1q_p16(a: poly16x8_t, b: poly16x8_t) -> poly16x8_t;
+        [neon] safe fn vuzp1q_p64(a: poly64x2_t, b: poly64x2_t) -> poly64x2_t;
+        [neon] safe fn vuzp1q_p8(a: poly8x16_t, b: poly8x16_t) -> poly8x16_t;
+        [neon] safe fn vuzp1q_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vuzp1q_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vuzp1q_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vuzp1q_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vuzp1q_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vuzp1q_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vuzp1q_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vuzp1q_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vuzp2_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vuzp2_p16(a: poly16x4_t, b: poly16x4_t) -> poly16x4_t;
+        [neon] safe fn vuzp2_p8(a: poly8x8_t, b: poly8x8_t) -> poly8x8_t;
+        [neon] safe fn vuzp2_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vuzp2_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vuzp2_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vuzp2_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vuzp2_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vuzp2_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vuzp2q_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vuzp2q_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vuzp2q_p16(a: poly16x8_t, b: poly16x8_t) -> poly16x8_t;
+        [neon] safe fn vuzp2q_p64(a: poly64x2_t, b: poly64x2_t) -> poly64x2_t;
+        [neon] safe fn vuzp2q_p8(a: poly8x16_t, b: poly8x16_t) -> poly8x16_t;
+        [neon] safe fn vuzp2q_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vuzp2q_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vuzp2q_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vuzp2q_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vuzp2q_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vuzp2q_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vuzp2q_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vuzp2q_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vuzp_f32(a: float32x2_t, b: float32x2_t) -> float32x2x2_t;
+        [neon] safe fn vuzp_p16(a: poly16x4_t, b: poly16x4_t) -> poly16x4x2_t;
+        [neon] safe fn vuzp_p8(a: poly8x8_t, b: poly8x8_t) -> poly8x8x2_t;
+        [neon] safe fn vuzp_s16(a: int16x4_t, b: int16x4_t) -> int16x4x2_t;
+        [neon] safe fn vuzp_s32(a: int32x2_t, b: int32x2_t) -> int32x2x2_t;
+        [neon] safe fn vuzp_s8(a: int8x8_t, b: int8x8_t) -> int8x8x2_t;
+        [neon] safe fn vuzp_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4x2_t;
+        [neon] safe fn vuzp_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2x2_t;
+        [neon] safe fn vuzp_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8x2_t;
+        [neon] safe fn vuzpq_f32(a: float32x4_t, b: float32x4_t) -> float32x4x2_t;
+        [neon] safe fn vuzpq_p16(a: poly16x8_t, b: poly16x8_t) -> poly16x8x2_t;
+        [neon] safe fn vuzpq_p8(a: poly8x16_t, b: poly8x16_t) -> poly8x16x2_t;
+        [neon] safe fn vuzpq_s16(a: int16x8_t, b: int16x8_t) -> int16x8x2_t;
+        [neon] safe fn vuzpq_s32(a: int32x4_t, b: int32x4_t) -> int32x4x2_t;
+        [neon] safe fn vuzpq_s8(a: int8x16_t, b: int8x16_t) -> int8x16x2_t;
+        [neon] safe fn vuzpq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8x2_t;
+        [neon] safe fn vuzpq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4x2_t;
+        [neon] safe fn vuzpq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16x2_t;
+        [neon] safe fn vzip1_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vzip1_p16(a: poly16x4_t, b: poly16x4_t) -> poly16x4_t;
+        [neon] safe fn vzip1_p8(a: poly8x8_t, b: poly8x8_t) -> poly8x8_t;
+        [neon] safe fn vzip1_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vzip1_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vzip1_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vzip1_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vzip1_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vzip1_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vzip1q_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vzip1q_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vzip1q_p16(a: poly16x8_t, b: poly16x8_t) -> poly16x8_t;
+        [neon] safe fn vzip1q_p64(a: poly64x2_t, b: poly64x2_t) -> poly64x2_t;
+        [neon] safe fn vzip1q_p8(a: poly8x16_t, b: poly8x16_t) -> poly8x16_t;
+        [neon] safe fn vzip1q_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vzip1q_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vzip1q_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vzip1q_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vzip1q_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vzip1q_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vzip1q_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vzip1q_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vzip2_f32(a: float32x2_t, b: float32x2_t) -> float32x2_t;
+        [neon] safe fn vzip2_p16(a: poly16x4_t, b: poly16x4_t) -> poly16x4_t;
+        [neon] safe fn vzip2_p8(a: poly8x8_t, b: poly8x8_t) -> poly8x8_t;
+        [neon] safe fn vzip2_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t;
+        [neon] safe fn vzip2_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t;
+        [neon] safe fn vzip2_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t;
+        [neon] safe fn vzip2_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t;
+        [neon] safe fn vzip2_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t;
+        [neon] safe fn vzip2_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t;
+        [neon] safe fn vzip2q_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t;
+        [neon] safe fn vzip2q_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t;
+        [neon] safe fn vzip2q_p16(a: poly16x8_t, b: poly16x8_t) -> poly16x8_t;
+        [neon] safe fn vzip2q_p64(a: poly64x2_t, b: poly64x2_t) -> poly64x2_t;
+        [neon] safe fn vzip2q_p8(a: poly8x16_t, b: poly8x16_t) -> poly8x16_t;
+        [neon] safe fn vzip2q_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t;
+        [neon] safe fn vzip2q_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t;
+        [neon] safe fn vzip2q_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t;
+        [neon] safe fn vzip2q_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t;
+        [neon] safe fn vzip2q_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t;
+        [neon] safe fn vzip2q_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon] safe fn vzip2q_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon] safe fn vzip2q_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t;
+        [neon] safe fn vzip_f32(a: float32x2_t, b: float32x2_t) -> float32x2x2_t;
+        [neon] safe fn vzip_p16(a: poly16x4_t, b: poly16x4_t) -> poly16x4x2_t;
+        [neon] safe fn vzip_p8(a: poly8x8_t, b: poly8x8_t) -> poly8x8x2_t;
+        [neon] safe fn vzip_s16(a: int16x4_t, b: int16x4_t) -> int16x4x2_t;
+        [neon] safe fn vzip_s32(a: int32x2_t, b: int32x2_t) -> int32x2x2_t;
+        [neon] safe fn vzip_s8(a: int8x8_t, b: int8x8_t) -> int8x8x2_t;
+        [neon] safe fn vzip_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4x2_t;
+        [neon] safe fn vzip_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2x2_t;
+        [neon] safe fn vzip_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8x2_t;
+        [neon] safe fn vzipq_f32(a: float32x4_t, b: float32x4_t) -> float32x4x2_t;
+        [neon] safe fn vzipq_p16(a: poly16x8_t, b: poly16x8_t) -> poly16x8x2_t;
+        [neon] safe fn vzipq_p8(a: poly8x16_t, b: poly8x16_t) -> poly8x16x2_t;
+        [neon] safe fn vzipq_s16(a: int16x8_t, b: int16x8_t) -> int16x8x2_t;
+        [neon] safe fn vzipq_s32(a: int32x4_t, b: int32x4_t) -> int32x4x2_t;
+        [neon] safe fn vzipq_s8(a: int8x16_t, b: int8x16_t) -> int8x16x2_t;
+        [neon] safe fn vzipq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8x2_t;
+        [neon] safe fn vzipq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4x2_t;
+        [neon] safe fn vzipq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16x2_t;
+    }
+}
+
+#[rustversion::since(1.63)]
+pub use since_1_63::*;
+
+#[rustversion::since(1.72)]
+mod since_1_72 {
+    use super::arch;
+    use super::arch::*;
+
+    intrinsics! {
+        [aes] safe fn vaesdq_u8(data: uint8x16_t, key: uint8x16_t) -> uint8x16_t;
+        [aes] safe fn vaeseq_u8(data: uint8x16_t, key: uint8x16_t) -> uint8x16_t;
+        [aes] safe fn vaesimcq_u8(data: uint8x16_t) -> uint8x16_t;
+        [aes] safe fn vaesmcq_u8(data: uint8x16_t) -> uint8x16_t;
+        [sha2] safe fn vsha1cq_u32(hash_abcd: uint32x4_t, hash_e: u32, wk: uint32x4_t) -> uint32x4_t;
+        [sha2] safe fn vsha1h_u32(hash_e: u32) -> u32;
+        [sha2] safe fn vsha1mq_u32(hash_abcd: uint32x4_t, hash_e: u32, wk: uint32x4_t) -> uint32x4_t;
+        [sha2] safe fn vsha1pq_u32(hash_abcd: uint32x4_t, hash_e: u32, wk: uint32x4_t) -> uint32x4_t;
+        [sha2] safe fn vsha1su0q_u32(w0_3: uint32x4_t, w4_7: uint32x4_t, w8_11: uint32x4_t) -> uint32x4_t;
+        [sha2] safe fn vsha1su1q_u32(tw0_3: uint32x4_t, w12_15: uint32x4_t) -> uint32x4_t;
+        [sha2] safe fn vsha256h2q_u32(hash_abcd: uint32x4_t, hash_efgh: uint32x4_t, wk: uint32x4_t) -> uint32x4_t;
+        [sha2] safe fn vsha256hq_u32(hash_abcd: uint32x4_t, hash_efgh: uint32x4_t, wk: uint32x4_t) -> uint32x4_t;
+        [sha2] safe fn vsha256su0q_u32(w0_3: uint32x4_t, w4_7: uint32x4_t) -> uint32x4_t;
+        [sha2] safe fn vsha256su1q_u32(tw0_3: uint32x4_t, w8_11: uint32x4_t, w12_15: uint32x4_t) -> uint32x4_t;
+    }
+}
+
+#[rustversion::since(1.72)]
+pub use since_1_72::*;
+
+#[rustversion::since(1.79)]
+mod since_1_79 {
+    use super::arch;
+    use super::arch::*;
+
+    intrinsics! {
+        [neon, sha3] safe fn vbcaxq_s16(a: int16x8_t, b: int16x8_t, c: int16x8_t) -> int16x8_t;
+        [neon, sha3] safe fn vbcaxq_s32(a: int32x4_t, b: int32x4_t, c: int32x4_t) -> int32x4_t;
+        [neon, sha3] safe fn vbcaxq_s64(a: int64x2_t, b: int64x2_t, c: int64x2_t) -> int64x2_t;
+        [neon, sha3] safe fn vbcaxq_s8(a: int8x16_t, b: int8x16_t, c: int8x16_t) -> int8x16_t;
+        [neon, sha3] safe fn vbcaxq_u16(a: uint16x8_t, b: uint16x8_t, c: uint16x8_t) -> uint16x8_t;
+        [neon, sha3] safe fn vbcaxq_u32(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t;
+        [neon, sha3] safe fn vbcaxq_u64(a: uint64x2_t, b: uint64x2_t, c: uint64x2_t) -> uint64x2_t;
+        [neon, sha3] safe fn vbcaxq_u8(a: uint8x16_t, b: uint8x16_t, c: uint8x16_t) -> uint8x16_t;
+        [neon, sha3] safe fn veor3q_s16(a: int16x8_t, b: int16x8_t, c: int16x8_t) -> int16x8_t;
+        [neon, sha3] safe fn veor3q_s32(a: int32x4_t, b: int32x4_t, c: int32x4_t) -> int32x4_t;
+        [neon, sha3] safe fn veor3q_s64(a: int64x2_t, b: int64x2_t, c: int64x2_t) -> int64x2_t;
+        [neon, sha3] safe fn veor3q_s8(a: int8x16_t, b: int8x16_t, c: int8x16_t) -> int8x16_t;
+        [neon, sha3] safe fn veor3q_u16(a: uint16x8_t, b: uint16x8_t, c: uint16x8_t) -> uint16x8_t;
+        [neon, sha3] safe fn veor3q_u32(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t;
+        [neon, sha3] safe fn veor3q_u64(a: uint64x2_t, b: uint64x2_t, c: uint64x2_t) -> uint64x2_t;
+        [neon, sha3] safe fn veor3q_u8(a: uint8x16_t, b: uint8x16_t, c: uint8x16_t) -> uint8x16_t;
+        [neon, sha3] safe fn vrax1q_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon, sha3] safe fn vsha512h2q_u64(a: uint64x2_t, b: uint64x2_t, c: uint64x2_t) -> uint64x2_t;
+        [neon, sha3] safe fn vsha512hq_u64(a: uint64x2_t, b: uint64x2_t, c: uint64x2_t) -> uint64x2_t;
+        [neon, sha3] safe fn vsha512su0q_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+        [neon, sha3] safe fn vsha512su1q_u64(a: uint64x2_t, b: uint64x2_t, c: uint64x2_t) -> uint64x2_t;
+        [neon, sha3] safe fn vxarq_u64<const IMM6: i32>(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+    }
+}
+
+#[rustversion::since(1.79)]
+pub use since_1_79::*;
+
+#[rustversion::since(1.80)]
+mod since_1_80 {
+    use super::arch;
+
+    intrinsics! {
+        [crc] safe fn __crc32b(crc: u32, data: u8) -> u32;
+        [crc] safe fn __crc32cb(crc: u32, data: u8) -> u32;
+        [crc] safe fn __crc32cd(crc: u32, data: u64) -> u32;
+        [crc] safe fn __crc32ch(crc: u32, data: u16) -> u32;
+        [crc] safe fn __crc32cw(crc: u32, data: u32) -> u32;
+        [crc] safe fn __crc32d(crc: u32, data: u64) -> u32;
+        [crc] safe fn __crc32h(crc: u32, data: u16) -> u32;
+        [crc] safe fn __crc32w(crc: u32, data: u32) -> u32;
+    }
+}
+
+#[rustversion::since(1.80)]
+pub use since_1_80::*;
+
+#[rustversion::since(1.94)]
+mod since_1_94 {
+    use super::arch;
+    use super::arch::*;
+
+    intrinsics! {
+        [neon, fp16] safe fn vabd_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vabdq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vabs_f16(a: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vabsq_f16(a: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vadd_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vaddq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vbsl_f16(a: uint16x4_t, b: float16x4_t, c: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vbslq_f16(a: uint16x8_t, b: float16x8_t, c: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vcage_f16(a: float16x4_t, b: float16x4_t) -> uint16x4_t;
+        [neon, fp16] safe fn vcageq_f16(a: float16x8_t, b: float16x8_t) -> uint16x8_t;
+        [neon, fp16] safe fn vcagt_f16(a: float16x4_t, b: float16x4_t) -> uint16x4_t;
+        [neon, fp16] safe fn vcagtq_f16(a: float16x8_t, b: float16x8_t) -> uint16x8_t;
+        [neon, fp16] safe fn vcale_f16(a: float16x4_t, b: float16x4_t) -> uint16x4_t;
+        [neon, fp16] safe fn vcaleq_f16(a: float16x8_t, b: float16x8_t) -> uint16x8_t;
+        [neon, fp16] safe fn vcalt_f16(a: float16x4_t, b: float16x4_t) -> uint16x4_t;
+        [neon, fp16] safe fn vcaltq_f16(a: float16x8_t, b: float16x8_t) -> uint16x8_t;
+        [neon, fp16] safe fn vceq_f16(a: float16x4_t, b: float16x4_t) -> uint16x4_t;
+        [neon, fp16] safe fn vceqq_f16(a: float16x8_t, b: float16x8_t) -> uint16x8_t;
+        [neon, fp16] safe fn vceqz_f16(a: float16x4_t) -> uint16x4_t;
+        [neon, fp16] safe fn vceqzq_f16(a: float16x8_t) -> uint16x8_t;
+        [neon, fp16] safe fn vcge_f16(a: float16x4_t, b: float16x4_t) -> uint16x4_t;
+        [neon, fp16] safe fn vcgeq_f16(a: float16x8_t, b: float16x8_t) -> uint16x8_t;
+        [neon, fp16] safe fn vcgez_f16(a: float16x4_t) -> uint16x4_t;
+        [neon, fp16] safe fn vcgezq_f16(a: float16x8_t) -> uint16x8_t;
+        [neon, fp16] safe fn vcgt_f16(a: float16x4_t, b: float16x4_t) -> uint16x4_t;
+        [neon, fp16] safe fn vcgtq_f16(a: float16x8_t, b: float16x8_t) -> uint16x8_t;
+        [neon, fp16] safe fn vcgtz_f16(a: float16x4_t) -> uint16x4_t;
+        [neon, fp16] safe fn vcgtzq_f16(a: float16x8_t) -> uint16x8_t;
+        [neon, fp16] safe fn vcle_f16(a: float16x4_t, b: float16x4_t) -> uint16x4_t;
+        [neon, fp16] safe fn vcleq_f16(a: float16x8_t, b: float16x8_t) -> uint16x8_t;
+        [neon, fp16] safe fn vclez_f16(a: float16x4_t) -> uint16x4_t;
+        [neon, fp16] safe fn vclezq_f16(a: float16x8_t) -> uint16x8_t;
+        [neon, fp16] safe fn vclt_f16(a: float16x4_t, b: float16x4_t) -> uint16x4_t;
+        [neon, fp16] safe fn vcltq_f16(a: float16x8_t, b: float16x8_t) -> uint16x8_t;
+        [neon, fp16] safe fn vcltz_f16(a: float16x4_t) -> uint16x4_t;
+        [neon, fp16] safe fn vcltzq_f16(a: float16x8_t) -> uint16x8_t;
+        [neon] safe fn vcombine_f16(a: float16x4_t, b: float16x4_t) -> float16x8_t;
+        [neon] safe fn vcreate_f16(a: u64) -> float16x4_t;
+        [neon] safe fn vcvt_f16_f32(a: float32x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vcvt_f16_s16(a: int16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vcvt_f16_u16(a: uint16x4_t) -> float16x4_t;
+        [neon] safe fn vcvt_f32_f16(a: float16x4_t) -> float32x4_t;
+        [neon] safe fn vcvt_high_f16_f32(a: float16x4_t, b: float32x4_t) -> float16x8_t;
+        [neon] safe fn vcvt_high_f32_f16(a: float16x8_t) -> float32x4_t;
+        [neon, fp16] safe fn vcvt_n_f16_s16<const N: i32>(a: int16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vcvt_n_f16_u16<const N: i32>(a: uint16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vcvt_n_s16_f16<const N: i32>(a: float16x4_t) -> int16x4_t;
+        [neon, fp16] safe fn vcvt_n_u16_f16<const N: i32>(a: float16x4_t) -> uint16x4_t;
+        [neon, fp16] safe fn vcvt_s16_f16(a: float16x4_t) -> int16x4_t;
+        [neon, fp16] safe fn vcvt_u16_f16(a: float16x4_t) -> uint16x4_t;
+        [neon, fp16] safe fn vcvta_s16_f16(a: float16x4_t) -> int16x4_t;
+        [neon, fp16] safe fn vcvta_u16_f16(a: float16x4_t) -> uint16x4_t;
+        [neon, fp16] safe fn vcvtaq_s16_f16(a: float16x8_t) -> int16x8_t;
+        [neon, fp16] safe fn vcvtaq_u16_f16(a: float16x8_t) -> uint16x8_t;
+        [neon, fp16] safe fn vcvtm_s16_f16(a: float16x4_t) -> int16x4_t;
+        [neon, fp16] safe fn vcvtm_u16_f16(a: float16x4_t) -> uint16x4_t;
+        [neon, fp16] safe fn vcvtmq_s16_f16(a: float16x8_t) -> int16x8_t;
+        [neon, fp16] safe fn vcvtmq_u16_f16(a: float16x8_t) -> uint16x8_t;
+        [neon, fp16] safe fn vcvtn_s16_f16(a: float16x4_t) -> int16x4_t;
+        [neon, fp16] safe fn vcvtn_u16_f16(a: float16x4_t) -> uint16x4_t;
+        [neon, fp16] safe fn vcvtnq_s16_f16(a: float16x8_t) -> int16x8_t;
+        [neon, fp16] safe fn vcvtnq_u16_f16(a: float16x8_t) -> uint16x8_t;
+        [neon, fp16] safe fn vcvtp_s16_f16(a: float16x4_t) -> int16x4_t;
+        [neon, fp16] safe fn vcvtp_u16_f16(a: float16x4_t) -> uint16x4_t;
+        [neon, fp16] safe fn vcvtpq_s16_f16(a: float16x8_t) -> int16x8_t;
+        [neon, fp16] safe fn vcvtpq_u16_f16(a: float16x8_t) -> uint16x8_t;
+        [neon, fp16] safe fn vcvtq_f16_s16(a: int16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vcvtq_f16_u16(a: uint16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vcvtq_n_f16_s16<const N: i32>(a: int16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vcvtq_n_f16_u16<const N: i32>(a: uint16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vcvtq_n_s16_f16<const N: i32>(a: float16x8_t) -> int16x8_t;
+        [neon, fp16] safe fn vcvtq_n_u16_f16<const N: i32>(a: float16x8_t) -> uint16x8_t;
+        [neon, fp16] safe fn vcvtq_s16_f16(a: float16x8_t) -> int16x8_t;
+        [neon, fp16] safe fn vcvtq_u16_f16(a: float16x8_t) -> uint16x8_t;
+        [neon, fp16] safe fn vdiv_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vdivq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon] safe fn vdup_lane_f16<const N: i32>(a: float16x4_t) -> float16x4_t;
+        [neon] safe fn vdup_laneq_f16<const N: i32>(a: float16x8_t) -> float16x4_t;
+        [neon] safe fn vdupq_lane_f16<const N: i32>(a: float16x4_t) -> float16x8_t;
+        [neon] safe fn vdupq_laneq_f16<const N: i32>(a: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vext_f16<const N: i32>(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vextq_f16<const N: i32>(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vfma_f16(a: float16x4_t, b: float16x4_t, c: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vfma_lane_f16<const LANE: i32>(a: float16x4_t, b: float16x4_t, c: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vfma_laneq_f16<const LANE: i32>(a: float16x4_t, b: float16x4_t, c: float16x8_t) -> float16x4_t;
+        [neon, fp16] safe fn vfmaq_f16(a: float16x8_t, b: float16x8_t, c: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vfmaq_lane_f16<const LANE: i32>(a: float16x8_t, b: float16x8_t, c: float16x4_t) -> float16x8_t;
+        [neon, fp16] safe fn vfmaq_laneq_f16<const LANE: i32>(a: float16x8_t, b: float16x8_t, c: float16x8_t) -> float16x8_t;
+        [neon, fp16, fhm] safe fn vfmlal_high_f16(r: float32x2_t, a: float16x4_t, b: float16x4_t) -> float32x2_t;
+        [neon, fp16, fhm] safe fn vfmlal_lane_high_f16<const LANE: i32>(r: float32x2_t, a: float16x4_t, b: float16x4_t) -> float32x2_t;
+        [neon, fp16, fhm] safe fn vfmlal_lane_low_f16<const LANE: i32>(r: float32x2_t, a: float16x4_t, b: float16x4_t) -> float32x2_t;
+        [neon, fp16, fhm] safe fn vfmlal_laneq_high_f16<const LANE: i32>(r: float32x2_t, a: float16x4_t, b: float16x8_t) -> float32x2_t;
+        [neon, fp16, fhm] safe fn vfmlal_laneq_low_f16<const LANE: i32>(r: float32x2_t, a: float16x4_t, b: float16x8_t) -> float32x2_t;
+        [neon, fp16, fhm] safe fn vfmlal_low_f16(r: float32x2_t, a: float16x4_t, b: float16x4_t) -> float32x2_t;
+        [neon, fp16, fhm] safe fn vfmlalq_high_f16(r: float32x4_t, a: float16x8_t, b: float16x8_t) -> float32x4_t;
+        [neon, fp16, fhm] safe fn vfmlalq_lane_high_f16<const LANE: i32>(r: float32x4_t, a: float16x8_t, b: float16x4_t) -> float32x4_t;
+        [neon, fp16, fhm] safe fn vfmlalq_lane_low_f16<const LANE: i32>(r: float32x4_t, a: float16x8_t, b: float16x4_t) -> float32x4_t;
+        [neon, fp16, fhm] safe fn vfmlalq_laneq_high_f16<const LANE: i32>(r: float32x4_t, a: float16x8_t, b: float16x8_t) -> float32x4_t;
+        [neon, fp16, fhm] safe fn vfmlalq_laneq_low_f16<const LANE: i32>(r: float32x4_t, a: float16x8_t, b: float16x8_t) -> float32x4_t;
+        [neon, fp16, fhm] safe fn vfmlalq_low_f16(r: float32x4_t, a: float16x8_t, b: float16x8_t) -> float32x4_t;
+        [neon, fp16, fhm] safe fn vfmlsl_high_f16(r: float32x2_t, a: float16x4_t, b: float16x4_t) -> float32x2_t;
+        [neon, fp16, fhm] safe fn vfmlsl_lane_high_f16<const LANE: i32>(r: float32x2_t, a: float16x4_t, b: float16x4_t) -> float32x2_t;
+        [neon, fp16, fhm] safe fn vfmlsl_lane_low_f16<const LANE: i32>(r: float32x2_t, a: float16x4_t, b: float16x4_t) -> float32x2_t;
+        [neon, fp16, fhm] safe fn vfmlsl_laneq_high_f16<const LANE: i32>(r: float32x2_t, a: float16x4_t, b: float16x8_t) -> float32x2_t;
+        [neon, fp16, fhm] safe fn vfmlsl_laneq_low_f16<const LANE: i32>(r: float32x2_t, a: float16x4_t, b: float16x8_t) -> float32x2_t;
+        [neon, fp16, fhm] safe fn vfmlsl_low_f16(r: float32x2_t, a: float16x4_t, b: float16x4_t) -> float32x2_t;
+        [neon, fp16, fhm] safe fn vfmlslq_high_f16(r: float32x4_t, a: float16x8_t, b: float16x8_t) -> float32x4_t;
+        [neon, fp16, fhm] safe fn vfmlslq_lane_high_f16<const LANE: i32>(r: float32x4_t, a: float16x8_t, b: float16x4_t) -> float32x4_t;
+        [neon, fp16, fhm] safe fn vfmlslq_lane_low_f16<const LANE: i32>(r: float32x4_t, a: float16x8_t, b: float16x4_t) -> float32x4_t;
+        [neon, fp16, fhm] safe fn vfmlslq_laneq_high_f16<const LANE: i32>(r: float32x4_t, a: float16x8_t, b: float16x8_t) -> float32x4_t;
+        [neon, fp16, fhm] safe fn vfmlslq_laneq_low_f16<const LANE: i32>(r: float32x4_t, a: float16x8_t, b: float16x8_t) -> float32x4_t;
+        [neon, fp16, fhm] safe fn vfmlslq_low_f16(r: float32x4_t, a: float16x8_t, b: float16x8_t) -> float32x4_t;
+        [neon, fp16] safe fn vfms_f16(a: float16x4_t, b: float16x4_t, c: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vfms_lane_f16<const LANE: i32>(a: float16x4_t, b: float16x4_t, c: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vfms_laneq_f16<const LANE: i32>(a: float16x4_t, b: float16x4_t, c: float16x8_t) -> float16x4_t;
+        [neon, fp16] safe fn vfmsq_f16(a: float16x8_t, b: float16x8_t, c: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vfmsq_lane_f16<const LANE: i32>(a: float16x8_t, b: float16x8_t, c: float16x4_t) -> float16x8_t;
+        [neon, fp16] safe fn vfmsq_laneq_f16<const LANE: i32>(a: float16x8_t, b: float16x8_t, c: float16x8_t) -> float16x8_t;
+        [neon] safe fn vget_high_f16(a: float16x8_t) -> float16x4_t;
+        [neon] safe fn vget_low_f16(a: float16x8_t) -> float16x4_t;
+        [neon, fp16] safe fn vmax_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vmaxnm_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vmaxnmq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vmaxq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vmin_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vminnm_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vminnmq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vminq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vmul_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vmul_lane_f16<const LANE: i32>(a: float16x4_t, v: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vmul_laneq_f16<const LANE: i32>(a: float16x4_t, b: float16x8_t) -> float16x4_t;
+        [neon, fp16] safe fn vmulq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vmulq_lane_f16<const LANE: i32>(a: float16x8_t, v: float16x4_t) -> float16x8_t;
+        [neon, fp16] safe fn vmulq_laneq_f16<const LANE: i32>(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vmulx_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vmulx_lane_f16<const LANE: i32>(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vmulx_laneq_f16<const LANE: i32>(a: float16x4_t, b: float16x8_t) -> float16x4_t;
+        [neon, fp16] safe fn vmulxq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vmulxq_lane_f16<const LANE: i32>(a: float16x8_t, b: float16x4_t) -> float16x8_t;
+        [neon, fp16] safe fn vmulxq_laneq_f16<const LANE: i32>(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vneg_f16(a: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vnegq_f16(a: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vpadd_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vpaddq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vpmax_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vpmaxnm_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vpmaxnmq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vpmaxq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vpmin_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vpminnm_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vpminnmq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vpminq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vrecpe_f16(a: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vrecpeq_f16(a: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vrecps_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vrecpsq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon] safe fn vreinterpret_f16_f32(a: float32x2_t) -> float16x4_t;
+        [neon] safe fn vreinterpret_f16_f64(a: float64x1_t) -> float16x4_t;
+        [neon] safe fn vreinterpret_f16_p16(a: poly16x4_t) -> float16x4_t;
+        [neon] safe fn vreinterpret_f16_p64(a: poly64x1_t) -> float16x4_t;
+        [neon] safe fn vreinterpret_f16_p8(a: poly8x8_t) -> float16x4_t;
+        [neon] safe fn vreinterpret_f16_s16(a: int16x4_t) -> float16x4_t;
+        [neon] safe fn vreinterpret_f16_s32(a: int32x2_t) -> float16x4_t;
+        [neon] safe fn vreinterpret_f16_s64(a: int64x1_t) -> float16x4_t;
+        [neon] safe fn vreinterpret_f16_s8(a: int8x8_t) -> float16x4_t;
+        [neon] safe fn vreinterpret_f16_u16(a: uint16x4_t) -> float16x4_t;
+        [neon] safe fn vreinterpret_f16_u32(a: uint32x2_t) -> float16x4_t;
+        [neon] safe fn vreinterpret_f16_u64(a: uint64x1_t) -> float16x4_t;
+        [neon] safe fn vreinterpret_f16_u8(a: uint8x8_t) -> float16x4_t;
+        [neon] safe fn vreinterpret_f32_f16(a: float16x4_t) -> float32x2_t;
+        [neon] safe fn vreinterpret_f64_f16(a: float16x4_t) -> float64x1_t;
+        [neon] safe fn vreinterpret_p16_f16(a: float16x4_t) -> poly16x4_t;
+        [neon] safe fn vreinterpret_p64_f16(a: float16x4_t) -> poly64x1_t;
+        [neon] safe fn vreinterpret_p8_f16(a: float16x4_t) -> poly8x8_t;
+        [neon] safe fn vreinterpret_s16_f16(a: float16x4_t) -> int16x4_t;
+        [neon] safe fn vreinterpret_s32_f16(a: float16x4_t) -> int32x2_t;
+        [neon] safe fn vreinterpret_s64_f16(a: float16x4_t) -> int64x1_t;
+        [neon] safe fn vreinterpret_s8_f16(a: float16x4_t) -> int8x8_t;
+        [neon] safe fn vreinterpret_u16_f16(a: float16x4_t) -> uint16x4_t;
+        [neon] safe fn vreinterpret_u32_f16(a: float16x4_t) -> uint32x2_t;
+        [neon] safe fn vreinterpret_u64_f16(a: float16x4_t) -> uint64x1_t;
+        [neon] safe fn vreinterpret_u8_f16(a: float16x4_t) -> uint8x8_t;
+        [neon] safe fn vreinterpretq_f16_f32(a: float32x4_t) -> float16x8_t;
+        [neon] safe fn vreinterpretq_f16_f64(a: float64x2_t) -> float16x8_t;
+        [neon] safe fn vreinterpretq_f16_p128(a: u128) -> float16x8_t;
+        [neon] safe fn vreinterpretq_f16_p16(a: poly16x8_t) -> float16x8_t;
+        [neon] safe fn vreinterpretq_f16_p64(a: poly64x2_t) -> float16x8_t;
+        [neon] safe fn vreinterpretq_f16_p8(a: poly8x16_t) -> float16x8_t;
+        [neon] safe fn vreinterpretq_f16_s16(a: int16x8_t) -> float16x8_t;
+        [neon] safe fn vreinterpretq_f16_s32(a: int32x4_t) -> float16x8_t;
+        [neon] safe fn vreinterpretq_f16_s64(a: int64x2_t) -> float16x8_t;
+        [neon] safe fn vreinterpretq_f16_s8(a: int8x16_t) -> float16x8_t;
+        [neon] safe fn vreinterpretq_f16_u16(a: uint16x8_t) -> float16x8_t;
+        [neon] safe fn vreinterpretq_f16_u32(a: uint32x4_t) -> float16x8_t;
+        [neon] safe fn vreinterpretq_f16_u64(a: uint64x2_t) -> float16x8_t;
+        [neon] safe fn vreinterpretq_f16_u8(a: uint8x16_t) -> float16x8_t;
+        [neon] safe fn vreinterpretq_f32_f16(a: float16x8_t) -> float32x4_t;
+        [neon] safe fn vreinterpretq_f64_f16(a: float16x8_t) -> float64x2_t;
+        [neon] safe fn vreinterpretq_p128_f16(a: float16x8_t) -> u128;
+        [neon] safe fn vreinterpretq_p16_f16(a: float16x8_t) -> poly16x8_t;
+        [neon] safe fn vreinterpretq_p64_f16(a: float16x8_t) -> poly64x2_t;
+        [neon] safe fn vreinterpretq_p8_f16(a: float16x8_t) -> poly8x16_t;
+        [neon] safe fn vreinterpretq_s16_f16(a: float16x8_t) -> int16x8_t;
+        [neon] safe fn vreinterpretq_s32_f16(a: float16x8_t) -> int32x4_t;
+        [neon] safe fn vreinterpretq_s64_f16(a: float16x8_t) -> int64x2_t;
+        [neon] safe fn vreinterpretq_s8_f16(a: float16x8_t) -> int8x16_t;
+        [neon] safe fn vreinterpretq_u16_f16(a: float16x8_t) -> uint16x8_t;
+        [neon] safe fn vreinterpretq_u32_f16(a: float16x8_t) -> uint32x4_t;
+        [neon] safe fn vreinterpretq_u64_f16(a: float16x8_t) -> uint64x2_t;
+        [neon] safe fn vreinterpretq_u8_f16(a: float16x8_t) -> uint8x16_t;
+        [neon, fp16] safe fn vrev64_f16(a: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vrev64q_f16(a: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vrnd_f16(a: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vrnda_f16(a: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vrndaq_f16(a: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vrndi_f16(a: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vrndiq_f16(a: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vrndm_f16(a: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vrndmq_f16(a: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vrndn_f16(a: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vrndnq_f16(a: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vrndp_f16(a: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vrndpq_f16(a: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vrndq_f16(a: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vrndx_f16(a: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vrndxq_f16(a: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vrsqrte_f16(a: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vrsqrteq_f16(a: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vrsqrts_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vrsqrtsq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vsqrt_f16(a: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vsqrtq_f16(a: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vsub_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vsubq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vtrn1_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vtrn1q_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vtrn2_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vtrn2q_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vtrn_f16(a: float16x4_t, b: float16x4_t) -> float16x4x2_t;
+        [neon, fp16] safe fn vtrnq_f16(a: float16x8_t, b: float16x8_t) -> float16x8x2_t;
+        [neon, fp16] safe fn vuzp1_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vuzp1q_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vuzp2_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vuzp2q_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vuzp_f16(a: float16x4_t, b: float16x4_t) -> float16x4x2_t;
+        [neon, fp16] safe fn vuzpq_f16(a: float16x8_t, b: float16x8_t) -> float16x8x2_t;
+        [neon, fp16] safe fn vzip1_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vzip1q_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vzip2_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+        [neon, fp16] safe fn vzip2q_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+        [neon, fp16] safe fn vzip_f16(a: float16x4_t, b: float16x4_t) -> float16x4x2_t;
+        [neon, fp16] safe fn vzipq_f16(a: float16x8_t, b: float16x8_t) -> float16x8x2_t;
+    }
+}
+
+#[rustversion::since(1.94)]
+pub use since_1_94::*;
+
+#[rustversion::since(1.95)]
+mod since_1_95 {
+    use super::arch;
+
+    intrinsics! {
+        [jsconv] safe fn __jcvt(a: f64) -> i32;
+    }
+}
+
+#[rustversion::since(1.95)]
+pub use since_1_95::*;
+
+#[cfg(arch_types_stdarch)]
+mod nightly {
+    use super::arch;
+    use super::arch::*;
+
+    intrinsics! {
+        [neon, dotprod] safe fn vdot_lane_s32<const LANE: i32>(a: int32x2_t, b: int8x8_t, c: int8x8_t) -> int32x2_t;
+        [neon, dotprod] safe fn vdot_lane_u32<const LANE: i32>(a: uint32x2_t, b: uint8x8_t, c: uint8x8_t) -> uint32x2_t;
+        [neon, dotprod] safe fn vdot_laneq_s32<const LANE: i32>(a: int32x2_t, b: int8x8_t, c: int8x16_t) -> int32x2_t;
+        [neon, dotprod] safe fn vdot_laneq_u32<const LANE: i32>(a: uint32x2_t, b: uint8x8_t, c: uint8x16_t) -> uint32x2_t;
+        [neon, dotprod] safe fn vdot_s32(a: int32x2_t, b: int8x8_t, c: int8x8_t) -> int32x2_t;
+        [neon, dotprod] safe fn vdot_u32(a: uint32x2_t, b: uint8x8_t, c: uint8x8_t) -> uint32x2_t;
+        [neon, dotprod] safe fn vdotq_lane_s32<const LANE: i32>(a: int32x4_t, b: int8x16_t, c: int8x8_t) -> int32x4_t;
+        [neon, dotprod] safe fn vdotq_lane_u32<const LANE: i32>(a: uint32x4_t, b: uint8x16_t, c: uint8x8_t) -> uint32x4_t;
+        [neon, dotprod] safe fn vdotq_laneq_s32<const LANE: i32>(a: int32x4_t, b: int8x16_t, c: int8x16_t) -> int32x4_t;
+        [neon, dotprod] safe fn vdotq_laneq_u32<const LANE: i32>(a: uint32x4_t, b: uint8x16_t, c: uint8x16_t) -> uint32x4_t;
+        [neon, dotprod] safe fn vdotq_s32(a: int32x4_t, b: int8x16_t, c: int8x16_t) -> int32x4_t;
+        [neon, dotprod] safe fn vdotq_u32(a: uint32x4_t, b: uint8x16_t, c: uint8x16_t) -> uint32x4_t;
+        [neon, sm4] safe fn vsm3partw1q_u32(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t;
+        [neon, sm4] safe fn vsm3partw2q_u32(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t;
+        [neon, sm4] safe fn vsm3ss1q_u32(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t;
+        [neon, sm4] safe fn vsm3tt1aq_u32<const IMM2: i32>(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t;
+        [neon, sm4] safe fn vsm3tt1bq_u32<const IMM2: i32>(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t;
+        [neon, sm4] safe fn vsm3tt2aq_u32<const IMM2: i32>(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t;
+        [neon, sm4] safe fn vsm3tt2bq_u32<const IMM2: i32>(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t;
+        [neon, sm4] safe fn vsm4ekeyq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+        [neon, sm4] safe fn vsm4eq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+    }
+}
+
+#[cfg(arch_types_stdarch)]
+pub use nightly::*;
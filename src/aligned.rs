@@ -0,0 +1,193 @@
+//! Buffers aligned to vector registers.
+//!
+//! Requires the `alloc` cargo feature.
+
+use crate::Features;
+use alloc::alloc::{alloc, dealloc, handle_alloc_error, Layout};
+use core::ptr::NonNull;
+use core::slice::{ChunksExact, ChunksExactMut};
+
+/// A heap-allocated buffer aligned and padded to the vector registers proven by `F`.
+///
+/// The buffer is aligned to [`F::VECTOR_BYTES`], and padded with `T::default()` to a whole number
+/// of chunks.  Each chunk returned by [`vector_chunks`](#method.vector_chunks) is therefore
+/// aligned for vector loads and stores.
+///
+/// ```
+/// use arch_types::{aligned::AlignedBuf, new_features_type, Features};
+///
+/// # #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+/// # {
+/// new_features_type! { Avx => "avx" }
+///
+/// if let Some(avx) = Avx::new() {
+///     let buf = AlignedBuf::from_slice(avx, &[1f32; 10]);
+///     assert_eq!(buf.as_ptr() as usize % 32, 0);
+///
+///     let chunks = buf.vector_chunks();
+///     assert_eq!(chunks.remainder().len(), 2);
+///     assert_eq!(chunks.count(), 1);
+///     assert_eq!(buf.as_padded_slice().len(), 16);
+/// }
+/// # }
+/// ```
+///
+/// [`F::VECTOR_BYTES`]: ../trait.Features.html#associatedconstant.VECTOR_BYTES
+/// [`ChunksExact::remainder`]: https://doc.rust-lang.org/core/slice/struct.ChunksExact.html#method.remainder
+/// [`ChunksExactMut::into_remainder`]: https://doc.rust-lang.org/core/slice/struct.ChunksExactMut.html#method.into_remainder
+pub struct AlignedBuf<T: Copy + Default, F: Features> {
+    ptr: NonNull<T>,
+    len: usize,
+    capacity: usize,
+    token: F,
+}
+
+// Safety: the buffer owns its elements
+unsafe impl<T: Copy + Default + Send, F: Features + Send> Send for AlignedBuf<T, F> {}
+unsafe impl<T: Copy + Default + Sync, F: Features + Sync> Sync for AlignedBuf<T, F> {}
+
+impl<T: Copy + Default, F: Features> AlignedBuf<T, F> {
+    /// The alignment of the buffer, in bytes.
+    #[inline]
+    pub fn alignment() -> usize {
+        core::cmp::max(F::VECTOR_BYTES, core::mem::align_of::<T>())
+    }
+
+    /// The number of elements in each vector chunk.
+    ///
+    /// This is the fewest elements spanning a multiple of the [`alignment`](#method.alignment),
+    /// so every chunk starts aligned.  If the size of `T` isn't a power of two, a chunk spans
+    /// several vectors: with 32-byte vectors, a chunk of `[f32; 3]` has 8 elements, or 96 bytes.
+    #[inline]
+    pub fn lanes() -> usize {
+        let size = core::mem::size_of::<T>();
+        if size == 0 {
+            return 1;
+        }
+
+        // The alignment is a power of two, so the largest power of two dividing the size is its
+        // greatest common divisor with the size
+        let alignment = Self::alignment();
+        alignment / core::cmp::min(size & size.wrapping_neg(), alignment)
+    }
+
+    /// Create a buffer of `len` copies of `T::default()`.
+    pub fn new(token: F, len: usize) -> Self {
+        Self::from_fn(token, len, |_| T::default())
+    }
+
+    /// Create a buffer containing a copy of `slice`.
+    pub fn from_slice(token: F, slice: &[T]) -> Self {
+        Self::from_fn(token, slice.len(), |i| slice[i])
+    }
+
+    fn from_fn(token: F, len: usize, f: impl Fn(usize) -> T) -> Self {
+        let lanes = Self::lanes();
+        let capacity = len.checked_add(lanes - 1).expect("capacity overflow") / lanes * lanes;
+        let ptr = match Self::layout(capacity) {
+            Some(layout) => {
+                let ptr = unsafe { alloc(layout) } as *mut T;
+                match NonNull::new(ptr) {
+                    Some(ptr) => ptr,
+                    None => handle_alloc_error(layout),
+                }
+            }
+            // Nothing is allocated, but the pointer must still be aligned
+            None => unsafe { NonNull::new_unchecked(Self::alignment() as *mut T) },
+        };
+        for i in 0..capacity {
+            let value = if i < len { f(i) } else { T::default() };
+            unsafe { ptr.as_ptr().add(i).write(value) };
+        }
+        Self {
+            ptr,
+            len,
+            capacity,
+            token,
+        }
+    }
+
+    // The layout of the allocation, or `None` if nothing needs to be allocated
+    fn layout(capacity: usize) -> Option<Layout> {
+        let size = capacity
+            .checked_mul(core::mem::size_of::<T>())
+            .expect("capacity overflow");
+        if size == 0 {
+            None
+        } else {
+            Some(Layout::from_size_align(size, Self::alignment()).expect("capacity overflow"))
+        }
+    }
+
+    /// Returns the token proving the vector features.
+    #[inline]
+    pub fn token(&self) -> F {
+        self.token
+    }
+
+    /// Returns the buffer, including the padding after the last element.
+    #[inline]
+    pub fn as_padded_slice(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), self.capacity) }
+    }
+
+    /// Returns the buffer mutably, including the padding after the last element.
+    #[inline]
+    pub fn as_padded_mut_slice(&mut self) -> &mut [T] {
+        unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.capacity) }
+    }
+
+    /// Returns an iterator over aligned chunks of [`lanes`](#method.lanes) elements.
+    ///
+    /// Elements that don't fill a whole chunk are available through
+    /// [`ChunksExact::remainder`].
+    #[inline]
+    pub fn vector_chunks(&self) -> ChunksExact<'_, T> {
+        self.chunks_exact(Self::lanes())
+    }
+
+    /// Returns an iterator over mutable aligned chunks of [`lanes`](#method.lanes) elements.
+    ///
+    /// Elements that don't fill a whole chunk are available through
+    /// [`ChunksExactMut::into_remainder`].
+    #[inline]
+    pub fn vector_chunks_mut(&mut self) -> ChunksExactMut<'_, T> {
+        self.chunks_exact_mut(Self::lanes())
+    }
+}
+
+impl<T: Copy + Default, F: Features> Drop for AlignedBuf<T, F> {
+    fn drop(&mut self) {
+        if let Some(layout) = Self::layout(self.capacity) {
+            unsafe { dealloc(self.ptr.as_ptr() as *mut u8, layout) }
+        }
+    }
+}
+
+impl<T: Copy + Default, F: Features> core::ops::Deref for AlignedBuf<T, F> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T: Copy + Default, F: Features> core::ops::DerefMut for AlignedBuf<T, F> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T: Copy + Default, F: Features> Clone for AlignedBuf<T, F> {
+    fn clone(&self) -> Self {
+        Self::from_slice(self.token, self)
+    }
+}
+
+impl<T: Copy + Default + core::fmt::Debug, F: Features> core::fmt::Debug for AlignedBuf<T, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
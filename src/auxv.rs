@@ -0,0 +1,138 @@
+//! PowerPC and AArch64 feature detection using the Linux auxiliary vector.
+//!
+//! This is used when `is_powerpc_feature_detected`, `is_powerpc64_feature_detected`, and
+//! `is_aarch64_feature_detected` are unavailable, either because `std` is disabled or the compiler
+//! doesn't support detecting a particular feature.
+
+/// Detect a PowerPC feature with `getauxval`, returning `false` if it can't be detected.
+#[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+macro_rules! powerpc_auxv_detected {
+    ("altivec") => {
+        $crate::auxv::hwcap() & $crate::auxv::PPC_FEATURE_HAS_ALTIVEC != 0
+    };
+    ("vsx") => {
+        $crate::auxv::hwcap() & $crate::auxv::PPC_FEATURE_HAS_VSX != 0
+    };
+    ("power8") => {
+        $crate::auxv::hwcap2() & $crate::auxv::PPC_FEATURE2_ARCH_2_07 != 0
+    };
+    ("power8-altivec") => {
+        powerpc_auxv_detected!("altivec") && powerpc_auxv_detected!("power8")
+    };
+    ("power8-vector") => {
+        powerpc_auxv_detected!("vsx") && powerpc_auxv_detected!("power8")
+    };
+    ("power8-crypto") => {
+        powerpc_auxv_detected!("power8-altivec")
+            && $crate::auxv::hwcap2() & $crate::auxv::PPC_FEATURE2_VEC_CRYPTO != 0
+    };
+    ("crypto") => {
+        powerpc_auxv_detected!("power8-crypto")
+    };
+    ("power9-altivec") => {
+        powerpc_auxv_detected!("power8-altivec")
+            && $crate::auxv::hwcap2() & $crate::auxv::PPC_FEATURE2_ARCH_3_00 != 0
+    };
+    ("power9-vector") => {
+        powerpc_auxv_detected!("power8-vector")
+            && $crate::auxv::hwcap2() & $crate::auxv::PPC_FEATURE2_ARCH_3_00 != 0
+    };
+    ("power10-vector") => {
+        powerpc_auxv_detected!("power9-vector")
+            && $crate::auxv::hwcap2() & $crate::auxv::PPC_FEATURE2_ARCH_3_1 != 0
+    };
+    // `lqarx` and `stqcx.` were introduced in Power ISA 2.07
+    ("quadword-atomics") => {
+        powerpc_auxv_detected!("power8")
+    };
+    ($feature:tt) => {
+        false
+    };
+}
+
+#[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+mod powerpc {
+    pub(crate) const PPC_FEATURE_HAS_ALTIVEC: usize = 0x1000_0000;
+    pub(crate) const PPC_FEATURE_HAS_VSX: usize = 0x0000_0080;
+    pub(crate) const PPC_FEATURE2_ARCH_2_07: usize = 0x8000_0000;
+    pub(crate) const PPC_FEATURE2_VEC_CRYPTO: usize = 0x0200_0000;
+    pub(crate) const PPC_FEATURE2_ARCH_3_00: usize = 0x0080_0000;
+    pub(crate) const PPC_FEATURE2_ARCH_3_1: usize = 0x0004_0000;
+}
+
+#[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+pub(crate) use powerpc::*;
+
+/// Detect an AArch64 feature with `getauxval`, returning `false` if it can't be detected.
+#[cfg(target_arch = "aarch64")]
+macro_rules! aarch64_auxv_detected {
+    ("wfxt") => {
+        $crate::auxv::hwcap2() & $crate::auxv::HWCAP2_WFXT != 0
+    };
+    ("cssc") => {
+        $crate::auxv::hwcap2() & $crate::auxv::HWCAP2_CSSC != 0
+    };
+    ("mops") => {
+        $crate::auxv::hwcap2() & $crate::auxv::HWCAP2_MOPS != 0
+    };
+    ("hbc") => {
+        $crate::auxv::hwcap2() & $crate::auxv::HWCAP2_HBC != 0
+    };
+    ($feature:tt) => {
+        false
+    };
+}
+
+#[cfg(target_arch = "aarch64")]
+mod aarch64 {
+    pub(crate) const HWCAP2_WFXT: usize = 1 << 31;
+    pub(crate) const HWCAP2_CSSC: usize = 1 << 34;
+    pub(crate) const HWCAP2_MOPS: usize = 1 << 43;
+    pub(crate) const HWCAP2_HBC: usize = 1 << 44;
+}
+
+#[cfg(target_arch = "aarch64")]
+pub(crate) use aarch64::*;
+
+#[cfg(target_os = "linux")]
+mod imp {
+    #[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+    const AT_HWCAP: usize = 16;
+    const AT_HWCAP2: usize = 26;
+
+    // `unsigned long` is the same size as `usize` on Linux
+    extern "C" {
+        fn getauxval(kind: usize) -> usize;
+    }
+
+    // AArch64 features are only detected from `AT_HWCAP2`
+    #[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+    #[inline]
+    pub(crate) fn hwcap() -> usize {
+        unsafe { getauxval(AT_HWCAP) }
+    }
+
+    #[inline]
+    pub(crate) fn hwcap2() -> usize {
+        unsafe { getauxval(AT_HWCAP2) }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod imp {
+    // AArch64 features are only detected from `AT_HWCAP2`
+    #[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+    #[inline]
+    pub(crate) fn hwcap() -> usize {
+        0
+    }
+
+    #[inline]
+    pub(crate) fn hwcap2() -> usize {
+        0
+    }
+}
+
+#[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+pub(crate) use imp::hwcap;
+pub(crate) use imp::hwcap2;
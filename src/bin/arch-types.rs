@@ -0,0 +1,166 @@
+//! Reports the CPU features detected on the host.
+use arch_types::{Feature, FeatureSet};
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::process;
+
+const USAGE: &str = "\
+Usage: arch-types [OPTIONS]
+
+Reports the CPU features detected on the host.
+
+Options:
+    --all              List every feature known for this architecture and whether it was detected
+    --json             Print detection results as JSON
+    --rustc            Print detected features as a `-C target-feature` string
+    --check FEATURES   Exit with a non-zero status if any of the comma-separated FEATURES are missing
+    -h, --help         Print this message
+";
+
+enum Mode {
+    List,
+    All,
+    Json,
+    Rustc,
+    Check(Vec<String>),
+}
+
+fn parse_args() -> Result<Mode, String> {
+    let mut mode = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let next = match arg.as_str() {
+            "--all" => Mode::All,
+            "--json" => Mode::Json,
+            "--rustc" => Mode::Rustc,
+            "--check" => {
+                let list = args
+                    .next()
+                    .ok_or_else(|| String::from("`--check` requires a list of features"))?;
+                let names = list
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|name| !name.is_empty())
+                    .map(String::from)
+                    .collect::<Vec<_>>();
+                if names.is_empty() {
+                    return Err(String::from("`--check` requires a list of features"));
+                }
+                Mode::Check(names)
+            }
+            "-h" | "--help" => {
+                print!("{}", USAGE);
+                process::exit(0);
+            }
+            other => return Err(format!("unknown argument `{}`", other)),
+        };
+        if mode.replace(next).is_some() {
+            return Err(String::from("only one output mode may be specified"));
+        }
+    }
+    Ok(mode.unwrap_or(Mode::List))
+}
+
+fn detected() -> impl Iterator<Item = Feature> {
+    Feature::ALL
+        .iter()
+        .cloned()
+        .filter(|feature| feature.is_detected())
+}
+
+fn check(names: &[String]) -> i32 {
+    let mut required = FeatureSet::new();
+    for name in names {
+        match Feature::from_name(name) {
+            Some(feature) => {
+                required.insert(feature);
+            }
+            None => {
+                eprintln!(
+                    "error: unknown feature `{}` for {}",
+                    name,
+                    std::env::consts::ARCH
+                );
+                return 2;
+            }
+        }
+    }
+    let missing = FeatureSet::detect().missing(&required);
+    if missing.is_empty() {
+        0
+    } else {
+        let missing = missing.iter().map(Feature::name).collect::<Vec<_>>();
+        eprintln!("missing features: {}", missing.join(", "));
+        1
+    }
+}
+
+// Writes the report for `mode` to `out`, returning the exit status.
+fn report(mode: Mode, out: &mut String) -> i32 {
+    match mode {
+        Mode::List => {
+            for feature in detected() {
+                writeln!(out, "{}", feature).unwrap();
+            }
+        }
+        Mode::All => {
+            let width = Feature::ALL
+                .iter()
+                .map(|feature| feature.name().len())
+                .max()
+                .unwrap_or(0);
+            for feature in Feature::ALL {
+                let status = if feature.is_detected() { "yes" } else { "no" };
+                writeln!(
+                    out,
+                    "{:width$}  {:3}  {}",
+                    feature.name(),
+                    status,
+                    feature.info().description,
+                    width = width
+                )
+                .unwrap();
+            }
+        }
+        Mode::Json => {
+            let features = Feature::ALL
+                .iter()
+                .map(|feature| format!("    \"{}\": {}", feature, feature.is_detected()))
+                .collect::<Vec<_>>();
+            writeln!(out, "{{").unwrap();
+            writeln!(out, "  \"arch\": \"{}\",", std::env::consts::ARCH).unwrap();
+            if features.is_empty() {
+                writeln!(out, "  \"features\": {{}}").unwrap();
+            } else {
+                writeln!(out, "  \"features\": {{\n{}\n  }}", features.join(",\n")).unwrap();
+            }
+            writeln!(out, "}}").unwrap();
+        }
+        Mode::Rustc => {
+            // Only report features that rustc understands
+            let features = detected()
+                .filter_map(|feature| feature.info().rustc_name)
+                .map(|name| format!("+{}", name))
+                .collect::<Vec<_>>();
+            writeln!(out, "{}", features.join(",")).unwrap();
+        }
+        Mode::Check(names) => return check(&names),
+    }
+    0
+}
+
+fn main() {
+    let mode = parse_args().unwrap_or_else(|error| {
+        eprintln!("error: {}\n\n{}", error, USAGE);
+        process::exit(2);
+    });
+
+    let mut out = String::new();
+    let status = report(mode, &mut out);
+
+    // Ignore write errors, such as a closed pipe when the output is truncated.
+    let mut stdout = io::stdout();
+    let _ = stdout.write_all(out.as_bytes());
+    let _ = stdout.flush();
+    process::exit(status);
+}
@@ -0,0 +1,128 @@
+//! Carry-less multiplication and the GHASH and POLYVAL universal hashes.
+//!
+//! The hardware implementations require a token proving the necessary features:
+//!   * On `x86` and `x86_64`, [`clmul64`], [`ghash`], and [`polyval`] require `pclmulqdq`.
+//!   * On `aarch64` (requires Rust 1.61) and `arm` (requires the `nightly` cargo feature), they
+//!     require `pmull`.
+//!
+//! [`clmul64_soft`], [`ghash_soft`], and [`polyval_soft`] are portable implementations.
+//!
+//! Every implementation, including the portable one, runs in constant time: there are no branches
+//! or memory accesses that depend on the inputs.
+//!
+//! ```
+//! use arch_types::clmul::polyval_soft;
+//!
+//! // From RFC 8452, appendix A
+//! let key = 0x7b754bba26f8311d7642925847936225u128.to_le_bytes();
+//! let blocks = [
+//!     0x62a2012dbb621740b6df838c66954f4fu128.to_le_bytes(),
+//!     0x62f3c9d3205fe4bb06d02127dd4da2d1u128.to_le_bytes(),
+//! ];
+//! let mut state = [0; 16];
+//! polyval_soft(&key, &mut state, &blocks);
+//! assert_eq!(u128::from_le_bytes(state), 0x7eb7e5f56c86b7e5fa1961847bb4a3f7);
+//! ```
+//!
+//! [`clmul64`]: fn.clmul64.html
+//! [`ghash`]: fn.ghash.html
+//! [`polyval`]: fn.polyval.html
+//! [`clmul64_soft`]: fn.clmul64_soft.html
+//! [`ghash_soft`]: fn.ghash_soft.html
+//! [`polyval_soft`]: fn.polyval_soft.html
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::x86::*;
+
+#[cfg(any(target_arch = "aarch64", all(target_arch = "arm", arch_types_stdarch)))]
+mod arm;
+#[cfg(any(target_arch = "aarch64", all(target_arch = "arm", arch_types_stdarch)))]
+pub use self::arm::*;
+
+// A 64-bit by 64-bit carry-less multiplication
+trait Multiply {
+    // Safety: the implementation's features must be supported
+    unsafe fn clmul64(a: u64, b: u64) -> u128;
+}
+
+struct Soft;
+
+impl Multiply for Soft {
+    #[inline(always)]
+    unsafe fn clmul64(a: u64, b: u64) -> u128 {
+        clmul64_soft(a, b)
+    }
+}
+
+/// Computes the 128-bit carry-less product of `a` and `b`.
+pub fn clmul64_soft(a: u64, b: u64) -> u128 {
+    let mut product = 0;
+    for i in 0..64 {
+        // Select the shifted multiplicand with a mask, rather than branching on each bit
+        let mask = 0u128.wrapping_sub(u128::from((b >> i) & 1));
+        product ^= (u128::from(a) << i) & mask;
+    }
+    product
+}
+
+/// Updates the GHASH `state` with `blocks`, using the hash key `key`.
+pub fn ghash_soft(key: &[u8; 16], state: &mut [u8; 16], blocks: &[[u8; 16]]) {
+    // Safety: the portable implementation has no requirements
+    unsafe { update_ghash::<Soft>(key, state, blocks) }
+}
+
+/// Updates the POLYVAL `state` with `blocks`, using the hash key `key`.
+pub fn polyval_soft(key: &[u8; 16], state: &mut [u8; 16], blocks: &[[u8; 16]]) {
+    // Safety: the portable implementation has no requirements
+    unsafe { update_polyval::<Soft>(key, state, blocks) }
+}
+
+// Multiply in the POLYVAL field, computing `a * b * x^-128` modulo
+// `x^128 + x^127 + x^126 + x^121 + 1`
+#[inline(always)]
+unsafe fn polyval_mul<M: Multiply>(a: u128, b: u128) -> u128 {
+    let (a0, a1) = (a as u64, (a >> 64) as u64);
+    let (b0, b1) = (b as u64, (b >> 64) as u64);
+
+    // Karatsuba multiplication
+    let low = M::clmul64(a0, b0);
+    let high = M::clmul64(a1, b1);
+    let middle = M::clmul64(a0 ^ a1, b0 ^ b1) ^ low ^ high;
+    let v0 = low as u64;
+    let v1 = (low >> 64) as u64 ^ middle as u64;
+    let v2 = high as u64 ^ (middle >> 64) as u64;
+    let v3 = (high >> 64) as u64;
+
+    // Montgomery reduction
+    let v2 = v2 ^ v0 ^ (v0 >> 1) ^ (v0 >> 2) ^ (v0 >> 7);
+    let v1 = v1 ^ (v0 << 63) ^ (v0 << 62) ^ (v0 << 57);
+    let v3 = v3 ^ v1 ^ (v1 >> 1) ^ (v1 >> 2) ^ (v1 >> 7);
+    let v2 = v2 ^ (v1 << 63) ^ (v1 << 62) ^ (v1 << 57);
+    (u128::from(v3) << 64) | u128::from(v2)
+}
+
+#[inline(always)]
+unsafe fn update_polyval<M: Multiply>(key: &[u8; 16], state: &mut [u8; 16], blocks: &[[u8; 16]]) {
+    let key = u128::from_le_bytes(*key);
+    let mut acc = u128::from_le_bytes(*state);
+    for block in blocks {
+        acc = polyval_mul::<M>(acc ^ u128::from_le_bytes(*block), key);
+    }
+    *state = acc.to_le_bytes();
+}
+
+#[inline(always)]
+unsafe fn update_ghash<M: Multiply>(key: &[u8; 16], state: &mut [u8; 16], blocks: &[[u8; 16]]) {
+    // GHASH is POLYVAL with the bytes reversed and the key multiplied by x
+    let key = u128::from_be_bytes(*key);
+    let carry = 0u128.wrapping_sub(key >> 127);
+    let key = (key << 1) ^ (carry & 0xc200_0000_0000_0000_0000_0000_0000_0001);
+
+    let mut acc = u128::from_be_bytes(*state);
+    for block in blocks {
+        acc = polyval_mul::<M>(acc ^ u128::from_be_bytes(*block), key);
+    }
+    *state = acc.to_be_bytes();
+}
@@ -0,0 +1,93 @@
+#[rustversion::since(1.61)]
+use super::{update_ghash, update_polyval, Multiply};
+#[rustversion::since(1.61)]
+use crate::logic::True;
+#[rustversion::since(1.61)]
+use crate::Features;
+
+#[cfg(target_arch = "aarch64")]
+#[rustversion::since(1.61)]
+use core::arch::aarch64::*;
+#[cfg(target_arch = "arm")]
+use core::arch::arm::*;
+
+#[rustversion::since(1.61)]
+struct Pmull;
+
+#[rustversion::since(1.61)]
+impl Multiply for Pmull {
+    // This is only called from functions defined with `pmull_fn`, which enable the features
+    #[allow(unknown_lints, inline_always_mismatching_target_features)]
+    #[inline(always)]
+    unsafe fn clmul64(a: u64, b: u64) -> u128 {
+        vmull_p64(a, b)
+    }
+}
+
+// Define a function with the `pmull` instructions enabled.  Polynomial multiplication is part of
+// the `aes` target feature.
+macro_rules! pmull_fn {
+    { unsafe fn $name:ident($($arg:ident: $ty:ty),*) $(-> $ret:ty)? $body:block } => {
+        #[cfg_attr(target_arch = "aarch64", target_feature(enable = "aes"))]
+        #[cfg_attr(target_arch = "arm", target_feature(enable = "aes,v8"))]
+        unsafe fn $name($($arg: $ty),*) $(-> $ret)? $body
+    };
+}
+
+/// Computes the 128-bit carry-less product of `a` and `b`, using `pmull`.
+///
+/// Requires Rust 1.61.
+#[rustversion::since(1.61)]
+#[inline]
+pub fn clmul64(_: impl Features<pmull = True>, a: u64, b: u64) -> u128 {
+    pmull_fn! {
+        unsafe fn clmul64(a: u64, b: u64) -> u128 {
+            Pmull::clmul64(a, b)
+        }
+    }
+
+    // Safety: the token proves the features are supported
+    unsafe { clmul64(a, b) }
+}
+
+/// Updates the GHASH `state` with `blocks`, using the hash key `key` and `pmull`.
+///
+/// Requires Rust 1.61.
+#[rustversion::since(1.61)]
+#[inline]
+pub fn ghash(
+    _: impl Features<pmull = True>,
+    key: &[u8; 16],
+    state: &mut [u8; 16],
+    blocks: &[[u8; 16]],
+) {
+    pmull_fn! {
+        unsafe fn ghash(key: &[u8; 16], state: &mut [u8; 16], blocks: &[[u8; 16]]) {
+            update_ghash::<Pmull>(key, state, blocks)
+        }
+    }
+
+    // Safety: the token proves the features are supported
+    unsafe { ghash(key, state, blocks) }
+}
+
+/// Updates the POLYVAL `state` with `blocks`, using the hash key `key` and `pmull`.
+///
+/// Requires Rust 1.61.
+#[rustversion::since(1.61)]
+#[inline]
+pub fn polyval(
+    _: impl Features<pmull = True>,
+    key: &[u8; 16],
+    state: &mut [u8; 16],
+    blocks: &[[u8; 16]],
+) {
+    pmull_fn! {
+        unsafe fn polyval(key: &[u8; 16], state: &mut [u8; 16], blocks: &[[u8; 16]]) {
+            update_polyval::<Pmull>(key, state, blocks)
+        }
+    }
+
+    // Safety: the token proves the features are supported
+    unsafe { polyval(key, state, blocks) }
+}
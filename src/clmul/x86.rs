@@ -0,0 +1,70 @@
+use super::{update_ghash, update_polyval, Multiply};
+use crate::logic::True;
+use crate::Features;
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+struct Pclmul;
+
+impl Multiply for Pclmul {
+    #[inline(always)]
+    unsafe fn clmul64(a: u64, b: u64) -> u128 {
+        let a = _mm_set_epi64x(0, a as i64);
+        let b = _mm_set_epi64x(0, b as i64);
+        let mut product = 0u128;
+        _mm_storeu_si128(
+            &mut product as *mut u128 as *mut __m128i,
+            _mm_clmulepi64_si128(a, b, 0x00),
+        );
+        product
+    }
+}
+
+/// Computes the 128-bit carry-less product of `a` and `b`, using `pclmulqdq`.
+#[inline]
+pub fn clmul64(_: impl Features<pclmulqdq = True>, a: u64, b: u64) -> u128 {
+    #[target_feature(enable = "pclmulqdq,sse2")]
+    unsafe fn clmul64(a: u64, b: u64) -> u128 {
+        Pclmul::clmul64(a, b)
+    }
+
+    // Safety: the token proves the features are supported
+    unsafe { clmul64(a, b) }
+}
+
+/// Updates the GHASH `state` with `blocks`, using the hash key `key` and `pclmulqdq`.
+#[inline]
+pub fn ghash(
+    _: impl Features<pclmulqdq = True>,
+    key: &[u8; 16],
+    state: &mut [u8; 16],
+    blocks: &[[u8; 16]],
+) {
+    #[target_feature(enable = "pclmulqdq,sse2")]
+    unsafe fn ghash(key: &[u8; 16], state: &mut [u8; 16], blocks: &[[u8; 16]]) {
+        update_ghash::<Pclmul>(key, state, blocks)
+    }
+
+    // Safety: the token proves the features are supported
+    unsafe { ghash(key, state, blocks) }
+}
+
+/// Updates the POLYVAL `state` with `blocks`, using the hash key `key` and `pclmulqdq`.
+#[inline]
+pub fn polyval(
+    _: impl Features<pclmulqdq = True>,
+    key: &[u8; 16],
+    state: &mut [u8; 16],
+    blocks: &[[u8; 16]],
+) {
+    #[target_feature(enable = "pclmulqdq,sse2")]
+    unsafe fn polyval(key: &[u8; 16], state: &mut [u8; 16], blocks: &[[u8; 16]]) {
+        update_polyval::<Pclmul>(key, state, blocks)
+    }
+
+    // Safety: the token proves the features are supported
+    unsafe { polyval(key, state, blocks) }
+}
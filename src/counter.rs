@@ -0,0 +1,88 @@
+//! Cycle counters for profiling.
+//!
+//! The counter types are:
+//!   * `Tsc` - the time stamp counter, available on `x86` and `x86_64`.
+//!   * `VirtualCounter` - the virtual count of the generic timer, available on `aarch64`.
+//!     Requires Rust 1.59.
+//!
+//! Counter values are converted to time with a [`Calibration`]:
+//!
+//! ```
+//! # #[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+//! # {
+//! use arch_types::counter::Tsc;
+//! use arch_types::{new_features_type, Features};
+//! use std::time::Duration;
+//!
+//! new_features_type! { TscFeature => "tsc" }
+//!
+//! if let Some(token) = TscFeature::new() {
+//!     let tsc = Tsc::from_token(token);
+//!     if tsc.is_invariant() {
+//!         let calibration = tsc.calibrate(Duration::from_millis(10));
+//!         let start = tsc.read();
+//!         // ...
+//!         let elapsed = calibration.to_duration(tsc.read().wrapping_sub(start));
+//!     }
+//! }
+//! # }
+//! ```
+
+use core::time::Duration;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::x86::*;
+
+#[cfg(target_arch = "aarch64")]
+mod aarch64;
+#[cfg(target_arch = "aarch64")]
+pub use self::aarch64::*;
+
+/// The frequency of a counter, for converting counts to time.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Calibration {
+    ticks_per_second: f64,
+}
+
+impl Calibration {
+    /// Create a calibration for a counter incrementing `ticks_per_second` times per second.
+    pub fn from_frequency(ticks_per_second: f64) -> Self {
+        Self { ticks_per_second }
+    }
+
+    // Measure the frequency of a counter against `std::time::Instant`, over at least `duration`.
+    // Longer durations produce more accurate calibrations.
+    #[cfg(all(
+        feature = "std",
+        any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")
+    ))]
+    pub(crate) fn measure(read: impl Fn() -> u64, duration: Duration) -> Self {
+        use std::time::Instant;
+
+        let start_time = Instant::now();
+        let start = read();
+        std::thread::sleep(duration);
+        let end = read();
+        let elapsed = start_time.elapsed();
+
+        let seconds = elapsed.as_secs() as f64 + f64::from(elapsed.subsec_nanos()) * 1e-9;
+        Self::from_frequency(end.wrapping_sub(start) as f64 / seconds)
+    }
+
+    /// Returns the number of ticks per second.
+    pub fn ticks_per_second(&self) -> f64 {
+        self.ticks_per_second
+    }
+
+    /// Converts a number of ticks to nanoseconds.
+    pub fn to_nanos(&self, ticks: u64) -> f64 {
+        ticks as f64 * 1e9 / self.ticks_per_second
+    }
+
+    /// Converts a number of ticks to a `Duration`.
+    pub fn to_duration(&self, ticks: u64) -> Duration {
+        Duration::from_nanos(self.to_nanos(ticks) as u64)
+    }
+}
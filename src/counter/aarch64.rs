@@ -0,0 +1,76 @@
+#[rustversion::since(1.59)]
+use super::Calibration;
+
+#[cfg(feature = "std")]
+#[rustversion::since(1.59)]
+use core::time::Duration;
+
+/// The virtual count of the generic timer, `CNTVCT_EL0`.
+///
+/// The generic timer is part of every AArch64 processor and runs at a constant frequency, reported
+/// by `CNTFRQ_EL0`.  Requires Rust 1.59.
+#[rustversion::since(1.59)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct VirtualCounter(());
+
+#[rustversion::since(1.59)]
+impl VirtualCounter {
+    /// Create a virtual counter.
+    pub fn new() -> Self {
+        Self(())
+    }
+
+    /// Reads the counter.
+    ///
+    /// The read may be reordered with surrounding instructions.
+    #[inline]
+    pub fn read(&self) -> u64 {
+        let ticks: u64;
+        // Safety: the counter is readable at EL0
+        unsafe {
+            core::arch::asm!("mrs {}, cntvct_el0", out(reg) ticks, options(nomem, nostack));
+        }
+        ticks
+    }
+
+    /// Reads the counter after all previous instructions complete.
+    #[inline]
+    pub fn read_ordered(&self) -> u64 {
+        let ticks: u64;
+        // Safety: the counter is readable at EL0
+        unsafe {
+            core::arch::asm!(
+                "isb",
+                "mrs {}, cntvct_el0",
+                out(reg) ticks,
+                options(nomem, nostack),
+            );
+        }
+        ticks
+    }
+
+    /// Returns the frequency of the counter in hertz, as reported by `CNTFRQ_EL0`.
+    pub fn frequency(&self) -> u64 {
+        let frequency: u64;
+        // Safety: the frequency is readable at EL0
+        unsafe {
+            core::arch::asm!("mrs {}, cntfrq_el0", out(reg) frequency, options(nomem, nostack));
+        }
+        frequency
+    }
+
+    /// Returns the calibration reported by `CNTFRQ_EL0`.
+    pub fn calibration(&self) -> Calibration {
+        Calibration::from_frequency(self.frequency() as f64)
+    }
+
+    /// Measure the frequency of the counter against `std::time::Instant`, over at least
+    /// `duration`.
+    ///
+    /// Firmware doesn't always report the frequency correctly, in which case this may be more
+    /// accurate than [`calibration`](#method.calibration).
+    #[cfg(feature = "std")]
+    pub fn calibrate(&self, duration: Duration) -> Calibration {
+        Calibration::measure(|| self.read(), duration)
+    }
+}
@@ -0,0 +1,86 @@
+use crate::logic::True;
+use crate::Features;
+
+#[cfg(feature = "std")]
+use super::Calibration;
+#[cfg(feature = "std")]
+use core::time::Duration;
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+// `__cpuid` is safe in newer versions of Rust
+#[allow(unused_unsafe)]
+fn cpuid(leaf: u32) -> CpuidResult {
+    // Safety: the time stamp counter is detected with `cpuid`, so it must be supported
+    unsafe { __cpuid(leaf) }
+}
+
+/// The time stamp counter.
+///
+/// The counter is read with `RDTSC`, or `RDTSCP` if supported.  Unless the counter is
+/// [invariant](#method.is_invariant), its rate may change with the processor's frequency and power
+/// state, so it measures cycles rather than time.
+#[derive(Copy, Clone, Debug)]
+pub struct Tsc {
+    rdtscp: bool,
+    invariant: bool,
+}
+
+impl Tsc {
+    /// Create a time stamp counter from a token proving it is supported.
+    pub fn from_token(_: impl Features<tsc = True>) -> Self {
+        let max_extended_leaf = cpuid(0x8000_0000).eax;
+        let rdtscp = max_extended_leaf >= 0x8000_0001 && cpuid(0x8000_0001).edx & (1 << 27) != 0;
+        let invariant = max_extended_leaf >= 0x8000_0007 && cpuid(0x8000_0007).edx & (1 << 8) != 0;
+        Self { rdtscp, invariant }
+    }
+
+    /// Returns true if the `RDTSCP` instruction is supported.
+    pub fn has_rdtscp(&self) -> bool {
+        self.rdtscp
+    }
+
+    /// Returns true if the counter runs at a constant rate, regardless of processor frequency or
+    /// power state, as reported by `CPUID` leaf `0x80000007`.
+    pub fn is_invariant(&self) -> bool {
+        self.invariant
+    }
+
+    /// Reads the counter with `RDTSC`.
+    ///
+    /// The read may be reordered with surrounding instructions.
+    #[inline]
+    pub fn read(&self) -> u64 {
+        // Safety: the token proves the time stamp counter is supported
+        unsafe { _rdtsc() }
+    }
+
+    /// Reads the counter with `RDTSCP`, returning the counter and the value of `IA32_TSC_AUX`,
+    /// which identifies the processor on most operating systems.
+    ///
+    /// The read waits for all previous instructions to complete.  Returns `None` if `RDTSCP` isn't
+    /// supported.
+    #[inline]
+    pub fn read_rdtscp(&self) -> Option<(u64, u32)> {
+        if self.rdtscp {
+            let mut aux = 0;
+            // Safety: checked that `RDTSCP` is supported
+            let ticks = unsafe { __rdtscp(&mut aux) };
+            Some((ticks, aux))
+        } else {
+            None
+        }
+    }
+
+    /// Measure the frequency of the counter against `std::time::Instant`, over at least
+    /// `duration`.
+    ///
+    /// The calibration is only meaningful if the counter is [invariant](#method.is_invariant).
+    #[cfg(feature = "std")]
+    pub fn calibrate(&self, duration: Duration) -> Calibration {
+        Calibration::measure(|| self.read(), duration)
+    }
+}
@@ -0,0 +1,104 @@
+#[rustversion::since(1.80)]
+use crate::logic::True;
+#[rustversion::since(1.80)]
+use crate::Features;
+
+#[cfg(target_arch = "aarch64")]
+#[rustversion::since(1.80)]
+use core::arch::aarch64::*;
+#[cfg(target_arch = "arm")]
+use core::arch::arm::*;
+
+#[rustversion::since(1.80)]
+features_type! {
+    /// A token for the CRC32 instructions.
+    Crc => crc
+}
+
+#[rustversion::since(1.80)]
+pub(super) fn crc32_detected(crc: u32, data: &[u8]) -> u32 {
+    if let Some(token) = Crc::new() {
+        crc32_arm(token, crc, data)
+    } else {
+        super::crc32_table(crc, data)
+    }
+}
+
+#[rustversion::since(1.80)]
+pub(super) fn crc32c_detected(crc: u32, data: &[u8]) -> u32 {
+    if let Some(token) = Crc::new() {
+        crc32c_arm(token, crc, data)
+    } else {
+        super::crc32c_table(crc, data)
+    }
+}
+
+// The CRC32 intrinsics aren't available, so always use the lookup table
+#[rustversion::before(1.80)]
+pub(super) use super::{crc32_table as crc32_detected, crc32c_table as crc32c_detected};
+
+// Implement a checksum using the CRC32 instructions, processing a word at a time
+macro_rules! update_crc {
+    { $name:ident, $word:ident, $byte:ident } => {
+        #[rustversion::since(1.80)]
+        #[cfg_attr(target_arch = "aarch64", target_feature(enable = "crc"))]
+        #[cfg_attr(target_arch = "arm", target_feature(enable = "crc,v8"))]
+        unsafe fn $name(crc: u32, data: &[u8]) -> u32 {
+            let mut crc = !crc;
+
+            #[cfg(target_arch = "aarch64")]
+            let data = {
+                let mut chunks = data.chunks_exact(8);
+                for chunk in &mut chunks {
+                    let word = core::ptr::read_unaligned(chunk.as_ptr() as *const u64);
+                    crc = $word(crc, u64::from_le(word));
+                }
+                chunks.remainder()
+            };
+
+            #[cfg(target_arch = "arm")]
+            let data = {
+                let mut chunks = data.chunks_exact(4);
+                for chunk in &mut chunks {
+                    let word = core::ptr::read_unaligned(chunk.as_ptr() as *const u32);
+                    crc = $word(crc, u32::from_le(word));
+                }
+                chunks.remainder()
+            };
+
+            for &byte in data {
+                crc = $byte(crc, byte);
+            }
+            !crc
+        }
+    };
+}
+
+#[cfg(target_arch = "aarch64")]
+update_crc! { update_crc32, __crc32d, __crc32b }
+#[cfg(target_arch = "aarch64")]
+update_crc! { update_crc32c, __crc32cd, __crc32cb }
+#[cfg(target_arch = "arm")]
+update_crc! { update_crc32, __crc32w, __crc32b }
+#[cfg(target_arch = "arm")]
+update_crc! { update_crc32c, __crc32cw, __crc32cb }
+
+/// Computes the CRC-32 of `data`, continuing from `crc`, using the CRC32 instructions.
+///
+/// Requires Rust 1.80.
+#[rustversion::since(1.80)]
+#[inline]
+pub fn crc32_arm(_: impl Features<crc = True>, crc: u32, data: &[u8]) -> u32 {
+    // Safety: the token proves the features are supported
+    unsafe { update_crc32(crc, data) }
+}
+
+/// Computes the CRC-32C of `data`, continuing from `crc`, using the CRC32 instructions.
+///
+/// Requires Rust 1.80.
+#[rustversion::since(1.80)]
+#[inline]
+pub fn crc32c_arm(_: impl Features<crc = True>, crc: u32, data: &[u8]) -> u32 {
+    // Safety: the token proves the features are supported
+    unsafe { update_crc32c(crc, data) }
+}
@@ -0,0 +1,182 @@
+use super::{crc32_table, crc32c_table, update_table, CRC32C_TABLE, CRC32_TABLE};
+use crate::logic::True;
+use crate::Features;
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+features_type! {
+    /// A token for the `crc32` instructions.
+    Sse42 => sse42
+}
+
+features_type! {
+    /// A token for carry-less multiplication.
+    Pclmul => pclmulqdq
+}
+
+pub(super) fn crc32_detected(crc: u32, data: &[u8]) -> u32 {
+    if let Some(token) = Pclmul::new() {
+        crc32_pclmulqdq(token, crc, data)
+    } else {
+        crc32_table(crc, data)
+    }
+}
+
+pub(super) fn crc32c_detected(crc: u32, data: &[u8]) -> u32 {
+    if let Some(token) = Sse42::new() {
+        crc32c_sse42(token, crc, data)
+    } else if let Some(token) = Pclmul::new() {
+        crc32c_pclmulqdq(token, crc, data)
+    } else {
+        crc32c_table(crc, data)
+    }
+}
+
+/// Computes the CRC-32C of `data`, continuing from `crc`, using the SSE4.2 `crc32` instructions.
+#[inline]
+pub fn crc32c_sse42(_: impl Features<sse42 = True>, crc: u32, data: &[u8]) -> u32 {
+    // Safety: the token proves the features are supported
+    unsafe { update_sse42(crc, data) }
+}
+
+#[target_feature(enable = "sse4.2")]
+unsafe fn update_sse42(crc: u32, data: &[u8]) -> u32 {
+    let mut crc = !crc;
+
+    #[cfg(target_arch = "x86_64")]
+    let data = {
+        let mut chunks = data.chunks_exact(8);
+        for chunk in &mut chunks {
+            let word = core::ptr::read_unaligned(chunk.as_ptr() as *const u64);
+            crc = _mm_crc32_u64(u64::from(crc), word) as u32;
+        }
+        chunks.remainder()
+    };
+
+    #[cfg(target_arch = "x86")]
+    let data = {
+        let mut chunks = data.chunks_exact(4);
+        for chunk in &mut chunks {
+            let word = core::ptr::read_unaligned(chunk.as_ptr() as *const u32);
+            crc = _mm_crc32_u32(crc, word);
+        }
+        chunks.remainder()
+    };
+
+    for &byte in data {
+        crc = _mm_crc32_u8(crc, byte);
+    }
+    !crc
+}
+
+// Constants for folding with carry-less multiplication, as described in Intel's "Fast CRC
+// Computation for Generic Polynomials Using PCLMULQDQ Instruction".  Each is a power of x modulo
+// the polynomial, bit-reflected.
+struct Keys {
+    k1: i64,
+    k2: i64,
+    k3: i64,
+    k4: i64,
+    k5: i64,
+    p_x: i64,
+    u_prime: i64,
+}
+
+const CRC32_KEYS: Keys = Keys {
+    k1: 0x1_5444_2bd4,
+    k2: 0x1_c6e4_1596,
+    k3: 0x1_7519_97d0,
+    k4: 0x0_ccaa_009e,
+    k5: 0x1_63cd_6124,
+    p_x: 0x1_db71_0641,
+    u_prime: 0x1_f701_1641,
+};
+
+const CRC32C_KEYS: Keys = Keys {
+    k1: 0x0_740e_ef02,
+    k2: 0x0_9e4a_ddf8,
+    k3: 0x0_f20c_0dfe,
+    k4: 0x1_4cd0_0bd6,
+    k5: 0x0_dd45_aab8,
+    p_x: 0x1_05ec_76f1,
+    u_prime: 0x0_dea7_13f1,
+};
+
+/// Computes the CRC-32 of `data`, continuing from `crc`, using carry-less multiplication.
+#[inline]
+pub fn crc32_pclmulqdq(_: impl Features<pclmulqdq = True>, crc: u32, data: &[u8]) -> u32 {
+    // Safety: the token proves the features are supported
+    unsafe { update_pclmulqdq(&CRC32_KEYS, &CRC32_TABLE, crc, data) }
+}
+
+/// Computes the CRC-32C of `data`, continuing from `crc`, using carry-less multiplication.
+#[inline]
+pub fn crc32c_pclmulqdq(_: impl Features<pclmulqdq = True>, crc: u32, data: &[u8]) -> u32 {
+    // Safety: the token proves the features are supported
+    unsafe { update_pclmulqdq(&CRC32C_KEYS, &CRC32C_TABLE, crc, data) }
+}
+
+#[inline(always)]
+unsafe fn load(chunk: &[u8]) -> __m128i {
+    _mm_loadu_si128(chunk.as_ptr() as *const __m128i)
+}
+
+// Fold `a` forward by the distance encoded in `keys` and add it to `b`
+#[inline(always)]
+unsafe fn fold(a: __m128i, b: __m128i, keys: __m128i) -> __m128i {
+    let low = _mm_clmulepi64_si128(a, keys, 0x00);
+    let high = _mm_clmulepi64_si128(a, keys, 0x11);
+    _mm_xor_si128(b, _mm_xor_si128(low, high))
+}
+
+#[target_feature(enable = "pclmulqdq,sse2")]
+unsafe fn update_pclmulqdq(keys: &Keys, table: &[u32; 256], crc: u32, data: &[u8]) -> u32 {
+    // Folding requires at least four blocks
+    if data.len() < 64 {
+        return update_table(table, crc, data);
+    }
+
+    let mut blocks = data.chunks_exact(16);
+    let mut x3 = load(blocks.next().unwrap());
+    let mut x2 = load(blocks.next().unwrap());
+    let mut x1 = load(blocks.next().unwrap());
+    let mut x0 = load(blocks.next().unwrap());
+    x3 = _mm_xor_si128(x3, _mm_cvtsi32_si128(!crc as i32));
+
+    // Fold four blocks at a time
+    let k1k2 = _mm_set_epi64x(keys.k2, keys.k1);
+    while blocks.len() >= 4 {
+        x3 = fold(x3, load(blocks.next().unwrap()), k1k2);
+        x2 = fold(x2, load(blocks.next().unwrap()), k1k2);
+        x1 = fold(x1, load(blocks.next().unwrap()), k1k2);
+        x0 = fold(x0, load(blocks.next().unwrap()), k1k2);
+    }
+
+    // Fold the remaining blocks into one
+    let k3k4 = _mm_set_epi64x(keys.k4, keys.k3);
+    let mut x = fold(x3, x2, k3k4);
+    x = fold(x, x1, k3k4);
+    x = fold(x, x0, k3k4);
+    for block in &mut blocks {
+        x = fold(x, load(block), k3k4);
+    }
+
+    // Reduce 128 bits to 64 bits
+    let low32 = _mm_set_epi32(0, 0, 0, !0);
+    x = _mm_xor_si128(_mm_clmulepi64_si128(x, k3k4, 0x10), _mm_srli_si128(x, 8));
+    x = _mm_xor_si128(
+        _mm_clmulepi64_si128(_mm_and_si128(x, low32), _mm_set_epi64x(0, keys.k5), 0x00),
+        _mm_srli_si128(x, 4),
+    );
+
+    // Barrett reduction to 32 bits
+    let pu = _mm_set_epi64x(keys.u_prime, keys.p_x);
+    let t1 = _mm_clmulepi64_si128(_mm_and_si128(x, low32), pu, 0x10);
+    let t2 = _mm_clmulepi64_si128(_mm_and_si128(t1, low32), pu, 0x00);
+    let crc = _mm_cvtsi128_si32(_mm_srli_si128(_mm_xor_si128(x, t2), 4)) as u32;
+
+    update_table(table, !crc, blocks.remainder())
+}
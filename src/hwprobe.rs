@@ -0,0 +1,224 @@
+//! RISC-V feature detection using the Linux `riscv_hwprobe` syscall.
+//!
+//! This is used when `is_riscv_feature_detected` is unavailable, either because `std` is disabled
+//! or the compiler doesn't support detecting a particular feature.
+
+/// Detect a RISC-V feature with `riscv_hwprobe`, returning `false` if it can't be detected.
+macro_rules! riscv_hwprobe_detected {
+    ("m") => {
+        $crate::hwprobe::base_ima()
+    };
+    ("a") => {
+        $crate::hwprobe::base_ima()
+    };
+    ("f") => {
+        $crate::hwprobe::has(0)
+    };
+    ("d") => {
+        $crate::hwprobe::has(0)
+    };
+    ("c") => {
+        $crate::hwprobe::has(1)
+    };
+    ("v") => {
+        $crate::hwprobe::has(2)
+    };
+    ("zba") => {
+        $crate::hwprobe::has(3)
+    };
+    ("zbb") => {
+        $crate::hwprobe::has(4)
+    };
+    ("zbs") => {
+        $crate::hwprobe::has(5)
+    };
+    ("zbc") => {
+        $crate::hwprobe::has(7)
+    };
+    ("zbkb") => {
+        $crate::hwprobe::has(8)
+    };
+    ("zbkc") => {
+        $crate::hwprobe::has(9)
+    };
+    ("zbkx") => {
+        $crate::hwprobe::has(10)
+    };
+    ("zknd") => {
+        $crate::hwprobe::has(11)
+    };
+    ("zkne") => {
+        $crate::hwprobe::has(12)
+    };
+    ("zknh") => {
+        $crate::hwprobe::has(13)
+    };
+    ("zksed") => {
+        $crate::hwprobe::has(14)
+    };
+    ("zksh") => {
+        $crate::hwprobe::has(15)
+    };
+    ("zkt") => {
+        $crate::hwprobe::has(16)
+    };
+    ("zfh") => {
+        $crate::hwprobe::has(27)
+    };
+    ("zfhmin") => {
+        $crate::hwprobe::has(28)
+    };
+    ("zacas") => {
+        $crate::hwprobe::has(34)
+    };
+    ("zicond") => {
+        $crate::hwprobe::has(35)
+    };
+    ("zihintpause") => {
+        $crate::hwprobe::has(36)
+    };
+    ("zkn") => {
+        riscv_hwprobe_detected!("zbkb")
+            && riscv_hwprobe_detected!("zbkc")
+            && riscv_hwprobe_detected!("zbkx")
+            && riscv_hwprobe_detected!("zkne")
+            && riscv_hwprobe_detected!("zknd")
+            && riscv_hwprobe_detected!("zknh")
+    };
+    ("zks") => {
+        riscv_hwprobe_detected!("zbkb")
+            && riscv_hwprobe_detected!("zbkc")
+            && riscv_hwprobe_detected!("zbkx")
+            && riscv_hwprobe_detected!("zksed")
+            && riscv_hwprobe_detected!("zksh")
+    };
+    ($feature:tt) => {
+        false
+    };
+}
+
+#[cfg(target_os = "linux")]
+mod imp {
+    use core::sync::atomic::{AtomicU32, Ordering};
+
+    const RISCV_HWPROBE_KEY_BASE_BEHAVIOR: i64 = 3;
+    const RISCV_HWPROBE_BASE_BEHAVIOR_IMA: u64 = 1 << 0;
+    const RISCV_HWPROBE_KEY_IMA_EXT_0: i64 = 4;
+
+    // Set once the cache has been filled
+    const INITIALIZED: u32 = 1 << 31;
+    const IMA: u32 = 1 << 0;
+
+    // The cached base behavior and `RISCV_HWPROBE_KEY_IMA_EXT_0` value, which is split since
+    // 64-bit atomics aren't available on `riscv32`.
+    static BASE: AtomicU32 = AtomicU32::new(0);
+    static EXT_LO: AtomicU32 = AtomicU32::new(0);
+    static EXT_HI: AtomicU32 = AtomicU32::new(0);
+
+    #[repr(C)]
+    struct Pair {
+        key: i64,
+        value: u64,
+    }
+
+    #[rustversion::since(1.59)]
+    fn probe(pairs: &mut [Pair]) -> bool {
+        const SYS_RISCV_HWPROBE: usize = 258;
+        let ret: isize;
+        // Safety: the kernel only writes within `pairs`, and no CPU set is provided
+        unsafe {
+            core::arch::asm!(
+                "ecall",
+                in("a7") SYS_RISCV_HWPROBE,
+                inlateout("a0") pairs.as_mut_ptr() => ret,
+                in("a1") pairs.len(),
+                in("a2") 0usize,
+                in("a3") 0usize,
+                in("a4") 0usize,
+                options(nostack),
+            );
+        }
+        ret == 0
+    }
+
+    // Inline assembly isn't available, so nothing is detected
+    #[rustversion::before(1.59)]
+    fn probe(_: &mut [Pair]) -> bool {
+        false
+    }
+
+    #[cold]
+    fn initialize() -> u32 {
+        let mut pairs = [
+            Pair {
+                key: RISCV_HWPROBE_KEY_BASE_BEHAVIOR,
+                value: 0,
+            },
+            Pair {
+                key: RISCV_HWPROBE_KEY_IMA_EXT_0,
+                value: 0,
+            },
+        ];
+
+        // Unknown keys are reported by the kernel with a key of -1
+        let mut base = INITIALIZED;
+        if probe(&mut pairs) {
+            if pairs[0].key == RISCV_HWPROBE_KEY_BASE_BEHAVIOR
+                && pairs[0].value & RISCV_HWPROBE_BASE_BEHAVIOR_IMA != 0
+            {
+                base |= IMA;
+            }
+            if pairs[1].key == RISCV_HWPROBE_KEY_IMA_EXT_0 {
+                EXT_LO.store(pairs[1].value as u32, Ordering::Relaxed);
+                EXT_HI.store((pairs[1].value >> 32) as u32, Ordering::Relaxed);
+            }
+        }
+        BASE.store(base, Ordering::Release);
+        base
+    }
+
+    #[inline]
+    fn base() -> u32 {
+        let base = BASE.load(Ordering::Acquire);
+        if base & INITIALIZED == 0 {
+            initialize()
+        } else {
+            base
+        }
+    }
+
+    /// Returns true if the base IMA behavior is supported.
+    #[inline]
+    pub(crate) fn base_ima() -> bool {
+        base() & IMA != 0
+    }
+
+    /// Returns true if `bit` of `RISCV_HWPROBE_KEY_IMA_EXT_0` is set.
+    #[inline]
+    pub(crate) fn has(bit: u32) -> bool {
+        // Extensions are only reported if the base behavior is supported
+        if !base_ima() {
+            return false;
+        }
+        if bit < 32 {
+            EXT_LO.load(Ordering::Relaxed) & (1 << bit) != 0
+        } else {
+            EXT_HI.load(Ordering::Relaxed) & (1 << (bit - 32)) != 0
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod imp {
+    #[inline]
+    pub(crate) fn base_ima() -> bool {
+        false
+    }
+
+    #[inline]
+    pub(crate) fn has(_: u32) -> bool {
+        false
+    }
+}
+
+pub(crate) use imp::{base_ima, has};
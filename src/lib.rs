@@ -1,6 +1,48 @@
 #![allow(non_camel_case_types)]
 #![cfg_attr(not(feature = "std"), no_std)]
-#![cfg_attr(feature = "nightly", feature(stdsimd))]
+#![cfg_attr(arch_types_stdsimd, feature(stdsimd))]
+#![cfg_attr(
+    all(
+        arch_types_amx,
+        feature = "std",
+        any(target_arch = "x86", target_arch = "x86_64")
+    ),
+    feature(x86_amx_intrinsics)
+)]
+#![cfg_attr(
+    all(arch_types_stdarch, target_arch = "aarch64"),
+    feature(stdarch_neon_dotprod, stdarch_neon_sm4)
+)]
+#![cfg_attr(
+    all(arch_types_stdarch, target_arch = "arm"),
+    feature(
+        stdarch_arm_feature_detection,
+        stdarch_aarch32_crc32,
+        stdarch_arm_neon_intrinsics
+    )
+)]
+#![cfg_attr(
+    all(any(arch_types_stdsimd, arch_types_stdarch), target_arch = "arm"),
+    feature(arm_target_feature)
+)]
+#![cfg_attr(
+    all(arch_types_stdarch, any(target_arch = "mips", target_arch = "mips64")),
+    feature(stdarch_mips_feature_detection)
+)]
+#![cfg_attr(
+    all(
+        arch_types_stdarch,
+        any(target_arch = "powerpc", target_arch = "powerpc64")
+    ),
+    feature(stdarch_powerpc_feature_detection)
+)]
+#![cfg_attr(
+    all(
+        arch_types_stdarch,
+        any(target_arch = "riscv32", target_arch = "riscv64")
+    ),
+    feature(stdarch_riscv_feature_detection)
+)]
 //! This crate provides type-level CPU feature detection using a tag dispatch model.
 //!
 //! Tag types implement the [`Features`] trait, which proves either statically or dynamically
@@ -9,21 +51,59 @@
 //! The [`new_features_type`] macro creates tag types and [`impl_features`] and [`has_features`]
 //! ensure CPU features are supported statically and dynamically, respectively.
 //!
+//! The features of every supported architecture, including those other than the target
+//! architecture, are described by the [`tables`] module.
+//!
+//! The [`simd`] module provides portable vector types, implemented for token types proving support
+//! for each vector instruction set.
+//!
+//! The [`crc`] module computes CRC-32 and CRC-32C checksums using the hardware implementation
+//! proven by a token.  The [`clmul`] module provides carry-less multiplication and the GHASH and
+//! POLYVAL universal hashes.  The [`counter`] module provides cycle counters for profiling.
+//!
+//! # Minimum Rust version
+//! This crate requires Rust 1.42.  Some functionality requires a later version, which is noted in
+//! its documentation.
+//!
 //! # Cargo features
 //! This crate provides the following cargo features:
 //!   * `std` (enabled by default) - Use the `std` crate for feature detection.  Disable this
 //!     feature for `#[no_std]` support.
 //!   * `nightly` - Enable nightly features.  This includes run-time feature detection for some
-//!     architectures, as well as detection of some particular features.
+//!     architectures, as well as detection of some particular features.  This feature has no
+//!     effect when not using a nightly compiler.
+//!   * `serde` - Implement `Serialize` and `Deserialize` for [`Feature`] and [`FeatureSet`].
+//!     Requires Rust 1.56.
+//!   * `rand_core` - Implement `rand_core::TryRngCore` and `rand_core::TryCryptoRng` for
+//!     [`HardwareRng`].  Requires Rust 1.63.
+//!   * `alloc` - Enable the [`aligned`] module, which provides buffers aligned to vector
+//!     registers.  Requires Rust 1.36.
+//!   * `cli` - Build the `arch-types` binary, which reports the features detected on the host.
 //!
 //! If feature detection cannot be performed (either not using `std` or not using a nightly
 //! compiler for a particular feature or architecture), feature detection is performed at compile
 //! time using `#[cfg(target_feature)]`.
 //!
+//! Some features, such as AMX on Linux, must also be enabled by the operating system.  Detecting
+//! these features requests permission, and [`Features::try_new`] reports if it was denied.
+//!
+//! [`Features::try_new`]: trait.Features.html#method.try_new
 //! [`Features`]: trait.Features.html
+//! [`tables`]: tables/index.html
+//! [`simd`]: simd/index.html
+//! [`crc`]: crc/index.html
+//! [`clmul`]: clmul/index.html
+//! [`counter`]: counter/index.html
+//! [`aligned`]: aligned/index.html
 //! [`new_features_type`]: macro.new_features_type.html
 //! [`impl_features`]: macro.impl_features.html
 //! [`has_features`]: macro.has_features.html
+//! [`Feature`]: enum.Feature.html
+//! [`HardwareRng`]: x86/rng/struct.HardwareRng.html
+//! [`FeatureSet`]: struct.FeatureSet.html
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 // Cannot be (safely) constructed in other crates.
 #[doc(hidden)]
@@ -67,6 +147,9 @@ pub mod marker {
     ///
     /// You should not implement this trait.  It is automatically implemented by [`new_features_type`].
     ///
+    /// # Safety
+    /// Implementing this trait asserts that every feature of this type is also supported by `T`.
+    ///
     /// [`new_features_type`]: ../macro.new_features_type.html
     pub unsafe trait Subset<T>: crate::Features
     where
@@ -78,6 +161,9 @@ pub mod marker {
     ///
     /// You should not implement this trait.  It is automatically implemented for types implementing [`Subset`].
     ///
+    /// # Safety
+    /// Implementing this trait asserts that every feature of `T` is also supported by this type.
+    ///
     /// [`Subset`]: trait.Subset.html
     pub unsafe trait Superset<T>: crate::Features
     where
@@ -102,20 +188,120 @@ pub mod marker {
     impl<T> Identity for T where T: Subset<T> + Superset<T> {}
 }
 
+/// Metadata describing a CPU feature.
+///
+/// The metadata for each feature supported by the target architecture is available in
+/// [`FEATURE_INFO`].
+///
+/// [`FEATURE_INFO`]: constant.FEATURE_INFO.html
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FeatureInfo {
+    /// The feature name, as used by [`impl_features`] and [`has_features`].
+    ///
+    /// [`impl_features`]: macro.impl_features.html
+    /// [`has_features`]: macro.has_features.html
+    pub name: &'static str,
+
+    /// A human-readable description of the feature.
+    pub description: &'static str,
+
+    /// The name used by `rustc` for `#[target_feature]` and `-C target-feature`, if `rustc`
+    /// supports it.
+    pub rustc_name: Option<&'static str>,
+
+    /// The name used by LLVM, if LLVM supports it.
+    pub llvm_name: Option<&'static str>,
+
+    /// The name of the flag reported by `/proc/cpuinfo` on Linux, if any.
+    pub cpuinfo_name: Option<&'static str>,
+
+    /// The names of features directly implied by this feature.
+    pub implies: &'static [&'static str],
+
+    /// The first microarchitecture, including its vendor, to support this feature.
+    pub microarchitecture: Option<&'static str>,
+
+    /// The minimum Rust version that supports detecting this feature.
+    pub version: &'static str,
+}
+
 #[allow(unused_macros)]
 macro_rules! features {
     {
+        $(#[doc = $doc:literal])*
+        @table $table:ident
+        @native #[$native:meta]
         @detect_macro $detect_macro:ident
+        $(@fallback_macro $fallback_macro:ident)?
+        $(@enable_macro $enable_macro:ident)?
+        $(@vector [$($vector_feature:ident)*] => [$vector_bytes:expr, $vector_registers:expr, $vector_masks:expr])*
+        $(
+            @alias $alias:ident $alias_lit:tt => [$($alias_target:ident),*]
+            @deprecated $alias_note:literal
+        )*
         $(
             @feature $ident:ident
             @detect $feature_lit:tt
             @version #$attr:tt $version_string:literal
+            @description $description:literal
+            @rustc $rustc:tt
+            @llvm $llvm:tt
+            @cpuinfo $cpuinfo:tt
+            @implies [$($implies:literal),*]
+            @microarch $microarch:tt
         )*
+    } => {
+        $(#[doc = $doc])*
+        pub mod $table {
+            features! {
+                @data
+                $([$ident, $feature_lit, $version_string, $description, $rustc, $llvm, $cpuinfo, [$($implies),*], $microarch])*
+            }
+        }
+
+        // Types and detection for the architecture being compiled for
+        #[$native]
+        #[macro_use]
+        pub(crate) mod native {
+            #[doc(inline)]
+            pub use super::$table::{Feature, FeatureSet, FEATURE_INFO};
+
+            features! {
+                @native $detect_macro [$($fallback_macro)?] [$($enable_macro)?]
+                {$([[$($vector_feature)*], $vector_bytes, $vector_registers, $vector_masks])*}
+                {$([$alias, $alias_lit, [$($alias_target),*], $alias_note])*}
+                $([$attr, $ident, $feature_lit, $version_string])*
+            }
+        }
+    };
+
+    {
+        @native #[$native:meta]
+    } => {
+        // Architectures without a feature table have no features
+        #[$native]
+        #[macro_use]
+        pub(crate) mod native {
+            features! { @data }
+            features! { @native compile_error [] [] {} {} }
+        }
+    };
+
+    {
+        @native $detect_macro:ident $fallback_macro:tt $enable_macro:tt $vectors:tt $aliases:tt
+        $([$attr:tt, $ident:ident, $feature_lit:tt, $version_string:literal])*
     } => {
         /// Indicates the presence of available CPU features.
         ///
         /// An instance of a type implementing `Features` serves as a proof that the specified CPU
         /// features are supported by the CPU.
+        ///
+        /// # Safety
+        /// Implementing this trait asserts that the associated types accurately describe the
+        /// features required to construct the type.  Use [`new_features_type`] instead of
+        /// implementing it directly.
+        ///
+        /// [`new_features_type`]: macro.new_features_type.html
         pub unsafe trait Features: Copy {
             $(
                 #[doc = "Indicates presence of the `"]
@@ -126,6 +312,21 @@ macro_rules! features {
                 type $ident: $crate::logic::Bool;
             )*
 
+            /// The width, in bytes, of the widest vector registers proven by this type, or 0 if no
+            /// vector features are proven.
+            ///
+            /// Any feature implying vector registers counts, such as `avx2` implying 32-byte
+            /// registers.
+            ///
+            /// For scalable vector extensions, this is the minimum width.
+            const VECTOR_BYTES: usize = features!(@vector bytes $vectors);
+
+            /// The number of vector registers of width [`VECTOR_BYTES`](#associatedconstant.VECTOR_BYTES).
+            const VECTOR_REGISTERS: usize = features!(@vector registers $vectors);
+
+            /// Indicates the presence of dedicated mask (or predicate) registers.
+            const HAS_MASK_REGISTERS: bool = features!(@vector masks $vectors);
+
             /// Detect the existence of these features, returning `None` if it isn't supported by the
             /// CPU.
             ///
@@ -133,14 +334,21 @@ macro_rules! features {
             /// Otherwise, available features are determined with `target_arch`.
             #[inline(always)]
             fn new() -> Option<Self> {
-                use $crate::logic::Bool;
+                Self::try_new().ok()
+            }
 
+            /// Detect the existence of these features, returning the reason if any is unavailable.
+            ///
+            /// Some features must be enabled by the operating system before use.  This performs the
+            /// same detection as [`new`](#method.new), requesting permission where necessary.
+            #[inline(always)]
+            fn try_new() -> Result<Self, DetectError> {
                 $(
-                    if Self::$ident::VALUE && !detect::$ident() {
-                        return None;
+                    if <Self::$ident as $crate::logic::Bool>::VALUE {
+                        Feature::$ident.check()?;
                     }
                 )*
-                Some(unsafe { Self::new_unchecked() })
+                Ok(unsafe { Self::new_unchecked() })
             }
 
             /// Create a new architecture type handle.
@@ -171,7 +379,7 @@ macro_rules! features {
                 T: Features
             {
                 $(
-                    if <<T as $crate::Features>::$ident as $crate::logic::Bool>::VALUE && !<Self::$ident as $crate::logic::Bool>::VALUE && !detect::$ident() {
+                    if <<T as $crate::Features>::$ident as $crate::logic::Bool>::VALUE && !<Self::$ident as $crate::logic::Bool>::VALUE && Feature::$ident.check().is_err() {
                         return None;
                     }
                 )*
@@ -179,12 +387,385 @@ macro_rules! features {
             }
         }
 
-        features! { @with_dollar ($), $detect_macro => $([$attr, $ident, $feature_lit])* }
+        impl Feature {
+            /// Detect the existence of this feature.
+            ///
+            /// This performs the same detection as `Features::new`.
+            #[inline]
+            pub fn is_detected(self) -> bool {
+                self.check().is_ok()
+            }
+
+            /// Detect the existence of this feature, returning the reason if it's unavailable.
+            ///
+            /// This performs the same detection as `Features::try_new`.
+            #[inline]
+            pub fn check(self) -> Result<(), DetectError> {
+                match self {
+                    $(
+                        Feature::$ident => {
+                            if !detect::$ident() {
+                                Err(DetectError::Unsupported(self))
+                            } else {
+                                enable::$ident().map_err(|errno| DetectError::PermissionDenied(self, errno))
+                            }
+                        }
+                    )*
+                }
+            }
+        }
+
+        /// The reason a feature is unavailable.
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        pub enum DetectError {
+            /// The feature isn't supported by the CPU.
+            Unsupported(Feature),
+
+            /// The feature is supported by the CPU, but the operating system refused to enable it.
+            /// Contains the `errno` value returned by the kernel.
+            PermissionDenied(Feature, i32),
+        }
+
+        impl core::fmt::Display for DetectError {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+                match self {
+                    DetectError::Unsupported(feature) => write!(f, "feature `{}` is not supported", feature),
+                    DetectError::PermissionDenied(feature, errno) => {
+                        write!(f, "permission to use feature `{}` was denied (error {})", feature, errno)
+                    }
+                }
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl std::error::Error for DetectError {}
+
+        // Some features must be enabled by the operating system before use, which is requested once
+        // the feature has been detected.
+        mod enable {
+            $(
+                #[inline(always)]
+                pub(crate) fn $ident() -> Result<(), i32> {
+                    features!(@enable $enable_macro $feature_lit)
+                }
+            )*
+        }
+
+        impl FeatureSet {
+            /// Create a feature set containing every feature detected on this CPU.
+            pub fn detect() -> Self {
+                Feature::ALL.iter().cloned().filter(|feature| feature.is_detected()).collect()
+            }
+
+            /// Create a feature set containing the features proven by `T`.
+            pub fn of<T: Features>() -> Self {
+                let features: &[(Feature, bool)] = &[
+                    $((Feature::$ident, <<T as Features>::$ident as $crate::logic::Bool>::VALUE)),*
+                ];
+                features.iter().filter(|f| f.1).map(|f| f.0).collect()
+            }
+        }
+
+        features! { @with_dollar ($), $detect_macro $fallback_macro $aliases => $([$attr, $ident, $feature_lit])* }
     };
 
     {
-        @with_dollar ($dollar:tt), $detect_macro:ident => $([$attr:tt, $ident:ident, $feature_lit:tt])*
+        @data
+        $([$ident:ident, $feature_lit:tt, $version_string:literal, $description:literal, $rustc:tt, $llvm:tt, $cpuinfo:tt, [$($implies:literal),*], $microarch:tt])*
     } => {
+        /// A CPU feature supported by this architecture.
+        ///
+        /// Feature names match those accepted by `impl_features!` and `has_features!`.
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum Feature {
+            $(
+                #[doc = $description]
+                #[doc = " (`"]
+                #[doc = $feature_lit]
+                #[doc = "`)."]
+                $ident,
+            )*
+        }
+
+        /// Metadata for every feature supported by this architecture, in the same order as
+        /// [`Feature::ALL`].
+        ///
+        /// [`Feature::ALL`]: enum.Feature.html#associatedconstant.ALL
+        pub const FEATURE_INFO: &[$crate::FeatureInfo] = &[
+            $(
+                $crate::FeatureInfo {
+                    name: $feature_lit,
+                    description: $description,
+                    rustc_name: features!(@option $rustc),
+                    llvm_name: features!(@option $llvm),
+                    cpuinfo_name: features!(@option $cpuinfo),
+                    implies: &[$($implies),*],
+                    microarchitecture: features!(@option $microarch),
+                    version: $version_string,
+                },
+            )*
+        ];
+
+        impl Feature {
+            /// Every feature supported by this architecture.
+            pub const ALL: &'static [Feature] = &[$(Feature::$ident),*];
+
+            /// Returns the name of this feature.
+            #[inline]
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Feature::$ident => $feature_lit,)*
+                }
+            }
+
+            /// Returns the feature with the specified name, or `None` if it isn't known.
+            #[inline]
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    $($feature_lit => Some(Feature::$ident),)*
+                    _ => None,
+                }
+            }
+
+            /// Returns the metadata for this feature.
+            #[inline]
+            pub fn info(self) -> &'static $crate::FeatureInfo {
+                &FEATURE_INFO[self as usize]
+            }
+        }
+
+        impl core::fmt::Display for Feature {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+                f.write_str(self.name())
+            }
+        }
+
+        const FEATURE_COUNT: usize = 0 $(+ features!(@one $ident))*;
+
+        /// A set of CPU features.
+        ///
+        /// Unlike a type implementing `Features`, a `FeatureSet` is not proof that the features are
+        /// supported.  It is useful for recording or comparing feature sets at run time.
+        #[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+        pub struct FeatureSet([u64; (FEATURE_COUNT + 63) / 64]);
+
+        impl FeatureSet {
+            /// Create an empty feature set.
+            #[inline]
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            /// Returns `true` if the set contains the feature.
+            #[inline]
+            pub fn contains(&self, feature: Feature) -> bool {
+                let index = feature as usize;
+                self.0[index / 64] & (1 << (index % 64)) != 0
+            }
+
+            /// Adds a feature to the set, returning `true` if it wasn't already present.
+            #[inline]
+            pub fn insert(&mut self, feature: Feature) -> bool {
+                let index = feature as usize;
+                let present = self.contains(feature);
+                self.0[index / 64] |= 1 << (index % 64);
+                !present
+            }
+
+            /// Removes a feature from the set, returning `true` if it was present.
+            #[inline]
+            pub fn remove(&mut self, feature: Feature) -> bool {
+                let index = feature as usize;
+                let present = self.contains(feature);
+                self.0[index / 64] &= !(1 << (index % 64));
+                present
+            }
+
+            /// Returns the number of features in the set.
+            pub fn len(&self) -> usize {
+                self.0.iter().map(|word| word.count_ones() as usize).sum()
+            }
+
+            /// Returns `true` if the set contains no features.
+            pub fn is_empty(&self) -> bool {
+                self.0.iter().all(|word| *word == 0)
+            }
+
+            /// Returns an iterator over the features in the set, in declaration order.
+            pub fn iter(&self) -> impl Iterator<Item = Feature> {
+                let set = *self;
+                Feature::ALL.iter().cloned().filter(move |feature| set.contains(*feature))
+            }
+
+            /// Returns `true` if every feature in this set is also in `other`.
+            pub fn is_subset(&self, other: &FeatureSet) -> bool {
+                self.0.iter().zip(other.0.iter()).all(|(a, b)| a & !b == 0)
+            }
+
+            /// Returns `true` if this set contains every feature in `requirements`.
+            ///
+            /// This can be used to determine if a CPU with these features is capable of running
+            /// code that requires `requirements`.
+            pub fn satisfies(&self, requirements: &FeatureSet) -> bool {
+                requirements.is_subset(self)
+            }
+
+            /// Returns the features in `requirements` that are missing from this set.
+            pub fn missing(&self, requirements: &FeatureSet) -> FeatureSet {
+                let mut missing = *requirements;
+                for (word, present) in missing.0.iter_mut().zip(self.0.iter()) {
+                    *word &= !present;
+                }
+                missing
+            }
+
+            /// Returns the features in either set.
+            pub fn union(&self, other: &FeatureSet) -> FeatureSet {
+                let mut union = *self;
+                for (word, other) in union.0.iter_mut().zip(other.0.iter()) {
+                    *word |= other;
+                }
+                union
+            }
+
+            /// Returns the features in both sets.
+            pub fn intersection(&self, other: &FeatureSet) -> FeatureSet {
+                let mut intersection = *self;
+                for (word, other) in intersection.0.iter_mut().zip(other.0.iter()) {
+                    *word &= other;
+                }
+                intersection
+            }
+        }
+
+        impl core::fmt::Debug for FeatureSet {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+                f.debug_set().entries(self.iter()).finish()
+            }
+        }
+
+        impl core::iter::FromIterator<Feature> for FeatureSet {
+            fn from_iter<I: IntoIterator<Item = Feature>>(iter: I) -> Self {
+                let mut set = Self::new();
+                set.extend(iter);
+                set
+            }
+        }
+
+        impl core::iter::Extend<Feature> for FeatureSet {
+            fn extend<I: IntoIterator<Item = Feature>>(&mut self, iter: I) {
+                for feature in iter {
+                    self.insert(feature);
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        const FEATURE_NAMES: &[&str] = &[$($feature_lit),*];
+
+        /// Serializes as the feature name.
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for Feature {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.name())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for Feature {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct Visitor;
+
+                impl<'de> serde::de::Visitor<'de> for Visitor {
+                    type Value = Feature;
+
+                    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                        f.write_str("a CPU feature name")
+                    }
+
+                    fn visit_str<E: serde::de::Error>(self, name: &str) -> Result<Feature, E> {
+                        Feature::from_name(name).ok_or_else(|| E::unknown_variant(name, FEATURE_NAMES))
+                    }
+                }
+
+                deserializer.deserialize_str(Visitor)
+            }
+        }
+
+        /// Serializes as a sequence of feature names.
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for FeatureSet {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_seq(self.iter())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for FeatureSet {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct Visitor;
+
+                impl<'de> serde::de::Visitor<'de> for Visitor {
+                    type Value = FeatureSet;
+
+                    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                        f.write_str("a sequence of CPU feature names")
+                    }
+
+                    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<FeatureSet, A::Error> {
+                        let mut set = FeatureSet::new();
+                        while let Some(feature) = seq.next_element()? {
+                            set.insert(feature);
+                        }
+                        Ok(set)
+                    }
+                }
+
+                deserializer.deserialize_seq(Visitor)
+            }
+        }
+    };
+
+    { @one $ident:ident } => { 1 };
+    { @option none } => { None };
+    { @option $value:literal } => { Some($value) };
+    { @fallback [] $feature_lit:tt } => { cfg!(target_feature = $feature_lit) };
+    { @fallback [$fallback_macro:ident] $feature_lit:tt } => {
+        cfg!(target_feature = $feature_lit) || $fallback_macro!($feature_lit)
+    };
+    { @vector bytes {} } => { 0 };
+    { @vector registers {} } => { 0 };
+    { @vector masks {} } => { false };
+    {
+        @vector $field:ident
+        {[[$($feature:ident)*], $bytes:expr, $registers:expr, $masks:expr] $($rest:tt)*}
+    } => {
+        // Select the first vector width with any of its features present
+        [
+            features!(@vector $field {$($rest)*}),
+            features!(@vector_field $field $bytes, $registers, $masks),
+        ][(false $(| <Self::$feature as $crate::logic::Bool>::VALUE)*) as usize]
+    };
+    { @vector_field bytes $bytes:expr, $registers:expr, $masks:expr } => { $bytes };
+    { @vector_field registers $bytes:expr, $registers:expr, $masks:expr } => { $registers };
+    { @vector_field masks $bytes:expr, $registers:expr, $masks:expr } => { $masks };
+    { @enable [] $feature_lit:tt } => { Ok(()) };
+    { @enable [$enable_macro:ident] $feature_lit:tt } => { $enable_macro!($feature_lit) };
+
+    {
+        @with_dollar ($dollar:tt), $detect_macro:ident $fallback_macro:tt
+        {$([$alias:ident, $alias_lit:tt, [$($alias_target:ident),*], $alias_note:literal])*}
+        => $([$attr:tt, $ident:ident, $feature_lit:tt])*
+    } => {
+        // Using an alias refers to one of these, producing a deprecation warning
+        #[doc(hidden)]
+        pub mod __deprecated {
+            $(
+                #[deprecated(note = $alias_note)]
+                pub type $alias = $crate::logic::True;
+            )*
+        }
+
         #[macro_export]
         #[doc(hidden)]
         macro_rules! new_features_type_internal {
@@ -196,6 +777,14 @@ macro_rules! features {
                 };
             )*
 
+            $(
+                {
+                    [$dollar($docs:literal)*] $vis:vis $name:ident => [$alias_lit $dollar($feature:tt)*] => [$dollar($feature_ident:tt)*]
+                } => {
+                    $crate::new_features_type_internal! { [$dollar($docs)*] $vis $name => [$dollar($feature)*] => [$dollar($feature_ident)* $($alias_target)*] }
+                };
+            )*
+
             {
                 [$dollar($docs:literal)*] $vis:vis $name:ident => [] => [$dollar($feature:ident)*]
             } => {
@@ -220,17 +809,24 @@ macro_rules! features {
                         type $ident = __associated_type!{ $ident };
                     )*
 
+                    #[allow(unused_unsafe)]
                     unsafe fn new_unchecked() -> Self {
                         Self(unsafe { $crate::UnsafeConstructible::new() })
                     }
                 }
 
-                unsafe impl<T> $crate::marker::Subset<T> for $name
+                unsafe impl<__T> $crate::marker::Subset<__T> for $name
                 where
-                    T: $crate::Features<$dollar($feature = $crate::logic::True),*>,
+                    __T: $crate::Features<$dollar($feature = $crate::logic::True),*>,
                 {
                 }
-            }
+            };
+
+            {
+                [$dollar($docs:literal)*] $vis:vis $name:ident => [$unknown:tt $dollar($feature:tt)*] => [$dollar($feature_ident:tt)*]
+            } => {
+                compile_error!(concat!("feature ", stringify!($unknown), " is not available on this architecture"));
+            };
         }
 
         #[macro_export]
@@ -246,8 +842,14 @@ macro_rules! features {
                 };
             )*
 
-            { [$dollar($all:tt)*] => [$dollar($output:tt)*] } => {
-                compile_error!("unknown feature")
+            $(
+                { [$alias_lit $dollar($rest:tt)*] => [$dollar($output:tt)*] } => {
+                    $crate::impl_features_internal!{ [$dollar($rest)*] => [ $($alias_target = $crate::__deprecated::$alias,)* $dollar($output)* ] }
+                };
+            )*
+
+            { [$unknown:tt $dollar($rest:tt)*] => [$dollar($output:tt)*] } => {
+                compile_error!(concat!("feature ", stringify!($unknown), " is not available on this architecture"))
             };
         }
 
@@ -260,19 +862,61 @@ macro_rules! features {
                 };
             )*
 
-            { $name:ident => $unknown:tt } => {
-                compile_error!("unknown feature")
+            $(
+                { $type:ty => $alias_lit } => {
+                    <$crate::__deprecated::$alias as $crate::logic::Bool>::VALUE
+                        $(&& <<$type as $crate::Features>::$alias_target as $crate::logic::Bool>::VALUE)*
+                };
+            )*
+
+            { $type:ty => $unknown:tt } => {
+                compile_error!(concat!("feature ", stringify!($unknown), " is not available on this architecture"))
             }
         }
 
+        // Some features are detected at run time but aren't valid `target_feature`s, and some
+        // feature names have been deprecated by `std`.
+        #[allow(deprecated, unexpected_cfgs)]
         mod detect {
+            #[allow(unused_macros)]
             macro_rules! implement_detector {
                 {
-                    [nightly], $impl_feature_lit:tt, $impl_ident:ident
+                    [nightly($gate:ident)], $impl_feature_lit:tt, $impl_ident:ident
+                } => {
+                    // If the build script found the nightly gate, detect the feature
+                    #[cfg($gate)]
+                    #[inline(always)]
+                    pub(crate) fn $impl_ident() -> bool {
+                        #[cfg(target_feature = $impl_feature_lit)]
+                        {
+                            true
+                        }
+                        #[cfg(not(target_feature = $impl_feature_lit))]
+                        {
+                            #[cfg(feature = "std")]
+                            {
+                                $detect_macro!($impl_feature_lit)
+                            }
+                            #[cfg(not(feature = "std"))]
+                            {
+                                features!(@fallback $fallback_macro $impl_feature_lit)
+                            }
+                        }
+                    }
+
+                    // Otherwise, we can only use the fallback
+                    #[cfg(not($gate))]
+                    #[inline(always)]
+                    pub(crate) fn $impl_ident() -> bool {
+                        features!(@fallback $fallback_macro $impl_feature_lit)
+                    }
+                };
+
+                {
+                    [since $impl_since:tt, renamed($impl_old_lit:tt, since $impl_old_since:tt)], $impl_feature_lit:tt, $impl_ident:ident
                 } => {
                     // If supported, detect the feature
-                    #[cfg(feature = "nightly")]
-                    #[rustversion::nightly]
+                    #[rustversion::since $impl_since]
                     #[inline(always)]
                     pub(crate) fn $impl_ident() -> bool {
                         #[cfg(target_feature = $impl_feature_lit)]
@@ -287,24 +931,47 @@ macro_rules! features {
                             }
                             #[cfg(not(feature = "std"))]
                             {
-                                false
+                                features!(@fallback $fallback_macro $impl_feature_lit)
+                            }
+                        }
+                    }
+
+                    // Older versions detect the feature by its previous name
+                    #[rustversion::all(since $impl_old_since, before $impl_since)]
+                    #[inline(always)]
+                    pub(crate) fn $impl_ident() -> bool {
+                        #[cfg(target_feature = $impl_old_lit)]
+                        {
+                            true
+                        }
+                        #[cfg(not(target_feature = $impl_old_lit))]
+                        {
+                            #[cfg(feature = "std")]
+                            {
+                                $detect_macro!($impl_old_lit)
+                            }
+                            #[cfg(not(feature = "std"))]
+                            {
+                                features!(@fallback $fallback_macro $impl_feature_lit)
                             }
                         }
                     }
 
-                    // If not supported, we don't detect it
-                    #[cfg(feature = "nightly")]
-                    #[rustversion::not(nightly)]
+                    // If not supported, we can only use the fallback
+                    #[rustversion::before $impl_old_since]
                     #[inline(always)]
                     pub(crate) fn $impl_ident() -> bool {
-                        false
+                        features!(@fallback $fallback_macro $impl_feature_lit)
                     }
+                };
 
-                    // If not nightly, we also don't detect it
-                    #[cfg(not(feature = "nightly"))]
+                {
+                    [fallback], $impl_feature_lit:tt, $impl_ident:ident
+                } => {
+                    // The detection macro doesn't support this feature
                     #[inline(always)]
                     pub(crate) fn $impl_ident() -> bool {
-                        false
+                        features!(@fallback $fallback_macro $impl_feature_lit)
                     }
                 };
 
@@ -327,16 +994,16 @@ macro_rules! features {
                             }
                             #[cfg(not(feature = "std"))]
                             {
-                                false
+                                features!(@fallback $fallback_macro $impl_feature_lit)
                             }
                         }
                     }
 
-                    // If not supported, we don't detect it
+                    // If not supported, we can only use the fallback
                     #[rustversion::not($dollar($impl_attr)*)]
                     #[inline(always)]
                     pub(crate) fn $impl_ident() -> bool {
-                        false
+                        features!(@fallback $fallback_macro $impl_feature_lit)
                     }
                 }
             }
@@ -402,6 +1069,11 @@ macro_rules! features {
 /// }
 ///
 /// ```
+///
+/// Naming a feature that isn't available on the target architecture is a compile error:
+/// ```compile_fail
+/// fn foo(_: arch_types::impl_features!("not-a-feature")) {}
+/// ```
 #[macro_export]
 macro_rules! impl_features {
     { $($feature:tt),* } => {
@@ -467,6 +1139,12 @@ macro_rules! has_features {
 /// # }
 /// ```
 ///
+/// A type with no features is available on every architecture, even those without any
+/// supported features:
+/// ```
+/// arch_types::new_features_type! { Baseline => }
+/// ```
+///
 /// Optionally, the type can be documented:
 /// ```
 /// #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
@@ -480,329 +1158,63 @@ macro_rules! new_features_type {
     { $(#[doc = $docs:literal])* $vis:vis $name:ident => $($feature:tt),* } => { $crate::new_features_type_internal!{ [$($docs)*] $vis $name => [$($feature)*] => [] } }
 }
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-features! {
-    @detect_macro is_x86_feature_detected
-
-    @feature aes
-    @detect "aes"
-    @version #[since(1.33)] "1.33"
-
-    @feature pclmulqdq
-    @detect "pclmulqdq"
-    @version #[since(1.33)] "1.33"
-
-    @feature rdrand
-    @detect "rdrand"
-    @version #[since(1.33)] "1.33"
-
-    @feature rdseed
-    @detect "rdseed"
-    @version #[since(1.33)] "1.33"
-
-    @feature tsc
-    @detect "tsc"
-    @version #[since(1.33)] "1.33"
-
-    @feature mmx
-    @detect "mmx"
-    @version #[since(1.33)] "1.33"
-
-    @feature sse
-    @detect "sse"
-    @version #[since(1.33)] "1.33"
-
-    @feature sse2
-    @detect "sse2"
-    @version #[since(1.33)] "1.33"
-
-    @feature sse3
-    @detect "sse3"
-    @version #[since(1.33)] "1.33"
-
-    @feature ssse3
-    @detect "ssse3"
-    @version #[since(1.33)] "1.33"
-
-    @feature sse41
-    @detect "sse4.1"
-    @version #[since(1.33)] "1.33"
-
-    @feature sse42
-    @detect "sse4.2"
-    @version #[since(1.33)] "1.33"
-
-    @feature sse4a
-    @detect "sse4a"
-    @version #[since(1.33)] "1.33"
-
-    @feature sha
-    @detect "sha"
-    @version #[since(1.33)] "1.33"
-
-    @feature avx
-    @detect "avx"
-    @version #[since(1.33)] "1.33"
-
-    @feature avx2
-    @detect "avx2"
-    @version #[since(1.33)] "1.33"
-
-    @feature avx512f
-    @detect "avx512f"
-    @version #[since(1.33)] "1.33"
-
-    @feature avx512cd
-    @detect "avx512cd"
-    @version #[since(1.33)] "1.33"
-
-    @feature avx512er
-    @detect "avx512er"
-    @version #[since(1.33)] "1.33"
-
-    @feature avx512pf
-    @detect "avx512pf"
-    @version #[since(1.33)] "1.33"
-
-    @feature avx512bw
-    @detect "avx512bw"
-    @version #[since(1.33)] "1.33"
-
-    @feature avx512dq
-    @detect "avx512dq"
-    @version #[since(1.33)] "1.33"
-
-    @feature avx512vl
-    @detect "avx512vl"
-    @version #[since(1.33)] "1.33"
-
-    @feature avx512ifma
-    @detect "avx512ifma"
-    @version #[since(1.33)] "1.33"
-
-    @feature avx512vbmi
-    @detect "avx512vbmi"
-    @version #[since(1.33)] "1.33"
-
-    @feature avx512vpopcntdq
-    @detect "avx512vpopcntdq"
-    @version #[since(1.33)] "1.33"
-
-    @feature avx512vbmi2
-    @detect "avx512vbmi2"
-    @version #[since(1.43.1)] "1.43.1"
-
-    @feature avx512gfni
-    @detect "avx512gfni"
-    @version #[since(1.43.1)] "1.43.1"
-
-    @feature avx512vaes
-    @detect "avx512vaes"
-    @version #[since(1.43.1)] "1.43.1"
-
-    @feature avx512vpclmulqdq
-    @detect "avx512vpclmulqdq"
-    @version #[since(1.43.1)] "1.43.1"
-
-    @feature avx512vnni
-    @detect "avx512vnni"
-    @version #[since(1.43.1)] "1.43.1"
-
-    @feature avx512bitalg
-    @detect "avx512bitalg"
-    @version #[since(1.43.1)] "1.43.1"
-
-    @feature avx512bf16
-    @detect "avx512bf16"
-    @version #[since(1.43.1)] "1.43.1"
-
-    @feature avx512vp2intersect
-    @detect "avx512vp2intersect"
-    @version #[since(1.43.1)] "1.43.1"
-
-    @feature f16c
-    @detect "f16c"
-    @version #[since(1.38)] "1.38"
-
-    @feature fma
-    @detect "fma"
-    @version #[since(1.33)] "1.33"
-
-    @feature bmi1
-    @detect "bmi1"
-    @version #[since(1.33)] "1.33"
-
-    @feature bmi2
-    @detect "bmi2"
-    @version #[since(1.33)] "1.33"
-
-    @feature abm
-    @detect "abm"
-    @version #[since(1.33)] "1.33"
-
-    @feature lzcnt
-    @detect "lzcnt"
-    @version #[since(1.33)] "1.33"
-
-    @feature tbm
-    @detect "tbm"
-    @version #[since(1.33)] "1.33"
-
-    @feature popcnt
-    @detect "popcnt"
-    @version #[since(1.33)] "1.33"
-
-    @feature fxsr
-    @detect "fxsr"
-    @version #[since(1.33)] "1.33"
-
-    @feature xsave
-    @detect "xsave"
-    @version #[since(1.33)] "1.33"
-
-    @feature xsaveopt
-    @detect "xsaveopt"
-    @version #[since(1.33)] "1.33"
-
-    @feature xsaves
-    @detect "xsaves"
-    @version #[since(1.33)] "1.33"
-
-    @feature xsavec
-    @detect "xsavec"
-    @version #[since(1.33)] "1.33"
-
-    @feature cmpxchg16b
-    @detect "cmpxchg16b"
-    @version #[since(1.33)] "1.33"
-
-    @feature adx
-    @detect "adx"
-    @version #[since(1.33)] "1.33"
-
-    @feature rtm
-    @detect "rtm"
-    @version #[since(1.38)] "1.38"
+// Like `new_features_type`, for use within this crate.  `new_features_type` refers to the
+// macro-expanded `new_features_type_internal` by absolute path, which isn't allowed in the crate
+// that defines it, so features are specified by their identifiers instead.
+#[allow(unused_macros)]
+macro_rules! features_type {
+    { $(#[doc = $docs:literal])* $vis:vis $name:ident => $($feature:ident),* } => {
+        new_features_type_internal! { [$($docs)*] $vis $name => [] => [$($feature)*] }
+    };
 }
 
-#[cfg(all(target_arch = "arm"))]
-features! {
-    @detect_macro is_arm_feature_detected
-
-    @feature neon
-    @detect "neon"
-    @version #[nightly] "nightly"
-
-    @feature pmull
-    @detect "pmull"
-    @version #[nightly] "nightly"
-
-    @feature crc
-    @detect "crc"
-    @version #[nightly] "nightly"
-
-    @feature crypto
-    @detect "crypto"
-    @version #[nightly] "nightly"
+// Used as the detection macro for architectures without run-time feature detection
+#[allow(unused_macros)]
+macro_rules! no_runtime_detection {
+    ($feature:tt) => {
+        false
+    };
 }
 
-#[cfg(all(target_arch = "aarch64"))]
-features! {
-    @detect_macro is_aarch64_feature_detected
-
-    @feature neon
-    @detect "neon"
-    @version #[nightly] "nightly"
-
-    @feature pmull
-    @detect "pmull"
-    @version #[nightly] "nightly"
-
-    @feature fp
-    @detect "fp"
-    @version #[nightly] "nightly"
-
-    @feature fp16
-    @detect "fp16"
-    @version #[nightly] "nightly"
-
-    @feature sve
-    @detect "sve"
-    @version #[nightly] "nightly"
-
-    @feature crc
-    @detect "crc"
-    @version #[nightly] "nightly"
-
-    @feature crypto
-    @detect "crypto"
-    @version #[nightly] "nightly"
-
-    @feature lse
-    @detect "lse"
-    @version #[nightly] "nightly"
-
-    @feature rdm
-    @detect "rdm"
-    @version #[nightly] "nightly"
-
-    @feature rcpc
-    @detect "rcpc"
-    @version #[nightly] "nightly"
-
-    @feature dotprod
-    @detect "dotprod"
-    @version #[nightly] "nightly"
+// `is_aarch64_feature_detected` is only exported from `std::arch` on stable Rust
+#[cfg(all(target_arch = "aarch64", feature = "std"))]
+macro_rules! is_aarch64_feature_detected {
+    ($feature:tt) => {
+        std::arch::is_aarch64_feature_detected!($feature)
+    };
 }
 
-#[cfg(all(target_arch = "mips"))]
-features! {
-    @detect_macro is_mips_feature_detected
-
-    @feature msa
-    @detect "msa"
-    @version #[nightly] "nightly"
-}
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[macro_use]
+mod hwprobe;
 
-#[cfg(all(target_arch = "mips64"))]
-features! {
-    @detect_macro is_mips64_feature_detected
+#[cfg(any(
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "aarch64"
+))]
+#[macro_use]
+mod auxv;
 
-    @feature msa
-    @detect "msa"
-    @version #[nightly] "nightly"
-}
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[macro_use]
+pub mod x86;
 
-#[cfg(all(target_arch = "powerpc"))]
-features! {
-    @detect_macro is_powerpc_feature_detected
+#[macro_use]
+pub mod tables;
 
-    @feature altivec
-    @detect "altivec"
-    @version #[nightly] "nightly"
+#[cfg(target_arch = "aarch64")]
+pub mod aarch64;
 
-    @feature vsx
-    @detect "vsx"
-    @version #[nightly] "nightly"
+pub mod simd;
 
-    @feature power8
-    @detect "power8"
-    @version #[nightly] "nightly"
-}
+pub mod crc;
 
-#[cfg(all(target_arch = "powerpc64"))]
-features! {
-    @detect_macro is_powerpc64_feature_detected
+pub mod clmul;
 
-    @feature altivec
-    @detect "altivec"
-    @version #[nightly] "nightly"
+pub mod counter;
 
-    @feature vsx
-    @detect "vsx"
-    @version #[nightly] "nightly"
+#[cfg(feature = "alloc")]
+pub mod aligned;
 
-    @feature power8
-    @detect "power8"
-    @version #[nightly] "nightly"
-}
+pub use tables::native::*;
@@ -0,0 +1,276 @@
+//! Portable SIMD vectors.
+//!
+//! Each token type in this module implements [`Simd`], providing vector types that are safe to use
+//! because the token proves the required CPU features are supported.  Kernels can be written once,
+//! generic over [`Simd`], and instantiated with the best token detected at run time:
+//!
+//! ```
+//! use arch_types::simd::{F32Vec, Scalar, Simd};
+//! use arch_types::Features;
+//!
+//! fn sum<S: Simd>(simd: S, values: &[f32]) -> f32 {
+//!     simd.vectorize(|| {
+//!         let mut chunks = values.chunks_exact(S::F32::LANES);
+//!         let mut sum = S::F32::splat(simd, 0.);
+//!         for chunk in &mut chunks {
+//!             sum = sum + S::F32::from_slice(simd, chunk);
+//!         }
+//!         sum.reduce_sum() + chunks.remainder().iter().sum::<f32>()
+//!     })
+//! }
+//!
+//! let values = [1.; 100];
+//! # #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+//! # {
+//! if let Some(simd) = arch_types::simd::Avx2Fma::new() {
+//!     assert_eq!(sum(simd, &values), 100.);
+//! }
+//! # }
+//! assert_eq!(sum(Scalar::new().unwrap(), &values), 100.);
+//! ```
+//!
+//! The token types are:
+//!   * [`Scalar`] - available on every architecture.
+//!   * `Sse2`, `Avx2Fma`, and `Avx512f` - available on `x86` and `x86_64`.  `Avx512f` requires
+//!     Rust 1.89.
+//!   * `Neon` - available on `aarch64`.  Requires Rust 1.61.
+//!
+//! [`Simd`]: trait.Simd.html
+//! [`Scalar`]: struct.Scalar.html
+
+use crate::Features;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// A token proving support for a set of SIMD vector types.
+pub trait Simd: Features {
+    /// A vector of `f32`.
+    type F32: F32Vec<Simd = Self>;
+
+    /// Call `f` with the features proven by this token enabled.
+    ///
+    /// Vector operations are only inlined into functions that enable the required features.
+    /// Calling them within `f` allows them to be inlined, rather than resulting in a function call
+    /// for every operation.
+    fn vectorize<F, R>(self, f: F) -> R
+    where
+        F: FnOnce() -> R;
+}
+
+/// A vector of `f32`.
+///
+/// The result of `min` and `max` is unspecified if either lane is NaN.
+pub trait F32Vec:
+    Copy
+    + core::fmt::Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    /// The token proving support for this vector.
+    type Simd: Simd;
+
+    /// The number of lanes in the vector.
+    const LANES: usize;
+
+    /// Create a vector with every lane set to `value`.
+    fn splat(simd: Self::Simd, value: f32) -> Self;
+
+    /// Load a vector from the first `LANES` elements of `slice`.
+    ///
+    /// # Panics
+    /// Panics if the slice has fewer than `LANES` elements.
+    fn from_slice(simd: Self::Simd, slice: &[f32]) -> Self;
+
+    /// Store the vector to the first `LANES` elements of `slice`.
+    ///
+    /// # Panics
+    /// Panics if the slice has fewer than `LANES` elements.
+    fn copy_to_slice(self, slice: &mut [f32]);
+
+    /// Computes `self * a + b`, fused if supported by the token.
+    fn mul_add(self, a: Self, b: Self) -> Self;
+
+    /// Returns the lanewise minimum.
+    fn min(self, other: Self) -> Self;
+
+    /// Returns the lanewise maximum.
+    fn max(self, other: Self) -> Self;
+
+    /// Returns the sum of the lanes.
+    fn reduce_sum(self) -> f32;
+
+    /// Returns the minimum lane.
+    fn reduce_min(self) -> f32;
+
+    /// Returns the maximum lane.
+    fn reduce_max(self) -> f32;
+}
+
+// Implement the arithmetic operators and `Debug` for a vector containing a register and a token
+#[allow(unused_macros)]
+macro_rules! vector_ops {
+    {
+        $(#[$attr:meta])*
+        $vector:ident: $lanes:literal, $add:path, $sub:path, $mul:path, $div:path
+    } => {
+        vector_ops! { @op [$(#[$attr])*] $vector, Add, add, $add }
+        vector_ops! { @op [$(#[$attr])*] $vector, Sub, sub, $sub }
+        vector_ops! { @op [$(#[$attr])*] $vector, Mul, mul, $mul }
+        vector_ops! { @op [$(#[$attr])*] $vector, Div, div, $div }
+
+        $(#[$attr])*
+        impl core::fmt::Debug for $vector {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                let mut lanes = [0f32; $lanes];
+                self.copy_to_slice(&mut lanes);
+                f.debug_tuple(stringify!($vector)).field(&lanes).finish()
+            }
+        }
+    };
+
+    { @op [$(#[$attr:meta])*] $vector:ident, $trait:ident, $fn:ident, $intrinsic:path } => {
+        $(#[$attr])*
+        impl core::ops::$trait for $vector {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $fn(self, rhs: Self) -> Self {
+                // Safety: the token proves the features are supported
+                Self(unsafe { $intrinsic(self.0, rhs.0) }, self.1)
+            }
+        }
+    };
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::x86::*;
+
+#[cfg(target_arch = "aarch64")]
+mod aarch64;
+#[cfg(target_arch = "aarch64")]
+pub use self::aarch64::*;
+
+new_features_type_internal! {
+    [" A token for scalar operations, available on every architecture."]
+    pub Scalar => [] => []
+}
+
+impl Simd for Scalar {
+    type F32 = F32x1;
+
+    #[inline(always)]
+    fn vectorize<F, R>(self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        f()
+    }
+}
+
+/// A single `f32`.
+#[derive(Copy, Clone)]
+pub struct F32x1(f32, Scalar);
+
+impl F32Vec for F32x1 {
+    type Simd = Scalar;
+
+    const LANES: usize = 1;
+
+    #[inline(always)]
+    fn splat(simd: Scalar, value: f32) -> Self {
+        Self(value, simd)
+    }
+
+    #[inline(always)]
+    fn from_slice(simd: Scalar, slice: &[f32]) -> Self {
+        Self(slice[0], simd)
+    }
+
+    #[inline(always)]
+    fn copy_to_slice(self, slice: &mut [f32]) {
+        slice[0] = self.0;
+    }
+
+    #[inline(always)]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+
+    #[inline(always)]
+    fn min(self, other: Self) -> Self {
+        if other.0 < self.0 {
+            other
+        } else {
+            self
+        }
+    }
+
+    #[inline(always)]
+    fn max(self, other: Self) -> Self {
+        if other.0 > self.0 {
+            other
+        } else {
+            self
+        }
+    }
+
+    #[inline(always)]
+    fn reduce_sum(self) -> f32 {
+        self.0
+    }
+
+    #[inline(always)]
+    fn reduce_min(self) -> f32 {
+        self.0
+    }
+
+    #[inline(always)]
+    fn reduce_max(self) -> f32 {
+        self.0
+    }
+}
+
+impl Add for F32x1 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0, self.1)
+    }
+}
+
+impl Sub for F32x1 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0, self.1)
+    }
+}
+
+impl Mul for F32x1 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self {
+        Self(self.0 * rhs.0, self.1)
+    }
+}
+
+impl Div for F32x1 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(self, rhs: Self) -> Self {
+        Self(self.0 / rhs.0, self.1)
+    }
+}
+
+impl core::fmt::Debug for F32x1 {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_tuple("F32x1").field(&self.0).finish()
+    }
+}
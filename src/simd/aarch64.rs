@@ -0,0 +1,93 @@
+use super::{F32Vec, Simd};
+
+use core::arch::aarch64::*;
+
+#[rustversion::since(1.61)]
+new_features_type_internal! {
+    [" A token for NEON vectors.  Requires Rust 1.61."]
+    pub Neon => [] => [neon]
+}
+
+#[rustversion::since(1.61)]
+impl Simd for Neon {
+    type F32 = F32x4;
+
+    #[inline(always)]
+    fn vectorize<F, R>(self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        #[target_feature(enable = "neon")]
+        #[inline]
+        unsafe fn vectorize<F: FnOnce() -> R, R>(f: F) -> R {
+            f()
+        }
+
+        // Safety: the token proves the features are supported
+        unsafe { vectorize(f) }
+    }
+}
+
+/// A vector of four `f32`, using NEON.  Requires Rust 1.61.
+#[rustversion::since(1.61)]
+#[derive(Copy, Clone)]
+pub struct F32x4(float32x4_t, Neon);
+
+#[rustversion::since(1.61)]
+impl F32Vec for F32x4 {
+    type Simd = Neon;
+
+    const LANES: usize = 4;
+
+    #[inline(always)]
+    fn splat(simd: Neon, value: f32) -> Self {
+        Self(unsafe { vdupq_n_f32(value) }, simd)
+    }
+
+    #[inline(always)]
+    fn from_slice(simd: Neon, slice: &[f32]) -> Self {
+        assert!(slice.len() >= Self::LANES);
+        Self(unsafe { vld1q_f32(slice.as_ptr()) }, simd)
+    }
+
+    #[inline(always)]
+    fn copy_to_slice(self, slice: &mut [f32]) {
+        assert!(slice.len() >= Self::LANES);
+        unsafe { vst1q_f32(slice.as_mut_ptr(), self.0) }
+    }
+
+    #[inline(always)]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Self(unsafe { vfmaq_f32(b.0, self.0, a.0) }, self.1)
+    }
+
+    #[inline(always)]
+    fn min(self, other: Self) -> Self {
+        Self(unsafe { vminq_f32(self.0, other.0) }, self.1)
+    }
+
+    #[inline(always)]
+    fn max(self, other: Self) -> Self {
+        Self(unsafe { vmaxq_f32(self.0, other.0) }, self.1)
+    }
+
+    #[inline(always)]
+    fn reduce_sum(self) -> f32 {
+        unsafe { vaddvq_f32(self.0) }
+    }
+
+    #[inline(always)]
+    fn reduce_min(self) -> f32 {
+        unsafe { vminvq_f32(self.0) }
+    }
+
+    #[inline(always)]
+    fn reduce_max(self) -> f32 {
+        unsafe { vmaxvq_f32(self.0) }
+    }
+}
+
+vector_ops! {
+    #[rustversion::since(1.61)]
+    F32x4: 4, vaddq_f32, vsubq_f32, vmulq_f32, vdivq_f32
+}
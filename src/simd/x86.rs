@@ -0,0 +1,298 @@
+use super::{F32Vec, Simd};
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+new_features_type_internal! {
+    [" A token for SSE2 vectors."]
+    pub Sse2 => [] => [sse2]
+}
+
+new_features_type_internal! {
+    [" A token for AVX2 and FMA vectors."]
+    pub Avx2Fma => [] => [avx2 fma]
+}
+
+// Horizontal operations on 128-bit vectors
+macro_rules! reduce_m128 {
+    { $name:ident, $op_ps:ident, $op_ss:ident } => {
+        #[inline(always)]
+        unsafe fn $name(v: __m128) -> f32 {
+            let v = $op_ps(v, _mm_movehl_ps(v, v));
+            let v = $op_ss(v, _mm_shuffle_ps(v, v, 0b01));
+            _mm_cvtss_f32(v)
+        }
+    };
+}
+
+reduce_m128! { reduce_add_m128, _mm_add_ps, _mm_add_ss }
+reduce_m128! { reduce_min_m128, _mm_min_ps, _mm_min_ss }
+reduce_m128! { reduce_max_m128, _mm_max_ps, _mm_max_ss }
+
+impl Simd for Sse2 {
+    type F32 = F32x4;
+
+    #[inline(always)]
+    fn vectorize<F, R>(self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        #[target_feature(enable = "sse2")]
+        #[inline]
+        unsafe fn vectorize<F: FnOnce() -> R, R>(f: F) -> R {
+            f()
+        }
+
+        // Safety: the token proves the features are supported
+        unsafe { vectorize(f) }
+    }
+}
+
+/// A vector of four `f32`, using SSE2.
+#[derive(Copy, Clone)]
+pub struct F32x4(__m128, Sse2);
+
+impl F32Vec for F32x4 {
+    type Simd = Sse2;
+
+    const LANES: usize = 4;
+
+    #[inline(always)]
+    fn splat(simd: Sse2, value: f32) -> Self {
+        Self(unsafe { _mm_set1_ps(value) }, simd)
+    }
+
+    #[inline(always)]
+    fn from_slice(simd: Sse2, slice: &[f32]) -> Self {
+        assert!(slice.len() >= Self::LANES);
+        Self(unsafe { _mm_loadu_ps(slice.as_ptr()) }, simd)
+    }
+
+    #[inline(always)]
+    fn copy_to_slice(self, slice: &mut [f32]) {
+        assert!(slice.len() >= Self::LANES);
+        unsafe { _mm_storeu_ps(slice.as_mut_ptr(), self.0) }
+    }
+
+    #[inline(always)]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+
+    #[inline(always)]
+    fn min(self, other: Self) -> Self {
+        Self(unsafe { _mm_min_ps(self.0, other.0) }, self.1)
+    }
+
+    #[inline(always)]
+    fn max(self, other: Self) -> Self {
+        Self(unsafe { _mm_max_ps(self.0, other.0) }, self.1)
+    }
+
+    #[inline(always)]
+    fn reduce_sum(self) -> f32 {
+        unsafe { reduce_add_m128(self.0) }
+    }
+
+    #[inline(always)]
+    fn reduce_min(self) -> f32 {
+        unsafe { reduce_min_m128(self.0) }
+    }
+
+    #[inline(always)]
+    fn reduce_max(self) -> f32 {
+        unsafe { reduce_max_m128(self.0) }
+    }
+}
+
+vector_ops! { F32x4: 4, _mm_add_ps, _mm_sub_ps, _mm_mul_ps, _mm_div_ps }
+
+impl Simd for Avx2Fma {
+    type F32 = F32x8;
+
+    #[inline(always)]
+    fn vectorize<F, R>(self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        #[target_feature(enable = "avx2,fma")]
+        #[inline]
+        unsafe fn vectorize<F: FnOnce() -> R, R>(f: F) -> R {
+            f()
+        }
+
+        // Safety: the token proves the features are supported
+        unsafe { vectorize(f) }
+    }
+}
+
+/// A vector of eight `f32`, using AVX2 and FMA.
+#[derive(Copy, Clone)]
+pub struct F32x8(__m256, Avx2Fma);
+
+impl F32x8 {
+    // Split the vector into its low and high halves
+    #[inline(always)]
+    unsafe fn halves(self) -> (__m128, __m128) {
+        (
+            _mm256_castps256_ps128(self.0),
+            _mm256_extractf128_ps(self.0, 1),
+        )
+    }
+}
+
+impl F32Vec for F32x8 {
+    type Simd = Avx2Fma;
+
+    const LANES: usize = 8;
+
+    #[inline(always)]
+    fn splat(simd: Avx2Fma, value: f32) -> Self {
+        Self(unsafe { _mm256_set1_ps(value) }, simd)
+    }
+
+    #[inline(always)]
+    fn from_slice(simd: Avx2Fma, slice: &[f32]) -> Self {
+        assert!(slice.len() >= Self::LANES);
+        Self(unsafe { _mm256_loadu_ps(slice.as_ptr()) }, simd)
+    }
+
+    #[inline(always)]
+    fn copy_to_slice(self, slice: &mut [f32]) {
+        assert!(slice.len() >= Self::LANES);
+        unsafe { _mm256_storeu_ps(slice.as_mut_ptr(), self.0) }
+    }
+
+    #[inline(always)]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Self(unsafe { _mm256_fmadd_ps(self.0, a.0, b.0) }, self.1)
+    }
+
+    #[inline(always)]
+    fn min(self, other: Self) -> Self {
+        Self(unsafe { _mm256_min_ps(self.0, other.0) }, self.1)
+    }
+
+    #[inline(always)]
+    fn max(self, other: Self) -> Self {
+        Self(unsafe { _mm256_max_ps(self.0, other.0) }, self.1)
+    }
+
+    #[inline(always)]
+    fn reduce_sum(self) -> f32 {
+        unsafe {
+            let (low, high) = self.halves();
+            reduce_add_m128(_mm_add_ps(low, high))
+        }
+    }
+
+    #[inline(always)]
+    fn reduce_min(self) -> f32 {
+        unsafe {
+            let (low, high) = self.halves();
+            reduce_min_m128(_mm_min_ps(low, high))
+        }
+    }
+
+    #[inline(always)]
+    fn reduce_max(self) -> f32 {
+        unsafe {
+            let (low, high) = self.halves();
+            reduce_max_m128(_mm_max_ps(low, high))
+        }
+    }
+}
+
+vector_ops! { F32x8: 8, _mm256_add_ps, _mm256_sub_ps, _mm256_mul_ps, _mm256_div_ps }
+
+#[rustversion::since(1.89)]
+new_features_type_internal! {
+    [" A token for AVX-512F vectors.  Requires Rust 1.89."]
+    pub Avx512f => [] => [avx512f]
+}
+
+#[rustversion::since(1.89)]
+impl Simd for Avx512f {
+    type F32 = F32x16;
+
+    #[inline(always)]
+    fn vectorize<F, R>(self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        #[target_feature(enable = "avx512f")]
+        #[inline]
+        unsafe fn vectorize<F: FnOnce() -> R, R>(f: F) -> R {
+            f()
+        }
+
+        // Safety: the token proves the features are supported
+        unsafe { vectorize(f) }
+    }
+}
+
+/// A vector of sixteen `f32`, using AVX-512F.  Requires Rust 1.89.
+#[rustversion::since(1.89)]
+#[derive(Copy, Clone)]
+pub struct F32x16(__m512, Avx512f);
+
+#[rustversion::since(1.89)]
+impl F32Vec for F32x16 {
+    type Simd = Avx512f;
+
+    const LANES: usize = 16;
+
+    #[inline(always)]
+    fn splat(simd: Avx512f, value: f32) -> Self {
+        Self(unsafe { _mm512_set1_ps(value) }, simd)
+    }
+
+    #[inline(always)]
+    fn from_slice(simd: Avx512f, slice: &[f32]) -> Self {
+        assert!(slice.len() >= Self::LANES);
+        Self(unsafe { _mm512_loadu_ps(slice.as_ptr()) }, simd)
+    }
+
+    #[inline(always)]
+    fn copy_to_slice(self, slice: &mut [f32]) {
+        assert!(slice.len() >= Self::LANES);
+        unsafe { _mm512_storeu_ps(slice.as_mut_ptr(), self.0) }
+    }
+
+    #[inline(always)]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Self(unsafe { _mm512_fmadd_ps(self.0, a.0, b.0) }, self.1)
+    }
+
+    #[inline(always)]
+    fn min(self, other: Self) -> Self {
+        Self(unsafe { _mm512_min_ps(self.0, other.0) }, self.1)
+    }
+
+    #[inline(always)]
+    fn max(self, other: Self) -> Self {
+        Self(unsafe { _mm512_max_ps(self.0, other.0) }, self.1)
+    }
+
+    #[inline(always)]
+    fn reduce_sum(self) -> f32 {
+        unsafe { _mm512_reduce_add_ps(self.0) }
+    }
+
+    #[inline(always)]
+    fn reduce_min(self) -> f32 {
+        unsafe { _mm512_reduce_min_ps(self.0) }
+    }
+
+    #[inline(always)]
+    fn reduce_max(self) -> f32 {
+        unsafe { _mm512_reduce_max_ps(self.0) }
+    }
+}
+
+vector_ops! {
+    #[rustversion::since(1.89)]
+    F32x16: 16, _mm512_add_ps, _mm512_sub_ps, _mm512_mul_ps, _mm512_div_ps
+}
@@ -0,0 +1,2049 @@
+//! Feature tables for every supported architecture.
+//!
+//! Unlike the rest of this crate, these tables are available regardless of the target
+//! architecture, which is useful for inspecting features of other architectures.  Types proving
+//! support for features, such as those created by [`new_features_type`], are only available for
+//! the architecture being compiled for.
+//!
+//! [`new_features_type`]: ../macro.new_features_type.html
+
+features! {
+    /// Features for the `x86` and `x86_64` architectures.
+    @table x86
+    @native #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    @detect_macro is_x86_feature_detected
+    @enable_macro x86_request_permission
+    // Only eight vector registers are available on 32-bit `x86`
+    @vector [
+        avx512f avx512cd avx512er avx512pf avx512bw avx512dq avx512vl avx512ifma avx512vbmi
+        avx512vpopcntdq avx512vbmi2 avx512vnni avx512bitalg avx512bf16 avx512vp2intersect
+        avx512fp16
+    ] => [64, 8 + 24 * (cfg!(target_arch = "x86_64") as usize), true]
+    @vector [
+        avx avx2 fma f16c vaes vpclmulqdq avxvnni avxifma avxneconvert avxvnniint8 avxvnniint16
+        sha512 sm3 sm4
+    ] => [32, 8 + 8 * (cfg!(target_arch = "x86_64") as usize), false]
+    @vector [
+        sse sse2 sse3 ssse3 sse41 sse42 sse4a aes pclmulqdq sha gfni
+    ] => [16, 8 + 8 * (cfg!(target_arch = "x86_64") as usize), false]
+
+    @alias avx512gfni "avx512gfni" => [gfni]
+    @deprecated "the `avx512gfni` feature has been renamed to `gfni`"
+
+    @alias avx512vaes "avx512vaes" => [vaes]
+    @deprecated "the `avx512vaes` feature has been renamed to `vaes`"
+
+    @alias avx512vpclmulqdq "avx512vpclmulqdq" => [vpclmulqdq]
+    @deprecated "the `avx512vpclmulqdq` feature has been renamed to `vpclmulqdq`"
+
+    @feature aes
+    @detect "aes"
+    @version #[since(1.33)] "1.33"
+    @description "AES-NI instructions"
+    @rustc "aes"
+    @llvm "aes"
+    @cpuinfo "aes"
+    @implies ["sse2"]
+    @microarch "Intel Westmere"
+
+    @feature pclmulqdq
+    @detect "pclmulqdq"
+    @version #[since(1.33)] "1.33"
+    @description "Carry-less multiplication instructions"
+    @rustc "pclmulqdq"
+    @llvm "pclmul"
+    @cpuinfo "pclmulqdq"
+    @implies ["sse2"]
+    @microarch "Intel Westmere"
+
+    @feature rdrand
+    @detect "rdrand"
+    @version #[since(1.33)] "1.33"
+    @description "RDRAND random number instruction"
+    @rustc "rdrand"
+    @llvm "rdrnd"
+    @cpuinfo "rdrand"
+    @implies []
+    @microarch "Intel Ivy Bridge"
+
+    @feature rdseed
+    @detect "rdseed"
+    @version #[since(1.33)] "1.33"
+    @description "RDSEED random seed instruction"
+    @rustc "rdseed"
+    @llvm "rdseed"
+    @cpuinfo "rdseed"
+    @implies []
+    @microarch "Intel Broadwell"
+
+    @feature tsc
+    @detect "tsc"
+    @version #[since(1.33)] "1.33"
+    @description "Time stamp counter"
+    @rustc none
+    @llvm none
+    @cpuinfo "tsc"
+    @implies []
+    @microarch "Intel P5"
+
+    @feature mmx
+    @detect "mmx"
+    @version #[since(1.33)] "1.33"
+    @description "MMX instructions"
+    @rustc none
+    @llvm "mmx"
+    @cpuinfo "mmx"
+    @implies []
+    @microarch "Intel P5"
+
+    @feature sse
+    @detect "sse"
+    @version #[since(1.33)] "1.33"
+    @description "Streaming SIMD Extensions"
+    @rustc "sse"
+    @llvm "sse"
+    @cpuinfo "sse"
+    @implies []
+    @microarch "Intel P6"
+
+    @feature sse2
+    @detect "sse2"
+    @version #[since(1.33)] "1.33"
+    @description "Streaming SIMD Extensions 2"
+    @rustc "sse2"
+    @llvm "sse2"
+    @cpuinfo "sse2"
+    @implies ["sse"]
+    @microarch "Intel NetBurst"
+
+    @feature sse3
+    @detect "sse3"
+    @version #[since(1.33)] "1.33"
+    @description "Streaming SIMD Extensions 3"
+    @rustc "sse3"
+    @llvm "sse3"
+    @cpuinfo "pni"
+    @implies ["sse2"]
+    @microarch "Intel NetBurst"
+
+    @feature ssse3
+    @detect "ssse3"
+    @version #[since(1.33)] "1.33"
+    @description "Supplemental Streaming SIMD Extensions 3"
+    @rustc "ssse3"
+    @llvm "ssse3"
+    @cpuinfo "ssse3"
+    @implies ["sse3"]
+    @microarch "Intel Core"
+
+    @feature sse41
+    @detect "sse4.1"
+    @version #[since(1.33)] "1.33"
+    @description "Streaming SIMD Extensions 4.1"
+    @rustc "sse4.1"
+    @llvm "sse4.1"
+    @cpuinfo "sse4_1"
+    @implies ["ssse3"]
+    @microarch "Intel Penryn"
+
+    @feature sse42
+    @detect "sse4.2"
+    @version #[since(1.33)] "1.33"
+    @description "Streaming SIMD Extensions 4.2"
+    @rustc "sse4.2"
+    @llvm "sse4.2"
+    @cpuinfo "sse4_2"
+    @implies ["sse4.1"]
+    @microarch "Intel Nehalem"
+
+    @feature sse4a
+    @detect "sse4a"
+    @version #[since(1.33)] "1.33"
+    @description "AMD Streaming SIMD Extensions 4a"
+    @rustc "sse4a"
+    @llvm "sse4a"
+    @cpuinfo "sse4a"
+    @implies ["sse3"]
+    @microarch "AMD K10"
+
+    @feature sha
+    @detect "sha"
+    @version #[since(1.33)] "1.33"
+    @description "SHA-1 and SHA-256 instructions"
+    @rustc "sha"
+    @llvm "sha"
+    @cpuinfo "sha_ni"
+    @implies ["sse2"]
+    @microarch "Intel Goldmont"
+
+    @feature avx
+    @detect "avx"
+    @version #[since(1.33)] "1.33"
+    @description "Advanced Vector Extensions"
+    @rustc "avx"
+    @llvm "avx"
+    @cpuinfo "avx"
+    @implies ["sse4.2"]
+    @microarch "Intel Sandy Bridge"
+
+    @feature avx2
+    @detect "avx2"
+    @version #[since(1.33)] "1.33"
+    @description "Advanced Vector Extensions 2"
+    @rustc "avx2"
+    @llvm "avx2"
+    @cpuinfo "avx2"
+    @implies ["avx"]
+    @microarch "Intel Haswell"
+
+    @feature sha512
+    @detect "sha512"
+    @version #[since(1.89)] "1.89"
+    @description "SHA-512 instructions"
+    @rustc "sha512"
+    @llvm "sha512"
+    @cpuinfo "sha512"
+    @implies ["avx2"]
+    @microarch "Intel Arrow Lake"
+
+    @feature sm3
+    @detect "sm3"
+    @version #[since(1.89)] "1.89"
+    @description "SM3 hash function instructions"
+    @rustc "sm3"
+    @llvm "sm3"
+    @cpuinfo "sm3"
+    @implies ["avx"]
+    @microarch "Intel Arrow Lake"
+
+    @feature sm4
+    @detect "sm4"
+    @version #[since(1.89)] "1.89"
+    @description "SM4 block cipher instructions"
+    @rustc "sm4"
+    @llvm "sm4"
+    @cpuinfo "sm4"
+    @implies ["avx2"]
+    @microarch "Intel Arrow Lake"
+
+    @feature avx512f
+    @detect "avx512f"
+    @version #[since(1.33)] "1.33"
+    @description "AVX-512 Foundation"
+    @rustc "avx512f"
+    @llvm "avx512f"
+    @cpuinfo "avx512f"
+    @implies ["avx2", "fma", "f16c"]
+    @microarch "Intel Knights Landing"
+
+    @feature avx512cd
+    @detect "avx512cd"
+    @version #[since(1.33)] "1.33"
+    @description "AVX-512 Conflict Detection instructions"
+    @rustc "avx512cd"
+    @llvm "avx512cd"
+    @cpuinfo "avx512cd"
+    @implies ["avx512f"]
+    @microarch "Intel Knights Landing"
+
+    @feature avx512er
+    @detect "avx512er"
+    @version #[since(1.33)] "1.33"
+    @description "AVX-512 Exponential and Reciprocal instructions"
+    @rustc none
+    @llvm none
+    @cpuinfo "avx512er"
+    @implies ["avx512f"]
+    @microarch "Intel Knights Landing"
+
+    @feature avx512pf
+    @detect "avx512pf"
+    @version #[since(1.33)] "1.33"
+    @description "AVX-512 Prefetch instructions"
+    @rustc none
+    @llvm none
+    @cpuinfo "avx512pf"
+    @implies ["avx512f"]
+    @microarch "Intel Knights Landing"
+
+    @feature avx512bw
+    @detect "avx512bw"
+    @version #[since(1.33)] "1.33"
+    @description "AVX-512 Byte and Word instructions"
+    @rustc "avx512bw"
+    @llvm "avx512bw"
+    @cpuinfo "avx512bw"
+    @implies ["avx512f"]
+    @microarch "Intel Skylake-SP"
+
+    @feature avx512dq
+    @detect "avx512dq"
+    @version #[since(1.33)] "1.33"
+    @description "AVX-512 Doubleword and Quadword instructions"
+    @rustc "avx512dq"
+    @llvm "avx512dq"
+    @cpuinfo "avx512dq"
+    @implies ["avx512f"]
+    @microarch "Intel Skylake-SP"
+
+    @feature avx512vl
+    @detect "avx512vl"
+    @version #[since(1.33)] "1.33"
+    @description "AVX-512 Vector Length extensions"
+    @rustc "avx512vl"
+    @llvm "avx512vl"
+    @cpuinfo "avx512vl"
+    @implies ["avx512f"]
+    @microarch "Intel Skylake-SP"
+
+    @feature avx512ifma
+    @detect "avx512ifma"
+    @version #[since(1.33)] "1.33"
+    @description "AVX-512 Integer Fused Multiply-Add instructions"
+    @rustc "avx512ifma"
+    @llvm "avx512ifma"
+    @cpuinfo "avx512ifma"
+    @implies ["avx512f"]
+    @microarch "Intel Cannon Lake"
+
+    @feature avx512vbmi
+    @detect "avx512vbmi"
+    @version #[since(1.33)] "1.33"
+    @description "AVX-512 Vector Byte Manipulation instructions"
+    @rustc "avx512vbmi"
+    @llvm "avx512vbmi"
+    @cpuinfo "avx512vbmi"
+    @implies ["avx512bw"]
+    @microarch "Intel Cannon Lake"
+
+    @feature avx512vpopcntdq
+    @detect "avx512vpopcntdq"
+    @version #[since(1.33)] "1.33"
+    @description "AVX-512 Vector Population Count instructions"
+    @rustc "avx512vpopcntdq"
+    @llvm "avx512vpopcntdq"
+    @cpuinfo "avx512_vpopcntdq"
+    @implies ["avx512f"]
+    @microarch "Intel Knights Mill"
+
+    @feature avx512vbmi2
+    @detect "avx512vbmi2"
+    @version #[since(1.43.1)] "1.43.1"
+    @description "AVX-512 Vector Byte Manipulation instructions 2"
+    @rustc "avx512vbmi2"
+    @llvm "avx512vbmi2"
+    @cpuinfo "avx512_vbmi2"
+    @implies ["avx512bw"]
+    @microarch "Intel Ice Lake"
+
+    @feature gfni
+    @detect "gfni"
+    @version #[since(1.67), renamed("avx512gfni", since(1.43.1))] "1.43.1"
+    @description "Galois Field New Instructions"
+    @rustc "gfni"
+    @llvm "gfni"
+    @cpuinfo "gfni"
+    @implies ["sse2"]
+    @microarch "Intel Ice Lake"
+
+    @feature vaes
+    @detect "vaes"
+    @version #[since(1.67), renamed("avx512vaes", since(1.43.1))] "1.43.1"
+    @description "Vector AES instructions"
+    @rustc "vaes"
+    @llvm "vaes"
+    @cpuinfo "vaes"
+    @implies ["avx2", "aes"]
+    @microarch "Intel Ice Lake"
+
+    @feature vpclmulqdq
+    @detect "vpclmulqdq"
+    @version #[since(1.67), renamed("avx512vpclmulqdq", since(1.43.1))] "1.43.1"
+    @description "Vector carry-less multiplication instructions"
+    @rustc "vpclmulqdq"
+    @llvm "vpclmulqdq"
+    @cpuinfo "vpclmulqdq"
+    @implies ["avx", "pclmulqdq"]
+    @microarch "Intel Ice Lake"
+
+    @feature avx512vnni
+    @detect "avx512vnni"
+    @version #[since(1.43.1)] "1.43.1"
+    @description "AVX-512 Vector Neural Network instructions"
+    @rustc "avx512vnni"
+    @llvm "avx512vnni"
+    @cpuinfo "avx512_vnni"
+    @implies ["avx512f"]
+    @microarch "Intel Cascade Lake"
+
+    @feature avx512bitalg
+    @detect "avx512bitalg"
+    @version #[since(1.43.1)] "1.43.1"
+    @description "AVX-512 Bit Algorithms"
+    @rustc "avx512bitalg"
+    @llvm "avx512bitalg"
+    @cpuinfo "avx512_bitalg"
+    @implies ["avx512bw"]
+    @microarch "Intel Ice Lake"
+
+    @feature avx512bf16
+    @detect "avx512bf16"
+    @version #[since(1.43.1)] "1.43.1"
+    @description "AVX-512 BFloat16 instructions"
+    @rustc "avx512bf16"
+    @llvm "avx512bf16"
+    @cpuinfo "avx512_bf16"
+    @implies ["avx512bw"]
+    @microarch "Intel Cooper Lake"
+
+    @feature avx512vp2intersect
+    @detect "avx512vp2intersect"
+    @version #[since(1.43.1)] "1.43.1"
+    @description "AVX-512 Vector Pair Intersection instructions"
+    @rustc "avx512vp2intersect"
+    @llvm "avx512vp2intersect"
+    @cpuinfo "avx512_vp2intersect"
+    @implies ["avx512f"]
+    @microarch "Intel Tiger Lake"
+
+    @feature avx512fp16
+    @detect "avx512fp16"
+    @version #[since(1.89)] "1.89"
+    @description "AVX-512 FP16 instructions"
+    @rustc "avx512fp16"
+    @llvm "avx512fp16"
+    @cpuinfo "avx512_fp16"
+    @implies ["avx512bw"]
+    @microarch "Intel Sapphire Rapids"
+
+    @feature avxvnni
+    @detect "avxvnni"
+    @version #[since(1.89)] "1.89"
+    @description "AVX Vector Neural Network instructions"
+    @rustc "avxvnni"
+    @llvm "avxvnni"
+    @cpuinfo "avx_vnni"
+    @implies ["avx2"]
+    @microarch "Intel Alder Lake"
+
+    @feature avxifma
+    @detect "avxifma"
+    @version #[since(1.89)] "1.89"
+    @description "AVX Integer Fused Multiply-Add instructions"
+    @rustc "avxifma"
+    @llvm "avxifma"
+    @cpuinfo "avx_ifma"
+    @implies ["avx2"]
+    @microarch "Intel Sierra Forest"
+
+    @feature avxneconvert
+    @detect "avxneconvert"
+    @version #[since(1.89)] "1.89"
+    @description "AVX No-Exception Floating-Point conversion instructions"
+    @rustc "avxneconvert"
+    @llvm "avxneconvert"
+    @cpuinfo "avx_ne_convert"
+    @implies ["avx2"]
+    @microarch "Intel Sierra Forest"
+
+    @feature avxvnniint8
+    @detect "avxvnniint8"
+    @version #[since(1.89)] "1.89"
+    @description "AVX VNNI INT8 instructions"
+    @rustc "avxvnniint8"
+    @llvm "avxvnniint8"
+    @cpuinfo "avx_vnni_int8"
+    @implies ["avx2"]
+    @microarch "Intel Sierra Forest"
+
+    @feature avxvnniint16
+    @detect "avxvnniint16"
+    @version #[since(1.89)] "1.89"
+    @description "AVX VNNI INT16 instructions"
+    @rustc "avxvnniint16"
+    @llvm "avxvnniint16"
+    @cpuinfo "avx_vnni_int16"
+    @implies ["avx2"]
+    @microarch "Intel Arrow Lake"
+
+    @feature amx_tile
+    @detect "amx-tile"
+    @version #[nightly(arch_types_amx)] "nightly"
+    @description "Advanced Matrix Extensions tile instructions"
+    @rustc "amx-tile"
+    @llvm "amx-tile"
+    @cpuinfo "amx_tile"
+    @implies []
+    @microarch "Intel Sapphire Rapids"
+
+    @feature amx_int8
+    @detect "amx-int8"
+    @version #[nightly(arch_types_amx)] "nightly"
+    @description "Advanced Matrix Extensions INT8 instructions"
+    @rustc "amx-int8"
+    @llvm "amx-int8"
+    @cpuinfo "amx_int8"
+    @implies ["amx-tile"]
+    @microarch "Intel Sapphire Rapids"
+
+    @feature amx_bf16
+    @detect "amx-bf16"
+    @version #[nightly(arch_types_amx)] "nightly"
+    @description "Advanced Matrix Extensions BFloat16 instructions"
+    @rustc "amx-bf16"
+    @llvm "amx-bf16"
+    @cpuinfo "amx_bf16"
+    @implies ["amx-tile"]
+    @microarch "Intel Sapphire Rapids"
+
+    @feature amx_fp16
+    @detect "amx-fp16"
+    @version #[nightly(arch_types_amx)] "nightly"
+    @description "Advanced Matrix Extensions FP16 instructions"
+    @rustc "amx-fp16"
+    @llvm "amx-fp16"
+    @cpuinfo "amx_fp16"
+    @implies ["amx-tile"]
+    @microarch "Intel Granite Rapids"
+
+    @feature f16c
+    @detect "f16c"
+    @version #[since(1.38)] "1.38"
+    @description "Half-precision floating-point conversion instructions"
+    @rustc "f16c"
+    @llvm "f16c"
+    @cpuinfo "f16c"
+    @implies ["avx"]
+    @microarch "AMD Bulldozer"
+
+    @feature fma
+    @detect "fma"
+    @version #[since(1.33)] "1.33"
+    @description "Three-operand fused multiply-add instructions"
+    @rustc "fma"
+    @llvm "fma"
+    @cpuinfo "fma"
+    @implies ["avx"]
+    @microarch "AMD Piledriver"
+
+    @feature bmi1
+    @detect "bmi1"
+    @version #[since(1.33)] "1.33"
+    @description "Bit Manipulation Instruction Set 1"
+    @rustc "bmi1"
+    @llvm "bmi"
+    @cpuinfo "bmi1"
+    @implies []
+    @microarch "AMD Piledriver"
+
+    @feature bmi2
+    @detect "bmi2"
+    @version #[since(1.33)] "1.33"
+    @description "Bit Manipulation Instruction Set 2"
+    @rustc "bmi2"
+    @llvm "bmi2"
+    @cpuinfo "bmi2"
+    @implies []
+    @microarch "Intel Haswell"
+
+    @feature abm
+    @detect "abm"
+    @version #[since(1.33)] "1.33"
+    @description "Advanced Bit Manipulation (LZCNT and POPCNT)"
+    @rustc none
+    @llvm none
+    @cpuinfo "abm"
+    @implies ["lzcnt", "popcnt"]
+    @microarch "AMD K10"
+
+    @feature lzcnt
+    @detect "lzcnt"
+    @version #[since(1.33)] "1.33"
+    @description "Leading zero count instruction"
+    @rustc "lzcnt"
+    @llvm "lzcnt"
+    @cpuinfo "abm"
+    @implies []
+    @microarch "AMD K10"
+
+    @feature tbm
+    @detect "tbm"
+    @version #[since(1.33)] "1.33"
+    @description "Trailing Bit Manipulation instructions"
+    @rustc "tbm"
+    @llvm "tbm"
+    @cpuinfo "tbm"
+    @implies []
+    @microarch "AMD Piledriver"
+
+    @feature popcnt
+    @detect "popcnt"
+    @version #[since(1.33)] "1.33"
+    @description "Population count instruction"
+    @rustc "popcnt"
+    @llvm "popcnt"
+    @cpuinfo "popcnt"
+    @implies []
+    @microarch "AMD K10"
+
+    @feature fxsr
+    @detect "fxsr"
+    @version #[since(1.33)] "1.33"
+    @description "FXSAVE and FXRSTOR instructions"
+    @rustc "fxsr"
+    @llvm "fxsr"
+    @cpuinfo "fxsr"
+    @implies []
+    @microarch "Intel P6"
+
+    @feature xsave
+    @detect "xsave"
+    @version #[since(1.33)] "1.33"
+    @description "XSAVE and XRSTOR instructions"
+    @rustc "xsave"
+    @llvm "xsave"
+    @cpuinfo "xsave"
+    @implies []
+    @microarch "Intel Penryn"
+
+    @feature xsaveopt
+    @detect "xsaveopt"
+    @version #[since(1.33)] "1.33"
+    @description "XSAVEOPT instruction"
+    @rustc "xsaveopt"
+    @llvm "xsaveopt"
+    @cpuinfo "xsaveopt"
+    @implies ["xsave"]
+    @microarch "Intel Sandy Bridge"
+
+    @feature xsaves
+    @detect "xsaves"
+    @version #[since(1.33)] "1.33"
+    @description "XSAVES and XRSTORS instructions"
+    @rustc "xsaves"
+    @llvm "xsaves"
+    @cpuinfo "xsaves"
+    @implies ["xsave"]
+    @microarch "Intel Skylake"
+
+    @feature xsavec
+    @detect "xsavec"
+    @version #[since(1.33)] "1.33"
+    @description "XSAVEC instruction"
+    @rustc "xsavec"
+    @llvm "xsavec"
+    @cpuinfo "xsavec"
+    @implies ["xsave"]
+    @microarch "Intel Skylake"
+
+    @feature cmpxchg16b
+    @detect "cmpxchg16b"
+    @version #[since(1.33)] "1.33"
+    @description "16-byte compare-and-exchange instruction"
+    @rustc "cmpxchg16b"
+    @llvm "cx16"
+    @cpuinfo "cx16"
+    @implies []
+    @microarch "Intel NetBurst"
+
+    @feature adx
+    @detect "adx"
+    @version #[since(1.33)] "1.33"
+    @description "Multi-precision add-carry instructions"
+    @rustc "adx"
+    @llvm "adx"
+    @cpuinfo "adx"
+    @implies []
+    @microarch "Intel Broadwell"
+
+    @feature rtm
+    @detect "rtm"
+    @version #[since(1.38)] "1.38"
+    @description "Restricted Transactional Memory"
+    @rustc "rtm"
+    @llvm "rtm"
+    @cpuinfo "rtm"
+    @implies []
+    @microarch "Intel Haswell"
+
+    @feature movbe
+    @detect "movbe"
+    @version #[since(1.67)] "1.67"
+    @description "Move data after swapping bytes"
+    @rustc "movbe"
+    @llvm "movbe"
+    @cpuinfo "movbe"
+    @implies []
+    @microarch "Intel Bonnell"
+
+    @feature ermsb
+    @detect "ermsb"
+    @version #[since(1.89)] "1.89"
+    @description "Enhanced REP MOVSB and STOSB"
+    @rustc "ermsb"
+    @llvm "ermsb"
+    @cpuinfo "erms"
+    @implies []
+    @microarch "Intel Ivy Bridge"
+
+    @feature kl
+    @detect "kl"
+    @version #[since(1.89)] "1.89"
+    @description "Key Locker instructions"
+    @rustc "kl"
+    @llvm "kl"
+    @cpuinfo none
+    @implies []
+    @microarch "Intel Tiger Lake"
+
+    @feature widekl
+    @detect "widekl"
+    @version #[since(1.89)] "1.89"
+    @description "Wide Key Locker instructions"
+    @rustc "widekl"
+    @llvm "widekl"
+    @cpuinfo none
+    @implies ["kl"]
+    @microarch "Intel Tiger Lake"
+}
+
+features! {
+    /// Features for the `arm` architecture.
+    ///
+    /// Features not supported by `is_arm_feature_detected` are only detected at compile time.
+    @table arm
+    @native #[cfg(target_arch = "arm")]
+    @detect_macro is_arm_feature_detected
+    @vector [neon pmull aes sha2 i8mm dotprod] => [16, 16, false]
+
+    @alias crypto "crypto" => [aes, sha2]
+    @deprecated "the `crypto` feature has been replaced by `aes` and `sha2`"
+
+    @feature neon
+    @detect "neon"
+    @version #[nightly(arch_types_arm_detection)] "nightly"
+    @description "NEON (Advanced SIMD) instructions"
+    @rustc "neon"
+    @llvm "neon"
+    @cpuinfo "neon"
+    @implies ["vfp3"]
+    @microarch "ARM Cortex-A8"
+
+    @feature pmull
+    @detect "pmull"
+    @version #[nightly(arch_types_arm_detection)] "nightly"
+    @description "Polynomial multiply long instructions"
+    @rustc none
+    @llvm none
+    @cpuinfo "pmull"
+    @implies ["neon"]
+    @microarch "ARM Cortex-A53"
+
+    @feature crc
+    @detect "crc"
+    @version #[nightly(arch_types_arm_detection)] "nightly"
+    @description "CRC32 instructions"
+    @rustc "crc"
+    @llvm "crc"
+    @cpuinfo "crc32"
+    @implies []
+    @microarch "ARM Cortex-A53"
+
+    @feature aes
+    @detect "aes"
+    @version #[nightly(arch_types_arm_detection)] "nightly"
+    @description "AES instructions"
+    @rustc "aes"
+    @llvm "aes"
+    @cpuinfo "aes"
+    @implies ["neon"]
+    @microarch "ARM Cortex-A53"
+
+    @feature sha2
+    @detect "sha2"
+    @version #[nightly(arch_types_arm_detection)] "nightly"
+    @description "SHA-1 and SHA-256 instructions"
+    @rustc "sha2"
+    @llvm "sha2"
+    @cpuinfo "sha2"
+    @implies ["neon"]
+    @microarch "ARM Cortex-A53"
+
+    @feature i8mm
+    @detect "i8mm"
+    @version #[nightly(arch_types_arm_detection)] "nightly"
+    @description "Int8 matrix multiplication instructions"
+    @rustc "i8mm"
+    @llvm "i8mm"
+    @cpuinfo "i8mm"
+    @implies ["neon"]
+    @microarch "ARM Cortex-A710"
+
+    @feature dotprod
+    @detect "dotprod"
+    @version #[nightly(arch_types_arm_detection)] "nightly"
+    @description "Dot product instructions"
+    @rustc "dotprod"
+    @llvm "dotprod"
+    @cpuinfo "asimddp"
+    @implies ["neon"]
+    @microarch "ARM Cortex-A75"
+
+    @feature v7
+    @detect "v7"
+    @version #[fallback] "1.34"
+    @description "ARMv7 instructions"
+    @rustc "v7"
+    @llvm "v7"
+    @cpuinfo none
+    @implies []
+    @microarch "ARM Cortex-A8"
+
+    @feature vfp2
+    @detect "vfp2"
+    @version #[fallback] "1.34"
+    @description "VFPv2 floating-point instructions"
+    @rustc "vfp2"
+    @llvm "vfp2"
+    @cpuinfo "vfp"
+    @implies []
+    @microarch "ARM ARM1136JF-S"
+
+    @feature vfp3
+    @detect "vfp3"
+    @version #[fallback] "1.34"
+    @description "VFPv3 floating-point instructions"
+    @rustc "vfp3"
+    @llvm "vfp3"
+    @cpuinfo "vfpv3"
+    @implies ["vfp2", "d32"]
+    @microarch "ARM Cortex-A8"
+
+    @feature vfp4
+    @detect "vfp4"
+    @version #[fallback] "1.34"
+    @description "VFPv4 floating-point instructions"
+    @rustc "vfp4"
+    @llvm "vfp4"
+    @cpuinfo "vfpv4"
+    @implies ["vfp3"]
+    @microarch "ARM Cortex-A5"
+
+    @feature d32
+    @detect "d32"
+    @version #[fallback] "1.34"
+    @description "32 double-precision floating-point registers"
+    @rustc "d32"
+    @llvm "d32"
+    @cpuinfo "vfpd32"
+    @implies []
+    @microarch "ARM Cortex-A8"
+
+    @feature thumb2
+    @detect "thumb2"
+    @version #[fallback] "1.34"
+    @description "Thumb-2 instructions"
+    @rustc "thumb2"
+    @llvm "thumb2"
+    @cpuinfo none
+    @implies []
+    @microarch "ARM ARM1156T2-S"
+
+    @feature aclass
+    @detect "aclass"
+    @version #[fallback] "1.34"
+    @description "A-profile architecture"
+    @rustc "aclass"
+    @llvm "aclass"
+    @cpuinfo none
+    @implies []
+    @microarch "ARM Cortex-A8"
+
+    @feature mclass
+    @detect "mclass"
+    @version #[fallback] "1.34"
+    @description "M-profile architecture"
+    @rustc "mclass"
+    @llvm "mclass"
+    @cpuinfo none
+    @implies []
+    @microarch "ARM Cortex-M3"
+
+    @feature dsp
+    @detect "dsp"
+    @version #[fallback] "1.34"
+    @description "DSP instructions"
+    @rustc "dsp"
+    @llvm "dsp"
+    @cpuinfo "edsp"
+    @implies []
+    @microarch "ARM ARM9E"
+
+    @feature mve
+    @detect "mve"
+    @version #[fallback] "1.34"
+    @description "M-profile Vector Extension integer instructions"
+    @rustc none
+    @llvm "mve"
+    @cpuinfo none
+    @implies ["dsp"]
+    @microarch "ARM Cortex-M55"
+
+    @feature mve_fp
+    @detect "mve.fp"
+    @version #[fallback] "1.34"
+    @description "M-profile Vector Extension floating-point instructions"
+    @rustc none
+    @llvm "mve.fp"
+    @cpuinfo none
+    @implies ["mve"]
+    @microarch "ARM Cortex-M55"
+}
+
+features! {
+    /// Features for the `aarch64` architecture.
+    ///
+    /// Features not supported by `is_aarch64_feature_detected` on stable Rust are detected with the
+    /// auxiliary vector on Linux.
+    @table aarch64
+    @native #[cfg(target_arch = "aarch64")]
+    @detect_macro is_aarch64_feature_detected
+    @fallback_macro aarch64_auxv_detected
+    @vector [sve sve2 sve2_aes] => [16, 32, true]
+    @vector [
+        neon pmull fp16 asimd rdm dotprod aes sha2 sha3 sm4 bf16 i8mm fcma jsconv fhm
+    ] => [16, 32, false]
+
+    @alias crypto "crypto" => [aes, sha2]
+    @deprecated "the `crypto` feature has been replaced by `aes` and `sha2`"
+
+    @feature neon
+    @detect "neon"
+    @version #[since(1.60)] "1.60"
+    @description "Advanced SIMD instructions"
+    @rustc "neon"
+    @llvm "neon"
+    @cpuinfo "asimd"
+    @implies ["fp"]
+    @microarch "Apple Cyclone"
+
+    @feature pmull
+    @detect "pmull"
+    @version #[since(1.60)] "1.60"
+    @description "Polynomial multiply long instructions"
+    @rustc none
+    @llvm none
+    @cpuinfo "pmull"
+    @implies ["neon"]
+    @microarch "Apple Cyclone"
+
+    @feature fp
+    @detect "fp"
+    @version #[since(1.60)] "1.60"
+    @description "Floating-point instructions"
+    @rustc none
+    @llvm "fp-armv8"
+    @cpuinfo "fp"
+    @implies []
+    @microarch "Apple Cyclone"
+
+    @feature fp16
+    @detect "fp16"
+    @version #[since(1.60)] "1.60"
+    @description "Half-precision floating-point instructions"
+    @rustc "fp16"
+    @llvm "fullfp16"
+    @cpuinfo "fphp"
+    @implies ["neon"]
+    @microarch "ARM Cortex-A75"
+
+    @feature sve
+    @detect "sve"
+    @version #[since(1.60)] "1.60"
+    @description "Scalable Vector Extension"
+    @rustc "sve"
+    @llvm "sve"
+    @cpuinfo "sve"
+    @implies ["fp16"]
+    @microarch "Fujitsu A64FX"
+
+    @feature crc
+    @detect "crc"
+    @version #[since(1.60)] "1.60"
+    @description "CRC32 instructions"
+    @rustc "crc"
+    @llvm "crc"
+    @cpuinfo "crc32"
+    @implies []
+    @microarch "ARM Cortex-A53"
+
+    @feature lse
+    @detect "lse"
+    @version #[since(1.60)] "1.60"
+    @description "Large System Extension atomic instructions"
+    @rustc "lse"
+    @llvm "lse"
+    @cpuinfo "atomics"
+    @implies []
+    @microarch "ARM Cortex-A75"
+
+    @feature rdm
+    @detect "rdm"
+    @version #[since(1.60)] "1.60"
+    @description "Rounding doubling multiply accumulate instructions"
+    @rustc "rdm"
+    @llvm "rdm"
+    @cpuinfo "asimdrdm"
+    @implies ["neon"]
+    @microarch "ARM Cortex-A75"
+
+    @feature rcpc
+    @detect "rcpc"
+    @version #[since(1.60)] "1.60"
+    @description "Release consistent processor consistent load instructions"
+    @rustc "rcpc"
+    @llvm "rcpc"
+    @cpuinfo "lrcpc"
+    @implies []
+    @microarch "ARM Cortex-A75"
+
+    @feature dotprod
+    @detect "dotprod"
+    @version #[since(1.60)] "1.60"
+    @description "Dot product instructions"
+    @rustc "dotprod"
+    @llvm "dotprod"
+    @cpuinfo "asimddp"
+    @implies ["neon"]
+    @microarch "ARM Cortex-A75"
+    @feature asimd
+    @detect "asimd"
+    @version #[since(1.60)] "1.60"
+    @description "Advanced SIMD instructions"
+    @rustc none
+    @llvm none
+    @cpuinfo "asimd"
+    @implies ["neon"]
+    @microarch "Apple Cyclone"
+
+    @feature aes
+    @detect "aes"
+    @version #[since(1.60)] "1.60"
+    @description "AES and polynomial multiply long instructions"
+    @rustc "aes"
+    @llvm "aes"
+    @cpuinfo "aes"
+    @implies ["neon"]
+    @microarch "Apple Cyclone"
+
+    @feature sha2
+    @detect "sha2"
+    @version #[since(1.60)] "1.60"
+    @description "SHA-1 and SHA-256 instructions"
+    @rustc "sha2"
+    @llvm "sha2"
+    @cpuinfo "sha2"
+    @implies ["neon"]
+    @microarch "Apple Cyclone"
+
+    @feature sha3
+    @detect "sha3"
+    @version #[since(1.60)] "1.60"
+    @description "SHA-512 and SHA-3 instructions"
+    @rustc "sha3"
+    @llvm "sha3"
+    @cpuinfo "sha3"
+    @implies ["sha2"]
+    @microarch "Apple Lightning"
+
+    @feature sm4
+    @detect "sm4"
+    @version #[since(1.60)] "1.60"
+    @description "SM3 and SM4 instructions"
+    @rustc "sm4"
+    @llvm "sm4"
+    @cpuinfo "sm4"
+    @implies ["neon"]
+    @microarch "HiSilicon TaiShan V110"
+
+    @feature bf16
+    @detect "bf16"
+    @version #[since(1.60)] "1.60"
+    @description "BFloat16 instructions"
+    @rustc "bf16"
+    @llvm "bf16"
+    @cpuinfo "bf16"
+    @implies []
+    @microarch "ARM Neoverse V1"
+
+    @feature i8mm
+    @detect "i8mm"
+    @version #[since(1.60)] "1.60"
+    @description "Int8 matrix multiplication instructions"
+    @rustc "i8mm"
+    @llvm "i8mm"
+    @cpuinfo "i8mm"
+    @implies []
+    @microarch "ARM Neoverse V1"
+
+    @feature sve2
+    @detect "sve2"
+    @version #[since(1.60)] "1.60"
+    @description "Scalable Vector Extension 2"
+    @rustc "sve2"
+    @llvm "sve2"
+    @cpuinfo "sve2"
+    @implies ["sve"]
+    @microarch "ARM Cortex-A710"
+
+    @feature sve2_aes
+    @detect "sve2-aes"
+    @version #[since(1.60)] "1.60"
+    @description "SVE2 AES and polynomial multiply long instructions"
+    @rustc "sve2-aes"
+    @llvm "sve2-aes"
+    @cpuinfo "sveaes"
+    @implies ["aes", "sve2"]
+    @microarch "ARM Cortex-A710"
+
+    @feature fcma
+    @detect "fcma"
+    @version #[since(1.60)] "1.60"
+    @description "Floating-point complex number instructions"
+    @rustc "fcma"
+    @llvm "complxnum"
+    @cpuinfo "fcma"
+    @implies ["neon"]
+    @microarch "Apple Vortex"
+
+    @feature jsconv
+    @detect "jsconv"
+    @version #[since(1.60)] "1.60"
+    @description "JavaScript conversion instruction"
+    @rustc "jsconv"
+    @llvm "jsconv"
+    @cpuinfo "jscvt"
+    @implies ["neon"]
+    @microarch "Apple Vortex"
+
+    @feature frintts
+    @detect "frintts"
+    @version #[since(1.60)] "1.60"
+    @description "Floating-point to integer rounding instructions"
+    @rustc "frintts"
+    @llvm "fptoint"
+    @cpuinfo "frint"
+    @implies []
+    @microarch "Apple Firestorm"
+
+    @feature mte
+    @detect "mte"
+    @version #[since(1.60)] "1.60"
+    @description "Memory Tagging Extension"
+    @rustc "mte"
+    @llvm "mte"
+    @cpuinfo "mte"
+    @implies []
+    @microarch "ARM Cortex-A710"
+
+    @feature bti
+    @detect "bti"
+    @version #[since(1.60)] "1.60"
+    @description "Branch Target Identification"
+    @rustc "bti"
+    @llvm "bti"
+    @cpuinfo "bti"
+    @implies []
+    @microarch "ARM Cortex-A710"
+
+    @feature paca
+    @detect "paca"
+    @version #[since(1.60)] "1.60"
+    @description "Pointer authentication of addresses"
+    @rustc "paca"
+    @llvm "pauth"
+    @cpuinfo "paca"
+    @implies []
+    @microarch "Apple Vortex"
+
+    @feature pacg
+    @detect "pacg"
+    @version #[since(1.60)] "1.60"
+    @description "Generic pointer authentication"
+    @rustc "pacg"
+    @llvm "pauth"
+    @cpuinfo "pacg"
+    @implies []
+    @microarch "Apple Vortex"
+
+    @feature dit
+    @detect "dit"
+    @version #[since(1.60)] "1.60"
+    @description "Data Independent Timing"
+    @rustc "dit"
+    @llvm "dit"
+    @cpuinfo "dit"
+    @implies []
+    @microarch "Apple Firestorm"
+
+    @feature flagm
+    @detect "flagm"
+    @version #[since(1.60)] "1.60"
+    @description "Flag manipulation instructions"
+    @rustc "flagm"
+    @llvm "flagm"
+    @cpuinfo "flagm"
+    @implies []
+    @microarch "Apple Lightning"
+
+    @feature ssbs
+    @detect "ssbs"
+    @version #[since(1.60)] "1.60"
+    @description "Speculative Store Bypass Safe"
+    @rustc "ssbs"
+    @llvm "ssbs"
+    @cpuinfo "ssbs"
+    @implies []
+    @microarch "ARM Cortex-A76"
+
+    @feature sb
+    @detect "sb"
+    @version #[since(1.60)] "1.60"
+    @description "Speculation barrier instruction"
+    @rustc "sb"
+    @llvm "sb"
+    @cpuinfo "sb"
+    @implies []
+    @microarch "Apple Firestorm"
+
+    @feature rand
+    @detect "rand"
+    @version #[since(1.60)] "1.60"
+    @description "Random number generation instructions"
+    @rustc "rand"
+    @llvm "rand"
+    @cpuinfo "rng"
+    @implies []
+    @microarch "ARM Neoverse V1"
+
+    @feature tme
+    @detect "tme"
+    @version #[since(1.60)] "1.60"
+    @description "Transactional Memory Extension"
+    @rustc "tme"
+    @llvm "tme"
+    @cpuinfo none
+    @implies []
+    @microarch none
+
+    @feature fhm
+    @detect "fhm"
+    @version #[since(1.60)] "1.60"
+    @description "Half-precision floating-point multiply-accumulate instructions"
+    @rustc "fhm"
+    @llvm "fp16fml"
+    @cpuinfo "asimdfhm"
+    @implies ["fp16"]
+    @microarch "Apple Lightning"
+
+    @feature lse2
+    @detect "lse2"
+    @version #[since(1.60)] "1.60"
+    @description "Large System Extension 2"
+    @rustc "lse2"
+    @llvm "lse2"
+    @cpuinfo "uscat"
+    @implies []
+    @microarch "Apple Lightning"
+
+    @feature wfxt
+    @detect "wfxt"
+    @version #[fallback] "1.60"
+    @description "WFE and WFI instructions with timeout"
+    @rustc "wfxt"
+    @llvm "wfxt"
+    @cpuinfo "wfxt"
+    @implies []
+    @microarch none
+
+    @feature hbc
+    @detect "hbc"
+    @version #[fallback] "1.60"
+    @description "Hinted conditional branch instructions"
+    @rustc "hbc"
+    @llvm "hbc"
+    @cpuinfo "hbc"
+    @implies []
+    @microarch none
+
+    @feature mops
+    @detect "mops"
+    @version #[fallback] "1.60"
+    @description "Memory copy and set instructions"
+    @rustc "mops"
+    @llvm "mops"
+    @cpuinfo "mops"
+    @implies []
+    @microarch none
+
+    @feature cssc
+    @detect "cssc"
+    @version #[fallback] "1.60"
+    @description "Common short sequence compression instructions"
+    @rustc "cssc"
+    @llvm "cssc"
+    @cpuinfo "cssc"
+    @implies []
+    @microarch none
+}
+
+features! {
+    /// Features for the `mips` architecture.
+    @table mips
+    @native #[cfg(target_arch = "mips")]
+    @detect_macro is_mips_feature_detected
+    @vector [msa] => [16, 32, false]
+
+    @feature msa
+    @detect "msa"
+    @version #[nightly(arch_types_mips_detection)] "nightly"
+    @description "MIPS SIMD Architecture"
+    @rustc "msa"
+    @llvm "msa"
+    @cpuinfo "msa"
+    @implies []
+    @microarch "MIPS P5600"
+}
+
+features! {
+    /// Features for the `mips64` architecture.
+    @table mips64
+    @native #[cfg(target_arch = "mips64")]
+    @detect_macro is_mips64_feature_detected
+    @vector [msa] => [16, 32, false]
+
+    @feature msa
+    @detect "msa"
+    @version #[nightly(arch_types_mips_detection)] "nightly"
+    @description "MIPS SIMD Architecture"
+    @rustc "msa"
+    @llvm "msa"
+    @cpuinfo "msa"
+    @implies []
+    @microarch "MIPS P5600"
+}
+
+features! {
+    /// Features for the `powerpc` architecture.
+    ///
+    /// Features not supported by `is_powerpc_feature_detected` are detected with the auxiliary vector
+    /// on Linux.
+    @table powerpc
+    @native #[cfg(target_arch = "powerpc")]
+    @detect_macro is_powerpc_feature_detected
+    @fallback_macro powerpc_auxv_detected
+    @vector [vsx power8 power8_vector power9_vector power10_vector] => [16, 64, false]
+    @vector [altivec power8_altivec power8_crypto power9_altivec crypto] => [16, 32, false]
+
+    @feature altivec
+    @detect "altivec"
+    @version #[nightly(arch_types_powerpc_detection)] "nightly"
+    @description "AltiVec vector instructions"
+    @rustc "altivec"
+    @llvm "altivec"
+    @cpuinfo "altivec"
+    @implies []
+    @microarch "Motorola G4"
+
+    @feature vsx
+    @detect "vsx"
+    @version #[nightly(arch_types_powerpc_detection)] "nightly"
+    @description "Vector-Scalar Extension"
+    @rustc "vsx"
+    @llvm "vsx"
+    @cpuinfo none
+    @implies ["altivec"]
+    @microarch "IBM POWER7"
+
+    @feature power8
+    @detect "power8"
+    @version #[nightly(arch_types_powerpc_detection)] "nightly"
+    @description "Power ISA 2.07 instructions"
+    @rustc none
+    @llvm none
+    @cpuinfo none
+    @implies ["vsx"]
+    @microarch "IBM POWER8"
+
+    @feature power8_altivec
+    @detect "power8-altivec"
+    @version #[nightly(arch_types_power9_detection)] "nightly"
+    @description "POWER8 AltiVec instructions"
+    @rustc "power8-altivec"
+    @llvm "power8-altivec"
+    @cpuinfo none
+    @implies ["altivec"]
+    @microarch "IBM POWER8"
+
+    @feature power8_vector
+    @detect "power8-vector"
+    @version #[nightly(arch_types_power9_detection)] "nightly"
+    @description "POWER8 vector-scalar instructions"
+    @rustc "power8-vector"
+    @llvm "power8-vector"
+    @cpuinfo none
+    @implies ["vsx", "power8-altivec"]
+    @microarch "IBM POWER8"
+
+    @feature power8_crypto
+    @detect "power8-crypto"
+    @version #[nightly(arch_types_power9_detection)] "nightly"
+    @description "POWER8 cryptographic instructions"
+    @rustc "power8-crypto"
+    @llvm "crypto"
+    @cpuinfo none
+    @implies ["power8-altivec"]
+    @microarch "IBM POWER8"
+
+    @feature power9_altivec
+    @detect "power9-altivec"
+    @version #[nightly(arch_types_power9_detection)] "nightly"
+    @description "POWER9 AltiVec instructions"
+    @rustc "power9-altivec"
+    @llvm "power9-altivec"
+    @cpuinfo none
+    @implies ["power8-altivec"]
+    @microarch "IBM POWER9"
+
+    @feature power9_vector
+    @detect "power9-vector"
+    @version #[nightly(arch_types_power9_detection)] "nightly"
+    @description "POWER9 vector-scalar instructions"
+    @rustc "power9-vector"
+    @llvm "power9-vector"
+    @cpuinfo none
+    @implies ["power8-vector", "power9-altivec"]
+    @microarch "IBM POWER9"
+
+    @feature power10_vector
+    @detect "power10-vector"
+    @version #[fallback] "1.34"
+    @description "POWER10 vector instructions"
+    @rustc "power10-vector"
+    @llvm "power10-vector"
+    @cpuinfo none
+    @implies ["power9-vector"]
+    @microarch "IBM POWER10"
+
+    @feature crypto
+    @detect "crypto"
+    @version #[fallback] "1.34"
+    @description "Vector cryptographic instructions"
+    @rustc none
+    @llvm "crypto"
+    @cpuinfo none
+    @implies ["power8-crypto"]
+    @microarch "IBM POWER8"
+}
+
+features! {
+    /// Features for the `powerpc64` architecture.
+    ///
+    /// Features not supported by `is_powerpc64_feature_detected` are detected with the auxiliary vector
+    /// on Linux.
+    @table powerpc64
+    @native #[cfg(target_arch = "powerpc64")]
+    @detect_macro is_powerpc64_feature_detected
+    @fallback_macro powerpc_auxv_detected
+    @vector [vsx power8 power8_vector power9_vector power10_vector] => [16, 64, false]
+    @vector [altivec power8_altivec power8_crypto power9_altivec crypto] => [16, 32, false]
+
+    @feature altivec
+    @detect "altivec"
+    @version #[nightly(arch_types_powerpc_detection)] "nightly"
+    @description "AltiVec vector instructions"
+    @rustc "altivec"
+    @llvm "altivec"
+    @cpuinfo "altivec"
+    @implies []
+    @microarch "Motorola G4"
+
+    @feature vsx
+    @detect "vsx"
+    @version #[nightly(arch_types_powerpc_detection)] "nightly"
+    @description "Vector-Scalar Extension"
+    @rustc "vsx"
+    @llvm "vsx"
+    @cpuinfo none
+    @implies ["altivec"]
+    @microarch "IBM POWER7"
+
+    @feature power8
+    @detect "power8"
+    @version #[nightly(arch_types_powerpc_detection)] "nightly"
+    @description "Power ISA 2.07 instructions"
+    @rustc none
+    @llvm none
+    @cpuinfo none
+    @implies ["vsx"]
+    @microarch "IBM POWER8"
+
+    @feature power8_altivec
+    @detect "power8-altivec"
+    @version #[nightly(arch_types_power9_detection)] "nightly"
+    @description "POWER8 AltiVec instructions"
+    @rustc "power8-altivec"
+    @llvm "power8-altivec"
+    @cpuinfo none
+    @implies ["altivec"]
+    @microarch "IBM POWER8"
+
+    @feature power8_vector
+    @detect "power8-vector"
+    @version #[nightly(arch_types_power9_detection)] "nightly"
+    @description "POWER8 vector-scalar instructions"
+    @rustc "power8-vector"
+    @llvm "power8-vector"
+    @cpuinfo none
+    @implies ["vsx", "power8-altivec"]
+    @microarch "IBM POWER8"
+
+    @feature power8_crypto
+    @detect "power8-crypto"
+    @version #[nightly(arch_types_power9_detection)] "nightly"
+    @description "POWER8 cryptographic instructions"
+    @rustc "power8-crypto"
+    @llvm "crypto"
+    @cpuinfo none
+    @implies ["power8-altivec"]
+    @microarch "IBM POWER8"
+
+    @feature power9_altivec
+    @detect "power9-altivec"
+    @version #[nightly(arch_types_power9_detection)] "nightly"
+    @description "POWER9 AltiVec instructions"
+    @rustc "power9-altivec"
+    @llvm "power9-altivec"
+    @cpuinfo none
+    @implies ["power8-altivec"]
+    @microarch "IBM POWER9"
+
+    @feature power9_vector
+    @detect "power9-vector"
+    @version #[nightly(arch_types_power9_detection)] "nightly"
+    @description "POWER9 vector-scalar instructions"
+    @rustc "power9-vector"
+    @llvm "power9-vector"
+    @cpuinfo none
+    @implies ["power8-vector", "power9-altivec"]
+    @microarch "IBM POWER9"
+
+    @feature power10_vector
+    @detect "power10-vector"
+    @version #[fallback] "1.34"
+    @description "POWER10 vector instructions"
+    @rustc "power10-vector"
+    @llvm "power10-vector"
+    @cpuinfo none
+    @implies ["power9-vector"]
+    @microarch "IBM POWER10"
+
+    @feature crypto
+    @detect "crypto"
+    @version #[fallback] "1.34"
+    @description "Vector cryptographic instructions"
+    @rustc none
+    @llvm "crypto"
+    @cpuinfo none
+    @implies ["power8-crypto"]
+    @microarch "IBM POWER8"
+
+    @feature quadword_atomics
+    @detect "quadword-atomics"
+    @version #[fallback] "1.34"
+    @description "Quadword atomic load and store instructions"
+    @rustc "quadword-atomics"
+    @llvm "quadword-atomics"
+    @cpuinfo none
+    @implies []
+    @microarch "IBM POWER8"
+}
+
+features! {
+    /// Features for the `riscv32` and `riscv64` architectures.
+    ///
+    /// Features are detected with `is_riscv_feature_detected` when possible, and otherwise with
+    /// the `riscv_hwprobe` system call on Linux.
+    @table riscv
+    @native #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+    @detect_macro is_riscv_feature_detected
+    @fallback_macro riscv_hwprobe_detected
+    @vector [v] => [16, 32, false]
+
+    @feature m
+    @detect "m"
+    @version #[since(1.78)] "1.78"
+    @description "Integer multiplication and division"
+    @rustc "m"
+    @llvm "m"
+    @cpuinfo "m"
+    @implies []
+    @microarch "SiFive E31"
+
+    @feature a
+    @detect "a"
+    @version #[since(1.78)] "1.78"
+    @description "Atomic instructions"
+    @rustc "a"
+    @llvm "a"
+    @cpuinfo "a"
+    @implies []
+    @microarch "SiFive E31"
+
+    @feature f
+    @detect "f"
+    @version #[nightly(arch_types_riscv_detection)] "nightly"
+    @description "Single-precision floating-point instructions"
+    @rustc "f"
+    @llvm "f"
+    @cpuinfo "f"
+    @implies []
+    @microarch "SiFive U54"
+
+    @feature d
+    @detect "d"
+    @version #[nightly(arch_types_riscv_detection)] "nightly"
+    @description "Double-precision floating-point instructions"
+    @rustc "d"
+    @llvm "d"
+    @cpuinfo "d"
+    @implies ["f"]
+    @microarch "SiFive U54"
+
+    @feature c
+    @detect "c"
+    @version #[since(1.78)] "1.78"
+    @description "Compressed instructions"
+    @rustc "c"
+    @llvm "c"
+    @cpuinfo "c"
+    @implies []
+    @microarch "SiFive E31"
+
+    @feature v
+    @detect "v"
+    @version #[nightly(arch_types_riscv_detection)] "nightly"
+    @description "Vector extension"
+    @rustc "v"
+    @llvm "v"
+    @cpuinfo "v"
+    @implies ["d"]
+    @microarch "SiFive X280"
+
+    @feature zba
+    @detect "zba"
+    @version #[since(1.78)] "1.78"
+    @description "Address generation instructions"
+    @rustc "zba"
+    @llvm "zba"
+    @cpuinfo "zba"
+    @implies []
+    @microarch none
+
+    @feature zbb
+    @detect "zbb"
+    @version #[since(1.78)] "1.78"
+    @description "Basic bit-manipulation instructions"
+    @rustc "zbb"
+    @llvm "zbb"
+    @cpuinfo "zbb"
+    @implies []
+    @microarch none
+
+    @feature zbc
+    @detect "zbc"
+    @version #[since(1.78)] "1.78"
+    @description "Carry-less multiplication instructions"
+    @rustc "zbc"
+    @llvm "zbc"
+    @cpuinfo "zbc"
+    @implies ["zbkc"]
+    @microarch none
+
+    @feature zbs
+    @detect "zbs"
+    @version #[since(1.78)] "1.78"
+    @description "Single-bit instructions"
+    @rustc "zbs"
+    @llvm "zbs"
+    @cpuinfo "zbs"
+    @implies []
+    @microarch none
+
+    @feature zbkb
+    @detect "zbkb"
+    @version #[since(1.78)] "1.78"
+    @description "Bit-manipulation instructions for cryptography"
+    @rustc "zbkb"
+    @llvm "zbkb"
+    @cpuinfo "zbkb"
+    @implies []
+    @microarch none
+
+    @feature zbkc
+    @detect "zbkc"
+    @version #[since(1.78)] "1.78"
+    @description "Carry-less multiplication instructions for cryptography"
+    @rustc "zbkc"
+    @llvm "zbkc"
+    @cpuinfo "zbkc"
+    @implies []
+    @microarch none
+
+    @feature zbkx
+    @detect "zbkx"
+    @version #[since(1.78)] "1.78"
+    @description "Crossbar permutation instructions"
+    @rustc "zbkx"
+    @llvm "zbkx"
+    @cpuinfo "zbkx"
+    @implies []
+    @microarch none
+
+    @feature zknd
+    @detect "zknd"
+    @version #[since(1.78)] "1.78"
+    @description "NIST suite AES decryption instructions"
+    @rustc "zknd"
+    @llvm "zknd"
+    @cpuinfo "zknd"
+    @implies []
+    @microarch none
+
+    @feature zkne
+    @detect "zkne"
+    @version #[since(1.78)] "1.78"
+    @description "NIST suite AES encryption instructions"
+    @rustc "zkne"
+    @llvm "zkne"
+    @cpuinfo "zkne"
+    @implies []
+    @microarch none
+
+    @feature zknh
+    @detect "zknh"
+    @version #[since(1.78)] "1.78"
+    @description "NIST suite hash function instructions"
+    @rustc "zknh"
+    @llvm "zknh"
+    @cpuinfo "zknh"
+    @implies []
+    @microarch none
+
+    @feature zksed
+    @detect "zksed"
+    @version #[since(1.78)] "1.78"
+    @description "ShangMi suite SM4 block cipher instructions"
+    @rustc "zksed"
+    @llvm "zksed"
+    @cpuinfo "zksed"
+    @implies []
+    @microarch none
+
+    @feature zksh
+    @detect "zksh"
+    @version #[since(1.78)] "1.78"
+    @description "ShangMi suite SM3 hash function instructions"
+    @rustc "zksh"
+    @llvm "zksh"
+    @cpuinfo "zksh"
+    @implies []
+    @microarch none
+
+    @feature zkn
+    @detect "zkn"
+    @version #[since(1.78)] "1.78"
+    @description "NIST algorithm suite"
+    @rustc "zkn"
+    @llvm "zkn"
+    @cpuinfo "zkn"
+    @implies ["zbkb", "zbkc", "zbkx", "zkne", "zknd", "zknh"]
+    @microarch none
+
+    @feature zks
+    @detect "zks"
+    @version #[since(1.78)] "1.78"
+    @description "ShangMi algorithm suite"
+    @rustc "zks"
+    @llvm "zks"
+    @cpuinfo "zks"
+    @implies ["zbkb", "zbkc", "zbkx", "zksed", "zksh"]
+    @microarch none
+
+    @feature zkt
+    @detect "zkt"
+    @version #[since(1.78)] "1.78"
+    @description "Data-independent execution latency"
+    @rustc "zkt"
+    @llvm "zkt"
+    @cpuinfo "zkt"
+    @implies []
+    @microarch none
+
+    @feature zfh
+    @detect "zfh"
+    @version #[nightly(arch_types_riscv_detection)] "nightly"
+    @description "Half-precision floating-point instructions"
+    @rustc "zfh"
+    @llvm "zfh"
+    @cpuinfo "zfh"
+    @implies ["zfhmin"]
+    @microarch none
+
+    @feature zfhmin
+    @detect "zfhmin"
+    @version #[nightly(arch_types_riscv_detection)] "nightly"
+    @description "Minimal half-precision floating-point instructions"
+    @rustc "zfhmin"
+    @llvm "zfhmin"
+    @cpuinfo "zfhmin"
+    @implies ["f"]
+    @microarch none
+
+    @feature zacas
+    @detect "zacas"
+    @version #[since(1.94)] "1.94"
+    @description "Atomic compare-and-swap instructions"
+    @rustc "zacas"
+    @llvm "zacas"
+    @cpuinfo "zacas"
+    @implies ["a"]
+    @microarch none
+
+    @feature zicond
+    @detect "zicond"
+    @version #[since(1.94)] "1.94"
+    @description "Integer conditional operations"
+    @rustc "zicond"
+    @llvm "zicond"
+    @cpuinfo "zicond"
+    @implies []
+    @microarch none
+
+    @feature zihintpause
+    @detect "zihintpause"
+    @version #[since(1.94)] "1.94"
+    @description "Pause hint instruction"
+    @rustc "zihintpause"
+    @llvm "zihintpause"
+    @cpuinfo "zihintpause"
+    @implies []
+    @microarch none
+}
+
+features! {
+    /// Features for the `wasm32` architecture.
+    ///
+    /// WebAssembly doesn't support run-time feature detection, so features are only detected at
+    /// compile time with `#[cfg(target_feature)]`.
+    @table wasm32
+    @native #[cfg(target_arch = "wasm32")]
+    @detect_macro no_runtime_detection
+    // WebAssembly has no registers
+    @vector [simd128 relaxed_simd] => [16, 0, false]
+
+    @feature simd128
+    @detect "simd128"
+    @version #[since(1.54)] "1.54"
+    @description "128-bit packed SIMD instructions"
+    @rustc "simd128"
+    @llvm "simd128"
+    @cpuinfo none
+    @implies []
+    @microarch none
+
+    @feature relaxed_simd
+    @detect "relaxed-simd"
+    @version #[since(1.82)] "1.82"
+    @description "Relaxed SIMD instructions"
+    @rustc "relaxed-simd"
+    @llvm "relaxed-simd"
+    @cpuinfo none
+    @implies ["simd128"]
+    @microarch none
+
+    @feature bulk_memory
+    @detect "bulk-memory"
+    @version #[since(1.76)] "1.76"
+    @description "Bulk memory operations"
+    @rustc "bulk-memory"
+    @llvm "bulk-memory"
+    @cpuinfo none
+    @implies []
+    @microarch none
+
+    @feature sign_ext
+    @detect "sign-ext"
+    @version #[since(1.76)] "1.76"
+    @description "Sign-extension operators"
+    @rustc "sign-ext"
+    @llvm "sign-ext"
+    @cpuinfo none
+    @implies []
+    @microarch none
+
+    @feature nontrapping_fptoint
+    @detect "nontrapping-fptoint"
+    @version #[since(1.76)] "1.76"
+    @description "Non-trapping float-to-int conversions"
+    @rustc "nontrapping-fptoint"
+    @llvm "nontrapping-fptoint"
+    @cpuinfo none
+    @implies []
+    @microarch none
+}
+
+features! {
+    /// Features for the `loongarch64` architecture.
+    @table loongarch64
+    @native #[cfg(target_arch = "loongarch64")]
+    @detect_macro is_loongarch_feature_detected
+    @vector [lasx] => [32, 32, false]
+    @vector [lsx] => [16, 32, false]
+
+    @feature f
+    @detect "f"
+    @version #[since(1.89)] "1.89"
+    @description "Single-precision floating-point instructions"
+    @rustc "f"
+    @llvm "f"
+    @cpuinfo none
+    @implies []
+    @microarch "Loongson 3A5000"
+
+    @feature d
+    @detect "d"
+    @version #[since(1.89)] "1.89"
+    @description "Double-precision floating-point instructions"
+    @rustc "d"
+    @llvm "d"
+    @cpuinfo none
+    @implies ["f"]
+    @microarch "Loongson 3A5000"
+
+    @feature frecipe
+    @detect "frecipe"
+    @version #[since(1.89)] "1.89"
+    @description "Floating-point reciprocal estimate instructions"
+    @rustc "frecipe"
+    @llvm "frecipe"
+    @cpuinfo none
+    @implies []
+    @microarch "Loongson 3A6000"
+
+    @feature lsx
+    @detect "lsx"
+    @version #[since(1.89)] "1.89"
+    @description "128-bit Loongson SIMD Extension"
+    @rustc "lsx"
+    @llvm "lsx"
+    @cpuinfo "lsx"
+    @implies ["d"]
+    @microarch "Loongson 3A5000"
+
+    @feature lasx
+    @detect "lasx"
+    @version #[since(1.89)] "1.89"
+    @description "256-bit Loongson Advanced SIMD Extension"
+    @rustc "lasx"
+    @llvm "lasx"
+    @cpuinfo "lasx"
+    @implies ["lsx"]
+    @microarch "Loongson 3A5000"
+
+    @feature lbt
+    @detect "lbt"
+    @version #[since(1.89)] "1.89"
+    @description "Loongson Binary Translation extension"
+    @rustc "lbt"
+    @llvm "lbt"
+    @cpuinfo none
+    @implies []
+    @microarch "Loongson 3A5000"
+
+    @feature lvz
+    @detect "lvz"
+    @version #[since(1.89)] "1.89"
+    @description "Loongson Virtualization extension"
+    @rustc "lvz"
+    @llvm "lvz"
+    @cpuinfo "lvz"
+    @implies []
+    @microarch "Loongson 3A5000"
+}
+
+features! {
+    /// Features for the `s390x` architecture.
+    @table s390x
+    @native #[cfg(target_arch = "s390x")]
+    @detect_macro is_s390x_feature_detected
+    @vector [vector vector_enhancements_1 vector_enhancements_2 nnp_assist] => [16, 32, false]
+
+    @feature vector
+    @detect "vector"
+    @version #[since(1.93)] "1.93"
+    @description "Vector facility"
+    @rustc "vector"
+    @llvm "vector"
+    @cpuinfo "vx"
+    @implies []
+    @microarch "IBM z13"
+
+    @feature vector_enhancements_1
+    @detect "vector-enhancements-1"
+    @version #[since(1.93)] "1.93"
+    @description "Vector-enhancements facility 1"
+    @rustc "vector-enhancements-1"
+    @llvm "vector-enhancements-1"
+    @cpuinfo "vxe"
+    @implies ["vector"]
+    @microarch "IBM z14"
+
+    @feature vector_enhancements_2
+    @detect "vector-enhancements-2"
+    @version #[since(1.93)] "1.93"
+    @description "Vector-enhancements facility 2"
+    @rustc "vector-enhancements-2"
+    @llvm "vector-enhancements-2"
+    @cpuinfo "vxe2"
+    @implies ["vector-enhancements-1"]
+    @microarch "IBM z15"
+
+    @feature nnp_assist
+    @detect "nnp-assist"
+    @version #[since(1.93)] "1.93"
+    @description "Neural-network-processing-assist facility"
+    @rustc "nnp-assist"
+    @llvm "nnp-assist"
+    @cpuinfo "nnpa"
+    @implies ["vector"]
+    @microarch "IBM z16"
+}
+
+features! {
+    @native #[cfg(not(any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "powerpc",
+        target_arch = "powerpc64",
+        target_arch = "riscv32",
+        target_arch = "riscv64",
+        target_arch = "wasm32",
+        target_arch = "loongarch64",
+        target_arch = "s390x"
+    )))]
+}
@@ -0,0 +1,151 @@
+//! Additional functionality for the `x86` and `x86_64` architectures.
+//!
+//! # AMX
+//! On Linux, the AMX tile data state must be requested with `arch_prctl(ARCH_REQ_XCOMP_PERM)`
+//! before any AMX instruction is executed.  Constructing a [`Features`] type requiring an AMX
+//! feature makes this request once per process, failing with [`DetectError::PermissionDenied`]
+//! if the kernel refuses.
+//!
+//! # Random numbers
+//! The [`rng`] module provides [`HardwareRng`], a random number generator using the `RDRAND` and
+//! `RDSEED` instructions.
+//!
+//! [`Features`]: ../trait.Features.html
+//! [`DetectError::PermissionDenied`]: ../enum.DetectError.html#variant.PermissionDenied
+//! [`rng`]: rng/index.html
+//! [`HardwareRng`]: rng/struct.HardwareRng.html
+
+pub mod intrinsics;
+pub mod rng;
+
+/// Request permission from the operating system for features that require it, evaluating to
+/// `Result<(), i32>`.
+macro_rules! x86_request_permission {
+    ("amx-tile") => {
+        $crate::x86::amx_permission()
+    };
+    ("amx-int8") => {
+        $crate::x86::amx_permission()
+    };
+    ("amx-bf16") => {
+        $crate::x86::amx_permission()
+    };
+    ("amx-fp16") => {
+        $crate::x86::amx_permission()
+    };
+    ($feature:tt) => {
+        Ok(())
+    };
+}
+
+#[cfg(all(target_arch = "x86_64", target_os = "linux"))]
+mod imp {
+    #[cfg(feature = "test-hooks")]
+    use core::sync::atomic::AtomicUsize;
+    use core::sync::atomic::{AtomicI32, Ordering};
+
+    const ARCH_REQ_XCOMP_PERM: usize = 0x1023;
+    const XFEATURE_XTILEDATA: usize = 18;
+
+    // Zero if permission hasn't been requested, one if it was granted, otherwise the negated errno
+    static PERMISSION: AtomicI32 = AtomicI32::new(0);
+
+    // A replacement for the `arch_prctl` syscall, used for testing
+    #[cfg(feature = "test-hooks")]
+    static HOOK: AtomicUsize = AtomicUsize::new(0);
+
+    #[rustversion::since(1.59)]
+    fn arch_prctl(code: usize, arg: usize) -> isize {
+        const SYS_ARCH_PRCTL: usize = 158;
+        let ret: isize;
+        // Safety: requesting permission doesn't access memory
+        unsafe {
+            core::arch::asm!(
+                "syscall",
+                inlateout("rax") SYS_ARCH_PRCTL => ret,
+                in("rdi") code,
+                in("rsi") arg,
+                lateout("rcx") _,
+                lateout("r11") _,
+                options(nostack),
+            );
+        }
+        ret
+    }
+
+    // Inline assembly isn't available, so permission can't be requested
+    #[rustversion::before(1.59)]
+    fn arch_prctl(_: usize, _: usize) -> isize {
+        const ENOSYS: isize = 38;
+        -ENOSYS
+    }
+
+    #[cfg(feature = "test-hooks")]
+    fn request_permission() -> isize {
+        let hook = HOOK.load(Ordering::Acquire);
+        if hook == 0 {
+            arch_prctl(ARCH_REQ_XCOMP_PERM, XFEATURE_XTILEDATA)
+        } else {
+            // Safety: only set from a valid function pointer in `mock_arch_prctl`
+            let hook: fn(usize, usize) -> isize = unsafe { core::mem::transmute(hook) };
+            hook(ARCH_REQ_XCOMP_PERM, XFEATURE_XTILEDATA)
+        }
+    }
+
+    #[cfg(not(feature = "test-hooks"))]
+    fn request_permission() -> isize {
+        arch_prctl(ARCH_REQ_XCOMP_PERM, XFEATURE_XTILEDATA)
+    }
+
+    #[cold]
+    fn request() -> i32 {
+        let ret = request_permission();
+        let state = if ret == 0 { 1 } else { ret as i32 };
+        PERMISSION.store(state, Ordering::Release);
+        state
+    }
+
+    /// Request permission to use the AMX tile data state, caching the result.
+    #[inline]
+    pub(crate) fn amx_permission() -> Result<(), i32> {
+        let state = match PERMISSION.load(Ordering::Acquire) {
+            0 => request(),
+            state => state,
+        };
+        if state == 1 {
+            Ok(())
+        } else {
+            Err(-state)
+        }
+    }
+
+    #[cfg(feature = "test-hooks")]
+    #[doc(hidden)]
+    pub unsafe fn mock_arch_prctl(hook: Option<fn(usize, usize) -> isize>) {
+        HOOK.store(hook.map_or(0, |hook| hook as usize), Ordering::Release);
+        PERMISSION.store(0, Ordering::Release);
+    }
+}
+
+#[cfg(not(all(target_arch = "x86_64", target_os = "linux")))]
+mod imp {
+    // Other operating systems don't require permission
+    #[inline]
+    pub(crate) fn amx_permission() -> Result<(), i32> {
+        Ok(())
+    }
+}
+
+pub(crate) use imp::amx_permission;
+
+/// Replaces the `arch_prctl` syscall used to request AMX permission and clears the cached result.
+///
+/// This is only intended for testing, and requires the `test-hooks` feature.
+///
+/// # Safety
+/// A hook reporting that permission was granted must only be installed if the kernel has actually
+/// granted it, or no AMX instructions may be executed while it is installed.  Executing AMX
+/// instructions without permission raises `SIGILL`.
+#[cfg(all(target_arch = "x86_64", target_os = "linux", feature = "test-hooks"))]
+#[doc(hidden)]
+pub use imp::mock_arch_prctl as __mock_arch_prctl;
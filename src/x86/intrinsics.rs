@@ -0,0 +1,6426 @@
+//! Safe wrappers for `core::arch` intrinsics.
+//!
+//! Each intrinsic requiring CPU features is exposed as a function with the same name, taking a
+//! [`Features`] token proving exactly the features the intrinsic requires as its first argument.
+//! Intrinsics that access memory through pointers remain `unsafe`, and have the same safety
+//! requirements as the underlying intrinsic.
+//!
+//! Intrinsics that don't require any CPU features, or are deprecated, are not included.
+//!
+//! Wrappers require Rust 1.63, or the version the intrinsic was stabilized in if later.
+//!
+//! ```
+//! # #[rustversion::since(1.63)]
+//! # fn main() {
+//! # #[cfg(target_arch = "x86_64")]
+//! # {
+//! use arch_types::{new_features_type, x86::intrinsics, Features};
+//! use core::arch::x86_64::__m256;
+//!
+//! new_features_type! { Avx => "avx" }
+//!
+//! if let Some(avx) = Avx::new() {
+//!     let a: __m256 = intrinsics::_mm256_set1_ps(avx, 1.);
+//!     let b = intrinsics::_mm256_add_ps(avx, a, a);
+//!     let mut out = [0f32; 8];
+//!     unsafe { intrinsics::_mm256_storeu_ps(avx, out.as_mut_ptr(), b) };
+//!     assert_eq!(out, [2.; 8]);
+//! }
+//! # }
+//! # }
+//! # #[rustversion::before(1.63)]
+//! # fn main() {}
+//! ```
+//!
+//! [`Features`]: ../../trait.Features.html
+#![allow(clippy::too_many_arguments, non_snake_case)]
+// `_mm_prefetch` takes a pointer, but prefetching never faults
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
+
+#[cfg(target_arch = "x86")]
+#[rustversion::since(1.63)]
+use core::arch::x86 as arch;
+#[cfg(target_arch = "x86_64")]
+#[rustversion::since(1.63)]
+use core::arch::x86_64 as arch;
+
+// Create a wrapper for each intrinsic, which calls the intrinsic after requiring a token
+#[rustversion::since(1.63)]
+macro_rules! intrinsics {
+    {
+        $(
+            $(#[$attr:meta])*
+            [$($feature:ident),*] $safety:tt fn $name:ident
+            $(<$(const $const:ident: $const_ty:ty),*>)?
+            ($($arg:ident: $arg_ty:ty),*) $(-> $ret:ty)?;
+        )*
+    } => {
+        $(
+            intrinsics! {
+                @wrapper $safety [$(#[$attr])*] [$($feature),*] $name
+                [$($(const $const: $const_ty),*)?] [$($($const),*)?]
+                ($($arg: $arg_ty),*) [$($ret)?]
+            }
+        )*
+    };
+
+    {
+        @wrapper safe [$(#[$attr:meta])*] [$($feature:ident),*] $name:ident
+        [$($generic:tt)*] [$($const:ident),*] ($($arg:ident: $arg_ty:ty),*) [$($ret:ty)?]
+    } => {
+        $(#[$attr])*
+        #[doc = concat!("Calls [`", stringify!($name), "`](https://doc.rust-lang.org/core/arch/x86_64/fn.", stringify!($name), ".html).")]
+        #[inline(always)]
+        pub fn $name<$($generic)*>(
+            _: impl crate::Features<$($feature = crate::logic::True),*>,
+            $($arg: $arg_ty),*
+        ) $(-> $ret)? {
+            unsafe { arch::$name::<$($const),*>($($arg),*) }
+        }
+    };
+
+    {
+        @wrapper unsafe [$(#[$attr:meta])*] [$($feature:ident),*] $name:ident
+        [$($generic:tt)*] [$($const:ident),*] ($($arg:ident: $arg_ty:ty),*) [$($ret:ty)?]
+    } => {
+        $(#[$attr])*
+        #[doc = concat!("Calls [`", stringify!($name), "`](https://doc.rust-lang.org/core/arch/x86_64/fn.", stringify!($name), ".html).")]
+        ///
+        /// # Safety
+        /// The safety requirements of the intrinsic must be upheld.
+        #[inline(always)]
+        pub unsafe fn $name<$($generic)*>(
+            _: impl crate::Features<$($feature = crate::logic::True),*>,
+            $($arg: $arg_ty),*
+        ) $(-> $ret)? {
+            arch::$name::<$($const),*>($($arg),*)
+        }
+    };
+}
+
+#[rustversion::since(1.63)]
+mod since_1_63 {
+    use super::arch;
+    use super::arch::*;
+
+    intrinsics! {
+        [sse] safe fn _MM_TRANSPOSE4_PS(row0: &mut __m128, row1: &mut __m128, row2: &mut __m128, row3: &mut __m128);
+        [adx] safe fn _addcarryx_u32(c_in: u8, a: u32, b: u32, out: &mut u32) -> u8;
+        #[cfg(target_arch = "x86_64")]
+        [adx] safe fn _addcarryx_u64(c_in: u8, a: u64, b: u64, out: &mut u64) -> u8;
+        [bmi1] safe fn _andn_u32(a: u32, b: u32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [bmi1] safe fn _andn_u64(a: u64, b: u64) -> u64;
+        [bmi1] safe fn _bextr2_u32(a: u32, control: u32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [bmi1] safe fn _bextr2_u64(a: u64, control: u64) -> u64;
+        [bmi1] safe fn _bextr_u32(a: u32, start: u32, len: u32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [bmi1] safe fn _bextr_u64(a: u64, start: u32, len: u32) -> u64;
+        [tbm] safe fn _blcfill_u32(x: u32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [tbm] safe fn _blcfill_u64(x: u64) -> u64;
+        [tbm] safe fn _blci_u32(x: u32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [tbm] safe fn _blci_u64(x: u64) -> u64;
+        [tbm] safe fn _blcic_u32(x: u32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [tbm] safe fn _blcic_u64(x: u64) -> u64;
+        [tbm] safe fn _blcmsk_u32(x: u32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [tbm] safe fn _blcmsk_u64(x: u64) -> u64;
+        [tbm] safe fn _blcs_u32(x: u32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [tbm] safe fn _blcs_u64(x: u64) -> u64;
+        [tbm] safe fn _blsfill_u32(x: u32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [tbm] safe fn _blsfill_u64(x: u64) -> u64;
+        [bmi1] safe fn _blsi_u32(x: u32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [bmi1] safe fn _blsi_u64(x: u64) -> u64;
+        [tbm] safe fn _blsic_u32(x: u32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [tbm] safe fn _blsic_u64(x: u64) -> u64;
+        [bmi1] safe fn _blsmsk_u32(x: u32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [bmi1] safe fn _blsmsk_u64(x: u64) -> u64;
+        [bmi1] safe fn _blsr_u32(x: u32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [bmi1] safe fn _blsr_u64(x: u64) -> u64;
+        [bmi2] safe fn _bzhi_u32(a: u32, index: u32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [bmi2] safe fn _bzhi_u64(a: u64, index: u32) -> u64;
+        [fxsr] unsafe fn _fxrstor(mem_addr: *const u8);
+        #[cfg(target_arch = "x86_64")]
+        [fxsr] unsafe fn _fxrstor64(mem_addr: *const u8);
+        [fxsr] unsafe fn _fxsave(mem_addr: *mut u8);
+        #[cfg(target_arch = "x86_64")]
+        [fxsr] unsafe fn _fxsave64(mem_addr: *mut u8);
+        [lzcnt] safe fn _lzcnt_u32(x: u32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [lzcnt] safe fn _lzcnt_u64(x: u64) -> u64;
+        [avx2] safe fn _mm256_abs_epi16(a: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_abs_epi32(a: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_abs_epi8(a: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_add_epi16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_add_epi32(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_add_epi64(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_add_epi8(a: __m256i, b: __m256i) -> __m256i;
+        [avx] safe fn _mm256_add_pd(a: __m256d, b: __m256d) -> __m256d;
+        [avx] safe fn _mm256_add_ps(a: __m256, b: __m256) -> __m256;
+        [avx2] safe fn _mm256_adds_epi16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_adds_epi8(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_adds_epu16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_adds_epu8(a: __m256i, b: __m256i) -> __m256i;
+        [avx] safe fn _mm256_addsub_pd(a: __m256d, b: __m256d) -> __m256d;
+        [avx] safe fn _mm256_addsub_ps(a: __m256, b: __m256) -> __m256;
+        [avx2] safe fn _mm256_alignr_epi8<const IMM8: i32>(a: __m256i, b: __m256i) -> __m256i;
+        [avx] safe fn _mm256_and_pd(a: __m256d, b: __m256d) -> __m256d;
+        [avx] safe fn _mm256_and_ps(a: __m256, b: __m256) -> __m256;
+        [avx2] safe fn _mm256_and_si256(a: __m256i, b: __m256i) -> __m256i;
+        [avx] safe fn _mm256_andnot_pd(a: __m256d, b: __m256d) -> __m256d;
+        [avx] safe fn _mm256_andnot_ps(a: __m256, b: __m256) -> __m256;
+        [avx2] safe fn _mm256_andnot_si256(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_avg_epu16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_avg_epu8(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_blend_epi16<const IMM8: i32>(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_blend_epi32<const IMM8: i32>(a: __m256i, b: __m256i) -> __m256i;
+        [avx] safe fn _mm256_blend_pd<const IMM4: i32>(a: __m256d, b: __m256d) -> __m256d;
+        [avx] safe fn _mm256_blend_ps<const IMM8: i32>(a: __m256, b: __m256) -> __m256;
+        [avx2] safe fn _mm256_blendv_epi8(a: __m256i, b: __m256i, mask: __m256i) -> __m256i;
+        [avx] safe fn _mm256_blendv_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+        [avx] safe fn _mm256_blendv_ps(a: __m256, b: __m256, c: __m256) -> __m256;
+        [avx] safe fn _mm256_broadcast_pd(a: &__m128d) -> __m256d;
+        [avx] safe fn _mm256_broadcast_ps(a: &__m128) -> __m256;
+        [avx] safe fn _mm256_broadcast_sd(f: &f64) -> __m256d;
+        [avx] safe fn _mm256_broadcast_ss(f: &f32) -> __m256;
+        [avx2] safe fn _mm256_broadcastb_epi8(a: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_broadcastd_epi32(a: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_broadcastq_epi64(a: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_broadcastsd_pd(a: __m128d) -> __m256d;
+        [avx2] safe fn _mm256_broadcastsi128_si256(a: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_broadcastss_ps(a: __m128) -> __m256;
+        [avx2] safe fn _mm256_broadcastw_epi16(a: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_bslli_epi128<const IMM8: i32>(a: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_bsrli_epi128<const IMM8: i32>(a: __m256i) -> __m256i;
+        [avx] safe fn _mm256_castpd128_pd256(a: __m128d) -> __m256d;
+        [avx] safe fn _mm256_castpd256_pd128(a: __m256d) -> __m128d;
+        [avx] safe fn _mm256_castpd_ps(a: __m256d) -> __m256;
+        [avx] safe fn _mm256_castpd_si256(a: __m256d) -> __m256i;
+        [avx] safe fn _mm256_castps128_ps256(a: __m128) -> __m256;
+        [avx] safe fn _mm256_castps256_ps128(a: __m256) -> __m128;
+        [avx] safe fn _mm256_castps_pd(a: __m256) -> __m256d;
+        [avx] safe fn _mm256_castps_si256(a: __m256) -> __m256i;
+        [avx] safe fn _mm256_castsi128_si256(a: __m128i) -> __m256i;
+        [avx] safe fn _mm256_castsi256_pd(a: __m256i) -> __m256d;
+        [avx] safe fn _mm256_castsi256_ps(a: __m256i) -> __m256;
+        [avx] safe fn _mm256_castsi256_si128(a: __m256i) -> __m128i;
+        [avx] safe fn _mm256_ceil_pd(a: __m256d) -> __m256d;
+        [avx] safe fn _mm256_ceil_ps(a: __m256) -> __m256;
+        [avx] safe fn _mm256_cmp_pd<const IMM5: i32>(a: __m256d, b: __m256d) -> __m256d;
+        [avx] safe fn _mm256_cmp_ps<const IMM5: i32>(a: __m256, b: __m256) -> __m256;
+        [avx2] safe fn _mm256_cmpeq_epi16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_cmpeq_epi32(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_cmpeq_epi64(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_cmpeq_epi8(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_cmpgt_epi16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_cmpgt_epi32(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_cmpgt_epi64(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_cmpgt_epi8(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_cvtepi16_epi32(a: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_cvtepi16_epi64(a: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_cvtepi32_epi64(a: __m128i) -> __m256i;
+        [avx] safe fn _mm256_cvtepi32_pd(a: __m128i) -> __m256d;
+        [avx] safe fn _mm256_cvtepi32_ps(a: __m256i) -> __m256;
+        [avx2] safe fn _mm256_cvtepi8_epi16(a: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_cvtepi8_epi32(a: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_cvtepi8_epi64(a: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_cvtepu16_epi32(a: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_cvtepu16_epi64(a: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_cvtepu32_epi64(a: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_cvtepu8_epi16(a: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_cvtepu8_epi32(a: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_cvtepu8_epi64(a: __m128i) -> __m256i;
+        [avx] safe fn _mm256_cvtpd_epi32(a: __m256d) -> __m128i;
+        [avx] safe fn _mm256_cvtpd_ps(a: __m256d) -> __m128;
+        [avx] safe fn _mm256_cvtps_epi32(a: __m256) -> __m256i;
+        [avx] safe fn _mm256_cvtps_pd(a: __m128) -> __m256d;
+        [avx] safe fn _mm256_cvtsd_f64(a: __m256d) -> f64;
+        [avx] safe fn _mm256_cvtsi256_si32(a: __m256i) -> i32;
+        [avx] safe fn _mm256_cvtss_f32(a: __m256) -> f32;
+        [avx] safe fn _mm256_cvttpd_epi32(a: __m256d) -> __m128i;
+        [avx] safe fn _mm256_cvttps_epi32(a: __m256) -> __m256i;
+        [avx] safe fn _mm256_div_pd(a: __m256d, b: __m256d) -> __m256d;
+        [avx] safe fn _mm256_div_ps(a: __m256, b: __m256) -> __m256;
+        [avx] safe fn _mm256_dp_ps<const IMM8: i32>(a: __m256, b: __m256) -> __m256;
+        [avx2] safe fn _mm256_extract_epi16<const INDEX: i32>(a: __m256i) -> i32;
+        [avx] safe fn _mm256_extract_epi32<const INDEX: i32>(a: __m256i) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [avx] safe fn _mm256_extract_epi64<const INDEX: i32>(a: __m256i) -> i64;
+        [avx2] safe fn _mm256_extract_epi8<const INDEX: i32>(a: __m256i) -> i32;
+        [avx] safe fn _mm256_extractf128_pd<const IMM1: i32>(a: __m256d) -> __m128d;
+        [avx] safe fn _mm256_extractf128_ps<const IMM1: i32>(a: __m256) -> __m128;
+        [avx] safe fn _mm256_extractf128_si256<const IMM1: i32>(a: __m256i) -> __m128i;
+        [avx2] safe fn _mm256_extracti128_si256<const IMM1: i32>(a: __m256i) -> __m128i;
+        [avx] safe fn _mm256_floor_pd(a: __m256d) -> __m256d;
+        [avx] safe fn _mm256_floor_ps(a: __m256) -> __m256;
+        [fma] safe fn _mm256_fmadd_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+        [fma] safe fn _mm256_fmadd_ps(a: __m256, b: __m256, c: __m256) -> __m256;
+        [fma] safe fn _mm256_fmaddsub_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+        [fma] safe fn _mm256_fmaddsub_ps(a: __m256, b: __m256, c: __m256) -> __m256;
+        [fma] safe fn _mm256_fmsub_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+        [fma] safe fn _mm256_fmsub_ps(a: __m256, b: __m256, c: __m256) -> __m256;
+        [fma] safe fn _mm256_fmsubadd_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+        [fma] safe fn _mm256_fmsubadd_ps(a: __m256, b: __m256, c: __m256) -> __m256;
+        [fma] safe fn _mm256_fnmadd_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+        [fma] safe fn _mm256_fnmadd_ps(a: __m256, b: __m256, c: __m256) -> __m256;
+        [fma] safe fn _mm256_fnmsub_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+        [fma] safe fn _mm256_fnmsub_ps(a: __m256, b: __m256, c: __m256) -> __m256;
+        [avx2] safe fn _mm256_hadd_epi16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_hadd_epi32(a: __m256i, b: __m256i) -> __m256i;
+        [avx] safe fn _mm256_hadd_pd(a: __m256d, b: __m256d) -> __m256d;
+        [avx] safe fn _mm256_hadd_ps(a: __m256, b: __m256) -> __m256;
+        [avx2] safe fn _mm256_hadds_epi16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_hsub_epi16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_hsub_epi32(a: __m256i, b: __m256i) -> __m256i;
+        [avx] safe fn _mm256_hsub_pd(a: __m256d, b: __m256d) -> __m256d;
+        [avx] safe fn _mm256_hsub_ps(a: __m256, b: __m256) -> __m256;
+        [avx2] safe fn _mm256_hsubs_epi16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] unsafe fn _mm256_i32gather_epi32<const SCALE: i32>(slice: *const i32, offsets: __m256i) -> __m256i;
+        [avx2] unsafe fn _mm256_i32gather_epi64<const SCALE: i32>(slice: *const i64, offsets: __m128i) -> __m256i;
+        [avx2] unsafe fn _mm256_i32gather_pd<const SCALE: i32>(slice: *const f64, offsets: __m128i) -> __m256d;
+        [avx2] unsafe fn _mm256_i32gather_ps<const SCALE: i32>(slice: *const f32, offsets: __m256i) -> __m256;
+        [avx2] unsafe fn _mm256_i64gather_epi32<const SCALE: i32>(slice: *const i32, offsets: __m256i) -> __m128i;
+        [avx2] unsafe fn _mm256_i64gather_epi64<const SCALE: i32>(slice: *const i64, offsets: __m256i) -> __m256i;
+        [avx2] unsafe fn _mm256_i64gather_pd<const SCALE: i32>(slice: *const f64, offsets: __m256i) -> __m256d;
+        [avx2] unsafe fn _mm256_i64gather_ps<const SCALE: i32>(slice: *const f32, offsets: __m256i) -> __m128;
+        [avx] safe fn _mm256_insert_epi16<const INDEX: i32>(a: __m256i, i: i16) -> __m256i;
+        [avx] safe fn _mm256_insert_epi32<const INDEX: i32>(a: __m256i, i: i32) -> __m256i;
+        #[cfg(target_arch = "x86_64")]
+        [avx] safe fn _mm256_insert_epi64<const INDEX: i32>(a: __m256i, i: i64) -> __m256i;
+        [avx] safe fn _mm256_insert_epi8<const INDEX: i32>(a: __m256i, i: i8) -> __m256i;
+        [avx] safe fn _mm256_insertf128_pd<const IMM1: i32>(a: __m256d, b: __m128d) -> __m256d;
+        [avx] safe fn _mm256_insertf128_ps<const IMM1: i32>(a: __m256, b: __m128) -> __m256;
+        [avx] safe fn _mm256_insertf128_si256<const IMM1: i32>(a: __m256i, b: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_inserti128_si256<const IMM1: i32>(a: __m256i, b: __m128i) -> __m256i;
+        [avx] unsafe fn _mm256_lddqu_si256(mem_addr: *const __m256i) -> __m256i;
+        [avx] unsafe fn _mm256_load_pd(mem_addr: *const f64) -> __m256d;
+        [avx] unsafe fn _mm256_load_ps(mem_addr: *const f32) -> __m256;
+        [avx] unsafe fn _mm256_load_si256(mem_addr: *const __m256i) -> __m256i;
+        [avx] unsafe fn _mm256_loadu2_m128(hiaddr: *const f32, loaddr: *const f32) -> __m256;
+        [avx] unsafe fn _mm256_loadu2_m128d(hiaddr: *const f64, loaddr: *const f64) -> __m256d;
+        [avx] unsafe fn _mm256_loadu2_m128i(hiaddr: *const __m128i, loaddr: *const __m128i) -> __m256i;
+        [avx] unsafe fn _mm256_loadu_pd(mem_addr: *const f64) -> __m256d;
+        [avx] unsafe fn _mm256_loadu_ps(mem_addr: *const f32) -> __m256;
+        [avx] unsafe fn _mm256_loadu_si256(mem_addr: *const __m256i) -> __m256i;
+        [avx2] safe fn _mm256_madd_epi16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_maddubs_epi16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] unsafe fn _mm256_mask_i32gather_epi32<const SCALE: i32>(src: __m256i, slice: *const i32, offsets: __m256i, mask: __m256i) -> __m256i;
+        [avx2] unsafe fn _mm256_mask_i32gather_epi64<const SCALE: i32>(src: __m256i, slice: *const i64, offsets: __m128i, mask: __m256i) -> __m256i;
+        [avx2] unsafe fn _mm256_mask_i32gather_pd<const SCALE: i32>(src: __m256d, slice: *const f64, offsets: __m128i, mask: __m256d) -> __m256d;
+        [avx2] unsafe fn _mm256_mask_i32gather_ps<const SCALE: i32>(src: __m256, slice: *const f32, offsets: __m256i, mask: __m256) -> __m256;
+        [avx2] unsafe fn _mm256_mask_i64gather_epi32<const SCALE: i32>(src: __m128i, slice: *const i32, offsets: __m256i, mask: __m128i) -> __m128i;
+        [avx2] unsafe fn _mm256_mask_i64gather_epi64<const SCALE: i32>(src: __m256i, slice: *const i64, offsets: __m256i, mask: __m256i) -> __m256i;
+        [avx2] unsafe fn _mm256_mask_i64gather_pd<const SCALE: i32>(src: __m256d, slice: *const f64, offsets: __m256i, mask: __m256d) -> __m256d;
+        [avx2] unsafe fn _mm256_mask_i64gather_ps<const SCALE: i32>(src: __m128, slice: *const f32, offsets: __m256i, mask: __m128) -> __m128;
+        [avx2] unsafe fn _mm256_maskload_epi32(mem_addr: *const i32, mask: __m256i) -> __m256i;
+        [avx2] unsafe fn _mm256_maskload_epi64(mem_addr: *const i64, mask: __m256i) -> __m256i;
+        [avx] unsafe fn _mm256_maskload_pd(mem_addr: *const f64, mask: __m256i) -> __m256d;
+        [avx] unsafe fn _mm256_maskload_ps(mem_addr: *const f32, mask: __m256i) -> __m256;
+        [avx2] unsafe fn _mm256_maskstore_epi32(mem_addr: *mut i32, mask: __m256i, a: __m256i);
+        [avx2] unsafe fn _mm256_maskstore_epi64(mem_addr: *mut i64, mask: __m256i, a: __m256i);
+        [avx] unsafe fn _mm256_maskstore_pd(mem_addr: *mut f64, mask: __m256i, a: __m256d);
+        [avx] unsafe fn _mm256_maskstore_ps(mem_addr: *mut f32, mask: __m256i, a: __m256);
+        [avx2] safe fn _mm256_max_epi16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_max_epi32(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_max_epi8(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_max_epu16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_max_epu32(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_max_epu8(a: __m256i, b: __m256i) -> __m256i;
+        [avx] safe fn _mm256_max_pd(a: __m256d, b: __m256d) -> __m256d;
+        [avx] safe fn _mm256_max_ps(a: __m256, b: __m256) -> __m256;
+        [avx2] safe fn _mm256_min_epi16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_min_epi32(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_min_epi8(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_min_epu16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_min_epu32(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_min_epu8(a: __m256i, b: __m256i) -> __m256i;
+        [avx] safe fn _mm256_min_pd(a: __m256d, b: __m256d) -> __m256d;
+        [avx] safe fn _mm256_min_ps(a: __m256, b: __m256) -> __m256;
+        [avx] safe fn _mm256_movedup_pd(a: __m256d) -> __m256d;
+        [avx] safe fn _mm256_movehdup_ps(a: __m256) -> __m256;
+        [avx] safe fn _mm256_moveldup_ps(a: __m256) -> __m256;
+        [avx2] safe fn _mm256_movemask_epi8(a: __m256i) -> i32;
+        [avx] safe fn _mm256_movemask_pd(a: __m256d) -> i32;
+        [avx] safe fn _mm256_movemask_ps(a: __m256) -> i32;
+        [avx2] safe fn _mm256_mpsadbw_epu8<const IMM8: i32>(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_mul_epi32(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_mul_epu32(a: __m256i, b: __m256i) -> __m256i;
+        [avx] safe fn _mm256_mul_pd(a: __m256d, b: __m256d) -> __m256d;
+        [avx] safe fn _mm256_mul_ps(a: __m256, b: __m256) -> __m256;
+        [avx2] safe fn _mm256_mulhi_epi16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_mulhi_epu16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_mulhrs_epi16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_mullo_epi16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_mullo_epi32(a: __m256i, b: __m256i) -> __m256i;
+        [avx] safe fn _mm256_or_pd(a: __m256d, b: __m256d) -> __m256d;
+        [avx] safe fn _mm256_or_ps(a: __m256, b: __m256) -> __m256;
+        [avx2] safe fn _mm256_or_si256(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_packs_epi16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_packs_epi32(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_packus_epi16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_packus_epi32(a: __m256i, b: __m256i) -> __m256i;
+        [avx] safe fn _mm256_permute2f128_pd<const IMM8: i32>(a: __m256d, b: __m256d) -> __m256d;
+        [avx] safe fn _mm256_permute2f128_ps<const IMM8: i32>(a: __m256, b: __m256) -> __m256;
+        [avx] safe fn _mm256_permute2f128_si256<const IMM8: i32>(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_permute2x128_si256<const IMM8: i32>(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_permute4x64_epi64<const IMM8: i32>(a: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_permute4x64_pd<const IMM8: i32>(a: __m256d) -> __m256d;
+        [avx] safe fn _mm256_permute_pd<const IMM4: i32>(a: __m256d) -> __m256d;
+        [avx] safe fn _mm256_permute_ps<const IMM8: i32>(a: __m256) -> __m256;
+        [avx2] safe fn _mm256_permutevar8x32_epi32(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_permutevar8x32_ps(a: __m256, idx: __m256i) -> __m256;
+        [avx] safe fn _mm256_permutevar_pd(a: __m256d, b: __m256i) -> __m256d;
+        [avx] safe fn _mm256_permutevar_ps(a: __m256, b: __m256i) -> __m256;
+        [avx] safe fn _mm256_rcp_ps(a: __m256) -> __m256;
+        [avx] safe fn _mm256_round_pd<const ROUNDING: i32>(a: __m256d) -> __m256d;
+        [avx] safe fn _mm256_round_ps<const ROUNDING: i32>(a: __m256) -> __m256;
+        [avx] safe fn _mm256_rsqrt_ps(a: __m256) -> __m256;
+        [avx2] safe fn _mm256_sad_epu8(a: __m256i, b: __m256i) -> __m256i;
+        [avx] safe fn _mm256_set1_epi16(a: i16) -> __m256i;
+        [avx] safe fn _mm256_set1_epi32(a: i32) -> __m256i;
+        [avx] safe fn _mm256_set1_epi64x(a: i64) -> __m256i;
+        [avx] safe fn _mm256_set1_epi8(a: i8) -> __m256i;
+        [avx] safe fn _mm256_set1_pd(a: f64) -> __m256d;
+        [avx] safe fn _mm256_set1_ps(a: f32) -> __m256;
+        [avx] safe fn _mm256_set_epi16(e00: i16, e01: i16, e02: i16, e03: i16, e04: i16, e05: i16, e06: i16, e07: i16, e08: i16, e09: i16, e10: i16, e11: i16, e12: i16, e13: i16, e14: i16, e15: i16) -> __m256i;
+        [avx] safe fn _mm256_set_epi32(e0: i32, e1: i32, e2: i32, e3: i32, e4: i32, e5: i32, e6: i32, e7: i32) -> __m256i;
+        [avx] safe fn _mm256_set_epi64x(a: i64, b: i64, c: i64, d: i64) -> __m256i;
+        [avx] safe fn _mm256_set_epi8(e00: i8, e01: i8, e02: i8, e03: i8, e04: i8, e05: i8, e06: i8, e07: i8, e08: i8, e09: i8, e10: i8, e11: i8, e12: i8, e13: i8, e14: i8, e15: i8, e16: i8, e17: i8, e18: i8, e19: i8, e20: i8, e21: i8, e22: i8, e23: i8, e24: i8, e25: i8, e26: i8, e27: i8, e28: i8, e29: i8, e30: i8, e31: i8) -> __m256i;
+        [avx] safe fn _mm256_set_m128(hi: __m128, lo: __m128) -> __m256;
+        [avx] safe fn _mm256_set_m128d(hi: __m128d, lo: __m128d) -> __m256d;
+        [avx] safe fn _mm256_set_m128i(hi: __m128i, lo: __m128i) -> __m256i;
+        [avx] safe fn _mm256_set_pd(a: f64, b: f64, c: f64, d: f64) -> __m256d;
+        [avx] safe fn _mm256_set_ps(a: f32, b: f32, c: f32, d: f32, e: f32, f: f32, g: f32, h: f32) -> __m256;
+        [avx] safe fn _mm256_setr_epi16(e00: i16, e01: i16, e02: i16, e03: i16, e04: i16, e05: i16, e06: i16, e07: i16, e08: i16, e09: i16, e10: i16, e11: i16, e12: i16, e13: i16, e14: i16, e15: i16) -> __m256i;
+        [avx] safe fn _mm256_setr_epi32(e0: i32, e1: i32, e2: i32, e3: i32, e4: i32, e5: i32, e6: i32, e7: i32) -> __m256i;
+        [avx] safe fn _mm256_setr_epi64x(a: i64, b: i64, c: i64, d: i64) -> __m256i;
+        [avx] safe fn _mm256_setr_epi8(e00: i8, e01: i8, e02: i8, e03: i8, e04: i8, e05: i8, e06: i8, e07: i8, e08: i8, e09: i8, e10: i8, e11: i8, e12: i8, e13: i8, e14: i8, e15: i8, e16: i8, e17: i8, e18: i8, e19: i8, e20: i8, e21: i8, e22: i8, e23: i8, e24: i8, e25: i8, e26: i8, e27: i8, e28: i8, e29: i8, e30: i8, e31: i8) -> __m256i;
+        [avx] safe fn _mm256_setr_m128(lo: __m128, hi: __m128) -> __m256;
+        [avx] safe fn _mm256_setr_m128d(lo: __m128d, hi: __m128d) -> __m256d;
+        [avx] safe fn _mm256_setr_m128i(lo: __m128i, hi: __m128i) -> __m256i;
+        [avx] safe fn _mm256_setr_pd(a: f64, b: f64, c: f64, d: f64) -> __m256d;
+        [avx] safe fn _mm256_setr_ps(a: f32, b: f32, c: f32, d: f32, e: f32, f: f32, g: f32, h: f32) -> __m256;
+        [avx] safe fn _mm256_setzero_pd() -> __m256d;
+        [avx] safe fn _mm256_setzero_ps() -> __m256;
+        [avx] safe fn _mm256_setzero_si256() -> __m256i;
+        [avx2] safe fn _mm256_shuffle_epi32<const MASK: i32>(a: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_shuffle_epi8(a: __m256i, b: __m256i) -> __m256i;
+        [avx] safe fn _mm256_shuffle_pd<const MASK: i32>(a: __m256d, b: __m256d) -> __m256d;
+        [avx] safe fn _mm256_shuffle_ps<const MASK: i32>(a: __m256, b: __m256) -> __m256;
+        [avx2] safe fn _mm256_shufflehi_epi16<const IMM8: i32>(a: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_shufflelo_epi16<const IMM8: i32>(a: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_sign_epi16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_sign_epi32(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_sign_epi8(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_sll_epi16(a: __m256i, count: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_sll_epi32(a: __m256i, count: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_sll_epi64(a: __m256i, count: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_slli_epi16<const IMM8: i32>(a: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_slli_epi32<const IMM8: i32>(a: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_slli_epi64<const IMM8: i32>(a: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_slli_si256<const IMM8: i32>(a: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_sllv_epi32(a: __m256i, count: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_sllv_epi64(a: __m256i, count: __m256i) -> __m256i;
+        [avx] safe fn _mm256_sqrt_pd(a: __m256d) -> __m256d;
+        [avx] safe fn _mm256_sqrt_ps(a: __m256) -> __m256;
+        [avx2] safe fn _mm256_sra_epi16(a: __m256i, count: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_sra_epi32(a: __m256i, count: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_srai_epi16<const IMM8: i32>(a: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_srai_epi32<const IMM8: i32>(a: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_srav_epi32(a: __m256i, count: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_srl_epi16(a: __m256i, count: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_srl_epi32(a: __m256i, count: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_srl_epi64(a: __m256i, count: __m128i) -> __m256i;
+        [avx2] safe fn _mm256_srli_epi16<const IMM8: i32>(a: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_srli_epi32<const IMM8: i32>(a: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_srli_epi64<const IMM8: i32>(a: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_srli_si256<const IMM8: i32>(a: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_srlv_epi32(a: __m256i, count: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_srlv_epi64(a: __m256i, count: __m256i) -> __m256i;
+        [avx] unsafe fn _mm256_store_pd(mem_addr: *mut f64, a: __m256d);
+        [avx] unsafe fn _mm256_store_ps(mem_addr: *mut f32, a: __m256);
+        [avx] unsafe fn _mm256_store_si256(mem_addr: *mut __m256i, a: __m256i);
+        [avx] unsafe fn _mm256_storeu2_m128(hiaddr: *mut f32, loaddr: *mut f32, a: __m256);
+        [avx] unsafe fn _mm256_storeu2_m128d(hiaddr: *mut f64, loaddr: *mut f64, a: __m256d);
+        [avx] unsafe fn _mm256_storeu2_m128i(hiaddr: *mut __m128i, loaddr: *mut __m128i, a: __m256i);
+        [avx] unsafe fn _mm256_storeu_pd(mem_addr: *mut f64, a: __m256d);
+        [avx] unsafe fn _mm256_storeu_ps(mem_addr: *mut f32, a: __m256);
+        [avx] unsafe fn _mm256_storeu_si256(mem_addr: *mut __m256i, a: __m256i);
+        [avx] unsafe fn _mm256_stream_pd(mem_addr: *mut f64, a: __m256d);
+        [avx] unsafe fn _mm256_stream_ps(mem_addr: *mut f32, a: __m256);
+        [avx] unsafe fn _mm256_stream_si256(mem_addr: *mut __m256i, a: __m256i);
+        [avx2] safe fn _mm256_sub_epi16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_sub_epi32(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_sub_epi64(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_sub_epi8(a: __m256i, b: __m256i) -> __m256i;
+        [avx] safe fn _mm256_sub_pd(a: __m256d, b: __m256d) -> __m256d;
+        [avx] safe fn _mm256_sub_ps(a: __m256, b: __m256) -> __m256;
+        [avx2] safe fn _mm256_subs_epi16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_subs_epi8(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_subs_epu16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_subs_epu8(a: __m256i, b: __m256i) -> __m256i;
+        [avx] safe fn _mm256_testc_pd(a: __m256d, b: __m256d) -> i32;
+        [avx] safe fn _mm256_testc_ps(a: __m256, b: __m256) -> i32;
+        [avx] safe fn _mm256_testc_si256(a: __m256i, b: __m256i) -> i32;
+        [avx] safe fn _mm256_testnzc_pd(a: __m256d, b: __m256d) -> i32;
+        [avx] safe fn _mm256_testnzc_ps(a: __m256, b: __m256) -> i32;
+        [avx] safe fn _mm256_testnzc_si256(a: __m256i, b: __m256i) -> i32;
+        [avx] safe fn _mm256_testz_pd(a: __m256d, b: __m256d) -> i32;
+        [avx] safe fn _mm256_testz_ps(a: __m256, b: __m256) -> i32;
+        [avx] safe fn _mm256_testz_si256(a: __m256i, b: __m256i) -> i32;
+        [avx] safe fn _mm256_undefined_pd() -> __m256d;
+        [avx] safe fn _mm256_undefined_ps() -> __m256;
+        [avx] safe fn _mm256_undefined_si256() -> __m256i;
+        [avx2] safe fn _mm256_unpackhi_epi16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_unpackhi_epi32(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_unpackhi_epi64(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_unpackhi_epi8(a: __m256i, b: __m256i) -> __m256i;
+        [avx] safe fn _mm256_unpackhi_pd(a: __m256d, b: __m256d) -> __m256d;
+        [avx] safe fn _mm256_unpackhi_ps(a: __m256, b: __m256) -> __m256;
+        [avx2] safe fn _mm256_unpacklo_epi16(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_unpacklo_epi32(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_unpacklo_epi64(a: __m256i, b: __m256i) -> __m256i;
+        [avx2] safe fn _mm256_unpacklo_epi8(a: __m256i, b: __m256i) -> __m256i;
+        [avx] safe fn _mm256_unpacklo_pd(a: __m256d, b: __m256d) -> __m256d;
+        [avx] safe fn _mm256_unpacklo_ps(a: __m256, b: __m256) -> __m256;
+        [avx] safe fn _mm256_xor_pd(a: __m256d, b: __m256d) -> __m256d;
+        [avx] safe fn _mm256_xor_ps(a: __m256, b: __m256) -> __m256;
+        [avx2] safe fn _mm256_xor_si256(a: __m256i, b: __m256i) -> __m256i;
+        [avx] safe fn _mm256_zeroall();
+        [avx] safe fn _mm256_zeroupper();
+        [avx] safe fn _mm256_zextpd128_pd256(a: __m128d) -> __m256d;
+        [avx] safe fn _mm256_zextps128_ps256(a: __m128) -> __m256;
+        [avx] safe fn _mm256_zextsi128_si256(a: __m128i) -> __m256i;
+        [ssse3] safe fn _mm_abs_epi16(a: __m128i) -> __m128i;
+        [ssse3] safe fn _mm_abs_epi32(a: __m128i) -> __m128i;
+        [ssse3] safe fn _mm_abs_epi8(a: __m128i) -> __m128i;
+        [sse2] safe fn _mm_add_epi16(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_add_epi32(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_add_epi64(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_add_epi8(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_add_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_add_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_add_sd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_add_ss(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_adds_epi16(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_adds_epi8(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_adds_epu16(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_adds_epu8(a: __m128i, b: __m128i) -> __m128i;
+        [sse3] safe fn _mm_addsub_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse3] safe fn _mm_addsub_ps(a: __m128, b: __m128) -> __m128;
+        [aes] safe fn _mm_aesdec_si128(a: __m128i, round_key: __m128i) -> __m128i;
+        [aes] safe fn _mm_aesdeclast_si128(a: __m128i, round_key: __m128i) -> __m128i;
+        [aes] safe fn _mm_aesenc_si128(a: __m128i, round_key: __m128i) -> __m128i;
+        [aes] safe fn _mm_aesenclast_si128(a: __m128i, round_key: __m128i) -> __m128i;
+        [aes] safe fn _mm_aesimc_si128(a: __m128i) -> __m128i;
+        [aes] safe fn _mm_aeskeygenassist_si128<const IMM8: i32>(a: __m128i) -> __m128i;
+        [ssse3] safe fn _mm_alignr_epi8<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_and_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_and_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_and_si128(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_andnot_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_andnot_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_andnot_si128(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_avg_epu16(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_avg_epu8(a: __m128i, b: __m128i) -> __m128i;
+        [sse41] safe fn _mm_blend_epi16<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i;
+        [avx2] safe fn _mm_blend_epi32<const IMM4: i32>(a: __m128i, b: __m128i) -> __m128i;
+        [sse41] safe fn _mm_blend_pd<const IMM2: i32>(a: __m128d, b: __m128d) -> __m128d;
+        [sse41] safe fn _mm_blend_ps<const IMM4: i32>(a: __m128, b: __m128) -> __m128;
+        [sse41] safe fn _mm_blendv_epi8(a: __m128i, b: __m128i, mask: __m128i) -> __m128i;
+        [sse41] safe fn _mm_blendv_pd(a: __m128d, b: __m128d, mask: __m128d) -> __m128d;
+        [sse41] safe fn _mm_blendv_ps(a: __m128, b: __m128, mask: __m128) -> __m128;
+        [avx] safe fn _mm_broadcast_ss(f: &f32) -> __m128;
+        [avx2] safe fn _mm_broadcastb_epi8(a: __m128i) -> __m128i;
+        [avx2] safe fn _mm_broadcastd_epi32(a: __m128i) -> __m128i;
+        [avx2] safe fn _mm_broadcastq_epi64(a: __m128i) -> __m128i;
+        [avx2] safe fn _mm_broadcastsd_pd(a: __m128d) -> __m128d;
+        [avx2] safe fn _mm_broadcastss_ps(a: __m128) -> __m128;
+        [avx2] safe fn _mm_broadcastw_epi16(a: __m128i) -> __m128i;
+        [sse2] safe fn _mm_bslli_si128<const IMM8: i32>(a: __m128i) -> __m128i;
+        [sse2] safe fn _mm_bsrli_si128<const IMM8: i32>(a: __m128i) -> __m128i;
+        [sse2] safe fn _mm_castpd_ps(a: __m128d) -> __m128;
+        [sse2] safe fn _mm_castpd_si128(a: __m128d) -> __m128i;
+        [sse2] safe fn _mm_castps_pd(a: __m128) -> __m128d;
+        [sse2] safe fn _mm_castps_si128(a: __m128) -> __m128i;
+        [sse2] safe fn _mm_castsi128_pd(a: __m128i) -> __m128d;
+        [sse2] safe fn _mm_castsi128_ps(a: __m128i) -> __m128;
+        [sse41] safe fn _mm_ceil_pd(a: __m128d) -> __m128d;
+        [sse41] safe fn _mm_ceil_ps(a: __m128) -> __m128;
+        [sse41] safe fn _mm_ceil_sd(a: __m128d, b: __m128d) -> __m128d;
+        [sse41] safe fn _mm_ceil_ss(a: __m128, b: __m128) -> __m128;
+        [sse2] unsafe fn _mm_clflush(p: *const u8);
+        [pclmulqdq] safe fn _mm_clmulepi64_si128<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i;
+        [avx] safe fn _mm_cmp_pd<const IMM5: i32>(a: __m128d, b: __m128d) -> __m128d;
+        [avx] safe fn _mm_cmp_ps<const IMM5: i32>(a: __m128, b: __m128) -> __m128;
+        [avx] safe fn _mm_cmp_sd<const IMM5: i32>(a: __m128d, b: __m128d) -> __m128d;
+        [avx] safe fn _mm_cmp_ss<const IMM5: i32>(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_cmpeq_epi16(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_cmpeq_epi32(a: __m128i, b: __m128i) -> __m128i;
+        [sse41] safe fn _mm_cmpeq_epi64(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_cmpeq_epi8(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_cmpeq_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmpeq_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_cmpeq_sd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmpeq_ss(a: __m128, b: __m128) -> __m128;
+        [sse42] safe fn _mm_cmpestra<const IMM8: i32>(a: __m128i, la: i32, b: __m128i, lb: i32) -> i32;
+        [sse42] safe fn _mm_cmpestrc<const IMM8: i32>(a: __m128i, la: i32, b: __m128i, lb: i32) -> i32;
+        [sse42] safe fn _mm_cmpestri<const IMM8: i32>(a: __m128i, la: i32, b: __m128i, lb: i32) -> i32;
+        [sse42] safe fn _mm_cmpestrm<const IMM8: i32>(a: __m128i, la: i32, b: __m128i, lb: i32) -> __m128i;
+        [sse42] safe fn _mm_cmpestro<const IMM8: i32>(a: __m128i, la: i32, b: __m128i, lb: i32) -> i32;
+        [sse42] safe fn _mm_cmpestrs<const IMM8: i32>(a: __m128i, la: i32, b: __m128i, lb: i32) -> i32;
+        [sse42] safe fn _mm_cmpestrz<const IMM8: i32>(a: __m128i, la: i32, b: __m128i, lb: i32) -> i32;
+        [sse2] safe fn _mm_cmpge_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmpge_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_cmpge_sd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmpge_ss(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_cmpgt_epi16(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_cmpgt_epi32(a: __m128i, b: __m128i) -> __m128i;
+        [sse42] safe fn _mm_cmpgt_epi64(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_cmpgt_epi8(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_cmpgt_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmpgt_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_cmpgt_sd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmpgt_ss(a: __m128, b: __m128) -> __m128;
+        [sse42] safe fn _mm_cmpistra<const IMM8: i32>(a: __m128i, b: __m128i) -> i32;
+        [sse42] safe fn _mm_cmpistrc<const IMM8: i32>(a: __m128i, b: __m128i) -> i32;
+        [sse42] safe fn _mm_cmpistri<const IMM8: i32>(a: __m128i, b: __m128i) -> i32;
+        [sse42] safe fn _mm_cmpistrm<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i;
+        [sse42] safe fn _mm_cmpistro<const IMM8: i32>(a: __m128i, b: __m128i) -> i32;
+        [sse42] safe fn _mm_cmpistrs<const IMM8: i32>(a: __m128i, b: __m128i) -> i32;
+        [sse42] safe fn _mm_cmpistrz<const IMM8: i32>(a: __m128i, b: __m128i) -> i32;
+        [sse2] safe fn _mm_cmple_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmple_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_cmple_sd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmple_ss(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_cmplt_epi16(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_cmplt_epi32(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_cmplt_epi8(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_cmplt_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmplt_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_cmplt_sd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmplt_ss(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_cmpneq_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmpneq_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_cmpneq_sd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmpneq_ss(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_cmpnge_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmpnge_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_cmpnge_sd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmpnge_ss(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_cmpngt_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmpngt_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_cmpngt_sd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmpngt_ss(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_cmpnle_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmpnle_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_cmpnle_sd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmpnle_ss(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_cmpnlt_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmpnlt_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_cmpnlt_sd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmpnlt_ss(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_cmpord_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmpord_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_cmpord_sd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmpord_ss(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_cmpunord_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmpunord_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_cmpunord_sd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_cmpunord_ss(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_comieq_sd(a: __m128d, b: __m128d) -> i32;
+        [sse] safe fn _mm_comieq_ss(a: __m128, b: __m128) -> i32;
+        [sse2] safe fn _mm_comige_sd(a: __m128d, b: __m128d) -> i32;
+        [sse] safe fn _mm_comige_ss(a: __m128, b: __m128) -> i32;
+        [sse2] safe fn _mm_comigt_sd(a: __m128d, b: __m128d) -> i32;
+        [sse] safe fn _mm_comigt_ss(a: __m128, b: __m128) -> i32;
+        [sse2] safe fn _mm_comile_sd(a: __m128d, b: __m128d) -> i32;
+        [sse] safe fn _mm_comile_ss(a: __m128, b: __m128) -> i32;
+        [sse2] safe fn _mm_comilt_sd(a: __m128d, b: __m128d) -> i32;
+        [sse] safe fn _mm_comilt_ss(a: __m128, b: __m128) -> i32;
+        [sse2] safe fn _mm_comineq_sd(a: __m128d, b: __m128d) -> i32;
+        [sse] safe fn _mm_comineq_ss(a: __m128, b: __m128) -> i32;
+        [sse42] safe fn _mm_crc32_u16(crc: u32, v: u16) -> u32;
+        [sse42] safe fn _mm_crc32_u32(crc: u32, v: u32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [sse42] safe fn _mm_crc32_u64(crc: u64, v: u64) -> u64;
+        [sse42] safe fn _mm_crc32_u8(crc: u32, v: u8) -> u32;
+        [sse] safe fn _mm_cvt_si2ss(a: __m128, b: i32) -> __m128;
+        [sse] safe fn _mm_cvt_ss2si(a: __m128) -> i32;
+        [sse41] safe fn _mm_cvtepi16_epi32(a: __m128i) -> __m128i;
+        [sse41] safe fn _mm_cvtepi16_epi64(a: __m128i) -> __m128i;
+        [sse41] safe fn _mm_cvtepi32_epi64(a: __m128i) -> __m128i;
+        [sse2] safe fn _mm_cvtepi32_pd(a: __m128i) -> __m128d;
+        [sse2] safe fn _mm_cvtepi32_ps(a: __m128i) -> __m128;
+        [sse41] safe fn _mm_cvtepi8_epi16(a: __m128i) -> __m128i;
+        [sse41] safe fn _mm_cvtepi8_epi32(a: __m128i) -> __m128i;
+        [sse41] safe fn _mm_cvtepi8_epi64(a: __m128i) -> __m128i;
+        [sse41] safe fn _mm_cvtepu16_epi32(a: __m128i) -> __m128i;
+        [sse41] safe fn _mm_cvtepu16_epi64(a: __m128i) -> __m128i;
+        [sse41] safe fn _mm_cvtepu32_epi64(a: __m128i) -> __m128i;
+        [sse41] safe fn _mm_cvtepu8_epi16(a: __m128i) -> __m128i;
+        [sse41] safe fn _mm_cvtepu8_epi32(a: __m128i) -> __m128i;
+        [sse41] safe fn _mm_cvtepu8_epi64(a: __m128i) -> __m128i;
+        [sse2] safe fn _mm_cvtpd_epi32(a: __m128d) -> __m128i;
+        [sse2] safe fn _mm_cvtpd_ps(a: __m128d) -> __m128;
+        [sse2] safe fn _mm_cvtps_epi32(a: __m128) -> __m128i;
+        [sse2] safe fn _mm_cvtps_pd(a: __m128) -> __m128d;
+        [sse2] safe fn _mm_cvtsd_f64(a: __m128d) -> f64;
+        [sse2] safe fn _mm_cvtsd_si32(a: __m128d) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [sse2] safe fn _mm_cvtsd_si64(a: __m128d) -> i64;
+        #[cfg(target_arch = "x86_64")]
+        [sse2] safe fn _mm_cvtsd_si64x(a: __m128d) -> i64;
+        [sse2] safe fn _mm_cvtsd_ss(a: __m128, b: __m128d) -> __m128;
+        [sse2] safe fn _mm_cvtsi128_si32(a: __m128i) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [sse2] safe fn _mm_cvtsi128_si64(a: __m128i) -> i64;
+        #[cfg(target_arch = "x86_64")]
+        [sse2] safe fn _mm_cvtsi128_si64x(a: __m128i) -> i64;
+        [sse2] safe fn _mm_cvtsi32_sd(a: __m128d, b: i32) -> __m128d;
+        [sse2] safe fn _mm_cvtsi32_si128(a: i32) -> __m128i;
+        [sse] safe fn _mm_cvtsi32_ss(a: __m128, b: i32) -> __m128;
+        #[cfg(target_arch = "x86_64")]
+        [sse2] safe fn _mm_cvtsi64_sd(a: __m128d, b: i64) -> __m128d;
+        #[cfg(target_arch = "x86_64")]
+        [sse2] safe fn _mm_cvtsi64_si128(a: i64) -> __m128i;
+        #[cfg(target_arch = "x86_64")]
+        [sse] safe fn _mm_cvtsi64_ss(a: __m128, b: i64) -> __m128;
+        #[cfg(target_arch = "x86_64")]
+        [sse2] safe fn _mm_cvtsi64x_sd(a: __m128d, b: i64) -> __m128d;
+        #[cfg(target_arch = "x86_64")]
+        [sse2] safe fn _mm_cvtsi64x_si128(a: i64) -> __m128i;
+        [sse] safe fn _mm_cvtss_f32(a: __m128) -> f32;
+        [sse2] safe fn _mm_cvtss_sd(a: __m128d, b: __m128) -> __m128d;
+        [sse] safe fn _mm_cvtss_si32(a: __m128) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [sse] safe fn _mm_cvtss_si64(a: __m128) -> i64;
+        [sse] safe fn _mm_cvtt_ss2si(a: __m128) -> i32;
+        [sse2] safe fn _mm_cvttpd_epi32(a: __m128d) -> __m128i;
+        [sse2] safe fn _mm_cvttps_epi32(a: __m128) -> __m128i;
+        [sse2] safe fn _mm_cvttsd_si32(a: __m128d) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [sse2] safe fn _mm_cvttsd_si64(a: __m128d) -> i64;
+        #[cfg(target_arch = "x86_64")]
+        [sse2] safe fn _mm_cvttsd_si64x(a: __m128d) -> i64;
+        [sse] safe fn _mm_cvttss_si32(a: __m128) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [sse] safe fn _mm_cvttss_si64(a: __m128) -> i64;
+        [sse2] safe fn _mm_div_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_div_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_div_sd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_div_ss(a: __m128, b: __m128) -> __m128;
+        [sse41] safe fn _mm_dp_pd<const IMM8: i32>(a: __m128d, b: __m128d) -> __m128d;
+        [sse41] safe fn _mm_dp_ps<const IMM8: i32>(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_extract_epi16<const IMM8: i32>(a: __m128i) -> i32;
+        [sse41] safe fn _mm_extract_epi32<const IMM8: i32>(a: __m128i) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [sse41] safe fn _mm_extract_epi64<const IMM1: i32>(a: __m128i) -> i64;
+        [sse41] safe fn _mm_extract_epi8<const IMM8: i32>(a: __m128i) -> i32;
+        [sse41] safe fn _mm_extract_ps<const IMM8: i32>(a: __m128) -> i32;
+        [sse4a] safe fn _mm_extract_si64(x: __m128i, y: __m128i) -> __m128i;
+        [sse41] safe fn _mm_floor_pd(a: __m128d) -> __m128d;
+        [sse41] safe fn _mm_floor_ps(a: __m128) -> __m128;
+        [sse41] safe fn _mm_floor_sd(a: __m128d, b: __m128d) -> __m128d;
+        [sse41] safe fn _mm_floor_ss(a: __m128, b: __m128) -> __m128;
+        [fma] safe fn _mm_fmadd_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [fma] safe fn _mm_fmadd_ps(a: __m128, b: __m128, c: __m128) -> __m128;
+        [fma] safe fn _mm_fmadd_sd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [fma] safe fn _mm_fmadd_ss(a: __m128, b: __m128, c: __m128) -> __m128;
+        [fma] safe fn _mm_fmaddsub_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [fma] safe fn _mm_fmaddsub_ps(a: __m128, b: __m128, c: __m128) -> __m128;
+        [fma] safe fn _mm_fmsub_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [fma] safe fn _mm_fmsub_ps(a: __m128, b: __m128, c: __m128) -> __m128;
+        [fma] safe fn _mm_fmsub_sd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [fma] safe fn _mm_fmsub_ss(a: __m128, b: __m128, c: __m128) -> __m128;
+        [fma] safe fn _mm_fmsubadd_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [fma] safe fn _mm_fmsubadd_ps(a: __m128, b: __m128, c: __m128) -> __m128;
+        [fma] safe fn _mm_fnmadd_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [fma] safe fn _mm_fnmadd_ps(a: __m128, b: __m128, c: __m128) -> __m128;
+        [fma] safe fn _mm_fnmadd_sd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [fma] safe fn _mm_fnmadd_ss(a: __m128, b: __m128, c: __m128) -> __m128;
+        [fma] safe fn _mm_fnmsub_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [fma] safe fn _mm_fnmsub_ps(a: __m128, b: __m128, c: __m128) -> __m128;
+        [fma] safe fn _mm_fnmsub_sd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [fma] safe fn _mm_fnmsub_ss(a: __m128, b: __m128, c: __m128) -> __m128;
+        [ssse3] safe fn _mm_hadd_epi16(a: __m128i, b: __m128i) -> __m128i;
+        [ssse3] safe fn _mm_hadd_epi32(a: __m128i, b: __m128i) -> __m128i;
+        [sse3] safe fn _mm_hadd_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse3] safe fn _mm_hadd_ps(a: __m128, b: __m128) -> __m128;
+        [ssse3] safe fn _mm_hadds_epi16(a: __m128i, b: __m128i) -> __m128i;
+        [ssse3] safe fn _mm_hsub_epi16(a: __m128i, b: __m128i) -> __m128i;
+        [ssse3] safe fn _mm_hsub_epi32(a: __m128i, b: __m128i) -> __m128i;
+        [sse3] safe fn _mm_hsub_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse3] safe fn _mm_hsub_ps(a: __m128, b: __m128) -> __m128;
+        [ssse3] safe fn _mm_hsubs_epi16(a: __m128i, b: __m128i) -> __m128i;
+        [avx2] unsafe fn _mm_i32gather_epi32<const SCALE: i32>(slice: *const i32, offsets: __m128i) -> __m128i;
+        [avx2] unsafe fn _mm_i32gather_epi64<const SCALE: i32>(slice: *const i64, offsets: __m128i) -> __m128i;
+        [avx2] unsafe fn _mm_i32gather_pd<const SCALE: i32>(slice: *const f64, offsets: __m128i) -> __m128d;
+        [avx2] unsafe fn _mm_i32gather_ps<const SCALE: i32>(slice: *const f32, offsets: __m128i) -> __m128;
+        [avx2] unsafe fn _mm_i64gather_epi32<const SCALE: i32>(slice: *const i32, offsets: __m128i) -> __m128i;
+        [avx2] unsafe fn _mm_i64gather_epi64<const SCALE: i32>(slice: *const i64, offsets: __m128i) -> __m128i;
+        [avx2] unsafe fn _mm_i64gather_pd<const SCALE: i32>(slice: *const f64, offsets: __m128i) -> __m128d;
+        [avx2] unsafe fn _mm_i64gather_ps<const SCALE: i32>(slice: *const f32, offsets: __m128i) -> __m128;
+        [sse2] safe fn _mm_insert_epi16<const IMM8: i32>(a: __m128i, i: i32) -> __m128i;
+        [sse41] safe fn _mm_insert_epi32<const IMM8: i32>(a: __m128i, i: i32) -> __m128i;
+        #[cfg(target_arch = "x86_64")]
+        [sse41] safe fn _mm_insert_epi64<const IMM1: i32>(a: __m128i, i: i64) -> __m128i;
+        [sse41] safe fn _mm_insert_epi8<const IMM8: i32>(a: __m128i, i: i32) -> __m128i;
+        [sse41] safe fn _mm_insert_ps<const IMM8: i32>(a: __m128, b: __m128) -> __m128;
+        [sse4a] safe fn _mm_insert_si64(x: __m128i, y: __m128i) -> __m128i;
+        [sse3] unsafe fn _mm_lddqu_si128(mem_addr: *const __m128i) -> __m128i;
+        [sse2] safe fn _mm_lfence();
+        [sse2] unsafe fn _mm_load1_pd(mem_addr: *const f64) -> __m128d;
+        [sse] unsafe fn _mm_load1_ps(p: *const f32) -> __m128;
+        [sse2] unsafe fn _mm_load_pd(mem_addr: *const f64) -> __m128d;
+        [sse2] unsafe fn _mm_load_pd1(mem_addr: *const f64) -> __m128d;
+        [sse] unsafe fn _mm_load_ps(p: *const f32) -> __m128;
+        [sse] unsafe fn _mm_load_ps1(p: *const f32) -> __m128;
+        [sse2] unsafe fn _mm_load_sd(mem_addr: *const f64) -> __m128d;
+        [sse2] unsafe fn _mm_load_si128(mem_addr: *const __m128i) -> __m128i;
+        [sse] unsafe fn _mm_load_ss(p: *const f32) -> __m128;
+        [sse3] unsafe fn _mm_loaddup_pd(mem_addr: *const f64) -> __m128d;
+        [sse2] unsafe fn _mm_loadh_pd(a: __m128d, mem_addr: *const f64) -> __m128d;
+        [sse2] unsafe fn _mm_loadl_epi64(mem_addr: *const __m128i) -> __m128i;
+        [sse2] unsafe fn _mm_loadl_pd(a: __m128d, mem_addr: *const f64) -> __m128d;
+        [sse2] unsafe fn _mm_loadr_pd(mem_addr: *const f64) -> __m128d;
+        [sse] unsafe fn _mm_loadr_ps(p: *const f32) -> __m128;
+        [sse2] unsafe fn _mm_loadu_pd(mem_addr: *const f64) -> __m128d;
+        [sse] unsafe fn _mm_loadu_ps(p: *const f32) -> __m128;
+        [sse2] unsafe fn _mm_loadu_si128(mem_addr: *const __m128i) -> __m128i;
+        [sse2] unsafe fn _mm_loadu_si64(mem_addr: *const u8) -> __m128i;
+        [sse2] safe fn _mm_madd_epi16(a: __m128i, b: __m128i) -> __m128i;
+        [ssse3] safe fn _mm_maddubs_epi16(a: __m128i, b: __m128i) -> __m128i;
+        [avx2] unsafe fn _mm_mask_i32gather_epi32<const SCALE: i32>(src: __m128i, slice: *const i32, offsets: __m128i, mask: __m128i) -> __m128i;
+        [avx2] unsafe fn _mm_mask_i32gather_epi64<const SCALE: i32>(src: __m128i, slice: *const i64, offsets: __m128i, mask: __m128i) -> __m128i;
+        [avx2] unsafe fn _mm_mask_i32gather_pd<const SCALE: i32>(src: __m128d, slice: *const f64, offsets: __m128i, mask: __m128d) -> __m128d;
+        [avx2] unsafe fn _mm_mask_i32gather_ps<const SCALE: i32>(src: __m128, slice: *const f32, offsets: __m128i, mask: __m128) -> __m128;
+        [avx2] unsafe fn _mm_mask_i64gather_epi32<const SCALE: i32>(src: __m128i, slice: *const i32, offsets: __m128i, mask: __m128i) -> __m128i;
+        [avx2] unsafe fn _mm_mask_i64gather_epi64<const SCALE: i32>(src: __m128i, slice: *const i64, offsets: __m128i, mask: __m128i) -> __m128i;
+        [avx2] unsafe fn _mm_mask_i64gather_pd<const SCALE: i32>(src: __m128d, slice: *const f64, offsets: __m128i, mask: __m128d) -> __m128d;
+        [avx2] unsafe fn _mm_mask_i64gather_ps<const SCALE: i32>(src: __m128, slice: *const f32, offsets: __m128i, mask: __m128) -> __m128;
+        [avx2] unsafe fn _mm_maskload_epi32(mem_addr: *const i32, mask: __m128i) -> __m128i;
+        [avx2] unsafe fn _mm_maskload_epi64(mem_addr: *const i64, mask: __m128i) -> __m128i;
+        [avx] unsafe fn _mm_maskload_pd(mem_addr: *const f64, mask: __m128i) -> __m128d;
+        [avx] unsafe fn _mm_maskload_ps(mem_addr: *const f32, mask: __m128i) -> __m128;
+        [sse2] unsafe fn _mm_maskmoveu_si128(a: __m128i, mask: __m128i, mem_addr: *mut i8);
+        [avx2] unsafe fn _mm_maskstore_epi32(mem_addr: *mut i32, mask: __m128i, a: __m128i);
+        [avx2] unsafe fn _mm_maskstore_epi64(mem_addr: *mut i64, mask: __m128i, a: __m128i);
+        [avx] unsafe fn _mm_maskstore_pd(mem_addr: *mut f64, mask: __m128i, a: __m128d);
+        [avx] unsafe fn _mm_maskstore_ps(mem_addr: *mut f32, mask: __m128i, a: __m128);
+        [sse2] safe fn _mm_max_epi16(a: __m128i, b: __m128i) -> __m128i;
+        [sse41] safe fn _mm_max_epi32(a: __m128i, b: __m128i) -> __m128i;
+        [sse41] safe fn _mm_max_epi8(a: __m128i, b: __m128i) -> __m128i;
+        [sse41] safe fn _mm_max_epu16(a: __m128i, b: __m128i) -> __m128i;
+        [sse41] safe fn _mm_max_epu32(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_max_epu8(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_max_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_max_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_max_sd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_max_ss(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_mfence();
+        [sse2] safe fn _mm_min_epi16(a: __m128i, b: __m128i) -> __m128i;
+        [sse41] safe fn _mm_min_epi32(a: __m128i, b: __m128i) -> __m128i;
+        [sse41] safe fn _mm_min_epi8(a: __m128i, b: __m128i) -> __m128i;
+        [sse41] safe fn _mm_min_epu16(a: __m128i, b: __m128i) -> __m128i;
+        [sse41] safe fn _mm_min_epu32(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_min_epu8(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_min_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_min_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_min_sd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_min_ss(a: __m128, b: __m128) -> __m128;
+        [sse41] safe fn _mm_minpos_epu16(a: __m128i) -> __m128i;
+        [sse2] safe fn _mm_move_epi64(a: __m128i) -> __m128i;
+        [sse2] safe fn _mm_move_sd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_move_ss(a: __m128, b: __m128) -> __m128;
+        [sse3] safe fn _mm_movedup_pd(a: __m128d) -> __m128d;
+        [sse3] safe fn _mm_movehdup_ps(a: __m128) -> __m128;
+        [sse] safe fn _mm_movehl_ps(a: __m128, b: __m128) -> __m128;
+        [sse3] safe fn _mm_moveldup_ps(a: __m128) -> __m128;
+        [sse] safe fn _mm_movelh_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_movemask_epi8(a: __m128i) -> i32;
+        [sse2] safe fn _mm_movemask_pd(a: __m128d) -> i32;
+        [sse] safe fn _mm_movemask_ps(a: __m128) -> i32;
+        [sse41] safe fn _mm_mpsadbw_epu8<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i;
+        [sse41] safe fn _mm_mul_epi32(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_mul_epu32(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_mul_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_mul_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_mul_sd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_mul_ss(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_mulhi_epi16(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_mulhi_epu16(a: __m128i, b: __m128i) -> __m128i;
+        [ssse3] safe fn _mm_mulhrs_epi16(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_mullo_epi16(a: __m128i, b: __m128i) -> __m128i;
+        [sse41] safe fn _mm_mullo_epi32(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_or_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_or_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_or_si128(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_packs_epi16(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_packs_epi32(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_packus_epi16(a: __m128i, b: __m128i) -> __m128i;
+        [sse41] safe fn _mm_packus_epi32(a: __m128i, b: __m128i) -> __m128i;
+        [avx] safe fn _mm_permute_pd<const IMM2: i32>(a: __m128d) -> __m128d;
+        [avx] safe fn _mm_permute_ps<const IMM8: i32>(a: __m128) -> __m128;
+        [avx] safe fn _mm_permutevar_pd(a: __m128d, b: __m128i) -> __m128d;
+        [avx] safe fn _mm_permutevar_ps(a: __m128, b: __m128i) -> __m128;
+        [sse] safe fn _mm_prefetch<const STRATEGY: i32>(p: *const i8);
+        [sse] safe fn _mm_rcp_ps(a: __m128) -> __m128;
+        [sse] safe fn _mm_rcp_ss(a: __m128) -> __m128;
+        [sse41] safe fn _mm_round_pd<const ROUNDING: i32>(a: __m128d) -> __m128d;
+        [sse41] safe fn _mm_round_ps<const ROUNDING: i32>(a: __m128) -> __m128;
+        [sse41] safe fn _mm_round_sd<const ROUNDING: i32>(a: __m128d, b: __m128d) -> __m128d;
+        [sse41] safe fn _mm_round_ss<const ROUNDING: i32>(a: __m128, b: __m128) -> __m128;
+        [sse] safe fn _mm_rsqrt_ps(a: __m128) -> __m128;
+        [sse] safe fn _mm_rsqrt_ss(a: __m128) -> __m128;
+        [sse2] safe fn _mm_sad_epu8(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_set1_epi16(a: i16) -> __m128i;
+        [sse2] safe fn _mm_set1_epi32(a: i32) -> __m128i;
+        [sse2] safe fn _mm_set1_epi64x(a: i64) -> __m128i;
+        [sse2] safe fn _mm_set1_epi8(a: i8) -> __m128i;
+        [sse2] safe fn _mm_set1_pd(a: f64) -> __m128d;
+        [sse] safe fn _mm_set1_ps(a: f32) -> __m128;
+        [sse2] safe fn _mm_set_epi16(e7: i16, e6: i16, e5: i16, e4: i16, e3: i16, e2: i16, e1: i16, e0: i16) -> __m128i;
+        [sse2] safe fn _mm_set_epi32(e3: i32, e2: i32, e1: i32, e0: i32) -> __m128i;
+        [sse2] safe fn _mm_set_epi64x(e1: i64, e0: i64) -> __m128i;
+        [sse2] safe fn _mm_set_epi8(e15: i8, e14: i8, e13: i8, e12: i8, e11: i8, e10: i8, e9: i8, e8: i8, e7: i8, e6: i8, e5: i8, e4: i8, e3: i8, e2: i8, e1: i8, e0: i8) -> __m128i;
+        [sse2] safe fn _mm_set_pd(a: f64, b: f64) -> __m128d;
+        [sse2] safe fn _mm_set_pd1(a: f64) -> __m128d;
+        [sse] safe fn _mm_set_ps(a: f32, b: f32, c: f32, d: f32) -> __m128;
+        [sse] safe fn _mm_set_ps1(a: f32) -> __m128;
+        [sse2] safe fn _mm_set_sd(a: f64) -> __m128d;
+        [sse] safe fn _mm_set_ss(a: f32) -> __m128;
+        [sse2] safe fn _mm_setr_epi16(e7: i16, e6: i16, e5: i16, e4: i16, e3: i16, e2: i16, e1: i16, e0: i16) -> __m128i;
+        [sse2] safe fn _mm_setr_epi32(e3: i32, e2: i32, e1: i32, e0: i32) -> __m128i;
+        [sse2] safe fn _mm_setr_epi8(e15: i8, e14: i8, e13: i8, e12: i8, e11: i8, e10: i8, e9: i8, e8: i8, e7: i8, e6: i8, e5: i8, e4: i8, e3: i8, e2: i8, e1: i8, e0: i8) -> __m128i;
+        [sse2] safe fn _mm_setr_pd(a: f64, b: f64) -> __m128d;
+        [sse] safe fn _mm_setr_ps(a: f32, b: f32, c: f32, d: f32) -> __m128;
+        [sse2] safe fn _mm_setzero_pd() -> __m128d;
+        [sse] safe fn _mm_setzero_ps() -> __m128;
+        [sse2] safe fn _mm_setzero_si128() -> __m128i;
+        [sse] safe fn _mm_sfence();
+        [sha] safe fn _mm_sha1msg1_epu32(a: __m128i, b: __m128i) -> __m128i;
+        [sha] safe fn _mm_sha1msg2_epu32(a: __m128i, b: __m128i) -> __m128i;
+        [sha] safe fn _mm_sha1nexte_epu32(a: __m128i, b: __m128i) -> __m128i;
+        [sha] safe fn _mm_sha1rnds4_epu32<const FUNC: i32>(a: __m128i, b: __m128i) -> __m128i;
+        [sha] safe fn _mm_sha256msg1_epu32(a: __m128i, b: __m128i) -> __m128i;
+        [sha] safe fn _mm_sha256msg2_epu32(a: __m128i, b: __m128i) -> __m128i;
+        [sha] safe fn _mm_sha256rnds2_epu32(a: __m128i, b: __m128i, k: __m128i) -> __m128i;
+        [sse2] safe fn _mm_shuffle_epi32<const IMM8: i32>(a: __m128i) -> __m128i;
+        [ssse3] safe fn _mm_shuffle_epi8(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_shuffle_pd<const MASK: i32>(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_shuffle_ps<const MASK: i32>(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_shufflehi_epi16<const IMM8: i32>(a: __m128i) -> __m128i;
+        [sse2] safe fn _mm_shufflelo_epi16<const IMM8: i32>(a: __m128i) -> __m128i;
+        [ssse3] safe fn _mm_sign_epi16(a: __m128i, b: __m128i) -> __m128i;
+        [ssse3] safe fn _mm_sign_epi32(a: __m128i, b: __m128i) -> __m128i;
+        [ssse3] safe fn _mm_sign_epi8(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_sll_epi16(a: __m128i, count: __m128i) -> __m128i;
+        [sse2] safe fn _mm_sll_epi32(a: __m128i, count: __m128i) -> __m128i;
+        [sse2] safe fn _mm_sll_epi64(a: __m128i, count: __m128i) -> __m128i;
+        [sse2] safe fn _mm_slli_epi16<const IMM8: i32>(a: __m128i) -> __m128i;
+        [sse2] safe fn _mm_slli_epi32<const IMM8: i32>(a: __m128i) -> __m128i;
+        [sse2] safe fn _mm_slli_epi64<const IMM8: i32>(a: __m128i) -> __m128i;
+        [sse2] safe fn _mm_slli_si128<const IMM8: i32>(a: __m128i) -> __m128i;
+        [avx2] safe fn _mm_sllv_epi32(a: __m128i, count: __m128i) -> __m128i;
+        [avx2] safe fn _mm_sllv_epi64(a: __m128i, count: __m128i) -> __m128i;
+        [sse2] safe fn _mm_sqrt_pd(a: __m128d) -> __m128d;
+        [sse] safe fn _mm_sqrt_ps(a: __m128) -> __m128;
+        [sse2] safe fn _mm_sqrt_sd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_sqrt_ss(a: __m128) -> __m128;
+        [sse2] safe fn _mm_sra_epi16(a: __m128i, count: __m128i) -> __m128i;
+        [sse2] safe fn _mm_sra_epi32(a: __m128i, count: __m128i) -> __m128i;
+        [sse2] safe fn _mm_srai_epi16<const IMM8: i32>(a: __m128i) -> __m128i;
+        [sse2] safe fn _mm_srai_epi32<const IMM8: i32>(a: __m128i) -> __m128i;
+        [avx2] safe fn _mm_srav_epi32(a: __m128i, count: __m128i) -> __m128i;
+        [sse2] safe fn _mm_srl_epi16(a: __m128i, count: __m128i) -> __m128i;
+        [sse2] safe fn _mm_srl_epi32(a: __m128i, count: __m128i) -> __m128i;
+        [sse2] safe fn _mm_srl_epi64(a: __m128i, count: __m128i) -> __m128i;
+        [sse2] safe fn _mm_srli_epi16<const IMM8: i32>(a: __m128i) -> __m128i;
+        [sse2] safe fn _mm_srli_epi32<const IMM8: i32>(a: __m128i) -> __m128i;
+        [sse2] safe fn _mm_srli_epi64<const IMM8: i32>(a: __m128i) -> __m128i;
+        [sse2] safe fn _mm_srli_si128<const IMM8: i32>(a: __m128i) -> __m128i;
+        [avx2] safe fn _mm_srlv_epi32(a: __m128i, count: __m128i) -> __m128i;
+        [avx2] safe fn _mm_srlv_epi64(a: __m128i, count: __m128i) -> __m128i;
+        [sse2] unsafe fn _mm_store1_pd(mem_addr: *mut f64, a: __m128d);
+        [sse] unsafe fn _mm_store1_ps(p: *mut f32, a: __m128);
+        [sse2] unsafe fn _mm_store_pd(mem_addr: *mut f64, a: __m128d);
+        [sse2] unsafe fn _mm_store_pd1(mem_addr: *mut f64, a: __m128d);
+        [sse] unsafe fn _mm_store_ps(p: *mut f32, a: __m128);
+        [sse] unsafe fn _mm_store_ps1(p: *mut f32, a: __m128);
+        [sse2] unsafe fn _mm_store_sd(mem_addr: *mut f64, a: __m128d);
+        [sse2] unsafe fn _mm_store_si128(mem_addr: *mut __m128i, a: __m128i);
+        [sse] unsafe fn _mm_store_ss(p: *mut f32, a: __m128);
+        [sse2] unsafe fn _mm_storeh_pd(mem_addr: *mut f64, a: __m128d);
+        [sse2] unsafe fn _mm_storel_epi64(mem_addr: *mut __m128i, a: __m128i);
+        [sse2] unsafe fn _mm_storel_pd(mem_addr: *mut f64, a: __m128d);
+        [sse2] unsafe fn _mm_storer_pd(mem_addr: *mut f64, a: __m128d);
+        [sse] unsafe fn _mm_storer_ps(p: *mut f32, a: __m128);
+        [sse2] unsafe fn _mm_storeu_pd(mem_addr: *mut f64, a: __m128d);
+        [sse] unsafe fn _mm_storeu_ps(p: *mut f32, a: __m128);
+        [sse2] unsafe fn _mm_storeu_si128(mem_addr: *mut __m128i, a: __m128i);
+        [sse2] unsafe fn _mm_stream_pd(mem_addr: *mut f64, a: __m128d);
+        [sse] unsafe fn _mm_stream_ps(mem_addr: *mut f32, a: __m128);
+        [sse4a] unsafe fn _mm_stream_sd(p: *mut f64, a: __m128d);
+        [sse2] unsafe fn _mm_stream_si128(mem_addr: *mut __m128i, a: __m128i);
+        [sse2] unsafe fn _mm_stream_si32(mem_addr: *mut i32, a: i32);
+        #[cfg(target_arch = "x86_64")]
+        [sse2] unsafe fn _mm_stream_si64(mem_addr: *mut i64, a: i64);
+        [sse4a] unsafe fn _mm_stream_ss(p: *mut f32, a: __m128);
+        [sse2] safe fn _mm_sub_epi16(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_sub_epi32(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_sub_epi64(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_sub_epi8(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_sub_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_sub_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_sub_sd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_sub_ss(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_subs_epi16(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_subs_epi8(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_subs_epu16(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_subs_epu8(a: __m128i, b: __m128i) -> __m128i;
+        [sse41] safe fn _mm_test_all_ones(a: __m128i) -> i32;
+        [sse41] safe fn _mm_test_all_zeros(a: __m128i, mask: __m128i) -> i32;
+        [sse41] safe fn _mm_test_mix_ones_zeros(a: __m128i, mask: __m128i) -> i32;
+        [avx] safe fn _mm_testc_pd(a: __m128d, b: __m128d) -> i32;
+        [avx] safe fn _mm_testc_ps(a: __m128, b: __m128) -> i32;
+        [sse41] safe fn _mm_testc_si128(a: __m128i, mask: __m128i) -> i32;
+        [avx] safe fn _mm_testnzc_pd(a: __m128d, b: __m128d) -> i32;
+        [avx] safe fn _mm_testnzc_ps(a: __m128, b: __m128) -> i32;
+        [sse41] safe fn _mm_testnzc_si128(a: __m128i, mask: __m128i) -> i32;
+        [avx] safe fn _mm_testz_pd(a: __m128d, b: __m128d) -> i32;
+        [avx] safe fn _mm_testz_ps(a: __m128, b: __m128) -> i32;
+        [sse41] safe fn _mm_testz_si128(a: __m128i, mask: __m128i) -> i32;
+        [bmi1] safe fn _mm_tzcnt_32(x: u32) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [bmi1] safe fn _mm_tzcnt_64(x: u64) -> i64;
+        [sse2] safe fn _mm_ucomieq_sd(a: __m128d, b: __m128d) -> i32;
+        [sse] safe fn _mm_ucomieq_ss(a: __m128, b: __m128) -> i32;
+        [sse2] safe fn _mm_ucomige_sd(a: __m128d, b: __m128d) -> i32;
+        [sse] safe fn _mm_ucomige_ss(a: __m128, b: __m128) -> i32;
+        [sse2] safe fn _mm_ucomigt_sd(a: __m128d, b: __m128d) -> i32;
+        [sse] safe fn _mm_ucomigt_ss(a: __m128, b: __m128) -> i32;
+        [sse2] safe fn _mm_ucomile_sd(a: __m128d, b: __m128d) -> i32;
+        [sse] safe fn _mm_ucomile_ss(a: __m128, b: __m128) -> i32;
+        [sse2] safe fn _mm_ucomilt_sd(a: __m128d, b: __m128d) -> i32;
+        [sse] safe fn _mm_ucomilt_ss(a: __m128, b: __m128) -> i32;
+        [sse2] safe fn _mm_ucomineq_sd(a: __m128d, b: __m128d) -> i32;
+        [sse] safe fn _mm_ucomineq_ss(a: __m128, b: __m128) -> i32;
+        [sse2] safe fn _mm_undefined_pd() -> __m128d;
+        [sse] safe fn _mm_undefined_ps() -> __m128;
+        [sse2] safe fn _mm_undefined_si128() -> __m128i;
+        [sse2] safe fn _mm_unpackhi_epi16(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_unpackhi_epi32(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_unpackhi_epi64(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_unpackhi_epi8(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_unpackhi_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_unpackhi_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_unpacklo_epi16(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_unpacklo_epi32(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_unpacklo_epi64(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_unpacklo_epi8(a: __m128i, b: __m128i) -> __m128i;
+        [sse2] safe fn _mm_unpacklo_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_unpacklo_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_xor_pd(a: __m128d, b: __m128d) -> __m128d;
+        [sse] safe fn _mm_xor_ps(a: __m128, b: __m128) -> __m128;
+        [sse2] safe fn _mm_xor_si128(a: __m128i, b: __m128i) -> __m128i;
+        [bmi2] safe fn _mulx_u32(a: u32, b: u32, hi: &mut u32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [bmi2] safe fn _mulx_u64(a: u64, b: u64, hi: &mut u64) -> u64;
+        [bmi2] safe fn _pdep_u32(a: u32, mask: u32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [bmi2] safe fn _pdep_u64(a: u64, mask: u64) -> u64;
+        [bmi2] safe fn _pext_u32(a: u32, mask: u32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [bmi2] safe fn _pext_u64(a: u64, mask: u64) -> u64;
+        [popcnt] safe fn _popcnt32(x: i32) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [popcnt] safe fn _popcnt64(x: i64) -> i32;
+        [rdrand] safe fn _rdrand16_step(val: &mut u16) -> i32;
+        [rdrand] safe fn _rdrand32_step(val: &mut u32) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [rdrand] safe fn _rdrand64_step(val: &mut u64) -> i32;
+        [rdseed] safe fn _rdseed16_step(val: &mut u16) -> i32;
+        [rdseed] safe fn _rdseed32_step(val: &mut u32) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [rdseed] safe fn _rdseed64_step(val: &mut u64) -> i32;
+        [tbm] safe fn _t1mskc_u32(x: u32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [tbm] safe fn _t1mskc_u64(x: u64) -> u64;
+        [bmi1] safe fn _tzcnt_u32(x: u32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [bmi1] safe fn _tzcnt_u64(x: u64) -> u64;
+        [tbm] safe fn _tzmsk_u32(x: u32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [tbm] safe fn _tzmsk_u64(x: u64) -> u64;
+        [xsave] unsafe fn _xgetbv(xcr_no: u32) -> u64;
+        [xsave] unsafe fn _xrstor(mem_addr: *const u8, rs_mask: u64);
+        #[cfg(target_arch = "x86_64")]
+        [xsave] unsafe fn _xrstor64(mem_addr: *const u8, rs_mask: u64);
+        [xsave, xsaves] unsafe fn _xrstors(mem_addr: *const u8, rs_mask: u64);
+        #[cfg(target_arch = "x86_64")]
+        [xsave, xsaves] unsafe fn _xrstors64(mem_addr: *const u8, rs_mask: u64);
+        [xsave] unsafe fn _xsave(mem_addr: *mut u8, save_mask: u64);
+        #[cfg(target_arch = "x86_64")]
+        [xsave] unsafe fn _xsave64(mem_addr: *mut u8, save_mask: u64);
+        [xsave, xsavec] unsafe fn _xsavec(mem_addr: *mut u8, save_mask: u64);
+        #[cfg(target_arch = "x86_64")]
+        [xsave, xsavec] unsafe fn _xsavec64(mem_addr: *mut u8, save_mask: u64);
+        [xsave, xsaveopt] unsafe fn _xsaveopt(mem_addr: *mut u8, save_mask: u64);
+        #[cfg(target_arch = "x86_64")]
+        [xsave, xsaveopt] unsafe fn _xsaveopt64(mem_addr: *mut u8, save_mask: u64);
+        [xsave, xsaves] unsafe fn _xsaves(mem_addr: *mut u8, save_mask: u64);
+        #[cfg(target_arch = "x86_64")]
+        [xsave, xsaves] unsafe fn _xsaves64(mem_addr: *mut u8, save_mask: u64);
+        [xsave] unsafe fn _xsetbv(a: u32, val: u64);
+    }
+}
+
+#[rustversion::since(1.63)]
+pub use since_1_63::*;
+
+#[rustversion::since(1.67)]
+mod since_1_67 {
+    use super::arch;
+    use core::sync::atomic::Ordering;
+
+    intrinsics! {
+        #[cfg(target_arch = "x86_64")]
+        [cmpxchg16b] unsafe fn cmpxchg16b(dst: *mut u128, old: u128, new: u128, success: Ordering, failure: Ordering) -> u128;
+    }
+}
+
+#[rustversion::since(1.67)]
+pub use since_1_67::*;
+
+#[rustversion::since(1.68)]
+mod since_1_68 {
+    use super::arch;
+    use super::arch::*;
+
+    intrinsics! {
+        [f16c] safe fn _mm256_cvtph_ps(a: __m128i) -> __m256;
+        [f16c] safe fn _mm256_cvtps_ph<const IMM_ROUNDING: i32>(a: __m256) -> __m128i;
+        [f16c] safe fn _mm_cvtph_ps(a: __m128i) -> __m128;
+        [f16c] safe fn _mm_cvtps_ph<const IMM_ROUNDING: i32>(a: __m128) -> __m128i;
+    }
+}
+
+#[rustversion::since(1.68)]
+pub use since_1_68::*;
+
+#[rustversion::since(1.82)]
+mod since_1_82 {
+    use super::arch;
+    use super::arch::*;
+
+    intrinsics! {
+        [tbm] safe fn _bextri_u32<const CONTROL: u32>(a: u32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [tbm] safe fn _bextri_u64<const CONTROL: u64>(a: u64) -> u64;
+        [avx2] unsafe fn _mm256_stream_load_si256(mem_addr: *const __m256i) -> __m256i;
+        [avx2] safe fn _mm_broadcastsi128_si256(a: __m128i) -> __m256i;
+        [sse4a] safe fn _mm_extracti_si64<const LEN: i32, const IDX: i32>(x: __m128i) -> __m128i;
+        [sse4a] safe fn _mm_inserti_si64<const LEN: i32, const IDX: i32>(x: __m128i, y: __m128i) -> __m128i;
+        [sse2] unsafe fn _mm_loadu_si16(mem_addr: *const u8) -> __m128i;
+        [sse2] unsafe fn _mm_loadu_si32(mem_addr: *const u8) -> __m128i;
+        [sse2] unsafe fn _mm_storeu_si16(mem_addr: *mut u8, a: __m128i);
+        [sse2] unsafe fn _mm_storeu_si32(mem_addr: *mut u8, a: __m128i);
+        [sse2] unsafe fn _mm_storeu_si64(mem_addr: *mut u8, a: __m128i);
+        [sse41] unsafe fn _mm_stream_load_si128(mem_addr: *const __m128i) -> __m128i;
+        [bmi1] safe fn _tzcnt_u16(x: u16) -> u16;
+    }
+}
+
+#[rustversion::since(1.82)]
+pub use since_1_82::*;
+
+#[rustversion::since(1.89)]
+mod since_1_89 {
+    use super::arch;
+    use super::arch::*;
+
+    intrinsics! {
+        [avx512f] safe fn _cvtmask16_u32(a: __mmask16) -> u32;
+        [avx512bw] safe fn _cvtmask32_u32(a: __mmask32) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512bw] safe fn _cvtmask64_u64(a: __mmask64) -> u64;
+        [avx512dq] safe fn _cvtmask8_u32(a: __mmask8) -> u32;
+        [avx512f] safe fn _cvtu32_mask16(a: u32) -> __mmask16;
+        [avx512bw] safe fn _cvtu32_mask32(a: u32) -> __mmask32;
+        [avx512dq] safe fn _cvtu32_mask8(a: u32) -> __mmask8;
+        #[cfg(target_arch = "x86_64")]
+        [avx512bw] safe fn _cvtu64_mask64(a: u64) -> __mmask64;
+        [avx512dq] safe fn _kadd_mask16(a: __mmask16, b: __mmask16) -> __mmask16;
+        [avx512bw] safe fn _kadd_mask32(a: __mmask32, b: __mmask32) -> __mmask32;
+        [avx512bw] safe fn _kadd_mask64(a: __mmask64, b: __mmask64) -> __mmask64;
+        [avx512dq] safe fn _kadd_mask8(a: __mmask8, b: __mmask8) -> __mmask8;
+        [avx512f] safe fn _kand_mask16(a: __mmask16, b: __mmask16) -> __mmask16;
+        [avx512bw] safe fn _kand_mask32(a: __mmask32, b: __mmask32) -> __mmask32;
+        [avx512bw] safe fn _kand_mask64(a: __mmask64, b: __mmask64) -> __mmask64;
+        [avx512dq] safe fn _kand_mask8(a: __mmask8, b: __mmask8) -> __mmask8;
+        [avx512f] safe fn _kandn_mask16(a: __mmask16, b: __mmask16) -> __mmask16;
+        [avx512bw] safe fn _kandn_mask32(a: __mmask32, b: __mmask32) -> __mmask32;
+        [avx512bw] safe fn _kandn_mask64(a: __mmask64, b: __mmask64) -> __mmask64;
+        [avx512dq] safe fn _kandn_mask8(a: __mmask8, b: __mmask8) -> __mmask8;
+        [avx512f] safe fn _knot_mask16(a: __mmask16) -> __mmask16;
+        [avx512bw] safe fn _knot_mask32(a: __mmask32) -> __mmask32;
+        [avx512bw] safe fn _knot_mask64(a: __mmask64) -> __mmask64;
+        [avx512dq] safe fn _knot_mask8(a: __mmask8) -> __mmask8;
+        [avx512f] safe fn _kor_mask16(a: __mmask16, b: __mmask16) -> __mmask16;
+        [avx512bw] safe fn _kor_mask32(a: __mmask32, b: __mmask32) -> __mmask32;
+        [avx512bw] safe fn _kor_mask64(a: __mmask64, b: __mmask64) -> __mmask64;
+        [avx512dq] safe fn _kor_mask8(a: __mmask8, b: __mmask8) -> __mmask8;
+        [avx512f] unsafe fn _kortest_mask16_u8(a: __mmask16, b: __mmask16, all_ones: *mut u8) -> u8;
+        [avx512bw] unsafe fn _kortest_mask32_u8(a: __mmask32, b: __mmask32, all_ones: *mut u8) -> u8;
+        [avx512bw] unsafe fn _kortest_mask64_u8(a: __mmask64, b: __mmask64, all_ones: *mut u8) -> u8;
+        [avx512dq] unsafe fn _kortest_mask8_u8(a: __mmask8, b: __mmask8, all_ones: *mut u8) -> u8;
+        [avx512f] safe fn _kortestc_mask16_u8(a: __mmask16, b: __mmask16) -> u8;
+        [avx512bw] safe fn _kortestc_mask32_u8(a: __mmask32, b: __mmask32) -> u8;
+        [avx512bw] safe fn _kortestc_mask64_u8(a: __mmask64, b: __mmask64) -> u8;
+        [avx512dq] safe fn _kortestc_mask8_u8(a: __mmask8, b: __mmask8) -> u8;
+        [avx512f] safe fn _kortestz_mask16_u8(a: __mmask16, b: __mmask16) -> u8;
+        [avx512bw] safe fn _kortestz_mask32_u8(a: __mmask32, b: __mmask32) -> u8;
+        [avx512bw] safe fn _kortestz_mask64_u8(a: __mmask64, b: __mmask64) -> u8;
+        [avx512dq] safe fn _kortestz_mask8_u8(a: __mmask8, b: __mmask8) -> u8;
+        [avx512f] safe fn _kshiftli_mask16<const COUNT: u32>(a: __mmask16) -> __mmask16;
+        [avx512bw] safe fn _kshiftli_mask32<const COUNT: u32>(a: __mmask32) -> __mmask32;
+        [avx512bw] safe fn _kshiftli_mask64<const COUNT: u32>(a: __mmask64) -> __mmask64;
+        [avx512dq] safe fn _kshiftli_mask8<const COUNT: u32>(a: __mmask8) -> __mmask8;
+        [avx512f] safe fn _kshiftri_mask16<const COUNT: u32>(a: __mmask16) -> __mmask16;
+        [avx512bw] safe fn _kshiftri_mask32<const COUNT: u32>(a: __mmask32) -> __mmask32;
+        [avx512bw] safe fn _kshiftri_mask64<const COUNT: u32>(a: __mmask64) -> __mmask64;
+        [avx512dq] safe fn _kshiftri_mask8<const COUNT: u32>(a: __mmask8) -> __mmask8;
+        [avx512dq] unsafe fn _ktest_mask16_u8(a: __mmask16, b: __mmask16, and_not: *mut u8) -> u8;
+        [avx512bw] unsafe fn _ktest_mask32_u8(a: __mmask32, b: __mmask32, and_not: *mut u8) -> u8;
+        [avx512bw] unsafe fn _ktest_mask64_u8(a: __mmask64, b: __mmask64, and_not: *mut u8) -> u8;
+        [avx512dq] unsafe fn _ktest_mask8_u8(a: __mmask8, b: __mmask8, and_not: *mut u8) -> u8;
+        [avx512dq] safe fn _ktestc_mask16_u8(a: __mmask16, b: __mmask16) -> u8;
+        [avx512bw] safe fn _ktestc_mask32_u8(a: __mmask32, b: __mmask32) -> u8;
+        [avx512bw] safe fn _ktestc_mask64_u8(a: __mmask64, b: __mmask64) -> u8;
+        [avx512dq] safe fn _ktestc_mask8_u8(a: __mmask8, b: __mmask8) -> u8;
+        [avx512dq] safe fn _ktestz_mask16_u8(a: __mmask16, b: __mmask16) -> u8;
+        [avx512bw] safe fn _ktestz_mask32_u8(a: __mmask32, b: __mmask32) -> u8;
+        [avx512bw] safe fn _ktestz_mask64_u8(a: __mmask64, b: __mmask64) -> u8;
+        [avx512dq] safe fn _ktestz_mask8_u8(a: __mmask8, b: __mmask8) -> u8;
+        [avx512f] safe fn _kxnor_mask16(a: __mmask16, b: __mmask16) -> __mmask16;
+        [avx512bw] safe fn _kxnor_mask32(a: __mmask32, b: __mmask32) -> __mmask32;
+        [avx512bw] safe fn _kxnor_mask64(a: __mmask64, b: __mmask64) -> __mmask64;
+        [avx512dq] safe fn _kxnor_mask8(a: __mmask8, b: __mmask8) -> __mmask8;
+        [avx512f] safe fn _kxor_mask16(a: __mmask16, b: __mmask16) -> __mmask16;
+        [avx512bw] safe fn _kxor_mask32(a: __mmask32, b: __mmask32) -> __mmask32;
+        [avx512bw] safe fn _kxor_mask64(a: __mmask64, b: __mmask64) -> __mmask64;
+        [avx512dq] safe fn _kxor_mask8(a: __mmask8, b: __mmask8) -> __mmask8;
+        [avx512f] unsafe fn _load_mask16(mem_addr: *const __mmask16) -> __mmask16;
+        [avx512bw] unsafe fn _load_mask32(mem_addr: *const __mmask32) -> __mmask32;
+        [avx512bw] unsafe fn _load_mask64(mem_addr: *const __mmask64) -> __mmask64;
+        [avx512dq] unsafe fn _load_mask8(mem_addr: *const __mmask8) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_abs_epi64(a: __m256i) -> __m256i;
+        [vaes] safe fn _mm256_aesdec_epi128(a: __m256i, round_key: __m256i) -> __m256i;
+        [vaes] safe fn _mm256_aesdeclast_epi128(a: __m256i, round_key: __m256i) -> __m256i;
+        [vaes] safe fn _mm256_aesenc_epi128(a: __m256i, round_key: __m256i) -> __m256i;
+        [vaes] safe fn _mm256_aesenclast_epi128(a: __m256i, round_key: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_alignr_epi32<const IMM8: i32>(a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_alignr_epi64<const IMM8: i32>(a: __m256i, b: __m256i) -> __m256i;
+        [avx512bitalg, avx512vl] safe fn _mm256_bitshuffle_epi64_mask(b: __m256i, c: __m256i) -> __mmask32;
+        [avx512dq, avx512vl] safe fn _mm256_broadcast_f32x2(a: __m128) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_broadcast_f32x4(a: __m128) -> __m256;
+        [avx512dq, avx512vl] safe fn _mm256_broadcast_f64x2(a: __m128d) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_broadcast_i32x2(a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_broadcast_i32x4(a: __m128i) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_broadcast_i64x2(a: __m128i) -> __m256i;
+        [avx512cd, avx512vl] safe fn _mm256_broadcastmb_epi64(k: __mmask8) -> __m256i;
+        [avx512cd, avx512vl] safe fn _mm256_broadcastmw_epi32(k: __mmask16) -> __m256i;
+        [vpclmulqdq] safe fn _mm256_clmulepi64_epi128<const IMM8: i32>(a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_cmp_epi16_mask<const IMM8: i32>(a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_cmp_epi32_mask<const IMM3: _MM_CMPINT_ENUM>(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_cmp_epi64_mask<const IMM3: _MM_CMPINT_ENUM>(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_cmp_epi8_mask<const IMM8: i32>(a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_cmp_epu16_mask<const IMM8: i32>(a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_cmp_epu32_mask<const IMM3: _MM_CMPINT_ENUM>(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_cmp_epu64_mask<const IMM3: _MM_CMPINT_ENUM>(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_cmp_epu8_mask<const IMM8: i32>(a: __m256i, b: __m256i) -> __mmask32;
+        [avx512f, avx512vl] safe fn _mm256_cmp_pd_mask<const IMM8: i32>(a: __m256d, b: __m256d) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_cmp_ps_mask<const IMM8: i32>(a: __m256, b: __m256) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_cmpeq_epi16_mask(a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_cmpeq_epi32_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_cmpeq_epi64_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_cmpeq_epi8_mask(a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_cmpeq_epu16_mask(a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_cmpeq_epu32_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_cmpeq_epu64_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_cmpeq_epu8_mask(a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_cmpge_epi16_mask(a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_cmpge_epi32_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_cmpge_epi64_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_cmpge_epi8_mask(a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_cmpge_epu16_mask(a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_cmpge_epu32_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_cmpge_epu64_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_cmpge_epu8_mask(a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_cmpgt_epi16_mask(a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_cmpgt_epi32_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_cmpgt_epi64_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_cmpgt_epi8_mask(a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_cmpgt_epu16_mask(a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_cmpgt_epu32_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_cmpgt_epu64_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_cmpgt_epu8_mask(a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_cmple_epi16_mask(a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_cmple_epi32_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_cmple_epi64_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_cmple_epi8_mask(a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_cmple_epu16_mask(a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_cmple_epu32_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_cmple_epu64_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_cmple_epu8_mask(a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_cmplt_epi16_mask(a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_cmplt_epi32_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_cmplt_epi64_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_cmplt_epi8_mask(a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_cmplt_epu16_mask(a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_cmplt_epu32_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_cmplt_epu64_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_cmplt_epu8_mask(a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_cmpneq_epi16_mask(a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_cmpneq_epi32_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_cmpneq_epi64_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_cmpneq_epi8_mask(a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_cmpneq_epu16_mask(a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_cmpneq_epu32_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_cmpneq_epu64_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_cmpneq_epu8_mask(a: __m256i, b: __m256i) -> __mmask32;
+        [avx512cd, avx512vl] safe fn _mm256_conflict_epi32(a: __m256i) -> __m256i;
+        [avx512cd, avx512vl] safe fn _mm256_conflict_epi64(a: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_cvtepi16_epi8(a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_cvtepi32_epi16(a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_cvtepi32_epi8(a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_cvtepi64_epi16(a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_cvtepi64_epi32(a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_cvtepi64_epi8(a: __m256i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm256_cvtepi64_pd(a: __m256i) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_cvtepi64_ps(a: __m256i) -> __m128;
+        [avx512f, avx512vl] safe fn _mm256_cvtepu32_pd(a: __m128i) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_cvtepu64_pd(a: __m256i) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_cvtepu64_ps(a: __m256i) -> __m128;
+        [avx512bf16, avx512vl] safe fn _mm256_cvtne2ps_pbh(a: __m256, b: __m256) -> __m256bh;
+        [avxneconvert] unsafe fn _mm256_cvtneebf16_ps(a: *const __m256bh) -> __m256;
+        [avxneconvert] unsafe fn _mm256_cvtneobf16_ps(a: *const __m256bh) -> __m256;
+        [avxneconvert] safe fn _mm256_cvtneps_avx_pbh(a: __m256) -> __m128bh;
+        [avx512bf16, avx512vl] safe fn _mm256_cvtneps_pbh(a: __m256) -> __m128bh;
+        [avx512bf16, avx512vl] safe fn _mm256_cvtpbh_ps(a: __m128bh) -> __m256;
+        [avx512dq, avx512vl] safe fn _mm256_cvtpd_epi64(a: __m256d) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_cvtpd_epu32(a: __m256d) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm256_cvtpd_epu64(a: __m256d) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_cvtps_epi64(a: __m128) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_cvtps_epu32(a: __m256) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_cvtps_epu64(a: __m128) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_cvtsepi16_epi8(a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_cvtsepi32_epi16(a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_cvtsepi32_epi8(a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_cvtsepi64_epi16(a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_cvtsepi64_epi32(a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_cvtsepi64_epi8(a: __m256i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm256_cvttpd_epi64(a: __m256d) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_cvttpd_epu32(a: __m256d) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm256_cvttpd_epu64(a: __m256d) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_cvttps_epi64(a: __m128) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_cvttps_epu32(a: __m256) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_cvttps_epu64(a: __m128) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_cvtusepi16_epi8(a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_cvtusepi32_epi16(a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_cvtusepi32_epi8(a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_cvtusepi64_epi16(a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_cvtusepi64_epi32(a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_cvtusepi64_epi8(a: __m256i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm256_dbsad_epu8<const IMM8: i32>(a: __m256i, b: __m256i) -> __m256i;
+        [avx512bf16, avx512vl] safe fn _mm256_dpbf16_ps(src: __m256, a: __m256bh, b: __m256bh) -> __m256;
+        [avxvnniint8] safe fn _mm256_dpbssd_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avxvnniint8] safe fn _mm256_dpbssds_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avxvnniint8] safe fn _mm256_dpbsud_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avxvnniint8] safe fn _mm256_dpbsuds_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avxvnni] safe fn _mm256_dpbusd_avx_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vnni, avx512vl] safe fn _mm256_dpbusd_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avxvnni] safe fn _mm256_dpbusds_avx_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vnni, avx512vl] safe fn _mm256_dpbusds_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avxvnniint8] safe fn _mm256_dpbuud_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avxvnniint8] safe fn _mm256_dpbuuds_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avxvnni] safe fn _mm256_dpwssd_avx_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vnni, avx512vl] safe fn _mm256_dpwssd_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avxvnni] safe fn _mm256_dpwssds_avx_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vnni, avx512vl] safe fn _mm256_dpwssds_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avxvnniint16] safe fn _mm256_dpwsud_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avxvnniint16] safe fn _mm256_dpwsuds_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avxvnniint16] safe fn _mm256_dpwusd_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avxvnniint16] safe fn _mm256_dpwusds_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avxvnniint16] safe fn _mm256_dpwuud_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avxvnniint16] safe fn _mm256_dpwuuds_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_extractf32x4_ps<const IMM8: i32>(a: __m256) -> __m128;
+        [avx512dq, avx512vl] safe fn _mm256_extractf64x2_pd<const IMM8: i32>(a: __m256d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm256_extracti32x4_epi32<const IMM1: i32>(a: __m256i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm256_extracti64x2_epi64<const IMM8: i32>(a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_fixupimm_pd<const IMM8: i32>(a: __m256d, b: __m256d, c: __m256i) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_fixupimm_ps<const IMM8: i32>(a: __m256, b: __m256, c: __m256i) -> __m256;
+        [avx512dq, avx512vl] safe fn _mm256_fpclass_pd_mask<const IMM8: i32>(a: __m256d) -> __mmask8;
+        [avx512dq, avx512vl] safe fn _mm256_fpclass_ps_mask<const IMM8: i32>(a: __m256) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_getexp_pd(a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_getexp_ps(a: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_getmant_pd<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_getmant_ps<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(a: __m256) -> __m256;
+        [gfni, avx] safe fn _mm256_gf2p8affine_epi64_epi8<const B: i32>(x: __m256i, a: __m256i) -> __m256i;
+        [gfni, avx] safe fn _mm256_gf2p8affineinv_epi64_epi8<const B: i32>(x: __m256i, a: __m256i) -> __m256i;
+        [gfni, avx] safe fn _mm256_gf2p8mul_epi8(a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_i32scatter_epi32<const SCALE: i32>(base_addr: *mut i32, vindex: __m256i, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_i32scatter_epi64<const SCALE: i32>(slice: *mut i64, offsets: __m128i, src: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_i32scatter_pd<const SCALE: i32>(base_addr: *mut f64, vindex: __m128i, a: __m256d);
+        [avx512f, avx512vl] unsafe fn _mm256_i32scatter_ps<const SCALE: i32>(base_addr: *mut f32, vindex: __m256i, a: __m256);
+        [avx512f, avx512vl] unsafe fn _mm256_i64scatter_epi32<const SCALE: i32>(base_addr: *mut i32, vindex: __m256i, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm256_i64scatter_epi64<const SCALE: i32>(base_addr: *mut i64, vindex: __m256i, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_i64scatter_pd<const SCALE: i32>(base_addr: *mut f64, vindex: __m256i, a: __m256d);
+        [avx512f, avx512vl] unsafe fn _mm256_i64scatter_ps<const SCALE: i32>(base_addr: *mut f32, vindex: __m256i, a: __m128);
+        [avx512f, avx512vl] safe fn _mm256_insertf32x4<const IMM8: i32>(a: __m256, b: __m128) -> __m256;
+        [avx512dq, avx512vl] safe fn _mm256_insertf64x2<const IMM8: i32>(a: __m256d, b: __m128d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_inserti32x4<const IMM8: i32>(a: __m256i, b: __m128i) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_inserti64x2<const IMM8: i32>(a: __m256i, b: __m128i) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_load_epi32(mem_addr: *const i32) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_load_epi64(mem_addr: *const i64) -> __m256i;
+        [avx512bw, avx512vl] unsafe fn _mm256_loadu_epi16(mem_addr: *const i16) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_loadu_epi32(mem_addr: *const i32) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_loadu_epi64(mem_addr: *const i64) -> __m256i;
+        [avx512bw, avx512vl] unsafe fn _mm256_loadu_epi8(mem_addr: *const i8) -> __m256i;
+        [avx512cd, avx512vl] safe fn _mm256_lzcnt_epi32(a: __m256i) -> __m256i;
+        [avx512cd, avx512vl] safe fn _mm256_lzcnt_epi64(a: __m256i) -> __m256i;
+        [avxifma] safe fn _mm256_madd52hi_avx_epu64(a: __m256i, b: __m256i, c: __m256i) -> __m256i;
+        [avx512ifma, avx512vl] safe fn _mm256_madd52hi_epu64(a: __m256i, b: __m256i, c: __m256i) -> __m256i;
+        [avxifma] safe fn _mm256_madd52lo_avx_epu64(a: __m256i, b: __m256i, c: __m256i) -> __m256i;
+        [avx512ifma, avx512vl] safe fn _mm256_madd52lo_epu64(a: __m256i, b: __m256i, c: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask2_permutex2var_epi16(a: __m256i, idx: __m256i, k: __mmask16, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask2_permutex2var_epi32(a: __m256i, idx: __m256i, k: __mmask8, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask2_permutex2var_epi64(a: __m256i, idx: __m256i, k: __mmask8, b: __m256i) -> __m256i;
+        [avx512vbmi, avx512vl] safe fn _mm256_mask2_permutex2var_epi8(a: __m256i, idx: __m256i, k: __mmask32, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask2_permutex2var_pd(a: __m256d, idx: __m256i, k: __mmask8, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask2_permutex2var_ps(a: __m256, idx: __m256i, k: __mmask8, b: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask3_fmadd_pd(a: __m256d, b: __m256d, c: __m256d, k: __mmask8) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask3_fmadd_ps(a: __m256, b: __m256, c: __m256, k: __mmask8) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask3_fmaddsub_pd(a: __m256d, b: __m256d, c: __m256d, k: __mmask8) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask3_fmaddsub_ps(a: __m256, b: __m256, c: __m256, k: __mmask8) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask3_fmsub_pd(a: __m256d, b: __m256d, c: __m256d, k: __mmask8) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask3_fmsub_ps(a: __m256, b: __m256, c: __m256, k: __mmask8) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask3_fmsubadd_pd(a: __m256d, b: __m256d, c: __m256d, k: __mmask8) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask3_fmsubadd_ps(a: __m256, b: __m256, c: __m256, k: __mmask8) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask3_fnmadd_pd(a: __m256d, b: __m256d, c: __m256d, k: __mmask8) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask3_fnmadd_ps(a: __m256, b: __m256, c: __m256, k: __mmask8) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask3_fnmsub_pd(a: __m256d, b: __m256d, c: __m256d, k: __mmask8) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask3_fnmsub_ps(a: __m256, b: __m256, c: __m256, k: __mmask8) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_mask_abs_epi16(src: __m256i, k: __mmask16, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_abs_epi32(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_abs_epi64(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_abs_epi8(src: __m256i, k: __mmask32, a: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_add_epi16(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_add_epi32(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_add_epi64(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_add_epi8(src: __m256i, k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_add_pd(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_add_ps(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_mask_adds_epi16(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_adds_epi8(src: __m256i, k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_adds_epu16(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_adds_epu8(src: __m256i, k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_alignr_epi32<const IMM8: i32>(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_alignr_epi64<const IMM8: i32>(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_alignr_epi8<const IMM8: i32>(src: __m256i, k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_and_epi32(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_and_epi64(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_mask_and_pd(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_mask_and_ps(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask_andnot_epi32(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_andnot_epi64(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_mask_andnot_pd(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_mask_andnot_ps(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_mask_avg_epu16(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_avg_epu8(src: __m256i, k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bitalg, avx512vl] safe fn _mm256_mask_bitshuffle_epi64_mask(k: __mmask32, b: __m256i, c: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_mask_blend_epi16(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_blend_epi32(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_blend_epi64(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_blend_epi8(k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_blend_pd(k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_blend_ps(k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512dq, avx512vl] safe fn _mm256_mask_broadcast_f32x2(src: __m256, k: __mmask8, a: __m128) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask_broadcast_f32x4(src: __m256, k: __mmask8, a: __m128) -> __m256;
+        [avx512dq, avx512vl] safe fn _mm256_mask_broadcast_f64x2(src: __m256d, k: __mmask8, a: __m128d) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_mask_broadcast_i32x2(src: __m256i, k: __mmask8, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_broadcast_i32x4(src: __m256i, k: __mmask8, a: __m128i) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_mask_broadcast_i64x2(src: __m256i, k: __mmask8, a: __m128i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_broadcastb_epi8(src: __m256i, k: __mmask32, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_broadcastd_epi32(src: __m256i, k: __mmask8, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_broadcastq_epi64(src: __m256i, k: __mmask8, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_broadcastsd_pd(src: __m256d, k: __mmask8, a: __m128d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_broadcastss_ps(src: __m256, k: __mmask8, a: __m128) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_mask_broadcastw_epi16(src: __m256i, k: __mmask16, a: __m128i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmp_epi16_mask<const IMM8: i32>(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmp_epi32_mask<const IMM3: _MM_CMPINT_ENUM>(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmp_epi64_mask<const IMM3: _MM_CMPINT_ENUM>(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmp_epi8_mask<const IMM8: i32>(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmp_epu16_mask<const IMM8: i32>(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmp_epu32_mask<const IMM3: _MM_CMPINT_ENUM>(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmp_epu64_mask<const IMM3: _MM_CMPINT_ENUM>(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmp_epu8_mask<const IMM8: i32>(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmp_pd_mask<const IMM8: i32>(k1: __mmask8, a: __m256d, b: __m256d) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmp_ps_mask<const IMM8: i32>(k1: __mmask8, a: __m256, b: __m256) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmpeq_epi16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmpeq_epi32_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmpeq_epi64_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmpeq_epi8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmpeq_epu16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmpeq_epu32_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmpeq_epu64_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmpeq_epu8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmpge_epi16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmpge_epi32_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmpge_epi64_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmpge_epi8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmpge_epu16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmpge_epu32_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmpge_epu64_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmpge_epu8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmpgt_epi16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmpgt_epi32_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmpgt_epi64_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmpgt_epi8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmpgt_epu16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmpgt_epu32_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmpgt_epu64_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmpgt_epu8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmple_epi16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmple_epi32_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmple_epi64_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmple_epi8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmple_epu16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmple_epu32_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmple_epu64_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmple_epu8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmplt_epi16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmplt_epi32_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmplt_epi64_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmplt_epi8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmplt_epu16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmplt_epu32_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmplt_epu64_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmplt_epu8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmpneq_epi16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmpneq_epi32_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmpneq_epi64_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmpneq_epi8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmpneq_epu16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmpneq_epu32_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_mask_cmpneq_epu64_mask(k1: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cmpneq_epu8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32;
+        [avx512vbmi2, avx512vl] safe fn _mm256_mask_compress_epi16(src: __m256i, k: __mmask16, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_compress_epi32(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_compress_epi64(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_mask_compress_epi8(src: __m256i, k: __mmask32, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_compress_pd(src: __m256d, k: __mmask8, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_compress_ps(src: __m256, k: __mmask8, a: __m256) -> __m256;
+        [avx512vbmi2, avx512vl] unsafe fn _mm256_mask_compressstoreu_epi16(base_addr: *mut i16, k: __mmask16, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_compressstoreu_epi32(base_addr: *mut i32, k: __mmask8, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_compressstoreu_epi64(base_addr: *mut i64, k: __mmask8, a: __m256i);
+        [avx512vbmi2, avx512vl] unsafe fn _mm256_mask_compressstoreu_epi8(base_addr: *mut i8, k: __mmask32, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_compressstoreu_pd(base_addr: *mut f64, k: __mmask8, a: __m256d);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_compressstoreu_ps(base_addr: *mut f32, k: __mmask8, a: __m256);
+        [avx512cd, avx512vl] safe fn _mm256_mask_conflict_epi32(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512cd, avx512vl] safe fn _mm256_mask_conflict_epi64(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvt_roundps_ph<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m256) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtepi16_epi32(src: __m256i, k: __mmask8, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtepi16_epi64(src: __m256i, k: __mmask8, a: __m128i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cvtepi16_epi8(src: __m128i, k: __mmask16, a: __m256i) -> __m128i;
+        [avx512bw, avx512vl] unsafe fn _mm256_mask_cvtepi16_storeu_epi8(mem_addr: *mut i8, k: __mmask16, a: __m256i);
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtepi32_epi16(src: __m128i, k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtepi32_epi64(src: __m256i, k: __mmask8, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtepi32_epi8(src: __m128i, k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtepi32_pd(src: __m256d, k: __mmask8, a: __m128i) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtepi32_ps(src: __m256, k: __mmask8, a: __m256i) -> __m256;
+        [avx512f, avx512vl] unsafe fn _mm256_mask_cvtepi32_storeu_epi16(mem_addr: *mut i16, k: __mmask8, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_cvtepi32_storeu_epi8(mem_addr: *mut i8, k: __mmask8, a: __m256i);
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtepi64_epi16(src: __m128i, k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtepi64_epi32(src: __m128i, k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtepi64_epi8(src: __m128i, k: __mmask8, a: __m256i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm256_mask_cvtepi64_pd(src: __m256d, k: __mmask8, a: __m256i) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_mask_cvtepi64_ps(src: __m128, k: __mmask8, a: __m256i) -> __m128;
+        [avx512f, avx512vl] unsafe fn _mm256_mask_cvtepi64_storeu_epi16(mem_addr: *mut i16, k: __mmask8, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_cvtepi64_storeu_epi32(mem_addr: *mut i32, k: __mmask8, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_cvtepi64_storeu_epi8(mem_addr: *mut i8, k: __mmask8, a: __m256i);
+        [avx512bw, avx512vl] safe fn _mm256_mask_cvtepi8_epi16(src: __m256i, k: __mmask16, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtepi8_epi32(src: __m256i, k: __mmask8, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtepi8_epi64(src: __m256i, k: __mmask8, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtepu16_epi32(src: __m256i, k: __mmask8, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtepu16_epi64(src: __m256i, k: __mmask8, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtepu32_epi64(src: __m256i, k: __mmask8, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtepu32_pd(src: __m256d, k: __mmask8, a: __m128i) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_mask_cvtepu64_pd(src: __m256d, k: __mmask8, a: __m256i) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_mask_cvtepu64_ps(src: __m128, k: __mmask8, a: __m256i) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cvtepu8_epi16(src: __m256i, k: __mmask16, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtepu8_epi32(src: __m256i, k: __mmask8, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtepu8_epi64(src: __m256i, k: __mmask8, a: __m128i) -> __m256i;
+        [avx512bf16, avx512vl] safe fn _mm256_mask_cvtne2ps_pbh(src: __m256bh, k: __mmask16, a: __m256, b: __m256) -> __m256bh;
+        [avx512bf16, avx512vl] safe fn _mm256_mask_cvtneps_pbh(src: __m128bh, k: __mmask8, a: __m256) -> __m128bh;
+        [avx512bf16, avx512vl] safe fn _mm256_mask_cvtpbh_ps(src: __m256, k: __mmask8, a: __m128bh) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtpd_epi32(src: __m128i, k: __mmask8, a: __m256d) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm256_mask_cvtpd_epi64(src: __m256i, k: __mmask8, a: __m256d) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtpd_epu32(src: __m128i, k: __mmask8, a: __m256d) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm256_mask_cvtpd_epu64(src: __m256i, k: __mmask8, a: __m256d) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtpd_ps(src: __m128, k: __mmask8, a: __m256d) -> __m128;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtph_ps(src: __m256, k: __mmask8, a: __m128i) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtps_epi32(src: __m256i, k: __mmask8, a: __m256) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_mask_cvtps_epi64(src: __m256i, k: __mmask8, a: __m128) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtps_epu32(src: __m256i, k: __mmask8, a: __m256) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_mask_cvtps_epu64(src: __m256i, k: __mmask8, a: __m128) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtps_ph<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m256) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cvtsepi16_epi8(src: __m128i, k: __mmask16, a: __m256i) -> __m128i;
+        [avx512bw, avx512vl] unsafe fn _mm256_mask_cvtsepi16_storeu_epi8(mem_addr: *mut i8, k: __mmask16, a: __m256i);
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtsepi32_epi16(src: __m128i, k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtsepi32_epi8(src: __m128i, k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm256_mask_cvtsepi32_storeu_epi16(mem_addr: *mut i16, k: __mmask8, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_cvtsepi32_storeu_epi8(mem_addr: *mut i8, k: __mmask8, a: __m256i);
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtsepi64_epi16(src: __m128i, k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtsepi64_epi32(src: __m128i, k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtsepi64_epi8(src: __m128i, k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm256_mask_cvtsepi64_storeu_epi16(mem_addr: *mut i16, k: __mmask8, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_cvtsepi64_storeu_epi32(mem_addr: *mut i32, k: __mmask8, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_cvtsepi64_storeu_epi8(mem_addr: *mut i8, k: __mmask8, a: __m256i);
+        [avx512f, avx512vl] safe fn _mm256_mask_cvttpd_epi32(src: __m128i, k: __mmask8, a: __m256d) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm256_mask_cvttpd_epi64(src: __m256i, k: __mmask8, a: __m256d) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvttpd_epu32(src: __m128i, k: __mmask8, a: __m256d) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm256_mask_cvttpd_epu64(src: __m256i, k: __mmask8, a: __m256d) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvttps_epi32(src: __m256i, k: __mmask8, a: __m256) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_mask_cvttps_epi64(src: __m256i, k: __mmask8, a: __m128) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvttps_epu32(src: __m256i, k: __mmask8, a: __m256) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_mask_cvttps_epu64(src: __m256i, k: __mmask8, a: __m128) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_cvtusepi16_epi8(src: __m128i, k: __mmask16, a: __m256i) -> __m128i;
+        [avx512bw, avx512vl] unsafe fn _mm256_mask_cvtusepi16_storeu_epi8(mem_addr: *mut i8, k: __mmask16, a: __m256i);
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtusepi32_epi16(src: __m128i, k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtusepi32_epi8(src: __m128i, k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm256_mask_cvtusepi32_storeu_epi16(mem_addr: *mut i16, k: __mmask8, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_cvtusepi32_storeu_epi8(mem_addr: *mut i8, k: __mmask8, a: __m256i);
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtusepi64_epi16(src: __m128i, k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtusepi64_epi32(src: __m128i, k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_mask_cvtusepi64_epi8(src: __m128i, k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm256_mask_cvtusepi64_storeu_epi16(mem_addr: *mut i16, k: __mmask8, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_cvtusepi64_storeu_epi32(mem_addr: *mut i32, k: __mmask8, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_cvtusepi64_storeu_epi8(mem_addr: *mut i8, k: __mmask8, a: __m256i);
+        [avx512bw, avx512vl] safe fn _mm256_mask_dbsad_epu8<const IMM8: i32>(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_div_pd(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_div_ps(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512bf16, avx512vl] safe fn _mm256_mask_dpbf16_ps(src: __m256, k: __mmask8, a: __m256bh, b: __m256bh) -> __m256;
+        [avx512vnni, avx512vl] safe fn _mm256_mask_dpbusd_epi32(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vnni, avx512vl] safe fn _mm256_mask_dpbusds_epi32(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vnni, avx512vl] safe fn _mm256_mask_dpwssd_epi32(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vnni, avx512vl] safe fn _mm256_mask_dpwssds_epi32(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_mask_expand_epi16(src: __m256i, k: __mmask16, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_expand_epi32(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_expand_epi64(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_mask_expand_epi8(src: __m256i, k: __mmask32, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_expand_pd(src: __m256d, k: __mmask8, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_expand_ps(src: __m256, k: __mmask8, a: __m256) -> __m256;
+        [avx512vbmi2, avx512vl] unsafe fn _mm256_mask_expandloadu_epi16(src: __m256i, k: __mmask16, mem_addr: *const i16) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_mask_expandloadu_epi32(src: __m256i, k: __mmask8, mem_addr: *const i32) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_mask_expandloadu_epi64(src: __m256i, k: __mmask8, mem_addr: *const i64) -> __m256i;
+        [avx512vbmi2, avx512vl] unsafe fn _mm256_mask_expandloadu_epi8(src: __m256i, k: __mmask32, mem_addr: *const i8) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_mask_expandloadu_pd(src: __m256d, k: __mmask8, mem_addr: *const f64) -> __m256d;
+        [avx512f, avx512vl] unsafe fn _mm256_mask_expandloadu_ps(src: __m256, k: __mmask8, mem_addr: *const f32) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask_extractf32x4_ps<const IMM8: i32>(src: __m128, k: __mmask8, a: __m256) -> __m128;
+        [avx512dq, avx512vl] safe fn _mm256_mask_extractf64x2_pd<const IMM8: i32>(src: __m128d, k: __mmask8, a: __m256d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm256_mask_extracti32x4_epi32<const IMM1: i32>(src: __m128i, k: __mmask8, a: __m256i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm256_mask_extracti64x2_epi64<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_mask_fixupimm_pd<const IMM8: i32>(a: __m256d, k: __mmask8, b: __m256d, c: __m256i) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_fixupimm_ps<const IMM8: i32>(a: __m256, k: __mmask8, b: __m256, c: __m256i) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask_fmadd_pd(a: __m256d, k: __mmask8, b: __m256d, c: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_fmadd_ps(a: __m256, k: __mmask8, b: __m256, c: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask_fmaddsub_pd(a: __m256d, k: __mmask8, b: __m256d, c: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_fmaddsub_ps(a: __m256, k: __mmask8, b: __m256, c: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask_fmsub_pd(a: __m256d, k: __mmask8, b: __m256d, c: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_fmsub_ps(a: __m256, k: __mmask8, b: __m256, c: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask_fmsubadd_pd(a: __m256d, k: __mmask8, b: __m256d, c: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_fmsubadd_ps(a: __m256, k: __mmask8, b: __m256, c: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask_fnmadd_pd(a: __m256d, k: __mmask8, b: __m256d, c: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_fnmadd_ps(a: __m256, k: __mmask8, b: __m256, c: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask_fnmsub_pd(a: __m256d, k: __mmask8, b: __m256d, c: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_fnmsub_ps(a: __m256, k: __mmask8, b: __m256, c: __m256) -> __m256;
+        [avx512dq, avx512vl] safe fn _mm256_mask_fpclass_pd_mask<const IMM8: i32>(k1: __mmask8, a: __m256d) -> __mmask8;
+        [avx512dq, avx512vl] safe fn _mm256_mask_fpclass_ps_mask<const IMM8: i32>(k1: __mmask8, a: __m256) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_mask_getexp_pd(src: __m256d, k: __mmask8, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_getexp_ps(src: __m256, k: __mmask8, a: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask_getmant_pd<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(src: __m256d, k: __mmask8, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_getmant_ps<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(src: __m256, k: __mmask8, a: __m256) -> __m256;
+        [gfni, avx512bw, avx512vl] safe fn _mm256_mask_gf2p8affine_epi64_epi8<const B: i32>(src: __m256i, k: __mmask32, x: __m256i, a: __m256i) -> __m256i;
+        [gfni, avx512bw, avx512vl] safe fn _mm256_mask_gf2p8affineinv_epi64_epi8<const B: i32>(src: __m256i, k: __mmask32, x: __m256i, a: __m256i) -> __m256i;
+        [gfni, avx512bw, avx512vl] safe fn _mm256_mask_gf2p8mul_epi8(src: __m256i, k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_mask_i32scatter_epi32<const SCALE: i32>(base_addr: *mut i32, k: __mmask8, vindex: __m256i, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_i32scatter_epi64<const SCALE: i32>(base_addr: *mut i64, k: __mmask8, vindex: __m128i, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_i32scatter_pd<const SCALE: i32>(base_addr: *mut f64, k: __mmask8, vindex: __m128i, a: __m256d);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_i32scatter_ps<const SCALE: i32>(base_addr: *mut f32, k: __mmask8, vindex: __m256i, a: __m256);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_i64scatter_epi32<const SCALE: i32>(base_addr: *mut i32, k: __mmask8, vindex: __m256i, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_i64scatter_epi64<const SCALE: i32>(base_addr: *mut i64, k: __mmask8, vindex: __m256i, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_i64scatter_pd<const SCALE: i32>(base_addr: *mut f64, k: __mmask8, vindex: __m256i, a: __m256d);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_i64scatter_ps<const SCALE: i32>(base_addr: *mut f32, k: __mmask8, vindex: __m256i, a: __m128);
+        [avx512f, avx512vl] safe fn _mm256_mask_insertf32x4<const IMM8: i32>(src: __m256, k: __mmask8, a: __m256, b: __m128) -> __m256;
+        [avx512dq, avx512vl] safe fn _mm256_mask_insertf64x2<const IMM8: i32>(src: __m256d, k: __mmask8, a: __m256d, b: __m128d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_inserti32x4<const IMM8: i32>(src: __m256i, k: __mmask8, a: __m256i, b: __m128i) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_mask_inserti64x2<const IMM8: i32>(src: __m256i, k: __mmask8, a: __m256i, b: __m128i) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_mask_load_epi32(src: __m256i, k: __mmask8, mem_addr: *const i32) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_mask_load_epi64(src: __m256i, k: __mmask8, mem_addr: *const i64) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_mask_load_pd(src: __m256d, k: __mmask8, mem_addr: *const f64) -> __m256d;
+        [avx512f, avx512vl] unsafe fn _mm256_mask_load_ps(src: __m256, k: __mmask8, mem_addr: *const f32) -> __m256;
+        [avx512bw, avx512vl] unsafe fn _mm256_mask_loadu_epi16(src: __m256i, k: __mmask16, mem_addr: *const i16) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_mask_loadu_epi32(src: __m256i, k: __mmask8, mem_addr: *const i32) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_mask_loadu_epi64(src: __m256i, k: __mmask8, mem_addr: *const i64) -> __m256i;
+        [avx512bw, avx512vl] unsafe fn _mm256_mask_loadu_epi8(src: __m256i, k: __mmask32, mem_addr: *const i8) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_mask_loadu_pd(src: __m256d, k: __mmask8, mem_addr: *const f64) -> __m256d;
+        [avx512f, avx512vl] unsafe fn _mm256_mask_loadu_ps(src: __m256, k: __mmask8, mem_addr: *const f32) -> __m256;
+        [avx512cd, avx512vl] safe fn _mm256_mask_lzcnt_epi32(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512cd, avx512vl] safe fn _mm256_mask_lzcnt_epi64(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512ifma, avx512vl] safe fn _mm256_mask_madd52hi_epu64(a: __m256i, k: __mmask8, b: __m256i, c: __m256i) -> __m256i;
+        [avx512ifma, avx512vl] safe fn _mm256_mask_madd52lo_epu64(a: __m256i, k: __mmask8, b: __m256i, c: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_madd_epi16(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_maddubs_epi16(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_max_epi16(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_max_epi32(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_max_epi64(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_max_epi8(src: __m256i, k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_max_epu16(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_max_epu32(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_max_epu64(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_max_epu8(src: __m256i, k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_max_pd(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_max_ps(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_mask_min_epi16(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_min_epi32(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_min_epi64(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_min_epi8(src: __m256i, k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_min_epu16(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_min_epu32(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_min_epu64(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_min_epu8(src: __m256i, k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_min_pd(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_min_ps(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_mask_mov_epi16(src: __m256i, k: __mmask16, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_mov_epi32(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_mov_epi64(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_mov_epi8(src: __m256i, k: __mmask32, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_mov_pd(src: __m256d, k: __mmask8, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_mov_ps(src: __m256, k: __mmask8, a: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask_movedup_pd(src: __m256d, k: __mmask8, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_movehdup_ps(src: __m256, k: __mmask8, a: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask_moveldup_ps(src: __m256, k: __mmask8, a: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask_mul_epi32(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_mul_epu32(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_mul_pd(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_mul_ps(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_mask_mulhi_epi16(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_mulhi_epu16(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_mulhrs_epi16(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_mullo_epi16(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_mullo_epi32(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_mask_mullo_epi64(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi, avx512vl] safe fn _mm256_mask_multishift_epi64_epi8(src: __m256i, k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_or_epi32(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_or_epi64(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_mask_or_pd(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_mask_or_ps(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_mask_packs_epi16(src: __m256i, k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_packs_epi32(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_packus_epi16(src: __m256i, k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_packus_epi32(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_permute_pd<const MASK: i32>(src: __m256d, k: __mmask8, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_permute_ps<const MASK: i32>(src: __m256, k: __mmask8, a: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask_permutevar_pd(src: __m256d, k: __mmask8, a: __m256d, b: __m256i) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_permutevar_ps(src: __m256, k: __mmask8, a: __m256, b: __m256i) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_mask_permutex2var_epi16(a: __m256i, k: __mmask16, idx: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_permutex2var_epi32(a: __m256i, k: __mmask8, idx: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_permutex2var_epi64(a: __m256i, k: __mmask8, idx: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi, avx512vl] safe fn _mm256_mask_permutex2var_epi8(a: __m256i, k: __mmask32, idx: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_permutex2var_pd(a: __m256d, k: __mmask8, idx: __m256i, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_permutex2var_ps(a: __m256, k: __mmask8, idx: __m256i, b: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask_permutex_epi64<const MASK: i32>(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_permutex_pd<const MASK: i32>(src: __m256d, k: __mmask8, a: __m256d) -> __m256d;
+        [avx512bw, avx512vl] safe fn _mm256_mask_permutexvar_epi16(src: __m256i, k: __mmask16, idx: __m256i, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_permutexvar_epi32(src: __m256i, k: __mmask8, idx: __m256i, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_permutexvar_epi64(src: __m256i, k: __mmask8, idx: __m256i, a: __m256i) -> __m256i;
+        [avx512vbmi, avx512vl] safe fn _mm256_mask_permutexvar_epi8(src: __m256i, k: __mmask32, idx: __m256i, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_permutexvar_pd(src: __m256d, k: __mmask8, idx: __m256i, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_permutexvar_ps(src: __m256, k: __mmask8, idx: __m256i, a: __m256) -> __m256;
+        [avx512bitalg, avx512vl] safe fn _mm256_mask_popcnt_epi16(src: __m256i, k: __mmask16, a: __m256i) -> __m256i;
+        [avx512vpopcntdq, avx512vl] safe fn _mm256_mask_popcnt_epi32(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512vpopcntdq, avx512vl] safe fn _mm256_mask_popcnt_epi64(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512bitalg, avx512vl] safe fn _mm256_mask_popcnt_epi8(src: __m256i, k: __mmask32, a: __m256i) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_mask_range_pd<const IMM8: i32>(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_mask_range_ps<const IMM8: i32>(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask_rcp14_pd(src: __m256d, k: __mmask8, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_rcp14_ps(src: __m256, k: __mmask8, a: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_mask_reduce_add_epi16(k: __mmask16, a: __m256i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm256_mask_reduce_add_epi8(k: __mmask32, a: __m256i) -> i8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_reduce_and_epi16(k: __mmask16, a: __m256i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm256_mask_reduce_and_epi8(k: __mmask32, a: __m256i) -> i8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_reduce_max_epi16(k: __mmask16, a: __m256i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm256_mask_reduce_max_epi8(k: __mmask32, a: __m256i) -> i8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_reduce_max_epu16(k: __mmask16, a: __m256i) -> u16;
+        [avx512bw, avx512vl] safe fn _mm256_mask_reduce_max_epu8(k: __mmask32, a: __m256i) -> u8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_reduce_min_epi16(k: __mmask16, a: __m256i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm256_mask_reduce_min_epi8(k: __mmask32, a: __m256i) -> i8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_reduce_min_epu16(k: __mmask16, a: __m256i) -> u16;
+        [avx512bw, avx512vl] safe fn _mm256_mask_reduce_min_epu8(k: __mmask32, a: __m256i) -> u8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_reduce_mul_epi16(k: __mmask16, a: __m256i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm256_mask_reduce_mul_epi8(k: __mmask32, a: __m256i) -> i8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_reduce_or_epi16(k: __mmask16, a: __m256i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm256_mask_reduce_or_epi8(k: __mmask32, a: __m256i) -> i8;
+        [avx512dq, avx512vl] safe fn _mm256_mask_reduce_pd<const IMM8: i32>(src: __m256d, k: __mmask8, a: __m256d) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_mask_reduce_ps<const IMM8: i32>(src: __m256, k: __mmask8, a: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask_rol_epi32<const IMM8: i32>(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_rol_epi64<const IMM8: i32>(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_rolv_epi32(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_rolv_epi64(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_ror_epi32<const IMM8: i32>(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_ror_epi64<const IMM8: i32>(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_rorv_epi32(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_rorv_epi64(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_roundscale_pd<const IMM8: i32>(src: __m256d, k: __mmask8, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_roundscale_ps<const IMM8: i32>(src: __m256, k: __mmask8, a: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask_rsqrt14_pd(src: __m256d, k: __mmask8, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_rsqrt14_ps(src: __m256, k: __mmask8, a: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask_scalef_pd(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_scalef_ps(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_mask_set1_epi16(src: __m256i, k: __mmask16, a: i16) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_set1_epi32(src: __m256i, k: __mmask8, a: i32) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_set1_epi64(src: __m256i, k: __mmask8, a: i64) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_set1_epi8(src: __m256i, k: __mmask32, a: i8) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_mask_shldi_epi16<const IMM8: i32>(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_mask_shldi_epi32<const IMM8: i32>(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_mask_shldi_epi64<const IMM8: i32>(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_mask_shldv_epi16(a: __m256i, k: __mmask16, b: __m256i, c: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_mask_shldv_epi32(a: __m256i, k: __mmask8, b: __m256i, c: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_mask_shldv_epi64(a: __m256i, k: __mmask8, b: __m256i, c: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_mask_shrdi_epi16<const IMM8: i32>(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_mask_shrdi_epi32<const IMM8: i32>(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_mask_shrdi_epi64<const IMM8: i32>(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_mask_shrdv_epi16(a: __m256i, k: __mmask16, b: __m256i, c: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_mask_shrdv_epi32(a: __m256i, k: __mmask8, b: __m256i, c: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_mask_shrdv_epi64(a: __m256i, k: __mmask8, b: __m256i, c: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_shuffle_epi32<const MASK: _MM_PERM_ENUM>(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_shuffle_epi8(src: __m256i, k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_shuffle_f32x4<const MASK: i32>(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask_shuffle_f64x2<const MASK: i32>(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_shuffle_i32x4<const MASK: i32>(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_shuffle_i64x2<const MASK: i32>(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_shuffle_pd<const MASK: i32>(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_shuffle_ps<const MASK: i32>(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_mask_shufflehi_epi16<const IMM8: i32>(src: __m256i, k: __mmask16, a: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_shufflelo_epi16<const IMM8: i32>(src: __m256i, k: __mmask16, a: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_sll_epi16(src: __m256i, k: __mmask16, a: __m256i, count: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_sll_epi32(src: __m256i, k: __mmask8, a: __m256i, count: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_sll_epi64(src: __m256i, k: __mmask8, a: __m256i, count: __m128i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_slli_epi16<const IMM8: u32>(src: __m256i, k: __mmask16, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_slli_epi32<const IMM8: u32>(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_slli_epi64<const IMM8: u32>(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_sllv_epi16(src: __m256i, k: __mmask16, a: __m256i, count: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_sllv_epi32(src: __m256i, k: __mmask8, a: __m256i, count: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_sllv_epi64(src: __m256i, k: __mmask8, a: __m256i, count: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_sqrt_pd(src: __m256d, k: __mmask8, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_sqrt_ps(src: __m256, k: __mmask8, a: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_mask_sra_epi16(src: __m256i, k: __mmask16, a: __m256i, count: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_sra_epi32(src: __m256i, k: __mmask8, a: __m256i, count: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_sra_epi64(src: __m256i, k: __mmask8, a: __m256i, count: __m128i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_srai_epi16<const IMM8: u32>(src: __m256i, k: __mmask16, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_srai_epi32<const IMM8: u32>(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_srai_epi64<const IMM8: u32>(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_srav_epi16(src: __m256i, k: __mmask16, a: __m256i, count: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_srav_epi32(src: __m256i, k: __mmask8, a: __m256i, count: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_srav_epi64(src: __m256i, k: __mmask8, a: __m256i, count: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_srl_epi16(src: __m256i, k: __mmask16, a: __m256i, count: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_srl_epi32(src: __m256i, k: __mmask8, a: __m256i, count: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_srl_epi64(src: __m256i, k: __mmask8, a: __m256i, count: __m128i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_srli_epi16<const IMM8: i32>(src: __m256i, k: __mmask16, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_srli_epi32<const IMM8: u32>(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_srli_epi64<const IMM8: u32>(src: __m256i, k: __mmask8, a: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_srlv_epi16(src: __m256i, k: __mmask16, a: __m256i, count: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_srlv_epi32(src: __m256i, k: __mmask8, a: __m256i, count: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_srlv_epi64(src: __m256i, k: __mmask8, a: __m256i, count: __m256i) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_mask_store_epi32(mem_addr: *mut i32, mask: __mmask8, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_store_epi64(mem_addr: *mut i64, mask: __mmask8, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_store_pd(mem_addr: *mut f64, mask: __mmask8, a: __m256d);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_store_ps(mem_addr: *mut f32, mask: __mmask8, a: __m256);
+        [avx512bw, avx512vl] unsafe fn _mm256_mask_storeu_epi16(mem_addr: *mut i16, mask: __mmask16, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_storeu_epi32(mem_addr: *mut i32, mask: __mmask8, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_storeu_epi64(mem_addr: *mut i64, mask: __mmask8, a: __m256i);
+        [avx512bw, avx512vl] unsafe fn _mm256_mask_storeu_epi8(mem_addr: *mut i8, mask: __mmask32, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_storeu_pd(mem_addr: *mut f64, mask: __mmask8, a: __m256d);
+        [avx512f, avx512vl] unsafe fn _mm256_mask_storeu_ps(mem_addr: *mut f32, mask: __mmask8, a: __m256);
+        [avx512bw, avx512vl] safe fn _mm256_mask_sub_epi16(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_sub_epi32(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_sub_epi64(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_sub_epi8(src: __m256i, k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_sub_pd(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_sub_ps(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_mask_subs_epi16(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_subs_epi8(src: __m256i, k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_subs_epu16(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_subs_epu8(src: __m256i, k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_ternarylogic_epi32<const IMM8: i32>(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_ternarylogic_epi64<const IMM8: i32>(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_test_epi16_mask(k: __mmask16, a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_mask_test_epi32_mask(k: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_mask_test_epi64_mask(k: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_test_epi8_mask(k: __mmask32, a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_mask_testn_epi16_mask(k: __mmask16, a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_mask_testn_epi32_mask(k: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_mask_testn_epi64_mask(k: __mmask8, a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_mask_testn_epi8_mask(k: __mmask32, a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_mask_unpackhi_epi16(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_unpackhi_epi32(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_unpackhi_epi64(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_unpackhi_epi8(src: __m256i, k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_unpackhi_pd(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_unpackhi_ps(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_mask_unpacklo_epi16(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_unpacklo_epi32(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_unpacklo_epi64(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_mask_unpacklo_epi8(src: __m256i, k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_unpacklo_pd(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_mask_unpacklo_ps(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_mask_xor_epi32(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_mask_xor_epi64(src: __m256i, k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_mask_xor_pd(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_mask_xor_ps(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_abs_epi16(k: __mmask16, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_abs_epi32(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_abs_epi64(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_abs_epi8(k: __mmask32, a: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_add_epi16(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_add_epi32(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_add_epi64(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_add_epi8(k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_add_pd(k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_add_ps(k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_adds_epi16(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_adds_epi8(k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_adds_epu16(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_adds_epu8(k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_alignr_epi32<const IMM8: i32>(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_alignr_epi64<const IMM8: i32>(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_alignr_epi8<const IMM8: i32>(k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_and_epi32(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_and_epi64(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_and_pd(k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_and_ps(k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_andnot_epi32(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_andnot_epi64(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_andnot_pd(k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_andnot_ps(k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_avg_epu16(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_avg_epu8(k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_broadcast_f32x2(k: __mmask8, a: __m128) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_broadcast_f32x4(k: __mmask8, a: __m128) -> __m256;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_broadcast_f64x2(k: __mmask8, a: __m128d) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_broadcast_i32x2(k: __mmask8, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_broadcast_i32x4(k: __mmask8, a: __m128i) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_broadcast_i64x2(k: __mmask8, a: __m128i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_broadcastb_epi8(k: __mmask32, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_broadcastd_epi32(k: __mmask8, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_broadcastq_epi64(k: __mmask8, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_broadcastsd_pd(k: __mmask8, a: __m128d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_broadcastss_ps(k: __mmask8, a: __m128) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_broadcastw_epi16(k: __mmask16, a: __m128i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_maskz_compress_epi16(k: __mmask16, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_compress_epi32(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_compress_epi64(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_maskz_compress_epi8(k: __mmask32, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_compress_pd(k: __mmask8, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_compress_ps(k: __mmask8, a: __m256) -> __m256;
+        [avx512cd, avx512vl] safe fn _mm256_maskz_conflict_epi32(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512cd, avx512vl] safe fn _mm256_maskz_conflict_epi64(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvt_roundps_ph<const IMM8: i32>(k: __mmask8, a: __m256) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtepi16_epi32(k: __mmask8, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtepi16_epi64(k: __mmask8, a: __m128i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_cvtepi16_epi8(k: __mmask16, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtepi32_epi16(k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtepi32_epi64(k: __mmask8, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtepi32_epi8(k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtepi32_pd(k: __mmask8, a: __m128i) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtepi32_ps(k: __mmask8, a: __m256i) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtepi64_epi16(k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtepi64_epi32(k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtepi64_epi8(k: __mmask8, a: __m256i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_cvtepi64_pd(k: __mmask8, a: __m256i) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_cvtepi64_ps(k: __mmask8, a: __m256i) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_cvtepi8_epi16(k: __mmask16, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtepi8_epi32(k: __mmask8, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtepi8_epi64(k: __mmask8, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtepu16_epi32(k: __mmask8, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtepu16_epi64(k: __mmask8, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtepu32_epi64(k: __mmask8, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtepu32_pd(k: __mmask8, a: __m128i) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_cvtepu64_pd(k: __mmask8, a: __m256i) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_cvtepu64_ps(k: __mmask8, a: __m256i) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_cvtepu8_epi16(k: __mmask16, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtepu8_epi32(k: __mmask8, a: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtepu8_epi64(k: __mmask8, a: __m128i) -> __m256i;
+        [avx512bf16, avx512vl] safe fn _mm256_maskz_cvtne2ps_pbh(k: __mmask16, a: __m256, b: __m256) -> __m256bh;
+        [avx512bf16, avx512vl] safe fn _mm256_maskz_cvtneps_pbh(k: __mmask8, a: __m256) -> __m128bh;
+        [avx512bf16, avx512vl] safe fn _mm256_maskz_cvtpbh_ps(k: __mmask8, a: __m128bh) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtpd_epi32(k: __mmask8, a: __m256d) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_cvtpd_epi64(k: __mmask8, a: __m256d) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtpd_epu32(k: __mmask8, a: __m256d) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_cvtpd_epu64(k: __mmask8, a: __m256d) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtpd_ps(k: __mmask8, a: __m256d) -> __m128;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtph_ps(k: __mmask8, a: __m128i) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtps_epi32(k: __mmask8, a: __m256) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_cvtps_epi64(k: __mmask8, a: __m128) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtps_epu32(k: __mmask8, a: __m256) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_cvtps_epu64(k: __mmask8, a: __m128) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtps_ph<const IMM8: i32>(k: __mmask8, a: __m256) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_cvtsepi16_epi8(k: __mmask16, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtsepi32_epi16(k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtsepi32_epi8(k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtsepi64_epi16(k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtsepi64_epi32(k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtsepi64_epi8(k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvttpd_epi32(k: __mmask8, a: __m256d) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_cvttpd_epi64(k: __mmask8, a: __m256d) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvttpd_epu32(k: __mmask8, a: __m256d) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_cvttpd_epu64(k: __mmask8, a: __m256d) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvttps_epi32(k: __mmask8, a: __m256) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_cvttps_epi64(k: __mmask8, a: __m128) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvttps_epu32(k: __mmask8, a: __m256) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_cvttps_epu64(k: __mmask8, a: __m128) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_cvtusepi16_epi8(k: __mmask16, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtusepi32_epi16(k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtusepi32_epi8(k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtusepi64_epi16(k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtusepi64_epi32(k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_cvtusepi64_epi8(k: __mmask8, a: __m256i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_dbsad_epu8<const IMM8: i32>(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_div_pd(k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_div_ps(k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512bf16, avx512vl] safe fn _mm256_maskz_dpbf16_ps(k: __mmask8, src: __m256, a: __m256bh, b: __m256bh) -> __m256;
+        [avx512vnni, avx512vl] safe fn _mm256_maskz_dpbusd_epi32(k: __mmask8, src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vnni, avx512vl] safe fn _mm256_maskz_dpbusds_epi32(k: __mmask8, src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vnni, avx512vl] safe fn _mm256_maskz_dpwssd_epi32(k: __mmask8, src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vnni, avx512vl] safe fn _mm256_maskz_dpwssds_epi32(k: __mmask8, src: __m256i, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_maskz_expand_epi16(k: __mmask16, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_expand_epi32(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_expand_epi64(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_maskz_expand_epi8(k: __mmask32, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_expand_pd(k: __mmask8, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_expand_ps(k: __mmask8, a: __m256) -> __m256;
+        [avx512vbmi2, avx512vl] unsafe fn _mm256_maskz_expandloadu_epi16(k: __mmask16, mem_addr: *const i16) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_maskz_expandloadu_epi32(k: __mmask8, mem_addr: *const i32) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_maskz_expandloadu_epi64(k: __mmask8, mem_addr: *const i64) -> __m256i;
+        [avx512vbmi2, avx512vl] unsafe fn _mm256_maskz_expandloadu_epi8(k: __mmask32, mem_addr: *const i8) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_maskz_expandloadu_pd(k: __mmask8, mem_addr: *const f64) -> __m256d;
+        [avx512f, avx512vl] unsafe fn _mm256_maskz_expandloadu_ps(k: __mmask8, mem_addr: *const f32) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_extractf32x4_ps<const IMM8: i32>(k: __mmask8, a: __m256) -> __m128;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_extractf64x2_pd<const IMM8: i32>(k: __mmask8, a: __m256d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_extracti32x4_epi32<const IMM1: i32>(k: __mmask8, a: __m256i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_extracti64x2_epi64<const IMM8: i32>(k: __mmask8, a: __m256i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_fixupimm_pd<const IMM8: i32>(k: __mmask8, a: __m256d, b: __m256d, c: __m256i) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_fixupimm_ps<const IMM8: i32>(k: __mmask8, a: __m256, b: __m256, c: __m256i) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_fmadd_pd(k: __mmask8, a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_fmadd_ps(k: __mmask8, a: __m256, b: __m256, c: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_fmaddsub_pd(k: __mmask8, a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_fmaddsub_ps(k: __mmask8, a: __m256, b: __m256, c: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_fmsub_pd(k: __mmask8, a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_fmsub_ps(k: __mmask8, a: __m256, b: __m256, c: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_fmsubadd_pd(k: __mmask8, a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_fmsubadd_ps(k: __mmask8, a: __m256, b: __m256, c: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_fnmadd_pd(k: __mmask8, a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_fnmadd_ps(k: __mmask8, a: __m256, b: __m256, c: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_fnmsub_pd(k: __mmask8, a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_fnmsub_ps(k: __mmask8, a: __m256, b: __m256, c: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_getexp_pd(k: __mmask8, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_getexp_ps(k: __mmask8, a: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_getmant_pd<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(k: __mmask8, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_getmant_ps<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(k: __mmask8, a: __m256) -> __m256;
+        [gfni, avx512bw, avx512vl] safe fn _mm256_maskz_gf2p8affine_epi64_epi8<const B: i32>(k: __mmask32, x: __m256i, a: __m256i) -> __m256i;
+        [gfni, avx512bw, avx512vl] safe fn _mm256_maskz_gf2p8affineinv_epi64_epi8<const B: i32>(k: __mmask32, x: __m256i, a: __m256i) -> __m256i;
+        [gfni, avx512bw, avx512vl] safe fn _mm256_maskz_gf2p8mul_epi8(k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_insertf32x4<const IMM8: i32>(k: __mmask8, a: __m256, b: __m128) -> __m256;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_insertf64x2<const IMM8: i32>(k: __mmask8, a: __m256d, b: __m128d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_inserti32x4<const IMM8: i32>(k: __mmask8, a: __m256i, b: __m128i) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_inserti64x2<const IMM8: i32>(k: __mmask8, a: __m256i, b: __m128i) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_maskz_load_epi32(k: __mmask8, mem_addr: *const i32) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_maskz_load_epi64(k: __mmask8, mem_addr: *const i64) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_maskz_load_pd(k: __mmask8, mem_addr: *const f64) -> __m256d;
+        [avx512f, avx512vl] unsafe fn _mm256_maskz_load_ps(k: __mmask8, mem_addr: *const f32) -> __m256;
+        [avx512bw, avx512vl] unsafe fn _mm256_maskz_loadu_epi16(k: __mmask16, mem_addr: *const i16) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_maskz_loadu_epi32(k: __mmask8, mem_addr: *const i32) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_maskz_loadu_epi64(k: __mmask8, mem_addr: *const i64) -> __m256i;
+        [avx512bw, avx512vl] unsafe fn _mm256_maskz_loadu_epi8(k: __mmask32, mem_addr: *const i8) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_maskz_loadu_pd(k: __mmask8, mem_addr: *const f64) -> __m256d;
+        [avx512f, avx512vl] unsafe fn _mm256_maskz_loadu_ps(k: __mmask8, mem_addr: *const f32) -> __m256;
+        [avx512cd, avx512vl] safe fn _mm256_maskz_lzcnt_epi32(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512cd, avx512vl] safe fn _mm256_maskz_lzcnt_epi64(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512ifma, avx512vl] safe fn _mm256_maskz_madd52hi_epu64(k: __mmask8, a: __m256i, b: __m256i, c: __m256i) -> __m256i;
+        [avx512ifma, avx512vl] safe fn _mm256_maskz_madd52lo_epu64(k: __mmask8, a: __m256i, b: __m256i, c: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_madd_epi16(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_maddubs_epi16(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_max_epi16(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_max_epi32(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_max_epi64(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_max_epi8(k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_max_epu16(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_max_epu32(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_max_epu64(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_max_epu8(k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_max_pd(k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_max_ps(k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_min_epi16(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_min_epi32(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_min_epi64(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_min_epi8(k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_min_epu16(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_min_epu32(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_min_epu64(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_min_epu8(k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_min_pd(k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_min_ps(k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_mov_epi16(k: __mmask16, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_mov_epi32(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_mov_epi64(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_mov_epi8(k: __mmask32, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_mov_pd(k: __mmask8, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_mov_ps(k: __mmask8, a: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_movedup_pd(k: __mmask8, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_movehdup_ps(k: __mmask8, a: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_moveldup_ps(k: __mmask8, a: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_mul_epi32(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_mul_epu32(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_mul_pd(k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_mul_ps(k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_mulhi_epi16(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_mulhi_epu16(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_mulhrs_epi16(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_mullo_epi16(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_mullo_epi32(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_mullo_epi64(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi, avx512vl] safe fn _mm256_maskz_multishift_epi64_epi8(k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_or_epi32(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_or_epi64(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_or_pd(k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_or_ps(k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_packs_epi16(k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_packs_epi32(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_packus_epi16(k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_packus_epi32(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_permute_pd<const MASK: i32>(k: __mmask8, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_permute_ps<const MASK: i32>(k: __mmask8, a: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_permutevar_pd(k: __mmask8, a: __m256d, b: __m256i) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_permutevar_ps(k: __mmask8, a: __m256, b: __m256i) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_permutex2var_epi16(k: __mmask16, a: __m256i, idx: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_permutex2var_epi32(k: __mmask8, a: __m256i, idx: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_permutex2var_epi64(k: __mmask8, a: __m256i, idx: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi, avx512vl] safe fn _mm256_maskz_permutex2var_epi8(k: __mmask32, a: __m256i, idx: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_permutex2var_pd(k: __mmask8, a: __m256d, idx: __m256i, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_permutex2var_ps(k: __mmask8, a: __m256, idx: __m256i, b: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_permutex_epi64<const MASK: i32>(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_permutex_pd<const MASK: i32>(k: __mmask8, a: __m256d) -> __m256d;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_permutexvar_epi16(k: __mmask16, idx: __m256i, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_permutexvar_epi32(k: __mmask8, idx: __m256i, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_permutexvar_epi64(k: __mmask8, idx: __m256i, a: __m256i) -> __m256i;
+        [avx512vbmi, avx512vl] safe fn _mm256_maskz_permutexvar_epi8(k: __mmask32, idx: __m256i, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_permutexvar_pd(k: __mmask8, idx: __m256i, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_permutexvar_ps(k: __mmask8, idx: __m256i, a: __m256) -> __m256;
+        [avx512bitalg, avx512vl] safe fn _mm256_maskz_popcnt_epi16(k: __mmask16, a: __m256i) -> __m256i;
+        [avx512vpopcntdq, avx512vl] safe fn _mm256_maskz_popcnt_epi32(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512vpopcntdq, avx512vl] safe fn _mm256_maskz_popcnt_epi64(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512bitalg, avx512vl] safe fn _mm256_maskz_popcnt_epi8(k: __mmask32, a: __m256i) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_range_pd<const IMM8: i32>(k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_range_ps<const IMM8: i32>(k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_rcp14_pd(k: __mmask8, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_rcp14_ps(k: __mmask8, a: __m256) -> __m256;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_reduce_pd<const IMM8: i32>(k: __mmask8, a: __m256d) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_reduce_ps<const IMM8: i32>(k: __mmask8, a: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_rol_epi32<const IMM8: i32>(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_rol_epi64<const IMM8: i32>(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_rolv_epi32(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_rolv_epi64(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_ror_epi32<const IMM8: i32>(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_ror_epi64<const IMM8: i32>(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_rorv_epi32(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_rorv_epi64(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_roundscale_pd<const IMM8: i32>(k: __mmask8, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_roundscale_ps<const IMM8: i32>(k: __mmask8, a: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_rsqrt14_pd(k: __mmask8, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_rsqrt14_ps(k: __mmask8, a: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_scalef_pd(k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_scalef_ps(k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_set1_epi16(k: __mmask16, a: i16) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_set1_epi32(k: __mmask8, a: i32) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_set1_epi64(k: __mmask8, a: i64) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_set1_epi8(k: __mmask32, a: i8) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_maskz_shldi_epi16<const IMM8: i32>(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_maskz_shldi_epi32<const IMM8: i32>(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_maskz_shldi_epi64<const IMM8: i32>(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_maskz_shldv_epi16(k: __mmask16, a: __m256i, b: __m256i, c: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_maskz_shldv_epi32(k: __mmask8, a: __m256i, b: __m256i, c: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_maskz_shldv_epi64(k: __mmask8, a: __m256i, b: __m256i, c: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_maskz_shrdi_epi16<const IMM8: i32>(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_maskz_shrdi_epi32<const IMM8: i32>(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_maskz_shrdi_epi64<const IMM8: i32>(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_maskz_shrdv_epi16(k: __mmask16, a: __m256i, b: __m256i, c: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_maskz_shrdv_epi32(k: __mmask8, a: __m256i, b: __m256i, c: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_maskz_shrdv_epi64(k: __mmask8, a: __m256i, b: __m256i, c: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_shuffle_epi32<const MASK: _MM_PERM_ENUM>(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_shuffle_epi8(k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_shuffle_f32x4<const MASK: i32>(k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_shuffle_f64x2<const MASK: i32>(k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_shuffle_i32x4<const MASK: i32>(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_shuffle_i64x2<const MASK: i32>(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_shuffle_pd<const MASK: i32>(k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_shuffle_ps<const MASK: i32>(k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_shufflehi_epi16<const IMM8: i32>(k: __mmask16, a: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_shufflelo_epi16<const IMM8: i32>(k: __mmask16, a: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_sll_epi16(k: __mmask16, a: __m256i, count: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_sll_epi32(k: __mmask8, a: __m256i, count: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_sll_epi64(k: __mmask8, a: __m256i, count: __m128i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_slli_epi16<const IMM8: u32>(k: __mmask16, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_slli_epi32<const IMM8: u32>(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_slli_epi64<const IMM8: u32>(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_sllv_epi16(k: __mmask16, a: __m256i, count: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_sllv_epi32(k: __mmask8, a: __m256i, count: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_sllv_epi64(k: __mmask8, a: __m256i, count: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_sqrt_pd(k: __mmask8, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_sqrt_ps(k: __mmask8, a: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_sra_epi16(k: __mmask16, a: __m256i, count: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_sra_epi32(k: __mmask8, a: __m256i, count: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_sra_epi64(k: __mmask8, a: __m256i, count: __m128i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_srai_epi16<const IMM8: u32>(k: __mmask16, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_srai_epi32<const IMM8: u32>(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_srai_epi64<const IMM8: u32>(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_srav_epi16(k: __mmask16, a: __m256i, count: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_srav_epi32(k: __mmask8, a: __m256i, count: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_srav_epi64(k: __mmask8, a: __m256i, count: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_srl_epi16(k: __mmask16, a: __m256i, count: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_srl_epi32(k: __mmask8, a: __m256i, count: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_srl_epi64(k: __mmask8, a: __m256i, count: __m128i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_srli_epi16<const IMM8: i32>(k: __mmask16, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_srli_epi32<const IMM8: u32>(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_srli_epi64<const IMM8: u32>(k: __mmask8, a: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_srlv_epi16(k: __mmask16, a: __m256i, count: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_srlv_epi32(k: __mmask8, a: __m256i, count: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_srlv_epi64(k: __mmask8, a: __m256i, count: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_sub_epi16(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_sub_epi32(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_sub_epi64(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_sub_epi8(k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_sub_pd(k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_sub_ps(k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_subs_epi16(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_subs_epi8(k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_subs_epu16(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_subs_epu8(k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_ternarylogic_epi32<const IMM8: i32>(k: __mmask8, a: __m256i, b: __m256i, c: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_ternarylogic_epi64<const IMM8: i32>(k: __mmask8, a: __m256i, b: __m256i, c: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_unpackhi_epi16(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_unpackhi_epi32(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_unpackhi_epi64(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_unpackhi_epi8(k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_unpackhi_pd(k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_unpackhi_ps(k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_unpacklo_epi16(k: __mmask16, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_unpacklo_epi32(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_unpacklo_epi64(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_maskz_unpacklo_epi8(k: __mmask32, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_unpacklo_pd(k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_maskz_unpacklo_ps(k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_maskz_xor_epi32(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_maskz_xor_epi64(k: __mmask8, a: __m256i, b: __m256i) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_xor_pd(k: __mmask8, a: __m256d, b: __m256d) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_maskz_xor_ps(k: __mmask8, a: __m256, b: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_max_epi64(a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_max_epu64(a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_min_epi64(a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_min_epu64(a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_mmask_i32gather_epi32<const SCALE: i32>(src: __m256i, k: __mmask8, vindex: __m256i, base_addr: *const i32) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_mmask_i32gather_epi64<const SCALE: i32>(src: __m256i, k: __mmask8, vindex: __m128i, base_addr: *const i64) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_mmask_i32gather_pd<const SCALE: i32>(src: __m256d, k: __mmask8, vindex: __m128i, base_addr: *const f64) -> __m256d;
+        [avx512f, avx512vl] unsafe fn _mm256_mmask_i32gather_ps<const SCALE: i32>(src: __m256, k: __mmask8, vindex: __m256i, base_addr: *const f32) -> __m256;
+        [avx512f, avx512vl] unsafe fn _mm256_mmask_i64gather_epi32<const SCALE: i32>(src: __m128i, k: __mmask8, vindex: __m256i, base_addr: *const i32) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm256_mmask_i64gather_epi64<const SCALE: i32>(src: __m256i, k: __mmask8, vindex: __m256i, base_addr: *const i64) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_mmask_i64gather_pd<const SCALE: i32>(src: __m256d, k: __mmask8, vindex: __m256i, base_addr: *const f64) -> __m256d;
+        [avx512f, avx512vl] unsafe fn _mm256_mmask_i64gather_ps<const SCALE: i32>(src: __m128, k: __mmask8, vindex: __m256i, base_addr: *const f32) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm256_movepi16_mask(a: __m256i) -> __mmask16;
+        [avx512dq, avx512vl] safe fn _mm256_movepi32_mask(a: __m256i) -> __mmask8;
+        [avx512dq, avx512vl] safe fn _mm256_movepi64_mask(a: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_movepi8_mask(a: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_movm_epi16(k: __mmask16) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_movm_epi32(k: __mmask8) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_movm_epi64(k: __mmask8) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_movm_epi8(k: __mmask32) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_mullo_epi64(a: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi, avx512vl] safe fn _mm256_multishift_epi64_epi8(a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_or_epi32(a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_or_epi64(a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_permutex2var_epi16(a: __m256i, idx: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_permutex2var_epi32(a: __m256i, idx: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_permutex2var_epi64(a: __m256i, idx: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi, avx512vl] safe fn _mm256_permutex2var_epi8(a: __m256i, idx: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_permutex2var_pd(a: __m256d, idx: __m256i, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_permutex2var_ps(a: __m256, idx: __m256i, b: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_permutex_epi64<const MASK: i32>(a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_permutex_pd<const MASK: i32>(a: __m256d) -> __m256d;
+        [avx512bw, avx512vl] safe fn _mm256_permutexvar_epi16(idx: __m256i, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_permutexvar_epi32(idx: __m256i, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_permutexvar_epi64(idx: __m256i, a: __m256i) -> __m256i;
+        [avx512vbmi, avx512vl] safe fn _mm256_permutexvar_epi8(idx: __m256i, a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_permutexvar_pd(idx: __m256i, a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_permutexvar_ps(idx: __m256i, a: __m256) -> __m256;
+        [avx512bitalg, avx512vl] safe fn _mm256_popcnt_epi16(a: __m256i) -> __m256i;
+        [avx512vpopcntdq, avx512vl] safe fn _mm256_popcnt_epi32(a: __m256i) -> __m256i;
+        [avx512vpopcntdq, avx512vl] safe fn _mm256_popcnt_epi64(a: __m256i) -> __m256i;
+        [avx512bitalg, avx512vl] safe fn _mm256_popcnt_epi8(a: __m256i) -> __m256i;
+        [avx512dq, avx512vl] safe fn _mm256_range_pd<const IMM8: i32>(a: __m256d, b: __m256d) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_range_ps<const IMM8: i32>(a: __m256, b: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_rcp14_pd(a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_rcp14_ps(a: __m256) -> __m256;
+        [avx512bw, avx512vl] safe fn _mm256_reduce_add_epi16(a: __m256i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm256_reduce_add_epi8(a: __m256i) -> i8;
+        [avx512bw, avx512vl] safe fn _mm256_reduce_and_epi16(a: __m256i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm256_reduce_and_epi8(a: __m256i) -> i8;
+        [avx512bw, avx512vl] safe fn _mm256_reduce_max_epi16(a: __m256i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm256_reduce_max_epi8(a: __m256i) -> i8;
+        [avx512bw, avx512vl] safe fn _mm256_reduce_max_epu16(a: __m256i) -> u16;
+        [avx512bw, avx512vl] safe fn _mm256_reduce_max_epu8(a: __m256i) -> u8;
+        [avx512bw, avx512vl] safe fn _mm256_reduce_min_epi16(a: __m256i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm256_reduce_min_epi8(a: __m256i) -> i8;
+        [avx512bw, avx512vl] safe fn _mm256_reduce_min_epu16(a: __m256i) -> u16;
+        [avx512bw, avx512vl] safe fn _mm256_reduce_min_epu8(a: __m256i) -> u8;
+        [avx512bw, avx512vl] safe fn _mm256_reduce_mul_epi16(a: __m256i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm256_reduce_mul_epi8(a: __m256i) -> i8;
+        [avx512bw, avx512vl] safe fn _mm256_reduce_or_epi16(a: __m256i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm256_reduce_or_epi8(a: __m256i) -> i8;
+        [avx512dq, avx512vl] safe fn _mm256_reduce_pd<const IMM8: i32>(a: __m256d) -> __m256d;
+        [avx512dq, avx512vl] safe fn _mm256_reduce_ps<const IMM8: i32>(a: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_rol_epi32<const IMM8: i32>(a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_rol_epi64<const IMM8: i32>(a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_rolv_epi32(a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_rolv_epi64(a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_ror_epi32<const IMM8: i32>(a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_ror_epi64<const IMM8: i32>(a: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_rorv_epi32(a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_rorv_epi64(a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_roundscale_pd<const IMM8: i32>(a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_roundscale_ps<const IMM8: i32>(a: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_rsqrt14_pd(a: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_rsqrt14_ps(a: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_scalef_pd(a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_scalef_ps(a: __m256, b: __m256) -> __m256;
+        [sha512, avx] safe fn _mm256_sha512msg1_epi64(a: __m256i, b: __m128i) -> __m256i;
+        [sha512, avx] safe fn _mm256_sha512msg2_epi64(a: __m256i, b: __m256i) -> __m256i;
+        [sha512, avx] safe fn _mm256_sha512rnds2_epi64(a: __m256i, b: __m256i, k: __m128i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_shldi_epi16<const IMM8: i32>(a: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_shldi_epi32<const IMM8: i32>(a: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_shldi_epi64<const IMM8: i32>(a: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_shldv_epi16(a: __m256i, b: __m256i, c: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_shldv_epi32(a: __m256i, b: __m256i, c: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_shldv_epi64(a: __m256i, b: __m256i, c: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_shrdi_epi16<const IMM8: i32>(a: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_shrdi_epi32<const IMM8: i32>(a: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_shrdi_epi64<const IMM8: i32>(a: __m256i, b: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_shrdv_epi16(a: __m256i, b: __m256i, c: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_shrdv_epi32(a: __m256i, b: __m256i, c: __m256i) -> __m256i;
+        [avx512vbmi2, avx512vl] safe fn _mm256_shrdv_epi64(a: __m256i, b: __m256i, c: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_shuffle_f32x4<const MASK: i32>(a: __m256, b: __m256) -> __m256;
+        [avx512f, avx512vl] safe fn _mm256_shuffle_f64x2<const MASK: i32>(a: __m256d, b: __m256d) -> __m256d;
+        [avx512f, avx512vl] safe fn _mm256_shuffle_i32x4<const MASK: i32>(a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_shuffle_i64x2<const MASK: i32>(a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_sllv_epi16(a: __m256i, count: __m256i) -> __m256i;
+        [sm4, avx] safe fn _mm256_sm4key4_epi32(a: __m256i, b: __m256i) -> __m256i;
+        [sm4, avx] safe fn _mm256_sm4rnds4_epi32(a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_sra_epi64(a: __m256i, count: __m128i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_srai_epi64<const IMM8: u32>(a: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_srav_epi16(a: __m256i, count: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_srav_epi64(a: __m256i, count: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_srlv_epi16(a: __m256i, count: __m256i) -> __m256i;
+        [avx512f, avx512vl] unsafe fn _mm256_store_epi32(mem_addr: *mut i32, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_store_epi64(mem_addr: *mut i64, a: __m256i);
+        [avx512bw, avx512vl] unsafe fn _mm256_storeu_epi16(mem_addr: *mut i16, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_storeu_epi32(mem_addr: *mut i32, a: __m256i);
+        [avx512f, avx512vl] unsafe fn _mm256_storeu_epi64(mem_addr: *mut i64, a: __m256i);
+        [avx512bw, avx512vl] unsafe fn _mm256_storeu_epi8(mem_addr: *mut i8, a: __m256i);
+        [avx512f, avx512vl] safe fn _mm256_ternarylogic_epi32<const IMM8: i32>(a: __m256i, b: __m256i, c: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_ternarylogic_epi64<const IMM8: i32>(a: __m256i, b: __m256i, c: __m256i) -> __m256i;
+        [avx512bw, avx512vl] safe fn _mm256_test_epi16_mask(a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_test_epi32_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_test_epi64_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_test_epi8_mask(a: __m256i, b: __m256i) -> __mmask32;
+        [avx512bw, avx512vl] safe fn _mm256_testn_epi16_mask(a: __m256i, b: __m256i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm256_testn_epi32_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm256_testn_epi64_mask(a: __m256i, b: __m256i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm256_testn_epi8_mask(a: __m256i, b: __m256i) -> __mmask32;
+        [avx512f, avx512vl] safe fn _mm256_xor_epi32(a: __m256i, b: __m256i) -> __m256i;
+        [avx512f, avx512vl] safe fn _mm256_xor_epi64(a: __m256i, b: __m256i) -> __m256i;
+        [avx512bw] safe fn _mm512_abs_epi16(a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_abs_epi32(a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_abs_epi64(a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_abs_epi8(a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_abs_pd(v2: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_abs_ps(v2: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_add_epi16(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_add_epi32(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_add_epi64(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_add_epi8(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_add_pd(a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_add_ps(a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_add_round_pd<const ROUNDING: i32>(a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_add_round_ps<const ROUNDING: i32>(a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_adds_epi16(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_adds_epi8(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_adds_epu16(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_adds_epu8(a: __m512i, b: __m512i) -> __m512i;
+        [vaes, avx512f] safe fn _mm512_aesdec_epi128(a: __m512i, round_key: __m512i) -> __m512i;
+        [vaes, avx512f] safe fn _mm512_aesdeclast_epi128(a: __m512i, round_key: __m512i) -> __m512i;
+        [vaes, avx512f] safe fn _mm512_aesenc_epi128(a: __m512i, round_key: __m512i) -> __m512i;
+        [vaes, avx512f] safe fn _mm512_aesenclast_epi128(a: __m512i, round_key: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_alignr_epi32<const IMM8: i32>(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_alignr_epi64<const IMM8: i32>(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_alignr_epi8<const IMM8: i32>(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_and_epi32(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_and_epi64(a: __m512i, b: __m512i) -> __m512i;
+        [avx512dq] safe fn _mm512_and_pd(a: __m512d, b: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_and_ps(a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_and_si512(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_andnot_epi32(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_andnot_epi64(a: __m512i, b: __m512i) -> __m512i;
+        [avx512dq] safe fn _mm512_andnot_pd(a: __m512d, b: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_andnot_ps(a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_andnot_si512(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_avg_epu16(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_avg_epu8(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bitalg] safe fn _mm512_bitshuffle_epi64_mask(b: __m512i, c: __m512i) -> __mmask64;
+        [avx512dq] safe fn _mm512_broadcast_f32x2(a: __m128) -> __m512;
+        [avx512f] safe fn _mm512_broadcast_f32x4(a: __m128) -> __m512;
+        [avx512dq] safe fn _mm512_broadcast_f32x8(a: __m256) -> __m512;
+        [avx512dq] safe fn _mm512_broadcast_f64x2(a: __m128d) -> __m512d;
+        [avx512f] safe fn _mm512_broadcast_f64x4(a: __m256d) -> __m512d;
+        [avx512dq] safe fn _mm512_broadcast_i32x2(a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_broadcast_i32x4(a: __m128i) -> __m512i;
+        [avx512dq] safe fn _mm512_broadcast_i32x8(a: __m256i) -> __m512i;
+        [avx512dq] safe fn _mm512_broadcast_i64x2(a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_broadcast_i64x4(a: __m256i) -> __m512i;
+        [avx512bw] safe fn _mm512_broadcastb_epi8(a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_broadcastd_epi32(a: __m128i) -> __m512i;
+        [avx512cd] safe fn _mm512_broadcastmb_epi64(k: __mmask8) -> __m512i;
+        [avx512cd] safe fn _mm512_broadcastmw_epi32(k: __mmask16) -> __m512i;
+        [avx512f] safe fn _mm512_broadcastq_epi64(a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_broadcastsd_pd(a: __m128d) -> __m512d;
+        [avx512f] safe fn _mm512_broadcastss_ps(a: __m128) -> __m512;
+        [avx512bw] safe fn _mm512_broadcastw_epi16(a: __m128i) -> __m512i;
+        [avx512bw] safe fn _mm512_bslli_epi128<const IMM8: i32>(a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_bsrli_epi128<const IMM8: i32>(a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_castpd128_pd512(a: __m128d) -> __m512d;
+        [avx512f] safe fn _mm512_castpd256_pd512(a: __m256d) -> __m512d;
+        [avx512f] safe fn _mm512_castpd512_pd128(a: __m512d) -> __m128d;
+        [avx512f] safe fn _mm512_castpd512_pd256(a: __m512d) -> __m256d;
+        [avx512f] safe fn _mm512_castpd_ps(a: __m512d) -> __m512;
+        [avx512f] safe fn _mm512_castpd_si512(a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_castps128_ps512(a: __m128) -> __m512;
+        [avx512f] safe fn _mm512_castps256_ps512(a: __m256) -> __m512;
+        [avx512f] safe fn _mm512_castps512_ps128(a: __m512) -> __m128;
+        [avx512f] safe fn _mm512_castps512_ps256(a: __m512) -> __m256;
+        [avx512f] safe fn _mm512_castps_pd(a: __m512) -> __m512d;
+        [avx512f] safe fn _mm512_castps_si512(a: __m512) -> __m512i;
+        [avx512f] safe fn _mm512_castsi128_si512(a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_castsi256_si512(a: __m256i) -> __m512i;
+        [avx512f] safe fn _mm512_castsi512_pd(a: __m512i) -> __m512d;
+        [avx512f] safe fn _mm512_castsi512_ps(a: __m512i) -> __m512;
+        [avx512f] safe fn _mm512_castsi512_si128(a: __m512i) -> __m128i;
+        [avx512f] safe fn _mm512_castsi512_si256(a: __m512i) -> __m256i;
+        [vpclmulqdq, avx512f] safe fn _mm512_clmulepi64_epi128<const IMM8: i32>(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_cmp_epi16_mask<const IMM8: i32>(a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_cmp_epi32_mask<const IMM3: _MM_CMPINT_ENUM>(a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_cmp_epi64_mask<const IMM3: _MM_CMPINT_ENUM>(a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_cmp_epi8_mask<const IMM8: i32>(a: __m512i, b: __m512i) -> __mmask64;
+        [avx512bw] safe fn _mm512_cmp_epu16_mask<const IMM8: i32>(a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_cmp_epu32_mask<const IMM3: _MM_CMPINT_ENUM>(a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_cmp_epu64_mask<const IMM3: _MM_CMPINT_ENUM>(a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_cmp_epu8_mask<const IMM8: i32>(a: __m512i, b: __m512i) -> __mmask64;
+        [avx512f] safe fn _mm512_cmp_pd_mask<const IMM8: i32>(a: __m512d, b: __m512d) -> __mmask8;
+        [avx512f] safe fn _mm512_cmp_ps_mask<const IMM8: i32>(a: __m512, b: __m512) -> __mmask16;
+        [avx512f] safe fn _mm512_cmp_round_pd_mask<const IMM5: i32, const SAE: i32>(a: __m512d, b: __m512d) -> __mmask8;
+        [avx512f] safe fn _mm512_cmp_round_ps_mask<const IMM5: i32, const SAE: i32>(a: __m512, b: __m512) -> __mmask16;
+        [avx512bw] safe fn _mm512_cmpeq_epi16_mask(a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_cmpeq_epi32_mask(a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_cmpeq_epi64_mask(a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_cmpeq_epi8_mask(a: __m512i, b: __m512i) -> __mmask64;
+        [avx512bw] safe fn _mm512_cmpeq_epu16_mask(a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_cmpeq_epu32_mask(a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_cmpeq_epu64_mask(a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_cmpeq_epu8_mask(a: __m512i, b: __m512i) -> __mmask64;
+        [avx512f] safe fn _mm512_cmpeq_pd_mask(a: __m512d, b: __m512d) -> __mmask8;
+        [avx512f] safe fn _mm512_cmpeq_ps_mask(a: __m512, b: __m512) -> __mmask16;
+        [avx512bw] safe fn _mm512_cmpge_epi16_mask(a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_cmpge_epi32_mask(a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_cmpge_epi64_mask(a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_cmpge_epi8_mask(a: __m512i, b: __m512i) -> __mmask64;
+        [avx512bw] safe fn _mm512_cmpge_epu16_mask(a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_cmpge_epu32_mask(a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_cmpge_epu64_mask(a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_cmpge_epu8_mask(a: __m512i, b: __m512i) -> __mmask64;
+        [avx512bw] safe fn _mm512_cmpgt_epi16_mask(a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_cmpgt_epi32_mask(a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_cmpgt_epi64_mask(a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_cmpgt_epi8_mask(a: __m512i, b: __m512i) -> __mmask64;
+        [avx512bw] safe fn _mm512_cmpgt_epu16_mask(a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_cmpgt_epu32_mask(a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_cmpgt_epu64_mask(a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_cmpgt_epu8_mask(a: __m512i, b: __m512i) -> __mmask64;
+        [avx512bw] safe fn _mm512_cmple_epi16_mask(a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_cmple_epi32_mask(a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_cmple_epi64_mask(a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_cmple_epi8_mask(a: __m512i, b: __m512i) -> __mmask64;
+        [avx512bw] safe fn _mm512_cmple_epu16_mask(a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_cmple_epu32_mask(a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_cmple_epu64_mask(a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_cmple_epu8_mask(a: __m512i, b: __m512i) -> __mmask64;
+        [avx512f] safe fn _mm512_cmple_pd_mask(a: __m512d, b: __m512d) -> __mmask8;
+        [avx512f] safe fn _mm512_cmple_ps_mask(a: __m512, b: __m512) -> __mmask16;
+        [avx512bw] safe fn _mm512_cmplt_epi16_mask(a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_cmplt_epi32_mask(a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_cmplt_epi64_mask(a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_cmplt_epi8_mask(a: __m512i, b: __m512i) -> __mmask64;
+        [avx512bw] safe fn _mm512_cmplt_epu16_mask(a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_cmplt_epu32_mask(a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_cmplt_epu64_mask(a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_cmplt_epu8_mask(a: __m512i, b: __m512i) -> __mmask64;
+        [avx512f] safe fn _mm512_cmplt_pd_mask(a: __m512d, b: __m512d) -> __mmask8;
+        [avx512f] safe fn _mm512_cmplt_ps_mask(a: __m512, b: __m512) -> __mmask16;
+        [avx512bw] safe fn _mm512_cmpneq_epi16_mask(a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_cmpneq_epi32_mask(a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_cmpneq_epi64_mask(a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_cmpneq_epi8_mask(a: __m512i, b: __m512i) -> __mmask64;
+        [avx512bw] safe fn _mm512_cmpneq_epu16_mask(a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_cmpneq_epu32_mask(a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_cmpneq_epu64_mask(a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_cmpneq_epu8_mask(a: __m512i, b: __m512i) -> __mmask64;
+        [avx512f] safe fn _mm512_cmpneq_pd_mask(a: __m512d, b: __m512d) -> __mmask8;
+        [avx512f] safe fn _mm512_cmpneq_ps_mask(a: __m512, b: __m512) -> __mmask16;
+        [avx512f] safe fn _mm512_cmpnle_pd_mask(a: __m512d, b: __m512d) -> __mmask8;
+        [avx512f] safe fn _mm512_cmpnle_ps_mask(a: __m512, b: __m512) -> __mmask16;
+        [avx512f] safe fn _mm512_cmpnlt_pd_mask(a: __m512d, b: __m512d) -> __mmask8;
+        [avx512f] safe fn _mm512_cmpnlt_ps_mask(a: __m512, b: __m512) -> __mmask16;
+        [avx512f] safe fn _mm512_cmpord_pd_mask(a: __m512d, b: __m512d) -> __mmask8;
+        [avx512f] safe fn _mm512_cmpord_ps_mask(a: __m512, b: __m512) -> __mmask16;
+        [avx512f] safe fn _mm512_cmpunord_pd_mask(a: __m512d, b: __m512d) -> __mmask8;
+        [avx512f] safe fn _mm512_cmpunord_ps_mask(a: __m512, b: __m512) -> __mmask16;
+        [avx512cd] safe fn _mm512_conflict_epi32(a: __m512i) -> __m512i;
+        [avx512cd] safe fn _mm512_conflict_epi64(a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_cvt_roundepi32_ps<const ROUNDING: i32>(a: __m512i) -> __m512;
+        [avx512dq] safe fn _mm512_cvt_roundepi64_pd<const ROUNDING: i32>(a: __m512i) -> __m512d;
+        [avx512dq] safe fn _mm512_cvt_roundepi64_ps<const ROUNDING: i32>(a: __m512i) -> __m256;
+        [avx512f] safe fn _mm512_cvt_roundepu32_ps<const ROUNDING: i32>(a: __m512i) -> __m512;
+        [avx512dq] safe fn _mm512_cvt_roundepu64_pd<const ROUNDING: i32>(a: __m512i) -> __m512d;
+        [avx512dq] safe fn _mm512_cvt_roundepu64_ps<const ROUNDING: i32>(a: __m512i) -> __m256;
+        [avx512f] safe fn _mm512_cvt_roundpd_epi32<const ROUNDING: i32>(a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_cvt_roundpd_epi64<const ROUNDING: i32>(a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_cvt_roundpd_epu32<const ROUNDING: i32>(a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_cvt_roundpd_epu64<const ROUNDING: i32>(a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_cvt_roundpd_ps<const ROUNDING: i32>(a: __m512d) -> __m256;
+        [avx512f] safe fn _mm512_cvt_roundph_ps<const SAE: i32>(a: __m256i) -> __m512;
+        [avx512f] safe fn _mm512_cvt_roundps_epi32<const ROUNDING: i32>(a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_cvt_roundps_epi64<const ROUNDING: i32>(a: __m256) -> __m512i;
+        [avx512f] safe fn _mm512_cvt_roundps_epu32<const ROUNDING: i32>(a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_cvt_roundps_epu64<const ROUNDING: i32>(a: __m256) -> __m512i;
+        [avx512f] safe fn _mm512_cvt_roundps_pd<const SAE: i32>(a: __m256) -> __m512d;
+        [avx512f] safe fn _mm512_cvt_roundps_ph<const ROUNDING: i32>(a: __m512) -> __m256i;
+        [avx512f] safe fn _mm512_cvtepi16_epi32(a: __m256i) -> __m512i;
+        [avx512f] safe fn _mm512_cvtepi16_epi64(a: __m128i) -> __m512i;
+        [avx512bw] safe fn _mm512_cvtepi16_epi8(a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_cvtepi32_epi16(a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_cvtepi32_epi64(a: __m256i) -> __m512i;
+        [avx512f] safe fn _mm512_cvtepi32_epi8(a: __m512i) -> __m128i;
+        [avx512f] safe fn _mm512_cvtepi32_pd(a: __m256i) -> __m512d;
+        [avx512f] safe fn _mm512_cvtepi32_ps(a: __m512i) -> __m512;
+        [avx512f] safe fn _mm512_cvtepi32lo_pd(v2: __m512i) -> __m512d;
+        [avx512f] safe fn _mm512_cvtepi64_epi16(a: __m512i) -> __m128i;
+        [avx512f] safe fn _mm512_cvtepi64_epi32(a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_cvtepi64_epi8(a: __m512i) -> __m128i;
+        [avx512dq] safe fn _mm512_cvtepi64_pd(a: __m512i) -> __m512d;
+        [avx512dq] safe fn _mm512_cvtepi64_ps(a: __m512i) -> __m256;
+        [avx512bw] safe fn _mm512_cvtepi8_epi16(a: __m256i) -> __m512i;
+        [avx512f] safe fn _mm512_cvtepi8_epi32(a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_cvtepi8_epi64(a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_cvtepu16_epi32(a: __m256i) -> __m512i;
+        [avx512f] safe fn _mm512_cvtepu16_epi64(a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_cvtepu32_epi64(a: __m256i) -> __m512i;
+        [avx512f] safe fn _mm512_cvtepu32_pd(a: __m256i) -> __m512d;
+        [avx512f] safe fn _mm512_cvtepu32_ps(a: __m512i) -> __m512;
+        [avx512f] safe fn _mm512_cvtepu32lo_pd(v2: __m512i) -> __m512d;
+        [avx512dq] safe fn _mm512_cvtepu64_pd(a: __m512i) -> __m512d;
+        [avx512dq] safe fn _mm512_cvtepu64_ps(a: __m512i) -> __m256;
+        [avx512bw] safe fn _mm512_cvtepu8_epi16(a: __m256i) -> __m512i;
+        [avx512f] safe fn _mm512_cvtepu8_epi32(a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_cvtepu8_epi64(a: __m128i) -> __m512i;
+        [avx512bf16, avx512f] safe fn _mm512_cvtne2ps_pbh(a: __m512, b: __m512) -> __m512bh;
+        [avx512bf16, avx512f] safe fn _mm512_cvtneps_pbh(a: __m512) -> __m256bh;
+        [avx512bf16, avx512f] safe fn _mm512_cvtpbh_ps(a: __m256bh) -> __m512;
+        [avx512f] safe fn _mm512_cvtpd_epi32(a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_cvtpd_epi64(a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_cvtpd_epu32(a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_cvtpd_epu64(a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_cvtpd_ps(a: __m512d) -> __m256;
+        [avx512f] safe fn _mm512_cvtpd_pslo(v2: __m512d) -> __m512;
+        [avx512f] safe fn _mm512_cvtph_ps(a: __m256i) -> __m512;
+        [avx512f] safe fn _mm512_cvtps_epi32(a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_cvtps_epi64(a: __m256) -> __m512i;
+        [avx512f] safe fn _mm512_cvtps_epu32(a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_cvtps_epu64(a: __m256) -> __m512i;
+        [avx512f] safe fn _mm512_cvtps_pd(a: __m256) -> __m512d;
+        [avx512f] safe fn _mm512_cvtps_ph<const ROUNDING: i32>(a: __m512) -> __m256i;
+        [avx512f] safe fn _mm512_cvtpslo_pd(v2: __m512) -> __m512d;
+        [avx512f] safe fn _mm512_cvtsd_f64(a: __m512d) -> f64;
+        [avx512bw] safe fn _mm512_cvtsepi16_epi8(a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_cvtsepi32_epi16(a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_cvtsepi32_epi8(a: __m512i) -> __m128i;
+        [avx512f] safe fn _mm512_cvtsepi64_epi16(a: __m512i) -> __m128i;
+        [avx512f] safe fn _mm512_cvtsepi64_epi32(a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_cvtsepi64_epi8(a: __m512i) -> __m128i;
+        [avx512f] safe fn _mm512_cvtsi512_si32(a: __m512i) -> i32;
+        [avx512f] safe fn _mm512_cvtss_f32(a: __m512) -> f32;
+        [avx512f] safe fn _mm512_cvtt_roundpd_epi32<const SAE: i32>(a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_cvtt_roundpd_epi64<const SAE: i32>(a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_cvtt_roundpd_epu32<const SAE: i32>(a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_cvtt_roundpd_epu64<const SAE: i32>(a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_cvtt_roundps_epi32<const SAE: i32>(a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_cvtt_roundps_epi64<const SAE: i32>(a: __m256) -> __m512i;
+        [avx512f] safe fn _mm512_cvtt_roundps_epu32<const SAE: i32>(a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_cvtt_roundps_epu64<const SAE: i32>(a: __m256) -> __m512i;
+        [avx512f] safe fn _mm512_cvttpd_epi32(a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_cvttpd_epi64(a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_cvttpd_epu32(a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_cvttpd_epu64(a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_cvttps_epi32(a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_cvttps_epi64(a: __m256) -> __m512i;
+        [avx512f] safe fn _mm512_cvttps_epu32(a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_cvttps_epu64(a: __m256) -> __m512i;
+        [avx512bw] safe fn _mm512_cvtusepi16_epi8(a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_cvtusepi32_epi16(a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_cvtusepi32_epi8(a: __m512i) -> __m128i;
+        [avx512f] safe fn _mm512_cvtusepi64_epi16(a: __m512i) -> __m128i;
+        [avx512f] safe fn _mm512_cvtusepi64_epi32(a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_cvtusepi64_epi8(a: __m512i) -> __m128i;
+        [avx512bw] safe fn _mm512_dbsad_epu8<const IMM8: i32>(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_div_pd(a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_div_ps(a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_div_round_pd<const ROUNDING: i32>(a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_div_round_ps<const ROUNDING: i32>(a: __m512, b: __m512) -> __m512;
+        [avx512bf16, avx512f] safe fn _mm512_dpbf16_ps(src: __m512, a: __m512bh, b: __m512bh) -> __m512;
+        [avx512vnni] safe fn _mm512_dpbusd_epi32(src: __m512i, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vnni] safe fn _mm512_dpbusds_epi32(src: __m512i, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vnni] safe fn _mm512_dpwssd_epi32(src: __m512i, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vnni] safe fn _mm512_dpwssds_epi32(src: __m512i, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_extractf32x4_ps<const IMM8: i32>(a: __m512) -> __m128;
+        [avx512dq] safe fn _mm512_extractf32x8_ps<const IMM8: i32>(a: __m512) -> __m256;
+        [avx512dq] safe fn _mm512_extractf64x2_pd<const IMM8: i32>(a: __m512d) -> __m128d;
+        [avx512f] safe fn _mm512_extractf64x4_pd<const IMM8: i32>(a: __m512d) -> __m256d;
+        [avx512f] safe fn _mm512_extracti32x4_epi32<const IMM2: i32>(a: __m512i) -> __m128i;
+        [avx512dq] safe fn _mm512_extracti32x8_epi32<const IMM8: i32>(a: __m512i) -> __m256i;
+        [avx512dq] safe fn _mm512_extracti64x2_epi64<const IMM8: i32>(a: __m512i) -> __m128i;
+        [avx512f] safe fn _mm512_extracti64x4_epi64<const IMM1: i32>(a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_fixupimm_pd<const IMM8: i32>(a: __m512d, b: __m512d, c: __m512i) -> __m512d;
+        [avx512f] safe fn _mm512_fixupimm_ps<const IMM8: i32>(a: __m512, b: __m512, c: __m512i) -> __m512;
+        [avx512f] safe fn _mm512_fixupimm_round_pd<const IMM8: i32, const SAE: i32>(a: __m512d, b: __m512d, c: __m512i) -> __m512d;
+        [avx512f] safe fn _mm512_fixupimm_round_ps<const IMM8: i32, const SAE: i32>(a: __m512, b: __m512, c: __m512i) -> __m512;
+        [avx512f] safe fn _mm512_fmadd_pd(a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_fmadd_ps(a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_fmadd_round_pd<const ROUNDING: i32>(a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_fmadd_round_ps<const ROUNDING: i32>(a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_fmaddsub_pd(a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_fmaddsub_ps(a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_fmaddsub_round_pd<const ROUNDING: i32>(a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_fmaddsub_round_ps<const ROUNDING: i32>(a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_fmsub_pd(a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_fmsub_ps(a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_fmsub_round_pd<const ROUNDING: i32>(a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_fmsub_round_ps<const ROUNDING: i32>(a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_fmsubadd_pd(a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_fmsubadd_ps(a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_fmsubadd_round_pd<const ROUNDING: i32>(a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_fmsubadd_round_ps<const ROUNDING: i32>(a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_fnmadd_pd(a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_fnmadd_ps(a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_fnmadd_round_pd<const ROUNDING: i32>(a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_fnmadd_round_ps<const ROUNDING: i32>(a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_fnmsub_pd(a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_fnmsub_ps(a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_fnmsub_round_pd<const ROUNDING: i32>(a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_fnmsub_round_ps<const ROUNDING: i32>(a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512dq] safe fn _mm512_fpclass_pd_mask<const IMM8: i32>(a: __m512d) -> __mmask8;
+        [avx512dq] safe fn _mm512_fpclass_ps_mask<const IMM8: i32>(a: __m512) -> __mmask16;
+        [avx512f] safe fn _mm512_getexp_pd(a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_getexp_ps(a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_getexp_round_pd<const SAE: i32>(a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_getexp_round_ps<const SAE: i32>(a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_getmant_pd<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_getmant_ps<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_getmant_round_pd<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM, const SAE: i32>(a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_getmant_round_ps<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM, const SAE: i32>(a: __m512) -> __m512;
+        [gfni, avx512f] safe fn _mm512_gf2p8affine_epi64_epi8<const B: i32>(x: __m512i, a: __m512i) -> __m512i;
+        [gfni, avx512f] safe fn _mm512_gf2p8affineinv_epi64_epi8<const B: i32>(x: __m512i, a: __m512i) -> __m512i;
+        [gfni, avx512f] safe fn _mm512_gf2p8mul_epi8(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] unsafe fn _mm512_i32gather_epi32<const SCALE: i32>(offsets: __m512i, slice: *const i32) -> __m512i;
+        [avx512f] unsafe fn _mm512_i32gather_epi64<const SCALE: i32>(offsets: __m256i, slice: *const i64) -> __m512i;
+        [avx512f] unsafe fn _mm512_i32gather_pd<const SCALE: i32>(offsets: __m256i, slice: *const f64) -> __m512d;
+        [avx512f] unsafe fn _mm512_i32gather_ps<const SCALE: i32>(offsets: __m512i, slice: *const f32) -> __m512;
+        [avx512f] unsafe fn _mm512_i32logather_epi64<const SCALE: i32>(vindex: __m512i, base_addr: *const i64) -> __m512i;
+        [avx512f] unsafe fn _mm512_i32logather_pd<const SCALE: i32>(vindex: __m512i, base_addr: *const f64) -> __m512d;
+        [avx512f] unsafe fn _mm512_i32loscatter_epi64<const SCALE: i32>(base_addr: *mut i64, vindex: __m512i, a: __m512i);
+        [avx512f] unsafe fn _mm512_i32loscatter_pd<const SCALE: i32>(base_addr: *mut f64, vindex: __m512i, a: __m512d);
+        [avx512f] unsafe fn _mm512_i32scatter_epi32<const SCALE: i32>(slice: *mut i32, offsets: __m512i, src: __m512i);
+        [avx512f] unsafe fn _mm512_i32scatter_epi64<const SCALE: i32>(slice: *mut i64, offsets: __m256i, src: __m512i);
+        [avx512f] unsafe fn _mm512_i32scatter_pd<const SCALE: i32>(slice: *mut f64, offsets: __m256i, src: __m512d);
+        [avx512f] unsafe fn _mm512_i32scatter_ps<const SCALE: i32>(slice: *mut f32, offsets: __m512i, src: __m512);
+        [avx512f] unsafe fn _mm512_i64gather_epi32<const SCALE: i32>(offsets: __m512i, slice: *const i32) -> __m256i;
+        [avx512f] unsafe fn _mm512_i64gather_epi64<const SCALE: i32>(offsets: __m512i, slice: *const i64) -> __m512i;
+        [avx512f] unsafe fn _mm512_i64gather_pd<const SCALE: i32>(offsets: __m512i, slice: *const f64) -> __m512d;
+        [avx512f] unsafe fn _mm512_i64gather_ps<const SCALE: i32>(offsets: __m512i, slice: *const f32) -> __m256;
+        [avx512f] unsafe fn _mm512_i64scatter_epi32<const SCALE: i32>(slice: *mut i32, offsets: __m512i, src: __m256i);
+        [avx512f] unsafe fn _mm512_i64scatter_epi64<const SCALE: i32>(slice: *mut i64, offsets: __m512i, src: __m512i);
+        [avx512f] unsafe fn _mm512_i64scatter_pd<const SCALE: i32>(slice: *mut f64, offsets: __m512i, src: __m512d);
+        [avx512f] unsafe fn _mm512_i64scatter_ps<const SCALE: i32>(slice: *mut f32, offsets: __m512i, src: __m256);
+        [avx512f] safe fn _mm512_insertf32x4<const IMM8: i32>(a: __m512, b: __m128) -> __m512;
+        [avx512dq] safe fn _mm512_insertf32x8<const IMM8: i32>(a: __m512, b: __m256) -> __m512;
+        [avx512dq] safe fn _mm512_insertf64x2<const IMM8: i32>(a: __m512d, b: __m128d) -> __m512d;
+        [avx512f] safe fn _mm512_insertf64x4<const IMM8: i32>(a: __m512d, b: __m256d) -> __m512d;
+        [avx512f] safe fn _mm512_inserti32x4<const IMM8: i32>(a: __m512i, b: __m128i) -> __m512i;
+        [avx512dq] safe fn _mm512_inserti32x8<const IMM8: i32>(a: __m512i, b: __m256i) -> __m512i;
+        [avx512dq] safe fn _mm512_inserti64x2<const IMM8: i32>(a: __m512i, b: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_inserti64x4<const IMM8: i32>(a: __m512i, b: __m256i) -> __m512i;
+        [avx512f] safe fn _mm512_int2mask(mask: i32) -> __mmask16;
+        [avx512f] safe fn _mm512_kand(a: __mmask16, b: __mmask16) -> __mmask16;
+        [avx512f] safe fn _mm512_kandn(a: __mmask16, b: __mmask16) -> __mmask16;
+        [avx512f] safe fn _mm512_kmov(a: __mmask16) -> __mmask16;
+        [avx512f] safe fn _mm512_knot(a: __mmask16) -> __mmask16;
+        [avx512f] safe fn _mm512_kor(a: __mmask16, b: __mmask16) -> __mmask16;
+        [avx512f] safe fn _mm512_kortestc(a: __mmask16, b: __mmask16) -> i32;
+        [avx512f] safe fn _mm512_kortestz(a: __mmask16, b: __mmask16) -> i32;
+        [avx512f] safe fn _mm512_kunpackb(a: __mmask16, b: __mmask16) -> __mmask16;
+        [avx512bw] safe fn _mm512_kunpackd(a: __mmask64, b: __mmask64) -> __mmask64;
+        [avx512bw] safe fn _mm512_kunpackw(a: __mmask32, b: __mmask32) -> __mmask32;
+        [avx512f] safe fn _mm512_kxnor(a: __mmask16, b: __mmask16) -> __mmask16;
+        [avx512f] safe fn _mm512_kxor(a: __mmask16, b: __mmask16) -> __mmask16;
+        [avx512f] unsafe fn _mm512_load_epi32(mem_addr: *const i32) -> __m512i;
+        [avx512f] unsafe fn _mm512_load_epi64(mem_addr: *const i64) -> __m512i;
+        [avx512f] unsafe fn _mm512_load_pd(mem_addr: *const f64) -> __m512d;
+        [avx512f] unsafe fn _mm512_load_ps(mem_addr: *const f32) -> __m512;
+        [avx512f] unsafe fn _mm512_load_si512(mem_addr: *const __m512i) -> __m512i;
+        [avx512bw] unsafe fn _mm512_loadu_epi16(mem_addr: *const i16) -> __m512i;
+        [avx512f] unsafe fn _mm512_loadu_epi32(mem_addr: *const i32) -> __m512i;
+        [avx512f] unsafe fn _mm512_loadu_epi64(mem_addr: *const i64) -> __m512i;
+        [avx512bw] unsafe fn _mm512_loadu_epi8(mem_addr: *const i8) -> __m512i;
+        [avx512f] unsafe fn _mm512_loadu_pd(mem_addr: *const f64) -> __m512d;
+        [avx512f] unsafe fn _mm512_loadu_ps(mem_addr: *const f32) -> __m512;
+        [avx512f] unsafe fn _mm512_loadu_si512(mem_addr: *const __m512i) -> __m512i;
+        [avx512cd] safe fn _mm512_lzcnt_epi32(a: __m512i) -> __m512i;
+        [avx512cd] safe fn _mm512_lzcnt_epi64(a: __m512i) -> __m512i;
+        [avx512ifma] safe fn _mm512_madd52hi_epu64(a: __m512i, b: __m512i, c: __m512i) -> __m512i;
+        [avx512ifma] safe fn _mm512_madd52lo_epu64(a: __m512i, b: __m512i, c: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_madd_epi16(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maddubs_epi16(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask2_permutex2var_epi16(a: __m512i, idx: __m512i, k: __mmask32, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask2_permutex2var_epi32(a: __m512i, idx: __m512i, k: __mmask16, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask2_permutex2var_epi64(a: __m512i, idx: __m512i, k: __mmask8, b: __m512i) -> __m512i;
+        [avx512vbmi] safe fn _mm512_mask2_permutex2var_epi8(a: __m512i, idx: __m512i, k: __mmask64, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask2_permutex2var_pd(a: __m512d, idx: __m512i, k: __mmask8, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask2_permutex2var_ps(a: __m512, idx: __m512i, k: __mmask16, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask2int(k1: __mmask16) -> i32;
+        [avx512f] safe fn _mm512_mask3_fmadd_pd(a: __m512d, b: __m512d, c: __m512d, k: __mmask8) -> __m512d;
+        [avx512f] safe fn _mm512_mask3_fmadd_ps(a: __m512, b: __m512, c: __m512, k: __mmask16) -> __m512;
+        [avx512f] safe fn _mm512_mask3_fmadd_round_pd<const ROUNDING: i32>(a: __m512d, b: __m512d, c: __m512d, k: __mmask8) -> __m512d;
+        [avx512f] safe fn _mm512_mask3_fmadd_round_ps<const ROUNDING: i32>(a: __m512, b: __m512, c: __m512, k: __mmask16) -> __m512;
+        [avx512f] safe fn _mm512_mask3_fmaddsub_pd(a: __m512d, b: __m512d, c: __m512d, k: __mmask8) -> __m512d;
+        [avx512f] safe fn _mm512_mask3_fmaddsub_ps(a: __m512, b: __m512, c: __m512, k: __mmask16) -> __m512;
+        [avx512f] safe fn _mm512_mask3_fmaddsub_round_pd<const ROUNDING: i32>(a: __m512d, b: __m512d, c: __m512d, k: __mmask8) -> __m512d;
+        [avx512f] safe fn _mm512_mask3_fmaddsub_round_ps<const ROUNDING: i32>(a: __m512, b: __m512, c: __m512, k: __mmask16) -> __m512;
+        [avx512f] safe fn _mm512_mask3_fmsub_pd(a: __m512d, b: __m512d, c: __m512d, k: __mmask8) -> __m512d;
+        [avx512f] safe fn _mm512_mask3_fmsub_ps(a: __m512, b: __m512, c: __m512, k: __mmask16) -> __m512;
+        [avx512f] safe fn _mm512_mask3_fmsub_round_pd<const ROUNDING: i32>(a: __m512d, b: __m512d, c: __m512d, k: __mmask8) -> __m512d;
+        [avx512f] safe fn _mm512_mask3_fmsub_round_ps<const ROUNDING: i32>(a: __m512, b: __m512, c: __m512, k: __mmask16) -> __m512;
+        [avx512f] safe fn _mm512_mask3_fmsubadd_pd(a: __m512d, b: __m512d, c: __m512d, k: __mmask8) -> __m512d;
+        [avx512f] safe fn _mm512_mask3_fmsubadd_ps(a: __m512, b: __m512, c: __m512, k: __mmask16) -> __m512;
+        [avx512f] safe fn _mm512_mask3_fmsubadd_round_pd<const ROUNDING: i32>(a: __m512d, b: __m512d, c: __m512d, k: __mmask8) -> __m512d;
+        [avx512f] safe fn _mm512_mask3_fmsubadd_round_ps<const ROUNDING: i32>(a: __m512, b: __m512, c: __m512, k: __mmask16) -> __m512;
+        [avx512f] safe fn _mm512_mask3_fnmadd_pd(a: __m512d, b: __m512d, c: __m512d, k: __mmask8) -> __m512d;
+        [avx512f] safe fn _mm512_mask3_fnmadd_ps(a: __m512, b: __m512, c: __m512, k: __mmask16) -> __m512;
+        [avx512f] safe fn _mm512_mask3_fnmadd_round_pd<const ROUNDING: i32>(a: __m512d, b: __m512d, c: __m512d, k: __mmask8) -> __m512d;
+        [avx512f] safe fn _mm512_mask3_fnmadd_round_ps<const ROUNDING: i32>(a: __m512, b: __m512, c: __m512, k: __mmask16) -> __m512;
+        [avx512f] safe fn _mm512_mask3_fnmsub_pd(a: __m512d, b: __m512d, c: __m512d, k: __mmask8) -> __m512d;
+        [avx512f] safe fn _mm512_mask3_fnmsub_ps(a: __m512, b: __m512, c: __m512, k: __mmask16) -> __m512;
+        [avx512f] safe fn _mm512_mask3_fnmsub_round_pd<const ROUNDING: i32>(a: __m512d, b: __m512d, c: __m512d, k: __mmask8) -> __m512d;
+        [avx512f] safe fn _mm512_mask3_fnmsub_round_ps<const ROUNDING: i32>(a: __m512, b: __m512, c: __m512, k: __mmask16) -> __m512;
+        [avx512bw] safe fn _mm512_mask_abs_epi16(src: __m512i, k: __mmask32, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_abs_epi32(src: __m512i, k: __mmask16, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_abs_epi64(src: __m512i, k: __mmask8, a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_abs_epi8(src: __m512i, k: __mmask64, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_abs_pd(src: __m512d, k: __mmask8, v2: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_abs_ps(src: __m512, k: __mmask16, v2: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_mask_add_epi16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_add_epi32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_add_epi64(src: __m512i, k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_add_epi8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_add_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_add_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_add_round_pd<const ROUNDING: i32>(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_add_round_ps<const ROUNDING: i32>(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_mask_adds_epi16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_adds_epi8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_adds_epu16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_adds_epu8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_alignr_epi32<const IMM8: i32>(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_alignr_epi64<const IMM8: i32>(src: __m512i, k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_alignr_epi8<const IMM8: i32>(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_and_epi32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_and_epi64(src: __m512i, k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512dq] safe fn _mm512_mask_and_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_mask_and_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_andnot_epi32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_andnot_epi64(src: __m512i, k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512dq] safe fn _mm512_mask_andnot_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_mask_andnot_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_mask_avg_epu16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_avg_epu8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bitalg] safe fn _mm512_mask_bitshuffle_epi64_mask(k: __mmask64, b: __m512i, c: __m512i) -> __mmask64;
+        [avx512bw] safe fn _mm512_mask_blend_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_blend_epi32(k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_blend_epi64(k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_blend_epi8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_blend_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_blend_ps(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512dq] safe fn _mm512_mask_broadcast_f32x2(src: __m512, k: __mmask16, a: __m128) -> __m512;
+        [avx512f] safe fn _mm512_mask_broadcast_f32x4(src: __m512, k: __mmask16, a: __m128) -> __m512;
+        [avx512dq] safe fn _mm512_mask_broadcast_f32x8(src: __m512, k: __mmask16, a: __m256) -> __m512;
+        [avx512dq] safe fn _mm512_mask_broadcast_f64x2(src: __m512d, k: __mmask8, a: __m128d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_broadcast_f64x4(src: __m512d, k: __mmask8, a: __m256d) -> __m512d;
+        [avx512dq] safe fn _mm512_mask_broadcast_i32x2(src: __m512i, k: __mmask16, a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_broadcast_i32x4(src: __m512i, k: __mmask16, a: __m128i) -> __m512i;
+        [avx512dq] safe fn _mm512_mask_broadcast_i32x8(src: __m512i, k: __mmask16, a: __m256i) -> __m512i;
+        [avx512dq] safe fn _mm512_mask_broadcast_i64x2(src: __m512i, k: __mmask8, a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_broadcast_i64x4(src: __m512i, k: __mmask8, a: __m256i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_broadcastb_epi8(src: __m512i, k: __mmask64, a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_broadcastd_epi32(src: __m512i, k: __mmask16, a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_broadcastq_epi64(src: __m512i, k: __mmask8, a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_broadcastsd_pd(src: __m512d, k: __mmask8, a: __m128d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_broadcastss_ps(src: __m512, k: __mmask16, a: __m128) -> __m512;
+        [avx512bw] safe fn _mm512_mask_broadcastw_epi16(src: __m512i, k: __mmask32, a: __m128i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_cmp_epi16_mask<const IMM8: i32>(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_mask_cmp_epi32_mask<const IMM3: _MM_CMPINT_ENUM>(k1: __mmask16, a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_mask_cmp_epi64_mask<const IMM3: _MM_CMPINT_ENUM>(k1: __mmask8, a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_mask_cmp_epi8_mask<const IMM8: i32>(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64;
+        [avx512bw] safe fn _mm512_mask_cmp_epu16_mask<const IMM8: i32>(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_mask_cmp_epu32_mask<const IMM3: _MM_CMPINT_ENUM>(k1: __mmask16, a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_mask_cmp_epu64_mask<const IMM3: _MM_CMPINT_ENUM>(k1: __mmask8, a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_mask_cmp_epu8_mask<const IMM8: i32>(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64;
+        [avx512f] safe fn _mm512_mask_cmp_pd_mask<const IMM8: i32>(k1: __mmask8, a: __m512d, b: __m512d) -> __mmask8;
+        [avx512f] safe fn _mm512_mask_cmp_ps_mask<const IMM8: i32>(k1: __mmask16, a: __m512, b: __m512) -> __mmask16;
+        [avx512f] safe fn _mm512_mask_cmp_round_pd_mask<const IMM5: i32, const SAE: i32>(k1: __mmask8, a: __m512d, b: __m512d) -> __mmask8;
+        [avx512f] safe fn _mm512_mask_cmp_round_ps_mask<const IMM5: i32, const SAE: i32>(m: __mmask16, a: __m512, b: __m512) -> __mmask16;
+        [avx512bw] safe fn _mm512_mask_cmpeq_epi16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_mask_cmpeq_epi32_mask(k1: __mmask16, a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_mask_cmpeq_epi64_mask(k1: __mmask8, a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_mask_cmpeq_epi8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64;
+        [avx512bw] safe fn _mm512_mask_cmpeq_epu16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_mask_cmpeq_epu32_mask(k1: __mmask16, a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_mask_cmpeq_epu64_mask(k1: __mmask8, a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_mask_cmpeq_epu8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64;
+        [avx512f] safe fn _mm512_mask_cmpeq_pd_mask(k1: __mmask8, a: __m512d, b: __m512d) -> __mmask8;
+        [avx512f] safe fn _mm512_mask_cmpeq_ps_mask(k1: __mmask16, a: __m512, b: __m512) -> __mmask16;
+        [avx512bw] safe fn _mm512_mask_cmpge_epi16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_mask_cmpge_epi32_mask(k1: __mmask16, a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_mask_cmpge_epi64_mask(k1: __mmask8, a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_mask_cmpge_epi8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64;
+        [avx512bw] safe fn _mm512_mask_cmpge_epu16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_mask_cmpge_epu32_mask(k1: __mmask16, a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_mask_cmpge_epu64_mask(k1: __mmask8, a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_mask_cmpge_epu8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64;
+        [avx512bw] safe fn _mm512_mask_cmpgt_epi16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_mask_cmpgt_epi32_mask(k1: __mmask16, a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_mask_cmpgt_epi64_mask(k1: __mmask8, a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_mask_cmpgt_epi8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64;
+        [avx512bw] safe fn _mm512_mask_cmpgt_epu16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_mask_cmpgt_epu32_mask(k1: __mmask16, a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_mask_cmpgt_epu64_mask(k1: __mmask8, a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_mask_cmpgt_epu8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64;
+        [avx512bw] safe fn _mm512_mask_cmple_epi16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_mask_cmple_epi32_mask(k1: __mmask16, a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_mask_cmple_epi64_mask(k1: __mmask8, a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_mask_cmple_epi8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64;
+        [avx512bw] safe fn _mm512_mask_cmple_epu16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_mask_cmple_epu32_mask(k1: __mmask16, a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_mask_cmple_epu64_mask(k1: __mmask8, a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_mask_cmple_epu8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64;
+        [avx512f] safe fn _mm512_mask_cmple_pd_mask(k1: __mmask8, a: __m512d, b: __m512d) -> __mmask8;
+        [avx512f] safe fn _mm512_mask_cmple_ps_mask(k1: __mmask16, a: __m512, b: __m512) -> __mmask16;
+        [avx512bw] safe fn _mm512_mask_cmplt_epi16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_mask_cmplt_epi32_mask(k1: __mmask16, a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_mask_cmplt_epi64_mask(k1: __mmask8, a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_mask_cmplt_epi8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64;
+        [avx512bw] safe fn _mm512_mask_cmplt_epu16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_mask_cmplt_epu32_mask(k1: __mmask16, a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_mask_cmplt_epu64_mask(k1: __mmask8, a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_mask_cmplt_epu8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64;
+        [avx512f] safe fn _mm512_mask_cmplt_pd_mask(k1: __mmask8, a: __m512d, b: __m512d) -> __mmask8;
+        [avx512f] safe fn _mm512_mask_cmplt_ps_mask(k1: __mmask16, a: __m512, b: __m512) -> __mmask16;
+        [avx512bw] safe fn _mm512_mask_cmpneq_epi16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_mask_cmpneq_epi32_mask(k1: __mmask16, a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_mask_cmpneq_epi64_mask(k1: __mmask8, a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_mask_cmpneq_epi8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64;
+        [avx512bw] safe fn _mm512_mask_cmpneq_epu16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_mask_cmpneq_epu32_mask(k1: __mmask16, a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_mask_cmpneq_epu64_mask(k1: __mmask8, a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_mask_cmpneq_epu8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64;
+        [avx512f] safe fn _mm512_mask_cmpneq_pd_mask(k1: __mmask8, a: __m512d, b: __m512d) -> __mmask8;
+        [avx512f] safe fn _mm512_mask_cmpneq_ps_mask(k1: __mmask16, a: __m512, b: __m512) -> __mmask16;
+        [avx512f] safe fn _mm512_mask_cmpnle_pd_mask(k1: __mmask8, a: __m512d, b: __m512d) -> __mmask8;
+        [avx512f] safe fn _mm512_mask_cmpnle_ps_mask(k1: __mmask16, a: __m512, b: __m512) -> __mmask16;
+        [avx512f] safe fn _mm512_mask_cmpnlt_pd_mask(m: __mmask8, a: __m512d, b: __m512d) -> __mmask8;
+        [avx512f] safe fn _mm512_mask_cmpnlt_ps_mask(k1: __mmask16, a: __m512, b: __m512) -> __mmask16;
+        [avx512f] safe fn _mm512_mask_cmpord_pd_mask(k1: __mmask8, a: __m512d, b: __m512d) -> __mmask8;
+        [avx512f] safe fn _mm512_mask_cmpord_ps_mask(k1: __mmask16, a: __m512, b: __m512) -> __mmask16;
+        [avx512f] safe fn _mm512_mask_cmpunord_pd_mask(k1: __mmask8, a: __m512d, b: __m512d) -> __mmask8;
+        [avx512f] safe fn _mm512_mask_cmpunord_ps_mask(k1: __mmask16, a: __m512, b: __m512) -> __mmask16;
+        [avx512vbmi2] safe fn _mm512_mask_compress_epi16(src: __m512i, k: __mmask32, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_compress_epi32(src: __m512i, k: __mmask16, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_compress_epi64(src: __m512i, k: __mmask8, a: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_mask_compress_epi8(src: __m512i, k: __mmask64, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_compress_pd(src: __m512d, k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_compress_ps(src: __m512, k: __mmask16, a: __m512) -> __m512;
+        [avx512vbmi2] unsafe fn _mm512_mask_compressstoreu_epi16(base_addr: *mut i16, k: __mmask32, a: __m512i);
+        [avx512f] unsafe fn _mm512_mask_compressstoreu_epi32(base_addr: *mut i32, k: __mmask16, a: __m512i);
+        [avx512f] unsafe fn _mm512_mask_compressstoreu_epi64(base_addr: *mut i64, k: __mmask8, a: __m512i);
+        [avx512vbmi2] unsafe fn _mm512_mask_compressstoreu_epi8(base_addr: *mut i8, k: __mmask64, a: __m512i);
+        [avx512f] unsafe fn _mm512_mask_compressstoreu_pd(base_addr: *mut f64, k: __mmask8, a: __m512d);
+        [avx512f] unsafe fn _mm512_mask_compressstoreu_ps(base_addr: *mut f32, k: __mmask16, a: __m512);
+        [avx512cd] safe fn _mm512_mask_conflict_epi32(src: __m512i, k: __mmask16, a: __m512i) -> __m512i;
+        [avx512cd] safe fn _mm512_mask_conflict_epi64(src: __m512i, k: __mmask8, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvt_roundepi32_ps<const ROUNDING: i32>(src: __m512, k: __mmask16, a: __m512i) -> __m512;
+        [avx512dq] safe fn _mm512_mask_cvt_roundepi64_pd<const ROUNDING: i32>(src: __m512d, k: __mmask8, a: __m512i) -> __m512d;
+        [avx512dq] safe fn _mm512_mask_cvt_roundepi64_ps<const ROUNDING: i32>(src: __m256, k: __mmask8, a: __m512i) -> __m256;
+        [avx512f] safe fn _mm512_mask_cvt_roundepu32_ps<const ROUNDING: i32>(src: __m512, k: __mmask16, a: __m512i) -> __m512;
+        [avx512dq] safe fn _mm512_mask_cvt_roundepu64_pd<const ROUNDING: i32>(src: __m512d, k: __mmask8, a: __m512i) -> __m512d;
+        [avx512dq] safe fn _mm512_mask_cvt_roundepu64_ps<const ROUNDING: i32>(src: __m256, k: __mmask8, a: __m512i) -> __m256;
+        [avx512f] safe fn _mm512_mask_cvt_roundpd_epi32<const ROUNDING: i32>(src: __m256i, k: __mmask8, a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_mask_cvt_roundpd_epi64<const ROUNDING: i32>(src: __m512i, k: __mmask8, a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvt_roundpd_epu32<const ROUNDING: i32>(src: __m256i, k: __mmask8, a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_mask_cvt_roundpd_epu64<const ROUNDING: i32>(src: __m512i, k: __mmask8, a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvt_roundpd_ps<const ROUNDING: i32>(src: __m256, k: __mmask8, a: __m512d) -> __m256;
+        [avx512f] safe fn _mm512_mask_cvt_roundph_ps<const SAE: i32>(src: __m512, k: __mmask16, a: __m256i) -> __m512;
+        [avx512f] safe fn _mm512_mask_cvt_roundps_epi32<const ROUNDING: i32>(src: __m512i, k: __mmask16, a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_mask_cvt_roundps_epi64<const ROUNDING: i32>(src: __m512i, k: __mmask8, a: __m256) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvt_roundps_epu32<const ROUNDING: i32>(src: __m512i, k: __mmask16, a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_mask_cvt_roundps_epu64<const ROUNDING: i32>(src: __m512i, k: __mmask8, a: __m256) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvt_roundps_pd<const SAE: i32>(src: __m512d, k: __mmask8, a: __m256) -> __m512d;
+        [avx512f] safe fn _mm512_mask_cvt_roundps_ph<const ROUNDING: i32>(src: __m256i, k: __mmask16, a: __m512) -> __m256i;
+        [avx512f] safe fn _mm512_mask_cvtepi16_epi32(src: __m512i, k: __mmask16, a: __m256i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvtepi16_epi64(src: __m512i, k: __mmask8, a: __m128i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_cvtepi16_epi8(src: __m256i, k: __mmask32, a: __m512i) -> __m256i;
+        [avx512bw] unsafe fn _mm512_mask_cvtepi16_storeu_epi8(mem_addr: *mut i8, k: __mmask32, a: __m512i);
+        [avx512f] safe fn _mm512_mask_cvtepi32_epi16(src: __m256i, k: __mmask16, a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_mask_cvtepi32_epi64(src: __m512i, k: __mmask8, a: __m256i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvtepi32_epi8(src: __m128i, k: __mmask16, a: __m512i) -> __m128i;
+        [avx512f] safe fn _mm512_mask_cvtepi32_pd(src: __m512d, k: __mmask8, a: __m256i) -> __m512d;
+        [avx512f] safe fn _mm512_mask_cvtepi32_ps(src: __m512, k: __mmask16, a: __m512i) -> __m512;
+        [avx512f] unsafe fn _mm512_mask_cvtepi32_storeu_epi16(mem_addr: *mut i16, k: __mmask16, a: __m512i);
+        [avx512f] unsafe fn _mm512_mask_cvtepi32_storeu_epi8(mem_addr: *mut i8, k: __mmask16, a: __m512i);
+        [avx512f] safe fn _mm512_mask_cvtepi32lo_pd(src: __m512d, k: __mmask8, v2: __m512i) -> __m512d;
+        [avx512f] safe fn _mm512_mask_cvtepi64_epi16(src: __m128i, k: __mmask8, a: __m512i) -> __m128i;
+        [avx512f] safe fn _mm512_mask_cvtepi64_epi32(src: __m256i, k: __mmask8, a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_mask_cvtepi64_epi8(src: __m128i, k: __mmask8, a: __m512i) -> __m128i;
+        [avx512dq] safe fn _mm512_mask_cvtepi64_pd(src: __m512d, k: __mmask8, a: __m512i) -> __m512d;
+        [avx512dq] safe fn _mm512_mask_cvtepi64_ps(src: __m256, k: __mmask8, a: __m512i) -> __m256;
+        [avx512f] unsafe fn _mm512_mask_cvtepi64_storeu_epi16(mem_addr: *mut i16, k: __mmask8, a: __m512i);
+        [avx512f] unsafe fn _mm512_mask_cvtepi64_storeu_epi32(mem_addr: *mut i32, k: __mmask8, a: __m512i);
+        [avx512f] unsafe fn _mm512_mask_cvtepi64_storeu_epi8(mem_addr: *mut i8, k: __mmask8, a: __m512i);
+        [avx512bw] safe fn _mm512_mask_cvtepi8_epi16(src: __m512i, k: __mmask32, a: __m256i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvtepi8_epi32(src: __m512i, k: __mmask16, a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvtepi8_epi64(src: __m512i, k: __mmask8, a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvtepu16_epi32(src: __m512i, k: __mmask16, a: __m256i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvtepu16_epi64(src: __m512i, k: __mmask8, a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvtepu32_epi64(src: __m512i, k: __mmask8, a: __m256i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvtepu32_pd(src: __m512d, k: __mmask8, a: __m256i) -> __m512d;
+        [avx512f] safe fn _mm512_mask_cvtepu32_ps(src: __m512, k: __mmask16, a: __m512i) -> __m512;
+        [avx512f] safe fn _mm512_mask_cvtepu32lo_pd(src: __m512d, k: __mmask8, v2: __m512i) -> __m512d;
+        [avx512dq] safe fn _mm512_mask_cvtepu64_pd(src: __m512d, k: __mmask8, a: __m512i) -> __m512d;
+        [avx512dq] safe fn _mm512_mask_cvtepu64_ps(src: __m256, k: __mmask8, a: __m512i) -> __m256;
+        [avx512bw] safe fn _mm512_mask_cvtepu8_epi16(src: __m512i, k: __mmask32, a: __m256i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvtepu8_epi32(src: __m512i, k: __mmask16, a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvtepu8_epi64(src: __m512i, k: __mmask8, a: __m128i) -> __m512i;
+        [avx512bf16, avx512f] safe fn _mm512_mask_cvtne2ps_pbh(src: __m512bh, k: __mmask32, a: __m512, b: __m512) -> __m512bh;
+        [avx512bf16, avx512f] safe fn _mm512_mask_cvtneps_pbh(src: __m256bh, k: __mmask16, a: __m512) -> __m256bh;
+        [avx512bf16, avx512f] safe fn _mm512_mask_cvtpbh_ps(src: __m512, k: __mmask16, a: __m256bh) -> __m512;
+        [avx512f] safe fn _mm512_mask_cvtpd_epi32(src: __m256i, k: __mmask8, a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_mask_cvtpd_epi64(src: __m512i, k: __mmask8, a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvtpd_epu32(src: __m256i, k: __mmask8, a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_mask_cvtpd_epu64(src: __m512i, k: __mmask8, a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvtpd_ps(src: __m256, k: __mmask8, a: __m512d) -> __m256;
+        [avx512f] safe fn _mm512_mask_cvtpd_pslo(src: __m512, k: __mmask8, v2: __m512d) -> __m512;
+        [avx512f] safe fn _mm512_mask_cvtph_ps(src: __m512, k: __mmask16, a: __m256i) -> __m512;
+        [avx512f] safe fn _mm512_mask_cvtps_epi32(src: __m512i, k: __mmask16, a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_mask_cvtps_epi64(src: __m512i, k: __mmask8, a: __m256) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvtps_epu32(src: __m512i, k: __mmask16, a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_mask_cvtps_epu64(src: __m512i, k: __mmask8, a: __m256) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvtps_pd(src: __m512d, k: __mmask8, a: __m256) -> __m512d;
+        [avx512f] safe fn _mm512_mask_cvtps_ph<const ROUNDING: i32>(src: __m256i, k: __mmask16, a: __m512) -> __m256i;
+        [avx512f] safe fn _mm512_mask_cvtpslo_pd(src: __m512d, k: __mmask8, v2: __m512) -> __m512d;
+        [avx512bw] safe fn _mm512_mask_cvtsepi16_epi8(src: __m256i, k: __mmask32, a: __m512i) -> __m256i;
+        [avx512bw] unsafe fn _mm512_mask_cvtsepi16_storeu_epi8(mem_addr: *mut i8, k: __mmask32, a: __m512i);
+        [avx512f] safe fn _mm512_mask_cvtsepi32_epi16(src: __m256i, k: __mmask16, a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_mask_cvtsepi32_epi8(src: __m128i, k: __mmask16, a: __m512i) -> __m128i;
+        [avx512f] unsafe fn _mm512_mask_cvtsepi32_storeu_epi16(mem_addr: *mut i16, k: __mmask16, a: __m512i);
+        [avx512f] unsafe fn _mm512_mask_cvtsepi32_storeu_epi8(mem_addr: *mut i8, k: __mmask16, a: __m512i);
+        [avx512f] safe fn _mm512_mask_cvtsepi64_epi16(src: __m128i, k: __mmask8, a: __m512i) -> __m128i;
+        [avx512f] safe fn _mm512_mask_cvtsepi64_epi32(src: __m256i, k: __mmask8, a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_mask_cvtsepi64_epi8(src: __m128i, k: __mmask8, a: __m512i) -> __m128i;
+        [avx512f] unsafe fn _mm512_mask_cvtsepi64_storeu_epi16(mem_addr: *mut i16, k: __mmask8, a: __m512i);
+        [avx512f] unsafe fn _mm512_mask_cvtsepi64_storeu_epi32(mem_addr: *mut i32, k: __mmask8, a: __m512i);
+        [avx512f] unsafe fn _mm512_mask_cvtsepi64_storeu_epi8(mem_addr: *mut i8, k: __mmask8, a: __m512i);
+        [avx512f] safe fn _mm512_mask_cvtt_roundpd_epi32<const SAE: i32>(src: __m256i, k: __mmask8, a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_mask_cvtt_roundpd_epi64<const SAE: i32>(src: __m512i, k: __mmask8, a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvtt_roundpd_epu32<const SAE: i32>(src: __m256i, k: __mmask8, a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_mask_cvtt_roundpd_epu64<const SAE: i32>(src: __m512i, k: __mmask8, a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvtt_roundps_epi32<const SAE: i32>(src: __m512i, k: __mmask16, a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_mask_cvtt_roundps_epi64<const SAE: i32>(src: __m512i, k: __mmask8, a: __m256) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvtt_roundps_epu32<const SAE: i32>(src: __m512i, k: __mmask16, a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_mask_cvtt_roundps_epu64<const SAE: i32>(src: __m512i, k: __mmask8, a: __m256) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvttpd_epi32(src: __m256i, k: __mmask8, a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_mask_cvttpd_epi64(src: __m512i, k: __mmask8, a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvttpd_epu32(src: __m256i, k: __mmask8, a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_mask_cvttpd_epu64(src: __m512i, k: __mmask8, a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvttps_epi32(src: __m512i, k: __mmask16, a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_mask_cvttps_epi64(src: __m512i, k: __mmask8, a: __m256) -> __m512i;
+        [avx512f] safe fn _mm512_mask_cvttps_epu32(src: __m512i, k: __mmask16, a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_mask_cvttps_epu64(src: __m512i, k: __mmask8, a: __m256) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_cvtusepi16_epi8(src: __m256i, k: __mmask32, a: __m512i) -> __m256i;
+        [avx512bw] unsafe fn _mm512_mask_cvtusepi16_storeu_epi8(mem_addr: *mut i8, k: __mmask32, a: __m512i);
+        [avx512f] safe fn _mm512_mask_cvtusepi32_epi16(src: __m256i, k: __mmask16, a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_mask_cvtusepi32_epi8(src: __m128i, k: __mmask16, a: __m512i) -> __m128i;
+        [avx512f] unsafe fn _mm512_mask_cvtusepi32_storeu_epi16(mem_addr: *mut i16, k: __mmask16, a: __m512i);
+        [avx512f] unsafe fn _mm512_mask_cvtusepi32_storeu_epi8(mem_addr: *mut i8, k: __mmask16, a: __m512i);
+        [avx512f] safe fn _mm512_mask_cvtusepi64_epi16(src: __m128i, k: __mmask8, a: __m512i) -> __m128i;
+        [avx512f] safe fn _mm512_mask_cvtusepi64_epi32(src: __m256i, k: __mmask8, a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_mask_cvtusepi64_epi8(src: __m128i, k: __mmask8, a: __m512i) -> __m128i;
+        [avx512f] unsafe fn _mm512_mask_cvtusepi64_storeu_epi16(mem_addr: *mut i16, k: __mmask8, a: __m512i);
+        [avx512f] unsafe fn _mm512_mask_cvtusepi64_storeu_epi32(mem_addr: *mut i32, k: __mmask8, a: __m512i);
+        [avx512f] unsafe fn _mm512_mask_cvtusepi64_storeu_epi8(mem_addr: *mut i8, k: __mmask8, a: __m512i);
+        [avx512bw] safe fn _mm512_mask_dbsad_epu8<const IMM8: i32>(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_div_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_div_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_div_round_pd<const ROUNDING: i32>(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_div_round_ps<const ROUNDING: i32>(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bf16, avx512f] safe fn _mm512_mask_dpbf16_ps(src: __m512, k: __mmask16, a: __m512bh, b: __m512bh) -> __m512;
+        [avx512vnni] safe fn _mm512_mask_dpbusd_epi32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vnni] safe fn _mm512_mask_dpbusds_epi32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vnni] safe fn _mm512_mask_dpwssd_epi32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vnni] safe fn _mm512_mask_dpwssds_epi32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_mask_expand_epi16(src: __m512i, k: __mmask32, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_expand_epi32(src: __m512i, k: __mmask16, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_expand_epi64(src: __m512i, k: __mmask8, a: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_mask_expand_epi8(src: __m512i, k: __mmask64, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_expand_pd(src: __m512d, k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_expand_ps(src: __m512, k: __mmask16, a: __m512) -> __m512;
+        [avx512vbmi2] unsafe fn _mm512_mask_expandloadu_epi16(src: __m512i, k: __mmask32, mem_addr: *const i16) -> __m512i;
+        [avx512f] unsafe fn _mm512_mask_expandloadu_epi32(src: __m512i, k: __mmask16, mem_addr: *const i32) -> __m512i;
+        [avx512f] unsafe fn _mm512_mask_expandloadu_epi64(src: __m512i, k: __mmask8, mem_addr: *const i64) -> __m512i;
+        [avx512vbmi2] unsafe fn _mm512_mask_expandloadu_epi8(src: __m512i, k: __mmask64, mem_addr: *const i8) -> __m512i;
+        [avx512f] unsafe fn _mm512_mask_expandloadu_pd(src: __m512d, k: __mmask8, mem_addr: *const f64) -> __m512d;
+        [avx512f] unsafe fn _mm512_mask_expandloadu_ps(src: __m512, k: __mmask16, mem_addr: *const f32) -> __m512;
+        [avx512f] safe fn _mm512_mask_extractf32x4_ps<const IMM8: i32>(src: __m128, k: __mmask8, a: __m512) -> __m128;
+        [avx512dq] safe fn _mm512_mask_extractf32x8_ps<const IMM8: i32>(src: __m256, k: __mmask8, a: __m512) -> __m256;
+        [avx512dq] safe fn _mm512_mask_extractf64x2_pd<const IMM8: i32>(src: __m128d, k: __mmask8, a: __m512d) -> __m128d;
+        [avx512f] safe fn _mm512_mask_extractf64x4_pd<const IMM8: i32>(src: __m256d, k: __mmask8, a: __m512d) -> __m256d;
+        [avx512f] safe fn _mm512_mask_extracti32x4_epi32<const IMM2: i32>(src: __m128i, k: __mmask8, a: __m512i) -> __m128i;
+        [avx512dq] safe fn _mm512_mask_extracti32x8_epi32<const IMM8: i32>(src: __m256i, k: __mmask8, a: __m512i) -> __m256i;
+        [avx512dq] safe fn _mm512_mask_extracti64x2_epi64<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m512i) -> __m128i;
+        [avx512f] safe fn _mm512_mask_extracti64x4_epi64<const IMM1: i32>(src: __m256i, k: __mmask8, a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_mask_fixupimm_pd<const IMM8: i32>(a: __m512d, k: __mmask8, b: __m512d, c: __m512i) -> __m512d;
+        [avx512f] safe fn _mm512_mask_fixupimm_ps<const IMM8: i32>(a: __m512, k: __mmask16, b: __m512, c: __m512i) -> __m512;
+        [avx512f] safe fn _mm512_mask_fixupimm_round_pd<const IMM8: i32, const SAE: i32>(a: __m512d, k: __mmask8, b: __m512d, c: __m512i) -> __m512d;
+        [avx512f] safe fn _mm512_mask_fixupimm_round_ps<const IMM8: i32, const SAE: i32>(a: __m512, k: __mmask16, b: __m512, c: __m512i) -> __m512;
+        [avx512f] safe fn _mm512_mask_fmadd_pd(a: __m512d, k: __mmask8, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_fmadd_ps(a: __m512, k: __mmask16, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_fmadd_round_pd<const ROUNDING: i32>(a: __m512d, k: __mmask8, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_fmadd_round_ps<const ROUNDING: i32>(a: __m512, k: __mmask16, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_fmaddsub_pd(a: __m512d, k: __mmask8, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_fmaddsub_ps(a: __m512, k: __mmask16, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_fmaddsub_round_pd<const ROUNDING: i32>(a: __m512d, k: __mmask8, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_fmaddsub_round_ps<const ROUNDING: i32>(a: __m512, k: __mmask16, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_fmsub_pd(a: __m512d, k: __mmask8, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_fmsub_ps(a: __m512, k: __mmask16, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_fmsub_round_pd<const ROUNDING: i32>(a: __m512d, k: __mmask8, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_fmsub_round_ps<const ROUNDING: i32>(a: __m512, k: __mmask16, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_fmsubadd_pd(a: __m512d, k: __mmask8, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_fmsubadd_ps(a: __m512, k: __mmask16, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_fmsubadd_round_pd<const ROUNDING: i32>(a: __m512d, k: __mmask8, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_fmsubadd_round_ps<const ROUNDING: i32>(a: __m512, k: __mmask16, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_fnmadd_pd(a: __m512d, k: __mmask8, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_fnmadd_ps(a: __m512, k: __mmask16, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_fnmadd_round_pd<const ROUNDING: i32>(a: __m512d, k: __mmask8, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_fnmadd_round_ps<const ROUNDING: i32>(a: __m512, k: __mmask16, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_fnmsub_pd(a: __m512d, k: __mmask8, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_fnmsub_ps(a: __m512, k: __mmask16, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_fnmsub_round_pd<const ROUNDING: i32>(a: __m512d, k: __mmask8, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_fnmsub_round_ps<const ROUNDING: i32>(a: __m512, k: __mmask16, b: __m512, c: __m512) -> __m512;
+        [avx512dq] safe fn _mm512_mask_fpclass_pd_mask<const IMM8: i32>(k1: __mmask8, a: __m512d) -> __mmask8;
+        [avx512dq] safe fn _mm512_mask_fpclass_ps_mask<const IMM8: i32>(k1: __mmask16, a: __m512) -> __mmask16;
+        [avx512f] safe fn _mm512_mask_getexp_pd(src: __m512d, k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_getexp_ps(src: __m512, k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_getexp_round_pd<const SAE: i32>(src: __m512d, k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_getexp_round_ps<const SAE: i32>(src: __m512, k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_getmant_pd<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(src: __m512d, k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_getmant_ps<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(src: __m512, k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_getmant_round_pd<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM, const SAE: i32>(src: __m512d, k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_getmant_round_ps<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM, const SAE: i32>(src: __m512, k: __mmask16, a: __m512) -> __m512;
+        [gfni, avx512bw, avx512f] safe fn _mm512_mask_gf2p8affine_epi64_epi8<const B: i32>(src: __m512i, k: __mmask64, x: __m512i, a: __m512i) -> __m512i;
+        [gfni, avx512bw, avx512f] safe fn _mm512_mask_gf2p8affineinv_epi64_epi8<const B: i32>(src: __m512i, k: __mmask64, x: __m512i, a: __m512i) -> __m512i;
+        [gfni, avx512bw, avx512f] safe fn _mm512_mask_gf2p8mul_epi8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] unsafe fn _mm512_mask_i32gather_epi32<const SCALE: i32>(src: __m512i, mask: __mmask16, offsets: __m512i, slice: *const i32) -> __m512i;
+        [avx512f] unsafe fn _mm512_mask_i32gather_epi64<const SCALE: i32>(src: __m512i, mask: __mmask8, offsets: __m256i, slice: *const i64) -> __m512i;
+        [avx512f] unsafe fn _mm512_mask_i32gather_pd<const SCALE: i32>(src: __m512d, mask: __mmask8, offsets: __m256i, slice: *const f64) -> __m512d;
+        [avx512f] unsafe fn _mm512_mask_i32gather_ps<const SCALE: i32>(src: __m512, mask: __mmask16, offsets: __m512i, slice: *const f32) -> __m512;
+        [avx512f] unsafe fn _mm512_mask_i32logather_epi64<const SCALE: i32>(src: __m512i, k: __mmask8, vindex: __m512i, base_addr: *const i64) -> __m512i;
+        [avx512f] unsafe fn _mm512_mask_i32logather_pd<const SCALE: i32>(src: __m512d, k: __mmask8, vindex: __m512i, base_addr: *const f64) -> __m512d;
+        [avx512f] unsafe fn _mm512_mask_i32loscatter_epi64<const SCALE: i32>(base_addr: *mut i64, k: __mmask8, vindex: __m512i, a: __m512i);
+        [avx512f] unsafe fn _mm512_mask_i32loscatter_pd<const SCALE: i32>(base_addr: *mut f64, k: __mmask8, vindex: __m512i, a: __m512d);
+        [avx512f] unsafe fn _mm512_mask_i32scatter_epi32<const SCALE: i32>(slice: *mut i32, mask: __mmask16, offsets: __m512i, src: __m512i);
+        [avx512f] unsafe fn _mm512_mask_i32scatter_epi64<const SCALE: i32>(slice: *mut i64, mask: __mmask8, offsets: __m256i, src: __m512i);
+        [avx512f] unsafe fn _mm512_mask_i32scatter_pd<const SCALE: i32>(slice: *mut f64, mask: __mmask8, offsets: __m256i, src: __m512d);
+        [avx512f] unsafe fn _mm512_mask_i32scatter_ps<const SCALE: i32>(slice: *mut f32, mask: __mmask16, offsets: __m512i, src: __m512);
+        [avx512f] unsafe fn _mm512_mask_i64gather_epi32<const SCALE: i32>(src: __m256i, mask: __mmask8, offsets: __m512i, slice: *const i32) -> __m256i;
+        [avx512f] unsafe fn _mm512_mask_i64gather_epi64<const SCALE: i32>(src: __m512i, mask: __mmask8, offsets: __m512i, slice: *const i64) -> __m512i;
+        [avx512f] unsafe fn _mm512_mask_i64gather_pd<const SCALE: i32>(src: __m512d, mask: __mmask8, offsets: __m512i, slice: *const f64) -> __m512d;
+        [avx512f] unsafe fn _mm512_mask_i64gather_ps<const SCALE: i32>(src: __m256, mask: __mmask8, offsets: __m512i, slice: *const f32) -> __m256;
+        [avx512f] unsafe fn _mm512_mask_i64scatter_epi32<const SCALE: i32>(slice: *mut i32, mask: __mmask8, offsets: __m512i, src: __m256i);
+        [avx512f] unsafe fn _mm512_mask_i64scatter_epi64<const SCALE: i32>(slice: *mut i64, mask: __mmask8, offsets: __m512i, src: __m512i);
+        [avx512f] unsafe fn _mm512_mask_i64scatter_pd<const SCALE: i32>(slice: *mut f64, mask: __mmask8, offsets: __m512i, src: __m512d);
+        [avx512f] unsafe fn _mm512_mask_i64scatter_ps<const SCALE: i32>(slice: *mut f32, mask: __mmask8, offsets: __m512i, src: __m256);
+        [avx512f] safe fn _mm512_mask_insertf32x4<const IMM8: i32>(src: __m512, k: __mmask16, a: __m512, b: __m128) -> __m512;
+        [avx512dq] safe fn _mm512_mask_insertf32x8<const IMM8: i32>(src: __m512, k: __mmask16, a: __m512, b: __m256) -> __m512;
+        [avx512dq] safe fn _mm512_mask_insertf64x2<const IMM8: i32>(src: __m512d, k: __mmask8, a: __m512d, b: __m128d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_insertf64x4<const IMM8: i32>(src: __m512d, k: __mmask8, a: __m512d, b: __m256d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_inserti32x4<const IMM8: i32>(src: __m512i, k: __mmask16, a: __m512i, b: __m128i) -> __m512i;
+        [avx512dq] safe fn _mm512_mask_inserti32x8<const IMM8: i32>(src: __m512i, k: __mmask16, a: __m512i, b: __m256i) -> __m512i;
+        [avx512dq] safe fn _mm512_mask_inserti64x2<const IMM8: i32>(src: __m512i, k: __mmask8, a: __m512i, b: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_inserti64x4<const IMM8: i32>(src: __m512i, k: __mmask8, a: __m512i, b: __m256i) -> __m512i;
+        [avx512f] unsafe fn _mm512_mask_load_epi32(src: __m512i, k: __mmask16, mem_addr: *const i32) -> __m512i;
+        [avx512f] unsafe fn _mm512_mask_load_epi64(src: __m512i, k: __mmask8, mem_addr: *const i64) -> __m512i;
+        [avx512f] unsafe fn _mm512_mask_load_pd(src: __m512d, k: __mmask8, mem_addr: *const f64) -> __m512d;
+        [avx512f] unsafe fn _mm512_mask_load_ps(src: __m512, k: __mmask16, mem_addr: *const f32) -> __m512;
+        [avx512bw] unsafe fn _mm512_mask_loadu_epi16(src: __m512i, k: __mmask32, mem_addr: *const i16) -> __m512i;
+        [avx512f] unsafe fn _mm512_mask_loadu_epi32(src: __m512i, k: __mmask16, mem_addr: *const i32) -> __m512i;
+        [avx512f] unsafe fn _mm512_mask_loadu_epi64(src: __m512i, k: __mmask8, mem_addr: *const i64) -> __m512i;
+        [avx512bw] unsafe fn _mm512_mask_loadu_epi8(src: __m512i, k: __mmask64, mem_addr: *const i8) -> __m512i;
+        [avx512f] unsafe fn _mm512_mask_loadu_pd(src: __m512d, k: __mmask8, mem_addr: *const f64) -> __m512d;
+        [avx512f] unsafe fn _mm512_mask_loadu_ps(src: __m512, k: __mmask16, mem_addr: *const f32) -> __m512;
+        [avx512cd] safe fn _mm512_mask_lzcnt_epi32(src: __m512i, k: __mmask16, a: __m512i) -> __m512i;
+        [avx512cd] safe fn _mm512_mask_lzcnt_epi64(src: __m512i, k: __mmask8, a: __m512i) -> __m512i;
+        [avx512ifma] safe fn _mm512_mask_madd52hi_epu64(a: __m512i, k: __mmask8, b: __m512i, c: __m512i) -> __m512i;
+        [avx512ifma] safe fn _mm512_mask_madd52lo_epu64(a: __m512i, k: __mmask8, b: __m512i, c: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_madd_epi16(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_maddubs_epi16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_max_epi16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_max_epi32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_max_epi64(src: __m512i, k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_max_epi8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_max_epu16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_max_epu32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_max_epu64(src: __m512i, k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_max_epu8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_max_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_max_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_max_round_pd<const SAE: i32>(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_max_round_ps<const SAE: i32>(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_mask_min_epi16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_min_epi32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_min_epi64(src: __m512i, k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_min_epi8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_min_epu16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_min_epu32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_min_epu64(src: __m512i, k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_min_epu8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_min_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_min_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_min_round_pd<const SAE: i32>(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_min_round_ps<const SAE: i32>(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_mask_mov_epi16(src: __m512i, k: __mmask32, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_mov_epi32(src: __m512i, k: __mmask16, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_mov_epi64(src: __m512i, k: __mmask8, a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_mov_epi8(src: __m512i, k: __mmask64, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_mov_pd(src: __m512d, k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_mov_ps(src: __m512, k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_movedup_pd(src: __m512d, k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_movehdup_ps(src: __m512, k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_moveldup_ps(src: __m512, k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_mul_epi32(src: __m512i, k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_mul_epu32(src: __m512i, k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_mul_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_mul_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_mul_round_pd<const ROUNDING: i32>(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_mul_round_ps<const ROUNDING: i32>(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_mask_mulhi_epi16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_mulhi_epu16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_mulhrs_epi16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_mullo_epi16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_mullo_epi32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512dq] safe fn _mm512_mask_mullo_epi64(src: __m512i, k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_mullox_epi64(src: __m512i, k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi] safe fn _mm512_mask_multishift_epi64_epi8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_or_epi32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_or_epi64(src: __m512i, k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512dq] safe fn _mm512_mask_or_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_mask_or_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_mask_packs_epi16(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_packs_epi32(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_packus_epi16(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_packus_epi32(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_permute_pd<const MASK: i32>(src: __m512d, k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_permute_ps<const MASK: i32>(src: __m512, k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_permutevar_epi32(src: __m512i, k: __mmask16, idx: __m512i, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_permutevar_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512i) -> __m512d;
+        [avx512f] safe fn _mm512_mask_permutevar_ps(src: __m512, k: __mmask16, a: __m512, b: __m512i) -> __m512;
+        [avx512bw] safe fn _mm512_mask_permutex2var_epi16(a: __m512i, k: __mmask32, idx: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_permutex2var_epi32(a: __m512i, k: __mmask16, idx: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_permutex2var_epi64(a: __m512i, k: __mmask8, idx: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi] safe fn _mm512_mask_permutex2var_epi8(a: __m512i, k: __mmask64, idx: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_permutex2var_pd(a: __m512d, k: __mmask8, idx: __m512i, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_permutex2var_ps(a: __m512, k: __mmask16, idx: __m512i, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_permutex_epi64<const MASK: i32>(src: __m512i, k: __mmask8, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_permutex_pd<const MASK: i32>(src: __m512d, k: __mmask8, a: __m512d) -> __m512d;
+        [avx512bw] safe fn _mm512_mask_permutexvar_epi16(src: __m512i, k: __mmask32, idx: __m512i, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_permutexvar_epi32(src: __m512i, k: __mmask16, idx: __m512i, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_permutexvar_epi64(src: __m512i, k: __mmask8, idx: __m512i, a: __m512i) -> __m512i;
+        [avx512vbmi] safe fn _mm512_mask_permutexvar_epi8(src: __m512i, k: __mmask64, idx: __m512i, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_permutexvar_pd(src: __m512d, k: __mmask8, idx: __m512i, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_permutexvar_ps(src: __m512, k: __mmask16, idx: __m512i, a: __m512) -> __m512;
+        [avx512bitalg] safe fn _mm512_mask_popcnt_epi16(src: __m512i, k: __mmask32, a: __m512i) -> __m512i;
+        [avx512vpopcntdq] safe fn _mm512_mask_popcnt_epi32(src: __m512i, k: __mmask16, a: __m512i) -> __m512i;
+        [avx512vpopcntdq] safe fn _mm512_mask_popcnt_epi64(src: __m512i, k: __mmask8, a: __m512i) -> __m512i;
+        [avx512bitalg] safe fn _mm512_mask_popcnt_epi8(src: __m512i, k: __mmask64, a: __m512i) -> __m512i;
+        [avx512dq] safe fn _mm512_mask_range_pd<const IMM8: i32>(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_mask_range_ps<const IMM8: i32>(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512dq] safe fn _mm512_mask_range_round_pd<const IMM8: i32, const SAE: i32>(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_mask_range_round_ps<const IMM8: i32, const SAE: i32>(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_rcp14_pd(src: __m512d, k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_rcp14_ps(src: __m512, k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_reduce_add_epi32(k: __mmask16, a: __m512i) -> i32;
+        [avx512f] safe fn _mm512_mask_reduce_add_epi64(k: __mmask8, a: __m512i) -> i64;
+        [avx512f] safe fn _mm512_mask_reduce_add_pd(k: __mmask8, a: __m512d) -> f64;
+        [avx512f] safe fn _mm512_mask_reduce_add_ps(k: __mmask16, a: __m512) -> f32;
+        [avx512f] safe fn _mm512_mask_reduce_and_epi32(k: __mmask16, a: __m512i) -> i32;
+        [avx512f] safe fn _mm512_mask_reduce_and_epi64(k: __mmask8, a: __m512i) -> i64;
+        [avx512f] safe fn _mm512_mask_reduce_max_epi32(k: __mmask16, a: __m512i) -> i32;
+        [avx512f] safe fn _mm512_mask_reduce_max_epi64(k: __mmask8, a: __m512i) -> i64;
+        [avx512f] safe fn _mm512_mask_reduce_max_epu32(k: __mmask16, a: __m512i) -> u32;
+        [avx512f] safe fn _mm512_mask_reduce_max_epu64(k: __mmask8, a: __m512i) -> u64;
+        [avx512f] safe fn _mm512_mask_reduce_max_pd(k: __mmask8, a: __m512d) -> f64;
+        [avx512f] safe fn _mm512_mask_reduce_max_ps(k: __mmask16, a: __m512) -> f32;
+        [avx512f] safe fn _mm512_mask_reduce_min_epi32(k: __mmask16, a: __m512i) -> i32;
+        [avx512f] safe fn _mm512_mask_reduce_min_epi64(k: __mmask8, a: __m512i) -> i64;
+        [avx512f] safe fn _mm512_mask_reduce_min_epu32(k: __mmask16, a: __m512i) -> u32;
+        [avx512f] safe fn _mm512_mask_reduce_min_epu64(k: __mmask8, a: __m512i) -> u64;
+        [avx512f] safe fn _mm512_mask_reduce_min_pd(k: __mmask8, a: __m512d) -> f64;
+        [avx512f] safe fn _mm512_mask_reduce_min_ps(k: __mmask16, a: __m512) -> f32;
+        [avx512f] safe fn _mm512_mask_reduce_mul_epi32(k: __mmask16, a: __m512i) -> i32;
+        [avx512f] safe fn _mm512_mask_reduce_mul_epi64(k: __mmask8, a: __m512i) -> i64;
+        [avx512f] safe fn _mm512_mask_reduce_mul_pd(k: __mmask8, a: __m512d) -> f64;
+        [avx512f] safe fn _mm512_mask_reduce_mul_ps(k: __mmask16, a: __m512) -> f32;
+        [avx512f] safe fn _mm512_mask_reduce_or_epi32(k: __mmask16, a: __m512i) -> i32;
+        [avx512f] safe fn _mm512_mask_reduce_or_epi64(k: __mmask8, a: __m512i) -> i64;
+        [avx512dq] safe fn _mm512_mask_reduce_pd<const IMM8: i32>(src: __m512d, k: __mmask8, a: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_mask_reduce_ps<const IMM8: i32>(src: __m512, k: __mmask16, a: __m512) -> __m512;
+        [avx512dq] safe fn _mm512_mask_reduce_round_pd<const IMM8: i32, const SAE: i32>(src: __m512d, k: __mmask8, a: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_mask_reduce_round_ps<const IMM8: i32, const SAE: i32>(src: __m512, k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_rol_epi32<const IMM8: i32>(src: __m512i, k: __mmask16, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_rol_epi64<const IMM8: i32>(src: __m512i, k: __mmask8, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_rolv_epi32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_rolv_epi64(src: __m512i, k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_ror_epi32<const IMM8: i32>(src: __m512i, k: __mmask16, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_ror_epi64<const IMM8: i32>(src: __m512i, k: __mmask8, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_rorv_epi32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_rorv_epi64(src: __m512i, k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_roundscale_pd<const IMM8: i32>(src: __m512d, k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_roundscale_ps<const IMM8: i32>(src: __m512, k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_roundscale_round_pd<const IMM8: i32, const SAE: i32>(src: __m512d, k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_roundscale_round_ps<const IMM8: i32, const SAE: i32>(src: __m512, k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_rsqrt14_pd(src: __m512d, k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_rsqrt14_ps(src: __m512, k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_scalef_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_scalef_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_scalef_round_pd<const ROUNDING: i32>(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_scalef_round_ps<const ROUNDING: i32>(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_mask_set1_epi16(src: __m512i, k: __mmask32, a: i16) -> __m512i;
+        [avx512f] safe fn _mm512_mask_set1_epi32(src: __m512i, k: __mmask16, a: i32) -> __m512i;
+        [avx512f] safe fn _mm512_mask_set1_epi64(src: __m512i, k: __mmask8, a: i64) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_set1_epi8(src: __m512i, k: __mmask64, a: i8) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_mask_shldi_epi16<const IMM8: i32>(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_mask_shldi_epi32<const IMM8: i32>(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_mask_shldi_epi64<const IMM8: i32>(src: __m512i, k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_mask_shldv_epi16(a: __m512i, k: __mmask32, b: __m512i, c: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_mask_shldv_epi32(a: __m512i, k: __mmask16, b: __m512i, c: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_mask_shldv_epi64(a: __m512i, k: __mmask8, b: __m512i, c: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_mask_shrdi_epi16<const IMM8: i32>(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_mask_shrdi_epi32<const IMM8: i32>(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_mask_shrdi_epi64<const IMM8: i32>(src: __m512i, k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_mask_shrdv_epi16(a: __m512i, k: __mmask32, b: __m512i, c: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_mask_shrdv_epi32(a: __m512i, k: __mmask16, b: __m512i, c: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_mask_shrdv_epi64(a: __m512i, k: __mmask8, b: __m512i, c: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_shuffle_epi32<const MASK: _MM_PERM_ENUM>(src: __m512i, k: __mmask16, a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_shuffle_epi8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_shuffle_f32x4<const MASK: i32>(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_shuffle_f64x2<const MASK: i32>(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_shuffle_i32x4<const MASK: i32>(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_shuffle_i64x2<const MASK: i32>(src: __m512i, k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_shuffle_pd<const MASK: i32>(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_shuffle_ps<const MASK: i32>(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_mask_shufflehi_epi16<const IMM8: i32>(src: __m512i, k: __mmask32, a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_shufflelo_epi16<const IMM8: i32>(src: __m512i, k: __mmask32, a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_sll_epi16(src: __m512i, k: __mmask32, a: __m512i, count: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_sll_epi32(src: __m512i, k: __mmask16, a: __m512i, count: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_sll_epi64(src: __m512i, k: __mmask8, a: __m512i, count: __m128i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_slli_epi16<const IMM8: u32>(src: __m512i, k: __mmask32, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_slli_epi32<const IMM8: u32>(src: __m512i, k: __mmask16, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_slli_epi64<const IMM8: u32>(src: __m512i, k: __mmask8, a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_sllv_epi16(src: __m512i, k: __mmask32, a: __m512i, count: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_sllv_epi32(src: __m512i, k: __mmask16, a: __m512i, count: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_sllv_epi64(src: __m512i, k: __mmask8, a: __m512i, count: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_sqrt_pd(src: __m512d, k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_sqrt_ps(src: __m512, k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_sqrt_round_pd<const ROUNDING: i32>(src: __m512d, k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_sqrt_round_ps<const ROUNDING: i32>(src: __m512, k: __mmask16, a: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_mask_sra_epi16(src: __m512i, k: __mmask32, a: __m512i, count: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_sra_epi32(src: __m512i, k: __mmask16, a: __m512i, count: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_sra_epi64(src: __m512i, k: __mmask8, a: __m512i, count: __m128i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_srai_epi16<const IMM8: u32>(src: __m512i, k: __mmask32, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_srai_epi32<const IMM8: u32>(src: __m512i, k: __mmask16, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_srai_epi64<const IMM8: u32>(src: __m512i, k: __mmask8, a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_srav_epi16(src: __m512i, k: __mmask32, a: __m512i, count: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_srav_epi32(src: __m512i, k: __mmask16, a: __m512i, count: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_srav_epi64(src: __m512i, k: __mmask8, a: __m512i, count: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_srl_epi16(src: __m512i, k: __mmask32, a: __m512i, count: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_srl_epi32(src: __m512i, k: __mmask16, a: __m512i, count: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_srl_epi64(src: __m512i, k: __mmask8, a: __m512i, count: __m128i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_srli_epi16<const IMM8: u32>(src: __m512i, k: __mmask32, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_srli_epi32<const IMM8: u32>(src: __m512i, k: __mmask16, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_srli_epi64<const IMM8: u32>(src: __m512i, k: __mmask8, a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_srlv_epi16(src: __m512i, k: __mmask32, a: __m512i, count: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_srlv_epi32(src: __m512i, k: __mmask16, a: __m512i, count: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_srlv_epi64(src: __m512i, k: __mmask8, a: __m512i, count: __m512i) -> __m512i;
+        [avx512f] unsafe fn _mm512_mask_store_epi32(mem_addr: *mut i32, mask: __mmask16, a: __m512i);
+        [avx512f] unsafe fn _mm512_mask_store_epi64(mem_addr: *mut i64, mask: __mmask8, a: __m512i);
+        [avx512f] unsafe fn _mm512_mask_store_pd(mem_addr: *mut f64, mask: __mmask8, a: __m512d);
+        [avx512f] unsafe fn _mm512_mask_store_ps(mem_addr: *mut f32, mask: __mmask16, a: __m512);
+        [avx512bw] unsafe fn _mm512_mask_storeu_epi16(mem_addr: *mut i16, mask: __mmask32, a: __m512i);
+        [avx512f] unsafe fn _mm512_mask_storeu_epi32(mem_addr: *mut i32, mask: __mmask16, a: __m512i);
+        [avx512f] unsafe fn _mm512_mask_storeu_epi64(mem_addr: *mut i64, mask: __mmask8, a: __m512i);
+        [avx512bw] unsafe fn _mm512_mask_storeu_epi8(mem_addr: *mut i8, mask: __mmask64, a: __m512i);
+        [avx512f] unsafe fn _mm512_mask_storeu_pd(mem_addr: *mut f64, mask: __mmask8, a: __m512d);
+        [avx512f] unsafe fn _mm512_mask_storeu_ps(mem_addr: *mut f32, mask: __mmask16, a: __m512);
+        [avx512bw] safe fn _mm512_mask_sub_epi16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_sub_epi32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_sub_epi64(src: __m512i, k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_sub_epi8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_sub_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_sub_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_sub_round_pd<const ROUNDING: i32>(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_sub_round_ps<const ROUNDING: i32>(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_mask_subs_epi16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_subs_epi8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_subs_epu16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_subs_epu8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_ternarylogic_epi32<const IMM8: i32>(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_ternarylogic_epi64<const IMM8: i32>(src: __m512i, k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_test_epi16_mask(k: __mmask32, a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_mask_test_epi32_mask(k: __mmask16, a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_mask_test_epi64_mask(k: __mmask8, a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_mask_test_epi8_mask(k: __mmask64, a: __m512i, b: __m512i) -> __mmask64;
+        [avx512bw] safe fn _mm512_mask_testn_epi16_mask(k: __mmask32, a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_mask_testn_epi32_mask(k: __mmask16, a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_mask_testn_epi64_mask(k: __mmask8, a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_mask_testn_epi8_mask(k: __mmask64, a: __m512i, b: __m512i) -> __mmask64;
+        [avx512bw] safe fn _mm512_mask_unpackhi_epi16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_unpackhi_epi32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_unpackhi_epi64(src: __m512i, k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_unpackhi_epi8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_unpackhi_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_unpackhi_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_mask_unpacklo_epi16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_unpacklo_epi32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_unpacklo_epi64(src: __m512i, k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mask_unpacklo_epi8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_unpacklo_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mask_unpacklo_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mask_xor_epi32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mask_xor_epi64(src: __m512i, k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512dq] safe fn _mm512_mask_xor_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_mask_xor_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_maskz_abs_epi16(k: __mmask32, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_abs_epi32(k: __mmask16, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_abs_epi64(k: __mmask8, a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_abs_epi8(k: __mmask64, a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_add_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_add_epi32(k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_add_epi64(k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_add_epi8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_add_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_add_ps(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_add_round_pd<const ROUNDING: i32>(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_add_round_ps<const ROUNDING: i32>(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_maskz_adds_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_adds_epi8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_adds_epu16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_adds_epu8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_alignr_epi32<const IMM8: i32>(k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_alignr_epi64<const IMM8: i32>(k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_alignr_epi8<const IMM8: i32>(k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_and_epi32(k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_and_epi64(k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512dq] safe fn _mm512_maskz_and_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_maskz_and_ps(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_andnot_epi32(k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_andnot_epi64(k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512dq] safe fn _mm512_maskz_andnot_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_maskz_andnot_ps(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_maskz_avg_epu16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_avg_epu8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512dq] safe fn _mm512_maskz_broadcast_f32x2(k: __mmask16, a: __m128) -> __m512;
+        [avx512f] safe fn _mm512_maskz_broadcast_f32x4(k: __mmask16, a: __m128) -> __m512;
+        [avx512dq] safe fn _mm512_maskz_broadcast_f32x8(k: __mmask16, a: __m256) -> __m512;
+        [avx512dq] safe fn _mm512_maskz_broadcast_f64x2(k: __mmask8, a: __m128d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_broadcast_f64x4(k: __mmask8, a: __m256d) -> __m512d;
+        [avx512dq] safe fn _mm512_maskz_broadcast_i32x2(k: __mmask16, a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_broadcast_i32x4(k: __mmask16, a: __m128i) -> __m512i;
+        [avx512dq] safe fn _mm512_maskz_broadcast_i32x8(k: __mmask16, a: __m256i) -> __m512i;
+        [avx512dq] safe fn _mm512_maskz_broadcast_i64x2(k: __mmask8, a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_broadcast_i64x4(k: __mmask8, a: __m256i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_broadcastb_epi8(k: __mmask64, a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_broadcastd_epi32(k: __mmask16, a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_broadcastq_epi64(k: __mmask8, a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_broadcastsd_pd(k: __mmask8, a: __m128d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_broadcastss_ps(k: __mmask16, a: __m128) -> __m512;
+        [avx512bw] safe fn _mm512_maskz_broadcastw_epi16(k: __mmask32, a: __m128i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_maskz_compress_epi16(k: __mmask32, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_compress_epi32(k: __mmask16, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_compress_epi64(k: __mmask8, a: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_maskz_compress_epi8(k: __mmask64, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_compress_pd(k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_compress_ps(k: __mmask16, a: __m512) -> __m512;
+        [avx512cd] safe fn _mm512_maskz_conflict_epi32(k: __mmask16, a: __m512i) -> __m512i;
+        [avx512cd] safe fn _mm512_maskz_conflict_epi64(k: __mmask8, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvt_roundepi32_ps<const ROUNDING: i32>(k: __mmask16, a: __m512i) -> __m512;
+        [avx512dq] safe fn _mm512_maskz_cvt_roundepi64_pd<const ROUNDING: i32>(k: __mmask8, a: __m512i) -> __m512d;
+        [avx512dq] safe fn _mm512_maskz_cvt_roundepi64_ps<const ROUNDING: i32>(k: __mmask8, a: __m512i) -> __m256;
+        [avx512f] safe fn _mm512_maskz_cvt_roundepu32_ps<const ROUNDING: i32>(k: __mmask16, a: __m512i) -> __m512;
+        [avx512dq] safe fn _mm512_maskz_cvt_roundepu64_pd<const ROUNDING: i32>(k: __mmask8, a: __m512i) -> __m512d;
+        [avx512dq] safe fn _mm512_maskz_cvt_roundepu64_ps<const ROUNDING: i32>(k: __mmask8, a: __m512i) -> __m256;
+        [avx512f] safe fn _mm512_maskz_cvt_roundpd_epi32<const ROUNDING: i32>(k: __mmask8, a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_maskz_cvt_roundpd_epi64<const ROUNDING: i32>(k: __mmask8, a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvt_roundpd_epu32<const ROUNDING: i32>(k: __mmask8, a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_maskz_cvt_roundpd_epu64<const ROUNDING: i32>(k: __mmask8, a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvt_roundpd_ps<const ROUNDING: i32>(k: __mmask8, a: __m512d) -> __m256;
+        [avx512f] safe fn _mm512_maskz_cvt_roundph_ps<const SAE: i32>(k: __mmask16, a: __m256i) -> __m512;
+        [avx512f] safe fn _mm512_maskz_cvt_roundps_epi32<const ROUNDING: i32>(k: __mmask16, a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_maskz_cvt_roundps_epi64<const ROUNDING: i32>(k: __mmask8, a: __m256) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvt_roundps_epu32<const ROUNDING: i32>(k: __mmask16, a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_maskz_cvt_roundps_epu64<const ROUNDING: i32>(k: __mmask8, a: __m256) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvt_roundps_pd<const SAE: i32>(k: __mmask8, a: __m256) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_cvt_roundps_ph<const ROUNDING: i32>(k: __mmask16, a: __m512) -> __m256i;
+        [avx512f] safe fn _mm512_maskz_cvtepi16_epi32(k: __mmask16, a: __m256i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvtepi16_epi64(k: __mmask8, a: __m128i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_cvtepi16_epi8(k: __mmask32, a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_maskz_cvtepi32_epi16(k: __mmask16, a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_maskz_cvtepi32_epi64(k: __mmask8, a: __m256i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvtepi32_epi8(k: __mmask16, a: __m512i) -> __m128i;
+        [avx512f] safe fn _mm512_maskz_cvtepi32_pd(k: __mmask8, a: __m256i) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_cvtepi32_ps(k: __mmask16, a: __m512i) -> __m512;
+        [avx512f] safe fn _mm512_maskz_cvtepi64_epi16(k: __mmask8, a: __m512i) -> __m128i;
+        [avx512f] safe fn _mm512_maskz_cvtepi64_epi32(k: __mmask8, a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_maskz_cvtepi64_epi8(k: __mmask8, a: __m512i) -> __m128i;
+        [avx512dq] safe fn _mm512_maskz_cvtepi64_pd(k: __mmask8, a: __m512i) -> __m512d;
+        [avx512dq] safe fn _mm512_maskz_cvtepi64_ps(k: __mmask8, a: __m512i) -> __m256;
+        [avx512bw] safe fn _mm512_maskz_cvtepi8_epi16(k: __mmask32, a: __m256i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvtepi8_epi32(k: __mmask16, a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvtepi8_epi64(k: __mmask8, a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvtepu16_epi32(k: __mmask16, a: __m256i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvtepu16_epi64(k: __mmask8, a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvtepu32_epi64(k: __mmask8, a: __m256i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvtepu32_pd(k: __mmask8, a: __m256i) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_cvtepu32_ps(k: __mmask16, a: __m512i) -> __m512;
+        [avx512dq] safe fn _mm512_maskz_cvtepu64_pd(k: __mmask8, a: __m512i) -> __m512d;
+        [avx512dq] safe fn _mm512_maskz_cvtepu64_ps(k: __mmask8, a: __m512i) -> __m256;
+        [avx512bw] safe fn _mm512_maskz_cvtepu8_epi16(k: __mmask32, a: __m256i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvtepu8_epi32(k: __mmask16, a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvtepu8_epi64(k: __mmask8, a: __m128i) -> __m512i;
+        [avx512bf16, avx512f] safe fn _mm512_maskz_cvtne2ps_pbh(k: __mmask32, a: __m512, b: __m512) -> __m512bh;
+        [avx512bf16, avx512f] safe fn _mm512_maskz_cvtneps_pbh(k: __mmask16, a: __m512) -> __m256bh;
+        [avx512bf16, avx512f] safe fn _mm512_maskz_cvtpbh_ps(k: __mmask16, a: __m256bh) -> __m512;
+        [avx512f] safe fn _mm512_maskz_cvtpd_epi32(k: __mmask8, a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_maskz_cvtpd_epi64(k: __mmask8, a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvtpd_epu32(k: __mmask8, a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_maskz_cvtpd_epu64(k: __mmask8, a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvtpd_ps(k: __mmask8, a: __m512d) -> __m256;
+        [avx512f] safe fn _mm512_maskz_cvtph_ps(k: __mmask16, a: __m256i) -> __m512;
+        [avx512f] safe fn _mm512_maskz_cvtps_epi32(k: __mmask16, a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_maskz_cvtps_epi64(k: __mmask8, a: __m256) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvtps_epu32(k: __mmask16, a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_maskz_cvtps_epu64(k: __mmask8, a: __m256) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvtps_pd(k: __mmask8, a: __m256) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_cvtps_ph<const ROUNDING: i32>(k: __mmask16, a: __m512) -> __m256i;
+        [avx512bw] safe fn _mm512_maskz_cvtsepi16_epi8(k: __mmask32, a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_maskz_cvtsepi32_epi16(k: __mmask16, a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_maskz_cvtsepi32_epi8(k: __mmask16, a: __m512i) -> __m128i;
+        [avx512f] safe fn _mm512_maskz_cvtsepi64_epi16(k: __mmask8, a: __m512i) -> __m128i;
+        [avx512f] safe fn _mm512_maskz_cvtsepi64_epi32(k: __mmask8, a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_maskz_cvtsepi64_epi8(k: __mmask8, a: __m512i) -> __m128i;
+        [avx512f] safe fn _mm512_maskz_cvtt_roundpd_epi32<const SAE: i32>(k: __mmask8, a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_maskz_cvtt_roundpd_epi64<const SAE: i32>(k: __mmask8, a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvtt_roundpd_epu32<const SAE: i32>(k: __mmask8, a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_maskz_cvtt_roundpd_epu64<const SAE: i32>(k: __mmask8, a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvtt_roundps_epi32<const SAE: i32>(k: __mmask16, a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_maskz_cvtt_roundps_epi64<const SAE: i32>(k: __mmask8, a: __m256) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvtt_roundps_epu32<const SAE: i32>(k: __mmask16, a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_maskz_cvtt_roundps_epu64<const SAE: i32>(k: __mmask8, a: __m256) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvttpd_epi32(k: __mmask8, a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_maskz_cvttpd_epi64(k: __mmask8, a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvttpd_epu32(k: __mmask8, a: __m512d) -> __m256i;
+        [avx512dq] safe fn _mm512_maskz_cvttpd_epu64(k: __mmask8, a: __m512d) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvttps_epi32(k: __mmask16, a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_maskz_cvttps_epi64(k: __mmask8, a: __m256) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_cvttps_epu32(k: __mmask16, a: __m512) -> __m512i;
+        [avx512dq] safe fn _mm512_maskz_cvttps_epu64(k: __mmask8, a: __m256) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_cvtusepi16_epi8(k: __mmask32, a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_maskz_cvtusepi32_epi16(k: __mmask16, a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_maskz_cvtusepi32_epi8(k: __mmask16, a: __m512i) -> __m128i;
+        [avx512f] safe fn _mm512_maskz_cvtusepi64_epi16(k: __mmask8, a: __m512i) -> __m128i;
+        [avx512f] safe fn _mm512_maskz_cvtusepi64_epi32(k: __mmask8, a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_maskz_cvtusepi64_epi8(k: __mmask8, a: __m512i) -> __m128i;
+        [avx512bw] safe fn _mm512_maskz_dbsad_epu8<const IMM8: i32>(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_div_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_div_ps(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_div_round_pd<const ROUNDING: i32>(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_div_round_ps<const ROUNDING: i32>(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bf16, avx512f] safe fn _mm512_maskz_dpbf16_ps(k: __mmask16, src: __m512, a: __m512bh, b: __m512bh) -> __m512;
+        [avx512vnni] safe fn _mm512_maskz_dpbusd_epi32(k: __mmask16, src: __m512i, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vnni] safe fn _mm512_maskz_dpbusds_epi32(k: __mmask16, src: __m512i, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vnni] safe fn _mm512_maskz_dpwssd_epi32(k: __mmask16, src: __m512i, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vnni] safe fn _mm512_maskz_dpwssds_epi32(k: __mmask16, src: __m512i, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_maskz_expand_epi16(k: __mmask32, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_expand_epi32(k: __mmask16, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_expand_epi64(k: __mmask8, a: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_maskz_expand_epi8(k: __mmask64, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_expand_pd(k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_expand_ps(k: __mmask16, a: __m512) -> __m512;
+        [avx512vbmi2] unsafe fn _mm512_maskz_expandloadu_epi16(k: __mmask32, mem_addr: *const i16) -> __m512i;
+        [avx512f] unsafe fn _mm512_maskz_expandloadu_epi32(k: __mmask16, mem_addr: *const i32) -> __m512i;
+        [avx512f] unsafe fn _mm512_maskz_expandloadu_epi64(k: __mmask8, mem_addr: *const i64) -> __m512i;
+        [avx512vbmi2] unsafe fn _mm512_maskz_expandloadu_epi8(k: __mmask64, mem_addr: *const i8) -> __m512i;
+        [avx512f] unsafe fn _mm512_maskz_expandloadu_pd(k: __mmask8, mem_addr: *const f64) -> __m512d;
+        [avx512f] unsafe fn _mm512_maskz_expandloadu_ps(k: __mmask16, mem_addr: *const f32) -> __m512;
+        [avx512f] safe fn _mm512_maskz_extractf32x4_ps<const IMM8: i32>(k: __mmask8, a: __m512) -> __m128;
+        [avx512dq] safe fn _mm512_maskz_extractf32x8_ps<const IMM8: i32>(k: __mmask8, a: __m512) -> __m256;
+        [avx512dq] safe fn _mm512_maskz_extractf64x2_pd<const IMM8: i32>(k: __mmask8, a: __m512d) -> __m128d;
+        [avx512f] safe fn _mm512_maskz_extractf64x4_pd<const IMM8: i32>(k: __mmask8, a: __m512d) -> __m256d;
+        [avx512f] safe fn _mm512_maskz_extracti32x4_epi32<const IMM2: i32>(k: __mmask8, a: __m512i) -> __m128i;
+        [avx512dq] safe fn _mm512_maskz_extracti32x8_epi32<const IMM8: i32>(k: __mmask8, a: __m512i) -> __m256i;
+        [avx512dq] safe fn _mm512_maskz_extracti64x2_epi64<const IMM8: i32>(k: __mmask8, a: __m512i) -> __m128i;
+        [avx512f] safe fn _mm512_maskz_extracti64x4_epi64<const IMM1: i32>(k: __mmask8, a: __m512i) -> __m256i;
+        [avx512f] safe fn _mm512_maskz_fixupimm_pd<const IMM8: i32>(k: __mmask8, a: __m512d, b: __m512d, c: __m512i) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_fixupimm_ps<const IMM8: i32>(k: __mmask16, a: __m512, b: __m512, c: __m512i) -> __m512;
+        [avx512f] safe fn _mm512_maskz_fixupimm_round_pd<const IMM8: i32, const SAE: i32>(k: __mmask8, a: __m512d, b: __m512d, c: __m512i) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_fixupimm_round_ps<const IMM8: i32, const SAE: i32>(k: __mmask16, a: __m512, b: __m512, c: __m512i) -> __m512;
+        [avx512f] safe fn _mm512_maskz_fmadd_pd(k: __mmask8, a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_fmadd_ps(k: __mmask16, a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_fmadd_round_pd<const ROUNDING: i32>(k: __mmask8, a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_fmadd_round_ps<const ROUNDING: i32>(k: __mmask16, a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_fmaddsub_pd(k: __mmask8, a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_fmaddsub_ps(k: __mmask16, a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_fmaddsub_round_pd<const ROUNDING: i32>(k: __mmask8, a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_fmaddsub_round_ps<const ROUNDING: i32>(k: __mmask16, a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_fmsub_pd(k: __mmask8, a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_fmsub_ps(k: __mmask16, a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_fmsub_round_pd<const ROUNDING: i32>(k: __mmask8, a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_fmsub_round_ps<const ROUNDING: i32>(k: __mmask16, a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_fmsubadd_pd(k: __mmask8, a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_fmsubadd_ps(k: __mmask16, a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_fmsubadd_round_pd<const ROUNDING: i32>(k: __mmask8, a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_fmsubadd_round_ps<const ROUNDING: i32>(k: __mmask16, a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_fnmadd_pd(k: __mmask8, a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_fnmadd_ps(k: __mmask16, a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_fnmadd_round_pd<const ROUNDING: i32>(k: __mmask8, a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_fnmadd_round_ps<const ROUNDING: i32>(k: __mmask16, a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_fnmsub_pd(k: __mmask8, a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_fnmsub_ps(k: __mmask16, a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_fnmsub_round_pd<const ROUNDING: i32>(k: __mmask8, a: __m512d, b: __m512d, c: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_fnmsub_round_ps<const ROUNDING: i32>(k: __mmask16, a: __m512, b: __m512, c: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_getexp_pd(k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_getexp_ps(k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_getexp_round_pd<const SAE: i32>(k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_getexp_round_ps<const SAE: i32>(k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_getmant_pd<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_getmant_ps<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_getmant_round_pd<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM, const SAE: i32>(k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_getmant_round_ps<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM, const SAE: i32>(k: __mmask16, a: __m512) -> __m512;
+        [gfni, avx512bw, avx512f] safe fn _mm512_maskz_gf2p8affine_epi64_epi8<const B: i32>(k: __mmask64, x: __m512i, a: __m512i) -> __m512i;
+        [gfni, avx512bw, avx512f] safe fn _mm512_maskz_gf2p8affineinv_epi64_epi8<const B: i32>(k: __mmask64, x: __m512i, a: __m512i) -> __m512i;
+        [gfni, avx512bw, avx512f] safe fn _mm512_maskz_gf2p8mul_epi8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_insertf32x4<const IMM8: i32>(k: __mmask16, a: __m512, b: __m128) -> __m512;
+        [avx512dq] safe fn _mm512_maskz_insertf32x8<const IMM8: i32>(k: __mmask16, a: __m512, b: __m256) -> __m512;
+        [avx512dq] safe fn _mm512_maskz_insertf64x2<const IMM8: i32>(k: __mmask8, a: __m512d, b: __m128d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_insertf64x4<const IMM8: i32>(k: __mmask8, a: __m512d, b: __m256d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_inserti32x4<const IMM8: i32>(k: __mmask16, a: __m512i, b: __m128i) -> __m512i;
+        [avx512dq] safe fn _mm512_maskz_inserti32x8<const IMM8: i32>(k: __mmask16, a: __m512i, b: __m256i) -> __m512i;
+        [avx512dq] safe fn _mm512_maskz_inserti64x2<const IMM8: i32>(k: __mmask8, a: __m512i, b: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_inserti64x4<const IMM8: i32>(k: __mmask8, a: __m512i, b: __m256i) -> __m512i;
+        [avx512f] unsafe fn _mm512_maskz_load_epi32(k: __mmask16, mem_addr: *const i32) -> __m512i;
+        [avx512f] unsafe fn _mm512_maskz_load_epi64(k: __mmask8, mem_addr: *const i64) -> __m512i;
+        [avx512f] unsafe fn _mm512_maskz_load_pd(k: __mmask8, mem_addr: *const f64) -> __m512d;
+        [avx512f] unsafe fn _mm512_maskz_load_ps(k: __mmask16, mem_addr: *const f32) -> __m512;
+        [avx512bw] unsafe fn _mm512_maskz_loadu_epi16(k: __mmask32, mem_addr: *const i16) -> __m512i;
+        [avx512f] unsafe fn _mm512_maskz_loadu_epi32(k: __mmask16, mem_addr: *const i32) -> __m512i;
+        [avx512f] unsafe fn _mm512_maskz_loadu_epi64(k: __mmask8, mem_addr: *const i64) -> __m512i;
+        [avx512bw] unsafe fn _mm512_maskz_loadu_epi8(k: __mmask64, mem_addr: *const i8) -> __m512i;
+        [avx512f] unsafe fn _mm512_maskz_loadu_pd(k: __mmask8, mem_addr: *const f64) -> __m512d;
+        [avx512f] unsafe fn _mm512_maskz_loadu_ps(k: __mmask16, mem_addr: *const f32) -> __m512;
+        [avx512cd] safe fn _mm512_maskz_lzcnt_epi32(k: __mmask16, a: __m512i) -> __m512i;
+        [avx512cd] safe fn _mm512_maskz_lzcnt_epi64(k: __mmask8, a: __m512i) -> __m512i;
+        [avx512ifma] safe fn _mm512_maskz_madd52hi_epu64(k: __mmask8, a: __m512i, b: __m512i, c: __m512i) -> __m512i;
+        [avx512ifma] safe fn _mm512_maskz_madd52lo_epu64(k: __mmask8, a: __m512i, b: __m512i, c: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_madd_epi16(k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_maddubs_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_max_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_max_epi32(k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_max_epi64(k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_max_epi8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_max_epu16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_max_epu32(k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_max_epu64(k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_max_epu8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_max_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_max_ps(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_max_round_pd<const SAE: i32>(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_max_round_ps<const SAE: i32>(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_maskz_min_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_min_epi32(k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_min_epi64(k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_min_epi8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_min_epu16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_min_epu32(k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_min_epu64(k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_min_epu8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_min_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_min_ps(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_min_round_pd<const SAE: i32>(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_min_round_ps<const SAE: i32>(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_maskz_mov_epi16(k: __mmask32, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_mov_epi32(k: __mmask16, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_mov_epi64(k: __mmask8, a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_mov_epi8(k: __mmask64, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_mov_pd(k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_mov_ps(k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_movedup_pd(k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_movehdup_ps(k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_moveldup_ps(k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_mul_epi32(k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_mul_epu32(k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_mul_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_mul_ps(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_mul_round_pd<const ROUNDING: i32>(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_mul_round_ps<const ROUNDING: i32>(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_maskz_mulhi_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_mulhi_epu16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_mulhrs_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_mullo_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_mullo_epi32(k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512dq] safe fn _mm512_maskz_mullo_epi64(k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi] safe fn _mm512_maskz_multishift_epi64_epi8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_or_epi32(k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_or_epi64(k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512dq] safe fn _mm512_maskz_or_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_maskz_or_ps(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_maskz_packs_epi16(k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_packs_epi32(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_packus_epi16(k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_packus_epi32(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_permute_pd<const MASK: i32>(k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_permute_ps<const MASK: i32>(k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_permutevar_pd(k: __mmask8, a: __m512d, b: __m512i) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_permutevar_ps(k: __mmask16, a: __m512, b: __m512i) -> __m512;
+        [avx512bw] safe fn _mm512_maskz_permutex2var_epi16(k: __mmask32, a: __m512i, idx: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_permutex2var_epi32(k: __mmask16, a: __m512i, idx: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_permutex2var_epi64(k: __mmask8, a: __m512i, idx: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi] safe fn _mm512_maskz_permutex2var_epi8(k: __mmask64, a: __m512i, idx: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_permutex2var_pd(k: __mmask8, a: __m512d, idx: __m512i, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_permutex2var_ps(k: __mmask16, a: __m512, idx: __m512i, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_permutex_epi64<const MASK: i32>(k: __mmask8, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_permutex_pd<const MASK: i32>(k: __mmask8, a: __m512d) -> __m512d;
+        [avx512bw] safe fn _mm512_maskz_permutexvar_epi16(k: __mmask32, idx: __m512i, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_permutexvar_epi32(k: __mmask16, idx: __m512i, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_permutexvar_epi64(k: __mmask8, idx: __m512i, a: __m512i) -> __m512i;
+        [avx512vbmi] safe fn _mm512_maskz_permutexvar_epi8(k: __mmask64, idx: __m512i, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_permutexvar_pd(k: __mmask8, idx: __m512i, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_permutexvar_ps(k: __mmask16, idx: __m512i, a: __m512) -> __m512;
+        [avx512bitalg] safe fn _mm512_maskz_popcnt_epi16(k: __mmask32, a: __m512i) -> __m512i;
+        [avx512vpopcntdq] safe fn _mm512_maskz_popcnt_epi32(k: __mmask16, a: __m512i) -> __m512i;
+        [avx512vpopcntdq] safe fn _mm512_maskz_popcnt_epi64(k: __mmask8, a: __m512i) -> __m512i;
+        [avx512bitalg] safe fn _mm512_maskz_popcnt_epi8(k: __mmask64, a: __m512i) -> __m512i;
+        [avx512dq] safe fn _mm512_maskz_range_pd<const IMM8: i32>(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_maskz_range_ps<const IMM8: i32>(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512dq] safe fn _mm512_maskz_range_round_pd<const IMM8: i32, const SAE: i32>(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_maskz_range_round_ps<const IMM8: i32, const SAE: i32>(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_rcp14_pd(k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_rcp14_ps(k: __mmask16, a: __m512) -> __m512;
+        [avx512dq] safe fn _mm512_maskz_reduce_pd<const IMM8: i32>(k: __mmask8, a: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_maskz_reduce_ps<const IMM8: i32>(k: __mmask16, a: __m512) -> __m512;
+        [avx512dq] safe fn _mm512_maskz_reduce_round_pd<const IMM8: i32, const SAE: i32>(k: __mmask8, a: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_maskz_reduce_round_ps<const IMM8: i32, const SAE: i32>(k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_rol_epi32<const IMM8: i32>(k: __mmask16, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_rol_epi64<const IMM8: i32>(k: __mmask8, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_rolv_epi32(k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_rolv_epi64(k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_ror_epi32<const IMM8: i32>(k: __mmask16, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_ror_epi64<const IMM8: i32>(k: __mmask8, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_rorv_epi32(k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_rorv_epi64(k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_roundscale_pd<const IMM8: i32>(k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_roundscale_ps<const IMM8: i32>(k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_roundscale_round_pd<const IMM8: i32, const SAE: i32>(k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_roundscale_round_ps<const IMM8: i32, const SAE: i32>(k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_rsqrt14_pd(k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_rsqrt14_ps(k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_scalef_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_scalef_ps(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_scalef_round_pd<const ROUNDING: i32>(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_scalef_round_ps<const ROUNDING: i32>(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_maskz_set1_epi16(k: __mmask32, a: i16) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_set1_epi32(k: __mmask16, a: i32) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_set1_epi64(k: __mmask8, a: i64) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_set1_epi8(k: __mmask64, a: i8) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_maskz_shldi_epi16<const IMM8: i32>(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_maskz_shldi_epi32<const IMM8: i32>(k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_maskz_shldi_epi64<const IMM8: i32>(k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_maskz_shldv_epi16(k: __mmask32, a: __m512i, b: __m512i, c: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_maskz_shldv_epi32(k: __mmask16, a: __m512i, b: __m512i, c: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_maskz_shldv_epi64(k: __mmask8, a: __m512i, b: __m512i, c: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_maskz_shrdi_epi16<const IMM8: i32>(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_maskz_shrdi_epi32<const IMM8: i32>(k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_maskz_shrdi_epi64<const IMM8: i32>(k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_maskz_shrdv_epi16(k: __mmask32, a: __m512i, b: __m512i, c: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_maskz_shrdv_epi32(k: __mmask16, a: __m512i, b: __m512i, c: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_maskz_shrdv_epi64(k: __mmask8, a: __m512i, b: __m512i, c: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_shuffle_epi32<const MASK: _MM_PERM_ENUM>(k: __mmask16, a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_shuffle_epi8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_shuffle_f32x4<const MASK: i32>(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_shuffle_f64x2<const MASK: i32>(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_shuffle_i32x4<const MASK: i32>(k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_shuffle_i64x2<const MASK: i32>(k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_shuffle_pd<const MASK: i32>(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_shuffle_ps<const MASK: i32>(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_maskz_shufflehi_epi16<const IMM8: i32>(k: __mmask32, a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_shufflelo_epi16<const IMM8: i32>(k: __mmask32, a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_sll_epi16(k: __mmask32, a: __m512i, count: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_sll_epi32(k: __mmask16, a: __m512i, count: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_sll_epi64(k: __mmask8, a: __m512i, count: __m128i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_slli_epi16<const IMM8: u32>(k: __mmask32, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_slli_epi32<const IMM8: u32>(k: __mmask16, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_slli_epi64<const IMM8: u32>(k: __mmask8, a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_sllv_epi16(k: __mmask32, a: __m512i, count: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_sllv_epi32(k: __mmask16, a: __m512i, count: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_sllv_epi64(k: __mmask8, a: __m512i, count: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_sqrt_pd(k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_sqrt_ps(k: __mmask16, a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_sqrt_round_pd<const ROUNDING: i32>(k: __mmask8, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_sqrt_round_ps<const ROUNDING: i32>(k: __mmask16, a: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_maskz_sra_epi16(k: __mmask32, a: __m512i, count: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_sra_epi32(k: __mmask16, a: __m512i, count: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_sra_epi64(k: __mmask8, a: __m512i, count: __m128i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_srai_epi16<const IMM8: u32>(k: __mmask32, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_srai_epi32<const IMM8: u32>(k: __mmask16, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_srai_epi64<const IMM8: u32>(k: __mmask8, a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_srav_epi16(k: __mmask32, a: __m512i, count: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_srav_epi32(k: __mmask16, a: __m512i, count: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_srav_epi64(k: __mmask8, a: __m512i, count: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_srl_epi16(k: __mmask32, a: __m512i, count: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_srl_epi32(k: __mmask16, a: __m512i, count: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_srl_epi64(k: __mmask8, a: __m512i, count: __m128i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_srli_epi16<const IMM8: i32>(k: __mmask32, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_srli_epi32<const IMM8: u32>(k: __mmask16, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_srli_epi64<const IMM8: u32>(k: __mmask8, a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_srlv_epi16(k: __mmask32, a: __m512i, count: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_srlv_epi32(k: __mmask16, a: __m512i, count: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_srlv_epi64(k: __mmask8, a: __m512i, count: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_sub_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_sub_epi32(k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_sub_epi64(k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_sub_epi8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_sub_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_sub_ps(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_sub_round_pd<const ROUNDING: i32>(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_sub_round_ps<const ROUNDING: i32>(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_maskz_subs_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_subs_epi8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_subs_epu16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_subs_epu8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_ternarylogic_epi32<const IMM8: i32>(k: __mmask16, a: __m512i, b: __m512i, c: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_ternarylogic_epi64<const IMM8: i32>(k: __mmask8, a: __m512i, b: __m512i, c: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_unpackhi_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_unpackhi_epi32(k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_unpackhi_epi64(k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_unpackhi_epi8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_unpackhi_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_unpackhi_ps(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_maskz_unpacklo_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_unpacklo_epi32(k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_unpacklo_epi64(k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_maskz_unpacklo_epi8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_unpacklo_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_maskz_unpacklo_ps(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_maskz_xor_epi32(k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_maskz_xor_epi64(k: __mmask8, a: __m512i, b: __m512i) -> __m512i;
+        [avx512dq] safe fn _mm512_maskz_xor_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_maskz_xor_ps(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_max_epi16(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_max_epi32(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_max_epi64(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_max_epi8(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_max_epu16(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_max_epu32(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_max_epu64(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_max_epu8(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_max_pd(a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_max_ps(a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_max_round_pd<const SAE: i32>(a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_max_round_ps<const SAE: i32>(a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_min_epi16(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_min_epi32(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_min_epi64(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_min_epi8(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_min_epu16(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_min_epu32(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_min_epu64(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_min_epu8(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_min_pd(a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_min_ps(a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_min_round_pd<const SAE: i32>(a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_min_round_ps<const SAE: i32>(a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_movedup_pd(a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_movehdup_ps(a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_moveldup_ps(a: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_movepi16_mask(a: __m512i) -> __mmask32;
+        [avx512dq] safe fn _mm512_movepi32_mask(a: __m512i) -> __mmask16;
+        [avx512dq] safe fn _mm512_movepi64_mask(a: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_movepi8_mask(a: __m512i) -> __mmask64;
+        [avx512bw] safe fn _mm512_movm_epi16(k: __mmask32) -> __m512i;
+        [avx512dq] safe fn _mm512_movm_epi32(k: __mmask16) -> __m512i;
+        [avx512dq] safe fn _mm512_movm_epi64(k: __mmask8) -> __m512i;
+        [avx512bw] safe fn _mm512_movm_epi8(k: __mmask64) -> __m512i;
+        [avx512f] safe fn _mm512_mul_epi32(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mul_epu32(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mul_pd(a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mul_ps(a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_mul_round_pd<const ROUNDING: i32>(a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_mul_round_ps<const ROUNDING: i32>(a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_mulhi_epi16(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mulhi_epu16(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mulhrs_epi16(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_mullo_epi16(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mullo_epi32(a: __m512i, b: __m512i) -> __m512i;
+        [avx512dq] safe fn _mm512_mullo_epi64(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_mullox_epi64(a: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi] safe fn _mm512_multishift_epi64_epi8(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_or_epi32(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_or_epi64(a: __m512i, b: __m512i) -> __m512i;
+        [avx512dq] safe fn _mm512_or_pd(a: __m512d, b: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_or_ps(a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_or_si512(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_packs_epi16(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_packs_epi32(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_packus_epi16(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_packus_epi32(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_permute_pd<const MASK: i32>(a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_permute_ps<const MASK: i32>(a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_permutevar_epi32(idx: __m512i, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_permutevar_pd(a: __m512d, b: __m512i) -> __m512d;
+        [avx512f] safe fn _mm512_permutevar_ps(a: __m512, b: __m512i) -> __m512;
+        [avx512bw] safe fn _mm512_permutex2var_epi16(a: __m512i, idx: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_permutex2var_epi32(a: __m512i, idx: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_permutex2var_epi64(a: __m512i, idx: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi] safe fn _mm512_permutex2var_epi8(a: __m512i, idx: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_permutex2var_pd(a: __m512d, idx: __m512i, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_permutex2var_ps(a: __m512, idx: __m512i, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_permutex_epi64<const MASK: i32>(a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_permutex_pd<const MASK: i32>(a: __m512d) -> __m512d;
+        [avx512bw] safe fn _mm512_permutexvar_epi16(idx: __m512i, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_permutexvar_epi32(idx: __m512i, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_permutexvar_epi64(idx: __m512i, a: __m512i) -> __m512i;
+        [avx512vbmi] safe fn _mm512_permutexvar_epi8(idx: __m512i, a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_permutexvar_pd(idx: __m512i, a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_permutexvar_ps(idx: __m512i, a: __m512) -> __m512;
+        [avx512bitalg] safe fn _mm512_popcnt_epi16(a: __m512i) -> __m512i;
+        [avx512vpopcntdq] safe fn _mm512_popcnt_epi32(a: __m512i) -> __m512i;
+        [avx512vpopcntdq] safe fn _mm512_popcnt_epi64(a: __m512i) -> __m512i;
+        [avx512bitalg] safe fn _mm512_popcnt_epi8(a: __m512i) -> __m512i;
+        [avx512dq] safe fn _mm512_range_pd<const IMM8: i32>(a: __m512d, b: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_range_ps<const IMM8: i32>(a: __m512, b: __m512) -> __m512;
+        [avx512dq] safe fn _mm512_range_round_pd<const IMM8: i32, const SAE: i32>(a: __m512d, b: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_range_round_ps<const IMM8: i32, const SAE: i32>(a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_rcp14_pd(a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_rcp14_ps(a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_reduce_add_epi32(a: __m512i) -> i32;
+        [avx512f] safe fn _mm512_reduce_add_epi64(a: __m512i) -> i64;
+        [avx512f] safe fn _mm512_reduce_add_pd(a: __m512d) -> f64;
+        [avx512f] safe fn _mm512_reduce_add_ps(a: __m512) -> f32;
+        [avx512f] safe fn _mm512_reduce_and_epi32(a: __m512i) -> i32;
+        [avx512f] safe fn _mm512_reduce_and_epi64(a: __m512i) -> i64;
+        [avx512f] safe fn _mm512_reduce_max_epi32(a: __m512i) -> i32;
+        [avx512f] safe fn _mm512_reduce_max_epi64(a: __m512i) -> i64;
+        [avx512f] safe fn _mm512_reduce_max_epu32(a: __m512i) -> u32;
+        [avx512f] safe fn _mm512_reduce_max_epu64(a: __m512i) -> u64;
+        [avx512f] safe fn _mm512_reduce_max_pd(a: __m512d) -> f64;
+        [avx512f] safe fn _mm512_reduce_max_ps(a: __m512) -> f32;
+        [avx512f] safe fn _mm512_reduce_min_epi32(a: __m512i) -> i32;
+        [avx512f] safe fn _mm512_reduce_min_epi64(a: __m512i) -> i64;
+        [avx512f] safe fn _mm512_reduce_min_epu32(a: __m512i) -> u32;
+        [avx512f] safe fn _mm512_reduce_min_epu64(a: __m512i) -> u64;
+        [avx512f] safe fn _mm512_reduce_min_pd(a: __m512d) -> f64;
+        [avx512f] safe fn _mm512_reduce_min_ps(a: __m512) -> f32;
+        [avx512f] safe fn _mm512_reduce_mul_epi32(a: __m512i) -> i32;
+        [avx512f] safe fn _mm512_reduce_mul_epi64(a: __m512i) -> i64;
+        [avx512f] safe fn _mm512_reduce_mul_pd(a: __m512d) -> f64;
+        [avx512f] safe fn _mm512_reduce_mul_ps(a: __m512) -> f32;
+        [avx512f] safe fn _mm512_reduce_or_epi32(a: __m512i) -> i32;
+        [avx512f] safe fn _mm512_reduce_or_epi64(a: __m512i) -> i64;
+        [avx512dq] safe fn _mm512_reduce_pd<const IMM8: i32>(a: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_reduce_ps<const IMM8: i32>(a: __m512) -> __m512;
+        [avx512dq] safe fn _mm512_reduce_round_pd<const IMM8: i32, const SAE: i32>(a: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_reduce_round_ps<const IMM8: i32, const SAE: i32>(a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_rol_epi32<const IMM8: i32>(a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_rol_epi64<const IMM8: i32>(a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_rolv_epi32(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_rolv_epi64(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_ror_epi32<const IMM8: i32>(a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_ror_epi64<const IMM8: i32>(a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_rorv_epi32(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_rorv_epi64(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_roundscale_pd<const IMM8: i32>(a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_roundscale_ps<const IMM8: i32>(a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_roundscale_round_pd<const IMM8: i32, const SAE: i32>(a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_roundscale_round_ps<const IMM8: i32, const SAE: i32>(a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_rsqrt14_pd(a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_rsqrt14_ps(a: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_sad_epu8(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_scalef_pd(a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_scalef_ps(a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_scalef_round_pd<const ROUNDING: i32>(a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_scalef_round_ps<const ROUNDING: i32>(a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_set1_epi16(a: i16) -> __m512i;
+        [avx512f] safe fn _mm512_set1_epi32(a: i32) -> __m512i;
+        [avx512f] safe fn _mm512_set1_epi64(a: i64) -> __m512i;
+        [avx512f] safe fn _mm512_set1_epi8(a: i8) -> __m512i;
+        [avx512f] safe fn _mm512_set1_pd(a: f64) -> __m512d;
+        [avx512f] safe fn _mm512_set1_ps(a: f32) -> __m512;
+        [avx512f] safe fn _mm512_set4_epi32(d: i32, c: i32, b: i32, a: i32) -> __m512i;
+        [avx512f] safe fn _mm512_set4_epi64(d: i64, c: i64, b: i64, a: i64) -> __m512i;
+        [avx512f] safe fn _mm512_set4_pd(d: f64, c: f64, b: f64, a: f64) -> __m512d;
+        [avx512f] safe fn _mm512_set4_ps(d: f32, c: f32, b: f32, a: f32) -> __m512;
+        [avx512f] safe fn _mm512_set_epi16(e31: i16, e30: i16, e29: i16, e28: i16, e27: i16, e26: i16, e25: i16, e24: i16, e23: i16, e22: i16, e21: i16, e20: i16, e19: i16, e18: i16, e17: i16, e16: i16, e15: i16, e14: i16, e13: i16, e12: i16, e11: i16, e10: i16, e9: i16, e8: i16, e7: i16, e6: i16, e5: i16, e4: i16, e3: i16, e2: i16, e1: i16, e0: i16) -> __m512i;
+        [avx512f] safe fn _mm512_set_epi32(e15: i32, e14: i32, e13: i32, e12: i32, e11: i32, e10: i32, e9: i32, e8: i32, e7: i32, e6: i32, e5: i32, e4: i32, e3: i32, e2: i32, e1: i32, e0: i32) -> __m512i;
+        [avx512f] safe fn _mm512_set_epi64(e0: i64, e1: i64, e2: i64, e3: i64, e4: i64, e5: i64, e6: i64, e7: i64) -> __m512i;
+        [avx512f] safe fn _mm512_set_epi8(e63: i8, e62: i8, e61: i8, e60: i8, e59: i8, e58: i8, e57: i8, e56: i8, e55: i8, e54: i8, e53: i8, e52: i8, e51: i8, e50: i8, e49: i8, e48: i8, e47: i8, e46: i8, e45: i8, e44: i8, e43: i8, e42: i8, e41: i8, e40: i8, e39: i8, e38: i8, e37: i8, e36: i8, e35: i8, e34: i8, e33: i8, e32: i8, e31: i8, e30: i8, e29: i8, e28: i8, e27: i8, e26: i8, e25: i8, e24: i8, e23: i8, e22: i8, e21: i8, e20: i8, e19: i8, e18: i8, e17: i8, e16: i8, e15: i8, e14: i8, e13: i8, e12: i8, e11: i8, e10: i8, e9: i8, e8: i8, e7: i8, e6: i8, e5: i8, e4: i8, e3: i8, e2: i8, e1: i8, e0: i8) -> __m512i;
+        [avx512f] safe fn _mm512_set_pd(e0: f64, e1: f64, e2: f64, e3: f64, e4: f64, e5: f64, e6: f64, e7: f64) -> __m512d;
+        [avx512f] safe fn _mm512_set_ps(e0: f32, e1: f32, e2: f32, e3: f32, e4: f32, e5: f32, e6: f32, e7: f32, e8: f32, e9: f32, e10: f32, e11: f32, e12: f32, e13: f32, e14: f32, e15: f32) -> __m512;
+        [avx512f] safe fn _mm512_setr4_epi32(d: i32, c: i32, b: i32, a: i32) -> __m512i;
+        [avx512f] safe fn _mm512_setr4_epi64(d: i64, c: i64, b: i64, a: i64) -> __m512i;
+        [avx512f] safe fn _mm512_setr4_pd(d: f64, c: f64, b: f64, a: f64) -> __m512d;
+        [avx512f] safe fn _mm512_setr4_ps(d: f32, c: f32, b: f32, a: f32) -> __m512;
+        [avx512f] safe fn _mm512_setr_epi32(e15: i32, e14: i32, e13: i32, e12: i32, e11: i32, e10: i32, e9: i32, e8: i32, e7: i32, e6: i32, e5: i32, e4: i32, e3: i32, e2: i32, e1: i32, e0: i32) -> __m512i;
+        [avx512f] safe fn _mm512_setr_epi64(e0: i64, e1: i64, e2: i64, e3: i64, e4: i64, e5: i64, e6: i64, e7: i64) -> __m512i;
+        [avx512f] safe fn _mm512_setr_pd(e0: f64, e1: f64, e2: f64, e3: f64, e4: f64, e5: f64, e6: f64, e7: f64) -> __m512d;
+        [avx512f] safe fn _mm512_setr_ps(e0: f32, e1: f32, e2: f32, e3: f32, e4: f32, e5: f32, e6: f32, e7: f32, e8: f32, e9: f32, e10: f32, e11: f32, e12: f32, e13: f32, e14: f32, e15: f32) -> __m512;
+        [avx512f] safe fn _mm512_setzero() -> __m512;
+        [avx512f] safe fn _mm512_setzero_epi32() -> __m512i;
+        [avx512f] safe fn _mm512_setzero_pd() -> __m512d;
+        [avx512f] safe fn _mm512_setzero_ps() -> __m512;
+        [avx512f] safe fn _mm512_setzero_si512() -> __m512i;
+        [avx512vbmi2] safe fn _mm512_shldi_epi16<const IMM8: i32>(a: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_shldi_epi32<const IMM8: i32>(a: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_shldi_epi64<const IMM8: i32>(a: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_shldv_epi16(a: __m512i, b: __m512i, c: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_shldv_epi32(a: __m512i, b: __m512i, c: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_shldv_epi64(a: __m512i, b: __m512i, c: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_shrdi_epi16<const IMM8: i32>(a: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_shrdi_epi32<const IMM8: i32>(a: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_shrdi_epi64<const IMM8: i32>(a: __m512i, b: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_shrdv_epi16(a: __m512i, b: __m512i, c: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_shrdv_epi32(a: __m512i, b: __m512i, c: __m512i) -> __m512i;
+        [avx512vbmi2] safe fn _mm512_shrdv_epi64(a: __m512i, b: __m512i, c: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_shuffle_epi32<const MASK: _MM_PERM_ENUM>(a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_shuffle_epi8(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_shuffle_f32x4<const MASK: i32>(a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_shuffle_f64x2<const MASK: i32>(a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_shuffle_i32x4<const MASK: i32>(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_shuffle_i64x2<const MASK: i32>(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_shuffle_pd<const MASK: i32>(a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_shuffle_ps<const MASK: i32>(a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_shufflehi_epi16<const IMM8: i32>(a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_shufflelo_epi16<const IMM8: i32>(a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_sll_epi16(a: __m512i, count: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_sll_epi32(a: __m512i, count: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_sll_epi64(a: __m512i, count: __m128i) -> __m512i;
+        [avx512bw] safe fn _mm512_slli_epi16<const IMM8: u32>(a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_slli_epi32<const IMM8: u32>(a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_slli_epi64<const IMM8: u32>(a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_sllv_epi16(a: __m512i, count: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_sllv_epi32(a: __m512i, count: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_sllv_epi64(a: __m512i, count: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_sqrt_pd(a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_sqrt_ps(a: __m512) -> __m512;
+        [avx512f] safe fn _mm512_sqrt_round_pd<const ROUNDING: i32>(a: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_sqrt_round_ps<const ROUNDING: i32>(a: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_sra_epi16(a: __m512i, count: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_sra_epi32(a: __m512i, count: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_sra_epi64(a: __m512i, count: __m128i) -> __m512i;
+        [avx512bw] safe fn _mm512_srai_epi16<const IMM8: u32>(a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_srai_epi32<const IMM8: u32>(a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_srai_epi64<const IMM8: u32>(a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_srav_epi16(a: __m512i, count: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_srav_epi32(a: __m512i, count: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_srav_epi64(a: __m512i, count: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_srl_epi16(a: __m512i, count: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_srl_epi32(a: __m512i, count: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_srl_epi64(a: __m512i, count: __m128i) -> __m512i;
+        [avx512bw] safe fn _mm512_srli_epi16<const IMM8: u32>(a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_srli_epi32<const IMM8: u32>(a: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_srli_epi64<const IMM8: u32>(a: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_srlv_epi16(a: __m512i, count: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_srlv_epi32(a: __m512i, count: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_srlv_epi64(a: __m512i, count: __m512i) -> __m512i;
+        [avx512f] unsafe fn _mm512_store_epi32(mem_addr: *mut i32, a: __m512i);
+        [avx512f] unsafe fn _mm512_store_epi64(mem_addr: *mut i64, a: __m512i);
+        [avx512f] unsafe fn _mm512_store_pd(mem_addr: *mut f64, a: __m512d);
+        [avx512f] unsafe fn _mm512_store_ps(mem_addr: *mut f32, a: __m512);
+        [avx512f] unsafe fn _mm512_store_si512(mem_addr: *mut __m512i, a: __m512i);
+        [avx512bw] unsafe fn _mm512_storeu_epi16(mem_addr: *mut i16, a: __m512i);
+        [avx512f] unsafe fn _mm512_storeu_epi32(mem_addr: *mut i32, a: __m512i);
+        [avx512f] unsafe fn _mm512_storeu_epi64(mem_addr: *mut i64, a: __m512i);
+        [avx512bw] unsafe fn _mm512_storeu_epi8(mem_addr: *mut i8, a: __m512i);
+        [avx512f] unsafe fn _mm512_storeu_pd(mem_addr: *mut f64, a: __m512d);
+        [avx512f] unsafe fn _mm512_storeu_ps(mem_addr: *mut f32, a: __m512);
+        [avx512f] unsafe fn _mm512_storeu_si512(mem_addr: *mut __m512i, a: __m512i);
+        [avx512f] unsafe fn _mm512_stream_load_si512(mem_addr: *const __m512i) -> __m512i;
+        [avx512f] unsafe fn _mm512_stream_pd(mem_addr: *mut f64, a: __m512d);
+        [avx512f] unsafe fn _mm512_stream_ps(mem_addr: *mut f32, a: __m512);
+        [avx512f] unsafe fn _mm512_stream_si512(mem_addr: *mut __m512i, a: __m512i);
+        [avx512bw] safe fn _mm512_sub_epi16(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_sub_epi32(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_sub_epi64(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_sub_epi8(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_sub_pd(a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_sub_ps(a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_sub_round_pd<const ROUNDING: i32>(a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_sub_round_ps<const ROUNDING: i32>(a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_subs_epi16(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_subs_epi8(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_subs_epu16(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_subs_epu8(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_ternarylogic_epi32<const IMM8: i32>(a: __m512i, b: __m512i, c: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_ternarylogic_epi64<const IMM8: i32>(a: __m512i, b: __m512i, c: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_test_epi16_mask(a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_test_epi32_mask(a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_test_epi64_mask(a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_test_epi8_mask(a: __m512i, b: __m512i) -> __mmask64;
+        [avx512bw] safe fn _mm512_testn_epi16_mask(a: __m512i, b: __m512i) -> __mmask32;
+        [avx512f] safe fn _mm512_testn_epi32_mask(a: __m512i, b: __m512i) -> __mmask16;
+        [avx512f] safe fn _mm512_testn_epi64_mask(a: __m512i, b: __m512i) -> __mmask8;
+        [avx512bw] safe fn _mm512_testn_epi8_mask(a: __m512i, b: __m512i) -> __mmask64;
+        [avx512f] safe fn _mm512_undefined() -> __m512;
+        [avx512f] safe fn _mm512_undefined_epi32() -> __m512i;
+        [avx512f] safe fn _mm512_undefined_pd() -> __m512d;
+        [avx512f] safe fn _mm512_undefined_ps() -> __m512;
+        [avx512bw] safe fn _mm512_unpackhi_epi16(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_unpackhi_epi32(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_unpackhi_epi64(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_unpackhi_epi8(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_unpackhi_pd(a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_unpackhi_ps(a: __m512, b: __m512) -> __m512;
+        [avx512bw] safe fn _mm512_unpacklo_epi16(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_unpacklo_epi32(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_unpacklo_epi64(a: __m512i, b: __m512i) -> __m512i;
+        [avx512bw] safe fn _mm512_unpacklo_epi8(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_unpacklo_pd(a: __m512d, b: __m512d) -> __m512d;
+        [avx512f] safe fn _mm512_unpacklo_ps(a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_xor_epi32(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_xor_epi64(a: __m512i, b: __m512i) -> __m512i;
+        [avx512dq] safe fn _mm512_xor_pd(a: __m512d, b: __m512d) -> __m512d;
+        [avx512dq] safe fn _mm512_xor_ps(a: __m512, b: __m512) -> __m512;
+        [avx512f] safe fn _mm512_xor_si512(a: __m512i, b: __m512i) -> __m512i;
+        [avx512f] safe fn _mm512_zextpd128_pd512(a: __m128d) -> __m512d;
+        [avx512f] safe fn _mm512_zextpd256_pd512(a: __m256d) -> __m512d;
+        [avx512f] safe fn _mm512_zextps128_ps512(a: __m128) -> __m512;
+        [avx512f] safe fn _mm512_zextps256_ps512(a: __m256) -> __m512;
+        [avx512f] safe fn _mm512_zextsi128_si512(a: __m128i) -> __m512i;
+        [avx512f] safe fn _mm512_zextsi256_si512(a: __m256i) -> __m512i;
+        [avx512f, avx512vl] safe fn _mm_abs_epi64(a: __m128i) -> __m128i;
+        [avx512f] safe fn _mm_add_round_sd<const ROUNDING: i32>(a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_add_round_ss<const ROUNDING: i32>(a: __m128, b: __m128) -> __m128;
+        [kl] unsafe fn _mm_aesdec128kl_u8(output: *mut __m128i, input: __m128i, handle: *const u8) -> u8;
+        [kl] unsafe fn _mm_aesdec256kl_u8(output: *mut __m128i, input: __m128i, handle: *const u8) -> u8;
+        [widekl] unsafe fn _mm_aesdecwide128kl_u8(output: *mut __m128i, input: *const __m128i, handle: *const u8) -> u8;
+        [widekl] unsafe fn _mm_aesdecwide256kl_u8(output: *mut __m128i, input: *const __m128i, handle: *const u8) -> u8;
+        [kl] unsafe fn _mm_aesenc128kl_u8(output: *mut __m128i, input: __m128i, handle: *const u8) -> u8;
+        [kl] unsafe fn _mm_aesenc256kl_u8(output: *mut __m128i, input: __m128i, handle: *const u8) -> u8;
+        [widekl] unsafe fn _mm_aesencwide128kl_u8(output: *mut __m128i, input: *const __m128i, handle: *const u8) -> u8;
+        [widekl] unsafe fn _mm_aesencwide256kl_u8(output: *mut __m128i, input: *const __m128i, handle: *const u8) -> u8;
+        [avx512f, avx512vl] safe fn _mm_alignr_epi32<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_alignr_epi64<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i;
+        [avx512bitalg, avx512vl] safe fn _mm_bitshuffle_epi64_mask(b: __m128i, c: __m128i) -> __mmask16;
+        [avx512dq, avx512vl] safe fn _mm_broadcast_i32x2(a: __m128i) -> __m128i;
+        [avx512cd, avx512vl] safe fn _mm_broadcastmb_epi64(k: __mmask8) -> __m128i;
+        [avx512cd, avx512vl] safe fn _mm_broadcastmw_epi32(k: __mmask16) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_cmp_epi16_mask<const IMM8: i32>(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmp_epi32_mask<const IMM3: _MM_CMPINT_ENUM>(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmp_epi64_mask<const IMM3: _MM_CMPINT_ENUM>(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_cmp_epi8_mask<const IMM8: i32>(a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_cmp_epu16_mask<const IMM8: i32>(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmp_epu32_mask<const IMM3: _MM_CMPINT_ENUM>(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmp_epu64_mask<const IMM3: _MM_CMPINT_ENUM>(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_cmp_epu8_mask<const IMM8: i32>(a: __m128i, b: __m128i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm_cmp_pd_mask<const IMM8: i32>(a: __m128d, b: __m128d) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmp_ps_mask<const IMM8: i32>(a: __m128, b: __m128) -> __mmask8;
+        [avx512f] safe fn _mm_cmp_round_sd_mask<const IMM5: i32, const SAE: i32>(a: __m128d, b: __m128d) -> __mmask8;
+        [avx512f] safe fn _mm_cmp_round_ss_mask<const IMM5: i32, const SAE: i32>(a: __m128, b: __m128) -> __mmask8;
+        [avx512f] safe fn _mm_cmp_sd_mask<const IMM8: i32>(a: __m128d, b: __m128d) -> __mmask8;
+        [avx512f] safe fn _mm_cmp_ss_mask<const IMM8: i32>(a: __m128, b: __m128) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_cmpeq_epi16_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmpeq_epi32_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmpeq_epi64_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_cmpeq_epi8_mask(a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_cmpeq_epu16_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmpeq_epu32_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmpeq_epu64_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_cmpeq_epu8_mask(a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_cmpge_epi16_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmpge_epi32_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmpge_epi64_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_cmpge_epi8_mask(a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_cmpge_epu16_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmpge_epu32_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmpge_epu64_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_cmpge_epu8_mask(a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_cmpgt_epi16_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmpgt_epi32_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmpgt_epi64_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_cmpgt_epi8_mask(a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_cmpgt_epu16_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmpgt_epu32_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmpgt_epu64_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_cmpgt_epu8_mask(a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_cmple_epi16_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmple_epi32_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmple_epi64_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_cmple_epi8_mask(a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_cmple_epu16_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmple_epu32_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmple_epu64_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_cmple_epu8_mask(a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_cmplt_epi16_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmplt_epi32_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmplt_epi64_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_cmplt_epi8_mask(a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_cmplt_epu16_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmplt_epu32_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmplt_epu64_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_cmplt_epu8_mask(a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_cmpneq_epi16_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmpneq_epi32_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmpneq_epi64_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_cmpneq_epi8_mask(a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_cmpneq_epu16_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmpneq_epu32_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_cmpneq_epu64_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_cmpneq_epu8_mask(a: __m128i, b: __m128i) -> __mmask16;
+        [avx512f] safe fn _mm_comi_round_sd<const IMM5: i32, const SAE: i32>(a: __m128d, b: __m128d) -> i32;
+        [avx512f] safe fn _mm_comi_round_ss<const IMM5: i32, const SAE: i32>(a: __m128, b: __m128) -> i32;
+        [avx512cd, avx512vl] safe fn _mm_conflict_epi32(a: __m128i) -> __m128i;
+        [avx512cd, avx512vl] safe fn _mm_conflict_epi64(a: __m128i) -> __m128i;
+        [avx512f] safe fn _mm_cvt_roundi32_ss<const ROUNDING: i32>(a: __m128, b: i32) -> __m128;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvt_roundi64_sd<const ROUNDING: i32>(a: __m128d, b: i64) -> __m128d;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvt_roundi64_ss<const ROUNDING: i32>(a: __m128, b: i64) -> __m128;
+        [avx512f] safe fn _mm_cvt_roundsd_i32<const ROUNDING: i32>(a: __m128d) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvt_roundsd_i64<const ROUNDING: i32>(a: __m128d) -> i64;
+        [avx512f] safe fn _mm_cvt_roundsd_si32<const ROUNDING: i32>(a: __m128d) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvt_roundsd_si64<const ROUNDING: i32>(a: __m128d) -> i64;
+        [avx512f] safe fn _mm_cvt_roundsd_ss<const ROUNDING: i32>(a: __m128, b: __m128d) -> __m128;
+        [avx512f] safe fn _mm_cvt_roundsd_u32<const ROUNDING: i32>(a: __m128d) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvt_roundsd_u64<const ROUNDING: i32>(a: __m128d) -> u64;
+        [avx512f] safe fn _mm_cvt_roundsi32_ss<const ROUNDING: i32>(a: __m128, b: i32) -> __m128;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvt_roundsi64_sd<const ROUNDING: i32>(a: __m128d, b: i64) -> __m128d;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvt_roundsi64_ss<const ROUNDING: i32>(a: __m128, b: i64) -> __m128;
+        [avx512f] safe fn _mm_cvt_roundss_i32<const ROUNDING: i32>(a: __m128) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvt_roundss_i64<const ROUNDING: i32>(a: __m128) -> i64;
+        [avx512f] safe fn _mm_cvt_roundss_sd<const SAE: i32>(a: __m128d, b: __m128) -> __m128d;
+        [avx512f] safe fn _mm_cvt_roundss_si32<const ROUNDING: i32>(a: __m128) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvt_roundss_si64<const ROUNDING: i32>(a: __m128) -> i64;
+        [avx512f] safe fn _mm_cvt_roundss_u32<const ROUNDING: i32>(a: __m128) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvt_roundss_u64<const ROUNDING: i32>(a: __m128) -> u64;
+        [avx512f] safe fn _mm_cvt_roundu32_ss<const ROUNDING: i32>(a: __m128, b: u32) -> __m128;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvt_roundu64_sd<const ROUNDING: i32>(a: __m128d, b: u64) -> __m128d;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvt_roundu64_ss<const ROUNDING: i32>(a: __m128, b: u64) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_cvtepi16_epi8(a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_cvtepi32_epi16(a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_cvtepi32_epi8(a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_cvtepi64_epi16(a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_cvtepi64_epi32(a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_cvtepi64_epi8(a: __m128i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_cvtepi64_pd(a: __m128i) -> __m128d;
+        [avx512dq, avx512vl] safe fn _mm_cvtepi64_ps(a: __m128i) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_cvtepu32_pd(a: __m128i) -> __m128d;
+        [avx512dq, avx512vl] safe fn _mm_cvtepu64_pd(a: __m128i) -> __m128d;
+        [avx512dq, avx512vl] safe fn _mm_cvtepu64_ps(a: __m128i) -> __m128;
+        [avx512f] safe fn _mm_cvti32_sd(a: __m128d, b: i32) -> __m128d;
+        [avx512f] safe fn _mm_cvti32_ss(a: __m128, b: i32) -> __m128;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvti64_sd(a: __m128d, b: i64) -> __m128d;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvti64_ss(a: __m128, b: i64) -> __m128;
+        [avx512bf16, avx512vl] safe fn _mm_cvtne2ps_pbh(a: __m128, b: __m128) -> __m128bh;
+        [avxneconvert] unsafe fn _mm_cvtneebf16_ps(a: *const __m128bh) -> __m128;
+        [avxneconvert] unsafe fn _mm_cvtneobf16_ps(a: *const __m128bh) -> __m128;
+        [avxneconvert] safe fn _mm_cvtneps_avx_pbh(a: __m128) -> __m128bh;
+        [avx512bf16, avx512vl] safe fn _mm_cvtneps_pbh(a: __m128) -> __m128bh;
+        [avx512bf16, avx512vl] safe fn _mm_cvtpbh_ps(a: __m128bh) -> __m128;
+        [avx512dq, avx512vl] safe fn _mm_cvtpd_epi64(a: __m128d) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_cvtpd_epu32(a: __m128d) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_cvtpd_epu64(a: __m128d) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_cvtps_epi64(a: __m128) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_cvtps_epu32(a: __m128) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_cvtps_epu64(a: __m128) -> __m128i;
+        [avx512f] safe fn _mm_cvtsd_i32(a: __m128d) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvtsd_i64(a: __m128d) -> i64;
+        [avx512f] safe fn _mm_cvtsd_u32(a: __m128d) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvtsd_u64(a: __m128d) -> u64;
+        [avx512bw, avx512vl] safe fn _mm_cvtsepi16_epi8(a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_cvtsepi32_epi16(a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_cvtsepi32_epi8(a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_cvtsepi64_epi16(a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_cvtsepi64_epi32(a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_cvtsepi64_epi8(a: __m128i) -> __m128i;
+        [avx512f] safe fn _mm_cvtss_i32(a: __m128) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvtss_i64(a: __m128) -> i64;
+        [avx512f] safe fn _mm_cvtss_u32(a: __m128) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvtss_u64(a: __m128) -> u64;
+        [avx512f] safe fn _mm_cvtt_roundsd_i32<const SAE: i32>(a: __m128d) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvtt_roundsd_i64<const SAE: i32>(a: __m128d) -> i64;
+        [avx512f] safe fn _mm_cvtt_roundsd_si32<const SAE: i32>(a: __m128d) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvtt_roundsd_si64<const SAE: i32>(a: __m128d) -> i64;
+        [avx512f] safe fn _mm_cvtt_roundsd_u32<const SAE: i32>(a: __m128d) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvtt_roundsd_u64<const SAE: i32>(a: __m128d) -> u64;
+        [avx512f] safe fn _mm_cvtt_roundss_i32<const SAE: i32>(a: __m128) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvtt_roundss_i64<const SAE: i32>(a: __m128) -> i64;
+        [avx512f] safe fn _mm_cvtt_roundss_si32<const SAE: i32>(a: __m128) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvtt_roundss_si64<const SAE: i32>(a: __m128) -> i64;
+        [avx512f] safe fn _mm_cvtt_roundss_u32<const SAE: i32>(a: __m128) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvtt_roundss_u64<const SAE: i32>(a: __m128) -> u64;
+        [avx512dq, avx512vl] safe fn _mm_cvttpd_epi64(a: __m128d) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_cvttpd_epu32(a: __m128d) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_cvttpd_epu64(a: __m128d) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_cvttps_epi64(a: __m128) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_cvttps_epu32(a: __m128) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_cvttps_epu64(a: __m128) -> __m128i;
+        [avx512f] safe fn _mm_cvttsd_i32(a: __m128d) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvttsd_i64(a: __m128d) -> i64;
+        [avx512f] safe fn _mm_cvttsd_u32(a: __m128d) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvttsd_u64(a: __m128d) -> u64;
+        [avx512f] safe fn _mm_cvttss_i32(a: __m128) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvttss_i64(a: __m128) -> i64;
+        [avx512f] safe fn _mm_cvttss_u32(a: __m128) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvttss_u64(a: __m128) -> u64;
+        [avx512f] safe fn _mm_cvtu32_sd(a: __m128d, b: u32) -> __m128d;
+        [avx512f] safe fn _mm_cvtu32_ss(a: __m128, b: u32) -> __m128;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvtu64_sd(a: __m128d, b: u64) -> __m128d;
+        #[cfg(target_arch = "x86_64")]
+        [avx512f] safe fn _mm_cvtu64_ss(a: __m128, b: u64) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_cvtusepi16_epi8(a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_cvtusepi32_epi16(a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_cvtusepi32_epi8(a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_cvtusepi64_epi16(a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_cvtusepi64_epi32(a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_cvtusepi64_epi8(a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_dbsad_epu8<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i;
+        [avx512f] safe fn _mm_div_round_sd<const ROUNDING: i32>(a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_div_round_ss<const ROUNDING: i32>(a: __m128, b: __m128) -> __m128;
+        [avx512bf16, avx512vl] safe fn _mm_dpbf16_ps(src: __m128, a: __m128bh, b: __m128bh) -> __m128;
+        [avxvnniint8] safe fn _mm_dpbssd_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [avxvnniint8] safe fn _mm_dpbssds_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [avxvnniint8] safe fn _mm_dpbsud_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [avxvnniint8] safe fn _mm_dpbsuds_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [avxvnni] safe fn _mm_dpbusd_avx_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vnni, avx512vl] safe fn _mm_dpbusd_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [avxvnni] safe fn _mm_dpbusds_avx_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vnni, avx512vl] safe fn _mm_dpbusds_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [avxvnniint8] safe fn _mm_dpbuud_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [avxvnniint8] safe fn _mm_dpbuuds_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [avxvnni] safe fn _mm_dpwssd_avx_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vnni, avx512vl] safe fn _mm_dpwssd_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [avxvnni] safe fn _mm_dpwssds_avx_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vnni, avx512vl] safe fn _mm_dpwssds_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [avxvnniint16] safe fn _mm_dpwsud_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [avxvnniint16] safe fn _mm_dpwsuds_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [avxvnniint16] safe fn _mm_dpwusd_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [avxvnniint16] safe fn _mm_dpwusds_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [avxvnniint16] safe fn _mm_dpwuud_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [avxvnniint16] safe fn _mm_dpwuuds_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [kl] unsafe fn _mm_encodekey128_u32(key_params: u32, key: __m128i, handle: *mut u8) -> u32;
+        [kl] unsafe fn _mm_encodekey256_u32(key_params: u32, key_lo: __m128i, key_hi: __m128i, handle: *mut u8) -> u32;
+        [avx512f, avx512vl] safe fn _mm_fixupimm_pd<const IMM8: i32>(a: __m128d, b: __m128d, c: __m128i) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_fixupimm_ps<const IMM8: i32>(a: __m128, b: __m128, c: __m128i) -> __m128;
+        [avx512f] safe fn _mm_fixupimm_round_sd<const IMM8: i32, const SAE: i32>(a: __m128d, b: __m128d, c: __m128i) -> __m128d;
+        [avx512f] safe fn _mm_fixupimm_round_ss<const IMM8: i32, const SAE: i32>(a: __m128, b: __m128, c: __m128i) -> __m128;
+        [avx512f] safe fn _mm_fixupimm_sd<const IMM8: i32>(a: __m128d, b: __m128d, c: __m128i) -> __m128d;
+        [avx512f] safe fn _mm_fixupimm_ss<const IMM8: i32>(a: __m128, b: __m128, c: __m128i) -> __m128;
+        [avx512f] safe fn _mm_fmadd_round_sd<const ROUNDING: i32>(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_fmadd_round_ss<const ROUNDING: i32>(a: __m128, b: __m128, c: __m128) -> __m128;
+        [avx512f] safe fn _mm_fmsub_round_sd<const ROUNDING: i32>(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_fmsub_round_ss<const ROUNDING: i32>(a: __m128, b: __m128, c: __m128) -> __m128;
+        [avx512f] safe fn _mm_fnmadd_round_sd<const ROUNDING: i32>(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_fnmadd_round_ss<const ROUNDING: i32>(a: __m128, b: __m128, c: __m128) -> __m128;
+        [avx512f] safe fn _mm_fnmsub_round_sd<const ROUNDING: i32>(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_fnmsub_round_ss<const ROUNDING: i32>(a: __m128, b: __m128, c: __m128) -> __m128;
+        [avx512dq, avx512vl] safe fn _mm_fpclass_pd_mask<const IMM8: i32>(a: __m128d) -> __mmask8;
+        [avx512dq, avx512vl] safe fn _mm_fpclass_ps_mask<const IMM8: i32>(a: __m128) -> __mmask8;
+        [avx512dq] safe fn _mm_fpclass_sd_mask<const IMM8: i32>(a: __m128d) -> __mmask8;
+        [avx512dq] safe fn _mm_fpclass_ss_mask<const IMM8: i32>(a: __m128) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_getexp_pd(a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_getexp_ps(a: __m128) -> __m128;
+        [avx512f] safe fn _mm_getexp_round_sd<const SAE: i32>(a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_getexp_round_ss<const SAE: i32>(a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_getexp_sd(a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_getexp_ss(a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_getmant_pd<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_getmant_ps<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(a: __m128) -> __m128;
+        [avx512f] safe fn _mm_getmant_round_sd<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM, const SAE: i32>(a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_getmant_round_ss<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM, const SAE: i32>(a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_getmant_sd<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_getmant_ss<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(a: __m128, b: __m128) -> __m128;
+        [gfni] safe fn _mm_gf2p8affine_epi64_epi8<const B: i32>(x: __m128i, a: __m128i) -> __m128i;
+        [gfni] safe fn _mm_gf2p8affineinv_epi64_epi8<const B: i32>(x: __m128i, a: __m128i) -> __m128i;
+        [gfni] safe fn _mm_gf2p8mul_epi8(a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_i32scatter_epi32<const SCALE: i32>(base_addr: *mut i32, vindex: __m128i, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_i32scatter_epi64<const SCALE: i32>(base_addr: *mut i64, vindex: __m128i, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_i32scatter_pd<const SCALE: i32>(base_addr: *mut f64, vindex: __m128i, a: __m128d);
+        [avx512f, avx512vl] unsafe fn _mm_i32scatter_ps<const SCALE: i32>(base_addr: *mut f32, vindex: __m128i, a: __m128);
+        [avx512f, avx512vl] unsafe fn _mm_i64scatter_epi32<const SCALE: i32>(base_addr: *mut i32, vindex: __m128i, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_i64scatter_epi64<const SCALE: i32>(base_addr: *mut i64, vindex: __m128i, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_i64scatter_pd<const SCALE: i32>(base_addr: *mut f64, vindex: __m128i, a: __m128d);
+        [avx512f, avx512vl] unsafe fn _mm_i64scatter_ps<const SCALE: i32>(base_addr: *mut f32, vindex: __m128i, a: __m128);
+        [avx512f, avx512vl] unsafe fn _mm_load_epi32(mem_addr: *const i32) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_load_epi64(mem_addr: *const i64) -> __m128i;
+        [kl] unsafe fn _mm_loadiwkey(control: u32, integrity_key: __m128i, key_lo: __m128i, key_hi: __m128i);
+        [avx512bw, avx512vl] unsafe fn _mm_loadu_epi16(mem_addr: *const i16) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_loadu_epi32(mem_addr: *const i32) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_loadu_epi64(mem_addr: *const i64) -> __m128i;
+        [avx512bw, avx512vl] unsafe fn _mm_loadu_epi8(mem_addr: *const i8) -> __m128i;
+        [avx512cd, avx512vl] safe fn _mm_lzcnt_epi32(a: __m128i) -> __m128i;
+        [avx512cd, avx512vl] safe fn _mm_lzcnt_epi64(a: __m128i) -> __m128i;
+        [avxifma] safe fn _mm_madd52hi_avx_epu64(a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [avx512ifma, avx512vl] safe fn _mm_madd52hi_epu64(a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [avxifma] safe fn _mm_madd52lo_avx_epu64(a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [avx512ifma, avx512vl] safe fn _mm_madd52lo_epu64(a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask2_permutex2var_epi16(a: __m128i, idx: __m128i, k: __mmask8, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask2_permutex2var_epi32(a: __m128i, idx: __m128i, k: __mmask8, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask2_permutex2var_epi64(a: __m128i, idx: __m128i, k: __mmask8, b: __m128i) -> __m128i;
+        [avx512vbmi, avx512vl] safe fn _mm_mask2_permutex2var_epi8(a: __m128i, idx: __m128i, k: __mmask16, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask2_permutex2var_pd(a: __m128d, idx: __m128i, k: __mmask8, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask2_permutex2var_ps(a: __m128, idx: __m128i, k: __mmask8, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask3_fmadd_pd(a: __m128d, b: __m128d, c: __m128d, k: __mmask8) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask3_fmadd_ps(a: __m128, b: __m128, c: __m128, k: __mmask8) -> __m128;
+        [avx512f] safe fn _mm_mask3_fmadd_round_sd<const ROUNDING: i32>(a: __m128d, b: __m128d, c: __m128d, k: __mmask8) -> __m128d;
+        [avx512f] safe fn _mm_mask3_fmadd_round_ss<const ROUNDING: i32>(a: __m128, b: __m128, c: __m128, k: __mmask8) -> __m128;
+        [avx512f] safe fn _mm_mask3_fmadd_sd(a: __m128d, b: __m128d, c: __m128d, k: __mmask8) -> __m128d;
+        [avx512f] safe fn _mm_mask3_fmadd_ss(a: __m128, b: __m128, c: __m128, k: __mmask8) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask3_fmaddsub_pd(a: __m128d, b: __m128d, c: __m128d, k: __mmask8) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask3_fmaddsub_ps(a: __m128, b: __m128, c: __m128, k: __mmask8) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask3_fmsub_pd(a: __m128d, b: __m128d, c: __m128d, k: __mmask8) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask3_fmsub_ps(a: __m128, b: __m128, c: __m128, k: __mmask8) -> __m128;
+        [avx512f] safe fn _mm_mask3_fmsub_round_sd<const ROUNDING: i32>(a: __m128d, b: __m128d, c: __m128d, k: __mmask8) -> __m128d;
+        [avx512f] safe fn _mm_mask3_fmsub_round_ss<const ROUNDING: i32>(a: __m128, b: __m128, c: __m128, k: __mmask8) -> __m128;
+        [avx512f] safe fn _mm_mask3_fmsub_sd(a: __m128d, b: __m128d, c: __m128d, k: __mmask8) -> __m128d;
+        [avx512f] safe fn _mm_mask3_fmsub_ss(a: __m128, b: __m128, c: __m128, k: __mmask8) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask3_fmsubadd_pd(a: __m128d, b: __m128d, c: __m128d, k: __mmask8) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask3_fmsubadd_ps(a: __m128, b: __m128, c: __m128, k: __mmask8) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask3_fnmadd_pd(a: __m128d, b: __m128d, c: __m128d, k: __mmask8) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask3_fnmadd_ps(a: __m128, b: __m128, c: __m128, k: __mmask8) -> __m128;
+        [avx512f] safe fn _mm_mask3_fnmadd_round_sd<const ROUNDING: i32>(a: __m128d, b: __m128d, c: __m128d, k: __mmask8) -> __m128d;
+        [avx512f] safe fn _mm_mask3_fnmadd_round_ss<const ROUNDING: i32>(a: __m128, b: __m128, c: __m128, k: __mmask8) -> __m128;
+        [avx512f] safe fn _mm_mask3_fnmadd_sd(a: __m128d, b: __m128d, c: __m128d, k: __mmask8) -> __m128d;
+        [avx512f] safe fn _mm_mask3_fnmadd_ss(a: __m128, b: __m128, c: __m128, k: __mmask8) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask3_fnmsub_pd(a: __m128d, b: __m128d, c: __m128d, k: __mmask8) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask3_fnmsub_ps(a: __m128, b: __m128, c: __m128, k: __mmask8) -> __m128;
+        [avx512f] safe fn _mm_mask3_fnmsub_round_sd<const ROUNDING: i32>(a: __m128d, b: __m128d, c: __m128d, k: __mmask8) -> __m128d;
+        [avx512f] safe fn _mm_mask3_fnmsub_round_ss<const ROUNDING: i32>(a: __m128, b: __m128, c: __m128, k: __mmask8) -> __m128;
+        [avx512f] safe fn _mm_mask3_fnmsub_sd(a: __m128d, b: __m128d, c: __m128d, k: __mmask8) -> __m128d;
+        [avx512f] safe fn _mm_mask3_fnmsub_ss(a: __m128, b: __m128, c: __m128, k: __mmask8) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_mask_abs_epi16(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_abs_epi32(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_abs_epi64(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_abs_epi8(src: __m128i, k: __mmask16, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_add_epi16(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_add_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_add_epi64(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_add_epi8(src: __m128i, k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_add_pd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_add_ps(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_add_round_sd<const ROUNDING: i32>(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_add_round_ss<const ROUNDING: i32>(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_add_sd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_add_ss(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_mask_adds_epi16(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_adds_epi8(src: __m128i, k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_adds_epu16(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_adds_epu8(src: __m128i, k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_alignr_epi32<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_alignr_epi64<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_alignr_epi8<const IMM8: i32>(src: __m128i, k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_and_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_and_epi64(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_mask_and_pd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512dq, avx512vl] safe fn _mm_mask_and_ps(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask_andnot_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_andnot_epi64(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_mask_andnot_pd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512dq, avx512vl] safe fn _mm_mask_andnot_ps(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_mask_avg_epu16(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_avg_epu8(src: __m128i, k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bitalg, avx512vl] safe fn _mm_mask_bitshuffle_epi64_mask(k: __mmask16, b: __m128i, c: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_mask_blend_epi16(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_blend_epi32(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_blend_epi64(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_blend_epi8(k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_blend_pd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_blend_ps(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512dq, avx512vl] safe fn _mm_mask_broadcast_i32x2(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_broadcastb_epi8(src: __m128i, k: __mmask16, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_broadcastd_epi32(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_broadcastq_epi64(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_broadcastss_ps(src: __m128, k: __mmask8, a: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_mask_broadcastw_epi16(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmp_epi16_mask<const IMM8: i32>(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmp_epi32_mask<const IMM3: _MM_CMPINT_ENUM>(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmp_epi64_mask<const IMM3: _MM_CMPINT_ENUM>(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmp_epi8_mask<const IMM8: i32>(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmp_epu16_mask<const IMM8: i32>(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmp_epu32_mask<const IMM3: _MM_CMPINT_ENUM>(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmp_epu64_mask<const IMM3: _MM_CMPINT_ENUM>(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmp_epu8_mask<const IMM8: i32>(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm_mask_cmp_pd_mask<const IMM8: i32>(k1: __mmask8, a: __m128d, b: __m128d) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmp_ps_mask<const IMM8: i32>(k1: __mmask8, a: __m128, b: __m128) -> __mmask8;
+        [avx512f] safe fn _mm_mask_cmp_round_sd_mask<const IMM5: i32, const SAE: i32>(k1: __mmask8, a: __m128d, b: __m128d) -> __mmask8;
+        [avx512f] safe fn _mm_mask_cmp_round_ss_mask<const IMM5: i32, const SAE: i32>(k1: __mmask8, a: __m128, b: __m128) -> __mmask8;
+        [avx512f] safe fn _mm_mask_cmp_sd_mask<const IMM8: i32>(k1: __mmask8, a: __m128d, b: __m128d) -> __mmask8;
+        [avx512f] safe fn _mm_mask_cmp_ss_mask<const IMM8: i32>(k1: __mmask8, a: __m128, b: __m128) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmpeq_epi16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmpeq_epi32_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmpeq_epi64_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmpeq_epi8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmpeq_epu16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmpeq_epu32_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmpeq_epu64_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmpeq_epu8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmpge_epi16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmpge_epi32_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmpge_epi64_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmpge_epi8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmpge_epu16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmpge_epu32_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmpge_epu64_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmpge_epu8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmpgt_epi16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmpgt_epi32_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmpgt_epi64_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmpgt_epi8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmpgt_epu16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmpgt_epu32_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmpgt_epu64_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmpgt_epu8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmple_epi16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmple_epi32_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmple_epi64_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmple_epi8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmple_epu16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmple_epu32_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmple_epu64_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmple_epu8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmplt_epi16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmplt_epi32_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmplt_epi64_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmplt_epi8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmplt_epu16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmplt_epu32_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmplt_epu64_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmplt_epu8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmpneq_epi16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmpneq_epi32_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmpneq_epi64_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmpneq_epi8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmpneq_epu16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmpneq_epu32_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_cmpneq_epu64_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_mask_cmpneq_epu8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16;
+        [avx512vbmi2, avx512vl] safe fn _mm_mask_compress_epi16(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_compress_epi32(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_compress_epi64(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_mask_compress_epi8(src: __m128i, k: __mmask16, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_compress_pd(src: __m128d, k: __mmask8, a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_compress_ps(src: __m128, k: __mmask8, a: __m128) -> __m128;
+        [avx512vbmi2, avx512vl] unsafe fn _mm_mask_compressstoreu_epi16(base_addr: *mut i16, k: __mmask8, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_mask_compressstoreu_epi32(base_addr: *mut i32, k: __mmask8, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_mask_compressstoreu_epi64(base_addr: *mut i64, k: __mmask8, a: __m128i);
+        [avx512vbmi2, avx512vl] unsafe fn _mm_mask_compressstoreu_epi8(base_addr: *mut i8, k: __mmask16, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_mask_compressstoreu_pd(base_addr: *mut f64, k: __mmask8, a: __m128d);
+        [avx512f, avx512vl] unsafe fn _mm_mask_compressstoreu_ps(base_addr: *mut f32, k: __mmask8, a: __m128);
+        [avx512cd, avx512vl] safe fn _mm_mask_conflict_epi32(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512cd, avx512vl] safe fn _mm_mask_conflict_epi64(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvt_roundps_ph<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m128) -> __m128i;
+        [avx512f] safe fn _mm_mask_cvt_roundsd_ss<const ROUNDING: i32>(src: __m128, k: __mmask8, a: __m128, b: __m128d) -> __m128;
+        [avx512f] safe fn _mm_mask_cvt_roundss_sd<const SAE: i32>(src: __m128d, k: __mmask8, a: __m128d, b: __m128) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtepi16_epi32(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtepi16_epi64(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_cvtepi16_epi8(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] unsafe fn _mm_mask_cvtepi16_storeu_epi8(mem_addr: *mut i8, k: __mmask8, a: __m128i);
+        [avx512f, avx512vl] safe fn _mm_mask_cvtepi32_epi16(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtepi32_epi64(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtepi32_epi8(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtepi32_pd(src: __m128d, k: __mmask8, a: __m128i) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtepi32_ps(src: __m128, k: __mmask8, a: __m128i) -> __m128;
+        [avx512f, avx512vl] unsafe fn _mm_mask_cvtepi32_storeu_epi16(mem_addr: *mut i16, k: __mmask8, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_mask_cvtepi32_storeu_epi8(mem_addr: *mut i8, k: __mmask8, a: __m128i);
+        [avx512f, avx512vl] safe fn _mm_mask_cvtepi64_epi16(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtepi64_epi32(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtepi64_epi8(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_mask_cvtepi64_pd(src: __m128d, k: __mmask8, a: __m128i) -> __m128d;
+        [avx512dq, avx512vl] safe fn _mm_mask_cvtepi64_ps(src: __m128, k: __mmask8, a: __m128i) -> __m128;
+        [avx512f, avx512vl] unsafe fn _mm_mask_cvtepi64_storeu_epi16(mem_addr: *mut i16, k: __mmask8, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_mask_cvtepi64_storeu_epi32(mem_addr: *mut i32, k: __mmask8, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_mask_cvtepi64_storeu_epi8(mem_addr: *mut i8, k: __mmask8, a: __m128i);
+        [avx512bw, avx512vl] safe fn _mm_mask_cvtepi8_epi16(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtepi8_epi32(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtepi8_epi64(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtepu16_epi32(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtepu16_epi64(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtepu32_epi64(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtepu32_pd(src: __m128d, k: __mmask8, a: __m128i) -> __m128d;
+        [avx512dq, avx512vl] safe fn _mm_mask_cvtepu64_pd(src: __m128d, k: __mmask8, a: __m128i) -> __m128d;
+        [avx512dq, avx512vl] safe fn _mm_mask_cvtepu64_ps(src: __m128, k: __mmask8, a: __m128i) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_mask_cvtepu8_epi16(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtepu8_epi32(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtepu8_epi64(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bf16, avx512vl] safe fn _mm_mask_cvtne2ps_pbh(src: __m128bh, k: __mmask8, a: __m128, b: __m128) -> __m128bh;
+        [avx512bf16, avx512vl] safe fn _mm_mask_cvtneps_pbh(src: __m128bh, k: __mmask8, a: __m128) -> __m128bh;
+        [avx512bf16, avx512vl] safe fn _mm_mask_cvtpbh_ps(src: __m128, k: __mmask8, a: __m128bh) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtpd_epi32(src: __m128i, k: __mmask8, a: __m128d) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_mask_cvtpd_epi64(src: __m128i, k: __mmask8, a: __m128d) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtpd_epu32(src: __m128i, k: __mmask8, a: __m128d) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_mask_cvtpd_epu64(src: __m128i, k: __mmask8, a: __m128d) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtpd_ps(src: __m128, k: __mmask8, a: __m128d) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtph_ps(src: __m128, k: __mmask8, a: __m128i) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtps_epi32(src: __m128i, k: __mmask8, a: __m128) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_mask_cvtps_epi64(src: __m128i, k: __mmask8, a: __m128) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtps_epu32(src: __m128i, k: __mmask8, a: __m128) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_mask_cvtps_epu64(src: __m128i, k: __mmask8, a: __m128) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtps_ph<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m128) -> __m128i;
+        [avx512f] safe fn _mm_mask_cvtsd_ss(src: __m128, k: __mmask8, a: __m128, b: __m128d) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_mask_cvtsepi16_epi8(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] unsafe fn _mm_mask_cvtsepi16_storeu_epi8(mem_addr: *mut i8, k: __mmask8, a: __m128i);
+        [avx512f, avx512vl] safe fn _mm_mask_cvtsepi32_epi16(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtsepi32_epi8(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_mask_cvtsepi32_storeu_epi16(mem_addr: *mut i16, k: __mmask8, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_mask_cvtsepi32_storeu_epi8(mem_addr: *mut i8, k: __mmask8, a: __m128i);
+        [avx512f, avx512vl] safe fn _mm_mask_cvtsepi64_epi16(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtsepi64_epi32(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtsepi64_epi8(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_mask_cvtsepi64_storeu_epi16(mem_addr: *mut i16, k: __mmask8, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_mask_cvtsepi64_storeu_epi32(mem_addr: *mut i32, k: __mmask8, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_mask_cvtsepi64_storeu_epi8(mem_addr: *mut i8, k: __mmask8, a: __m128i);
+        [avx512f] safe fn _mm_mask_cvtss_sd(src: __m128d, k: __mmask8, a: __m128d, b: __m128) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_cvttpd_epi32(src: __m128i, k: __mmask8, a: __m128d) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_mask_cvttpd_epi64(src: __m128i, k: __mmask8, a: __m128d) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvttpd_epu32(src: __m128i, k: __mmask8, a: __m128d) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_mask_cvttpd_epu64(src: __m128i, k: __mmask8, a: __m128d) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvttps_epi32(src: __m128i, k: __mmask8, a: __m128) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_mask_cvttps_epi64(src: __m128i, k: __mmask8, a: __m128) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvttps_epu32(src: __m128i, k: __mmask8, a: __m128) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_mask_cvttps_epu64(src: __m128i, k: __mmask8, a: __m128) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_cvtusepi16_epi8(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] unsafe fn _mm_mask_cvtusepi16_storeu_epi8(mem_addr: *mut i8, k: __mmask8, a: __m128i);
+        [avx512f, avx512vl] safe fn _mm_mask_cvtusepi32_epi16(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtusepi32_epi8(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_mask_cvtusepi32_storeu_epi16(mem_addr: *mut i16, k: __mmask8, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_mask_cvtusepi32_storeu_epi8(mem_addr: *mut i8, k: __mmask8, a: __m128i);
+        [avx512f, avx512vl] safe fn _mm_mask_cvtusepi64_epi16(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtusepi64_epi32(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_cvtusepi64_epi8(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_mask_cvtusepi64_storeu_epi16(mem_addr: *mut i16, k: __mmask8, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_mask_cvtusepi64_storeu_epi32(mem_addr: *mut i32, k: __mmask8, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_mask_cvtusepi64_storeu_epi8(mem_addr: *mut i8, k: __mmask8, a: __m128i);
+        [avx512bw, avx512vl] safe fn _mm_mask_dbsad_epu8<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_div_pd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_div_ps(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_div_round_sd<const ROUNDING: i32>(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_div_round_ss<const ROUNDING: i32>(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_div_sd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_div_ss(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bf16, avx512vl] safe fn _mm_mask_dpbf16_ps(src: __m128, k: __mmask8, a: __m128bh, b: __m128bh) -> __m128;
+        [avx512vnni, avx512vl] safe fn _mm_mask_dpbusd_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vnni, avx512vl] safe fn _mm_mask_dpbusds_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vnni, avx512vl] safe fn _mm_mask_dpwssd_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vnni, avx512vl] safe fn _mm_mask_dpwssds_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_mask_expand_epi16(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_expand_epi32(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_expand_epi64(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_mask_expand_epi8(src: __m128i, k: __mmask16, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_expand_pd(src: __m128d, k: __mmask8, a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_expand_ps(src: __m128, k: __mmask8, a: __m128) -> __m128;
+        [avx512vbmi2, avx512vl] unsafe fn _mm_mask_expandloadu_epi16(src: __m128i, k: __mmask8, mem_addr: *const i16) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_mask_expandloadu_epi32(src: __m128i, k: __mmask8, mem_addr: *const i32) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_mask_expandloadu_epi64(src: __m128i, k: __mmask8, mem_addr: *const i64) -> __m128i;
+        [avx512vbmi2, avx512vl] unsafe fn _mm_mask_expandloadu_epi8(src: __m128i, k: __mmask16, mem_addr: *const i8) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_mask_expandloadu_pd(src: __m128d, k: __mmask8, mem_addr: *const f64) -> __m128d;
+        [avx512f, avx512vl] unsafe fn _mm_mask_expandloadu_ps(src: __m128, k: __mmask8, mem_addr: *const f32) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask_fixupimm_pd<const IMM8: i32>(a: __m128d, k: __mmask8, b: __m128d, c: __m128i) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_fixupimm_ps<const IMM8: i32>(a: __m128, k: __mmask8, b: __m128, c: __m128i) -> __m128;
+        [avx512f] safe fn _mm_mask_fixupimm_round_sd<const IMM8: i32, const SAE: i32>(a: __m128d, k: __mmask8, b: __m128d, c: __m128i) -> __m128d;
+        [avx512f] safe fn _mm_mask_fixupimm_round_ss<const IMM8: i32, const SAE: i32>(a: __m128, k: __mmask8, b: __m128, c: __m128i) -> __m128;
+        [avx512f] safe fn _mm_mask_fixupimm_sd<const IMM8: i32>(a: __m128d, k: __mmask8, b: __m128d, c: __m128i) -> __m128d;
+        [avx512f] safe fn _mm_mask_fixupimm_ss<const IMM8: i32>(a: __m128, k: __mmask8, b: __m128, c: __m128i) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask_fmadd_pd(a: __m128d, k: __mmask8, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_fmadd_ps(a: __m128, k: __mmask8, b: __m128, c: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_fmadd_round_sd<const ROUNDING: i32>(a: __m128d, k: __mmask8, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_fmadd_round_ss<const ROUNDING: i32>(a: __m128, k: __mmask8, b: __m128, c: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_fmadd_sd(a: __m128d, k: __mmask8, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_fmadd_ss(a: __m128, k: __mmask8, b: __m128, c: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask_fmaddsub_pd(a: __m128d, k: __mmask8, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_fmaddsub_ps(a: __m128, k: __mmask8, b: __m128, c: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask_fmsub_pd(a: __m128d, k: __mmask8, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_fmsub_ps(a: __m128, k: __mmask8, b: __m128, c: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_fmsub_round_sd<const ROUNDING: i32>(a: __m128d, k: __mmask8, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_fmsub_round_ss<const ROUNDING: i32>(a: __m128, k: __mmask8, b: __m128, c: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_fmsub_sd(a: __m128d, k: __mmask8, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_fmsub_ss(a: __m128, k: __mmask8, b: __m128, c: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask_fmsubadd_pd(a: __m128d, k: __mmask8, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_fmsubadd_ps(a: __m128, k: __mmask8, b: __m128, c: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask_fnmadd_pd(a: __m128d, k: __mmask8, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_fnmadd_ps(a: __m128, k: __mmask8, b: __m128, c: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_fnmadd_round_sd<const ROUNDING: i32>(a: __m128d, k: __mmask8, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_fnmadd_round_ss<const ROUNDING: i32>(a: __m128, k: __mmask8, b: __m128, c: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_fnmadd_sd(a: __m128d, k: __mmask8, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_fnmadd_ss(a: __m128, k: __mmask8, b: __m128, c: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask_fnmsub_pd(a: __m128d, k: __mmask8, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_fnmsub_ps(a: __m128, k: __mmask8, b: __m128, c: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_fnmsub_round_sd<const ROUNDING: i32>(a: __m128d, k: __mmask8, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_fnmsub_round_ss<const ROUNDING: i32>(a: __m128, k: __mmask8, b: __m128, c: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_fnmsub_sd(a: __m128d, k: __mmask8, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_fnmsub_ss(a: __m128, k: __mmask8, b: __m128, c: __m128) -> __m128;
+        [avx512dq, avx512vl] safe fn _mm_mask_fpclass_pd_mask<const IMM8: i32>(k1: __mmask8, a: __m128d) -> __mmask8;
+        [avx512dq, avx512vl] safe fn _mm_mask_fpclass_ps_mask<const IMM8: i32>(k1: __mmask8, a: __m128) -> __mmask8;
+        [avx512dq] safe fn _mm_mask_fpclass_sd_mask<const IMM8: i32>(k1: __mmask8, a: __m128d) -> __mmask8;
+        [avx512dq] safe fn _mm_mask_fpclass_ss_mask<const IMM8: i32>(k1: __mmask8, a: __m128) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_getexp_pd(src: __m128d, k: __mmask8, a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_getexp_ps(src: __m128, k: __mmask8, a: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_getexp_round_sd<const SAE: i32>(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_getexp_round_ss<const SAE: i32>(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_getexp_sd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_getexp_ss(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask_getmant_pd<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(src: __m128d, k: __mmask8, a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_getmant_ps<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(src: __m128, k: __mmask8, a: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_getmant_round_sd<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM, const SAE: i32>(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_getmant_round_ss<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM, const SAE: i32>(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_getmant_sd<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_getmant_ss<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [gfni, avx512bw, avx512vl] safe fn _mm_mask_gf2p8affine_epi64_epi8<const B: i32>(src: __m128i, k: __mmask16, x: __m128i, a: __m128i) -> __m128i;
+        [gfni, avx512bw, avx512vl] safe fn _mm_mask_gf2p8affineinv_epi64_epi8<const B: i32>(src: __m128i, k: __mmask16, x: __m128i, a: __m128i) -> __m128i;
+        [gfni, avx512bw, avx512vl] safe fn _mm_mask_gf2p8mul_epi8(src: __m128i, k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_mask_i32scatter_epi32<const SCALE: i32>(base_addr: *mut i32, k: __mmask8, vindex: __m128i, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_mask_i32scatter_epi64<const SCALE: i32>(base_addr: *mut i64, k: __mmask8, vindex: __m128i, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_mask_i32scatter_pd<const SCALE: i32>(base_addr: *mut f64, k: __mmask8, vindex: __m128i, a: __m128d);
+        [avx512f, avx512vl] unsafe fn _mm_mask_i32scatter_ps<const SCALE: i32>(base_addr: *mut f32, k: __mmask8, vindex: __m128i, a: __m128);
+        [avx512f, avx512vl] unsafe fn _mm_mask_i64scatter_epi32<const SCALE: i32>(base_addr: *mut i32, k: __mmask8, vindex: __m128i, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_mask_i64scatter_epi64<const SCALE: i32>(base_addr: *mut i64, k: __mmask8, vindex: __m128i, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_mask_i64scatter_pd<const SCALE: i32>(base_addr: *mut f64, k: __mmask8, vindex: __m128i, a: __m128d);
+        [avx512f, avx512vl] unsafe fn _mm_mask_i64scatter_ps<const SCALE: i32>(base_addr: *mut f32, k: __mmask8, vindex: __m128i, a: __m128);
+        [avx512f, avx512vl] unsafe fn _mm_mask_load_epi32(src: __m128i, k: __mmask8, mem_addr: *const i32) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_mask_load_epi64(src: __m128i, k: __mmask8, mem_addr: *const i64) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_mask_load_pd(src: __m128d, k: __mmask8, mem_addr: *const f64) -> __m128d;
+        [avx512f, avx512vl] unsafe fn _mm_mask_load_ps(src: __m128, k: __mmask8, mem_addr: *const f32) -> __m128;
+        [avx512f] unsafe fn _mm_mask_load_sd(src: __m128d, k: __mmask8, mem_addr: *const f64) -> __m128d;
+        [avx512f] unsafe fn _mm_mask_load_ss(src: __m128, k: __mmask8, mem_addr: *const f32) -> __m128;
+        [avx512bw, avx512vl] unsafe fn _mm_mask_loadu_epi16(src: __m128i, k: __mmask8, mem_addr: *const i16) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_mask_loadu_epi32(src: __m128i, k: __mmask8, mem_addr: *const i32) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_mask_loadu_epi64(src: __m128i, k: __mmask8, mem_addr: *const i64) -> __m128i;
+        [avx512bw, avx512vl] unsafe fn _mm_mask_loadu_epi8(src: __m128i, k: __mmask16, mem_addr: *const i8) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_mask_loadu_pd(src: __m128d, k: __mmask8, mem_addr: *const f64) -> __m128d;
+        [avx512f, avx512vl] unsafe fn _mm_mask_loadu_ps(src: __m128, k: __mmask8, mem_addr: *const f32) -> __m128;
+        [avx512cd, avx512vl] safe fn _mm_mask_lzcnt_epi32(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512cd, avx512vl] safe fn _mm_mask_lzcnt_epi64(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512ifma, avx512vl] safe fn _mm_mask_madd52hi_epu64(a: __m128i, k: __mmask8, b: __m128i, c: __m128i) -> __m128i;
+        [avx512ifma, avx512vl] safe fn _mm_mask_madd52lo_epu64(a: __m128i, k: __mmask8, b: __m128i, c: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_madd_epi16(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_maddubs_epi16(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_max_epi16(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_max_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_max_epi64(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_max_epi8(src: __m128i, k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_max_epu16(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_max_epu32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_max_epu64(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_max_epu8(src: __m128i, k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_max_pd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_max_ps(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_max_round_sd<const SAE: i32>(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_max_round_ss<const SAE: i32>(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_max_sd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_max_ss(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_mask_min_epi16(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_min_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_min_epi64(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_min_epi8(src: __m128i, k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_min_epu16(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_min_epu32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_min_epu64(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_min_epu8(src: __m128i, k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_min_pd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_min_ps(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_min_round_sd<const SAE: i32>(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_min_round_ss<const SAE: i32>(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_min_sd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_min_ss(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_mask_mov_epi16(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_mov_epi32(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_mov_epi64(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_mov_epi8(src: __m128i, k: __mmask16, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_mov_pd(src: __m128d, k: __mmask8, a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_mov_ps(src: __m128, k: __mmask8, a: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_move_sd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_move_ss(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask_movedup_pd(src: __m128d, k: __mmask8, a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_movehdup_ps(src: __m128, k: __mmask8, a: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask_moveldup_ps(src: __m128, k: __mmask8, a: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask_mul_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_mul_epu32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_mul_pd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_mul_ps(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_mul_round_sd<const ROUNDING: i32>(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_mul_round_ss<const ROUNDING: i32>(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_mul_sd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_mul_ss(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_mask_mulhi_epi16(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_mulhi_epu16(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_mulhrs_epi16(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_mullo_epi16(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_mullo_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_mask_mullo_epi64(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi, avx512vl] safe fn _mm_mask_multishift_epi64_epi8(src: __m128i, k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_or_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_or_epi64(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_mask_or_pd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512dq, avx512vl] safe fn _mm_mask_or_ps(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_mask_packs_epi16(src: __m128i, k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_packs_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_packus_epi16(src: __m128i, k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_packus_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_permute_pd<const IMM2: i32>(src: __m128d, k: __mmask8, a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_permute_ps<const MASK: i32>(src: __m128, k: __mmask8, a: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask_permutevar_pd(src: __m128d, k: __mmask8, a: __m128d, b: __m128i) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_permutevar_ps(src: __m128, k: __mmask8, a: __m128, b: __m128i) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_mask_permutex2var_epi16(a: __m128i, k: __mmask8, idx: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_permutex2var_epi32(a: __m128i, k: __mmask8, idx: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_permutex2var_epi64(a: __m128i, k: __mmask8, idx: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi, avx512vl] safe fn _mm_mask_permutex2var_epi8(a: __m128i, k: __mmask16, idx: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_permutex2var_pd(a: __m128d, k: __mmask8, idx: __m128i, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_permutex2var_ps(a: __m128, k: __mmask8, idx: __m128i, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_mask_permutexvar_epi16(src: __m128i, k: __mmask8, idx: __m128i, a: __m128i) -> __m128i;
+        [avx512vbmi, avx512vl] safe fn _mm_mask_permutexvar_epi8(src: __m128i, k: __mmask16, idx: __m128i, a: __m128i) -> __m128i;
+        [avx512bitalg, avx512vl] safe fn _mm_mask_popcnt_epi16(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512vpopcntdq, avx512vl] safe fn _mm_mask_popcnt_epi32(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512vpopcntdq, avx512vl] safe fn _mm_mask_popcnt_epi64(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bitalg, avx512vl] safe fn _mm_mask_popcnt_epi8(src: __m128i, k: __mmask16, a: __m128i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_mask_range_pd<const IMM8: i32>(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512dq, avx512vl] safe fn _mm_mask_range_ps<const IMM8: i32>(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512dq] safe fn _mm_mask_range_round_sd<const IMM8: i32, const SAE: i32>(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512dq] safe fn _mm_mask_range_round_ss<const IMM8: i32, const SAE: i32>(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512dq] safe fn _mm_mask_range_sd<const IMM8: i32>(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512dq] safe fn _mm_mask_range_ss<const IMM8: i32>(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask_rcp14_pd(src: __m128d, k: __mmask8, a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_rcp14_ps(src: __m128, k: __mmask8, a: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_rcp14_sd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_rcp14_ss(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_mask_reduce_add_epi16(k: __mmask8, a: __m128i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm_mask_reduce_add_epi8(k: __mmask16, a: __m128i) -> i8;
+        [avx512bw, avx512vl] safe fn _mm_mask_reduce_and_epi16(k: __mmask8, a: __m128i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm_mask_reduce_and_epi8(k: __mmask16, a: __m128i) -> i8;
+        [avx512bw, avx512vl] safe fn _mm_mask_reduce_max_epi16(k: __mmask8, a: __m128i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm_mask_reduce_max_epi8(k: __mmask16, a: __m128i) -> i8;
+        [avx512bw, avx512vl] safe fn _mm_mask_reduce_max_epu16(k: __mmask8, a: __m128i) -> u16;
+        [avx512bw, avx512vl] safe fn _mm_mask_reduce_max_epu8(k: __mmask16, a: __m128i) -> u8;
+        [avx512bw, avx512vl] safe fn _mm_mask_reduce_min_epi16(k: __mmask8, a: __m128i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm_mask_reduce_min_epi8(k: __mmask16, a: __m128i) -> i8;
+        [avx512bw, avx512vl] safe fn _mm_mask_reduce_min_epu16(k: __mmask8, a: __m128i) -> u16;
+        [avx512bw, avx512vl] safe fn _mm_mask_reduce_min_epu8(k: __mmask16, a: __m128i) -> u8;
+        [avx512bw, avx512vl] safe fn _mm_mask_reduce_mul_epi16(k: __mmask8, a: __m128i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm_mask_reduce_mul_epi8(k: __mmask16, a: __m128i) -> i8;
+        [avx512bw, avx512vl] safe fn _mm_mask_reduce_or_epi16(k: __mmask8, a: __m128i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm_mask_reduce_or_epi8(k: __mmask16, a: __m128i) -> i8;
+        [avx512dq, avx512vl] safe fn _mm_mask_reduce_pd<const IMM8: i32>(src: __m128d, k: __mmask8, a: __m128d) -> __m128d;
+        [avx512dq, avx512vl] safe fn _mm_mask_reduce_ps<const IMM8: i32>(src: __m128, k: __mmask8, a: __m128) -> __m128;
+        [avx512dq] safe fn _mm_mask_reduce_round_sd<const IMM8: i32, const SAE: i32>(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512dq] safe fn _mm_mask_reduce_round_ss<const IMM8: i32, const SAE: i32>(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512dq] safe fn _mm_mask_reduce_sd<const IMM8: i32>(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512dq] safe fn _mm_mask_reduce_ss<const IMM8: i32>(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask_rol_epi32<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_rol_epi64<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_rolv_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_rolv_epi64(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_ror_epi32<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_ror_epi64<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_rorv_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_rorv_epi64(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_roundscale_pd<const IMM8: i32>(src: __m128d, k: __mmask8, a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_roundscale_ps<const IMM8: i32>(src: __m128, k: __mmask8, a: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_roundscale_round_sd<const IMM8: i32, const SAE: i32>(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_roundscale_round_ss<const IMM8: i32, const SAE: i32>(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_roundscale_sd<const IMM8: i32>(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_roundscale_ss<const IMM8: i32>(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask_rsqrt14_pd(src: __m128d, k: __mmask8, a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_rsqrt14_ps(src: __m128, k: __mmask8, a: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_rsqrt14_sd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_rsqrt14_ss(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask_scalef_pd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_scalef_ps(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_scalef_round_sd<const ROUNDING: i32>(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_scalef_round_ss<const ROUNDING: i32>(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_scalef_sd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_scalef_ss(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_mask_set1_epi16(src: __m128i, k: __mmask8, a: i16) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_set1_epi32(src: __m128i, k: __mmask8, a: i32) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_set1_epi64(src: __m128i, k: __mmask8, a: i64) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_set1_epi8(src: __m128i, k: __mmask16, a: i8) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_mask_shldi_epi16<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_mask_shldi_epi32<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_mask_shldi_epi64<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_mask_shldv_epi16(a: __m128i, k: __mmask8, b: __m128i, c: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_mask_shldv_epi32(a: __m128i, k: __mmask8, b: __m128i, c: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_mask_shldv_epi64(a: __m128i, k: __mmask8, b: __m128i, c: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_mask_shrdi_epi16<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_mask_shrdi_epi32<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_mask_shrdi_epi64<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_mask_shrdv_epi16(a: __m128i, k: __mmask8, b: __m128i, c: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_mask_shrdv_epi32(a: __m128i, k: __mmask8, b: __m128i, c: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_mask_shrdv_epi64(a: __m128i, k: __mmask8, b: __m128i, c: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_shuffle_epi32<const MASK: _MM_PERM_ENUM>(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_shuffle_epi8(src: __m128i, k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_shuffle_pd<const MASK: i32>(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_shuffle_ps<const MASK: i32>(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_mask_shufflehi_epi16<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_shufflelo_epi16<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_sll_epi16(src: __m128i, k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_sll_epi32(src: __m128i, k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_sll_epi64(src: __m128i, k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_slli_epi16<const IMM8: u32>(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_slli_epi32<const IMM8: u32>(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_slli_epi64<const IMM8: u32>(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_sllv_epi16(src: __m128i, k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_sllv_epi32(src: __m128i, k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_sllv_epi64(src: __m128i, k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_sqrt_pd(src: __m128d, k: __mmask8, a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_sqrt_ps(src: __m128, k: __mmask8, a: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_sqrt_round_sd<const ROUNDING: i32>(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_sqrt_round_ss<const ROUNDING: i32>(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_sqrt_sd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_sqrt_ss(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_mask_sra_epi16(src: __m128i, k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_sra_epi32(src: __m128i, k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_sra_epi64(src: __m128i, k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_srai_epi16<const IMM8: u32>(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_srai_epi32<const IMM8: u32>(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_srai_epi64<const IMM8: u32>(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_srav_epi16(src: __m128i, k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_srav_epi32(src: __m128i, k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_srav_epi64(src: __m128i, k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_srl_epi16(src: __m128i, k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_srl_epi32(src: __m128i, k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_srl_epi64(src: __m128i, k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_srli_epi16<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_srli_epi32<const IMM8: u32>(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_srli_epi64<const IMM8: u32>(src: __m128i, k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_srlv_epi16(src: __m128i, k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_srlv_epi32(src: __m128i, k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_srlv_epi64(src: __m128i, k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_mask_store_epi32(mem_addr: *mut i32, mask: __mmask8, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_mask_store_epi64(mem_addr: *mut i64, mask: __mmask8, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_mask_store_pd(mem_addr: *mut f64, mask: __mmask8, a: __m128d);
+        [avx512f, avx512vl] unsafe fn _mm_mask_store_ps(mem_addr: *mut f32, mask: __mmask8, a: __m128);
+        [avx512f] unsafe fn _mm_mask_store_sd(mem_addr: *mut f64, k: __mmask8, a: __m128d);
+        [avx512f] unsafe fn _mm_mask_store_ss(mem_addr: *mut f32, k: __mmask8, a: __m128);
+        [avx512bw, avx512vl] unsafe fn _mm_mask_storeu_epi16(mem_addr: *mut i16, mask: __mmask8, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_mask_storeu_epi32(mem_addr: *mut i32, mask: __mmask8, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_mask_storeu_epi64(mem_addr: *mut i64, mask: __mmask8, a: __m128i);
+        [avx512bw, avx512vl] unsafe fn _mm_mask_storeu_epi8(mem_addr: *mut i8, mask: __mmask16, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_mask_storeu_pd(mem_addr: *mut f64, mask: __mmask8, a: __m128d);
+        [avx512f, avx512vl] unsafe fn _mm_mask_storeu_ps(mem_addr: *mut f32, mask: __mmask8, a: __m128);
+        [avx512bw, avx512vl] safe fn _mm_mask_sub_epi16(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_sub_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_sub_epi64(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_sub_epi8(src: __m128i, k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_sub_pd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_sub_ps(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_sub_round_sd<const ROUNDING: i32>(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_sub_round_ss<const ROUNDING: i32>(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_mask_sub_sd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mask_sub_ss(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_mask_subs_epi16(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_subs_epi8(src: __m128i, k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_subs_epu16(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_subs_epu8(src: __m128i, k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_ternarylogic_epi32<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_ternarylogic_epi64<const IMM8: i32>(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_test_epi16_mask(k: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_test_epi32_mask(k: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_test_epi64_mask(k: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_mask_test_epi8_mask(k: __mmask16, a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_mask_testn_epi16_mask(k: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_testn_epi32_mask(k: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_mask_testn_epi64_mask(k: __mmask8, a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_mask_testn_epi8_mask(k: __mmask16, a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_mask_unpackhi_epi16(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_unpackhi_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_unpackhi_epi64(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_unpackhi_epi8(src: __m128i, k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_unpackhi_pd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_unpackhi_ps(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_mask_unpacklo_epi16(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_unpacklo_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_unpacklo_epi64(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_mask_unpacklo_epi8(src: __m128i, k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_unpacklo_pd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_mask_unpacklo_ps(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_mask_xor_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_mask_xor_epi64(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_mask_xor_pd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512dq, avx512vl] safe fn _mm_mask_xor_ps(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_maskz_abs_epi16(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_abs_epi32(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_abs_epi64(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_abs_epi8(k: __mmask16, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_add_epi16(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_add_epi32(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_add_epi64(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_add_epi8(k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_add_pd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_add_ps(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_add_round_sd<const ROUNDING: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_add_round_ss<const ROUNDING: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_add_sd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_add_ss(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_maskz_adds_epi16(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_adds_epi8(k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_adds_epu16(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_adds_epu8(k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_alignr_epi32<const IMM8: i32>(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_alignr_epi64<const IMM8: i32>(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_alignr_epi8<const IMM8: i32>(k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_and_epi32(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_and_epi64(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_maskz_and_pd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512dq, avx512vl] safe fn _mm_maskz_and_ps(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_maskz_andnot_epi32(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_andnot_epi64(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_maskz_andnot_pd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512dq, avx512vl] safe fn _mm_maskz_andnot_ps(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_maskz_avg_epu16(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_avg_epu8(k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_maskz_broadcast_i32x2(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_broadcastb_epi8(k: __mmask16, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_broadcastd_epi32(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_broadcastq_epi64(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_broadcastss_ps(k: __mmask8, a: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_maskz_broadcastw_epi16(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_maskz_compress_epi16(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_compress_epi32(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_compress_epi64(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_maskz_compress_epi8(k: __mmask16, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_compress_pd(k: __mmask8, a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_compress_ps(k: __mmask8, a: __m128) -> __m128;
+        [avx512cd, avx512vl] safe fn _mm_maskz_conflict_epi32(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512cd, avx512vl] safe fn _mm_maskz_conflict_epi64(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvt_roundps_ph<const IMM8: i32>(k: __mmask8, a: __m128) -> __m128i;
+        [avx512f] safe fn _mm_maskz_cvt_roundsd_ss<const ROUNDING: i32>(k: __mmask8, a: __m128, b: __m128d) -> __m128;
+        [avx512f] safe fn _mm_maskz_cvt_roundss_sd<const SAE: i32>(k: __mmask8, a: __m128d, b: __m128) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtepi16_epi32(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtepi16_epi64(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_cvtepi16_epi8(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtepi32_epi16(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtepi32_epi64(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtepi32_epi8(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtepi32_pd(k: __mmask8, a: __m128i) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtepi32_ps(k: __mmask8, a: __m128i) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtepi64_epi16(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtepi64_epi32(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtepi64_epi8(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_maskz_cvtepi64_pd(k: __mmask8, a: __m128i) -> __m128d;
+        [avx512dq, avx512vl] safe fn _mm_maskz_cvtepi64_ps(k: __mmask8, a: __m128i) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_maskz_cvtepi8_epi16(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtepi8_epi32(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtepi8_epi64(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtepu16_epi32(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtepu16_epi64(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtepu32_epi64(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtepu32_pd(k: __mmask8, a: __m128i) -> __m128d;
+        [avx512dq, avx512vl] safe fn _mm_maskz_cvtepu64_pd(k: __mmask8, a: __m128i) -> __m128d;
+        [avx512dq, avx512vl] safe fn _mm_maskz_cvtepu64_ps(k: __mmask8, a: __m128i) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_maskz_cvtepu8_epi16(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtepu8_epi32(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtepu8_epi64(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bf16, avx512vl] safe fn _mm_maskz_cvtne2ps_pbh(k: __mmask8, a: __m128, b: __m128) -> __m128bh;
+        [avx512bf16, avx512vl] safe fn _mm_maskz_cvtneps_pbh(k: __mmask8, a: __m128) -> __m128bh;
+        [avx512bf16, avx512vl] safe fn _mm_maskz_cvtpbh_ps(k: __mmask8, a: __m128bh) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtpd_epi32(k: __mmask8, a: __m128d) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_maskz_cvtpd_epi64(k: __mmask8, a: __m128d) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtpd_epu32(k: __mmask8, a: __m128d) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_maskz_cvtpd_epu64(k: __mmask8, a: __m128d) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtpd_ps(k: __mmask8, a: __m128d) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtph_ps(k: __mmask8, a: __m128i) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtps_epi32(k: __mmask8, a: __m128) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_maskz_cvtps_epi64(k: __mmask8, a: __m128) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtps_epu32(k: __mmask8, a: __m128) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_maskz_cvtps_epu64(k: __mmask8, a: __m128) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtps_ph<const IMM8: i32>(k: __mmask8, a: __m128) -> __m128i;
+        [avx512f] safe fn _mm_maskz_cvtsd_ss(k: __mmask8, a: __m128, b: __m128d) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_maskz_cvtsepi16_epi8(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtsepi32_epi16(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtsepi32_epi8(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtsepi64_epi16(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtsepi64_epi32(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtsepi64_epi8(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f] safe fn _mm_maskz_cvtss_sd(k: __mmask8, a: __m128d, b: __m128) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvttpd_epi32(k: __mmask8, a: __m128d) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_maskz_cvttpd_epi64(k: __mmask8, a: __m128d) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvttpd_epu32(k: __mmask8, a: __m128d) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_maskz_cvttpd_epu64(k: __mmask8, a: __m128d) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvttps_epi32(k: __mmask8, a: __m128) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_maskz_cvttps_epi64(k: __mmask8, a: __m128) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvttps_epu32(k: __mmask8, a: __m128) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_maskz_cvttps_epu64(k: __mmask8, a: __m128) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_cvtusepi16_epi8(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtusepi32_epi16(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtusepi32_epi8(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtusepi64_epi16(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtusepi64_epi32(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_cvtusepi64_epi8(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_dbsad_epu8<const IMM8: i32>(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_div_pd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_div_ps(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_div_round_sd<const ROUNDING: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_div_round_ss<const ROUNDING: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_div_sd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_div_ss(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bf16, avx512vl] safe fn _mm_maskz_dpbf16_ps(k: __mmask8, src: __m128, a: __m128bh, b: __m128bh) -> __m128;
+        [avx512vnni, avx512vl] safe fn _mm_maskz_dpbusd_epi32(k: __mmask8, src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vnni, avx512vl] safe fn _mm_maskz_dpbusds_epi32(k: __mmask8, src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vnni, avx512vl] safe fn _mm_maskz_dpwssd_epi32(k: __mmask8, src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vnni, avx512vl] safe fn _mm_maskz_dpwssds_epi32(k: __mmask8, src: __m128i, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_maskz_expand_epi16(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_expand_epi32(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_expand_epi64(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_maskz_expand_epi8(k: __mmask16, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_expand_pd(k: __mmask8, a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_expand_ps(k: __mmask8, a: __m128) -> __m128;
+        [avx512vbmi2, avx512vl] unsafe fn _mm_maskz_expandloadu_epi16(k: __mmask8, mem_addr: *const i16) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_maskz_expandloadu_epi32(k: __mmask8, mem_addr: *const i32) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_maskz_expandloadu_epi64(k: __mmask8, mem_addr: *const i64) -> __m128i;
+        [avx512vbmi2, avx512vl] unsafe fn _mm_maskz_expandloadu_epi8(k: __mmask16, mem_addr: *const i8) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_maskz_expandloadu_pd(k: __mmask8, mem_addr: *const f64) -> __m128d;
+        [avx512f, avx512vl] unsafe fn _mm_maskz_expandloadu_ps(k: __mmask8, mem_addr: *const f32) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_maskz_fixupimm_pd<const IMM8: i32>(k: __mmask8, a: __m128d, b: __m128d, c: __m128i) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_fixupimm_ps<const IMM8: i32>(k: __mmask8, a: __m128, b: __m128, c: __m128i) -> __m128;
+        [avx512f] safe fn _mm_maskz_fixupimm_round_sd<const IMM8: i32, const SAE: i32>(k: __mmask8, a: __m128d, b: __m128d, c: __m128i) -> __m128d;
+        [avx512f] safe fn _mm_maskz_fixupimm_round_ss<const IMM8: i32, const SAE: i32>(k: __mmask8, a: __m128, b: __m128, c: __m128i) -> __m128;
+        [avx512f] safe fn _mm_maskz_fixupimm_sd<const IMM8: i32>(k: __mmask8, a: __m128d, b: __m128d, c: __m128i) -> __m128d;
+        [avx512f] safe fn _mm_maskz_fixupimm_ss<const IMM8: i32>(k: __mmask8, a: __m128, b: __m128, c: __m128i) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_maskz_fmadd_pd(k: __mmask8, a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_fmadd_ps(k: __mmask8, a: __m128, b: __m128, c: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_fmadd_round_sd<const ROUNDING: i32>(k: __mmask8, a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_fmadd_round_ss<const ROUNDING: i32>(k: __mmask8, a: __m128, b: __m128, c: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_fmadd_sd(k: __mmask8, a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_fmadd_ss(k: __mmask8, a: __m128, b: __m128, c: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_maskz_fmaddsub_pd(k: __mmask8, a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_fmaddsub_ps(k: __mmask8, a: __m128, b: __m128, c: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_maskz_fmsub_pd(k: __mmask8, a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_fmsub_ps(k: __mmask8, a: __m128, b: __m128, c: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_fmsub_round_sd<const ROUNDING: i32>(k: __mmask8, a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_fmsub_round_ss<const ROUNDING: i32>(k: __mmask8, a: __m128, b: __m128, c: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_fmsub_sd(k: __mmask8, a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_fmsub_ss(k: __mmask8, a: __m128, b: __m128, c: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_maskz_fmsubadd_pd(k: __mmask8, a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_fmsubadd_ps(k: __mmask8, a: __m128, b: __m128, c: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_maskz_fnmadd_pd(k: __mmask8, a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_fnmadd_ps(k: __mmask8, a: __m128, b: __m128, c: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_fnmadd_round_sd<const ROUNDING: i32>(k: __mmask8, a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_fnmadd_round_ss<const ROUNDING: i32>(k: __mmask8, a: __m128, b: __m128, c: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_fnmadd_sd(k: __mmask8, a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_fnmadd_ss(k: __mmask8, a: __m128, b: __m128, c: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_maskz_fnmsub_pd(k: __mmask8, a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_fnmsub_ps(k: __mmask8, a: __m128, b: __m128, c: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_fnmsub_round_sd<const ROUNDING: i32>(k: __mmask8, a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_fnmsub_round_ss<const ROUNDING: i32>(k: __mmask8, a: __m128, b: __m128, c: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_fnmsub_sd(k: __mmask8, a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_fnmsub_ss(k: __mmask8, a: __m128, b: __m128, c: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_maskz_getexp_pd(k: __mmask8, a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_getexp_ps(k: __mmask8, a: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_getexp_round_sd<const SAE: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_getexp_round_ss<const SAE: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_getexp_sd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_getexp_ss(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_maskz_getmant_pd<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(k: __mmask8, a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_getmant_ps<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(k: __mmask8, a: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_getmant_round_sd<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM, const SAE: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_getmant_round_ss<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM, const SAE: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_getmant_sd<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_getmant_ss<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [gfni, avx512bw, avx512vl] safe fn _mm_maskz_gf2p8affine_epi64_epi8<const B: i32>(k: __mmask16, x: __m128i, a: __m128i) -> __m128i;
+        [gfni, avx512bw, avx512vl] safe fn _mm_maskz_gf2p8affineinv_epi64_epi8<const B: i32>(k: __mmask16, x: __m128i, a: __m128i) -> __m128i;
+        [gfni, avx512bw, avx512vl] safe fn _mm_maskz_gf2p8mul_epi8(k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_maskz_load_epi32(k: __mmask8, mem_addr: *const i32) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_maskz_load_epi64(k: __mmask8, mem_addr: *const i64) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_maskz_load_pd(k: __mmask8, mem_addr: *const f64) -> __m128d;
+        [avx512f, avx512vl] unsafe fn _mm_maskz_load_ps(k: __mmask8, mem_addr: *const f32) -> __m128;
+        [avx512f] unsafe fn _mm_maskz_load_sd(k: __mmask8, mem_addr: *const f64) -> __m128d;
+        [avx512f] unsafe fn _mm_maskz_load_ss(k: __mmask8, mem_addr: *const f32) -> __m128;
+        [avx512bw, avx512vl] unsafe fn _mm_maskz_loadu_epi16(k: __mmask8, mem_addr: *const i16) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_maskz_loadu_epi32(k: __mmask8, mem_addr: *const i32) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_maskz_loadu_epi64(k: __mmask8, mem_addr: *const i64) -> __m128i;
+        [avx512bw, avx512vl] unsafe fn _mm_maskz_loadu_epi8(k: __mmask16, mem_addr: *const i8) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_maskz_loadu_pd(k: __mmask8, mem_addr: *const f64) -> __m128d;
+        [avx512f, avx512vl] unsafe fn _mm_maskz_loadu_ps(k: __mmask8, mem_addr: *const f32) -> __m128;
+        [avx512cd, avx512vl] safe fn _mm_maskz_lzcnt_epi32(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512cd, avx512vl] safe fn _mm_maskz_lzcnt_epi64(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512ifma, avx512vl] safe fn _mm_maskz_madd52hi_epu64(k: __mmask8, a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [avx512ifma, avx512vl] safe fn _mm_maskz_madd52lo_epu64(k: __mmask8, a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_madd_epi16(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_maddubs_epi16(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_max_epi16(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_max_epi32(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_max_epi64(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_max_epi8(k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_max_epu16(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_max_epu32(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_max_epu64(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_max_epu8(k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_max_pd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_max_ps(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_max_round_sd<const SAE: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_max_round_ss<const SAE: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_max_sd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_max_ss(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_maskz_min_epi16(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_min_epi32(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_min_epi64(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_min_epi8(k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_min_epu16(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_min_epu32(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_min_epu64(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_min_epu8(k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_min_pd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_min_ps(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_min_round_sd<const SAE: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_min_round_ss<const SAE: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_min_sd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_min_ss(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_maskz_mov_epi16(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_mov_epi32(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_mov_epi64(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_mov_epi8(k: __mmask16, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_mov_pd(k: __mmask8, a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_mov_ps(k: __mmask8, a: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_move_sd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_move_ss(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_maskz_movedup_pd(k: __mmask8, a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_movehdup_ps(k: __mmask8, a: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_maskz_moveldup_ps(k: __mmask8, a: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_maskz_mul_epi32(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_mul_epu32(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_mul_pd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_mul_ps(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_mul_round_sd<const ROUNDING: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_mul_round_ss<const ROUNDING: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_mul_sd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_mul_ss(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_maskz_mulhi_epi16(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_mulhi_epu16(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_mulhrs_epi16(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_mullo_epi16(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_mullo_epi32(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_maskz_mullo_epi64(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi, avx512vl] safe fn _mm_maskz_multishift_epi64_epi8(k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_or_epi32(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_or_epi64(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_maskz_or_pd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512dq, avx512vl] safe fn _mm_maskz_or_ps(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_maskz_packs_epi16(k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_packs_epi32(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_packus_epi16(k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_packus_epi32(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_permute_pd<const IMM2: i32>(k: __mmask8, a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_permute_ps<const MASK: i32>(k: __mmask8, a: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_maskz_permutevar_pd(k: __mmask8, a: __m128d, b: __m128i) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_permutevar_ps(k: __mmask8, a: __m128, b: __m128i) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_maskz_permutex2var_epi16(k: __mmask8, a: __m128i, idx: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_permutex2var_epi32(k: __mmask8, a: __m128i, idx: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_permutex2var_epi64(k: __mmask8, a: __m128i, idx: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi, avx512vl] safe fn _mm_maskz_permutex2var_epi8(k: __mmask16, a: __m128i, idx: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_permutex2var_pd(k: __mmask8, a: __m128d, idx: __m128i, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_permutex2var_ps(k: __mmask8, a: __m128, idx: __m128i, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_maskz_permutexvar_epi16(k: __mmask8, idx: __m128i, a: __m128i) -> __m128i;
+        [avx512vbmi, avx512vl] safe fn _mm_maskz_permutexvar_epi8(k: __mmask16, idx: __m128i, a: __m128i) -> __m128i;
+        [avx512bitalg, avx512vl] safe fn _mm_maskz_popcnt_epi16(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512vpopcntdq, avx512vl] safe fn _mm_maskz_popcnt_epi32(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512vpopcntdq, avx512vl] safe fn _mm_maskz_popcnt_epi64(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bitalg, avx512vl] safe fn _mm_maskz_popcnt_epi8(k: __mmask16, a: __m128i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_maskz_range_pd<const IMM8: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512dq, avx512vl] safe fn _mm_maskz_range_ps<const IMM8: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512dq] safe fn _mm_maskz_range_round_sd<const IMM8: i32, const SAE: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512dq] safe fn _mm_maskz_range_round_ss<const IMM8: i32, const SAE: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512dq] safe fn _mm_maskz_range_sd<const IMM8: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512dq] safe fn _mm_maskz_range_ss<const IMM8: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_maskz_rcp14_pd(k: __mmask8, a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_rcp14_ps(k: __mmask8, a: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_rcp14_sd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_rcp14_ss(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512dq, avx512vl] safe fn _mm_maskz_reduce_pd<const IMM8: i32>(k: __mmask8, a: __m128d) -> __m128d;
+        [avx512dq, avx512vl] safe fn _mm_maskz_reduce_ps<const IMM8: i32>(k: __mmask8, a: __m128) -> __m128;
+        [avx512dq] safe fn _mm_maskz_reduce_round_sd<const IMM8: i32, const SAE: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512dq] safe fn _mm_maskz_reduce_round_ss<const IMM8: i32, const SAE: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512dq] safe fn _mm_maskz_reduce_sd<const IMM8: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512dq] safe fn _mm_maskz_reduce_ss<const IMM8: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_maskz_rol_epi32<const IMM8: i32>(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_rol_epi64<const IMM8: i32>(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_rolv_epi32(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_rolv_epi64(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_ror_epi32<const IMM8: i32>(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_ror_epi64<const IMM8: i32>(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_rorv_epi32(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_rorv_epi64(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_roundscale_pd<const IMM8: i32>(k: __mmask8, a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_roundscale_ps<const IMM8: i32>(k: __mmask8, a: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_roundscale_round_sd<const IMM8: i32, const SAE: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_roundscale_round_ss<const IMM8: i32, const SAE: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_roundscale_sd<const IMM8: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_roundscale_ss<const IMM8: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_maskz_rsqrt14_pd(k: __mmask8, a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_rsqrt14_ps(k: __mmask8, a: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_rsqrt14_sd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_rsqrt14_ss(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_maskz_scalef_pd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_scalef_ps(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_scalef_round_sd<const ROUNDING: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_scalef_round_ss<const ROUNDING: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_scalef_sd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_scalef_ss(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_maskz_set1_epi16(k: __mmask8, a: i16) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_set1_epi32(k: __mmask8, a: i32) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_set1_epi64(k: __mmask8, a: i64) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_set1_epi8(k: __mmask16, a: i8) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_maskz_shldi_epi16<const IMM8: i32>(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_maskz_shldi_epi32<const IMM8: i32>(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_maskz_shldi_epi64<const IMM8: i32>(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_maskz_shldv_epi16(k: __mmask8, a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_maskz_shldv_epi32(k: __mmask8, a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_maskz_shldv_epi64(k: __mmask8, a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_maskz_shrdi_epi16<const IMM8: i32>(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_maskz_shrdi_epi32<const IMM8: i32>(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_maskz_shrdi_epi64<const IMM8: i32>(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_maskz_shrdv_epi16(k: __mmask8, a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_maskz_shrdv_epi32(k: __mmask8, a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_maskz_shrdv_epi64(k: __mmask8, a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_shuffle_epi32<const MASK: _MM_PERM_ENUM>(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_shuffle_epi8(k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_shuffle_pd<const MASK: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_shuffle_ps<const MASK: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_maskz_shufflehi_epi16<const IMM8: i32>(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_shufflelo_epi16<const IMM8: i32>(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_sll_epi16(k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_sll_epi32(k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_sll_epi64(k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_slli_epi16<const IMM8: u32>(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_slli_epi32<const IMM8: u32>(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_slli_epi64<const IMM8: u32>(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_sllv_epi16(k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_sllv_epi32(k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_sllv_epi64(k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_sqrt_pd(k: __mmask8, a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_sqrt_ps(k: __mmask8, a: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_sqrt_round_sd<const ROUNDING: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_sqrt_round_ss<const ROUNDING: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_sqrt_sd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_sqrt_ss(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_maskz_sra_epi16(k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_sra_epi32(k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_sra_epi64(k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_srai_epi16<const IMM8: u32>(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_srai_epi32<const IMM8: u32>(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_srai_epi64<const IMM8: u32>(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_srav_epi16(k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_srav_epi32(k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_srav_epi64(k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_srl_epi16(k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_srl_epi32(k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_srl_epi64(k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_srli_epi16<const IMM8: i32>(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_srli_epi32<const IMM8: u32>(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_srli_epi64<const IMM8: u32>(k: __mmask8, a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_srlv_epi16(k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_srlv_epi32(k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_srlv_epi64(k: __mmask8, a: __m128i, count: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_sub_epi16(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_sub_epi32(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_sub_epi64(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_sub_epi8(k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_sub_pd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_sub_ps(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_sub_round_sd<const ROUNDING: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_sub_round_ss<const ROUNDING: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_maskz_sub_sd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_maskz_sub_ss(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_maskz_subs_epi16(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_subs_epi8(k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_subs_epu16(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_subs_epu8(k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_ternarylogic_epi32<const IMM8: i32>(k: __mmask8, a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_ternarylogic_epi64<const IMM8: i32>(k: __mmask8, a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_unpackhi_epi16(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_unpackhi_epi32(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_unpackhi_epi64(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_unpackhi_epi8(k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_unpackhi_pd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_unpackhi_ps(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_maskz_unpacklo_epi16(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_unpacklo_epi32(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_unpacklo_epi64(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_maskz_unpacklo_epi8(k: __mmask16, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_unpacklo_pd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_maskz_unpacklo_ps(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_maskz_xor_epi32(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_maskz_xor_epi64(k: __mmask8, a: __m128i, b: __m128i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_maskz_xor_pd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d;
+        [avx512dq, avx512vl] safe fn _mm_maskz_xor_ps(k: __mmask8, a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_max_epi64(a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_max_epu64(a: __m128i, b: __m128i) -> __m128i;
+        [avx512f] safe fn _mm_max_round_sd<const SAE: i32>(a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_max_round_ss<const SAE: i32>(a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_min_epi64(a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_min_epu64(a: __m128i, b: __m128i) -> __m128i;
+        [avx512f] safe fn _mm_min_round_sd<const SAE: i32>(a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_min_round_ss<const SAE: i32>(a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] unsafe fn _mm_mmask_i32gather_epi32<const SCALE: i32>(src: __m128i, k: __mmask8, vindex: __m128i, base_addr: *const i32) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_mmask_i32gather_epi64<const SCALE: i32>(src: __m128i, k: __mmask8, vindex: __m128i, base_addr: *const i64) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_mmask_i32gather_pd<const SCALE: i32>(src: __m128d, k: __mmask8, vindex: __m128i, base_addr: *const f64) -> __m128d;
+        [avx512f, avx512vl] unsafe fn _mm_mmask_i32gather_ps<const SCALE: i32>(src: __m128, k: __mmask8, vindex: __m128i, base_addr: *const f32) -> __m128;
+        [avx512f, avx512vl] unsafe fn _mm_mmask_i64gather_epi32<const SCALE: i32>(src: __m128i, k: __mmask8, vindex: __m128i, base_addr: *const i32) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_mmask_i64gather_epi64<const SCALE: i32>(src: __m128i, k: __mmask8, vindex: __m128i, base_addr: *const i64) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_mmask_i64gather_pd<const SCALE: i32>(src: __m128d, k: __mmask8, vindex: __m128i, base_addr: *const f64) -> __m128d;
+        [avx512f, avx512vl] unsafe fn _mm_mmask_i64gather_ps<const SCALE: i32>(src: __m128, k: __mmask8, vindex: __m128i, base_addr: *const f32) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_movepi16_mask(a: __m128i) -> __mmask8;
+        [avx512dq, avx512vl] safe fn _mm_movepi32_mask(a: __m128i) -> __mmask8;
+        [avx512dq, avx512vl] safe fn _mm_movepi64_mask(a: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_movepi8_mask(a: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_movm_epi16(k: __mmask8) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_movm_epi32(k: __mmask8) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_movm_epi64(k: __mmask8) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_movm_epi8(k: __mmask16) -> __m128i;
+        [avx512f] safe fn _mm_mul_round_sd<const ROUNDING: i32>(a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_mul_round_ss<const ROUNDING: i32>(a: __m128, b: __m128) -> __m128;
+        [avx512dq, avx512vl] safe fn _mm_mullo_epi64(a: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi, avx512vl] safe fn _mm_multishift_epi64_epi8(a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_or_epi32(a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_or_epi64(a: __m128i, b: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_permutex2var_epi16(a: __m128i, idx: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_permutex2var_epi32(a: __m128i, idx: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_permutex2var_epi64(a: __m128i, idx: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi, avx512vl] safe fn _mm_permutex2var_epi8(a: __m128i, idx: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_permutex2var_pd(a: __m128d, idx: __m128i, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_permutex2var_ps(a: __m128, idx: __m128i, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_permutexvar_epi16(idx: __m128i, a: __m128i) -> __m128i;
+        [avx512vbmi, avx512vl] safe fn _mm_permutexvar_epi8(idx: __m128i, a: __m128i) -> __m128i;
+        [avx512bitalg, avx512vl] safe fn _mm_popcnt_epi16(a: __m128i) -> __m128i;
+        [avx512vpopcntdq, avx512vl] safe fn _mm_popcnt_epi32(a: __m128i) -> __m128i;
+        [avx512vpopcntdq, avx512vl] safe fn _mm_popcnt_epi64(a: __m128i) -> __m128i;
+        [avx512bitalg, avx512vl] safe fn _mm_popcnt_epi8(a: __m128i) -> __m128i;
+        [avx512dq, avx512vl] safe fn _mm_range_pd<const IMM8: i32>(a: __m128d, b: __m128d) -> __m128d;
+        [avx512dq, avx512vl] safe fn _mm_range_ps<const IMM8: i32>(a: __m128, b: __m128) -> __m128;
+        [avx512dq] safe fn _mm_range_round_sd<const IMM8: i32, const SAE: i32>(a: __m128d, b: __m128d) -> __m128d;
+        [avx512dq] safe fn _mm_range_round_ss<const IMM8: i32, const SAE: i32>(a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_rcp14_pd(a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_rcp14_ps(a: __m128) -> __m128;
+        [avx512f] safe fn _mm_rcp14_sd(a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_rcp14_ss(a: __m128, b: __m128) -> __m128;
+        [avx512bw, avx512vl] safe fn _mm_reduce_add_epi16(a: __m128i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm_reduce_add_epi8(a: __m128i) -> i8;
+        [avx512bw, avx512vl] safe fn _mm_reduce_and_epi16(a: __m128i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm_reduce_and_epi8(a: __m128i) -> i8;
+        [avx512bw, avx512vl] safe fn _mm_reduce_max_epi16(a: __m128i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm_reduce_max_epi8(a: __m128i) -> i8;
+        [avx512bw, avx512vl] safe fn _mm_reduce_max_epu16(a: __m128i) -> u16;
+        [avx512bw, avx512vl] safe fn _mm_reduce_max_epu8(a: __m128i) -> u8;
+        [avx512bw, avx512vl] safe fn _mm_reduce_min_epi16(a: __m128i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm_reduce_min_epi8(a: __m128i) -> i8;
+        [avx512bw, avx512vl] safe fn _mm_reduce_min_epu16(a: __m128i) -> u16;
+        [avx512bw, avx512vl] safe fn _mm_reduce_min_epu8(a: __m128i) -> u8;
+        [avx512bw, avx512vl] safe fn _mm_reduce_mul_epi16(a: __m128i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm_reduce_mul_epi8(a: __m128i) -> i8;
+        [avx512bw, avx512vl] safe fn _mm_reduce_or_epi16(a: __m128i) -> i16;
+        [avx512bw, avx512vl] safe fn _mm_reduce_or_epi8(a: __m128i) -> i8;
+        [avx512dq, avx512vl] safe fn _mm_reduce_pd<const IMM8: i32>(a: __m128d) -> __m128d;
+        [avx512dq, avx512vl] safe fn _mm_reduce_ps<const IMM8: i32>(a: __m128) -> __m128;
+        [avx512dq] safe fn _mm_reduce_round_sd<const IMM8: i32, const SAE: i32>(a: __m128d, b: __m128d) -> __m128d;
+        [avx512dq] safe fn _mm_reduce_round_ss<const IMM8: i32, const SAE: i32>(a: __m128, b: __m128) -> __m128;
+        [avx512dq] safe fn _mm_reduce_sd<const IMM8: i32>(a: __m128d, b: __m128d) -> __m128d;
+        [avx512dq] safe fn _mm_reduce_ss<const IMM8: i32>(a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_rol_epi32<const IMM8: i32>(a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_rol_epi64<const IMM8: i32>(a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_rolv_epi32(a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_rolv_epi64(a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_ror_epi32<const IMM8: i32>(a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_ror_epi64<const IMM8: i32>(a: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_rorv_epi32(a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_rorv_epi64(a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_roundscale_pd<const IMM8: i32>(a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_roundscale_ps<const IMM8: i32>(a: __m128) -> __m128;
+        [avx512f] safe fn _mm_roundscale_round_sd<const IMM8: i32, const SAE: i32>(a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_roundscale_round_ss<const IMM8: i32, const SAE: i32>(a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_roundscale_sd<const IMM8: i32>(a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_roundscale_ss<const IMM8: i32>(a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_rsqrt14_pd(a: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_rsqrt14_ps(a: __m128) -> __m128;
+        [avx512f] safe fn _mm_rsqrt14_sd(a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_rsqrt14_ss(a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_scalef_pd(a: __m128d, b: __m128d) -> __m128d;
+        [avx512f, avx512vl] safe fn _mm_scalef_ps(a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_scalef_round_sd<const ROUNDING: i32>(a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_scalef_round_ss<const ROUNDING: i32>(a: __m128, b: __m128) -> __m128;
+        [avx512f] safe fn _mm_scalef_sd(a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_scalef_ss(a: __m128, b: __m128) -> __m128;
+        [avx512vbmi2, avx512vl] safe fn _mm_shldi_epi16<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_shldi_epi32<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_shldi_epi64<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_shldv_epi16(a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_shldv_epi32(a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_shldv_epi64(a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_shrdi_epi16<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_shrdi_epi32<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_shrdi_epi64<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_shrdv_epi16(a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_shrdv_epi32(a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [avx512vbmi2, avx512vl] safe fn _mm_shrdv_epi64(a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_sllv_epi16(a: __m128i, count: __m128i) -> __m128i;
+        [sm3, avx] safe fn _mm_sm3msg1_epi32(a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [sm3, avx] safe fn _mm_sm3msg2_epi32(a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [sm3, avx] safe fn _mm_sm3rnds2_epi32<const IMM8: i32>(a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [sm4, avx] safe fn _mm_sm4key4_epi32(a: __m128i, b: __m128i) -> __m128i;
+        [sm4, avx] safe fn _mm_sm4rnds4_epi32(a: __m128i, b: __m128i) -> __m128i;
+        [avx512f] safe fn _mm_sqrt_round_sd<const ROUNDING: i32>(a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_sqrt_round_ss<const ROUNDING: i32>(a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_sra_epi64(a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_srai_epi64<const IMM8: u32>(a: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_srav_epi16(a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_srav_epi64(a: __m128i, count: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_srlv_epi16(a: __m128i, count: __m128i) -> __m128i;
+        [avx512f, avx512vl] unsafe fn _mm_store_epi32(mem_addr: *mut i32, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_store_epi64(mem_addr: *mut i64, a: __m128i);
+        [avx512bw, avx512vl] unsafe fn _mm_storeu_epi16(mem_addr: *mut i16, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_storeu_epi32(mem_addr: *mut i32, a: __m128i);
+        [avx512f, avx512vl] unsafe fn _mm_storeu_epi64(mem_addr: *mut i64, a: __m128i);
+        [avx512bw, avx512vl] unsafe fn _mm_storeu_epi8(mem_addr: *mut i8, a: __m128i);
+        [avx512f] safe fn _mm_sub_round_sd<const ROUNDING: i32>(a: __m128d, b: __m128d) -> __m128d;
+        [avx512f] safe fn _mm_sub_round_ss<const ROUNDING: i32>(a: __m128, b: __m128) -> __m128;
+        [avx512f, avx512vl] safe fn _mm_ternarylogic_epi32<const IMM8: i32>(a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_ternarylogic_epi64<const IMM8: i32>(a: __m128i, b: __m128i, c: __m128i) -> __m128i;
+        [avx512bw, avx512vl] safe fn _mm_test_epi16_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_test_epi32_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_test_epi64_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_test_epi8_mask(a: __m128i, b: __m128i) -> __mmask16;
+        [avx512bw, avx512vl] safe fn _mm_testn_epi16_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_testn_epi32_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512f, avx512vl] safe fn _mm_testn_epi64_mask(a: __m128i, b: __m128i) -> __mmask8;
+        [avx512bw, avx512vl] safe fn _mm_testn_epi8_mask(a: __m128i, b: __m128i) -> __mmask16;
+        [avx512f, avx512vl] safe fn _mm_xor_epi32(a: __m128i, b: __m128i) -> __m128i;
+        [avx512f, avx512vl] safe fn _mm_xor_epi64(a: __m128i, b: __m128i) -> __m128i;
+        [avx512f] unsafe fn _store_mask16(mem_addr: *mut __mmask16, a: __mmask16);
+        [avx512bw] unsafe fn _store_mask32(mem_addr: *mut __mmask32, a: __mmask32);
+        [avx512bw] unsafe fn _store_mask64(mem_addr: *mut __mmask64, a: __mmask64);
+        [avx512dq] unsafe fn _store_mask8(mem_addr: *mut __mmask8, a: __mmask8);
+    }
+}
+
+#[rustversion::since(1.89)]
+pub use since_1_89::*;
+
+#[rustversion::since(1.94)]
+mod since_1_94 {
+    use super::arch;
+    use super::arch::*;
+
+    intrinsics! {
+        [avx512fp16, avx512vl] safe fn _mm256_abs_ph(v2: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_add_ph(a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16] safe fn _mm256_castpd_ph(a: __m256d) -> __m256h;
+        [avx512fp16] safe fn _mm256_castph128_ph256(a: __m128h) -> __m256h;
+        [avx512fp16] safe fn _mm256_castph256_ph128(a: __m256h) -> __m128h;
+        [avx512fp16] safe fn _mm256_castph_pd(a: __m256h) -> __m256d;
+        [avx512fp16] safe fn _mm256_castph_ps(a: __m256h) -> __m256;
+        [avx512fp16] safe fn _mm256_castph_si256(a: __m256h) -> __m256i;
+        [avx512fp16] safe fn _mm256_castps_ph(a: __m256) -> __m256h;
+        [avx512fp16] safe fn _mm256_castsi256_ph(a: __m256i) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_cmp_ph_mask<const IMM5: i32>(a: __m256h, b: __m256h) -> __mmask16;
+        [avx512fp16, avx512vl] safe fn _mm256_cmul_pch(a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_conj_pch(a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_cvtepi16_ph(a: __m256i) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_cvtepi32_ph(a: __m256i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm256_cvtepi64_ph(a: __m256i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm256_cvtepu16_ph(a: __m256i) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_cvtepu32_ph(a: __m256i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm256_cvtepu64_ph(a: __m256i) -> __m128h;
+        [avxneconvert] unsafe fn _mm256_cvtneeph_ps(a: *const __m256h) -> __m256;
+        [avxneconvert] unsafe fn _mm256_cvtneoph_ps(a: *const __m256h) -> __m256;
+        [avx512fp16, avx512vl] safe fn _mm256_cvtpd_ph(a: __m256d) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm256_cvtph_epi16(a: __m256h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_cvtph_epi32(a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_cvtph_epi64(a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_cvtph_epu16(a: __m256h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_cvtph_epu32(a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_cvtph_epu64(a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_cvtph_pd(a: __m128h) -> __m256d;
+        [avx512fp16, avx512vl] safe fn _mm256_cvttph_epi16(a: __m256h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_cvttph_epi32(a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_cvttph_epi64(a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_cvttph_epu16(a: __m256h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_cvttph_epu32(a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_cvttph_epu64(a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_cvtxph_ps(a: __m128h) -> __m256;
+        [avx512fp16, avx512vl] safe fn _mm256_cvtxps_ph(a: __m256) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm256_div_ph(a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_fcmadd_pch(a: __m256h, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_fcmul_pch(a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_fmadd_pch(a: __m256h, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_fmadd_ph(a: __m256h, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_fmaddsub_ph(a: __m256h, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_fmsub_ph(a: __m256h, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_fmsubadd_ph(a: __m256h, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_fmul_pch(a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_fnmadd_ph(a: __m256h, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_fnmsub_ph(a: __m256h, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_fpclass_ph_mask<const IMM8: i32>(a: __m256h) -> __mmask16;
+        [avx512fp16, avx512vl] safe fn _mm256_getexp_ph(a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_getmant_ph<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask3_fcmadd_pch(a: __m256h, b: __m256h, c: __m256h, k: __mmask8) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask3_fmadd_pch(a: __m256h, b: __m256h, c: __m256h, k: __mmask8) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask3_fmadd_ph(a: __m256h, b: __m256h, c: __m256h, k: __mmask16) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask3_fmaddsub_ph(a: __m256h, b: __m256h, c: __m256h, k: __mmask16) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask3_fmsub_ph(a: __m256h, b: __m256h, c: __m256h, k: __mmask16) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask3_fmsubadd_ph(a: __m256h, b: __m256h, c: __m256h, k: __mmask16) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask3_fnmadd_ph(a: __m256h, b: __m256h, c: __m256h, k: __mmask16) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask3_fnmsub_ph(a: __m256h, b: __m256h, c: __m256h, k: __mmask16) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_add_ph(src: __m256h, k: __mmask16, a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_blend_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cmp_ph_mask<const IMM5: i32>(k1: __mmask16, a: __m256h, b: __m256h) -> __mmask16;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cmul_pch(src: __m256h, k: __mmask8, a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_conj_pch(src: __m256h, k: __mmask8, a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cvtepi16_ph(src: __m256h, k: __mmask16, a: __m256i) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cvtepi32_ph(src: __m128h, k: __mmask8, a: __m256i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cvtepi64_ph(src: __m128h, k: __mmask8, a: __m256i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cvtepu16_ph(src: __m256h, k: __mmask16, a: __m256i) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cvtepu32_ph(src: __m128h, k: __mmask8, a: __m256i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cvtepu64_ph(src: __m128h, k: __mmask8, a: __m256i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cvtpd_ph(src: __m128h, k: __mmask8, a: __m256d) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cvtph_epi16(src: __m256i, k: __mmask16, a: __m256h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cvtph_epi32(src: __m256i, k: __mmask8, a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cvtph_epi64(src: __m256i, k: __mmask8, a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cvtph_epu16(src: __m256i, k: __mmask16, a: __m256h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cvtph_epu32(src: __m256i, k: __mmask8, a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cvtph_epu64(src: __m256i, k: __mmask8, a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cvtph_pd(src: __m256d, k: __mmask8, a: __m128h) -> __m256d;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cvttph_epi16(src: __m256i, k: __mmask16, a: __m256h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cvttph_epi32(src: __m256i, k: __mmask8, a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cvttph_epi64(src: __m256i, k: __mmask8, a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cvttph_epu16(src: __m256i, k: __mmask16, a: __m256h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cvttph_epu32(src: __m256i, k: __mmask8, a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cvttph_epu64(src: __m256i, k: __mmask8, a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cvtxph_ps(src: __m256, k: __mmask8, a: __m128h) -> __m256;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_cvtxps_ph(src: __m128h, k: __mmask8, a: __m256) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_div_ph(src: __m256h, k: __mmask16, a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_fcmadd_pch(a: __m256h, k: __mmask8, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_fcmul_pch(src: __m256h, k: __mmask8, a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_fmadd_pch(a: __m256h, k: __mmask8, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_fmadd_ph(a: __m256h, k: __mmask16, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_fmaddsub_ph(a: __m256h, k: __mmask16, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_fmsub_ph(a: __m256h, k: __mmask16, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_fmsubadd_ph(a: __m256h, k: __mmask16, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_fmul_pch(src: __m256h, k: __mmask8, a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_fnmadd_ph(a: __m256h, k: __mmask16, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_fnmsub_ph(a: __m256h, k: __mmask16, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_fpclass_ph_mask<const IMM8: i32>(k1: __mmask16, a: __m256h) -> __mmask16;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_getexp_ph(src: __m256h, k: __mmask16, a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_getmant_ph<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(src: __m256h, k: __mmask16, a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_max_ph(src: __m256h, k: __mmask16, a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_min_ph(src: __m256h, k: __mmask16, a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_mul_pch(src: __m256h, k: __mmask8, a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_mul_ph(src: __m256h, k: __mmask16, a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_rcp_ph(src: __m256h, k: __mmask16, a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_reduce_ph<const IMM8: i32>(src: __m256h, k: __mmask16, a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_roundscale_ph<const IMM8: i32>(src: __m256h, k: __mmask16, a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_rsqrt_ph(src: __m256h, k: __mmask16, a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_scalef_ph(src: __m256h, k: __mmask16, a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_sqrt_ph(src: __m256h, k: __mmask16, a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mask_sub_ph(src: __m256h, k: __mmask16, a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_add_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_cmul_pch(k: __mmask8, a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_conj_pch(k: __mmask8, a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_cvtepi16_ph(k: __mmask16, a: __m256i) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_cvtepi32_ph(k: __mmask8, a: __m256i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_cvtepi64_ph(k: __mmask8, a: __m256i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_cvtepu16_ph(k: __mmask16, a: __m256i) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_cvtepu32_ph(k: __mmask8, a: __m256i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_cvtepu64_ph(k: __mmask8, a: __m256i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_cvtpd_ph(k: __mmask8, a: __m256d) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_cvtph_epi16(k: __mmask16, a: __m256h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_cvtph_epi32(k: __mmask8, a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_cvtph_epi64(k: __mmask8, a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_cvtph_epu16(k: __mmask16, a: __m256h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_cvtph_epu32(k: __mmask8, a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_cvtph_epu64(k: __mmask8, a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_cvtph_pd(k: __mmask8, a: __m128h) -> __m256d;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_cvttph_epi16(k: __mmask16, a: __m256h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_cvttph_epi32(k: __mmask8, a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_cvttph_epi64(k: __mmask8, a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_cvttph_epu16(k: __mmask16, a: __m256h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_cvttph_epu32(k: __mmask8, a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_cvttph_epu64(k: __mmask8, a: __m128h) -> __m256i;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_cvtxph_ps(k: __mmask8, a: __m128h) -> __m256;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_cvtxps_ph(k: __mmask8, a: __m256) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_div_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_fcmadd_pch(k: __mmask8, a: __m256h, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_fcmul_pch(k: __mmask8, a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_fmadd_pch(k: __mmask8, a: __m256h, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_fmadd_ph(k: __mmask16, a: __m256h, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_fmaddsub_ph(k: __mmask16, a: __m256h, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_fmsub_ph(k: __mmask16, a: __m256h, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_fmsubadd_ph(k: __mmask16, a: __m256h, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_fmul_pch(k: __mmask8, a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_fnmadd_ph(k: __mmask16, a: __m256h, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_fnmsub_ph(k: __mmask16, a: __m256h, b: __m256h, c: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_getexp_ph(k: __mmask16, a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_getmant_ph<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(k: __mmask16, a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_max_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_min_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_mul_pch(k: __mmask8, a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_mul_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_rcp_ph(k: __mmask16, a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_reduce_ph<const IMM8: i32>(k: __mmask16, a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_roundscale_ph<const IMM8: i32>(k: __mmask16, a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_rsqrt_ph(k: __mmask16, a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_scalef_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_sqrt_ph(k: __mmask16, a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_maskz_sub_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_max_ph(a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_min_ph(a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mul_pch(a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_mul_ph(a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_permutex2var_ph(a: __m256h, idx: __m256i, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_permutexvar_ph(idx: __m256i, a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_rcp_ph(a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_reduce_ph<const IMM8: i32>(a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_roundscale_ph<const IMM8: i32>(a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_rsqrt_ph(a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_scalef_ph(a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_setzero_ph() -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_sqrt_ph(a: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_sub_ph(a: __m256h, b: __m256h) -> __m256h;
+        [avx512fp16, avx512vl] safe fn _mm256_undefined_ph() -> __m256h;
+        [avx512fp16] safe fn _mm256_zextph128_ph256(a: __m128h) -> __m256h;
+        [avx512fp16] safe fn _mm512_abs_ph(v2: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_add_ph(a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_add_round_ph<const ROUNDING: i32>(a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_castpd_ph(a: __m512d) -> __m512h;
+        [avx512fp16] safe fn _mm512_castph128_ph512(a: __m128h) -> __m512h;
+        [avx512fp16] safe fn _mm512_castph256_ph512(a: __m256h) -> __m512h;
+        [avx512fp16] safe fn _mm512_castph512_ph128(a: __m512h) -> __m128h;
+        [avx512fp16] safe fn _mm512_castph512_ph256(a: __m512h) -> __m256h;
+        [avx512fp16] safe fn _mm512_castph_pd(a: __m512h) -> __m512d;
+        [avx512fp16] safe fn _mm512_castph_ps(a: __m512h) -> __m512;
+        [avx512fp16] safe fn _mm512_castph_si512(a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_castps_ph(a: __m512) -> __m512h;
+        [avx512fp16] safe fn _mm512_castsi512_ph(a: __m512i) -> __m512h;
+        [avx512fp16] safe fn _mm512_cmp_ph_mask<const IMM5: i32>(a: __m512h, b: __m512h) -> __mmask32;
+        [avx512fp16] safe fn _mm512_cmp_round_ph_mask<const IMM5: i32, const SAE: i32>(a: __m512h, b: __m512h) -> __mmask32;
+        [avx512fp16] safe fn _mm512_cmul_pch(a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_cmul_round_pch<const ROUNDING: i32>(a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_conj_pch(a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_cvt_roundepi16_ph<const ROUNDING: i32>(a: __m512i) -> __m512h;
+        [avx512fp16] safe fn _mm512_cvt_roundepi32_ph<const ROUNDING: i32>(a: __m512i) -> __m256h;
+        [avx512fp16] safe fn _mm512_cvt_roundepi64_ph<const ROUNDING: i32>(a: __m512i) -> __m128h;
+        [avx512fp16] safe fn _mm512_cvt_roundepu16_ph<const ROUNDING: i32>(a: __m512i) -> __m512h;
+        [avx512fp16] safe fn _mm512_cvt_roundepu32_ph<const ROUNDING: i32>(a: __m512i) -> __m256h;
+        [avx512fp16] safe fn _mm512_cvt_roundepu64_ph<const ROUNDING: i32>(a: __m512i) -> __m128h;
+        [avx512fp16] safe fn _mm512_cvt_roundpd_ph<const ROUNDING: i32>(a: __m512d) -> __m128h;
+        [avx512fp16] safe fn _mm512_cvt_roundph_epi16<const ROUNDING: i32>(a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvt_roundph_epi32<const ROUNDING: i32>(a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvt_roundph_epi64<const ROUNDING: i32>(a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvt_roundph_epu16<const SAE: i32>(a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvt_roundph_epu32<const ROUNDING: i32>(a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvt_roundph_epu64<const ROUNDING: i32>(a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvt_roundph_pd<const SAE: i32>(a: __m128h) -> __m512d;
+        [avx512fp16] safe fn _mm512_cvtepi16_ph(a: __m512i) -> __m512h;
+        [avx512fp16] safe fn _mm512_cvtepi32_ph(a: __m512i) -> __m256h;
+        [avx512fp16] safe fn _mm512_cvtepi64_ph(a: __m512i) -> __m128h;
+        [avx512fp16] safe fn _mm512_cvtepu16_ph(a: __m512i) -> __m512h;
+        [avx512fp16] safe fn _mm512_cvtepu32_ph(a: __m512i) -> __m256h;
+        [avx512fp16] safe fn _mm512_cvtepu64_ph(a: __m512i) -> __m128h;
+        [avx512fp16] safe fn _mm512_cvtpd_ph(a: __m512d) -> __m128h;
+        [avx512fp16] safe fn _mm512_cvtph_epi16(a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvtph_epi32(a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvtph_epi64(a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvtph_epu16(a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvtph_epu32(a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvtph_epu64(a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvtph_pd(a: __m128h) -> __m512d;
+        [avx512fp16] safe fn _mm512_cvtt_roundph_epi16<const SAE: i32>(a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvtt_roundph_epi32<const SAE: i32>(a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvtt_roundph_epi64<const SAE: i32>(a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvtt_roundph_epu16<const SAE: i32>(a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvtt_roundph_epu32<const SAE: i32>(a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvtt_roundph_epu64<const SAE: i32>(a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvttph_epi16(a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvttph_epi32(a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvttph_epi64(a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvttph_epu16(a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvttph_epu32(a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvttph_epu64(a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_cvtx_roundph_ps<const SAE: i32>(a: __m256h) -> __m512;
+        [avx512fp16] safe fn _mm512_cvtx_roundps_ph<const ROUNDING: i32>(a: __m512) -> __m256h;
+        [avx512fp16] safe fn _mm512_cvtxph_ps(a: __m256h) -> __m512;
+        [avx512fp16] safe fn _mm512_cvtxps_ph(a: __m512) -> __m256h;
+        [avx512fp16] safe fn _mm512_div_ph(a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_div_round_ph<const ROUNDING: i32>(a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_fcmadd_pch(a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_fcmadd_round_pch<const ROUNDING: i32>(a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_fcmul_pch(a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_fcmul_round_pch<const ROUNDING: i32>(a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_fmadd_pch(a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_fmadd_ph(a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_fmadd_round_pch<const ROUNDING: i32>(a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_fmadd_round_ph<const ROUNDING: i32>(a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_fmaddsub_ph(a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_fmaddsub_round_ph<const ROUNDING: i32>(a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_fmsub_ph(a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_fmsub_round_ph<const ROUNDING: i32>(a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_fmsubadd_ph(a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_fmsubadd_round_ph<const ROUNDING: i32>(a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_fmul_pch(a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_fmul_round_pch<const ROUNDING: i32>(a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_fnmadd_ph(a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_fnmadd_round_ph<const ROUNDING: i32>(a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_fnmsub_ph(a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_fnmsub_round_ph<const ROUNDING: i32>(a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_fpclass_ph_mask<const IMM8: i32>(a: __m512h) -> __mmask32;
+        [avx512fp16] safe fn _mm512_getexp_ph(a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_getexp_round_ph<const SAE: i32>(a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_getmant_ph<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_getmant_round_ph<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM, const SAE: i32>(a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask3_fcmadd_pch(a: __m512h, b: __m512h, c: __m512h, k: __mmask16) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask3_fcmadd_round_pch<const ROUNDING: i32>(a: __m512h, b: __m512h, c: __m512h, k: __mmask16) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask3_fmadd_pch(a: __m512h, b: __m512h, c: __m512h, k: __mmask16) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask3_fmadd_ph(a: __m512h, b: __m512h, c: __m512h, k: __mmask32) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask3_fmadd_round_pch<const ROUNDING: i32>(a: __m512h, b: __m512h, c: __m512h, k: __mmask16) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask3_fmadd_round_ph<const ROUNDING: i32>(a: __m512h, b: __m512h, c: __m512h, k: __mmask32) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask3_fmaddsub_ph(a: __m512h, b: __m512h, c: __m512h, k: __mmask32) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask3_fmaddsub_round_ph<const ROUNDING: i32>(a: __m512h, b: __m512h, c: __m512h, k: __mmask32) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask3_fmsub_ph(a: __m512h, b: __m512h, c: __m512h, k: __mmask32) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask3_fmsub_round_ph<const ROUNDING: i32>(a: __m512h, b: __m512h, c: __m512h, k: __mmask32) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask3_fmsubadd_ph(a: __m512h, b: __m512h, c: __m512h, k: __mmask32) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask3_fmsubadd_round_ph<const ROUNDING: i32>(a: __m512h, b: __m512h, c: __m512h, k: __mmask32) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask3_fnmadd_ph(a: __m512h, b: __m512h, c: __m512h, k: __mmask32) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask3_fnmadd_round_ph<const ROUNDING: i32>(a: __m512h, b: __m512h, c: __m512h, k: __mmask32) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask3_fnmsub_ph(a: __m512h, b: __m512h, c: __m512h, k: __mmask32) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask3_fnmsub_round_ph<const ROUNDING: i32>(a: __m512h, b: __m512h, c: __m512h, k: __mmask32) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_add_ph(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_add_round_ph<const ROUNDING: i32>(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_blend_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_cmp_ph_mask<const IMM5: i32>(k1: __mmask32, a: __m512h, b: __m512h) -> __mmask32;
+        [avx512fp16] safe fn _mm512_mask_cmp_round_ph_mask<const IMM5: i32, const SAE: i32>(k1: __mmask32, a: __m512h, b: __m512h) -> __mmask32;
+        [avx512fp16] safe fn _mm512_mask_cmul_pch(src: __m512h, k: __mmask16, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_cmul_round_pch<const ROUNDING: i32>(src: __m512h, k: __mmask16, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_conj_pch(src: __m512h, k: __mmask16, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_cvt_roundepi16_ph<const ROUNDING: i32>(src: __m512h, k: __mmask32, a: __m512i) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_cvt_roundepi32_ph<const ROUNDING: i32>(src: __m256h, k: __mmask16, a: __m512i) -> __m256h;
+        [avx512fp16] safe fn _mm512_mask_cvt_roundepi64_ph<const ROUNDING: i32>(src: __m128h, k: __mmask8, a: __m512i) -> __m128h;
+        [avx512fp16] safe fn _mm512_mask_cvt_roundepu16_ph<const ROUNDING: i32>(src: __m512h, k: __mmask32, a: __m512i) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_cvt_roundepu32_ph<const ROUNDING: i32>(src: __m256h, k: __mmask16, a: __m512i) -> __m256h;
+        [avx512fp16] safe fn _mm512_mask_cvt_roundepu64_ph<const ROUNDING: i32>(src: __m128h, k: __mmask8, a: __m512i) -> __m128h;
+        [avx512fp16] safe fn _mm512_mask_cvt_roundpd_ph<const ROUNDING: i32>(src: __m128h, k: __mmask8, a: __m512d) -> __m128h;
+        [avx512fp16] safe fn _mm512_mask_cvt_roundph_epi16<const ROUNDING: i32>(src: __m512i, k: __mmask32, a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvt_roundph_epi32<const ROUNDING: i32>(src: __m512i, k: __mmask16, a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvt_roundph_epi64<const ROUNDING: i32>(src: __m512i, k: __mmask8, a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvt_roundph_epu16<const SAE: i32>(src: __m512i, k: __mmask32, a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvt_roundph_epu32<const ROUNDING: i32>(src: __m512i, k: __mmask16, a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvt_roundph_epu64<const ROUNDING: i32>(src: __m512i, k: __mmask8, a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvt_roundph_pd<const SAE: i32>(src: __m512d, k: __mmask8, a: __m128h) -> __m512d;
+        [avx512fp16] safe fn _mm512_mask_cvtepi16_ph(src: __m512h, k: __mmask32, a: __m512i) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_cvtepi32_ph(src: __m256h, k: __mmask16, a: __m512i) -> __m256h;
+        [avx512fp16] safe fn _mm512_mask_cvtepi64_ph(src: __m128h, k: __mmask8, a: __m512i) -> __m128h;
+        [avx512fp16] safe fn _mm512_mask_cvtepu16_ph(src: __m512h, k: __mmask32, a: __m512i) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_cvtepu32_ph(src: __m256h, k: __mmask16, a: __m512i) -> __m256h;
+        [avx512fp16] safe fn _mm512_mask_cvtepu64_ph(src: __m128h, k: __mmask8, a: __m512i) -> __m128h;
+        [avx512fp16] safe fn _mm512_mask_cvtpd_ph(src: __m128h, k: __mmask8, a: __m512d) -> __m128h;
+        [avx512fp16] safe fn _mm512_mask_cvtph_epi16(src: __m512i, k: __mmask32, a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvtph_epi32(src: __m512i, k: __mmask16, a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvtph_epi64(src: __m512i, k: __mmask8, a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvtph_epu16(src: __m512i, k: __mmask32, a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvtph_epu32(src: __m512i, k: __mmask16, a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvtph_epu64(src: __m512i, k: __mmask8, a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvtph_pd(src: __m512d, k: __mmask8, a: __m128h) -> __m512d;
+        [avx512fp16] safe fn _mm512_mask_cvtt_roundph_epi16<const SAE: i32>(src: __m512i, k: __mmask32, a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvtt_roundph_epi32<const SAE: i32>(src: __m512i, k: __mmask16, a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvtt_roundph_epi64<const SAE: i32>(src: __m512i, k: __mmask8, a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvtt_roundph_epu16<const SAE: i32>(src: __m512i, k: __mmask32, a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvtt_roundph_epu32<const SAE: i32>(src: __m512i, k: __mmask16, a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvtt_roundph_epu64<const SAE: i32>(src: __m512i, k: __mmask8, a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvttph_epi16(src: __m512i, k: __mmask32, a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvttph_epi32(src: __m512i, k: __mmask16, a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvttph_epi64(src: __m512i, k: __mmask8, a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvttph_epu16(src: __m512i, k: __mmask32, a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvttph_epu32(src: __m512i, k: __mmask16, a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvttph_epu64(src: __m512i, k: __mmask8, a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_mask_cvtx_roundph_ps<const SAE: i32>(src: __m512, k: __mmask16, a: __m256h) -> __m512;
+        [avx512fp16] safe fn _mm512_mask_cvtx_roundps_ph<const ROUNDING: i32>(src: __m256h, k: __mmask16, a: __m512) -> __m256h;
+        [avx512fp16] safe fn _mm512_mask_cvtxph_ps(src: __m512, k: __mmask16, a: __m256h) -> __m512;
+        [avx512fp16] safe fn _mm512_mask_cvtxps_ph(src: __m256h, k: __mmask16, a: __m512) -> __m256h;
+        [avx512fp16] safe fn _mm512_mask_div_ph(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_div_round_ph<const ROUNDING: i32>(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_fcmadd_pch(a: __m512h, k: __mmask16, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_fcmadd_round_pch<const ROUNDING: i32>(a: __m512h, k: __mmask16, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_fcmul_pch(src: __m512h, k: __mmask16, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_fcmul_round_pch<const ROUNDING: i32>(src: __m512h, k: __mmask16, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_fmadd_pch(a: __m512h, k: __mmask16, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_fmadd_ph(a: __m512h, k: __mmask32, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_fmadd_round_pch<const ROUNDING: i32>(a: __m512h, k: __mmask16, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_fmadd_round_ph<const ROUNDING: i32>(a: __m512h, k: __mmask32, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_fmaddsub_ph(a: __m512h, k: __mmask32, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_fmaddsub_round_ph<const ROUNDING: i32>(a: __m512h, k: __mmask32, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_fmsub_ph(a: __m512h, k: __mmask32, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_fmsub_round_ph<const ROUNDING: i32>(a: __m512h, k: __mmask32, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_fmsubadd_ph(a: __m512h, k: __mmask32, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_fmsubadd_round_ph<const ROUNDING: i32>(a: __m512h, k: __mmask32, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_fmul_pch(src: __m512h, k: __mmask16, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_fmul_round_pch<const ROUNDING: i32>(src: __m512h, k: __mmask16, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_fnmadd_ph(a: __m512h, k: __mmask32, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_fnmadd_round_ph<const ROUNDING: i32>(a: __m512h, k: __mmask32, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_fnmsub_ph(a: __m512h, k: __mmask32, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_fnmsub_round_ph<const ROUNDING: i32>(a: __m512h, k: __mmask32, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_fpclass_ph_mask<const IMM8: i32>(k1: __mmask32, a: __m512h) -> __mmask32;
+        [avx512fp16] safe fn _mm512_mask_getexp_ph(src: __m512h, k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_getexp_round_ph<const SAE: i32>(src: __m512h, k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_getmant_ph<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(src: __m512h, k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_getmant_round_ph<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM, const SAE: i32>(src: __m512h, k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_max_ph(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_max_round_ph<const SAE: i32>(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_min_ph(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_min_round_ph<const SAE: i32>(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_mul_pch(src: __m512h, k: __mmask16, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_mul_ph(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_mul_round_pch<const ROUNDING: i32>(src: __m512h, k: __mmask16, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_mul_round_ph<const ROUNDING: i32>(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_rcp_ph(src: __m512h, k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_reduce_ph<const IMM8: i32>(src: __m512h, k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_reduce_round_ph<const IMM8: i32, const SAE: i32>(src: __m512h, k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_roundscale_ph<const IMM8: i32>(src: __m512h, k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_roundscale_round_ph<const IMM8: i32, const SAE: i32>(src: __m512h, k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_rsqrt_ph(src: __m512h, k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_scalef_ph(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_scalef_round_ph<const ROUNDING: i32>(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_sqrt_ph(src: __m512h, k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_sqrt_round_ph<const ROUNDING: i32>(src: __m512h, k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_sub_ph(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mask_sub_round_ph<const ROUNDING: i32>(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_add_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_add_round_ph<const ROUNDING: i32>(k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_cmul_pch(k: __mmask16, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_cmul_round_pch<const ROUNDING: i32>(k: __mmask16, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_conj_pch(k: __mmask16, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_cvt_roundepi16_ph<const ROUNDING: i32>(k: __mmask32, a: __m512i) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_cvt_roundepi32_ph<const ROUNDING: i32>(k: __mmask16, a: __m512i) -> __m256h;
+        [avx512fp16] safe fn _mm512_maskz_cvt_roundepi64_ph<const ROUNDING: i32>(k: __mmask8, a: __m512i) -> __m128h;
+        [avx512fp16] safe fn _mm512_maskz_cvt_roundepu16_ph<const ROUNDING: i32>(k: __mmask32, a: __m512i) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_cvt_roundepu32_ph<const ROUNDING: i32>(k: __mmask16, a: __m512i) -> __m256h;
+        [avx512fp16] safe fn _mm512_maskz_cvt_roundepu64_ph<const ROUNDING: i32>(k: __mmask8, a: __m512i) -> __m128h;
+        [avx512fp16] safe fn _mm512_maskz_cvt_roundpd_ph<const ROUNDING: i32>(k: __mmask8, a: __m512d) -> __m128h;
+        [avx512fp16] safe fn _mm512_maskz_cvt_roundph_epi16<const ROUNDING: i32>(k: __mmask32, a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvt_roundph_epi32<const ROUNDING: i32>(k: __mmask16, a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvt_roundph_epi64<const ROUNDING: i32>(k: __mmask8, a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvt_roundph_epu16<const SAE: i32>(k: __mmask32, a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvt_roundph_epu32<const ROUNDING: i32>(k: __mmask16, a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvt_roundph_epu64<const ROUNDING: i32>(k: __mmask8, a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvt_roundph_pd<const SAE: i32>(k: __mmask8, a: __m128h) -> __m512d;
+        [avx512fp16] safe fn _mm512_maskz_cvtepi16_ph(k: __mmask32, a: __m512i) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_cvtepi32_ph(k: __mmask16, a: __m512i) -> __m256h;
+        [avx512fp16] safe fn _mm512_maskz_cvtepi64_ph(k: __mmask8, a: __m512i) -> __m128h;
+        [avx512fp16] safe fn _mm512_maskz_cvtepu16_ph(k: __mmask32, a: __m512i) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_cvtepu32_ph(k: __mmask16, a: __m512i) -> __m256h;
+        [avx512fp16] safe fn _mm512_maskz_cvtepu64_ph(k: __mmask8, a: __m512i) -> __m128h;
+        [avx512fp16] safe fn _mm512_maskz_cvtpd_ph(k: __mmask8, a: __m512d) -> __m128h;
+        [avx512fp16] safe fn _mm512_maskz_cvtph_epi16(k: __mmask32, a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvtph_epi32(k: __mmask16, a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvtph_epi64(k: __mmask8, a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvtph_epu16(k: __mmask32, a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvtph_epu32(k: __mmask16, a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvtph_epu64(k: __mmask8, a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvtph_pd(k: __mmask8, a: __m128h) -> __m512d;
+        [avx512fp16] safe fn _mm512_maskz_cvtt_roundph_epi16<const SAE: i32>(k: __mmask32, a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvtt_roundph_epi32<const SAE: i32>(k: __mmask16, a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvtt_roundph_epi64<const SAE: i32>(k: __mmask8, a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvtt_roundph_epu16<const SAE: i32>(k: __mmask32, a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvtt_roundph_epu32<const SAE: i32>(k: __mmask16, a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvtt_roundph_epu64<const SAE: i32>(k: __mmask8, a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvttph_epi16(k: __mmask32, a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvttph_epi32(k: __mmask16, a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvttph_epi64(k: __mmask8, a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvttph_epu16(k: __mmask32, a: __m512h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvttph_epu32(k: __mmask16, a: __m256h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvttph_epu64(k: __mmask8, a: __m128h) -> __m512i;
+        [avx512fp16] safe fn _mm512_maskz_cvtx_roundph_ps<const SAE: i32>(k: __mmask16, a: __m256h) -> __m512;
+        [avx512fp16] safe fn _mm512_maskz_cvtx_roundps_ph<const ROUNDING: i32>(k: __mmask16, a: __m512) -> __m256h;
+        [avx512fp16] safe fn _mm512_maskz_cvtxph_ps(k: __mmask16, a: __m256h) -> __m512;
+        [avx512fp16] safe fn _mm512_maskz_cvtxps_ph(k: __mmask16, a: __m512) -> __m256h;
+        [avx512fp16] safe fn _mm512_maskz_div_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_div_round_ph<const ROUNDING: i32>(k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_fcmadd_pch(k: __mmask16, a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_fcmadd_round_pch<const ROUNDING: i32>(k: __mmask16, a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_fcmul_pch(k: __mmask16, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_fcmul_round_pch<const ROUNDING: i32>(k: __mmask16, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_fmadd_pch(k: __mmask16, a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_fmadd_ph(k: __mmask32, a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_fmadd_round_pch<const ROUNDING: i32>(k: __mmask16, a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_fmadd_round_ph<const ROUNDING: i32>(k: __mmask32, a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_fmaddsub_ph(k: __mmask32, a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_fmaddsub_round_ph<const ROUNDING: i32>(k: __mmask32, a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_fmsub_ph(k: __mmask32, a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_fmsub_round_ph<const ROUNDING: i32>(k: __mmask32, a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_fmsubadd_ph(k: __mmask32, a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_fmsubadd_round_ph<const ROUNDING: i32>(k: __mmask32, a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_fmul_pch(k: __mmask16, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_fmul_round_pch<const ROUNDING: i32>(k: __mmask16, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_fnmadd_ph(k: __mmask32, a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_fnmadd_round_ph<const ROUNDING: i32>(k: __mmask32, a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_fnmsub_ph(k: __mmask32, a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_fnmsub_round_ph<const ROUNDING: i32>(k: __mmask32, a: __m512h, b: __m512h, c: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_getexp_ph(k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_getexp_round_ph<const SAE: i32>(k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_getmant_ph<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_getmant_round_ph<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM, const SAE: i32>(k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_max_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_max_round_ph<const SAE: i32>(k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_min_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_min_round_ph<const SAE: i32>(k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_mul_pch(k: __mmask16, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_mul_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_mul_round_pch<const ROUNDING: i32>(k: __mmask16, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_mul_round_ph<const ROUNDING: i32>(k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_rcp_ph(k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_reduce_ph<const IMM8: i32>(k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_reduce_round_ph<const IMM8: i32, const SAE: i32>(k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_roundscale_ph<const IMM8: i32>(k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_roundscale_round_ph<const IMM8: i32, const SAE: i32>(k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_rsqrt_ph(k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_scalef_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_scalef_round_ph<const ROUNDING: i32>(k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_sqrt_ph(k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_sqrt_round_ph<const ROUNDING: i32>(k: __mmask32, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_sub_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_maskz_sub_round_ph<const ROUNDING: i32>(k: __mmask32, a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_max_ph(a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_max_round_ph<const SAE: i32>(a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_min_ph(a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_min_round_ph<const SAE: i32>(a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mul_pch(a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mul_ph(a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mul_round_pch<const ROUNDING: i32>(a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_mul_round_ph<const ROUNDING: i32>(a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_permutex2var_ph(a: __m512h, idx: __m512i, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_permutexvar_ph(idx: __m512i, a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_rcp_ph(a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_reduce_ph<const IMM8: i32>(a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_reduce_round_ph<const IMM8: i32, const SAE: i32>(a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_roundscale_ph<const IMM8: i32>(a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_roundscale_round_ph<const IMM8: i32, const SAE: i32>(a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_rsqrt_ph(a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_scalef_ph(a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_scalef_round_ph<const ROUNDING: i32>(a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_setzero_ph() -> __m512h;
+        [avx512fp16] safe fn _mm512_sqrt_ph(a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_sqrt_round_ph<const ROUNDING: i32>(a: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_sub_ph(a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_sub_round_ph<const ROUNDING: i32>(a: __m512h, b: __m512h) -> __m512h;
+        [avx512fp16] safe fn _mm512_undefined_ph() -> __m512h;
+        [avx512fp16] safe fn _mm512_zextph128_ph512(a: __m128h) -> __m512h;
+        [avx512fp16] safe fn _mm512_zextph256_ph512(a: __m256h) -> __m512h;
+        [avx512fp16, avx512vl] safe fn _mm_abs_ph(v2: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_add_ph(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_add_round_sh<const ROUNDING: i32>(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_add_sh(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_castpd_ph(a: __m128d) -> __m128h;
+        [avx512fp16] safe fn _mm_castph_pd(a: __m128h) -> __m128d;
+        [avx512fp16] safe fn _mm_castph_ps(a: __m128h) -> __m128;
+        [avx512fp16] safe fn _mm_castph_si128(a: __m128h) -> __m128i;
+        [avx512fp16] safe fn _mm_castps_ph(a: __m128) -> __m128h;
+        [avx512fp16] safe fn _mm_castsi128_ph(a: __m128i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_cmp_ph_mask<const IMM5: i32>(a: __m128h, b: __m128h) -> __mmask8;
+        [avx512fp16] safe fn _mm_cmp_round_sh_mask<const IMM5: i32, const SAE: i32>(a: __m128h, b: __m128h) -> __mmask8;
+        [avx512fp16] safe fn _mm_cmp_sh_mask<const IMM5: i32>(a: __m128h, b: __m128h) -> __mmask8;
+        [avx512fp16, avx512vl] safe fn _mm_cmul_pch(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_cmul_round_sch<const ROUNDING: i32>(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_cmul_sch(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_comi_round_sh<const IMM5: i32, const SAE: i32>(a: __m128h, b: __m128h) -> i32;
+        [avx512fp16] safe fn _mm_comi_sh<const IMM5: i32>(a: __m128h, b: __m128h) -> i32;
+        [avx512fp16] safe fn _mm_comieq_sh(a: __m128h, b: __m128h) -> i32;
+        [avx512fp16] safe fn _mm_comige_sh(a: __m128h, b: __m128h) -> i32;
+        [avx512fp16] safe fn _mm_comigt_sh(a: __m128h, b: __m128h) -> i32;
+        [avx512fp16] safe fn _mm_comile_sh(a: __m128h, b: __m128h) -> i32;
+        [avx512fp16] safe fn _mm_comilt_sh(a: __m128h, b: __m128h) -> i32;
+        [avx512fp16] safe fn _mm_comineq_sh(a: __m128h, b: __m128h) -> i32;
+        [avx512fp16, avx512vl] safe fn _mm_conj_pch(a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_cvt_roundi32_sh<const ROUNDING: i32>(a: __m128h, b: i32) -> __m128h;
+        #[cfg(target_arch = "x86_64")]
+        [avx512fp16] safe fn _mm_cvt_roundi64_sh<const ROUNDING: i32>(a: __m128h, b: i64) -> __m128h;
+        [avx512fp16] safe fn _mm_cvt_roundsd_sh<const ROUNDING: i32>(a: __m128h, b: __m128d) -> __m128h;
+        [avx512fp16] safe fn _mm_cvt_roundsh_i32<const ROUNDING: i32>(a: __m128h) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512fp16] safe fn _mm_cvt_roundsh_i64<const ROUNDING: i32>(a: __m128h) -> i64;
+        [avx512fp16] safe fn _mm_cvt_roundsh_sd<const SAE: i32>(a: __m128d, b: __m128h) -> __m128d;
+        [avx512fp16] safe fn _mm_cvt_roundsh_ss<const SAE: i32>(a: __m128, b: __m128h) -> __m128;
+        [avx512fp16] safe fn _mm_cvt_roundsh_u32<const SAE: i32>(a: __m128h) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512fp16] safe fn _mm_cvt_roundsh_u64<const ROUNDING: i32>(a: __m128h) -> u64;
+        [avx512fp16] safe fn _mm_cvt_roundss_sh<const ROUNDING: i32>(a: __m128h, b: __m128) -> __m128h;
+        [avx512fp16] safe fn _mm_cvt_roundu32_sh<const ROUNDING: i32>(a: __m128h, b: u32) -> __m128h;
+        #[cfg(target_arch = "x86_64")]
+        [avx512fp16] safe fn _mm_cvt_roundu64_sh<const ROUNDING: i32>(a: __m128h, b: u64) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_cvtepi16_ph(a: __m128i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_cvtepi32_ph(a: __m128i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_cvtepi64_ph(a: __m128i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_cvtepu16_ph(a: __m128i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_cvtepu32_ph(a: __m128i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_cvtepu64_ph(a: __m128i) -> __m128h;
+        [avx512fp16] safe fn _mm_cvti32_sh(a: __m128h, b: i32) -> __m128h;
+        #[cfg(target_arch = "x86_64")]
+        [avx512fp16] safe fn _mm_cvti64_sh(a: __m128h, b: i64) -> __m128h;
+        [avxneconvert] unsafe fn _mm_cvtneeph_ps(a: *const __m128h) -> __m128;
+        [avxneconvert] unsafe fn _mm_cvtneoph_ps(a: *const __m128h) -> __m128;
+        [avx512fp16, avx512vl] safe fn _mm_cvtpd_ph(a: __m128d) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_cvtph_epi16(a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_cvtph_epi32(a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_cvtph_epi64(a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_cvtph_epu16(a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_cvtph_epu32(a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_cvtph_epu64(a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_cvtph_pd(a: __m128h) -> __m128d;
+        [avx512fp16] safe fn _mm_cvtsd_sh(a: __m128h, b: __m128d) -> __m128h;
+        [avx512fp16] safe fn _mm_cvtsh_i32(a: __m128h) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512fp16] safe fn _mm_cvtsh_i64(a: __m128h) -> i64;
+        [avx512fp16] safe fn _mm_cvtsh_sd(a: __m128d, b: __m128h) -> __m128d;
+        [avx512fp16] safe fn _mm_cvtsh_ss(a: __m128, b: __m128h) -> __m128;
+        [avx512fp16] safe fn _mm_cvtsh_u32(a: __m128h) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512fp16] safe fn _mm_cvtsh_u64(a: __m128h) -> u64;
+        [avx512fp16] safe fn _mm_cvtsi128_si16(a: __m128i) -> i16;
+        [avx512fp16] safe fn _mm_cvtsi16_si128(a: i16) -> __m128i;
+        [avx512fp16] safe fn _mm_cvtss_sh(a: __m128h, b: __m128) -> __m128h;
+        [avx512fp16] safe fn _mm_cvtt_roundsh_i32<const SAE: i32>(a: __m128h) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512fp16] safe fn _mm_cvtt_roundsh_i64<const SAE: i32>(a: __m128h) -> i64;
+        [avx512fp16] safe fn _mm_cvtt_roundsh_u32<const SAE: i32>(a: __m128h) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512fp16] safe fn _mm_cvtt_roundsh_u64<const SAE: i32>(a: __m128h) -> u64;
+        [avx512fp16, avx512vl] safe fn _mm_cvttph_epi16(a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_cvttph_epi32(a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_cvttph_epi64(a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_cvttph_epu16(a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_cvttph_epu32(a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_cvttph_epu64(a: __m128h) -> __m128i;
+        [avx512fp16] safe fn _mm_cvttsh_i32(a: __m128h) -> i32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512fp16] safe fn _mm_cvttsh_i64(a: __m128h) -> i64;
+        [avx512fp16] safe fn _mm_cvttsh_u32(a: __m128h) -> u32;
+        #[cfg(target_arch = "x86_64")]
+        [avx512fp16] safe fn _mm_cvttsh_u64(a: __m128h) -> u64;
+        [avx512fp16] safe fn _mm_cvtu32_sh(a: __m128h, b: u32) -> __m128h;
+        #[cfg(target_arch = "x86_64")]
+        [avx512fp16] safe fn _mm_cvtu64_sh(a: __m128h, b: u64) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_cvtxph_ps(a: __m128h) -> __m128;
+        [avx512fp16, avx512vl] safe fn _mm_cvtxps_ph(a: __m128) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_div_ph(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_div_round_sh<const ROUNDING: i32>(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_div_sh(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_fcmadd_pch(a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_fcmadd_round_sch<const ROUNDING: i32>(a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_fcmadd_sch(a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_fcmul_pch(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_fcmul_round_sch<const ROUNDING: i32>(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_fcmul_sch(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_fmadd_pch(a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_fmadd_ph(a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_fmadd_round_sch<const ROUNDING: i32>(a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_fmadd_round_sh<const ROUNDING: i32>(a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_fmadd_sch(a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_fmadd_sh(a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_fmaddsub_ph(a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_fmsub_ph(a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_fmsub_round_sh<const ROUNDING: i32>(a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_fmsub_sh(a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_fmsubadd_ph(a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_fmul_pch(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_fmul_round_sch<const ROUNDING: i32>(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_fmul_sch(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_fnmadd_ph(a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_fnmadd_round_sh<const ROUNDING: i32>(a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_fnmadd_sh(a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_fnmsub_ph(a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_fnmsub_round_sh<const ROUNDING: i32>(a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_fnmsub_sh(a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_fpclass_ph_mask<const IMM8: i32>(a: __m128h) -> __mmask8;
+        [avx512fp16] safe fn _mm_fpclass_sh_mask<const IMM8: i32>(a: __m128h) -> __mmask8;
+        [avx512fp16, avx512vl] safe fn _mm_getexp_ph(a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_getexp_round_sh<const SAE: i32>(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_getexp_sh(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_getmant_ph<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_getmant_round_sh<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM, const SAE: i32>(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_getmant_sh<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask3_fcmadd_pch(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h;
+        [avx512fp16] safe fn _mm_mask3_fcmadd_round_sch<const ROUNDING: i32>(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h;
+        [avx512fp16] safe fn _mm_mask3_fcmadd_sch(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask3_fmadd_pch(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask3_fmadd_ph(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h;
+        [avx512fp16] safe fn _mm_mask3_fmadd_round_sch<const ROUNDING: i32>(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h;
+        [avx512fp16] safe fn _mm_mask3_fmadd_round_sh<const ROUNDING: i32>(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h;
+        [avx512fp16] safe fn _mm_mask3_fmadd_sch(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h;
+        [avx512fp16] safe fn _mm_mask3_fmadd_sh(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask3_fmaddsub_ph(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask3_fmsub_ph(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h;
+        [avx512fp16] safe fn _mm_mask3_fmsub_round_sh<const ROUNDING: i32>(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h;
+        [avx512fp16] safe fn _mm_mask3_fmsub_sh(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask3_fmsubadd_ph(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask3_fnmadd_ph(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h;
+        [avx512fp16] safe fn _mm_mask3_fnmadd_round_sh<const ROUNDING: i32>(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h;
+        [avx512fp16] safe fn _mm_mask3_fnmadd_sh(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask3_fnmsub_ph(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h;
+        [avx512fp16] safe fn _mm_mask3_fnmsub_round_sh<const ROUNDING: i32>(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h;
+        [avx512fp16] safe fn _mm_mask3_fnmsub_sh(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_add_ph(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_add_round_sh<const ROUNDING: i32>(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_add_sh(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_blend_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cmp_ph_mask<const IMM5: i32>(k1: __mmask8, a: __m128h, b: __m128h) -> __mmask8;
+        [avx512fp16] safe fn _mm_mask_cmp_round_sh_mask<const IMM5: i32, const SAE: i32>(k1: __mmask8, a: __m128h, b: __m128h) -> __mmask8;
+        [avx512fp16] safe fn _mm_mask_cmp_sh_mask<const IMM5: i32>(k1: __mmask8, a: __m128h, b: __m128h) -> __mmask8;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cmul_pch(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_cmul_round_sch<const ROUNDING: i32>(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_cmul_sch(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_conj_pch(src: __m128h, k: __mmask8, a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_cvt_roundsd_sh<const ROUNDING: i32>(src: __m128h, k: __mmask8, a: __m128h, b: __m128d) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_cvt_roundsh_sd<const SAE: i32>(src: __m128d, k: __mmask8, a: __m128d, b: __m128h) -> __m128d;
+        [avx512fp16] safe fn _mm_mask_cvt_roundsh_ss<const SAE: i32>(src: __m128, k: __mmask8, a: __m128, b: __m128h) -> __m128;
+        [avx512fp16] safe fn _mm_mask_cvt_roundss_sh<const ROUNDING: i32>(src: __m128h, k: __mmask8, a: __m128h, b: __m128) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cvtepi16_ph(src: __m128h, k: __mmask8, a: __m128i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cvtepi32_ph(src: __m128h, k: __mmask8, a: __m128i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cvtepi64_ph(src: __m128h, k: __mmask8, a: __m128i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cvtepu16_ph(src: __m128h, k: __mmask8, a: __m128i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cvtepu32_ph(src: __m128h, k: __mmask8, a: __m128i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cvtepu64_ph(src: __m128h, k: __mmask8, a: __m128i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cvtpd_ph(src: __m128h, k: __mmask8, a: __m128d) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cvtph_epi16(src: __m128i, k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cvtph_epi32(src: __m128i, k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cvtph_epi64(src: __m128i, k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cvtph_epu16(src: __m128i, k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cvtph_epu32(src: __m128i, k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cvtph_epu64(src: __m128i, k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cvtph_pd(src: __m128d, k: __mmask8, a: __m128h) -> __m128d;
+        [avx512fp16] safe fn _mm_mask_cvtsd_sh(src: __m128h, k: __mmask8, a: __m128h, b: __m128d) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_cvtsh_sd(src: __m128d, k: __mmask8, a: __m128d, b: __m128h) -> __m128d;
+        [avx512fp16] safe fn _mm_mask_cvtsh_ss(src: __m128, k: __mmask8, a: __m128, b: __m128h) -> __m128;
+        [avx512fp16] safe fn _mm_mask_cvtss_sh(src: __m128h, k: __mmask8, a: __m128h, b: __m128) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cvttph_epi16(src: __m128i, k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cvttph_epi32(src: __m128i, k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cvttph_epi64(src: __m128i, k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cvttph_epu16(src: __m128i, k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cvttph_epu32(src: __m128i, k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cvttph_epu64(src: __m128i, k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cvtxph_ps(src: __m128, k: __mmask8, a: __m128h) -> __m128;
+        [avx512fp16, avx512vl] safe fn _mm_mask_cvtxps_ph(src: __m128h, k: __mmask8, a: __m128) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_div_ph(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_div_round_sh<const ROUNDING: i32>(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_div_sh(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_fcmadd_pch(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_fcmadd_round_sch<const ROUNDING: i32>(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_fcmadd_sch(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_fcmul_pch(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_fcmul_round_sch<const ROUNDING: i32>(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_fcmul_sch(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_fmadd_pch(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_fmadd_ph(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_fmadd_round_sch<const ROUNDING: i32>(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_fmadd_round_sh<const ROUNDING: i32>(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_fmadd_sch(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_fmadd_sh(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_fmaddsub_ph(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_fmsub_ph(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_fmsub_round_sh<const ROUNDING: i32>(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_fmsub_sh(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_fmsubadd_ph(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_fmul_pch(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_fmul_round_sch<const ROUNDING: i32>(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_fmul_sch(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_fnmadd_ph(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_fnmadd_round_sh<const ROUNDING: i32>(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_fnmadd_sh(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_fnmsub_ph(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_fnmsub_round_sh<const ROUNDING: i32>(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_fnmsub_sh(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_fpclass_ph_mask<const IMM8: i32>(k1: __mmask8, a: __m128h) -> __mmask8;
+        [avx512fp16] safe fn _mm_mask_fpclass_sh_mask<const IMM8: i32>(k1: __mmask8, a: __m128h) -> __mmask8;
+        [avx512fp16, avx512vl] safe fn _mm_mask_getexp_ph(src: __m128h, k: __mmask8, a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_getexp_round_sh<const SAE: i32>(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_getexp_sh(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_getmant_ph<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(src: __m128h, k: __mmask8, a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_getmant_round_sh<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM, const SAE: i32>(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_getmant_sh<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_max_ph(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_max_round_sh<const SAE: i32>(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_max_sh(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_min_ph(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_min_round_sh<const SAE: i32>(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_min_sh(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_move_sh(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_mul_pch(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_mul_ph(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_mul_round_sch<const ROUNDING: i32>(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_mul_round_sh<const ROUNDING: i32>(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_mul_sch(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_mul_sh(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_rcp_ph(src: __m128h, k: __mmask8, a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_rcp_sh(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_reduce_ph<const IMM8: i32>(src: __m128h, k: __mmask8, a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_reduce_round_sh<const IMM8: i32, const SAE: i32>(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_reduce_sh<const IMM8: i32>(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_roundscale_ph<const IMM8: i32>(src: __m128h, k: __mmask8, a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_roundscale_round_sh<const IMM8: i32, const SAE: i32>(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_roundscale_sh<const IMM8: i32>(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_rsqrt_ph(src: __m128h, k: __mmask8, a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_rsqrt_sh(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_scalef_ph(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_scalef_round_sh<const ROUNDING: i32>(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_scalef_sh(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_sqrt_ph(src: __m128h, k: __mmask8, a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_sqrt_round_sh<const ROUNDING: i32>(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_sqrt_sh(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mask_sub_ph(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_sub_round_sh<const ROUNDING: i32>(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mask_sub_sh(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_add_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_add_round_sh<const ROUNDING: i32>(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_add_sh(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_cmul_pch(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_cmul_round_sch<const ROUNDING: i32>(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_cmul_sch(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_conj_pch(k: __mmask8, a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_cvt_roundsd_sh<const ROUNDING: i32>(k: __mmask8, a: __m128h, b: __m128d) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_cvt_roundsh_sd<const SAE: i32>(k: __mmask8, a: __m128d, b: __m128h) -> __m128d;
+        [avx512fp16] safe fn _mm_maskz_cvt_roundsh_ss<const SAE: i32>(k: __mmask8, a: __m128, b: __m128h) -> __m128;
+        [avx512fp16] safe fn _mm_maskz_cvt_roundss_sh<const ROUNDING: i32>(k: __mmask8, a: __m128h, b: __m128) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_cvtepi16_ph(k: __mmask8, a: __m128i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_cvtepi32_ph(k: __mmask8, a: __m128i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_cvtepi64_ph(k: __mmask8, a: __m128i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_cvtepu16_ph(k: __mmask8, a: __m128i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_cvtepu32_ph(k: __mmask8, a: __m128i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_cvtepu64_ph(k: __mmask8, a: __m128i) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_cvtpd_ph(k: __mmask8, a: __m128d) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_cvtph_epi16(k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_cvtph_epi32(k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_cvtph_epi64(k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_cvtph_epu16(k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_cvtph_epu32(k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_cvtph_epu64(k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_cvtph_pd(k: __mmask8, a: __m128h) -> __m128d;
+        [avx512fp16] safe fn _mm_maskz_cvtsd_sh(k: __mmask8, a: __m128h, b: __m128d) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_cvtsh_sd(k: __mmask8, a: __m128d, b: __m128h) -> __m128d;
+        [avx512fp16] safe fn _mm_maskz_cvtsh_ss(k: __mmask8, a: __m128, b: __m128h) -> __m128;
+        [avx512fp16] safe fn _mm_maskz_cvtss_sh(k: __mmask8, a: __m128h, b: __m128) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_cvttph_epi16(k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_cvttph_epi32(k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_cvttph_epi64(k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_cvttph_epu16(k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_cvttph_epu32(k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_cvttph_epu64(k: __mmask8, a: __m128h) -> __m128i;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_cvtxph_ps(k: __mmask8, a: __m128h) -> __m128;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_cvtxps_ph(k: __mmask8, a: __m128) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_div_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_div_round_sh<const ROUNDING: i32>(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_div_sh(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_fcmadd_pch(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_fcmadd_round_sch<const ROUNDING: i32>(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_fcmadd_sch(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_fcmul_pch(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_fcmul_round_sch<const ROUNDING: i32>(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_fcmul_sch(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_fmadd_pch(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_fmadd_ph(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_fmadd_round_sch<const ROUNDING: i32>(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_fmadd_round_sh<const ROUNDING: i32>(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_fmadd_sch(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_fmadd_sh(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_fmaddsub_ph(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_fmsub_ph(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_fmsub_round_sh<const ROUNDING: i32>(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_fmsub_sh(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_fmsubadd_ph(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_fmul_pch(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_fmul_round_sch<const ROUNDING: i32>(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_fmul_sch(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_fnmadd_ph(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_fnmadd_round_sh<const ROUNDING: i32>(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_fnmadd_sh(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_fnmsub_ph(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_fnmsub_round_sh<const ROUNDING: i32>(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_fnmsub_sh(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_getexp_ph(k: __mmask8, a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_getexp_round_sh<const SAE: i32>(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_getexp_sh(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_getmant_ph<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(k: __mmask8, a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_getmant_round_sh<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM, const SAE: i32>(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_getmant_sh<const NORM: _MM_MANTISSA_NORM_ENUM, const SIGN: _MM_MANTISSA_SIGN_ENUM>(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_max_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_max_round_sh<const SAE: i32>(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_max_sh(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_min_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_min_round_sh<const SAE: i32>(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_min_sh(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_move_sh(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_mul_pch(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_mul_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_mul_round_sch<const ROUNDING: i32>(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_mul_round_sh<const ROUNDING: i32>(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_mul_sch(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_mul_sh(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_rcp_ph(k: __mmask8, a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_rcp_sh(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_reduce_ph<const IMM8: i32>(k: __mmask8, a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_reduce_round_sh<const IMM8: i32, const SAE: i32>(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_reduce_sh<const IMM8: i32>(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_roundscale_ph<const IMM8: i32>(k: __mmask8, a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_roundscale_round_sh<const IMM8: i32, const SAE: i32>(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_roundscale_sh<const IMM8: i32>(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_rsqrt_ph(k: __mmask8, a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_rsqrt_sh(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_scalef_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_scalef_round_sh<const ROUNDING: i32>(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_scalef_sh(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_sqrt_ph(k: __mmask8, a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_sqrt_round_sh<const ROUNDING: i32>(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_sqrt_sh(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_maskz_sub_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_sub_round_sh<const ROUNDING: i32>(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_maskz_sub_sh(k: __mmask8, a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_max_ph(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_max_round_sh<const SAE: i32>(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_max_sh(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_min_ph(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_min_round_sh<const SAE: i32>(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_min_sh(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_move_sh(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mul_pch(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_mul_ph(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mul_round_sch<const ROUNDING: i32>(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mul_round_sh<const ROUNDING: i32>(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mul_sch(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_mul_sh(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_permutex2var_ph(a: __m128h, idx: __m128i, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_permutexvar_ph(idx: __m128i, a: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_rcp_ph(a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_rcp_sh(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_reduce_ph<const IMM8: i32>(a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_reduce_round_sh<const IMM8: i32, const SAE: i32>(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_reduce_sh<const IMM8: i32>(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_roundscale_ph<const IMM8: i32>(a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_roundscale_round_sh<const IMM8: i32, const SAE: i32>(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_roundscale_sh<const IMM8: i32>(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_rsqrt_ph(a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_rsqrt_sh(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_scalef_ph(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_scalef_round_sh<const ROUNDING: i32>(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_scalef_sh(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_setzero_ph() -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_sqrt_ph(a: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_sqrt_round_sh<const ROUNDING: i32>(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_sqrt_sh(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16, avx512vl] safe fn _mm_sub_ph(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_sub_round_sh<const ROUNDING: i32>(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_sub_sh(a: __m128h, b: __m128h) -> __m128h;
+        [avx512fp16] safe fn _mm_ucomieq_sh(a: __m128h, b: __m128h) -> i32;
+        [avx512fp16] safe fn _mm_ucomige_sh(a: __m128h, b: __m128h) -> i32;
+        [avx512fp16] safe fn _mm_ucomigt_sh(a: __m128h, b: __m128h) -> i32;
+        [avx512fp16] safe fn _mm_ucomile_sh(a: __m128h, b: __m128h) -> i32;
+        [avx512fp16] safe fn _mm_ucomilt_sh(a: __m128h, b: __m128h) -> i32;
+        [avx512fp16] safe fn _mm_ucomineq_sh(a: __m128h, b: __m128h) -> i32;
+        [avx512fp16, avx512vl] safe fn _mm_undefined_ph() -> __m128h;
+    }
+}
+
+#[rustversion::since(1.94)]
+pub use since_1_94::*;
@@ -0,0 +1,210 @@
+//! Hardware random number generation with `RDRAND` and `RDSEED`.
+//!
+//! ```
+//! use arch_types::{new_features_type, Features};
+//! use arch_types::x86::rng::HardwareRng;
+//!
+//! new_features_type! { Rdrand => "rdrand" }
+//!
+//! if let Some(token) = Rdrand::new() {
+//!     let mut rng = HardwareRng::new(token).expect("RDRAND is broken");
+//!     let mut key = [0u8; 32];
+//!     rng.try_fill_bytes(&mut key).unwrap();
+//! }
+//! ```
+
+use crate::logic::True;
+use crate::Features;
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+#[cfg(target_arch = "x86")]
+type Word = u32;
+#[cfg(target_arch = "x86_64")]
+type Word = u64;
+
+// The number of attempts before reporting failure.  Intel recommends ten attempts for `RDRAND`,
+// while `RDSEED` may need many more as it waits for the entropy source.
+const RDRAND_RETRIES: usize = 10;
+const RDSEED_RETRIES: usize = 1000;
+
+/// An error produced when the hardware random number generator fails.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RngError(());
+
+impl core::fmt::Display for RngError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("the hardware random number generator failed")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RngError {}
+
+#[derive(Copy, Clone, Debug)]
+enum Source {
+    Rdrand,
+    Rdseed,
+}
+
+/// A random number generator using the `RDRAND` or `RDSEED` instructions.
+///
+/// Each read retries while the instruction reports that no random value is available, failing with
+/// [`RngError`] if the retries are exhausted.  Some AMD processors return all ones without
+/// reporting failure (for example, after resuming from suspend), so all-ones values are treated as
+/// failures as well, and construction fails if the generator doesn't appear to work.
+///
+/// With the `rand_core` cargo feature, this type implements `rand_core::TryRngCore` and
+/// `rand_core::TryCryptoRng`.  Use `TryRngCore::unwrap_err` to obtain a `rand_core::RngCore`.
+///
+/// [`RngError`]: struct.RngError.html
+#[derive(Clone, Debug)]
+pub struct HardwareRng {
+    source: Source,
+}
+
+impl HardwareRng {
+    /// Create a random number generator using `RDRAND`.
+    ///
+    /// `RDRAND` returns the output of a cryptographically secure generator that is periodically
+    /// reseeded by the hardware entropy source.
+    pub fn new(_: impl Features<rdrand = True>) -> Result<Self, RngError> {
+        Self::with_source(Source::Rdrand)
+    }
+
+    /// Create a seed source using `RDSEED`.
+    ///
+    /// `RDSEED` returns values directly from the hardware entropy source, suitable for seeding
+    /// other generators.  It is slower than `RDRAND` and more likely to need retries.
+    pub fn seed_source(_: impl Features<rdseed = True>) -> Result<Self, RngError> {
+        Self::with_source(Source::Rdseed)
+    }
+
+    fn with_source(source: Source) -> Result<Self, RngError> {
+        let mut rng = Self { source };
+
+        // A working generator shouldn't repeat itself
+        let first = rng.word()?;
+        for _ in 0..8 {
+            if rng.word()? != first {
+                return Ok(rng);
+            }
+        }
+        Err(RngError(()))
+    }
+
+    #[inline]
+    fn word(&mut self) -> Result<Word, RngError> {
+        // Safety: the token used to construct `self` proves the features are supported
+        unsafe {
+            match self.source {
+                Source::Rdrand => rdrand(),
+                Source::Rdseed => rdseed(),
+            }
+        }
+    }
+
+    /// Returns a random `u32`.
+    #[inline]
+    pub fn try_next_u32(&mut self) -> Result<u32, RngError> {
+        self.word().map(|word| word as u32)
+    }
+
+    /// Returns a random `u64`.
+    #[inline]
+    pub fn try_next_u64(&mut self) -> Result<u64, RngError> {
+        #[cfg(target_arch = "x86_64")]
+        {
+            self.word()
+        }
+
+        #[cfg(target_arch = "x86")]
+        {
+            let low = u64::from(self.word()?);
+            let high = u64::from(self.word()?);
+            Ok(low | (high << 32))
+        }
+    }
+
+    /// Fills `dest` with random bytes.
+    pub fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RngError> {
+        let mut chunks = dest.chunks_exact_mut(core::mem::size_of::<Word>());
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.word()?.to_le_bytes());
+        }
+        let remainder = chunks.into_remainder();
+        if !remainder.is_empty() {
+            let word = self.word()?.to_le_bytes();
+            remainder.copy_from_slice(&word[..remainder.len()]);
+        }
+        Ok(())
+    }
+}
+
+// Generate a function reading a word, retrying on failure
+macro_rules! read_word {
+    { #[$attr:meta] $name:ident, $step:ident, $retries:expr, $pause:expr } => {
+        #[$attr]
+        unsafe fn $name() -> Result<Word, RngError> {
+            for _ in 0..$retries {
+                let mut word = 0;
+                // A carry flag of zero indicates that no value was available.  Some AMD processors
+                // return all ones without clearing the carry flag.
+                if $step(&mut word) == 1 && word != Word::max_value() {
+                    return Ok(word);
+                }
+                if $pause {
+                    _mm_pause();
+                }
+            }
+            Err(RngError(()))
+        }
+    };
+}
+
+#[cfg(target_arch = "x86")]
+read_word! {
+    #[target_feature(enable = "rdrand")]
+    rdrand, _rdrand32_step, RDRAND_RETRIES, false
+}
+#[cfg(target_arch = "x86")]
+read_word! {
+    #[target_feature(enable = "rdseed")]
+    rdseed, _rdseed32_step, RDSEED_RETRIES, true
+}
+#[cfg(target_arch = "x86_64")]
+read_word! {
+    #[target_feature(enable = "rdrand")]
+    rdrand, _rdrand64_step, RDRAND_RETRIES, false
+}
+#[cfg(target_arch = "x86_64")]
+read_word! {
+    #[target_feature(enable = "rdseed")]
+    rdseed, _rdseed64_step, RDSEED_RETRIES, true
+}
+
+#[cfg(feature = "rand_core")]
+impl rand_core::TryRngCore for HardwareRng {
+    type Error = RngError;
+
+    #[inline]
+    fn try_next_u32(&mut self) -> Result<u32, RngError> {
+        HardwareRng::try_next_u32(self)
+    }
+
+    #[inline]
+    fn try_next_u64(&mut self) -> Result<u64, RngError> {
+        HardwareRng::try_next_u64(self)
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RngError> {
+        HardwareRng::try_fill_bytes(self, dest)
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl rand_core::TryCryptoRng for HardwareRng {}
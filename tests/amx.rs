@@ -0,0 +1,72 @@
+// Requesting AMX permission is process-wide, so this is kept separate from other tests that detect
+// features.  Replacing the permission request requires the `test-hooks` feature.
+#![cfg(all(target_arch = "x86_64", target_os = "linux", feature = "test-hooks"))]
+
+arch_types::new_features_type! { Amx => "amx-tile" }
+
+// Whether the processor and operating system support AMX, independent of this crate's detection
+fn amx_supported() -> bool {
+    use std::arch::x86_64::{__cpuid, __cpuid_count, _xgetbv};
+
+    #[target_feature(enable = "xsave")]
+    unsafe fn xcr0() -> u64 {
+        _xgetbv(0)
+    }
+
+    // Safety: `xgetbv` is only executed if the operating system enabled `xsave`
+    #[allow(unused_unsafe)]
+    unsafe {
+        let osxsave = __cpuid(1).ecx & (1 << 27) != 0;
+        let amx_tile = __cpuid_count(7, 0).edx & (1 << 24) != 0;
+        // Both XTILECFG and XTILEDATA must be enabled
+        osxsave && amx_tile && xcr0() & 0x6_0000 == 0x6_0000
+    }
+}
+
+// AMX is only detected with the `nightly` cargo feature on a recent nightly compiler
+#[rustversion::all(nightly, since(2024-09-01))]
+const DETECTS_AMX: bool = cfg!(feature = "nightly");
+#[rustversion::not(all(nightly, since(2024-09-01)))]
+const DETECTS_AMX: bool = false;
+
+#[test]
+fn amx_permission() {
+    use arch_types::{DetectError, Feature, Features};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    fn deny(code: usize, arg: usize) -> isize {
+        assert_eq!((code, arg), (0x1023, 18));
+        CALLS.fetch_add(1, Ordering::Relaxed);
+        -1
+    }
+    fn grant(_: usize, _: usize) -> isize {
+        CALLS.fetch_add(1, Ordering::Relaxed);
+        0
+    }
+
+    let supported = DETECTS_AMX && amx_supported();
+    assert_eq!(
+        Feature::amx_tile.check() != Err(DetectError::Unsupported(Feature::amx_tile)),
+        supported
+    );
+
+    // Safety: permission is denied, so no AMX instructions can be executed
+    unsafe { arch_types::x86::__mock_arch_prctl(Some(deny)) };
+    for _ in 0..2 {
+        match Amx::try_new() {
+            Ok(_) => panic!("AMX permission was denied"),
+            Err(DetectError::PermissionDenied(Feature::amx_tile, 1)) => assert!(supported),
+            Err(error) => assert_eq!(error, DetectError::Unsupported(Feature::amx_tile)),
+        }
+    }
+    assert!(Amx::new().is_none());
+    assert_eq!(CALLS.load(Ordering::Relaxed), supported as usize);
+
+    // Safety: no AMX instructions are executed while the hook is installed
+    unsafe { arch_types::x86::__mock_arch_prctl(Some(grant)) };
+    assert_eq!(Amx::new().is_some(), supported);
+    assert_eq!(Feature::amx_tile.is_detected(), supported);
+    // Safety: the real syscall is restored
+    unsafe { arch_types::x86::__mock_arch_prctl(None) };
+}
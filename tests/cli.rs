@@ -0,0 +1,93 @@
+// Runs the `arch-types` binary, which is only built with the `cli` feature.
+#![cfg(feature = "cli")]
+
+use arch_types::Feature;
+use std::process::{Command, Output};
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_arch-types"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+#[test]
+fn list() {
+    let output = run(&[]);
+    assert_eq!(output.status.code(), Some(0));
+    let detected = Feature::ALL
+        .iter()
+        .filter(|feature| feature.is_detected())
+        .map(|feature| feature.name())
+        .collect::<Vec<_>>();
+    assert_eq!(stdout(&output).lines().collect::<Vec<_>>(), detected);
+}
+
+#[test]
+fn all() {
+    let output = run(&["--all"]);
+    assert_eq!(output.status.code(), Some(0));
+    let lines = stdout(&output).lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), Feature::ALL.len());
+    for (line, feature) in lines.iter().zip(Feature::ALL) {
+        let mut columns = line.split_whitespace();
+        assert_eq!(columns.next(), Some(feature.name()));
+        let status = if feature.is_detected() { "yes" } else { "no" };
+        assert_eq!(columns.next(), Some(status));
+    }
+}
+
+#[test]
+fn json() {
+    let output = run(&["--json"]);
+    assert_eq!(output.status.code(), Some(0));
+    let json = stdout(&output);
+    assert!(json.starts_with('{'));
+    assert!(json.contains(&format!("\"arch\": \"{}\"", std::env::consts::ARCH)));
+    for feature in Feature::ALL {
+        assert!(json.contains(&format!("\"{}\": {}", feature, feature.is_detected())));
+    }
+}
+
+#[test]
+fn rustc() {
+    let output = run(&["--rustc"]);
+    assert_eq!(output.status.code(), Some(0));
+    let features = stdout(&output).trim();
+    if !features.is_empty() {
+        assert!(features.split(',').all(|feature| feature.starts_with('+')));
+    }
+}
+
+#[test]
+fn check() {
+    if let Some(feature) = Feature::ALL.iter().find(|feature| feature.is_detected()) {
+        assert_eq!(run(&["--check", feature.name()]).status.code(), Some(0));
+    }
+
+    if let Some(feature) = Feature::ALL.iter().find(|feature| !feature.is_detected()) {
+        let output = run(&["--check", feature.name()]);
+        assert_eq!(output.status.code(), Some(1));
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(stderr.contains(feature.name()));
+    }
+
+    assert_eq!(run(&["--check", "not-a-feature"]).status.code(), Some(2));
+    assert_eq!(run(&["--check", ""]).status.code(), Some(2));
+    assert_eq!(run(&["--check", ","]).status.code(), Some(2));
+    assert_eq!(run(&["--check"]).status.code(), Some(2));
+}
+
+#[test]
+fn usage() {
+    let output = run(&["--help"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(stdout(&output).starts_with("Usage: arch-types"));
+
+    assert_eq!(run(&["--unknown"]).status.code(), Some(2));
+    assert_eq!(run(&["--all", "--json"]).status.code(), Some(2));
+}
@@ -16,8 +16,15 @@ mod x86 {
         assert!(arch_types::has_features!(tag => "avx2"));
     }
 
+    // Deprecated names are still accepted
+    #[allow(deprecated)]
+    fn gfni(tag: arch_types::impl_features! { "avx512gfni" }) {
+        assert!(arch_types::has_features!(tag => "gfni", "avx512gfni"));
+    }
+
     arch_types::new_features_type! { ArchSseSse2Avx => "sse", "sse2", "avx" }
-    arch_types::new_features_type! { ArchSseAvxAvx2 => "sse", "avx", "avx2" }
+    arch_types::new_features_type! { ArchGfni => "gfni" }
+    arch_types::new_features_type! { pub ArchSseAvxAvx2 => "sse", "avx", "avx2" }
     arch_types::new_features_type! { ArchSseAvx2 => "sse", "avx2" }
 
     #[test]
@@ -33,6 +40,96 @@ mod x86 {
             sse_avx(tag);
             avx2(tag);
         }
+        if let Some(tag) = ArchGfni::new() {
+            gfni(tag);
+        }
+    }
+
+    #[test]
+    fn feature_set_of() {
+        use arch_types::{Feature, FeatureSet};
+        let set = FeatureSet::of::<ArchSseAvx2>();
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            [Feature::sse, Feature::avx2]
+        );
+        assert!(FeatureSet::of::<ArchSseAvxAvx2>().satisfies(&set));
+        assert!(!FeatureSet::of::<ArchSseSse2Avx>().satisfies(&set));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use arch_types::{Feature, FeatureSet};
+        use serde_test::{assert_tokens, Token};
+
+        assert_tokens(&Feature::sse41, &[Token::Str("sse4.1")]);
+        assert_tokens(
+            &FeatureSet::of::<ArchSseAvx2>(),
+            &[
+                Token::Seq { len: None },
+                Token::Str("sse"),
+                Token::Str("avx2"),
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    arch_types::new_features_type! { pub ArchAvx512 => "avx512f" }
+    arch_types::new_features_type! { pub ArchSse2 => "sse2" }
+    arch_types::new_features_type! { ArchSse41 => "sse4.1" }
+    arch_types::new_features_type! { ArchAvx2 => "avx2" }
+    arch_types::new_features_type! { pub ArchAvx2Fma => "avx2", "fma" }
+    arch_types::new_features_type! { pub ArchAvx512bw => "avx512bw" }
+
+    #[test]
+    fn vector_registers() {
+        use arch_types::Features;
+        let buffer = [0u8; <ArchSseAvxAvx2 as Features>::VECTOR_BYTES];
+        assert_eq!(buffer.len(), 32);
+        assert_eq!(ArchSseAvx2::VECTOR_BYTES, 32);
+        assert_eq!(ArchAvx512::VECTOR_BYTES, 64);
+        assert_eq!(super::Baseline::VECTOR_BYTES, 0);
+
+        // Features implying vector registers
+        assert_eq!(ArchSse2::VECTOR_BYTES, 16);
+        assert_eq!(ArchSse41::VECTOR_BYTES, 16);
+        assert_eq!(ArchAvx2::VECTOR_BYTES, 32);
+        assert_eq!(ArchAvx2Fma::VECTOR_BYTES, 32);
+        assert_eq!(ArchAvx512bw::VECTOR_BYTES, 64);
+        assert!(ArchAvx512bw::HAS_MASK_REGISTERS);
+
+        let (registers, avx512_registers) = if cfg!(target_arch = "x86_64") {
+            (16, 32)
+        } else {
+            (8, 8)
+        };
+        assert_eq!(ArchSseAvxAvx2::VECTOR_REGISTERS, registers);
+        assert_eq!(ArchSse2::VECTOR_REGISTERS, registers);
+        assert_eq!(ArchAvx512::VECTOR_REGISTERS, avx512_registers);
+        assert!(ArchAvx512::HAS_MASK_REGISTERS);
+        assert!(!ArchSseAvxAvx2::HAS_MASK_REGISTERS);
+    }
+
+    // The intrinsic wrappers require Rust 1.63
+    #[rustversion::since(1.63)]
+    #[test]
+    fn intrinsics() {
+        use arch_types::{x86::intrinsics::*, Features};
+        if let Some(tag) = ArchSseSse2Avx::new() {
+            let a = _mm_setr_ps(tag, 1., 2., 3., 4.);
+            let b = _mm_shuffle_ps::<0x1b>(tag, a, a);
+            let mut out = [0f32; 4];
+            unsafe { _mm_storeu_ps(tag, out.as_mut_ptr(), _mm_add_ps(tag, a, b)) };
+            assert_eq!(out, [5.; 4]);
+        }
+        if let Some(tag) = ArchSseAvxAvx2::new() {
+            let a = _mm256_set1_epi32(tag, 3);
+            assert_eq!(
+                _mm256_extract_epi32::<5>(tag, _mm256_add_epi32(tag, a, a)),
+                6
+            );
+        }
     }
 
     #[test]
@@ -47,4 +144,563 @@ mod x86 {
             assert!(tag.shrink::<ArchSseSse2Avx>().is_none());
         }
     }
+
+    arch_types::new_features_type! { ArchTsc => "tsc" }
+
+    #[test]
+    fn tsc() {
+        use arch_types::counter::Tsc;
+        use arch_types::Features;
+
+        if let Some(token) = ArchTsc::new() {
+            let tsc = Tsc::from_token(token);
+            let start = tsc.read();
+            if let Some((ticks, _)) = tsc.read_rdtscp() {
+                assert!(ticks >= start);
+            }
+            #[cfg(feature = "std")]
+            {
+                if tsc.is_invariant() {
+                    let calibration = tsc.calibrate(std::time::Duration::from_millis(20));
+                    assert!(calibration.ticks_per_second() > 1e6);
+                    let ticks = calibration.ticks_per_second() as u64;
+                    let seconds = calibration.to_duration(ticks).as_secs_f64();
+                    assert!((seconds - 1.).abs() < 1e-6);
+                }
+            }
+        }
+    }
+
+    arch_types::new_features_type! { Rdrand => "rdrand" }
+    arch_types::new_features_type! { Rdseed => "rdseed" }
+
+    #[test]
+    fn hardware_rng() {
+        use arch_types::x86::rng::HardwareRng;
+        use arch_types::Features;
+
+        fn check(mut rng: HardwareRng) {
+            let values = (0..4)
+                .map(|_| rng.try_next_u64().unwrap())
+                .collect::<Vec<_>>();
+            assert!(values.iter().any(|x| *x != values[0]));
+
+            let mut bytes = [0u8; 67];
+            rng.try_fill_bytes(&mut bytes).unwrap();
+            assert!(bytes.iter().any(|x| *x != 0));
+
+            #[cfg(feature = "rand_core")]
+            {
+                use rand_core::{RngCore, TryRngCore};
+                rng.try_next_u32().unwrap();
+                rng.unwrap_mut().next_u64();
+            }
+        }
+
+        if let Some(token) = Rdrand::new() {
+            check(HardwareRng::new(token).unwrap());
+        }
+        if let Some(token) = Rdseed::new() {
+            check(HardwareRng::seed_source(token).unwrap());
+        }
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod aarch64 {
+    use arch_types::aarch64::Aarch64Level;
+
+    #[test]
+    fn levels() {
+        for higher in Aarch64Level::ALL {
+            for lower in Aarch64Level::ALL.iter().filter(|lower| *lower <= higher) {
+                assert!(higher.features().satisfies(&lower.features()));
+            }
+        }
+        assert!(Aarch64Level::Armv9_1 > Aarch64Level::Armv8_6);
+        assert!(Aarch64Level::Armv9_0 > Aarch64Level::Armv8_5);
+        assert_eq!(
+            Aarch64Level::Armv8_6.partial_cmp(&Aarch64Level::Armv9_0),
+            None
+        );
+
+        let level = Aarch64Level::detect().unwrap();
+        for detected in Aarch64Level::ALL.iter().filter(|level| level.is_detected()) {
+            assert!(*detected <= level);
+        }
+    }
+
+    arch_types::new_features_type! { NeonCrc => "neon", "crc" }
+
+    #[test]
+    fn intrinsics() {
+        use arch_types::{aarch64::intrinsics::*, Features};
+        if let Some(tag) = NeonCrc::new() {
+            let a = vdupq_n_u32(tag, 3);
+            let b = vaddq_u32(tag, a, a);
+            assert_eq!(vgetq_lane_u32::<2>(tag, b), 6);
+            let mut out = [0u32; 4];
+            unsafe { vst1q_u32(tag, out.as_mut_ptr(), b) };
+            assert_eq!(out, [6; 4]);
+            assert_eq!(__crc32cb(tag, !0, 0), 0xad82acae);
+        }
+    }
+
+    #[test]
+    fn virtual_counter() {
+        use arch_types::counter::VirtualCounter;
+
+        let counter = VirtualCounter::new();
+        let start = counter.read();
+        assert!(counter.read_ordered() >= start);
+        assert!(counter.frequency() > 0);
+        #[cfg(feature = "std")]
+        {
+            let calibration = counter.calibrate(std::time::Duration::from_millis(20));
+            let ratio = calibration.ticks_per_second() / counter.frequency() as f64;
+            assert!(ratio > 0.5 && ratio < 2.);
+        }
+    }
+
+    arch_types::new_features_type! { Sve => "sve" }
+
+    #[test]
+    fn vector_registers() {
+        use arch_types::Features;
+        assert_eq!(NeonCrc::VECTOR_BYTES, 16);
+        assert_eq!(NeonCrc::VECTOR_REGISTERS, 32);
+        assert!(!NeonCrc::HAS_MASK_REGISTERS);
+        assert!(Sve::HAS_MASK_REGISTERS);
+        assert_eq!(Sve2::VECTOR_BYTES, 16);
+        assert!(Sve2::HAS_MASK_REGISTERS);
+        assert_eq!(Dotprod::VECTOR_BYTES, 16);
+        assert!(!Dotprod::HAS_MASK_REGISTERS);
+    }
+
+    arch_types::new_features_type! { Sve2 => "sve2" }
+    arch_types::new_features_type! { Dotprod => "dotprod" }
+
+    #[test]
+    fn sve_vector_length() {
+        use arch_types::aarch64::SveVectorLength;
+        use arch_types::Features;
+        if let Some(tag) = Sve::new() {
+            let length = tag.sve_vector_length();
+            assert_eq!(length % 16, 0);
+            #[cfg(target_os = "linux")]
+            assert_eq!(
+                arch_types::aarch64::get_sve_config(tag)
+                    .unwrap()
+                    .vector_length,
+                length
+            );
+        }
+    }
+}
+
+mod simd {
+    use arch_types::simd::{F32Vec, Scalar, Simd};
+    use arch_types::Features;
+
+    // Computes the dot product, minimum, and maximum of `a * b`, and the sum of `(a - b) / 2`
+    fn kernel<S: Simd>(simd: S, a: &[f32], b: &[f32]) -> [f32; 4] {
+        simd.vectorize(|| {
+            let lanes = S::F32::LANES;
+            let mut dot = S::F32::splat(simd, 0.);
+            let mut min = S::F32::splat(simd, std::f32::INFINITY);
+            let mut max = S::F32::splat(simd, std::f32::NEG_INFINITY);
+            let mut out = vec![0.; a.len()];
+            for ((a, b), out) in a
+                .chunks_exact(lanes)
+                .zip(b.chunks_exact(lanes))
+                .zip(out.chunks_exact_mut(lanes))
+            {
+                let a = S::F32::from_slice(simd, a);
+                let b = S::F32::from_slice(simd, b);
+                dot = a.mul_add(b, dot);
+                min = min.min(a * b);
+                max = max.max(a * b);
+                ((a - b) / S::F32::splat(simd, 2.)).copy_to_slice(out);
+            }
+            [
+                dot.reduce_sum(),
+                min.reduce_min(),
+                max.reduce_max(),
+                out.iter().sum(),
+            ]
+        })
+    }
+
+    fn check<S: Simd>(simd: S) {
+        let a = (0..64).map(|x| x as f32 - 10.).collect::<Vec<_>>();
+        let b = (0..64).map(|x| (x % 7) as f32).collect::<Vec<_>>();
+        assert_eq!(kernel(simd, &a, &b), kernel(Scalar::new().unwrap(), &a, &b));
+        assert_eq!(kernel(simd, &a, &b), [4221., -25., 312., 593.5]);
+    }
+
+    #[test]
+    fn simd() {
+        check(Scalar::new().unwrap());
+        assert_eq!(
+            format!(
+                "{:?}",
+                <Scalar as Simd>::F32::splat(Scalar::new().unwrap(), 1.)
+            ),
+            "F32x1(1.0)"
+        );
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            use arch_types::simd::{Avx2Fma, Sse2};
+            if let Some(simd) = Sse2::new() {
+                check(simd);
+                assert_eq!(
+                    format!("{:?}", <Sse2 as Simd>::F32::splat(simd, 1.)),
+                    "F32x4([1.0, 1.0, 1.0, 1.0])"
+                );
+            }
+            if let Some(simd) = Avx2Fma::new() {
+                check(simd);
+            }
+        }
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[rustversion::since(1.89)]
+    #[test]
+    fn simd_avx512() {
+        if let Some(simd) = arch_types::simd::Avx512f::new() {
+            check(simd);
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    #[rustversion::since(1.61)]
+    #[test]
+    fn simd_neon() {
+        if let Some(simd) = arch_types::simd::Neon::new() {
+            check(simd);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn aligned_buf() {
+    use arch_types::aligned::AlignedBuf;
+    use arch_types::Features;
+
+    fn check<F: Features>(token: F, alignment: usize, lanes: usize) {
+        assert_eq!(AlignedBuf::<u16, F>::alignment(), alignment);
+        assert_eq!(AlignedBuf::<u16, F>::lanes(), lanes);
+
+        let values = (0..37).collect::<Vec<u16>>();
+        let mut buf = AlignedBuf::from_slice(token, &values);
+        assert_eq!(buf.as_ptr() as usize % alignment, 0);
+        assert_eq!(&buf[..], &values[..]);
+        assert_eq!(buf.as_padded_slice().len() % lanes, 0);
+        assert!(buf.as_padded_slice()[buf.len()..].iter().all(|x| *x == 0));
+
+        for chunk in buf.vector_chunks_mut() {
+            assert_eq!(chunk.as_ptr() as usize % alignment, 0);
+            chunk[0] = 100;
+        }
+        let chunks = buf.vector_chunks();
+        assert_eq!(chunks.remainder().len(), 37 % lanes);
+        assert_eq!(chunks.len(), 37 / lanes);
+        assert_eq!(buf.clone()[0], 100);
+
+        // Chunks of elements that don't evenly divide a vector are still aligned
+        let buf = AlignedBuf::<[u16; 3], F>::new(token, 37);
+        for chunk in buf.vector_chunks() {
+            assert_eq!(chunk.as_ptr() as usize % alignment, 0);
+        }
+
+        assert!(AlignedBuf::<u16, F>::new(token, 0).is_empty());
+        assert_eq!(AlignedBuf::<(), F>::new(token, 5).len(), 5);
+    }
+
+    check(Baseline::new().unwrap(), 2, 1);
+    assert_eq!(AlignedBuf::<[u16; 3], Baseline>::lanes(), 1);
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        assert_eq!(AlignedBuf::<f32, x86::ArchSse2>::alignment(), 16);
+        assert_eq!(AlignedBuf::<f32, x86::ArchSse2>::lanes(), 4);
+        assert_eq!(AlignedBuf::<f32, x86::ArchAvx2Fma>::alignment(), 32);
+        assert_eq!(AlignedBuf::<f32, x86::ArchAvx2Fma>::lanes(), 8);
+        assert_eq!(AlignedBuf::<[f32; 3], x86::ArchAvx2Fma>::lanes(), 8);
+        assert_eq!(AlignedBuf::<[u64; 6], x86::ArchAvx2Fma>::lanes(), 2);
+        assert_eq!(AlignedBuf::<[u64; 8], x86::ArchAvx2Fma>::lanes(), 1);
+        assert_eq!(AlignedBuf::<u64, x86::ArchAvx512bw>::lanes(), 8);
+
+        if let Some(token) = x86::ArchSseAvxAvx2::new() {
+            check(token, 32, 16);
+        }
+        if let Some(token) = x86::ArchAvx512::new() {
+            check(token, 64, 32);
+        }
+    }
+}
+
+#[test]
+fn crc() {
+    use arch_types::crc::{crc32, crc32_table, crc32c, crc32c_table};
+
+    assert_eq!(crc32_table(0, b"123456789"), 0xcbf4_3926);
+    assert_eq!(crc32c_table(0, b"123456789"), 0xe306_9283);
+
+    let mut state = 1u32;
+    let data = (0..1000)
+        .map(|_| {
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            (state >> 16) as u8
+        })
+        .collect::<Vec<_>>();
+
+    // Compare against the lookup table, including unaligned data and resuming from a checksum
+    let check = |f: &dyn Fn(u32, &[u8]) -> u32, table: fn(u32, &[u8]) -> u32| {
+        for &len in &[0, 1, 7, 15, 16, 63, 64, 65, 127, 128, 200, 999] {
+            for &offset in &[0, 1] {
+                let data = &data[offset..offset + len];
+                assert_eq!(f(0, data), table(0, data));
+                assert_eq!(f(0x1234_5678, data), table(0x1234_5678, data));
+            }
+        }
+    };
+    check(&crc32, crc32_table);
+    check(&crc32c, crc32c_table);
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        use arch_types::crc::{crc32_pclmulqdq, crc32c_pclmulqdq, crc32c_sse42};
+        use arch_types::Features;
+        arch_types::new_features_type! { Sse42 => "sse4.2" }
+        arch_types::new_features_type! { Pclmul => "pclmulqdq" }
+        if let Some(token) = Sse42::new() {
+            check(&|crc, data| crc32c_sse42(token, crc, data), crc32c_table);
+        }
+        if let Some(token) = Pclmul::new() {
+            check(&|crc, data| crc32_pclmulqdq(token, crc, data), crc32_table);
+            check(
+                &|crc, data| crc32c_pclmulqdq(token, crc, data),
+                crc32c_table,
+            );
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    {
+        use arch_types::crc::{crc32_arm, crc32c_arm};
+        use arch_types::Features;
+        arch_types::new_features_type! { Crc => "crc" }
+        if let Some(token) = Crc::new() {
+            check(&|crc, data| crc32_arm(token, crc, data), crc32_table);
+            check(&|crc, data| crc32c_arm(token, crc, data), crc32c_table);
+        }
+    }
+}
+
+#[test]
+fn clmul() {
+    use arch_types::clmul::{clmul64_soft, ghash_soft, polyval_soft};
+
+    type Hash<'a> = &'a dyn Fn(&[u8; 16], &mut [u8; 16], &[[u8; 16]]);
+
+    assert_eq!(clmul64_soft(0b11, 0b11), 0b101);
+    assert_eq!(clmul64_soft(1 << 63, 1 << 63), 1 << 126);
+
+    let mut state = 1u64;
+    let mut random = move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        state
+    };
+    let mut random_block = move || {
+        let mut block = [0; 16];
+        block[..8].copy_from_slice(&random().to_le_bytes());
+        block[8..].copy_from_slice(&random().to_le_bytes());
+        block
+    };
+    let key = random_block();
+    let blocks = (0..10).map(|_| random_block()).collect::<Vec<_>>();
+
+    // Compare the hashes against known answers and the portable implementation
+    let check = |clmul64: &dyn Fn(u64, u64) -> u128, ghash: Hash, polyval: Hash| {
+        assert_eq!(clmul64(!0, !0), clmul64_soft(!0, !0));
+        assert_eq!(
+            clmul64(0x0123_4567_89ab_cdef, 0xfedc_ba98_7654_3210),
+            clmul64_soft(0x0123_4567_89ab_cdef, 0xfedc_ba98_7654_3210)
+        );
+
+        // From RFC 8452, appendix A
+        let mut state = [0; 16];
+        polyval(
+            &0x7b75_4bba_26f8_311d_7642_9258_4793_6225u128.to_le_bytes(),
+            &mut state,
+            &[
+                0x62a2_012d_bb62_1740_b6df_838c_6695_4f4fu128.to_le_bytes(),
+                0x62f3_c9d3_205f_e4bb_06d0_2127_dd4d_a2d1u128.to_le_bytes(),
+            ],
+        );
+        assert_eq!(
+            u128::from_le_bytes(state),
+            0x7eb7_e5f5_6c86_b7e5_fa19_6184_7bb4_a3f7
+        );
+
+        // From test case 2 of the GCM specification
+        let mut state = [0; 16];
+        ghash(
+            &0x66e9_4bd4_ef8a_2c3b_884c_fa59_ca34_2b2eu128.to_be_bytes(),
+            &mut state,
+            &[
+                0x0388_dace_60b6_a392_f328_c2b9_71b2_fe78u128.to_be_bytes(),
+                0x80u128.to_be_bytes(),
+            ],
+        );
+        assert_eq!(
+            u128::from_be_bytes(state),
+            0xf38c_bb1a_d692_23dc_c345_7ae5_b6b0_f885
+        );
+
+        for &(hash, soft) in &[(ghash, &ghash_soft as Hash), (polyval, &polyval_soft)] {
+            let mut state = [0; 16];
+            let mut expected = [0; 16];
+            hash(&key, &mut state, &blocks[..3]);
+            hash(&key, &mut state, &blocks[3..]);
+            soft(&key, &mut expected, &blocks);
+            assert_eq!(state, expected);
+        }
+    };
+    check(&clmul64_soft, &ghash_soft, &polyval_soft);
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        use arch_types::clmul::{clmul64, ghash, polyval};
+        use arch_types::Features;
+        arch_types::new_features_type! { Pclmul => "pclmulqdq" }
+        if let Some(token) = Pclmul::new() {
+            check(
+                &|a, b| clmul64(token, a, b),
+                &|key, state, blocks| ghash(token, key, state, blocks),
+                &|key, state, blocks| polyval(token, key, state, blocks),
+            );
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    {
+        use arch_types::clmul::{clmul64, ghash, polyval};
+        use arch_types::Features;
+        arch_types::new_features_type! { Pmull => "pmull" }
+        if let Some(token) = Pmull::new() {
+            check(
+                &|a, b| clmul64(token, a, b),
+                &|key, state, blocks| ghash(token, key, state, blocks),
+                &|key, state, blocks| polyval(token, key, state, blocks),
+            );
+        }
+    }
+}
+
+arch_types::new_features_type! { Baseline => }
+
+fn baseline(_: arch_types::impl_features!()) {}
+
+#[test]
+fn no_features() {
+    use arch_types::Features;
+    let tag = Baseline::new().unwrap();
+    baseline(tag);
+    assert!(tag.shrink::<Baseline>().is_some());
+    assert!(arch_types::FeatureSet::of::<Baseline>().is_empty());
+}
+
+#[test]
+fn feature_names() {
+    use arch_types::Feature;
+    for feature in Feature::ALL {
+        assert_eq!(Feature::from_name(feature.name()), Some(*feature));
+    }
+    assert_eq!(Feature::from_name("not-a-feature"), None);
+}
+
+#[test]
+fn feature_info() {
+    use arch_types::{Feature, FEATURE_INFO};
+    assert_eq!(FEATURE_INFO.len(), Feature::ALL.len());
+    for feature in Feature::ALL {
+        let info = feature.info();
+        assert_eq!(info.name, feature.name());
+        for implied in info.implies {
+            assert!(Feature::from_name(implied).is_some(), "{}", implied);
+        }
+    }
+}
+
+#[test]
+fn feature_set() {
+    use arch_types::{Feature, FeatureSet};
+    let mut all = FeatureSet::new();
+    assert!(all.is_empty());
+    for feature in Feature::ALL {
+        assert!(all.insert(*feature));
+        assert!(!all.insert(*feature));
+    }
+    assert_eq!(all.len(), Feature::ALL.len());
+    assert!(all.iter().eq(Feature::ALL.iter().cloned()));
+
+    let detected = FeatureSet::detect();
+    assert!(all.satisfies(&detected));
+    assert!(detected.satisfies(&FeatureSet::new()));
+    assert!(all.missing(&detected).is_empty());
+    assert_eq!(detected.union(&detected.missing(&all)), all);
+}
+
+#[test]
+fn tables() {
+    use arch_types::tables::{aarch64, arm, loongarch64, powerpc64, riscv, s390x, wasm32, x86};
+
+    let mut worker = aarch64::FeatureSet::new();
+    worker.extend(
+        ["neon", "crc", "dotprod"]
+            .iter()
+            .filter_map(|name| aarch64::Feature::from_name(name)),
+    );
+    let mut job = aarch64::FeatureSet::new();
+    job.insert(aarch64::Feature::dotprod);
+    assert!(worker.satisfies(&job));
+    job.insert(aarch64::Feature::sve);
+    assert!(!worker.satisfies(&job));
+    assert!(aarch64::Feature::from_name("crypto").is_none());
+    assert_eq!(aarch64::Feature::sve2_aes.info().implies, ["aes", "sve2"]);
+
+    assert_eq!(
+        arm::Feature::from_name("mve.fp"),
+        Some(arm::Feature::mve_fp)
+    );
+    assert_eq!(x86::Feature::sse41.name(), "sse4.1");
+    assert_eq!(x86::Feature::gfni.info().version, "1.43.1");
+    assert!(powerpc64::Feature::from_name("avx").is_none());
+    assert_eq!(powerpc64::FEATURE_INFO.len(), powerpc64::Feature::ALL.len());
+    assert_eq!(
+        powerpc64::Feature::power10_vector.info().implies,
+        ["power9-vector"]
+    );
+
+    let zkn = riscv::Feature::zkn.info();
+    assert!(zkn.implies.contains(&"zknh"));
+    assert_eq!(
+        riscv::Feature::from_name("zicond"),
+        Some(riscv::Feature::zicond)
+    );
+
+    assert_eq!(wasm32::Feature::relaxed_simd.name(), "relaxed-simd");
+    assert_eq!(wasm32::Feature::relaxed_simd.info().implies, ["simd128"]);
+
+    assert_eq!(loongarch64::Feature::lasx.info().implies, ["lsx"]);
+    assert_eq!(
+        s390x::Feature::from_name("vector-enhancements-2"),
+        Some(s390x::Feature::vector_enhancements_2)
+    );
 }